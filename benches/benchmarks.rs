@@ -22,13 +22,29 @@ while i < 10 {
     i = i + 1;
 }
 "#;
+const NESTED_SCOPE_SUM: &str = r#"
+let mut sum = 0;
+let scale = 2;
+{
+    let offset = 1;
+    {
+        let base = 0;
+        {
+            let bias = 0;
+            for i in 0..1000 {
+                sum = sum + i * scale + offset + base + bias;
+            }
+        }
+    }
+}
+"#;
 const NESTED_EXPR: &str = "let result = (1 + 2) * (3 + 4) - (5 * 6) / (7 + 1);";
 
 fn bench_parse_simple_expr(c: &mut Criterion) {
     c.bench_function("parse_simple_expr", |b| {
         b.iter(|| {
             let mut parser = Parser::new(black_box(SIMPLE_EXPR));
-            black_box(parser.parse());
+            let _ = black_box(parser.parse());
         });
     });
 }
@@ -37,7 +53,7 @@ fn bench_parse_function(c: &mut Criterion) {
     c.bench_function("parse_function_def", |b| {
         b.iter(|| {
             let mut parser = Parser::new(black_box(FUNCTION_DEF));
-            black_box(parser.parse());
+            let _ = black_box(parser.parse());
         });
     });
 }
@@ -46,7 +62,7 @@ fn bench_parse_loop(c: &mut Criterion) {
     c.bench_function("parse_while_loop", |b| {
         b.iter(|| {
             let mut parser = Parser::new(black_box(LOOP_PROGRAM));
-            black_box(parser.parse());
+            let _ = black_box(parser.parse());
         });
     });
 }
@@ -55,7 +71,7 @@ fn bench_parse_nested_expr(c: &mut Criterion) {
     c.bench_function("parse_nested_expr", |b| {
         b.iter(|| {
             let mut parser = Parser::new(black_box(NESTED_EXPR));
-            black_box(parser.parse());
+            let _ = black_box(parser.parse());
         });
     });
 }
@@ -86,6 +102,17 @@ fn bench_eval_loop(c: &mut Criterion) {
     });
 }
 
+fn bench_eval_nested_scope_sum(c: &mut Criterion) {
+    // INTERP-087: outer-variable lookups from nested scopes hit the resolution cache
+    let ast = Parser::new(NESTED_SCOPE_SUM).parse().unwrap();
+    c.bench_function("eval_nested_scope_sum_range_1000", |b| {
+        b.iter(|| {
+            let mut evaluator = Evaluator::new();
+            let _ = black_box(evaluator.eval_program(&ast));
+        });
+    });
+}
+
 fn bench_scope_operations(c: &mut Criterion) {
     c.bench_function("scope_define_get_100_vars", |b| {
         b.iter(|| {
@@ -96,7 +123,7 @@ fn bench_scope_operations(c: &mut Criterion) {
             }
             for i in 0..100 {
                 let name = format!("var_{i}");
-                black_box(scope.get_cloned(&name));
+                let _ = black_box(scope.get_cloned(&name));
             }
         });
     });
@@ -114,7 +141,7 @@ fn bench_parse_scaling(c: &mut Criterion) {
             |b, program| {
                 b.iter(|| {
                     let mut parser = Parser::new(black_box(program));
-                    black_box(parser.parse());
+                    let _ = black_box(parser.parse());
                 });
             },
         );
@@ -130,6 +157,7 @@ criterion_group!(
     bench_parse_nested_expr,
     bench_eval_simple,
     bench_eval_loop,
+    bench_eval_nested_scope_sum,
     bench_scope_operations,
     bench_parse_scaling,
 );
@@ -77,7 +77,10 @@ fun main() {
     let compile_start = Instant::now();
     let mut main_fn: Option<fn() -> i64> = None;
     for node in ast.nodes() {
-        if let ruchyruchy::interpreter::parser::AstNode::FunctionDef { name, params, body } = node {
+        if let ruchyruchy::interpreter::parser::AstNode::FunctionDef {
+            name, params, body, ..
+        } = node
+        {
             if name == "main" {
                 main_fn = Some(
                     jit.compile_function_with_params(
@@ -708,8 +708,7 @@ fn handle_profile(_args: &[String]) {
         if let Some(svg_path) = flame_graph_svg {
             use ruchyruchy::profiling::FlameGraph;
             let flame_graph = FlameGraph::from_samples(&samples);
-            let flamegraph_data = flame_graph.to_string();
-            fs::write(&svg_path, flamegraph_data).unwrap_or_else(|e| {
+            fs::write(&svg_path, flame_graph.to_svg()).unwrap_or_else(|e| {
                 eprintln!("Error writing flame graph: {}", e);
                 exit(1);
             });
@@ -1129,7 +1128,7 @@ fn analyze_elf_symbols(elf: &goblin::elf::Elf, json: &mut String) {
         .collect();
 
     // Sort by size descending
-    symbols_vec.sort_by(|a, b| b.st_size.cmp(&a.st_size));
+    symbols_vec.sort_by_key(|b| std::cmp::Reverse(b.st_size));
 
     for (i, sym) in symbols_vec.iter().take(20).enumerate() {
         // Top 20 symbols
@@ -397,11 +397,10 @@ fn run_stack_profiler(args: &[String]) {
     use ruchyruchy::interpreter::evaluator::Evaluator;
     let mut eval = Evaluator::new().with_profiling();
 
-    for statement in ast.nodes() {
-        if let Err(e) = eval.eval(statement) {
-            eprintln!("Evaluation error: {:?}", e);
-            exit(EXIT_ERROR);
-        }
+    // eval_program flushes program output before an error is reported
+    if let Err(e) = eval.eval_program(&ast) {
+        eprintln!("Evaluation error: {:?}", e);
+        exit(EXIT_ERROR);
     }
 
     // Get profiling data
@@ -596,11 +595,10 @@ fn run_types_profiler(args: &[String]) {
     let profiler = CompilerProfiler::new();
     let mut eval = Evaluator::new().with_type_observation(&profiler);
 
-    for statement in ast.nodes() {
-        if let Err(e) = eval.eval(statement) {
-            eprintln!("Evaluation error: {:?}", e);
-            exit(EXIT_ERROR);
-        }
+    // eval_program flushes program output before an error is reported
+    if let Err(e) = eval.eval_program(&ast) {
+        eprintln!("Evaluation error: {:?}", e);
+        exit(EXIT_ERROR);
     }
 
     // Display type stability report
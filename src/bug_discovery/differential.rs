@@ -11,6 +11,9 @@ use crate::bug_discovery::confidence::{
     RootCauseClarity,
 };
 use crate::bug_discovery::statistics::{cohens_d, mean, welchs_t_test, PerformanceRegression};
+use crate::conformance::TestCase;
+use crate::debugger::differential::{compare_results, run_interpreter_with, run_jit};
+use crate::interpreter::{Evaluator, OutputSink};
 use std::time::{Duration, Instant};
 
 /// Test status for differential testing
@@ -114,6 +117,26 @@ pub enum FailureMode {
     },
 }
 
+/// Outcome of checking a conformance corpus for interpreter/JIT divergence
+#[derive(Debug, Clone, Default)]
+pub struct CorpusReport {
+    /// Tests run through both engines
+    pub checked: usize,
+    /// Tests the JIT cannot compile (or without a parameterless `main`)
+    pub skipped: usize,
+    /// Tests where the engines disagreed
+    pub divergences: Vec<CorpusDivergence>,
+}
+
+/// A conformance test whose interpreter and JIT results differ
+#[derive(Debug, Clone)]
+pub struct CorpusDivergence {
+    /// Conformance test name
+    pub test_name: String,
+    /// Description of the mismatch
+    pub message: String,
+}
+
 /// Differential tester
 pub struct DifferentialTester {
     /// Compiler versions to test
@@ -289,6 +312,38 @@ impl DifferentialTester {
         bugs
     }
 
+    /// Check every conformance test for interpreter/JIT divergence
+    ///
+    /// Each test's `main` is run through both engines and the results are
+    /// compared. Tests the JIT cannot compile are counted as skipped.
+    pub fn check_conformance_corpus(&self, test_cases: &[TestCase]) -> CorpusReport {
+        let mut report = CorpusReport::default();
+
+        for test_case in test_cases {
+            let source = &test_case.source_code;
+            let Ok(jit_result) = run_jit(source, "main", &[]) else {
+                report.skipped += 1;
+                continue;
+            };
+
+            // Capture output so conformance programs don't print to stdout
+            let evaluator = Evaluator::new().with_output(OutputSink::buffer());
+            let interp_result = run_interpreter_with(evaluator, source, "main", &[]).ok();
+
+            report.checked += 1;
+            if let Err(message) =
+                compare_results(source, "main", &[], interp_result, Some(jit_result))
+            {
+                report.divergences.push(CorpusDivergence {
+                    test_name: test_case.name.clone(),
+                    message,
+                });
+            }
+        }
+
+        report
+    }
+
     /// Run test case across all versions
     fn run_across_versions(&self, test_case: &str) -> Vec<(CompilerVersion, Vec<TestResult>)> {
         self.versions
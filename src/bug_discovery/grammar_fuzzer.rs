@@ -5,12 +5,15 @@
 // - Holler et al. (2012): "Fuzzing with Code Fragments"
 // - Zalewski (2014): "american fuzzy lop" (AFL) mutation strategies
 // - Section 4.2 of BUG_DISCOVERY_REPORTER_REPLICATOR_SPEC.md
+//
+// Coverage-guided mode (`fuzz_guided`) keeps inputs that reach new coverage
+// and mutates their derivation trees, AFL-style.
 
 use crate::bug_discovery::confidence::{
     ConfidenceScore, ConfidenceScorer, DiscoveryMethod, EvidenceLevel, Reproducibility,
     RootCauseClarity,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Grammar rule for generating syntax elements
 #[derive(Debug, Clone, PartialEq)]
@@ -194,6 +197,85 @@ impl Grammar {
     }
 }
 
+/// Derivation tree of a generated input
+///
+/// Guided fuzzing keeps the tree of each corpus input so it can mutate whole
+/// grammar fragments (regenerate or splice a rule's expansion) and stay
+/// syntactically valid, instead of flipping characters.
+#[derive(Debug, Clone)]
+enum Derivation {
+    /// Literal text from a terminal
+    Text(String),
+    /// Expansion of a named rule
+    Rule {
+        /// Rule name
+        name: String,
+        /// Recursion depth the rule was expanded at
+        depth: usize,
+        /// Expansion, in order
+        children: Vec<Derivation>,
+    },
+}
+
+impl Derivation {
+    /// The generated input
+    fn text(&self) -> String {
+        let mut text = String::new();
+        self.write(&mut text);
+        text
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Derivation::Text(text) => out.push_str(text),
+            Derivation::Rule { children, .. } => {
+                for child in children {
+                    child.write(out);
+                }
+            }
+        }
+    }
+
+    /// Every rule expansion in the tree, in pre-order
+    fn rules(&self) -> Vec<&Derivation> {
+        let mut rules = Vec::new();
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            if let Derivation::Rule { children, .. } = node {
+                rules.push(node);
+                stack.extend(children.iter().rev());
+            }
+        }
+        rules
+    }
+
+    /// Replace the `index`-th rule expansion (pre-order) with `replacement`
+    fn replace_rule(&mut self, index: &mut usize, replacement: &Derivation) -> bool {
+        let Derivation::Rule { children, .. } = self else {
+            return false;
+        };
+        if *index == 0 {
+            *self = replacement.clone();
+            return true;
+        }
+        *index -= 1;
+        children
+            .iter_mut()
+            .any(|child| child.replace_rule(index, replacement))
+    }
+}
+
+/// Summary of a `fuzz_guided` run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuidedFuzzReport {
+    /// Inputs generated and passed to the coverage function
+    pub executions: usize,
+    /// Inputs added to the corpus because they reached new coverage
+    pub new_inputs: usize,
+    /// Distinct coverage points reached so far (across all runs)
+    pub coverage: usize,
+}
+
 /// Grammar-based fuzzer
 pub struct GrammarFuzzer {
     /// Grammar definition
@@ -203,6 +285,10 @@ pub struct GrammarFuzzer {
     seed: u64,
     /// Current random state
     state: u64,
+    /// Coverage-increasing inputs kept by guided fuzzing, in discovery order
+    corpus: Vec<Derivation>,
+    /// Coverage points reached by guided fuzzing so far
+    coverage: HashSet<u64>,
 }
 
 impl GrammarFuzzer {
@@ -212,67 +298,157 @@ impl GrammarFuzzer {
             grammar,
             seed,
             state: seed,
+            corpus: Vec::new(),
+            coverage: HashSet::new(),
         }
     }
 
     /// Generate a test case from the grammar
     pub fn generate(&mut self) -> String {
-        self.generate_from_rule(&self.grammar.start_symbol.clone(), 0)
+        self.generate_derivation().text()
     }
 
-    /// Generate from a specific rule with depth tracking
-    fn generate_from_rule(&mut self, rule_name: &str, depth: usize) -> String {
+    /// Generate a test case's derivation tree from the start symbol
+    fn generate_derivation(&mut self) -> Derivation {
+        let start = self.grammar.start_symbol.clone();
+        self.expand_rule(&start, 0)
+    }
+
+    /// Expand a named rule with depth tracking
+    fn expand_rule(&mut self, rule_name: &str, depth: usize) -> Derivation {
+        let mut children = Vec::new();
         // Prevent infinite recursion
-        if depth > self.grammar.max_depth {
-            return String::new();
+        if depth <= self.grammar.max_depth {
+            let rule = match self.grammar.rules.get(rule_name) {
+                Some(r) => r.clone(),
+                None => return Derivation::Text(format!("<UNDEFINED:{}>", rule_name)),
+            };
+            self.expand(&rule, depth, &mut children);
+        }
+        Derivation::Rule {
+            name: rule_name.to_string(),
+            depth,
+            children,
         }
-
-        let rule = match self.grammar.rules.get(rule_name) {
-            Some(r) => r.clone(),
-            None => return format!("<UNDEFINED:{}>", rule_name),
-        };
-
-        self.generate_from_grammar_rule(&rule, depth)
     }
 
-    /// Generate from a grammar rule
-    fn generate_from_grammar_rule(&mut self, rule: &GrammarRule, depth: usize) -> String {
+    /// Expand a grammar rule, appending its derivation to `out`
+    fn expand(&mut self, rule: &GrammarRule, depth: usize, out: &mut Vec<Derivation>) {
         match rule {
-            GrammarRule::Terminal(s) => s.clone(),
-            GrammarRule::NonTerminal(name) => self.generate_from_rule(name, depth + 1),
+            GrammarRule::Terminal(s) => out.push(Derivation::Text(s.clone())),
+            GrammarRule::NonTerminal(name) => out.push(self.expand_rule(name, depth + 1)),
             GrammarRule::Sequence(rules) => {
-                let mut result = String::new();
                 for r in rules {
-                    result.push_str(&self.generate_from_grammar_rule(r, depth));
+                    self.expand(r, depth, out);
                 }
-                result
             }
             GrammarRule::Choice(choices) => {
                 if choices.is_empty() {
-                    return String::new();
+                    return;
                 }
                 let idx = self.random_usize(choices.len());
-                self.generate_from_grammar_rule(&choices[idx], depth)
+                self.expand(&choices[idx], depth, out);
             }
             GrammarRule::Optional(rule) => {
                 if self.random_bool() {
-                    self.generate_from_grammar_rule(rule, depth)
-                } else {
-                    String::new()
+                    self.expand(rule, depth, out);
                 }
             }
             GrammarRule::Repeat(rule) => {
-                let mut result = String::new();
                 // Generate 0-3 repetitions (biased toward smaller)
                 let count = self.random_usize(4);
                 for _ in 0..count {
-                    result.push_str(&self.generate_from_grammar_rule(rule, depth));
+                    self.expand(rule, depth, out);
                 }
-                result
             }
         }
     }
 
+    /// Coverage-guided fuzzing (AFL-style feedback loop)
+    ///
+    /// Each iteration either generates a fresh input or mutates an input from
+    /// the corpus, then calls `coverage_fn` with it to get the coverage
+    /// points it reached (e.g. parser/evaluator branch ids). An input that
+    /// reaches a point no earlier input reached is added to the corpus, so
+    /// later iterations build on inputs that got further into the code.
+    ///
+    /// Mutations work on derivation trees: one rule expansion is either
+    /// regenerated from the grammar or replaced by an expansion of the same
+    /// rule from another corpus input (Holler et al., 2012), so mutants stay
+    /// grammatical. The corpus and coverage carry over to later calls.
+    pub fn fuzz_guided<F, C>(&mut self, iterations: usize, mut coverage_fn: F) -> GuidedFuzzReport
+    where
+        F: FnMut(&str) -> C,
+        C: IntoIterator<Item = u64>,
+    {
+        let mut new_inputs = 0;
+        for _ in 0..iterations {
+            // Keep exploring with fresh inputs a quarter of the time
+            let derivation = if self.corpus.is_empty() || self.random_usize(4) == 0 {
+                self.generate_derivation()
+            } else {
+                self.mutate()
+            };
+
+            let text = derivation.text();
+            let before = self.coverage.len();
+            self.coverage.extend(coverage_fn(&text));
+            if self.coverage.len() > before {
+                self.corpus.push(derivation);
+                new_inputs += 1;
+            }
+        }
+
+        GuidedFuzzReport {
+            executions: iterations,
+            new_inputs,
+            coverage: self.coverage.len(),
+        }
+    }
+
+    /// Inputs kept by guided fuzzing, in the order they were found
+    pub fn guided_corpus(&self) -> Vec<String> {
+        self.corpus.iter().map(Derivation::text).collect()
+    }
+
+    /// Mutate a random corpus input by replacing one rule expansion
+    fn mutate(&mut self) -> Derivation {
+        let parent = self.random_usize(self.corpus.len());
+        let mut derivation = self.corpus[parent].clone();
+        let rules = derivation.rules();
+        let index = self.random_usize(rules.len());
+        let Derivation::Rule { name, depth, .. } = rules[index] else {
+            return derivation;
+        };
+        let (name, depth) = (name.clone(), *depth);
+
+        // Splice an expansion of the same rule from the corpus half the time.
+        // Only fragments expanded at least as deep fit without exceeding
+        // the grammar's max_depth.
+        let fragments: Vec<Derivation> = if self.random_bool() {
+            self.corpus
+                .iter()
+                .flat_map(Derivation::rules)
+                .filter(|rule| {
+                    matches!(rule, Derivation::Rule { name: other, depth: at, .. }
+                        if *other == name && *at >= depth)
+                })
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let replacement = if fragments.is_empty() {
+            self.expand_rule(&name, depth)
+        } else {
+            fragments[self.random_usize(fragments.len())].clone()
+        };
+
+        let mut remaining = index;
+        derivation.replace_rule(&mut remaining, &replacement);
+        derivation
+    }
+
     /// Simple LCG random number generator for reproducibility
     fn next_random(&mut self) -> u64 {
         // Linear Congruential Generator (LCG)
@@ -281,9 +457,17 @@ impl GrammarFuzzer {
         self.state
     }
 
+    /// Random bits from the high half of the state
+    ///
+    /// The low bits of an LCG cycle with tiny periods (bit 0 just
+    /// alternates), which would make choices repeat in lockstep.
+    fn random_bits(&mut self) -> u64 {
+        self.next_random() >> 32
+    }
+
     /// Generate random boolean
     fn random_bool(&mut self) -> bool {
-        self.next_random().is_multiple_of(2)
+        self.random_bits().is_multiple_of(2)
     }
 
     /// Generate random usize in range [0, max)
@@ -291,7 +475,7 @@ impl GrammarFuzzer {
         if max == 0 {
             return 0;
         }
-        (self.next_random() as usize) % max
+        (self.random_bits() as usize) % max
     }
 
     /// Generate multiple test cases
@@ -576,6 +760,95 @@ mod tests {
         assert_eq!(batch.len(), 5);
     }
 
+    /// number ::= digit digit digit digit digit digit
+    fn digits_grammar() -> Grammar {
+        let mut grammar = Grammar::new("number".to_string());
+        grammar.add_rule(
+            "number".to_string(),
+            GrammarRule::Sequence(vec![GrammarRule::NonTerminal("digit".to_string()); 6]),
+        );
+        grammar.add_rule(
+            "digit".to_string(),
+            GrammarRule::Choice(
+                (0..10)
+                    .map(|d| GrammarRule::Terminal(d.to_string()))
+                    .collect(),
+            ),
+        );
+        grammar
+    }
+
+    /// Coverage of an input: one point per leading digit matching "314159",
+    /// like a parser branch guarded by a chain of magic-byte comparisons
+    fn magic_prefix_coverage(input: &str) -> Vec<u64> {
+        let matched = input
+            .chars()
+            .zip("314159".chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        (0..=matched as u64).collect()
+    }
+
+    #[test]
+    fn test_fuzz_guided_corpus_grows_only_on_new_coverage() {
+        // start ::= ("a" | "b" | "c")*, covering one point per distinct letter
+        let mut grammar = Grammar::new("start".to_string());
+        grammar.add_rule(
+            "start".to_string(),
+            GrammarRule::Repeat(Box::new(GrammarRule::Choice(vec![
+                GrammarRule::Terminal("a".to_string()),
+                GrammarRule::Terminal("b".to_string()),
+                GrammarRule::Terminal("c".to_string()),
+            ]))),
+        );
+        let mut fuzzer = GrammarFuzzer::new(grammar, 7);
+
+        let mut seen = HashSet::new();
+        let mut novel = Vec::new();
+        let report = fuzzer.fuzz_guided(200, |input| {
+            let points: Vec<u64> = input.bytes().map(u64::from).collect();
+            if points.iter().any(|point| !seen.contains(point)) {
+                novel.push(input.to_string());
+            }
+            seen.extend(points.iter().copied());
+            points
+        });
+
+        assert_eq!(report.executions, 200);
+        assert_eq!(report.coverage, 3);
+        assert_eq!(report.new_inputs, novel.len());
+        assert_eq!(fuzzer.guided_corpus(), novel);
+        assert!(novel.len() <= 3);
+
+        // Nothing new to reach: the corpus stays as it is
+        let report =
+            fuzzer.fuzz_guided(50, |input| input.bytes().map(u64::from).collect::<Vec<_>>());
+        assert_eq!(report.new_inputs, 0);
+        assert_eq!(fuzzer.guided_corpus(), novel);
+    }
+
+    #[test]
+    fn test_fuzz_guided_reaches_deeper_than_random() {
+        let mut guided = GrammarFuzzer::new(digits_grammar(), 42);
+        let report = guided.fuzz_guided(3000, magic_prefix_coverage);
+
+        let mut random = GrammarFuzzer::new(digits_grammar(), 42);
+        let random_coverage: HashSet<u64> = random
+            .generate_batch(3000)
+            .iter()
+            .flat_map(|input| magic_prefix_coverage(input))
+            .collect();
+
+        // Guided fuzzing climbs the whole prefix one digit at a time
+        assert_eq!(report.coverage, 7);
+        assert!(random_coverage.len() < 7);
+        // Mutants are whole grammar fragments, so every input stays valid
+        for input in guided.guided_corpus() {
+            assert_eq!(input.len(), 6);
+            assert!(input.chars().all(|c| c.is_ascii_digit()));
+        }
+    }
+
     #[test]
     fn test_fuzz_bug_confidence_crash() {
         let bug = FuzzBug::new(
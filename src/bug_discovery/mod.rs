@@ -26,14 +26,16 @@ pub mod statistics;
 
 pub use code_churn::{ChurnAnalyzer, ChurnHotspot, ChurnMetrics, FileChange, RiskLevel};
 pub use confidence::{ConfidenceScore, ConfidenceScorer};
-pub use differential::DifferentialTester;
+pub use differential::{CorpusDivergence, CorpusReport, DifferentialTester};
 pub use grammar_fuzzer::{
-    FuzzBug, FuzzCorpus, FuzzResult, Grammar, GrammarFuzzer, GrammarRule, TestMinimizer,
+    FuzzBug, FuzzCorpus, FuzzResult, Grammar, GrammarFuzzer, GrammarRule, GuidedFuzzReport,
+    TestMinimizer,
 };
 pub use property_testing::{
     AstGenerator, Generator, Property, PropertyBug, PropertyChecker, PropertyResult,
 };
 pub use schema_fuzzer::{
-    RuntimeSchema, RuntimeTestCase, SchemaFuzzer, SchemaFuzzerConfig, ShadowState, TimeoutDetection,
+    RuntimeSchema, RuntimeTestCase, SchemaFuzzer, SchemaFuzzerConfig, SeedReport, ShadowState,
+    TimeoutDetection,
 };
 pub use statistics::{cohens_d, welchs_t_test, PerformanceRegression};
@@ -37,10 +37,24 @@
 // let logger = Logger::create();  // Timeout check: <100ms
 // logger.test();                   // Timeout check: <1000ms (FAILS on Issue #79!)
 // ```
-
+//
+// Seeding: `seed_from_dir` turns existing `.ruchy` programs into schemas. Each
+// `impl` block becomes a schema whose constructor is its first method without
+// `self` and whose operations are its `self` methods; arguments are the
+// literals observed at call sites in the program. The program (minus `main`)
+// is kept by the fuzzer as the type's prelude, `generate_seeded_tests` mutates
+// the observed arguments as starting points, and `test_code` renders a test
+// with its prelude.
+
+use crate::interpreter::ast::{AstNode, UnaryOperator};
+use crate::interpreter::parser::Parser;
+use crate::interpreter::visitor::{walk_node, Visitor};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 /// Runtime schema for an object type
@@ -218,6 +232,11 @@ pub struct OperationCall {
 impl RuntimeTestCase {
     /// Generate Ruchy code for this test case
     pub fn to_ruchy_code(&self) -> String {
+        self.to_ruchy_code_with("")
+    }
+
+    /// Generate Ruchy code with `definitions` emitted before `main`
+    fn to_ruchy_code_with(&self, definitions: &str) -> String {
         let mut code = String::new();
 
         // Add enum/struct definitions if needed
@@ -240,6 +259,9 @@ impl RuntimeTestCase {
             code.push_str("        println!(\"Value: {}\", val);\n");
             code.push_str("    }\n");
             code.push_str("}\n\n");
+        } else if !definitions.is_empty() {
+            code.push_str(definitions);
+            code.push_str("\n\n");
         }
 
         code.push_str("fun main() {\n");
@@ -284,6 +306,22 @@ pub struct SchemaFuzzer {
 
     /// Test case counter
     test_counter: usize,
+
+    /// Schemas extracted by `seed_from_dir`
+    seeds: Vec<RuntimeSchema>,
+
+    /// Source defining each seeded type (program minus `main`), by type name
+    preludes: HashMap<String, String>,
+}
+
+/// Result of seeding a fuzzer from a directory of programs
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeedReport {
+    /// Files that contributed at least one schema
+    pub seeded: Vec<PathBuf>,
+
+    /// Files that could not be read or parsed
+    pub skipped: Vec<PathBuf>,
 }
 
 /// Configuration for schema fuzzer
@@ -315,7 +353,95 @@ impl SchemaFuzzer {
         SchemaFuzzer {
             config,
             test_counter: 0,
+            seeds: Vec::new(),
+            preludes: HashMap::new(),
+        }
+    }
+
+    /// Extract schemas from the `.ruchy` programs in `dir`
+    ///
+    /// Files are visited in path order. Unreadable or unparsable files are
+    /// skipped with a warning; files without an `impl` block add no schema.
+    pub fn seed_from_dir(&mut self, dir: &Path) -> io::Result<SeedReport> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "ruchy") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut report = SeedReport::default();
+        for path in paths {
+            let source = match fs::read_to_string(&path) {
+                Ok(source) => source,
+                Err(e) => {
+                    eprintln!("Warning: skipping {}: {}", path.display(), e);
+                    report.skipped.push(path);
+                    continue;
+                }
+            };
+            match schemas_from_source(&source) {
+                Ok(schemas) if schemas.is_empty() => {}
+                Ok(schemas) => {
+                    let prelude = without_main(&source);
+                    for schema in &schemas {
+                        self.preludes
+                            .entry(schema.type_name.clone())
+                            .or_insert_with(|| prelude.clone());
+                    }
+                    self.seeds.extend(schemas);
+                    report.seeded.push(path);
+                }
+                Err(e) => {
+                    eprintln!("Warning: skipping {}: {}", path.display(), e);
+                    report.skipped.push(path);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Schemas extracted by `seed_from_dir` so far
+    pub fn seeded_schemas(&self) -> &[RuntimeSchema] {
+        &self.seeds
+    }
+
+    /// Ruchy code for `test`, preceded by the source of its seeded type
+    ///
+    /// Same as `RuntimeTestCase::to_ruchy_code` for types that were not
+    /// seeded. If several files define the type, the first file's source is
+    /// used.
+    pub fn test_code(&self, test: &RuntimeTestCase) -> String {
+        let prelude = self
+            .preludes
+            .get(&test.schema.type_name)
+            .map_or("", String::as_str);
+        test.to_ruchy_code_with(prelude)
+    }
+
+    /// Generate test cases from every seeded schema
+    ///
+    /// The first case of each schema uses the observed arguments; later cases
+    /// replace them with boundary values of the same type.
+    pub fn generate_seeded_tests(&mut self) -> Vec<RuntimeTestCase> {
+        let mut tests = Vec::new();
+        for schema in self.seeds.clone() {
+            for (round, mut test) in self.generate_tests(&schema).into_iter().enumerate() {
+                let constructor = &mut test.schema.constructor;
+                for param in &mut constructor.parameters {
+                    *param = mutate_literal(param, round);
+                }
+                for call in &mut test.operations {
+                    for param in &mut call.parameters {
+                        *param = mutate_literal(param, round);
+                    }
+                }
+                tests.push(test);
+            }
         }
+        tests
     }
 
     /// Generate test cases from schema
@@ -387,7 +513,7 @@ impl SchemaFuzzer {
         test: &RuntimeTestCase,
         executor: impl Fn(&str) -> Result<(), String>,
     ) -> Option<TimeoutDetection> {
-        let code = test.to_ruchy_code();
+        let code = self.test_code(test);
 
         let start = Instant::now();
         match executor(&code) {
@@ -436,6 +562,189 @@ impl SchemaFuzzer {
     }
 }
 
+/// Schemas for the `impl` blocks of a program
+fn schemas_from_source(source: &str) -> Result<Vec<RuntimeSchema>, String> {
+    let ast = Parser::new(source).parse().map_err(|e| e.to_string())?;
+    let mut calls = CallSites::default();
+    calls.visit_ast(&ast);
+
+    let mut schemas = Vec::new();
+    for node in &ast.nodes {
+        let AstNode::ImplBlock { type_name, methods } = node.without_span() else {
+            continue;
+        };
+        let mut constructor = None;
+        let mut operations = Vec::new();
+        for method in methods {
+            let AstNode::FunctionDef { name, params, .. } = method.without_span() else {
+                continue;
+            };
+            if params.first().is_some_and(|param| param == "self") {
+                operations.push(Operation {
+                    name: name.clone(),
+                    preconditions: vec![],
+                    parameters: calls.arguments(&format!(".{}", name), params.len() - 1),
+                    timeout_ms: default_operation_timeout(),
+                    returns: "unknown".to_string(),
+                });
+            } else if constructor.is_none() {
+                constructor = Some(Constructor {
+                    name: name.clone(),
+                    parameters: calls.arguments(&format!("{}::{}", type_name, name), params.len()),
+                    timeout_ms: default_constructor_timeout(),
+                    returns: type_name.clone(),
+                });
+            }
+        }
+        if let Some(constructor) = constructor {
+            schemas.push(RuntimeSchema {
+                type_name: type_name.clone(),
+                constructor,
+                operations,
+                max_sequence_length: default_max_sequence_length(),
+            });
+        }
+    }
+    Ok(schemas)
+}
+
+/// Literal arguments of the first call of each function or method
+///
+/// Keys are `Type::function` for calls and `.method` for method calls.
+#[derive(Default)]
+struct CallSites {
+    arguments: HashMap<String, Vec<Option<String>>>,
+}
+
+impl CallSites {
+    /// Observed arguments for `key`, with `0` where none was a literal
+    fn arguments(&self, key: &str, arity: usize) -> Vec<String> {
+        let observed = self.arguments.get(key);
+        (0..arity)
+            .map(|i| {
+                observed
+                    .and_then(|args| args.get(i).cloned().flatten())
+                    .unwrap_or_else(|| "0".to_string())
+            })
+            .collect()
+    }
+
+    fn record(&mut self, key: String, args: &[AstNode]) {
+        self.arguments
+            .entry(key)
+            .or_insert_with(|| args.iter().map(literal).collect());
+    }
+}
+
+impl Visitor for CallSites {
+    fn visit_function_call(&mut self, name: &str, args: &[AstNode]) {
+        self.record(name.to_string(), args);
+        self.visit_statements(args);
+    }
+
+    fn visit_method_call(&mut self, receiver: &AstNode, method: &str, args: &[AstNode]) {
+        self.record(format!(".{}", method), args);
+        walk_node(self, receiver);
+        self.visit_statements(args);
+    }
+}
+
+/// Source text of a literal argument (including a negated number)
+fn literal(node: &AstNode) -> Option<String> {
+    match node.without_span() {
+        AstNode::IntegerLiteral(_)
+        | AstNode::FloatLiteral(_)
+        | AstNode::StringLiteral(_)
+        | AstNode::CharLiteral(_)
+        | AstNode::BooleanLiteral(_) => Some(node.emit()),
+        AstNode::UnaryOp {
+            op: UnaryOperator::Negate,
+            operand,
+        } if matches!(
+            operand.without_span(),
+            AstNode::IntegerLiteral(_) | AstNode::FloatLiteral(_)
+        ) =>
+        {
+            Some(node.emit())
+        }
+        _ => None,
+    }
+}
+
+/// Boundary value of the same type as `value` for mutation `round`
+///
+/// Round 0 keeps the value. Integers cycle through 0, -1, n+1, -n and
+/// i64::MAX; strings through empty and doubled; booleans flip.
+fn mutate_literal(value: &str, round: usize) -> String {
+    if round == 0 {
+        return value.to_string();
+    }
+    if let Ok(n) = value.parse::<i64>() {
+        let candidates = [0, -1, n.wrapping_add(1), n.wrapping_neg(), i64::MAX];
+        return candidates[(round - 1) % candidates.len()].to_string();
+    }
+    if let Some(inner) = value
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        return match round % 2 {
+            1 => "\"\"".to_string(),
+            _ => format!("\"{}{}\"", inner, inner),
+        };
+    }
+    match value {
+        "true" if round % 2 == 1 => "false".to_string(),
+        "false" if round % 2 == 1 => "true".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// `source` with its top-level `fun main() { ... }` removed
+fn without_main(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut depth = 0usize;
+    let mut main_start = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.saturating_sub(1);
+                if let (0, Some(start)) = (depth, main_start) {
+                    let rest = &source[i + 1..];
+                    return format!("{}{}", source[..start].trim_end(), rest.trim_end())
+                        .trim()
+                        .to_string();
+                }
+            }
+            b'f' if depth == 0
+                && main_start.is_none()
+                && source[i..].starts_with("fun main(")
+                && (i == 0 || bytes[i - 1].is_ascii_whitespace()) =>
+            {
+                main_start = Some(i);
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    source.trim().to_string()
+}
+
 /// Timeout detection result
 #[derive(Debug, Clone, PartialEq)]
 pub struct TimeoutDetection {
@@ -678,4 +987,90 @@ mod tests {
         assert!(code.contains("self.level as i32"));
         assert!(code.contains("// RUNTIME CHECK: Must not hang!"));
     }
+
+    #[test]
+    fn test_seed_from_dir() {
+        let dir = std::env::temp_dir().join(format!("schema-seed-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("counter.ruchy"),
+            "struct Counter { count: i64 }\n\
+             impl Counter {\n\
+                 fun new(start) { Counter { count: start } }\n\
+                 fun add(self, n) { self.count + n }\n\
+                 fun get(self) { self.count }\n\
+             }\n\
+             fun main() {\n\
+                 let c = Counter::new(5);\n\
+                 println(c.add(2));\n\
+                 println(c.get());\n\
+             }\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("greeter.ruchy"),
+            "struct Greeter { name: String }\n\
+             impl Greeter {\n\
+                 fun named(name) { Greeter { name: name } }\n\
+                 fun greet(self, greeting) { println(greeting + \", \" + self.name) }\n\
+             }\n\
+             fun main() {\n\
+                 let g = Greeter::named(\"Ada\");\n\
+                 g.greet(\"Hello\");\n\
+             }\n",
+        )
+        .unwrap();
+        fs::write(dir.join("broken.ruchy"), "let x = ;").unwrap();
+        fs::write(dir.join("notes.txt"), "not a program").unwrap();
+
+        let mut fuzzer = SchemaFuzzer::new(SchemaFuzzerConfig {
+            num_test_cases: 3,
+            max_operations: 2,
+            seed: 42,
+        });
+        let report = fuzzer.seed_from_dir(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            report.seeded,
+            vec![dir.join("counter.ruchy"), dir.join("greeter.ruchy")]
+        );
+        assert_eq!(report.skipped, vec![dir.join("broken.ruchy")]);
+
+        let schemas = fuzzer.seeded_schemas();
+        assert_eq!(schemas.len(), 2);
+        assert_eq!(schemas[0].type_name, "Counter");
+        assert_eq!(schemas[0].constructor.name, "new");
+        assert_eq!(schemas[0].constructor.parameters, vec!["5"]);
+        let operations: Vec<(&str, &[String])> = schemas[0]
+            .operations
+            .iter()
+            .map(|op| (op.name.as_str(), op.parameters.as_slice()))
+            .collect();
+        assert_eq!(
+            operations,
+            vec![("add", &["2".to_string()][..]), ("get", &[][..])]
+        );
+        assert_eq!(schemas[1].type_name, "Greeter");
+        assert_eq!(schemas[1].constructor.name, "named");
+        assert_eq!(schemas[1].constructor.parameters, vec!["\"Ada\""]);
+        assert_eq!(schemas[1].operations[0].parameters, vec!["\"Hello\""]);
+
+        let tests = fuzzer.generate_seeded_tests();
+        assert_eq!(tests.len(), 6);
+        let counter: Vec<String> = tests[..3].iter().map(|t| fuzzer.test_code(t)).collect();
+        assert!(counter[0].contains("impl Counter {"));
+        assert!(!counter[0].contains("let c = Counter::new(5)"));
+        assert!(counter[0].contains("let obj = Counter::new(5);"));
+        assert!(counter[1].contains("let obj = Counter::new(0);"));
+        assert!(counter[2].contains("let obj = Counter::new(-1);"));
+        let greeter = fuzzer.test_code(&tests[3]);
+        assert!(greeter.contains("let obj = Greeter::named(\"Ada\");"));
+        assert!(greeter.contains("obj.greet(\"Hello\");"));
+        assert!(fuzzer.test_code(&tests[4]).contains("Greeter::named(\"\")"));
+        assert!(!tests[0].to_ruchy_code().contains("impl Counter {"));
+        for test in &tests {
+            assert!(Parser::new(&fuzzer.test_code(test)).parse().is_ok());
+        }
+    }
 }
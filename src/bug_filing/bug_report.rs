@@ -3,12 +3,13 @@
 //! Provides bug report representation and GitHub issue markdown generation
 //! for filing bugs to the upstream Ruchy compiler repository.
 
+use serde::{Deserialize, Serialize};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
 /// Bug category classification
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Category {
     /// Parser bugs (syntax, tokenization, AST)
     Parser,
@@ -23,7 +24,7 @@ pub enum Category {
 }
 
 /// Severity level
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Severity {
     /// Critical bugs (crashes, data loss)
     Critical,
@@ -38,7 +39,7 @@ pub enum Severity {
 /// Bug report structure
 ///
 /// Represents a bug report for filing to upstream Ruchy compiler.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BugReport {
     /// Bug category
     pub category: Category,
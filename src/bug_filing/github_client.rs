@@ -1,8 +1,78 @@
 //! GitHub Bug Filer Module
 //!
 //! Provides GitHub API integration for filing bugs to upstream Ruchy repository.
+//!
+//! Before filing, the repository's open issues are fetched and compared with
+//! the new report (title and body, Jaccard similarity from
+//! `bug_reporting::issue_linking`). A report at or above `DUPLICATE_THRESHOLD`
+//! is not filed again; the matching issue's number is returned instead.
+//!
+//! When a queue directory is configured, a report that cannot be filed
+//! because of a transient failure (network error, HTTP 5xx, rate limit) is
+//! saved there as JSON and `FilingError::Queued` is returned.
+//! `flush_queue` retries queued reports with exponential backoff; a
+//! rate-limited report waits until the limit's reset time.
 
 use super::bug_report::BugReport;
+use crate::bug_reporting::issue_linking::{BugIssue, SimilarityCalculator, DUPLICATE_THRESHOLD};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Seconds to wait after a rate limit response without a reset time
+const DEFAULT_RATE_LIMIT_WAIT_SECS: u64 = 60;
+
+/// GitHub API request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiRequest {
+    /// HTTP method ("GET" or "POST")
+    pub method: String,
+    /// API path (e.g. `repos/paiml/ruchy/issues?state=open`)
+    pub path: String,
+    /// JSON request body
+    pub body: Option<String>,
+}
+
+/// GitHub API response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: Vec<(String, String)>,
+    /// Response body
+    pub body: String,
+}
+
+impl ApiResponse {
+    /// Create a response with no headers
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// Value of a header (case-insensitive name)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// GitHub API transport: performs a request, or returns `Err` when the
+/// request could not be sent (e.g. the network is down)
+pub type ApiTransport = Box<dyn Fn(&ApiRequest) -> Result<ApiResponse, String>>;
 
 /// GitHub bug filer
 ///
@@ -14,6 +84,15 @@ pub struct GitHubBugFiler {
     pub dry_run: bool,
     /// Fingerprints of already-filed bugs (for deduplication)
     filed_fingerprints: Vec<String>,
+    /// Transport for API requests (filing is simulated when `None`)
+    api: Option<ApiTransport>,
+    /// Directory for reports awaiting a retry (transient errors are returned
+    /// when `None`)
+    queue_dir: Option<PathBuf>,
+    /// Attempts per queued report in one `flush_queue`
+    max_attempts: u32,
+    /// Delay before the first retry, doubled after each failure
+    base_delay: Duration,
 }
 
 impl GitHubBugFiler {
@@ -35,6 +114,10 @@ impl GitHubBugFiler {
             repo: repo.to_string(),
             dry_run: false,
             filed_fingerprints: Vec::new(),
+            api: None,
+            queue_dir: None,
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
         }
     }
 
@@ -60,11 +143,52 @@ impl GitHubBugFiler {
         self
     }
 
+    /// Send API requests through `transport`
+    ///
+    /// Open issues are queried before every filing, also in dry-run mode
+    /// since the query is read-only. Issues are only created outside dry-run
+    /// mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ruchyruchy::bug_filing::{ApiResponse, GitHubBugFiler};
+    ///
+    /// let filer = GitHubBugFiler::new("paiml/ruchy")
+    ///     .with_dry_run(true)
+    ///     .with_api(|_request| Ok(ApiResponse::new(200, "[]")));
+    /// ```
+    pub fn with_api(
+        mut self,
+        transport: impl Fn(&ApiRequest) -> Result<ApiResponse, String> + 'static,
+    ) -> Self {
+        self.api = Some(Box::new(transport));
+        self
+    }
+
+    /// Queue reports that fail transiently in `dir` (created on demand)
+    pub fn with_queue_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.queue_dir = Some(dir.into());
+        self
+    }
+
+    /// Set the retry policy of `flush_queue`
+    ///
+    /// Each queued report is tried up to `max_attempts` times per flush,
+    /// waiting `base_delay`, then twice as long, and so on between attempts.
+    pub fn with_retry(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.base_delay = base_delay;
+        self
+    }
+
     /// File a bug report
     ///
     /// Files a bug report to GitHub if:
     /// - Confidence ≥ 0.9
     /// - Not a duplicate (based on fingerprint)
+    /// - No open issue is a near-duplicate (when an API is configured);
+    ///   otherwise the existing issue's number is returned without filing
     ///
     /// # Arguments
     ///
@@ -78,8 +202,9 @@ impl GitHubBugFiler {
     /// # Errors
     ///
     /// - `LowConfidence` - Confidence < 0.9
-    /// - `Duplicate` - Bug already filed
-    /// - `ApiError` - GitHub API error
+    /// - `Duplicate` - Bug already filed (or queued)
+    /// - `Queued` - Transient failure; the report was saved for `flush_queue`
+    /// - `ApiError` - GitHub API error, including a failed open-issue query
     pub fn file_bug(&mut self, bug: &BugReport) -> Result<IssueNumber, FilingError> {
         // Check confidence threshold
         if bug.confidence < 0.9 {
@@ -92,16 +217,104 @@ impl GitHubBugFiler {
             return Err(FilingError::Duplicate(fingerprint));
         }
 
-        if self.dry_run {
-            // Dry run: just validate and return mock issue number
-            self.filed_fingerprints.push(fingerprint);
-            return Ok(IssueNumber(0));
+        match self.submit(bug) {
+            Ok(Submitted::Existing(issue)) => Ok(issue),
+            Ok(Submitted::Filed(issue)) => {
+                self.filed_fingerprints.push(fingerprint);
+                Ok(issue)
+            }
+            Err(failure) => match (&self.queue_dir, failure.retry_after()) {
+                (Some(dir), Some(retry_after)) if !self.dry_run => {
+                    let path = dir.join(format!("{}.json", fingerprint));
+                    let queued = QueuedReport {
+                        report: bug.clone(),
+                        attempts: 1,
+                        retry_after,
+                    };
+                    queued.save(&path)?;
+                    self.filed_fingerprints.push(fingerprint);
+                    Err(FilingError::Queued(path))
+                }
+                _ => Err(FilingError::ApiError(failure.message())),
+            },
         }
+    }
+
+    /// Retry every queued report that is due
+    ///
+    /// Each due report is tried up to the configured number of attempts with
+    /// exponential backoff between them. Filed reports leave the queue. A
+    /// report still failing transiently stays queued; after a rate limit it
+    /// waits until the reset time, and the remaining reports are not tried
+    /// in this flush. A report rejected for another reason stays queued and
+    /// is listed in `FlushReport::failed`.
+    ///
+    /// # Errors
+    ///
+    /// `ApiError` if the queue directory cannot be read.
+    pub fn flush_queue(&mut self) -> Result<FlushReport, FilingError> {
+        let mut report = FlushReport::default();
+        let Some(dir) = self.queue_dir.clone() else {
+            return Ok(report);
+        };
+        let mut paths: Vec<PathBuf> = match fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(report),
+            Err(e) => return Err(queue_error(&dir, e)),
+        };
+        paths.sort();
+
+        let mut rate_limited = false;
+        for path in paths {
+            let mut queued = match QueuedReport::load(&path) {
+                Ok(queued) => queued,
+                Err(e) => {
+                    report.failed.push((path, e));
+                    continue;
+                }
+            };
+            if rate_limited || queued.retry_after > now() {
+                report.pending.push(path);
+                continue;
+            }
 
-        // GREEN phase: Actual GitHub API call will go here
-        // For now, just simulate success
-        self.filed_fingerprints.push(fingerprint);
-        Ok(IssueNumber(0))
+            let mut attempt = 0;
+            let outcome = loop {
+                attempt += 1;
+                queued.attempts += 1;
+                match self.submit(&queued.report) {
+                    Err(ApiFailure::Transient(_)) if attempt < self.max_attempts => {
+                        std::thread::sleep(self.base_delay * 2u32.saturating_pow(attempt - 1));
+                    }
+                    outcome => break outcome,
+                }
+            };
+
+            match outcome {
+                Ok(Submitted::Filed(issue) | Submitted::Existing(issue)) => {
+                    fs::remove_file(&path).map_err(|e| queue_error(&path, e))?;
+                    report.filed.push(issue);
+                }
+                Err(failure) => match failure.retry_after() {
+                    Some(retry_after) => {
+                        rate_limited = matches!(failure, ApiFailure::RateLimited { .. });
+                        queued.retry_after = retry_after;
+                        queued.save(&path)?;
+                        report.pending.push(path);
+                    }
+                    None => {
+                        queued.save(&path)?;
+                        report
+                            .failed
+                            .push((path, FilingError::ApiError(failure.message())));
+                    }
+                },
+            }
+        }
+        Ok(report)
     }
 
     /// Check if bug is duplicate
@@ -116,12 +329,218 @@ impl GitHubBugFiler {
     pub fn is_duplicate(&self, bug: &BugReport) -> bool {
         self.filed_fingerprints.contains(&bug.fingerprint())
     }
+
+    /// Find the open issue most similar to `bug`, if it is a duplicate
+    ///
+    /// Only the first page (100 issues) of open issues is checked. Pull
+    /// requests, which the issues endpoint also lists, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// `ApiError` if the request fails or the response is not an issue list.
+    pub fn find_open_duplicate(&self, bug: &BugReport) -> Result<Option<IssueNumber>, FilingError> {
+        self.open_duplicate(bug)
+            .map_err(|failure| FilingError::ApiError(failure.message()))
+    }
+
+    /// Dedup against open issues, then create the issue
+    fn submit(&self, bug: &BugReport) -> Result<Submitted, ApiFailure> {
+        if let Some(existing) = self.open_duplicate(bug)? {
+            return Ok(Submitted::Existing(existing));
+        }
+        if self.dry_run || self.api.is_none() {
+            // Dry run (or no transport): just validate and return mock issue number
+            return Ok(Submitted::Filed(IssueNumber(0)));
+        }
+
+        let payload = serde_json::json!({
+            "title": bug.title,
+            "body": bug.to_github_markdown(),
+        });
+        let body = self.request(
+            "POST",
+            format!("repos/{}/issues", self.repo),
+            Some(payload.to_string()),
+        )?;
+        serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|created| created["number"].as_u64())
+            .map(|number| Submitted::Filed(IssueNumber(number)))
+            .ok_or_else(|| ApiFailure::Rejected("unexpected issue response".to_string()))
+    }
+
+    fn open_duplicate(&self, bug: &BugReport) -> Result<Option<IssueNumber>, ApiFailure> {
+        if self.api.is_none() {
+            return Ok(None);
+        }
+        let path = format!("repos/{}/issues?state=open&per_page=100", self.repo);
+        let issues = parse_issues(&self.request("GET", path, None)?)?;
+
+        let new_issue = BugIssue::new(0, bug.title.clone(), bug.to_github_markdown());
+        Ok(issues
+            .iter()
+            .map(|issue| (issue.id, text_similarity(&new_issue, issue)))
+            .filter(|(_, similarity)| *similarity >= DUPLICATE_THRESHOLD)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| IssueNumber(id)))
+    }
+
+    /// Send a request and classify a failure
+    fn request(
+        &self,
+        method: &str,
+        path: String,
+        body: Option<String>,
+    ) -> Result<String, ApiFailure> {
+        let Some(transport) = &self.api else {
+            return Err(ApiFailure::Rejected("no API transport".to_string()));
+        };
+        let request = ApiRequest {
+            method: method.to_string(),
+            path,
+            body,
+        };
+        let response = transport(&request).map_err(ApiFailure::Transient)?;
+        let status = response.status;
+        let rate_limited = status == 429
+            || (status == 403 && response.header("x-ratelimit-remaining") == Some("0"));
+        match status {
+            200..=299 => Ok(response.body),
+            _ if rate_limited => Err(ApiFailure::RateLimited {
+                reset: response
+                    .header("x-ratelimit-reset")
+                    .and_then(|reset| reset.trim().parse().ok())
+                    .unwrap_or_else(|| now() + DEFAULT_RATE_LIMIT_WAIT_SECS),
+            }),
+            500..=599 => Err(ApiFailure::Transient(format!(
+                "HTTP {}: {}",
+                status, response.body
+            ))),
+            _ => Err(ApiFailure::Rejected(format!(
+                "HTTP {}: {}",
+                status, response.body
+            ))),
+        }
+    }
+}
+
+/// Successful submission
+enum Submitted {
+    /// A new issue was created
+    Filed(IssueNumber),
+    /// An open issue already reports the bug
+    Existing(IssueNumber),
+}
+
+/// Why an API request failed
+enum ApiFailure {
+    /// Network error or server error; worth retrying
+    Transient(String),
+    /// Rate limit exhausted until `reset` (Unix epoch seconds)
+    RateLimited { reset: u64 },
+    /// Request rejected or response not understood; retrying won't help
+    Rejected(String),
+}
+
+impl ApiFailure {
+    /// Earliest retry time (Unix epoch seconds), or `None` if not retryable
+    fn retry_after(&self) -> Option<u64> {
+        match self {
+            ApiFailure::Transient(_) => Some(now()),
+            ApiFailure::RateLimited { reset } => Some(*reset),
+            ApiFailure::Rejected(_) => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            ApiFailure::Transient(message) | ApiFailure::Rejected(message) => message.clone(),
+            ApiFailure::RateLimited { reset } => {
+                format!("rate limit exceeded (resets at {})", reset)
+            }
+        }
+    }
+}
+
+/// Report waiting in the queue directory
+#[derive(Debug, Serialize, Deserialize)]
+struct QueuedReport {
+    report: BugReport,
+    /// Filing attempts so far
+    attempts: u32,
+    /// Unix epoch seconds before which no retry is made
+    retry_after: u64,
+}
+
+impl QueuedReport {
+    fn load(path: &Path) -> Result<Self, FilingError> {
+        let json = fs::read_to_string(path).map_err(|e| queue_error(path, e))?;
+        serde_json::from_str(&json).map_err(|e| queue_error(path, e))
+    }
+
+    fn save(&self, path: &Path) -> Result<(), FilingError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| queue_error(dir, e))?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| queue_error(path, e))?;
+        fs::write(path, json).map_err(|e| queue_error(path, e))
+    }
+}
+
+fn queue_error(path: &Path, error: impl std::fmt::Display) -> FilingError {
+    FilingError::ApiError(format!("queue {}: {}", path.display(), error))
+}
+
+/// Current time in Unix epoch seconds
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Mean title and body similarity of two issues
+///
+/// Files, labels and error messages are not known for GitHub issues, so
+/// the full `SimilarityCalculator::calculate` score does not apply.
+fn text_similarity(a: &BugIssue, b: &BugIssue) -> f64 {
+    let title = SimilarityCalculator::jaccard_similarity(&a.title, &b.title);
+    let body = SimilarityCalculator::jaccard_similarity(&a.body, &b.body);
+    (title + body) / 2.0
+}
+
+/// Parse a GitHub issue list response, skipping pull requests
+fn parse_issues(json: &str) -> Result<Vec<BugIssue>, ApiFailure> {
+    let invalid = || ApiFailure::Rejected("unexpected issue list response".to_string());
+    let value: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| ApiFailure::Rejected(format!("invalid issue list: {}", e)))?;
+    let mut issues = Vec::new();
+    for item in value.as_array().ok_or_else(invalid)? {
+        if item.get("pull_request").is_some() {
+            continue;
+        }
+        let number = item["number"].as_u64().ok_or_else(invalid)?;
+        let title = item["title"].as_str().unwrap_or_default();
+        let body = item["body"].as_str().unwrap_or_default();
+        issues.push(BugIssue::new(number, title.to_string(), body.to_string()));
+    }
+    Ok(issues)
 }
 
 /// Issue number returned from GitHub
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IssueNumber(pub u64);
 
+/// Result of `GitHubBugFiler::flush_queue`
+#[derive(Debug, Default)]
+pub struct FlushReport {
+    /// Issues filed (or found already open) for queued reports
+    pub filed: Vec<IssueNumber>,
+    /// Queued reports still waiting for a retry
+    pub pending: Vec<PathBuf>,
+    /// Queued reports that could not be filed or read
+    pub failed: Vec<(PathBuf, FilingError)>,
+}
+
 /// Filing error types
 #[derive(Debug)]
 pub enum FilingError {
@@ -131,6 +550,8 @@ pub enum FilingError {
     Duplicate(String),
     /// GitHub API error
     ApiError(String),
+    /// Transient failure; the report was saved to this queue file
+    Queued(PathBuf),
 }
 
 impl std::fmt::Display for FilingError {
@@ -141,6 +562,9 @@ impl std::fmt::Display for FilingError {
             }
             FilingError::Duplicate(fp) => write!(f, "Duplicate bug (fingerprint: {})", fp),
             FilingError::ApiError(msg) => write!(f, "GitHub API error: {}", msg),
+            FilingError::Queued(path) => {
+                write!(f, "GitHub unavailable, report queued at {}", path.display())
+            }
         }
     }
 }
@@ -151,6 +575,8 @@ impl std::error::Error for FilingError {}
 mod tests {
     use super::*;
     use crate::bug_filing::{BugReport, Category, Severity};
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
 
     #[test]
     fn test_github_client_creation() {
@@ -181,4 +607,239 @@ mod tests {
         let result = filer.file_bug(&bug);
         assert!(result.is_err());
     }
+
+    /// Helper: a filable parser bug
+    fn parser_bug(title: &str, actual: &str) -> BugReport {
+        BugReport::new(
+            Category::Parser,
+            Severity::High,
+            title.to_string(),
+            "if (true) 42 else 0".to_string(),
+            "Should parse successfully".to_string(),
+            actual.to_string(),
+        )
+        .with_confidence(0.95)
+    }
+
+    /// Helper: an empty queue directory unique to this test
+    fn queue_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("bug-filing-queue-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Helper: a transport with no open issues that creates issue `number`,
+    /// or fails with `failure` while it is set
+    fn transport(
+        number: u64,
+        failure: Rc<RefCell<Option<Result<ApiResponse, String>>>>,
+        requests: Rc<Cell<usize>>,
+    ) -> impl Fn(&ApiRequest) -> Result<ApiResponse, String> {
+        move |request| {
+            requests.set(requests.get() + 1);
+            if let Some(failure) = failure.borrow().clone() {
+                return failure;
+            }
+            Ok(match request.method.as_str() {
+                "GET" => ApiResponse::new(200, "[]"),
+                _ => ApiResponse::new(201, format!("{{\"number\": {}}}", number)),
+            })
+        }
+    }
+
+    #[test]
+    fn test_open_near_duplicate_not_refiled() {
+        let existing = parser_bug(
+            "Parser fails on parenthesized if-else",
+            "Parse error: unexpected token",
+        );
+        // Same report filed earlier, with a slightly different title
+        let issues = serde_json::json!([
+            {"number": 7, "title": "Add LSP hover", "body": "Feature request"},
+            {"number": 12, "title": "Fix parser", "body": existing.to_github_markdown(),
+             "pull_request": {}},
+            {"number": 42, "title": "Parser fails on if-else",
+             "body": existing.to_github_markdown()},
+        ])
+        .to_string();
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let log = requests.clone();
+        let mut filer = GitHubBugFiler::new("paiml/ruchy").with_api(move |request| {
+            log.borrow_mut().push(request.clone());
+            Ok(match request.method.as_str() {
+                "GET" => ApiResponse::new(200, issues.clone()),
+                _ => ApiResponse::new(201, "{\"number\": 43}"),
+            })
+        });
+
+        let result = filer.file_bug(&existing);
+        assert_eq!(result.unwrap(), IssueNumber(42));
+        assert!(filer.filed_fingerprints.is_empty());
+        assert_eq!(
+            requests.borrow().as_slice(),
+            [ApiRequest {
+                method: "GET".to_string(),
+                path: "repos/paiml/ruchy/issues?state=open&per_page=100".to_string(),
+                body: None,
+            }]
+        );
+
+        // An unrelated report is still filed
+        let other = BugReport::new(
+            Category::Runtime,
+            Severity::Critical,
+            "Division by zero crashes the evaluator".to_string(),
+            "let x = 1 / 0;".to_string(),
+            "Runtime error".to_string(),
+            "thread panicked".to_string(),
+        )
+        .with_confidence(0.95);
+        assert_eq!(filer.file_bug(&other).unwrap(), IssueNumber(43));
+        assert!(filer.is_duplicate(&other));
+        let created = requests.borrow()[2].clone();
+        assert_eq!(created.method, "POST");
+        assert_eq!(created.path, "repos/paiml/ruchy/issues");
+        assert!(created.body.unwrap().contains("Division by zero"));
+    }
+
+    #[test]
+    fn test_open_issue_check_in_dry_run_and_errors() {
+        let bug = parser_bug("Parser fails on if-else", "Parse error");
+        let body = bug.to_github_markdown();
+        let mut filer = GitHubBugFiler::new("paiml/ruchy")
+            .with_dry_run(true)
+            .with_api(move |_| {
+                let issues = serde_json::json!([{"number": 3, "title": "Parser fails on if-else",
+                                                 "body": body}]);
+                Ok(ApiResponse::new(200, issues.to_string()))
+            });
+        assert_eq!(filer.file_bug(&bug).unwrap(), IssueNumber(3));
+
+        let mut failing = GitHubBugFiler::new("paiml/ruchy")
+            .with_api(|_| Ok(ApiResponse::new(401, "Bad credentials")));
+        assert!(matches!(
+            failing.file_bug(&bug),
+            Err(FilingError::ApiError(message)) if message == "HTTP 401: Bad credentials"
+        ));
+
+        let mut malformed = GitHubBugFiler::new("paiml/ruchy")
+            .with_api(|_| Ok(ApiResponse::new(200, "{\"message\": \"Not Found\"}")));
+        assert!(matches!(
+            malformed.file_bug(&bug),
+            Err(FilingError::ApiError(_))
+        ));
+    }
+
+    #[test]
+    fn test_network_failure_queues_report_until_flush() {
+        let dir = queue_dir("network");
+        let failure = Rc::new(RefCell::new(Some(Err("connection refused".to_string()))));
+        let requests = Rc::new(Cell::new(0));
+        let mut filer = GitHubBugFiler::new("paiml/ruchy")
+            .with_api(transport(77, failure.clone(), requests.clone()))
+            .with_queue_dir(&dir)
+            .with_retry(2, Duration::ZERO);
+        let bug = parser_bug("Parser fails on if-else", "Parse error");
+
+        let path = match filer.file_bug(&bug) {
+            Err(FilingError::Queued(path)) => path,
+            other => panic!("expected a queued report, got {:?}", other),
+        };
+        assert!(path.starts_with(&dir));
+        let queued = QueuedReport::load(&path).unwrap();
+        assert_eq!(queued.report.title, "Parser fails on if-else");
+        assert!(matches!(
+            filer.file_bug(&bug),
+            Err(FilingError::Duplicate(_))
+        ));
+
+        // Still offline: both attempts fail and the report stays queued
+        let flushed = filer.flush_queue().unwrap();
+        assert!(flushed.filed.is_empty());
+        assert_eq!(flushed.pending, vec![path.clone()]);
+        assert_eq!(QueuedReport::load(&path).unwrap().attempts, 3);
+
+        // Back online: the report is filed and leaves the queue
+        *failure.borrow_mut() = None;
+        let flushed = filer.flush_queue().unwrap();
+        assert_eq!(flushed.filed, vec![IssueNumber(77)]);
+        assert!(flushed.pending.is_empty() && flushed.failed.is_empty());
+        assert!(!path.exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_server_error_retried_with_backoff() {
+        let dir = queue_dir("backoff");
+        let failure = Rc::new(RefCell::new(Some(Ok(ApiResponse::new(502, "Bad Gateway")))));
+        let requests = Rc::new(Cell::new(0));
+        let recovering = {
+            let failure = failure.clone();
+            let inner = transport(5, failure.clone(), requests.clone());
+            move |request: &ApiRequest| {
+                let response = inner(request);
+                // The server recovers after the third request
+                if requests.get() >= 3 {
+                    *failure.borrow_mut() = None;
+                }
+                response
+            }
+        };
+        let mut filer = GitHubBugFiler::new("paiml/ruchy")
+            .with_api(recovering)
+            .with_queue_dir(&dir)
+            .with_retry(3, Duration::from_millis(1));
+
+        let bug = parser_bug("Parser fails on if-else", "Parse error");
+        assert!(matches!(filer.file_bug(&bug), Err(FilingError::Queued(_))));
+        let flushed = filer.flush_queue().unwrap();
+        assert_eq!(flushed.filed, vec![IssueNumber(5)]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit_waits_for_reset() {
+        let dir = queue_dir("rate-limit");
+        let reset = now() + 3600;
+        let limited = ApiResponse::new(403, "API rate limit exceeded")
+            .with_header("X-RateLimit-Remaining", "0")
+            .with_header("X-RateLimit-Reset", reset.to_string());
+        let failure = Rc::new(RefCell::new(Some(Ok(limited))));
+        let requests = Rc::new(Cell::new(0));
+        let mut filer = GitHubBugFiler::new("paiml/ruchy")
+            .with_api(transport(9, failure, requests.clone()))
+            .with_queue_dir(&dir);
+
+        let bug = parser_bug("Parser fails on if-else", "Parse error");
+        let path = match filer.file_bug(&bug) {
+            Err(FilingError::Queued(path)) => path,
+            other => panic!("expected a queued report, got {:?}", other),
+        };
+        assert_eq!(QueuedReport::load(&path).unwrap().retry_after, reset);
+
+        // Not retried before the reset time
+        let sent = requests.get();
+        let flushed = filer.flush_queue().unwrap();
+        assert_eq!(flushed.pending, vec![path]);
+        assert_eq!(requests.get(), sent);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_transient_failure_without_queue_is_an_error() {
+        let failure = Rc::new(RefCell::new(Some(Err("timed out".to_string()))));
+        let mut filer = GitHubBugFiler::new("paiml/ruchy").with_api(transport(
+            1,
+            failure,
+            Rc::new(Cell::new(0)),
+        ));
+        let bug = parser_bug("Parser fails on if-else", "Parse error");
+        assert!(matches!(
+            filer.file_bug(&bug),
+            Err(FilingError::ApiError(message)) if message == "timed out"
+        ));
+        assert!(!filer.is_duplicate(&bug));
+        assert!(filer.flush_queue().unwrap().filed.is_empty());
+    }
 }
@@ -77,4 +77,6 @@ pub mod github_client;
 // Re-export main types
 pub use bug_report::{BugReport, Category, Severity};
 pub use confidence::{ConfidenceCalculator, ConfidenceFactors, Impact, Reproducibility};
-pub use github_client::{FilingError, GitHubBugFiler, IssueNumber};
+pub use github_client::{
+    ApiRequest, ApiResponse, ApiTransport, FilingError, FlushReport, GitHubBugFiler, IssueNumber,
+};
@@ -94,6 +94,8 @@ pub struct BisectionState {
     pub tested: Vec<(CommitId, TestResult)>,
     /// Current commit being tested (if any)
     pub current: Option<CommitId>,
+    /// Commits that could not be tested, in the order they were tried
+    pub skipped: Vec<CommitId>,
 }
 
 impl BisectionState {
@@ -104,6 +106,7 @@ impl BisectionState {
             bad,
             tested: Vec::new(),
             current: None,
+            skipped: Vec::new(),
         }
     }
 
@@ -121,6 +124,7 @@ impl BisectionState {
             }
             TestResult::Skip => {
                 // Keep current boundaries
+                self.skipped.push(commit);
             }
         }
 
@@ -137,6 +141,11 @@ impl BisectionState {
         self.tested.iter().any(|(c, _)| c == commit)
     }
 
+    /// Check if a commit was skipped as untestable
+    pub fn is_skipped(&self, commit: &CommitId) -> bool {
+        self.skipped.contains(commit)
+    }
+
     /// Get result for a commit if tested
     pub fn get_result(&self, commit: &CommitId) -> Option<TestResult> {
         self.tested
@@ -157,6 +166,11 @@ pub struct BisectionResult {
     pub commits_tested: usize,
     /// All test results
     pub test_results: Vec<(CommitId, TestResult)>,
+    /// Untestable commits between the last good and first bad commit
+    ///
+    /// When non-empty the regression may have been introduced by any of
+    /// these commits as well as by `first_bad_commit`.
+    pub skipped: Vec<Commit>,
 }
 
 impl BisectionResult {
@@ -172,14 +186,36 @@ impl BisectionResult {
             last_good_commit,
             commits_tested,
             test_results,
+            skipped: Vec::new(),
         }
     }
 
+    /// Set the untestable commits between the last good and first bad commit
+    pub fn with_skipped(mut self, skipped: Vec<Commit>) -> Self {
+        self.skipped = skipped;
+        self
+    }
+
+    /// Check if the regression could not be narrowed to a single commit
+    pub fn is_ambiguous(&self) -> bool {
+        !self.skipped.is_empty()
+    }
+
     /// Generate summary text
     pub fn summary(&self) -> String {
+        let culprit = if self.is_ambiguous() {
+            let skipped: Vec<String> = self.skipped.iter().map(|c| c.id.short()).collect();
+            format!(
+                "one of {} (untestable) or {}",
+                skipped.join(", "),
+                self.first_bad_commit.id.short()
+            )
+        } else {
+            self.first_bad_commit.id.short()
+        };
         format!(
             "Regression introduced in commit {} (\"{}\")\nLast good: {} (\"{}\")\nCommits tested: {}",
-            self.first_bad_commit.id.short(),
+            culprit,
             self.first_bad_commit.message,
             self.last_good_commit.id.short(),
             self.last_good_commit.message,
@@ -236,26 +272,25 @@ where
     }
 
     /// Select next commit to test (binary search midpoint)
+    ///
+    /// Like `git bisect skip`, when the midpoint was skipped the nearest
+    /// untested commit is tried instead, alternating after and before it.
     fn select_next_commit(&self) -> Option<CommitId> {
         let range = self.get_commit_range();
 
-        if range.is_empty() {
-            return None;
-        }
-
-        // Find midpoint of untested commits
-        let untested: Vec<&Commit> = range
-            .into_iter()
-            .filter(|c| !self.state.has_tested(&c.id))
-            .collect();
-
-        if untested.is_empty() {
+        // The bad end of the range is already known
+        let candidates = &range[..range.len().saturating_sub(1)];
+        if candidates.is_empty() {
             return None;
         }
 
-        // Select middle commit
-        let mid = untested.len() / 2;
-        Some(untested[mid].id.clone())
+        let mid = candidates.len() / 2;
+        (0..candidates.len())
+            .flat_map(|offset| [mid.checked_add(offset), mid.checked_sub(offset + 1)])
+            .flatten()
+            .filter_map(|index| candidates.get(index))
+            .find(|c| !self.state.has_tested(&c.id))
+            .map(|c| c.id.clone())
     }
 
     /// Run bisection to find first bad commit
@@ -306,13 +341,18 @@ where
 
         let first_bad = self.commits[bad_idx].clone();
         let last_good = self.commits[good_idx].clone();
-
-        Some(BisectionResult::new(
-            first_bad,
-            last_good,
-            self.state.commits_tested(),
-            self.state.tested.clone(),
-        ))
+        // Everything left between the boundaries was skipped
+        let skipped = self.commits[good_idx + 1..bad_idx].to_vec();
+
+        Some(
+            BisectionResult::new(
+                first_bad,
+                last_good,
+                self.state.commits_tested(),
+                self.state.tested.clone(),
+            )
+            .with_skipped(skipped),
+        )
     }
 
     /// Get current bisection state
@@ -572,4 +612,77 @@ mod tests {
         let result = bisector.bisect();
         assert!(result.is_none()); // Cannot bisect if bad commit is not bad
     }
+
+    /// Helper: bisect commits 0..count with the bug at `first_bad` and the
+    /// commits in `skipped` untestable
+    fn bisect_with_skips(
+        count: usize,
+        first_bad: usize,
+        skipped: std::ops::RangeInclusive<usize>,
+    ) -> (BisectionResult, BisectionState) {
+        let test_fn = |commit: &CommitId| {
+            let commit_num: usize = commit.0[6..9].parse().unwrap();
+            if skipped.contains(&commit_num) {
+                TestResult::Skip
+            } else if commit_num < first_bad {
+                TestResult::Good
+            } else {
+                TestResult::Bad
+            }
+        };
+        let mut bisector = GitBisector::new(
+            test_fn,
+            CommitId::new("commit000".to_string()),
+            CommitId::new(format!("commit{:03}", count - 1)),
+            create_test_commits(count),
+        );
+        let result = bisector.bisect().unwrap();
+        (result, bisector.state().clone())
+    }
+
+    #[test]
+    fn test_git_bisector_skips_past_untestable_block() {
+        // Commits 3-6 don't build; the bug is introduced at commit 8
+        let (result, state) = bisect_with_skips(16, 8, 3..=6);
+
+        assert_eq!(result.first_bad_commit.id.0, "commit008");
+        assert_eq!(result.last_good_commit.id.0, "commit007");
+        assert!(!result.is_ambiguous());
+        assert!(!result.summary().contains("untestable"));
+
+        // Every skipped commit was tried once and recorded as such
+        assert!(!state.skipped.is_empty());
+        assert!(state
+            .skipped
+            .iter()
+            .all(|c| state.get_result(c) == Some(TestResult::Skip)));
+        assert!(state.is_skipped(&CommitId::new("commit004".to_string())));
+        assert!(!state.is_skipped(&CommitId::new("commit008".to_string())));
+    }
+
+    #[test]
+    fn test_git_bisector_boundary_inside_skipped_run() {
+        // Commits 3-6 don't build and the bug is introduced at commit 5
+        let (result, _) = bisect_with_skips(16, 5, 3..=6);
+
+        assert!(result.is_ambiguous());
+        assert_eq!(result.last_good_commit.id.0, "commit002");
+        assert_eq!(result.first_bad_commit.id.0, "commit007");
+        let skipped: Vec<&str> = result.skipped.iter().map(|c| c.id.0.as_str()).collect();
+        assert_eq!(
+            skipped,
+            vec!["commit003", "commit004", "commit005", "commit006"]
+        );
+        assert!(result.summary().contains("(untestable) or"));
+    }
+
+    #[test]
+    fn test_git_bisector_skip_at_range_end() {
+        // Only the commit right before the bad end is untestable
+        let (result, _) = bisect_with_skips(10, 9, 8..=8);
+
+        assert!(result.is_ambiguous());
+        assert_eq!(result.last_good_commit.id.0, "commit007");
+        assert_eq!(result.first_bad_commit.id.0, "commit009");
+    }
 }
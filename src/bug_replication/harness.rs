@@ -22,6 +22,9 @@ pub struct Environment {
     pub env_vars: HashMap<String, String>,
     /// Dependencies with versions
     pub dependencies: HashMap<String, String>,
+    /// Time limit for running a test in this environment (overrides the
+    /// harness timeout)
+    pub timeout: Option<Duration>,
 }
 
 impl Environment {
@@ -33,6 +36,7 @@ impl Environment {
             arch,
             env_vars: HashMap::new(),
             dependencies: HashMap::new(),
+            timeout: None,
         }
     }
 
@@ -48,6 +52,12 @@ impl Environment {
         self
     }
 
+    /// Set the time limit for running a test
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Capture current system environment
     pub fn capture_current() -> Self {
         let os = std::env::consts::OS.to_string();
@@ -64,6 +74,7 @@ impl Environment {
             arch,
             env_vars: HashMap::new(),
             dependencies: HashMap::new(),
+            timeout: None,
         }
     }
 }
@@ -87,6 +98,17 @@ pub enum ExecutionResult {
         /// Execution duration
         duration: Duration,
     },
+    /// Program ran but an `assert`/`assert_eq`/`assert_approx` failed
+    ///
+    /// A "wrong answer" rather than a crash or a parse/runtime error.
+    AssertionFailure {
+        /// Assertion message (what was asserted and the values involved)
+        message: String,
+        /// Test output before the assertion failed
+        output: String,
+        /// Execution duration
+        duration: Duration,
+    },
     /// Test hung (exceeded timeout)
     Timeout {
         /// Timeout value in milliseconds
@@ -116,6 +138,11 @@ impl ExecutionResult {
         matches!(self, ExecutionResult::Failure { .. })
     }
 
+    /// Check if result indicates a failed assertion
+    pub fn is_assertion_failure(&self) -> bool {
+        matches!(self, ExecutionResult::AssertionFailure { .. })
+    }
+
     /// Check if result indicates timeout
     pub fn is_timeout(&self) -> bool {
         matches!(self, ExecutionResult::Timeout { .. })
@@ -131,6 +158,7 @@ impl ExecutionResult {
         match self {
             ExecutionResult::Success { duration, .. } => Some(*duration),
             ExecutionResult::Failure { duration, .. } => Some(*duration),
+            ExecutionResult::AssertionFailure { duration, .. } => Some(*duration),
             ExecutionResult::Crash { duration, .. } => Some(*duration),
             ExecutionResult::Timeout { .. } => None,
         }
@@ -181,6 +209,9 @@ impl ReproducibleTest {
         ));
         md.push_str(&format!("- **OS**: {}\n", self.environment.os));
         md.push_str(&format!("- **Arch**: {}\n", self.environment.arch));
+        if let Some(timeout) = self.environment.timeout {
+            md.push_str(&format!("- **Timeout**: {:?}\n", timeout));
+        }
 
         if !self.environment.dependencies.is_empty() {
             md.push_str("\n### Dependencies\n\n");
@@ -222,6 +253,20 @@ impl ReproducibleTest {
                 md.push_str(output);
                 md.push_str("\n```\n");
             }
+            ExecutionResult::AssertionFailure {
+                message,
+                output,
+                duration,
+            } => {
+                md.push_str(&format!(
+                    "**Status**: Assertion failure ({:?})\n\n",
+                    duration
+                ));
+                md.push_str(&format!("**Assertion**: {}\n\n", message));
+                md.push_str("**Output**:\n```\n");
+                md.push_str(output);
+                md.push_str("\n```\n");
+            }
             ExecutionResult::Timeout {
                 timeout_ms,
                 partial_output,
@@ -331,6 +376,140 @@ impl ReplicationHarness {
         }
     }
 
+    /// Execute a program with the in-process interpreter
+    ///
+    /// Unlike `execute`, this actually runs the source and classifies the
+    /// outcome: parse and runtime errors are `Failure`, a failed assertion is
+    /// `AssertionFailure`, an interpreter panic is `Crash`, and exceeding
+    /// `timeout_ms` is `Timeout`.
+    pub fn execute_interpreted(&self, source: &str) -> ExecutionResult {
+        self.run_interpreted(source, Duration::from_millis(self.timeout_ms))
+    }
+
+    /// Run a reproducible test with the in-process interpreter
+    ///
+    /// Uses the test environment's timeout when set, otherwise `timeout_ms`.
+    pub fn run_test(&self, test: &ReproducibleTest) -> ExecutionResult {
+        let timeout = test
+            .environment
+            .timeout
+            .unwrap_or(Duration::from_millis(self.timeout_ms));
+        self.run_interpreted(&test.source, timeout)
+    }
+
+    /// Run `source` on a worker thread, giving up after `timeout`
+    ///
+    /// On expiry the worker is interrupted at its next loop iteration or
+    /// function call and given a short grace period to report what it
+    /// printed, which becomes the timeout's partial output. A worker stuck
+    /// in a single builtin is left to finish on its own.
+    fn run_interpreted(&self, source: &str, timeout: Duration) -> ExecutionResult {
+        use crate::interpreter::{EvalError, Evaluator, OutputSink, Parser};
+        use std::panic::{self, AssertUnwindSafe};
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::{mpsc, Arc};
+        use std::thread;
+
+        /// How long an interrupted worker has to report its partial output
+        const INTERRUPT_GRACE: Duration = Duration::from_millis(500);
+
+        /// How an interpreted run ended (before timing is attached)
+        enum Outcome {
+            Completed,
+            Assertion(String),
+            Error(String),
+            Interrupted,
+        }
+
+        /// Whether evaluation stopped because of the interrupt flag
+        fn is_interrupted(error: &EvalError) -> bool {
+            match error {
+                EvalError::Interrupted => true,
+                EvalError::WithCallStack { error, .. } => is_interrupted(error),
+                _ => false,
+            }
+        }
+
+        let start = Instant::now();
+        let (sender, receiver) = mpsc::channel();
+        let source = source.to_string();
+        let interrupt = Arc::new(AtomicBool::new(false));
+        let worker_interrupt = Arc::clone(&interrupt);
+
+        let spawned = thread::Builder::new()
+            .stack_size(16 * 1024 * 1024)
+            .spawn(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    let ast = match Parser::new(&source).with_spans().parse() {
+                        Ok(ast) => ast,
+                        Err(e) => {
+                            return (String::new(), Outcome::Error(format!("parse error: {}", e)))
+                        }
+                    };
+                    let sink = OutputSink::buffer();
+                    let mut eval = Evaluator::new()
+                        .with_output(sink.clone())
+                        .with_debug_output(sink.clone())
+                        .with_interrupt(worker_interrupt);
+                    let outcome = match eval.eval_program(&ast) {
+                        Ok(_) => Outcome::Completed,
+                        Err(e) if e.is_assertion_failure() => Outcome::Assertion(e.to_string()),
+                        Err(e) if is_interrupted(&e) => Outcome::Interrupted,
+                        Err(e) => Outcome::Error(e.to_string()),
+                    };
+                    (sink.contents().unwrap_or_default(), outcome)
+                }));
+                let _ = sender.send(result.map_err(|payload| {
+                    payload
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "unknown panic".to_string())
+                }));
+            });
+        if let Err(e) = spawned {
+            return ExecutionResult::Crash {
+                signal: format!("spawn failed: {}", e),
+                output: String::new(),
+                duration: start.elapsed(),
+            };
+        }
+
+        let received = receiver.recv_timeout(timeout);
+        let duration = start.elapsed();
+        let timed_out = |partial_output| ExecutionResult::Timeout {
+            timeout_ms: timeout.as_millis() as u64,
+            partial_output,
+        };
+        match received {
+            Ok(Ok((output, Outcome::Completed))) => ExecutionResult::Success { output, duration },
+            Ok(Ok((output, Outcome::Assertion(message)))) => ExecutionResult::AssertionFailure {
+                message,
+                output,
+                duration,
+            },
+            Ok(Ok((output, Outcome::Error(error)))) => ExecutionResult::Failure {
+                error,
+                output,
+                duration,
+            },
+            // Only set after the deadline, so not reachable here
+            Ok(Ok((output, Outcome::Interrupted))) => timed_out(output),
+            Ok(Err(panic_message)) => ExecutionResult::Crash {
+                signal: format!("panic: {}", panic_message),
+                output: String::new(),
+                duration,
+            },
+            Err(_) => {
+                interrupt.store(true, Ordering::Relaxed);
+                match receiver.recv_timeout(INTERRUPT_GRACE) {
+                    Ok(Ok((output, _))) => timed_out(output),
+                    _ => timed_out(String::new()),
+                }
+            }
+        }
+    }
+
     /// Reproduce a bug with multiple attempts
     pub fn reproduce(&self, source: &str, attempts: usize) -> Vec<ExecutionResult> {
         let mut results = Vec::new();
@@ -603,4 +782,75 @@ mod tests {
         assert_eq!(test.source, "fun main() {}");
         assert!(!test.steps.is_empty());
     }
+
+    #[test]
+    fn test_run_test_times_out_infinite_loop() {
+        let env = Environment::capture_current().with_timeout(Duration::from_millis(200));
+        let source = "println(\"started\");\nwhile true {\n}\n";
+        let expected = ExecutionResult::Timeout {
+            timeout_ms: 200,
+            partial_output: String::new(),
+        };
+        let test = ReproducibleTest::new(source.to_string(), expected, env);
+
+        let start = Instant::now();
+        let result = ReplicationHarness::new().run_test(&test);
+        let elapsed = start.elapsed();
+
+        assert_eq!(
+            result,
+            ExecutionResult::Timeout {
+                timeout_ms: 200,
+                partial_output: "started\n".to_string(),
+            }
+        );
+        assert!(elapsed >= Duration::from_millis(200));
+        assert!(elapsed < Duration::from_secs(3), "took {:?}", elapsed);
+        assert!(test.to_markdown().contains("- **Timeout**: 200ms"));
+    }
+
+    #[test]
+    fn test_run_test_interrupts_while_let_and_recursion() {
+        let sources = [
+            "println(\"started\");\nwhile let Ok(v) = Ok(1) {\n}\n",
+            "println(\"started\");\nfun f(n) {\n    if n == 0 {\n        return 0;\n    }\n    f(n - 1) + f(n - 1)\n}\nf(25);\n",
+        ];
+        for source in sources {
+            let env = Environment::capture_current().with_timeout(Duration::from_millis(200));
+            let expected = ExecutionResult::Timeout {
+                timeout_ms: 200,
+                partial_output: String::new(),
+            };
+            let test = ReproducibleTest::new(source.to_string(), expected, env);
+
+            let start = Instant::now();
+            let result = ReplicationHarness::new().run_test(&test);
+            let elapsed = start.elapsed();
+
+            assert_eq!(
+                result,
+                ExecutionResult::Timeout {
+                    timeout_ms: 200,
+                    partial_output: "started\n".to_string(),
+                }
+            );
+            assert!(elapsed < Duration::from_secs(3), "took {:?}", elapsed);
+        }
+    }
+
+    #[test]
+    fn test_run_test_uses_harness_timeout() {
+        let test = ReproducibleTest::new(
+            "let x = 1 + 1;\nprintln(x);".to_string(),
+            ExecutionResult::Success {
+                output: "2\n".to_string(),
+                duration: Duration::ZERO,
+            },
+            Environment::capture_current(),
+        );
+        let result = ReplicationHarness::new()
+            .with_timeout_ms(2000)
+            .run_test(&test);
+        assert!(result.is_success(), "{:?}", result);
+    }
 }
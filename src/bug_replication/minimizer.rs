@@ -85,8 +85,14 @@ where
     test_runs: usize,
     /// Cache of tested configurations to avoid redundant tests
     cache: HashSet<String>,
+    /// Candidates tested concurrently by `minimize_parallel`
+    parallelism: usize,
 }
 
+/// Finds the index of the first failing input among a ddmin step's
+/// candidates, running tests through the debugger
+type Evaluator<D> = dyn FnMut(&mut D, &[String]) -> Option<usize>;
+
 impl<F> DeltaDebugger<F>
 where
     F: FnMut(&str) -> TestOutcome,
@@ -98,6 +104,7 @@ where
             strategy,
             test_runs: 0,
             cache: HashSet::new(),
+            parallelism: 1,
         }
     }
 
@@ -115,8 +122,26 @@ where
         outcome
     }
 
+    /// Set how many candidate reductions `minimize_parallel` tests at once
+    ///
+    /// A value of 0 is treated as 1.
+    pub fn with_parallelism(mut self, threads: usize) -> Self {
+        self.parallelism = threads.max(1);
+        self
+    }
+
     /// Minimize a failing test case using delta debugging
     pub fn minimize(&mut self, input: &str) -> MinimizationResult {
+        self.minimize_with(input, &mut |debugger, candidates| {
+            candidates
+                .iter()
+                .position(|candidate| debugger.test(candidate) == TestOutcome::Fail)
+        })
+    }
+
+    /// Minimize using `evaluate` to find the first failing candidate of
+    /// each ddmin step
+    fn minimize_with(&mut self, input: &str, evaluate: &mut Evaluator<Self>) -> MinimizationResult {
         // Verify input actually fails
         if self.test(input) != TestOutcome::Fail {
             // Input doesn't fail - return as-is
@@ -125,12 +150,17 @@ where
         }
 
         let original_size = self.measure_size(input);
-        let minimized = match self.strategy {
-            MinimizationStrategy::Line => self.minimize_lines(input),
-            MinimizationStrategy::Token => self.minimize_tokens(input),
-            MinimizationStrategy::Character => self.minimize_chars(input),
-            MinimizationStrategy::Semantic => self.minimize_semantic(input),
+        // Semantic minimization is line-based for now; a real implementation
+        // would parse the AST to preserve structure
+        let chunks = match self.strategy {
+            MinimizationStrategy::Line | MinimizationStrategy::Semantic => {
+                input.lines().map(|s| s.to_string()).collect()
+            }
+            MinimizationStrategy::Token => self.tokenize(input),
+            MinimizationStrategy::Character => input.chars().map(|c| c.to_string()).collect(),
         };
+        let minimized = self.ddmin(chunks, 2, evaluate);
+        let minimized = self.reconstruct(&minimized);
 
         let minimized_size = self.measure_size(&minimized);
 
@@ -148,7 +178,15 @@ where
     }
 
     /// Delta debugging algorithm (classic ddmin)
-    fn ddmin(&mut self, chunks: Vec<String>, granularity: usize) -> Vec<String> {
+    ///
+    /// Each phase builds its candidates in order and recurses on the first
+    /// one `evaluate` reports as failing.
+    fn ddmin(
+        &mut self,
+        chunks: Vec<String>,
+        granularity: usize,
+        evaluate: &mut Evaluator<Self>,
+    ) -> Vec<String> {
         let n = chunks.len();
 
         if n == 1 {
@@ -160,87 +198,43 @@ where
         let subset_size = std::cmp::max(1, n / granularity);
 
         // Phase 1: Try subsets (remove chunks)
-        for i in 0..(n / subset_size) {
-            let start = i * subset_size;
-            let end = ((i + 1) * subset_size).min(n);
-            let subset: Vec<String> = chunks
-                .iter()
-                .enumerate()
-                .filter(|(idx, _)| *idx < start || *idx >= end)
-                .map(|(_, chunk)| chunk.clone())
+        // Phase 2: Try complements (keep only one subset)
+        for keep_range in [false, true] {
+            let candidates: Vec<Vec<String>> = (0..(n / subset_size))
+                .map(|i| {
+                    let start = i * subset_size;
+                    let end = ((i + 1) * subset_size).min(n);
+                    chunks
+                        .iter()
+                        .enumerate()
+                        .filter(|(idx, _)| (*idx >= start && *idx < end) == keep_range)
+                        .map(|(_, chunk)| chunk.clone())
+                        .collect()
+                })
+                .filter(|candidate: &Vec<String>| !candidate.is_empty())
                 .collect();
 
-            if subset.is_empty() {
-                continue;
-            }
-
-            let test_input = self.reconstruct(&subset);
-            if self.test(&test_input) == TestOutcome::Fail {
-                // Subset still fails - recurse with same granularity
-                return self.ddmin(subset, 2);
-            }
-        }
-
-        // Phase 2: Try complements (keep only one subset)
-        for i in 0..(n / subset_size) {
-            let start = i * subset_size;
-            let end = ((i + 1) * subset_size).min(n);
-            let complement: Vec<String> = chunks
+            let inputs: Vec<String> = candidates
                 .iter()
-                .enumerate()
-                .filter(|(idx, _)| *idx >= start && *idx < end)
-                .map(|(_, chunk)| chunk.clone())
+                .map(|candidate| self.reconstruct(candidate))
                 .collect();
-
-            if complement.is_empty() {
-                continue;
-            }
-
-            let test_input = self.reconstruct(&complement);
-            if self.test(&test_input) == TestOutcome::Fail {
-                // Complement still fails - recurse with same granularity
-                return self.ddmin(complement, 2);
+            if let Some(index) = evaluate(self, &inputs) {
+                // Candidate still fails - recurse with same granularity
+                let candidate = candidates.into_iter().nth(index).unwrap_or_default();
+                return self.ddmin(candidate, 2, evaluate);
             }
         }
 
         // Phase 3: Increase granularity
         if granularity < n {
             // Try smaller subsets (increase granularity)
-            return self.ddmin(chunks, std::cmp::min(granularity * 2, n));
+            return self.ddmin(chunks, std::cmp::min(granularity * 2, n), evaluate);
         }
 
         // Cannot reduce further
         chunks
     }
 
-    /// Minimize by removing lines
-    fn minimize_lines(&mut self, input: &str) -> String {
-        let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
-        let minimized_lines = self.ddmin(lines, 2);
-        self.reconstruct(&minimized_lines)
-    }
-
-    /// Minimize by removing tokens
-    fn minimize_tokens(&mut self, input: &str) -> String {
-        let tokens = self.tokenize(input);
-        let minimized_tokens = self.ddmin(tokens, 2);
-        minimized_tokens.join("")
-    }
-
-    /// Minimize by removing characters
-    fn minimize_chars(&mut self, input: &str) -> String {
-        let chars: Vec<String> = input.chars().map(|c| c.to_string()).collect();
-        let minimized_chars = self.ddmin(chars, 2);
-        minimized_chars.join("")
-    }
-
-    /// Minimize with semantic awareness (preserve syntax)
-    fn minimize_semantic(&mut self, input: &str) -> String {
-        // For semantic minimization, we use line-based but preserve structure
-        // This is a simplified version - a real implementation would parse AST
-        self.minimize_lines(input)
-    }
-
     /// Tokenize input (simple whitespace + punctuation split)
     fn tokenize(&self, input: &str) -> Vec<String> {
         let mut tokens = Vec::new();
@@ -278,6 +272,46 @@ where
     }
 }
 
+impl<F> DeltaDebugger<F>
+where
+    F: Fn(&str) -> TestOutcome + Sync,
+{
+    /// Minimize a failing test case, testing candidate reductions in parallel
+    ///
+    /// Each ddmin step tests up to `parallelism` candidates at a time on
+    /// scoped threads and continues from the first failing one in candidate
+    /// order, so the result matches `minimize` regardless of thread timing.
+    /// Candidates after the first failure in a batch are still run and
+    /// counted in `test_runs`.
+    pub fn minimize_parallel(&mut self, input: &str) -> MinimizationResult {
+        self.minimize_with(input, &mut |debugger, candidates| {
+            for (batch_index, batch) in candidates.chunks(debugger.parallelism).enumerate() {
+                let test_fn = &debugger.test_fn;
+                let outcomes: Vec<TestOutcome> = std::thread::scope(|scope| {
+                    let handles: Vec<_> = batch
+                        .iter()
+                        .map(|candidate| scope.spawn(move || test_fn(candidate)))
+                        .collect();
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.join().unwrap_or(TestOutcome::Unresolved))
+                        .collect()
+                });
+                debugger.test_runs += batch.len();
+                debugger.cache.extend(batch.iter().cloned());
+
+                if let Some(index) = outcomes
+                    .iter()
+                    .position(|outcome| *outcome == TestOutcome::Fail)
+                {
+                    return Some(batch_index * debugger.parallelism + index);
+                }
+            }
+            None
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -477,4 +511,46 @@ mod tests {
         let input = "hello";
         assert_eq!(debugger.measure_size(input), 5);
     }
+
+    #[test]
+    fn test_with_parallelism() {
+        let test_fn = |_: &str| TestOutcome::Pass;
+        let debugger = DeltaDebugger::new(test_fn, MinimizationStrategy::Line).with_parallelism(0);
+        assert_eq!(debugger.parallelism, 1);
+    }
+
+    #[test]
+    fn test_minimize_parallel_matches_sequential() {
+        // Slow oracle: fails while "x = 1" and "print(x)" are both present
+        let test_fn = |input: &str| {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            if input.contains("x = 1") && input.contains("print(x)") {
+                TestOutcome::Fail
+            } else {
+                TestOutcome::Pass
+            }
+        };
+        let input = (0..24)
+            .map(|i| match i {
+                5 => "x = 1".to_string(),
+                17 => "print(x)".to_string(),
+                _ => format!("noise{}", i),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        for strategy in [MinimizationStrategy::Line, MinimizationStrategy::Token] {
+            let sequential = DeltaDebugger::new(test_fn, strategy).minimize(&input);
+            for threads in [1, 3, 8] {
+                let parallel = DeltaDebugger::new(test_fn, strategy)
+                    .with_parallelism(threads)
+                    .minimize_parallel(&input);
+                assert_eq!(parallel.minimized, sequential.minimized);
+                assert_eq!(parallel.minimized_size, sequential.minimized_size);
+                assert!(parallel.test_runs >= sequential.test_runs);
+            }
+        }
+        let line = DeltaDebugger::new(test_fn, MinimizationStrategy::Line).minimize(&input);
+        assert_eq!(line.minimized, "x = 1\nprint(x)");
+    }
 }
@@ -7,6 +7,8 @@
 // - REPORT-002: Assisted Five-Whys Analysis
 // - REPORT-003: TDD Integration
 // - REPORT-004: Markdown Report Generator
+// - REPORT-005: SARIF Output
+// - REPORT-006: Report Templates
 
 /// Confidence scoring for bug reports
 pub mod confidence;
@@ -20,6 +22,8 @@ pub mod issue_linking;
 pub mod metrics;
 /// Bug report generation
 pub mod report_generator;
+/// User-supplied report templates
+pub mod report_template;
 /// TDD cycle tracking
 pub mod tdd;
 /// Bug corpus validation
@@ -43,7 +47,10 @@ pub use metrics::{
     ChurnCorrelation, ComplexityMetrics, DependencyAnalyzer, DependencyNode, QuantitativeAnalysis,
     SatdDetector, SatdType,
 };
-pub use report_generator::{BugCategory, BugReport, Severity};
+pub use report_generator::{
+    to_sarif, BugCategory, BugReport, Severity, SARIF_SCHEMA, SARIF_VERSION,
+};
+pub use report_template::{ReportTemplate, TemplateError};
 pub use tdd::{QualityGate, QualityGates, TddCycle, TddHistory, TddPhase, TestResult};
 pub use validation::{
     BugCategory as ValidationBugCategory, BugCorpusValidator, DetectionResult, HistoricalBug,
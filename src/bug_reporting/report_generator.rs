@@ -7,6 +7,9 @@
 // - REPORT-003: TDD Integration (RED-GREEN-REFACTOR workflow)
 // - Confidence Scoring (from bug_discovery module)
 //
+// REPORT-005 adds SARIF 2.1.0 output (`to_sarif`) so discovered bugs can be
+// ingested by code-scanning dashboards such as GitHub's Security tab.
+//
 // References:
 // - docs/specifications/BUG_DISCOVERY_REPORTER_REPLICATOR_SPEC.md (Section 7: Bug Reporter Module)
 // - Potdar & Shihab (2014): "Characteristics of Self-Admitted Technical Debt"
@@ -17,6 +20,7 @@
 use crate::bug_discovery::confidence::ConfidenceScore;
 use crate::bug_reporting::five_whys::FiveWhysAnalysis;
 use crate::bug_reporting::metrics::QuantitativeAnalysis;
+use crate::bug_reporting::report_template::ReportTemplate;
 use crate::bug_reporting::tdd::TddHistory;
 
 /// Bug severity level
@@ -52,6 +56,15 @@ impl Severity {
             Severity::Low => "LOW",
         }
     }
+
+    /// Convert to SARIF result level
+    pub fn sarif_level(&self) -> &'static str {
+        match self {
+            Severity::Critical | Severity::High => "error",
+            Severity::Medium => "warning",
+            Severity::Low => "note",
+        }
+    }
 }
 
 /// Bug category
@@ -89,6 +102,33 @@ impl BugCategory {
             BugCategory::Other(s) => s,
         }
     }
+
+    /// Stable rule id for SARIF output (e.g. `RUCHY-WRONG-OUTPUT`)
+    pub fn rule_id(&self) -> String {
+        let slug = match self {
+            BugCategory::Crash => "CRASH",
+            BugCategory::Hang => "HANG",
+            BugCategory::WrongOutput => "WRONG-OUTPUT",
+            BugCategory::PerformanceRegression => "PERFORMANCE-REGRESSION",
+            BugCategory::MemoryLeak => "MEMORY-LEAK",
+            BugCategory::TypeError => "TYPE-ERROR",
+            BugCategory::ParserError => "PARSER-ERROR",
+            BugCategory::Other(s) => {
+                let slug: String = s
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() {
+                            c.to_ascii_uppercase()
+                        } else {
+                            '-'
+                        }
+                    })
+                    .collect();
+                return format!("RUCHY-OTHER-{}", slug.trim_matches('-'));
+            }
+        };
+        format!("RUCHY-{}", slug)
+    }
 }
 
 /// Complete bug report
@@ -402,6 +442,105 @@ impl BugReport {
 
         report
     }
+
+    /// Generate a report from a user-supplied template
+    pub fn render_with_template(&self, template: &ReportTemplate) -> String {
+        template.render(self)
+    }
+}
+
+/// SARIF schema location referenced by generated documents
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF specification version of generated documents
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Generate a SARIF 2.1.0 document for code-scanning dashboards
+///
+/// Each report becomes one `result`: the rule id comes from its
+/// `BugCategory`, the level from its `Severity`. Related files become
+/// result locations. Reports without related files point at their
+/// reproduction code, embedded as a run artifact. No regions are emitted
+/// since reports carry no source spans.
+pub fn to_sarif(reports: &[BugReport]) -> String {
+    let mut rules: Vec<serde_json::Value> = Vec::new();
+    let mut rule_ids: Vec<String> = Vec::new();
+    let mut artifacts: Vec<serde_json::Value> = Vec::new();
+    let mut results: Vec<serde_json::Value> = Vec::new();
+
+    for (index, report) in reports.iter().enumerate() {
+        let rule_id = report.category.rule_id();
+        let rule_index = match rule_ids.iter().position(|id| *id == rule_id) {
+            Some(existing) => existing,
+            None => {
+                rules.push(serde_json::json!({
+                    "id": rule_id,
+                    "name": report.category.as_str(),
+                    "shortDescription": { "text": report.category.as_str() },
+                }));
+                rule_ids.push(rule_id.clone());
+                rule_ids.len() - 1
+            }
+        };
+
+        let mut locations: Vec<serde_json::Value> = report
+            .related_files
+            .iter()
+            .map(|file| {
+                serde_json::json!({
+                    "physicalLocation": { "artifactLocation": { "uri": file } }
+                })
+            })
+            .collect();
+        if locations.is_empty() && !report.reproduction_code.is_empty() {
+            let uri = format!("reproduction/bug-{}.ruchy", index + 1);
+            locations.push(serde_json::json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": uri, "index": artifacts.len() }
+                }
+            }));
+            artifacts.push(serde_json::json!({
+                "location": { "uri": uri },
+                "contents": { "text": report.reproduction_code },
+            }));
+        }
+
+        results.push(serde_json::json!({
+            "ruleId": rule_id,
+            "ruleIndex": rule_index,
+            "level": report.severity.sarif_level(),
+            "message": { "text": format!("{}: {}", report.title, report.description) },
+            "locations": locations,
+            "properties": {
+                "severity": report.severity.as_str(),
+                "confidence": report.confidence.overall,
+                "expected": report.expected,
+                "actual": report.actual,
+            },
+        }));
+    }
+
+    let mut run = serde_json::json!({
+        "tool": {
+            "driver": {
+                "name": "ruchyruchy",
+                "version": env!("CARGO_PKG_VERSION"),
+                "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                "rules": rules,
+            }
+        },
+        "results": results,
+    });
+    if !artifacts.is_empty() {
+        run["artifacts"] = serde_json::Value::Array(artifacts);
+    }
+
+    let document = serde_json::json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [run],
+    });
+    serde_json::to_string_pretty(&document).expect("SARIF document serializes")
 }
 
 #[cfg(test)]
@@ -436,6 +575,19 @@ mod tests {
         assert_eq!(BugCategory::Other("Custom".to_string()).as_str(), "Custom");
     }
 
+    #[test]
+    fn test_sarif_rule_ids_and_levels() {
+        assert_eq!(BugCategory::Crash.rule_id(), "RUCHY-CRASH");
+        assert_eq!(BugCategory::WrongOutput.rule_id(), "RUCHY-WRONG-OUTPUT");
+        assert_eq!(
+            BugCategory::Other("Bad codegen!".to_string()).rule_id(),
+            "RUCHY-OTHER-BAD-CODEGEN"
+        );
+        assert_eq!(Severity::Critical.sarif_level(), "error");
+        assert_eq!(Severity::Medium.sarif_level(), "warning");
+        assert_eq!(Severity::Low.sarif_level(), "note");
+    }
+
     #[test]
     fn test_bug_report_creation() {
         let confidence = ConfidenceScore {
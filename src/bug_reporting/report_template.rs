@@ -0,0 +1,242 @@
+// Report Templates
+// Renders a BugReport into a user-supplied Markdown template
+//
+// `BugReport::to_markdown` has a fixed layout. A template lets the same report
+// be formatted for GitHub, GitLab or an internal wiki. Placeholders are
+// written `{{name}}` (surrounding spaces allowed):
+// - `{{title}}`: report title
+// - `{{severity}}`: severity (e.g. HIGH)
+// - `{{repro}}`: minimal reproduction code, without a code fence
+// - `{{five_whys}}`: Five-Whys analysis as Markdown (empty if none)
+// - `{{confidence}}`: overall confidence score, two decimals
+//
+// Templates are checked when loaded, so an unknown or unclosed placeholder is
+// reported before any report is rendered.
+
+use crate::bug_reporting::report_generator::BugReport;
+use std::path::Path;
+
+/// Placeholder in a report template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder {
+    Title,
+    Severity,
+    Repro,
+    FiveWhys,
+    Confidence,
+}
+
+impl Placeholder {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "title" => Some(Placeholder::Title),
+            "severity" => Some(Placeholder::Severity),
+            "repro" => Some(Placeholder::Repro),
+            "five_whys" => Some(Placeholder::FiveWhys),
+            "confidence" => Some(Placeholder::Confidence),
+            _ => None,
+        }
+    }
+
+    fn render(self, report: &BugReport) -> String {
+        match self {
+            Placeholder::Title => report.title.clone(),
+            Placeholder::Severity => report.severity.as_str().to_string(),
+            Placeholder::Repro => report.reproduction_code.clone(),
+            Placeholder::FiveWhys => report
+                .five_whys
+                .as_ref()
+                .map(|analysis| analysis.to_markdown())
+                .unwrap_or_default(),
+            Placeholder::Confidence => format!("{:.2}", report.confidence.overall),
+        }
+    }
+}
+
+/// Piece of a parsed template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// Markdown template for bug reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportTemplate {
+    segments: Vec<Segment>,
+}
+
+impl ReportTemplate {
+    /// Parse a template string
+    ///
+    /// # Errors
+    ///
+    /// `UnknownPlaceholder` or `UnclosedPlaceholder`, with the 1-based line
+    /// of the offending `{{`.
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+        while let Some(open) = rest.find("{{") {
+            let line = template[..template.len() - rest.len() + open]
+                .matches('\n')
+                .count()
+                + 1;
+            if open > 0 {
+                segments.push(Segment::Text(rest[..open].to_string()));
+            }
+            let after = &rest[open + 2..];
+            let close = after
+                .find("}}")
+                .ok_or(TemplateError::UnclosedPlaceholder { line })?;
+            let name = after[..close].trim();
+            let placeholder =
+                Placeholder::from_name(name).ok_or_else(|| TemplateError::UnknownPlaceholder {
+                    name: name.to_string(),
+                    line,
+                })?;
+            segments.push(Segment::Placeholder(placeholder));
+            rest = &after[close + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Load and parse a template file
+    ///
+    /// # Errors
+    ///
+    /// `Io` if the file cannot be read, otherwise as for `parse`.
+    pub fn load(path: &Path) -> Result<Self, TemplateError> {
+        let template = std::fs::read_to_string(path)
+            .map_err(|e| TemplateError::Io(format!("{}: {}", path.display(), e)))?;
+        Self::parse(&template)
+    }
+
+    /// Render a report into this template
+    pub fn render(&self, report: &BugReport) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.clone(),
+                Segment::Placeholder(placeholder) => placeholder.render(report),
+            })
+            .collect()
+    }
+}
+
+/// Error loading a report template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// `{{name}}` is not a known placeholder
+    UnknownPlaceholder {
+        /// Placeholder name as written (trimmed)
+        name: String,
+        /// 1-based line number
+        line: usize,
+    },
+    /// `{{` without a closing `}}`
+    UnclosedPlaceholder {
+        /// 1-based line number
+        line: usize,
+    },
+    /// Template file could not be read
+    Io(String),
+}
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::UnknownPlaceholder { name, line } => {
+                write!(f, "line {}: unknown placeholder {{{{{}}}}}", line, name)
+            }
+            TemplateError::UnclosedPlaceholder { line } => {
+                write!(f, "line {}: unclosed placeholder", line)
+            }
+            TemplateError::Io(msg) => write!(f, "cannot read template: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bug_discovery::confidence::ConfidenceScore;
+    use crate::bug_reporting::five_whys::FiveWhysAnalysis;
+    use crate::bug_reporting::report_generator::{BugCategory, Severity};
+
+    /// Helper: a parser crash report
+    fn report() -> BugReport {
+        let confidence = ConfidenceScore {
+            overall: 0.875,
+            discovery_method_weight: 0.9,
+            reproducibility_score: 0.9,
+            quantitative_evidence: 0.8,
+            root_cause_clarity: 0.8,
+        };
+        BugReport::new(
+            "Parser crashes on nested expressions".to_string(),
+            "Stack overflow on deep nesting".to_string(),
+            Severity::High,
+            BugCategory::Crash,
+            "let x = ((((1))));".to_string(),
+            "Should parse".to_string(),
+            "Stack overflow".to_string(),
+            confidence,
+        )
+    }
+
+    #[test]
+    fn test_render_custom_template() {
+        let template = ReportTemplate::parse(
+            "h1. {{title}}\n\n*Severity*: {{ severity }} ({{confidence}})\n\n{code}\n{{repro}}\n{code}\n{{five_whys}}",
+        )
+        .unwrap();
+        assert_eq!(
+            report().render_with_template(&template),
+            "h1. Parser crashes on nested expressions\n\n*Severity*: HIGH (0.88)\n\n{code}\nlet x = ((((1))));\n{code}\n"
+        );
+
+        let analysis = FiveWhysAnalysis::new("Parser crashes".to_string());
+        let with_whys = report().with_five_whys(analysis.clone());
+        assert_eq!(
+            with_whys.render_with_template(&template),
+            format!(
+                "h1. Parser crashes on nested expressions\n\n*Severity*: HIGH (0.88)\n\n{{code}}\nlet x = ((((1))));\n{{code}}\n{}",
+                analysis.to_markdown()
+            )
+        );
+    }
+
+    #[test]
+    fn test_unknown_placeholder_rejected_at_load() {
+        let error = ReportTemplate::parse("# {{title}}\n\nReported by {{author}}").unwrap_err();
+        assert_eq!(
+            error,
+            TemplateError::UnknownPlaceholder {
+                name: "author".to_string(),
+                line: 3,
+            }
+        );
+        assert_eq!(error.to_string(), "line 3: unknown placeholder {{author}}");
+
+        assert_eq!(
+            ReportTemplate::parse("{{title}} {{severity").unwrap_err(),
+            TemplateError::UnclosedPlaceholder { line: 1 }
+        );
+        assert!(matches!(
+            ReportTemplate::load(Path::new("/nonexistent/template.md")),
+            Err(TemplateError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_template_without_placeholders() {
+        let template = ReportTemplate::parse("static text").unwrap();
+        assert_eq!(template.render(&report()), "static text");
+        assert_eq!(ReportTemplate::parse("").unwrap().render(&report()), "");
+    }
+}
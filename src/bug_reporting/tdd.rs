@@ -247,13 +247,11 @@ impl TddHistory {
                 TddPhase::Green if cycle.test_result.is_pass() && seen_red => {
                     seen_green = true;
                 }
-                TddPhase::Refactor if cycle.test_result.is_pass() && seen_green => {
-                    if in_cycle {
-                        complete += 1;
-                        in_cycle = false;
-                        seen_red = false;
-                        seen_green = false;
-                    }
+                TddPhase::Refactor if cycle.test_result.is_pass() && seen_green && in_cycle => {
+                    complete += 1;
+                    in_cycle = false;
+                    seen_red = false;
+                    seen_green = false;
                 }
                 _ => {}
             }
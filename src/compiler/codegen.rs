@@ -1,6 +1,94 @@
 // Code Generator: AST → Rust source code
 //
 // Takes RuchyRuchy interpreter AST and generates equivalent Rust code
+//
+// COMPILE-002: Functions, let bindings, expressions, if/while, and tuple
+// returns. Ruchy is untyped, so parameters are assumed to be i64 and other
+// types are inferred from literals, operators, and known function signatures.
+//
+// COMPILE-003: Vector literals, ranges, and `for` loops over both. Ranges in
+// a loop header stay Rust ranges; elsewhere they become a collected Vec<i64>,
+// matching the interpreter, which evaluates a range to a vector.
+
+use super::CompileError;
+use crate::interpreter::ast::{Ast, AstNode, BinaryOperator, Pattern, UnaryOperator};
+use crate::interpreter::visitor::{walk_node, Visitor};
+use std::collections::{HashMap, HashSet};
+
+/// Rust type inferred for a generated expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum RustType {
+    /// 64-bit integer (also the assumed type of every parameter)
+    I64,
+    /// 64-bit float
+    F64,
+    /// Boolean
+    Bool,
+    /// Owned string
+    String,
+    /// The unit type `()`
+    Unit,
+    /// Tuple of element types
+    Tuple(Vec<RustType>),
+    /// Vector of one element type
+    Vec(Box<RustType>),
+    /// Inference failed; emitted as i64 with a warning
+    Unknown,
+}
+
+impl RustType {
+    /// Rust spelling of this type (`Unknown` falls back to `i64`)
+    pub fn rust_name(&self) -> String {
+        match self {
+            RustType::I64 | RustType::Unknown => "i64".to_string(),
+            RustType::F64 => "f64".to_string(),
+            RustType::Bool => "bool".to_string(),
+            RustType::String => "String".to_string(),
+            RustType::Unit => "()".to_string(),
+            RustType::Tuple(elements) if elements.len() == 1 => {
+                format!("({},)", elements[0].rust_name())
+            }
+            RustType::Tuple(elements) => format!(
+                "({})",
+                elements
+                    .iter()
+                    .map(RustType::rust_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            RustType::Vec(element) => format!("Vec<{}>", element.rust_name()),
+        }
+    }
+
+    /// Whether values of this type are `Copy` in Rust
+    fn is_copy(&self) -> bool {
+        match self {
+            RustType::String | RustType::Vec(_) => false,
+            RustType::Tuple(elements) => elements.iter().all(RustType::is_copy),
+            _ => true,
+        }
+    }
+
+    /// Combine two inferred types for the same value (e.g. two return sites)
+    ///
+    /// Unknown parts are filled in from the other side; real conflicts are `None`.
+    fn merge(&self, other: &RustType) -> Option<RustType> {
+        match (self, other) {
+            (RustType::Unknown, t) | (t, RustType::Unknown) => Some(t.clone()),
+            (RustType::Tuple(a), RustType::Tuple(b)) if a.len() == b.len() => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| x.merge(y))
+                .collect::<Option<Vec<_>>>()
+                .map(RustType::Tuple),
+            (RustType::Vec(a), RustType::Vec(b)) => {
+                a.merge(b).map(|element| RustType::Vec(Box::new(element)))
+            }
+            (a, b) if a == b => Some(a.clone()),
+            _ => None,
+        }
+    }
+}
 
 /// Code generator for transpiling Ruchy AST to Rust
 pub struct CodeGenerator {
@@ -8,6 +96,16 @@ pub struct CodeGenerator {
     output: String,
     /// Current indentation level
     indent: usize,
+    /// Return types of functions generated so far
+    functions: HashMap<String, RustType>,
+    /// Local variable types, innermost scope last
+    scopes: Vec<HashMap<String, RustType>>,
+    /// Variables reassigned in the current function (declared `mut`)
+    mutated: HashSet<String>,
+    /// Types of the `return` statements in the current function
+    returns: Vec<RustType>,
+    /// Places where inference fell back to an assumption
+    warnings: Vec<String>,
 }
 
 impl CodeGenerator {
@@ -16,6 +114,11 @@ impl CodeGenerator {
         Self {
             output: String::new(),
             indent: 0,
+            functions: HashMap::new(),
+            scopes: Vec::new(),
+            mutated: HashSet::new(),
+            returns: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
@@ -24,16 +127,612 @@ impl CodeGenerator {
         &self.output
     }
 
+    /// Inference fallbacks from the last `generate` call
+    ///
+    /// Each entry names a type that could not be inferred and was assumed to
+    /// be `i64`; the generated code may not compile if the assumption is wrong.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     /// Clear the output buffer
     pub fn clear(&mut self) {
         self.output.clear();
         self.indent = 0;
+        self.functions.clear();
+        self.scopes.clear();
+        self.mutated.clear();
+        self.returns.clear();
+        self.warnings.clear();
     }
 
-    /// Emit a line of code with current indentation
+    /// Translate a parsed program to Rust source
     ///
-    /// Note: Will be used in COMPILE-002 for expression codegen
-    #[allow(dead_code)]
+    /// Each `fun` becomes a Rust `fn`. Top-level statements become the body
+    /// of a generated `fn main()`, so they cannot be mixed with a `fun main`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ruchyruchy::compiler::CodeGenerator;
+    /// use ruchyruchy::interpreter::Parser;
+    ///
+    /// let ast = Parser::new("fun pair(n) { (n, n * 2) }").parse().unwrap();
+    /// let mut codegen = CodeGenerator::new();
+    /// let rust = codegen.generate(&ast).unwrap();
+    /// assert!(rust.contains("fn pair(n: i64) -> (i64, i64) {"));
+    /// ```
+    pub fn generate(&mut self, ast: &Ast) -> Result<&str, CompileError> {
+        self.clear();
+
+        let mut functions = Vec::new();
+        let mut statements = Vec::new();
+        for node in ast.nodes() {
+            match node.without_span() {
+                AstNode::FunctionDef { name, params, body, .. } => functions.push((name, params, body)),
+                other => statements.push(other.clone()),
+            }
+        }
+
+        let (main_name, no_params) = ("main".to_string(), Vec::new());
+        if !statements.is_empty() {
+            if functions.iter().any(|(name, _, _)| *name == "main") {
+                return Err(CompileError::UnsupportedFeature(
+                    "top-level statements alongside fun main()".to_string(),
+                ));
+            }
+            functions.push((&main_name, &no_params, &statements));
+        }
+
+        for (i, (name, params, body)) in functions.into_iter().enumerate() {
+            if i > 0 {
+                self.output.push('\n');
+            }
+            self.gen_function(name, params, body)?;
+        }
+        Ok(&self.output)
+    }
+
+    /// Emit one function, inferring its return type from its body
+    fn gen_function(
+        &mut self,
+        name: &str,
+        params: &[String],
+        body: &[AstNode],
+    ) -> Result<(), CompileError> {
+        self.scopes = vec![params
+            .iter()
+            .map(|p| (p.clone(), RustType::I64))
+            .collect()];
+        self.mutated = mutated_names(body);
+        self.returns.clear();
+
+        // Generate the body first: the signature depends on what it returns
+        let outer = std::mem::take(&mut self.output);
+        self.indent = 1;
+        // Rust's main cannot return a value, so its tail is a statement
+        let tail = self.gen_body(body, name != "main");
+        self.indent = 0;
+        let body_code = std::mem::replace(&mut self.output, outer);
+        let tail = tail?;
+
+        let mut return_type = tail;
+        for found in std::mem::take(&mut self.returns) {
+            return_type = match (&return_type, &found) {
+                (RustType::Unit, _) if body_ends_in_return(body) => found,
+                _ => return_type.merge(&found).ok_or_else(|| {
+                    CompileError::CodeGenError(format!(
+                        "fun {}: returns both {} and {}",
+                        name,
+                        return_type.rust_name(),
+                        found.rust_name()
+                    ))
+                })?,
+            };
+        }
+        self.note_fallbacks(&format!("return type of fun {}", name), &return_type);
+        self.functions.insert(name.to_string(), return_type.clone());
+
+        let params = params
+            .iter()
+            .map(|p| format!("{}: i64", p))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let signature = match return_type {
+            RustType::Unit => format!("fn {}({}) {{", name, params),
+            ref ty => format!("fn {}({}) -> {} {{", name, params, ty.rust_name()),
+        };
+        self.emit_line(&signature);
+        self.output.push_str(&body_code);
+        self.emit_line("}");
+        Ok(())
+    }
+
+    /// Record a warning for every part of `ty` that fell back to i64
+    fn note_fallbacks(&mut self, what: &str, ty: &RustType) {
+        match ty {
+            RustType::Unknown => self.warnings.push(format!(
+                "could not infer the {}; assuming i64 (generated code may not compile)",
+                what
+            )),
+            RustType::Tuple(elements) => {
+                for (i, element) in elements.iter().enumerate() {
+                    self.note_fallbacks(&format!("{} (tuple element {})", what, i), element);
+                }
+            }
+            RustType::Vec(element) => {
+                self.note_fallbacks(&format!("{} (vector element)", what), element)
+            }
+            _ => {}
+        }
+    }
+
+    /// Emit a statement list; with `tail`, the last expression is the value
+    fn gen_body(&mut self, body: &[AstNode], tail: bool) -> Result<RustType, CompileError> {
+        let mut result = RustType::Unit;
+        for (i, node) in body.iter().enumerate() {
+            if tail && i + 1 == body.len() {
+                result = self.gen_tail(node)?;
+            } else {
+                self.gen_statement(node)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Emit a nested block in its own scope
+    fn gen_block(&mut self, body: &[AstNode], tail: bool) -> Result<RustType, CompileError> {
+        self.scopes.push(HashMap::new());
+        self.indent();
+        let result = self.gen_body(body, tail);
+        self.dedent();
+        self.scopes.pop();
+        result
+    }
+
+    /// Emit the value-producing last statement of a function or branch
+    fn gen_tail(&mut self, node: &AstNode) -> Result<RustType, CompileError> {
+        match node.without_span() {
+            AstNode::IfExpr {
+                condition,
+                then_branch,
+                else_branch: Some(else_branch),
+            } => {
+                let (cond, _) = self.gen_expr(condition)?;
+                self.emit_line(&format!("if {} {{", cond));
+                let then_type = self.gen_block(then_branch, true)?;
+                self.emit_line("} else {");
+                let else_type = self.gen_block(else_branch, true)?;
+                self.emit_line("}");
+                then_type.merge(&else_type).ok_or_else(|| {
+                    CompileError::CodeGenError(format!(
+                        "if branches have different types: {} and {}",
+                        then_type.rust_name(),
+                        else_type.rust_name()
+                    ))
+                })
+            }
+            AstNode::Block { statements } => {
+                self.emit_line("{");
+                let result = self.gen_block(statements, true)?;
+                self.emit_line("}");
+                Ok(result)
+            }
+            other if is_expression(other) => {
+                let (code, ty) = self.gen_expr(other)?;
+                if ty == RustType::Unit {
+                    self.emit_line(&format!("{};", code));
+                } else {
+                    self.emit_line(&code);
+                }
+                Ok(ty)
+            }
+            other => {
+                self.gen_statement(other)?;
+                Ok(RustType::Unit)
+            }
+        }
+    }
+
+    /// Emit a statement whose value (if any) is discarded
+    fn gen_statement(&mut self, node: &AstNode) -> Result<(), CompileError> {
+        match node.without_span() {
+            AstNode::LetDecl { name, value, .. } => {
+                let (code, ty) = self.gen_expr(value)?;
+                let line = format!("let {} = {};", self.binding(name), code);
+                self.emit_line(&line);
+                self.define(name, ty);
+            }
+            AstNode::TupleDestruct { patterns, value } => {
+                let (code, ty) = self.gen_expr(value)?;
+                let pattern = self.tuple_binding(patterns, ty)?;
+                self.emit_line(&format!("let {} = {};", pattern, code));
+            }
+            AstNode::Assignment { name, value } => {
+                let (code, _) = self.gen_expr(value)?;
+                self.emit_line(&format!("{} = {};", name, code));
+            }
+            AstNode::CompoundAssignment { lhs, op, rhs } => {
+                let (target, _) = self.gen_expr(lhs)?;
+                let (code, _) = self.gen_expr(rhs)?;
+                self.emit_line(&format!("{} {}= {};", target, op.symbol(), code));
+            }
+            AstNode::IfExpr {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let (cond, _) = self.gen_expr(condition)?;
+                self.emit_line(&format!("if {} {{", cond));
+                self.gen_block(then_branch, false)?;
+                if let Some(else_branch) = else_branch {
+                    self.emit_line("} else {");
+                    self.gen_block(else_branch, false)?;
+                }
+                self.emit_line("}");
+            }
+            AstNode::WhileLoop { condition, body } => {
+                let (cond, _) = self.gen_expr(condition)?;
+                self.emit_line(&format!("while {} {{", cond));
+                self.gen_block(body, false)?;
+                self.emit_line("}");
+            }
+            AstNode::ForLoop {
+                var,
+                iterable,
+                body,
+            } => self.gen_for(var, iterable, body)?,
+            AstNode::Block { statements } => {
+                self.emit_line("{");
+                self.gen_block(statements, false)?;
+                self.emit_line("}");
+            }
+            AstNode::Return { value: Some(value) } => {
+                let (code, ty) = self.gen_expr(value)?;
+                self.returns.push(ty);
+                self.emit_line(&format!("return {};", code));
+            }
+            AstNode::Return { value: None } => {
+                self.returns.push(RustType::Unit);
+                self.emit_line("return;");
+            }
+            AstNode::Break { value: None } => self.emit_line("break;"),
+            AstNode::Continue => self.emit_line("continue;"),
+            other if is_expression(other) => {
+                let (code, _) = self.gen_expr(other)?;
+                self.emit_line(&format!("{};", code));
+            }
+            other => return Err(unsupported(other)),
+        }
+        Ok(())
+    }
+
+    /// Emit a `for` loop over a range or a vector
+    ///
+    /// Ranges are iterated directly. A vector variable is borrowed, as the
+    /// interpreter leaves it usable after the loop: `Copy` elements bind by
+    /// value (`for &x in &v`), others are iterated from a clone.
+    fn gen_for(
+        &mut self,
+        var: &str,
+        iterable: &AstNode,
+        body: &[AstNode],
+    ) -> Result<(), CompileError> {
+        let binding = self.binding(var);
+        let (header, element) = match iterable.without_span() {
+            AstNode::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let range = self.gen_range(start, end, *inclusive)?;
+                (format!("for {} in {} {{", binding, range), RustType::I64)
+            }
+            other => {
+                let (code, ty) = self.gen_expr(other)?;
+                let element = match ty {
+                    RustType::Vec(element) => *element,
+                    RustType::Unknown => RustType::Unknown,
+                    ty => {
+                        return Err(CompileError::CodeGenError(format!(
+                            "for {} in a {}: expected a range or vector",
+                            var,
+                            ty.rust_name()
+                        )))
+                    }
+                };
+                self.note_fallbacks(&format!("element type of for {}", var), &element);
+                let header = match other {
+                    AstNode::Identifier(_) if element.is_copy() => {
+                        match binding.strip_prefix("mut ") {
+                            Some(name) => format!("for &(mut {}) in &{} {{", name, code),
+                            None => format!("for &{} in &{} {{", binding, code),
+                        }
+                    }
+                    AstNode::Identifier(_) => format!("for {} in {}.clone() {{", binding, code),
+                    _ => format!("for {} in {} {{", binding, code),
+                };
+                (header, element)
+            }
+        };
+
+        self.emit_line(&header);
+        // The loop variable lives in a scope around the body's own block scope
+        let loop_scope = HashMap::from([(var.to_string(), element)]);
+        self.scopes.push(loop_scope);
+        let result = self.gen_block(body, false);
+        self.scopes.pop();
+        result?;
+        self.emit_line("}");
+        Ok(())
+    }
+
+    /// Generate `start..end` or `start..=end`
+    fn gen_range(
+        &mut self,
+        start: &AstNode,
+        end: &AstNode,
+        inclusive: bool,
+    ) -> Result<String, CompileError> {
+        let (start_code, _) = self.gen_expr(start)?;
+        let (end_code, _) = self.gen_expr(end)?;
+        let op = if inclusive { "..=" } else { ".." };
+        Ok(format!("{}{}{}", start_code, op, end_code))
+    }
+
+    /// Generate an expression, returning its code and inferred type
+    fn gen_expr(&mut self, node: &AstNode) -> Result<(String, RustType), CompileError> {
+        Ok(match node.without_span() {
+            AstNode::IntegerLiteral(n) => (n.to_string(), RustType::I64),
+            AstNode::FloatLiteral(f) => (format!("{:?}", f), RustType::F64),
+            AstNode::BooleanLiteral(b) => (b.to_string(), RustType::Bool),
+            AstNode::StringLiteral(s) => (format!("String::from({:?})", s), RustType::String),
+            AstNode::Identifier(name) => (name.clone(), self.lookup(name)),
+            AstNode::TupleLiteral { elements } => {
+                let mut codes = Vec::with_capacity(elements.len());
+                let mut types = Vec::with_capacity(elements.len());
+                for element in elements {
+                    let (code, ty) = self.gen_expr(element)?;
+                    codes.push(code);
+                    types.push(ty);
+                }
+                let code = if codes.len() == 1 {
+                    format!("({},)", codes[0])
+                } else {
+                    format!("({})", codes.join(", "))
+                };
+                (code, RustType::Tuple(types))
+            }
+            AstNode::VectorLiteral { elements } => {
+                let mut codes = Vec::with_capacity(elements.len());
+                let mut element_type = RustType::Unknown;
+                for element in elements {
+                    let (code, ty) = self.gen_expr(element)?;
+                    codes.push(code);
+                    element_type = element_type.merge(&ty).ok_or_else(|| {
+                        CompileError::CodeGenError(format!(
+                            "vector mixes {} and {} elements",
+                            element_type.rust_name(),
+                            ty.rust_name()
+                        ))
+                    })?;
+                }
+                (
+                    format!("vec![{}]", codes.join(", ")),
+                    RustType::Vec(Box::new(element_type)),
+                )
+            }
+            AstNode::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                // Outside a loop header a range is a vector, as in the interpreter
+                let range = self.gen_range(start, end, *inclusive)?;
+                (
+                    format!("({}).collect::<Vec<i64>>()", range),
+                    RustType::Vec(Box::new(RustType::I64)),
+                )
+            }
+            AstNode::BinaryOp { op, left, right } => {
+                let (left_code, left_type) = self.gen_operand(left, *op, false)?;
+                let (right_code, right_type) = self.gen_operand(right, *op, true)?;
+                match op {
+                    BinaryOperator::Add
+                        if left_type == RustType::String || right_type == RustType::String =>
+                    {
+                        (
+                            format!("format!(\"{{}}{{}}\", {}, {})", left_code, right_code),
+                            RustType::String,
+                        )
+                    }
+                    BinaryOperator::Add
+                    | BinaryOperator::Subtract
+                    | BinaryOperator::Multiply
+                    | BinaryOperator::Divide
+                    | BinaryOperator::Modulo
+                    | BinaryOperator::BitAnd
+                    | BinaryOperator::BitOr
+                    | BinaryOperator::BitXor
+                    | BinaryOperator::Shl
+                    | BinaryOperator::Shr => (
+                        format!("{} {} {}", left_code, op.symbol(), right_code),
+                        left_type.merge(&right_type).unwrap_or(left_type),
+                    ),
+                    _ => (
+                        format!("{} {} {}", left_code, op.symbol(), right_code),
+                        RustType::Bool,
+                    ),
+                }
+            }
+            AstNode::UnaryOp { op, operand } => {
+                let (code, ty) = self.gen_expr(operand)?;
+                let code = match operand.without_span() {
+                    AstNode::BinaryOp { .. } => format!("({})", code),
+                    _ => code,
+                };
+                match op {
+                    UnaryOperator::Negate => (format!("-{}", code), ty),
+                    UnaryOperator::Not => (format!("!{}", code), RustType::Bool),
+                    UnaryOperator::Plus => (code, ty),
+                    UnaryOperator::Dereference => return Err(unsupported(node)),
+                }
+            }
+            AstNode::IfExpr {
+                condition,
+                then_branch,
+                else_branch: Some(else_branch),
+            } if then_branch.len() == 1 && else_branch.len() == 1 => {
+                // Single-expression branches fit on one line: `if c { a } else { b }`
+                let (cond, _) = self.gen_expr(condition)?;
+                let (then_code, then_type) = self.gen_expr(&then_branch[0])?;
+                let (else_code, else_type) = self.gen_expr(&else_branch[0])?;
+                (
+                    format!("if {} {{ {} }} else {{ {} }}", cond, then_code, else_code),
+                    then_type.merge(&else_type).unwrap_or(RustType::Unknown),
+                )
+            }
+            AstNode::FunctionCall { name, args } if name == "println" || name == "print" => {
+                (self.gen_print(name, args)?, RustType::Unit)
+            }
+            AstNode::FunctionCall { name, args } => {
+                let mut codes = Vec::with_capacity(args.len());
+                for arg in args {
+                    codes.push(self.gen_expr(arg)?.0);
+                }
+                let ty = self
+                    .functions
+                    .get(name)
+                    .cloned()
+                    .unwrap_or(RustType::Unknown);
+                (format!("{}({})", name, codes.join(", ")), ty)
+            }
+            other => return Err(unsupported(other)),
+        })
+    }
+
+    /// Generate a binary operand, parenthesized where Rust would regroup it
+    fn gen_operand(
+        &mut self,
+        operand: &AstNode,
+        parent: BinaryOperator,
+        is_right: bool,
+    ) -> Result<(String, RustType), CompileError> {
+        let (code, ty) = self.gen_expr(operand)?;
+        let needs_parens = match operand.without_span() {
+            AstNode::BinaryOp { op, .. } => {
+                let (child, outer) = (rust_precedence(*op), rust_precedence(parent));
+                // Comparisons do not chain in Rust, so they always need grouping
+                child < outer || (child == outer && (is_right || outer == COMPARISON))
+            }
+            _ => false,
+        };
+        Ok(if needs_parens {
+            (format!("({})", code), ty)
+        } else {
+            (code, ty)
+        })
+    }
+
+    /// Translate `println`/`print` to the matching Rust macro
+    fn gen_print(&mut self, name: &str, args: &[AstNode]) -> Result<String, CompileError> {
+        let mut codes = Vec::with_capacity(args.len());
+        let template = match args {
+            [] => String::new(),
+            [single] => {
+                let (code, ty) = self.gen_expr(single)?;
+                codes.push(code);
+                match ty {
+                    RustType::Tuple(_) | RustType::Vec(_) => "{:?}".to_string(),
+                    _ => "{}".to_string(),
+                }
+            }
+            [AstNode::StringLiteral(template), rest @ ..] => {
+                for arg in rest {
+                    codes.push(self.gen_expr(arg)?.0);
+                }
+                template.clone()
+            }
+            _ => {
+                return Err(CompileError::UnsupportedFeature(format!(
+                    "{} with a non-literal format string",
+                    name
+                )))
+            }
+        };
+        let mut call = format!("{}!({:?}", name, template);
+        for code in codes {
+            call.push_str(", ");
+            call.push_str(&code);
+        }
+        call.push(')');
+        Ok(call)
+    }
+
+    /// Binding pattern for a `let`, marked `mut` if the name is reassigned
+    fn binding(&self, name: &str) -> String {
+        if self.mutated.contains(name) {
+            format!("mut {}", name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Binding pattern for a tuple `let`, defining each bound name with the
+    /// type of its element; nested tuples recurse (INTERP-136)
+    fn tuple_binding(&mut self, patterns: &[Pattern], ty: RustType) -> Result<String, CompileError> {
+        let element_types = match ty {
+            RustType::Tuple(elements) if elements.len() == patterns.len() => elements,
+            RustType::Tuple(elements) => {
+                return Err(CompileError::CodeGenError(format!(
+                    "cannot destructure a {}-tuple into {} names",
+                    elements.len(),
+                    patterns.len()
+                )))
+            }
+            _ => vec![RustType::Unknown; patterns.len()],
+        };
+        let mut parts = Vec::with_capacity(patterns.len());
+        for (pattern, ty) in patterns.iter().zip(element_types) {
+            parts.push(match pattern {
+                Pattern::Identifier(name) => {
+                    let binding = self.binding(name);
+                    self.define(name, ty);
+                    binding
+                }
+                Pattern::Wildcard => "_".to_string(),
+                Pattern::Tuple(inner) => self.tuple_binding(inner, ty)?,
+                other => {
+                    return Err(CompileError::UnsupportedFeature(format!(
+                        "{:?} pattern in tuple destructuring",
+                        other
+                    )))
+                }
+            });
+        }
+        Ok(match parts.as_slice() {
+            [single] => format!("({},)", single),
+            _ => format!("({})", parts.join(", ")),
+        })
+    }
+
+    /// Record the type of a local variable in the innermost scope
+    fn define(&mut self, name: &str, ty: RustType) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.to_string(), ty);
+        }
+    }
+
+    /// Type of a local variable, innermost scope first
+    fn lookup(&self, name: &str) -> RustType {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).cloned())
+            .unwrap_or(RustType::Unknown)
+    }
+
+    /// Emit a line of code with current indentation
     fn emit_line(&mut self, line: &str) {
         for _ in 0..self.indent {
             self.output.push_str("    ");
@@ -44,24 +743,18 @@ impl CodeGenerator {
 
     /// Emit raw code without indentation
     ///
-    /// Note: Will be used in COMPILE-002 for inline code emission
+    /// Note: Will be used for inline code emission
     #[allow(dead_code)]
     fn emit(&mut self, code: &str) {
         self.output.push_str(code);
     }
 
     /// Increase indentation level
-    ///
-    /// Note: Will be used in COMPILE-003 for block structures
-    #[allow(dead_code)]
     fn indent(&mut self) {
         self.indent += 1;
     }
 
     /// Decrease indentation level
-    ///
-    /// Note: Will be used in COMPILE-003 for block structures
-    #[allow(dead_code)]
     fn dedent(&mut self) {
         if self.indent > 0 {
             self.indent -= 1;
@@ -75,6 +768,88 @@ impl Default for CodeGenerator {
     }
 }
 
+/// Rust precedence level of comparison operators
+const COMPARISON: u8 = 3;
+
+/// Rust binding strength of a binary operator (higher binds tighter)
+fn rust_precedence(op: BinaryOperator) -> u8 {
+    match op {
+        BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => 9,
+        BinaryOperator::Add | BinaryOperator::Subtract => 8,
+        BinaryOperator::Shl | BinaryOperator::Shr => 7,
+        BinaryOperator::BitAnd => 6,
+        BinaryOperator::BitXor => 5,
+        BinaryOperator::BitOr => 4,
+        BinaryOperator::And => 2,
+        BinaryOperator::Or => 1,
+        _ => COMPARISON,
+    }
+}
+
+/// Whether a node produces a value (as opposed to a pure statement)
+fn is_expression(node: &AstNode) -> bool {
+    !matches!(
+        node,
+        AstNode::LetDecl { .. }
+            | AstNode::TupleDestruct { .. }
+            | AstNode::Assignment { .. }
+            | AstNode::CompoundAssignment { .. }
+            | AstNode::WhileLoop { .. }
+            | AstNode::ForLoop { .. }
+            | AstNode::RepeatUntil { .. }
+            | AstNode::Return { .. }
+            | AstNode::Break { .. }
+            | AstNode::Continue
+            | AstNode::FunctionDef { .. }
+            | AstNode::Block { .. }
+    )
+}
+
+/// Whether the last statement of a body is an explicit `return`
+fn body_ends_in_return(body: &[AstNode]) -> bool {
+    matches!(
+        body.last().map(AstNode::without_span),
+        Some(AstNode::Return { .. })
+    )
+}
+
+/// Names assigned anywhere in a function body
+fn mutated_names(body: &[AstNode]) -> HashSet<String> {
+    /// Collects assignment targets
+    struct Assigned(HashSet<String>);
+
+    impl Visitor for Assigned {
+        fn visit_node(&mut self, node: &AstNode) {
+            match node {
+                AstNode::Assignment { name, .. } => {
+                    self.0.insert(name.clone());
+                }
+                AstNode::CompoundAssignment { lhs, .. } => {
+                    if let AstNode::Identifier(name) = lhs.without_span() {
+                        self.0.insert(name.clone());
+                    }
+                }
+                _ => {}
+            }
+            walk_node(self, node);
+        }
+    }
+
+    let mut assigned = Assigned(HashSet::new());
+    assigned.visit_statements(body);
+    assigned.0
+}
+
+/// Error for a node kind the generator does not translate yet
+fn unsupported(node: &AstNode) -> CompileError {
+    let debug = format!("{:?}", node);
+    let kind = debug
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default();
+    CompileError::UnsupportedFeature(format!("{} in codegen", kind))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
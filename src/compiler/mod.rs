@@ -3,14 +3,14 @@
 // Transpiles Ruchy AST to Rust source code
 //
 // Architecture:
-// - codegen.rs: AST → Rust code generation
+// - codegen.rs: AST → Rust code generation (functions, expressions, tuples)
 // - emit.rs: Code formatting and emission (future)
 // - runtime.rs: Runtime library for compiled code (future)
 
 /// Code generation module
 pub mod codegen;
 
-pub use codegen::CodeGenerator;
+pub use codegen::{CodeGenerator, RustType};
 
 /// Compilation errors
 #[derive(Debug, Clone, PartialEq)]
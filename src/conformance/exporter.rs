@@ -10,7 +10,28 @@
 use std::fs;
 use std::fs::File;
 use std::io::Write;
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use super::runner::WORKER_STACK_SIZE;
+
+use crate::interpreter::ast::Pattern;
+use crate::interpreter::value::Value;
+use crate::interpreter::{AstNode, Evaluator, OutputSink, Parser};
+
+/// Built-in chapters: (number, name, expected test count)
+const CHAPTERS: &[(usize, &str, usize)] = &[
+    (1, "hello_world", 12),
+    (2, "variables", 19),
+    (3, "functions", 15),
+    (4, "practical_patterns", 24),
+    (5, "loops", 22),
+    (6, "data_structures", 28),
+    (10, "io", 18),
+];
 
 /// Conformance test suite exporter
 ///
@@ -58,20 +79,10 @@ impl ConformanceExporter {
     ///          result.test_count, result.chapters_exported);
     /// ```
     pub fn export_all_chapters(&self) -> Result<ExportResult, ExportError> {
-        let chapters = vec![
-            (1, "hello_world", 12),
-            (2, "variables", 19),
-            (3, "functions", 15),
-            (4, "practical_patterns", 24),
-            (5, "loops", 22),
-            (6, "data_structures", 28),
-            (10, "io", 18),
-        ];
-
         let mut total_tests = 0;
         let mut chapters_exported = 0;
 
-        for (chapter_num, chapter_name, expected_count) in chapters {
+        for &(chapter_num, chapter_name, expected_count) in CHAPTERS {
             match self.export_chapter(chapter_num, chapter_name, expected_count) {
                 Ok(result) => {
                     total_tests += result.test_count;
@@ -89,6 +100,104 @@ impl ConformanceExporter {
         })
     }
 
+    /// Export all chapters, writing test files on parallel worker threads
+    ///
+    /// Produces the same files and counts as [`export_all_chapters`]. Test
+    /// cases are extracted first; the files of all chapters are then written
+    /// concurrently, one worker per available CPU. `progress` is called with
+    /// `(done, total)` after each file, from the worker that wrote it; calls
+    /// are serialized and `done` increases by one each time.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ExportResult)`: Export succeeded with statistics
+    /// - `Err(ExportError)`: A file could not be written (the first failing
+    ///   file in chapter order is reported)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ruchyruchy::conformance::ConformanceExporter;
+    ///
+    /// let exporter = ConformanceExporter::new();
+    /// let result = exporter
+    ///     .export_all_chapters_parallel(|done, total| eprint!("\r{}/{}", done, total))
+    ///     .unwrap();
+    /// println!("\nExported {} tests", result.test_count);
+    /// ```
+    ///
+    /// [`export_all_chapters`]: Self::export_all_chapters
+    pub fn export_all_chapters_parallel(
+        &self,
+        progress: impl Fn(usize, usize) + Sync,
+    ) -> Result<ExportResult, ExportError> {
+        let mut jobs: Vec<(PathBuf, TestCase)> = Vec::new();
+        let mut chapters_exported = 0;
+        for &(chapter_num, chapter_name, _) in CHAPTERS {
+            let test_cases = match self.extract_test_cases(chapter_num, chapter_name) {
+                Ok(test_cases) => test_cases,
+                Err(e) => {
+                    eprintln!("Warning: Failed to export chapter {}: {:?}", chapter_num, e);
+                    continue;
+                }
+            };
+            let chapter_dir = self.create_chapter_dir(chapter_num, chapter_name)?;
+            for (idx, test_case) in test_cases.into_iter().enumerate() {
+                jobs.push((test_file_path(&chapter_dir, idx, &test_case), test_case));
+            }
+            chapters_exported += 1;
+        }
+
+        let total = jobs.len();
+        let next_job = AtomicUsize::new(0);
+        let done = Mutex::new(0);
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .clamp(1, total.max(1));
+
+        // (job index, error) for every failed file
+        let mut failures: Vec<(usize, ExportError)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    thread::Builder::new()
+                        .stack_size(WORKER_STACK_SIZE)
+                        .spawn_scoped(scope, || {
+                            let mut failures = Vec::new();
+                            loop {
+                                let index = next_job.fetch_add(1, Ordering::Relaxed);
+                                let Some((path, test_case)) = jobs.get(index) else {
+                                    break;
+                                };
+                                if let Err(e) = self.write_test_file(path, test_case) {
+                                    failures.push((index, e));
+                                }
+                                let mut done = done.lock().unwrap_or_else(|e| e.into_inner());
+                                *done += 1;
+                                progress(*done, total);
+                            }
+                            failures
+                        })
+                        .expect("failed to spawn export worker")
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("export worker panicked"))
+                .collect()
+        });
+
+        failures.sort_by_key(|(index, _)| *index);
+        if let Some((_, e)) = failures.into_iter().next() {
+            return Err(e);
+        }
+
+        Ok(ExportResult {
+            test_count: total,
+            chapters_exported,
+        })
+    }
+
     /// Export a single chapter
     ///
     /// # Arguments
@@ -119,21 +228,92 @@ impl ConformanceExporter {
     ) -> Result<ExportResult, ExportError> {
         // Extract test cases from corresponding test file
         let test_cases = self.extract_test_cases(chapter_num, chapter_name)?;
+        self.write_chapter(chapter_num, chapter_name, &test_cases)
+    }
 
-        // Create chapter directory
-        let chapter_dir = self
-            .output_dir
-            .join(format!("chapter_{:02}_{}", chapter_num, chapter_name));
-        fs::create_dir_all(&chapter_dir)
-            .map_err(|e| ExportError::IoError(format!("Failed to create directory: {}", e)))?;
+    /// Export the test cases of an external directory as chapter 00
+    ///
+    /// Imports `dir` with [`import_from_dir`](Self::import_from_dir) and
+    /// writes the cases to `chapter_00_<dir name>/` next to the built-in
+    /// chapters, so they are exported and re-imported like the rest of the
+    /// suite.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use ruchyruchy::conformance::ConformanceExporter;
+    ///
+    /// let exporter = ConformanceExporter::new();
+    /// let result = exporter.export_imported_dir(Path::new("my_conformance")).unwrap();
+    /// println!("Exported {} project tests", result.test_count);
+    /// ```
+    pub fn export_imported_dir(&self, dir: &Path) -> Result<ExportResult, ExportError> {
+        let test_cases = self.import_from_dir(dir)?;
+        self.write_chapter(0, &external_chapter_name(dir), &test_cases)
+    }
+
+    /// Import test cases from a directory of `.ruchy` files
+    ///
+    /// Each `name.ruchy` needs a companion `name.expected` file holding the
+    /// program's expected output, one line per output line. Files are read in
+    /// name order; subdirectories are not scanned. Imported cases are chapter
+    /// 0, named after the directory.
+    ///
+    /// # Errors
+    ///
+    /// - `MissingExpectedOutput`: a `.ruchy` file has no `.expected` file
+    /// - `IoError`: the directory or a file cannot be read
+    pub fn import_from_dir(&self, dir: &Path) -> Result<Vec<TestCase>, ExportError> {
+        let chapter_name = external_chapter_name(dir);
+        let mut test_cases = Vec::new();
+        for source_file in sorted_entries(dir, "")? {
+            if source_file.extension().is_none_or(|ext| ext != "ruchy") {
+                continue;
+            }
+            let expected_file = source_file.with_extension("expected");
+            if !expected_file.is_file() {
+                return Err(ExportError::MissingExpectedOutput {
+                    source_file,
+                    expected_file,
+                });
+            }
+            let read = |path: &Path| {
+                fs::read_to_string(path).map_err(|e| {
+                    ExportError::IoError(format!("Failed to read {}: {}", path.display(), e))
+                })
+            };
+            let source_code = read(&source_file)?;
+            let expected = read(&expected_file)?;
+
+            let name = source_file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            test_cases.push(TestCase {
+                name,
+                chapter: 0,
+                chapter_name: chapter_name.clone(),
+                description: format!("Imported from {}", source_file.display()),
+                source_code: source_code.trim_end().to_string(),
+                expected_output: expected.lines().map(str::to_string).collect(),
+            });
+        }
+        Ok(test_cases)
+    }
+
+    /// Write test cases to `chapter_NN_name/`
+    fn write_chapter(
+        &self,
+        chapter_num: usize,
+        chapter_name: &str,
+        test_cases: &[TestCase],
+    ) -> Result<ExportResult, ExportError> {
+        let chapter_dir = self.create_chapter_dir(chapter_num, chapter_name)?;
 
         // Export each test case
         for (idx, test_case) in test_cases.iter().enumerate() {
-            let test_file = chapter_dir.join(format!(
-                "test_{:03}_{}.ruchy",
-                idx + 1,
-                sanitize_filename(&test_case.name)
-            ));
+            let test_file = test_file_path(&chapter_dir, idx, test_case);
             self.write_test_file(&test_file, test_case)?;
         }
 
@@ -143,6 +323,46 @@ impl ConformanceExporter {
         })
     }
 
+    /// Create the `chapter_NN_name/` directory
+    fn create_chapter_dir(
+        &self,
+        chapter_num: usize,
+        chapter_name: &str,
+    ) -> Result<PathBuf, ExportError> {
+        let chapter_dir = self
+            .output_dir
+            .join(format!("chapter_{:02}_{}", chapter_num, chapter_name));
+        fs::create_dir_all(&chapter_dir)
+            .map_err(|e| ExportError::IoError(format!("Failed to create directory: {}", e)))?;
+        Ok(chapter_dir)
+    }
+
+    /// Import previously exported test files from `output_dir`
+    ///
+    /// Reads every `chapter_*/test_*.ruchy` file, in chapter then file order,
+    /// and parses it back with [`parse_exported_test`].
+    pub fn import_exported(&self) -> Result<Vec<TestCase>, ExportError> {
+        let mut test_cases = Vec::new();
+        for chapter_dir in sorted_entries(&self.output_dir, "chapter_")? {
+            if !chapter_dir.is_dir() {
+                continue;
+            }
+            for test_file in sorted_entries(&chapter_dir, "test_")? {
+                if test_file.extension().is_some_and(|ext| ext == "ruchy") {
+                    let content = fs::read_to_string(&test_file).map_err(|e| {
+                        ExportError::IoError(format!(
+                            "Failed to read test file {}: {}",
+                            test_file.display(),
+                            e
+                        ))
+                    })?;
+                    test_cases.push(parse_exported_test(&content)?);
+                }
+            }
+        }
+        Ok(test_cases)
+    }
+
     /// Extract test cases from interpreter test files
     fn extract_test_cases(
         &self,
@@ -204,6 +424,18 @@ impl ConformanceExporter {
         writeln!(file, "{}", test_case.source_code)
             .map_err(|e| ExportError::IoError(format!("Failed to write source code: {}", e)))?;
 
+        // Self-check assertions, so the file validates itself under any implementation
+        let assertions = golden_assertions(&test_case.source_code);
+        if !assertions.is_empty() {
+            writeln!(file, "\n// Self-check: golden values captured by the RuchyRuchy interpreter")
+                .map_err(|e| ExportError::IoError(format!("Failed to write self-check: {}", e)))?;
+            for assertion in &assertions {
+                writeln!(file, "{}", assertion).map_err(|e| {
+                    ExportError::IoError(format!("Failed to write self-check: {}", e))
+                })?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -232,6 +464,13 @@ pub enum ExportError {
     InvalidChapter(usize),
     /// Parse error
     ParseError(String),
+    /// Imported `.ruchy` file without its `.expected` output file
+    MissingExpectedOutput {
+        /// The test source
+        source_file: PathBuf,
+        /// Where the expected output was looked for
+        expected_file: PathBuf,
+    },
 }
 
 impl std::fmt::Display for ExportError {
@@ -240,6 +479,15 @@ impl std::fmt::Display for ExportError {
             ExportError::IoError(msg) => write!(f, "I/O error: {}", msg),
             ExportError::InvalidChapter(ch) => write!(f, "Invalid chapter: {}", ch),
             ExportError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+            ExportError::MissingExpectedOutput {
+                source_file,
+                expected_file,
+            } => write!(
+                f,
+                "Missing expected output for {}: create {} with the program's output",
+                source_file.display(),
+                expected_file.display()
+            ),
         }
     }
 }
@@ -263,6 +511,90 @@ pub struct TestCase {
     pub expected_output: Vec<String>,
 }
 
+/// Parse a test file written by the exporter back into a test case
+///
+/// The self-check assertions appended on export are not part of the
+/// original program and are dropped from `source_code`.
+pub fn parse_exported_test(content: &str) -> Result<TestCase, ExportError> {
+    let mut lines = content.lines();
+    let mut header = |prefix: &str| {
+        lines
+            .next()
+            .and_then(|line| line.strip_prefix(prefix))
+            .map(str::to_string)
+            .ok_or_else(|| ExportError::ParseError(format!("missing '{}' header", prefix)))
+    };
+
+    let name = header("// Test: ")?;
+    let chapter_line = header("// Chapter: ")?;
+    let description = header("// Description: ")?;
+    header("//")?;
+    header("// Expected Output:")?;
+
+    let (chapter, chapter_name) = chapter_line
+        .split_once(" - ")
+        .and_then(|(num, name)| Some((num.parse().ok()?, name.to_string())))
+        .ok_or_else(|| ExportError::ParseError(format!("bad chapter line: {}", chapter_line)))?;
+
+    let mut expected_output = Vec::new();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        let output = line.strip_prefix("//").ok_or_else(|| {
+            ExportError::ParseError(format!("bad expected output line: {}", line))
+        })?;
+        expected_output.push(output.strip_prefix(' ').unwrap_or(output).to_string());
+    }
+
+    let source: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("// Self-check:"))
+        .collect();
+
+    Ok(TestCase {
+        name,
+        chapter,
+        chapter_name,
+        description,
+        source_code: source.join("\n").trim_end().to_string(),
+        expected_output,
+    })
+}
+
+/// Directory entries whose file name starts with `prefix`, sorted by path
+fn sorted_entries(dir: &Path, prefix: &str) -> Result<Vec<PathBuf>, ExportError> {
+    let entries = fs::read_dir(dir).map_err(|e| {
+        ExportError::IoError(format!("Failed to read directory {}: {}", dir.display(), e))
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(prefix))
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Path of the `idx`-th (0-based) test file of a chapter
+fn test_file_path(chapter_dir: &Path, idx: usize, test_case: &TestCase) -> PathBuf {
+    chapter_dir.join(format!(
+        "test_{:03}_{}.ruchy",
+        idx + 1,
+        sanitize_filename(&test_case.name)
+    ))
+}
+
+/// Chapter name for an imported directory: its sanitized name
+fn external_chapter_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|name| sanitize_filename(&name.to_string_lossy()))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "external".to_string())
+}
+
 /// Parse test cases from Rust test file
 ///
 /// Extracts Ruchy source code from Rust test functions.
@@ -371,6 +703,102 @@ fn parse_rust_test_file(
     Ok(test_cases)
 }
 
+/// In-language `assert_eq` checks of a program's golden values
+///
+/// Runs the program with the RuchyRuchy interpreter and returns one
+/// `assert_eq` statement per top-level `let` binding (checking its final
+/// value), plus one for the return value of a parameterless `main`. Appended
+/// to the source, they make the program fail under any implementation that
+/// computes different values.
+///
+/// Values without a literal form (nil, maps, structs, functions, non-finite
+/// floats, strings containing quotes) are skipped. A program that fails to
+/// parse or run yields no assertions.
+///
+/// # Example
+///
+/// ```
+/// use ruchyruchy::conformance::golden_assertions;
+///
+/// let checks = golden_assertions("let total = 2 + 3;\nlet name = \"ruchy\";");
+/// assert_eq!(checks, vec!["assert_eq(total, 5)", "assert_eq(name, \"ruchy\")"]);
+/// ```
+pub fn golden_assertions(source: &str) -> Vec<String> {
+    let Ok(ast) = Parser::new(source).parse() else {
+        return Vec::new();
+    };
+    let mut eval = Evaluator::new().with_output(OutputSink::buffer());
+    if eval.eval_program(&ast).is_err() {
+        return Vec::new();
+    }
+
+    let mut names: Vec<&str> = Vec::new();
+    let mut has_main = false;
+    for node in ast.nodes() {
+        match node {
+            AstNode::LetDecl { name, .. } => names.push(name),
+            AstNode::TupleDestruct { patterns, .. } => {
+                patterns.iter().for_each(|p| pattern_names(p, &mut names))
+            }
+            AstNode::FunctionDef { name, params, .. } if name == "main" => {
+                has_main = params.is_empty()
+            }
+            _ => {}
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut assertions: Vec<String> = names
+        .into_iter()
+        .filter(|name| *name != "_" && seen.insert(*name))
+        .filter_map(|name| {
+            let literal = value_literal(&eval.get_variable(name)?)?;
+            Some(format!("assert_eq({}, {})", name, literal))
+        })
+        .collect();
+
+    if has_main {
+        let call = AstNode::FunctionCall {
+            name: "main".to_string(),
+            args: Vec::new(),
+        };
+        if let Some(literal) = eval.eval(&call).ok().as_ref().and_then(value_literal) {
+            assertions.push(format!("assert_eq(main(), {})", literal));
+        }
+    }
+
+    assertions
+}
+
+/// Collect the names a tuple binding pattern binds, in source order
+fn pattern_names<'a>(pattern: &'a Pattern, names: &mut Vec<&'a str>) {
+    match pattern {
+        Pattern::Identifier(name) => names.push(name),
+        Pattern::Tuple(elements) => elements.iter().for_each(|p| pattern_names(p, names)),
+        _ => {}
+    }
+}
+
+/// Render a value as Ruchy source that evaluates back to an equal value
+fn value_literal(value: &Value) -> Option<String> {
+    let list = |items: &[Value]| -> Option<Vec<String>> { items.iter().map(value_literal).collect() };
+    match value {
+        // i64::MIN has no literal: its magnitude overflows before negation
+        Value::Integer(n) if *n != i64::MIN => Some(n.to_string()),
+        Value::Float(f) if f.is_finite() => {
+            let text = f.to_string();
+            // Keep a decimal point so the literal stays a float
+            Some(if text.contains('.') { text } else { format!("{}.0", text) })
+        }
+        Value::String(s) if !s.contains('"') => Some(format!("\"{}\"", s)),
+        Value::Boolean(b) => Some(b.to_string()),
+        Value::Vector(items) => Some(format!("[{}]", list(items)?.join(", "))),
+        // A one-element tuple literal would parse as a parenthesized value
+        Value::Tuple(items) if items.len() > 1 => Some(format!("({})", list(items)?.join(", "))),
+        _ => None,
+    }
+}
+
 /// Sanitize filename for filesystem
 ///
 /// Converts test name to valid filename by:
@@ -15,6 +15,8 @@
 //! - **TestCase**: Represents a single test case
 //! - **ExportResult**: Statistics from export operation
 //! - **ExportError**: Error types for export failures
+//! - **ConformanceRunner**: Runs test cases in parallel with isolated output
+//! - **RunSummary** / **TestOutcome**: Aggregated and per-test run results
 //!
 //! # Usage
 //!
@@ -27,6 +29,13 @@
 //!          result.test_count, result.chapters_exported);
 //! ```
 //!
+//! `export_all_chapters_parallel` writes the same files on parallel worker
+//! threads and reports `(done, total)` to a progress callback after each one.
+//!
+//! Projects can add their own cases: a directory of `.ruchy` files, each
+//! with a companion `.expected` output file, is read with
+//! `import_from_dir` and exported as chapter 00 with `export_imported_dir`.
+//!
 //! # Output Format
 //!
 //! Exported tests are organized by chapter:
@@ -45,9 +54,16 @@
 //! - Metadata (test name, chapter, description)
 //! - Expected output
 //! - Ruchy source code
+//! - Self-check `assert_eq` statements pinning the interpreter's golden values
 
 /// Conformance exporter implementation
 pub mod exporter;
+/// Parallel conformance test runner
+pub mod runner;
 
 // Re-export main types
-pub use exporter::{ConformanceExporter, ExportError, ExportResult, TestCase};
+pub use exporter::{
+    golden_assertions, parse_exported_test, ConformanceExporter, ExportError, ExportResult,
+    TestCase,
+};
+pub use runner::{ConformanceRunner, RunSummary, TestOutcome};
@@ -0,0 +1,287 @@
+//! Conformance Test Runner
+//!
+//! Executes conformance test cases against the RuchyRuchy interpreter
+//! using a pool of worker threads.
+//!
+//! Each test case runs in its own `Evaluator` with a buffered output
+//! sink, so captured stdout never mixes between tests. A panic inside
+//! one test is caught and recorded as a failure for that test only.
+//! Outcomes are sorted by test id, so reports are deterministic
+//! regardless of scheduling.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::exporter::TestCase;
+use crate::interpreter::{Evaluator, OutputSink, Parser};
+
+/// Stack size for worker threads (deeply recursive programs need headroom)
+pub(super) const WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Parallel conformance test runner
+///
+/// # Example
+///
+/// ```
+/// use ruchyruchy::conformance::{ConformanceRunner, TestCase};
+///
+/// let case = TestCase {
+///     name: "test_hello".to_string(),
+///     chapter: 1,
+///     chapter_name: "hello_world".to_string(),
+///     description: "Prints hello".to_string(),
+///     source_code: "println(\"Hello\")".to_string(),
+///     expected_output: vec!["Hello".to_string()],
+/// };
+///
+/// let summary = ConformanceRunner::new().with_workers(2).run(&[case]);
+/// assert_eq!(summary.passed(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConformanceRunner {
+    /// Number of worker threads
+    pub workers: usize,
+}
+
+impl ConformanceRunner {
+    /// Create a runner with one worker per available CPU
+    pub fn new() -> Self {
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        Self { workers }
+    }
+
+    /// Set the number of worker threads (at least one is always used)
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Run all test cases and return outcomes sorted by test id
+    pub fn run(&self, cases: &[TestCase]) -> RunSummary {
+        self.run_with(cases, run_case)
+    }
+
+    /// Run test cases with a custom executor (returns captured output and failure)
+    fn run_with<F>(&self, cases: &[TestCase], execute: F) -> RunSummary
+    where
+        F: Fn(&TestCase) -> (String, Option<String>) + Sync,
+    {
+        let start = Instant::now();
+        let next_case = AtomicUsize::new(0);
+        let workers = self.workers.clamp(1, cases.len().max(1));
+
+        let mut outcomes: Vec<TestOutcome> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    thread::Builder::new()
+                        .stack_size(WORKER_STACK_SIZE)
+                        .spawn_scoped(scope, || {
+                            let mut local = Vec::new();
+                            loop {
+                                let index = next_case.fetch_add(1, Ordering::Relaxed);
+                                let Some(case) = cases.get(index) else {
+                                    break;
+                                };
+                                local.push(run_isolated(case, &execute));
+                            }
+                            local
+                        })
+                        .expect("failed to spawn conformance worker")
+                })
+                .collect();
+
+            // Test panics are caught in run_isolated, so a worker panic is a
+            // runner bug; propagate it rather than drop the worker's outcomes
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+                })
+                .collect()
+        });
+
+        outcomes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        RunSummary {
+            outcomes,
+            wall_time: start.elapsed(),
+        }
+    }
+}
+
+impl Default for ConformanceRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of running a single test case
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestOutcome {
+    /// Test id (`chNN/name`), used for report ordering
+    pub id: String,
+    /// Whether the test passed
+    pub passed: bool,
+    /// Output captured from the program
+    pub output: String,
+    /// Failure reason (parse/eval error, output mismatch, or panic)
+    pub error: Option<String>,
+    /// Time spent running this test
+    pub duration: Duration,
+}
+
+/// Aggregated results of a conformance run
+#[derive(Debug, Clone)]
+pub struct RunSummary {
+    /// Per-test outcomes, sorted by test id
+    pub outcomes: Vec<TestOutcome>,
+    /// Total wall-clock time for the run
+    pub wall_time: Duration,
+}
+
+impl RunSummary {
+    /// Number of passing tests
+    pub fn passed(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.passed).count()
+    }
+
+    /// Number of failing tests
+    pub fn failed(&self) -> usize {
+        self.outcomes.len() - self.passed()
+    }
+
+    /// Render a human-readable report with per-test timings
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+        for outcome in &self.outcomes {
+            let status = if outcome.passed { "PASS" } else { "FAIL" };
+            report.push_str(&format!(
+                "{} {} ({:.3}ms)\n",
+                status,
+                outcome.id,
+                outcome.duration.as_secs_f64() * 1000.0
+            ));
+            if let Some(error) = &outcome.error {
+                report.push_str(&format!("    {}\n", error));
+            }
+        }
+        report.push_str(&format!(
+            "{} passed, {} failed, {} total in {:.3}s\n",
+            self.passed(),
+            self.failed(),
+            self.outcomes.len(),
+            self.wall_time.as_secs_f64()
+        ));
+        report
+    }
+}
+
+/// Stable id for a test case, used to order reports
+fn test_id(case: &TestCase) -> String {
+    format!("ch{:02}/{}", case.chapter, case.name)
+}
+
+/// Run one test case, converting a panic into a failed outcome
+fn run_isolated<F>(case: &TestCase, execute: &F) -> TestOutcome
+where
+    F: Fn(&TestCase) -> (String, Option<String>),
+{
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| execute(case)));
+    let duration = start.elapsed();
+
+    let (output, error) = match result {
+        Ok(outcome) => outcome,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            (String::new(), Some(format!("panicked: {}", message)))
+        }
+    };
+
+    TestOutcome {
+        id: test_id(case),
+        passed: error.is_none(),
+        output,
+        error,
+        duration,
+    }
+}
+
+/// Parse and evaluate a test case, returning captured output and any failure
+fn run_case(case: &TestCase) -> (String, Option<String>) {
+    let sink = OutputSink::buffer();
+    let captured = |sink: &OutputSink| sink.contents().unwrap_or_default();
+
+    let ast = match Parser::new(&case.source_code).with_spans().parse() {
+        Ok(ast) => ast,
+        Err(e) => return (String::new(), Some(format!("parse error: {}", e))),
+    };
+
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    if let Err(e) = eval.eval_program(&ast) {
+        return (captured(&sink), Some(format!("eval error: {}", e)));
+    }
+
+    let output = captured(&sink);
+    if !case.expected_output.is_empty() {
+        let actual: Vec<&str> = output.lines().collect();
+        if actual != case.expected_output {
+            return (
+                output.clone(),
+                Some(format!(
+                    "output mismatch: expected {:?}, got {:?}",
+                    case.expected_output, actual
+                )),
+            );
+        }
+    }
+
+    (output, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(name: &str, source: &str) -> TestCase {
+        TestCase {
+            name: name.to_string(),
+            chapter: 1,
+            chapter_name: "unit".to_string(),
+            description: String::new(),
+            source_code: source.to_string(),
+            expected_output: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_panicking_test_is_isolated() {
+        let cases = vec![case("a_ok", "1"), case("b_panics", "2"), case("c_ok", "3")];
+        let summary = ConformanceRunner::new().with_workers(2).run_with(&cases, |c| {
+            if c.name == "b_panics" {
+                panic!("boom");
+            }
+            run_case(c)
+        });
+
+        assert_eq!(summary.passed(), 2);
+        let failed = &summary.outcomes[1];
+        assert_eq!(failed.id, "ch01/b_panics");
+        assert_eq!(failed.error.as_deref(), Some("panicked: boom"));
+    }
+
+    #[test]
+    fn test_with_workers_is_at_least_one() {
+        assert_eq!(ConformanceRunner::new().with_workers(0).workers, 1);
+    }
+}
@@ -206,7 +206,9 @@ fn ast_to_json(nodes: &[AstNode], indent: usize) -> String {
 fn ast_node_to_json(node: &AstNode, indent: usize) -> String {
     let indent_str = "  ".repeat(indent);
     match node {
-        AstNode::FunctionDef { name, params, body } => {
+        AstNode::FunctionDef {
+            name, params, body, ..
+        } => {
             let mut body_json = String::from("[\n");
             for (i, stmt) in body.iter().enumerate() {
                 if i > 0 {
@@ -225,7 +227,7 @@ fn ast_node_to_json(node: &AstNode, indent: usize) -> String {
                 indent_str, indent_str, name, indent_str, params, indent_str, body_json, indent_str
             )
         }
-        AstNode::LetDecl { name, value } => {
+        AstNode::LetDecl { name, value, .. } => {
             format!(
                 "{{\n{}  \"type\": \"LetDecl\",\n{}  \"name\": \"{}\",\n{}  \"value\": {}\n{}}}",
                 indent_str,
@@ -324,10 +326,12 @@ fn node_diff(node1: &AstNode, node2: &AstNode) -> String {
             AstNode::LetDecl {
                 name: n1,
                 value: v1,
+                ..
             },
             AstNode::LetDecl {
                 name: n2,
                 value: v2,
+                ..
             },
         ) => {
             let mut diff = String::new();
@@ -66,7 +66,17 @@ pub struct CoverageStats {
 /// # Returns
 /// Result containing function return value or error message
 pub fn run_interpreter(source: &str, function_name: &str, args: &[i64]) -> Result<i64, String> {
-    use crate::interpreter::{AstNode, Evaluator, Parser, Value};
+    run_interpreter_with(crate::interpreter::Evaluator::new(), source, function_name, args)
+}
+
+/// Run code through a preconfigured interpreter (e.g. with captured output)
+pub(crate) fn run_interpreter_with(
+    mut evaluator: crate::interpreter::Evaluator,
+    source: &str,
+    function_name: &str,
+    args: &[i64],
+) -> Result<i64, String> {
+    use crate::interpreter::{AstNode, Parser, Value};
 
     // Parse source code
     let mut parser = Parser::new(source);
@@ -74,8 +84,7 @@ pub fn run_interpreter(source: &str, function_name: &str, args: &[i64]) -> Resul
         .parse()
         .map_err(|e| format!("Parse error: {:?}", e))?;
 
-    // Create evaluator and load program (registers functions)
-    let mut evaluator = Evaluator::new();
+    // Load program (registers functions)
     evaluator
         .eval_program(&ast)
         .map_err(|e| format!("Eval error loading program: {:?}", e))?;
@@ -93,10 +102,12 @@ pub fn run_interpreter(source: &str, function_name: &str, args: &[i64]) -> Resul
         .eval(&func_call)
         .map_err(|e| format!("Function call error: {:?}", e))?;
 
-    // Extract integer result (convert booleans to integers)
+    // Extract integer result (convert booleans to integers, and nil to 0 -
+    // the JIT represents statement values such as loops as 0)
     match result {
         Value::Integer(i) => Ok(i),
         Value::Boolean(b) => Ok(if b { 1 } else { 0 }),
+        Value::Nil => Ok(0),
         other => Err(format!(
             "Expected integer or boolean result, got {:?}",
             other
@@ -107,6 +118,9 @@ pub fn run_interpreter(source: &str, function_name: &str, args: &[i64]) -> Resul
 /// Run code through JIT compiler
 ///
 /// Executes Ruchy code using Cranelift JIT and returns the result.
+/// Other functions in the source are compiled first (in source order) so the
+/// entry function can call them; helpers the JIT cannot compile are left out,
+/// making calls to them a compile error.
 ///
 /// # Arguments
 /// * `source` - Ruchy source code
@@ -125,6 +139,22 @@ pub fn run_jit(source: &str, function_name: &str, args: &[i64]) -> Result<i64, S
         .parse()
         .map_err(|e| format!("Parse error: {:?}", e))?;
 
+    // Create JIT compiler
+    let mut jit = JitCompiler::new().map_err(|e| format!("JIT creation error: {:?}", e))?;
+
+    // Compile and register the helpers the entry function may call
+    for node in ast.nodes() {
+        if let AstNode::FunctionDef {
+            name, params, body, ..
+        } = node
+        {
+            if name != function_name {
+                // Registers itself under `name` on success
+                let _ = jit.compile_named_function::<*const u8>(name, params, &body_node(body));
+            }
+        }
+    }
+
     // Find the function definition
     let nodes = ast.nodes();
     let func_def = nodes
@@ -138,34 +168,25 @@ pub fn run_jit(source: &str, function_name: &str, args: &[i64]) -> Result<i64, S
         _ => unreachable!(),
     };
 
-    // Convert body vec to single node (wrap in Block if multiple statements)
-    let body_node = if body.len() == 1 {
-        body[0].clone()
-    } else {
-        // Wrap multiple statements in a Block node
-        AstNode::Block { statements: body }
-    };
-
-    // Create JIT compiler
-    let mut jit = JitCompiler::new().map_err(|e| format!("JIT creation error: {:?}", e))?;
+    let body_node = body_node(&body);
 
     // Compile function based on argument count
     let result = match args.len() {
         0 => {
             let compiled: fn() -> i64 = jit
-                .compile_function_with_params(&param_names, &body_node)
+                .compile_named_function(function_name, &param_names, &body_node)
                 .map_err(|e| format!("JIT compile error: {:?}", e))?;
             compiled()
         }
         1 => {
             let compiled: fn(i64) -> i64 = jit
-                .compile_function_with_params(&param_names, &body_node)
+                .compile_named_function(function_name, &param_names, &body_node)
                 .map_err(|e| format!("JIT compile error: {:?}", e))?;
             compiled(args[0])
         }
         2 => {
             let compiled: fn(i64, i64) -> i64 = jit
-                .compile_function_with_params(&param_names, &body_node)
+                .compile_named_function(function_name, &param_names, &body_node)
                 .map_err(|e| format!("JIT compile error: {:?}", e))?;
             compiled(args[0], args[1])
         }
@@ -180,6 +201,17 @@ pub fn run_jit(source: &str, function_name: &str, args: &[i64]) -> Result<i64, S
     Ok(result)
 }
 
+/// Convert a function body to a single node (wrap in Block if multiple statements)
+fn body_node(body: &[crate::interpreter::AstNode]) -> crate::interpreter::AstNode {
+    if body.len() == 1 {
+        body[0].clone()
+    } else {
+        crate::interpreter::AstNode::Block {
+            statements: body.to_vec(),
+        }
+    }
+}
+
 /// Compare interpreter and JIT results (Jidoka validation)
 ///
 /// Compares execution results from interpreter and JIT to detect discrepancies.
@@ -361,7 +361,7 @@ fn identify_bottlenecks(
     }
 
     // Sort by duration (highest first)
-    bottlenecks.sort_by(|a, b| b.duration_ns.cmp(&a.duration_ns));
+    bottlenecks.sort_by_key(|b| std::cmp::Reverse(b.duration_ns));
 
     bottlenecks
 }
@@ -10,7 +10,7 @@
 //
 // Research-backed: bashrs shows 10x faster debugging with REPL approach
 
-use crate::interpreter::{Ast, Evaluator, Parser};
+use crate::interpreter::{Ast, Evaluator, Frame, Parser};
 use std::collections::HashSet;
 
 /// Debug commands available in REPL
@@ -22,6 +22,8 @@ pub enum DebugCommand {
     Print(String),
     /// Set breakpoint at line
     Break(usize),
+    /// Set breakpoint at a source line (1-indexed), also inside functions
+    BreakAt(usize),
     /// Run until next breakpoint or completion
     Continue,
     /// Show current AST node
@@ -58,6 +60,10 @@ pub struct DebugSession {
     history: Vec<ExecutionSnapshot>,
     /// Whether execution is finished
     finished: bool,
+    /// Source line of the last failed assertion, if any
+    error_line: Option<usize>,
+    /// Frames captured at the source-line breakpoint hit by the last statement
+    paused_frames: Option<(usize, Vec<Frame>)>,
 }
 
 impl DebugSession {
@@ -65,12 +71,14 @@ impl DebugSession {
     ///
     /// Parses the source code and prepares for interactive debugging.
     pub fn new(source: &str) -> Result<Self, String> {
-        let mut parser = Parser::new(source);
+        // Spans let failed assertions report their source line
+        let mut parser = Parser::new(source).with_spans();
         let ast = parser
             .parse()
             .map_err(|e| format!("Parse error: {:?}", e))?;
 
-        let evaluator = Evaluator::new();
+        // Frame inspection lets :backtrace show each frame's locals
+        let evaluator = Evaluator::new().with_frame_inspection();
 
         // Save initial state in history (deep clone for independent snapshot)
         let initial_snapshot = ExecutionSnapshot {
@@ -85,6 +93,8 @@ impl DebugSession {
             breakpoints: HashSet::new(),
             history: vec![initial_snapshot],
             finished: false,
+            error_line: None,
+            paused_frames: None,
         })
     }
 
@@ -93,6 +103,24 @@ impl DebugSession {
         self.current_line
     }
 
+    /// Source line (1-indexed) of the assertion that stopped execution
+    ///
+    /// Execution stays on the failing statement, so `Ast` shows it and a
+    /// breakpoint can be set there before rewinding.
+    pub fn error_line(&self) -> Option<usize> {
+        self.error_line
+    }
+
+    /// Frames active when the last statement hit a source-line breakpoint
+    ///
+    /// Outermost first, each with its variables, so a breakpoint inside a
+    /// recursive function shows the parameters of every level.
+    pub fn paused_frames(&self) -> Option<&[Frame]> {
+        self.paused_frames
+            .as_ref()
+            .map(|(_, frames)| frames.as_slice())
+    }
+
     /// Check if execution is finished
     pub fn is_finished(&self) -> bool {
         self.finished
@@ -109,6 +137,7 @@ impl DebugSession {
             DebugCommand::Step => self.cmd_step(),
             DebugCommand::Print(var) => self.cmd_print(&var),
             DebugCommand::Break(line) => self.cmd_break(line),
+            DebugCommand::BreakAt(line) => self.cmd_break_at(line),
             DebugCommand::Continue => self.cmd_continue(),
             DebugCommand::Ast => self.cmd_ast(),
             DebugCommand::Backtrace => self.cmd_backtrace(),
@@ -123,19 +152,16 @@ impl DebugSession {
             return Err("Execution already finished".to_string());
         }
 
-        // Get nodes from AST
-        let nodes = self.ast.nodes();
+        // Get statement count from AST
+        let statement_count = self.ast.nodes().len();
 
-        if self.current_line >= nodes.len() {
+        if self.current_line >= statement_count {
             self.finished = true;
             return Ok("Execution complete".to_string());
         }
 
         // Execute current statement
-        let node = &nodes[self.current_line];
-        self.evaluator
-            .eval(node)
-            .map_err(|e| format!("Evaluation error: {:?}", e))?;
+        self.eval_current()?;
 
         // Save snapshot before advancing
         self.current_line += 1;
@@ -147,13 +173,33 @@ impl DebugSession {
         self.history.push(snapshot);
 
         // Check if finished
-        if self.current_line >= nodes.len() {
+        if self.current_line >= statement_count {
             self.finished = true;
         }
 
         Ok(format!("Stepped to line {}", self.current_line))
     }
 
+    /// Evaluate the current statement, recording the line of a failed assertion
+    fn eval_current(&mut self) -> Result<(), String> {
+        let node = &self.ast.nodes()[self.current_line];
+        let result = self.evaluator.eval(node);
+        self.paused_frames = self.evaluator.take_paused_frames();
+        match result {
+            Ok(_) => {
+                self.error_line = None;
+                Ok(())
+            }
+            Err(e) => {
+                self.error_line = e.assertion_line();
+                Err(match self.error_line {
+                    Some(line) => format!("Evaluation error at line {}: {:?}", line, e),
+                    None => format!("Evaluation error: {:?}", e),
+                })
+            }
+        }
+    }
+
     /// Inspect variable value
     fn cmd_print(&self, var_name: &str) -> CommandResult {
         // Try to get variable value from evaluator's scope
@@ -172,26 +218,32 @@ impl DebugSession {
         Ok(format!("Breakpoint set at line {}", line))
     }
 
+    /// Set breakpoint at a source line, capturing the frames when it is reached
+    ///
+    /// Statements run to completion, so the stack is captured as the line
+    /// starts and execution stops after the enclosing top-level statement.
+    fn cmd_break_at(&mut self, line: usize) -> CommandResult {
+        self.evaluator.pause_at_line(line);
+        Ok(format!("Breakpoint set at source line {}", line))
+    }
+
     /// Run until next breakpoint or completion
     fn cmd_continue(&mut self) -> CommandResult {
         if self.finished {
             return Err("Execution already finished".to_string());
         }
 
-        let nodes = self.ast.nodes();
+        let statement_count = self.ast.nodes().len();
 
         // Keep stepping until breakpoint hit or completion
-        while self.current_line < nodes.len() {
+        while self.current_line < statement_count {
             // Check if we're at a breakpoint (before executing)
             if self.breakpoints.contains(&self.current_line) {
                 return Ok(format!("Breakpoint hit at line {}", self.current_line));
             }
 
             // Execute current statement
-            let node = &nodes[self.current_line];
-            self.evaluator
-                .eval(node)
-                .map_err(|e| format!("Evaluation error: {:?}", e))?;
+            self.eval_current()?;
 
             // Save snapshot
             self.current_line += 1;
@@ -200,6 +252,14 @@ impl DebugSession {
                 evaluator: self.evaluator.deep_clone(),
             };
             self.history.push(snapshot);
+
+            if let Some((line, frames)) = &self.paused_frames {
+                let function = frames.last().map_or("top level", |f| f.function.as_str());
+                return Ok(format!(
+                    "Breakpoint hit at source line {} in {}",
+                    line, function
+                ));
+            }
         }
 
         self.finished = true;
@@ -212,12 +272,30 @@ impl DebugSession {
             return Err("No current statement (execution finished)".to_string());
         }
 
-        let node = &self.ast.nodes()[self.current_line];
+        let node = self.ast.nodes()[self.current_line].without_span();
         Ok(format!("{:#?}", node))
     }
 
     /// Display call stack
     fn cmd_backtrace(&self) -> CommandResult {
+        if let Some((line, frames)) = &self.paused_frames {
+            let mut output = format!("Call stack at source line {}:\n", line);
+            for (i, frame) in frames.iter().enumerate() {
+                let variables: Vec<String> = frame
+                    .variables
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect();
+                output.push_str(&format!(
+                    "  #{}: {}({})\n",
+                    i,
+                    frame.function,
+                    variables.join(", ")
+                ));
+            }
+            return Ok(output);
+        }
+
         // Get call stack from evaluator
         let call_stack = self.evaluator.get_call_stack();
 
@@ -268,6 +346,7 @@ impl DebugSession {
   :step              Execute one statement and stop
   :print <var>       Inspect variable value
   :break <line>      Set breakpoint at line
+  :breakat <line>    Set breakpoint at a source line (inside functions too)
   :continue          Run until next breakpoint or completion
   :ast               Show current AST node structure
   :backtrace         Display call stack
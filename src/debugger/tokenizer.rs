@@ -9,7 +9,7 @@
 // - Manual comparison of working vs broken code took hours
 // - Root cause (lexer issue) hidden from parser error messages
 
-use crate::interpreter::parser::Parser;
+use crate::interpreter::parser::{ParseError, Parser, Span};
 
 /// Show detailed token stream with source locations
 ///
@@ -223,6 +223,9 @@ pub fn compare_tokens_with_hints(working: &str, broken: &str) -> String {
 /// Show parser state at failure point
 ///
 /// Addresses PARSER-079 pain: "Couldn't see what parser expected vs what it got"
+///
+/// Each token consumed before the failure is listed with its line:column,
+/// ending at the failing token, followed by the failing line with a caret.
 pub fn parser_trace(source: &str) -> String {
     let mut parser = Parser::new(source);
     let mut output = String::new();
@@ -235,12 +238,34 @@ pub fn parser_trace(source: &str) -> String {
             output.push_str("✅ Parse successful - no errors detected\n");
         }
         Err(e) => {
-            output.push_str("❌ Parse failed:\n");
-            output.push_str(&format!("Error: {:?}\n\n", e));
+            output.push_str(&format!("❌ Parse failed{}:\n", location_suffix(&e)));
+            output.push_str(&format!("Error: {}\n\n", e));
 
             output.push_str("Parser state at error:\n");
-            output.push_str("Expected: [See error message above]\n");
-            output.push_str(&format!("Got: {}\n", e));
+            let tokens = parser.debug_get_token_spans().unwrap_or_default();
+            let failing = e.span().map(|span| (span.line, span.column));
+            for (token, span) in tokens {
+                let position = (span.line, span.column);
+                if failing.is_some_and(|failing| position > failing) {
+                    break;
+                }
+                let marker = if Some(position) == failing {
+                    "  <-- failing token"
+                } else {
+                    ""
+                };
+                output.push_str(&format!(
+                    "  {:>7}  {}{}\n",
+                    format!("{}:{}", span.line, span.column),
+                    token,
+                    marker
+                ));
+            }
+
+            if let Some(span) = e.span() {
+                output.push('\n');
+                output.push_str(&source_excerpt(source, span, 0));
+            }
         }
     }
 
@@ -262,9 +287,14 @@ pub fn parser_trace_with_analysis(source: &str) -> String {
             output.push_str("✅ Parse successful - no errors detected\n");
         }
         Err(e) => {
-            output.push_str("❌ Parse failed:\n");
+            output.push_str(&format!("❌ Parse failed{}:\n", location_suffix(&e)));
             output.push_str(&format!("Error: {:?}\n\n", e));
 
+            if let Some(span) = e.span() {
+                output.push_str(&source_excerpt(source, span, 0));
+                output.push('\n');
+            }
+
             // Try to get tokens to analyze
             if let Ok(tokens) = parser.debug_get_tokens() {
                 if !tokens.is_empty() {
@@ -295,6 +325,9 @@ pub fn parser_trace_with_analysis(source: &str) -> String {
     output
 }
 
+/// Lines of context shown on each side of the failing line in errors-only mode
+const ERROR_CONTEXT_LINES: usize = 2;
+
 /// Show only the failing portion of parse trace
 ///
 /// Addresses PARSER-079 pain: "Full parser trace too verbose, needed focused view"
@@ -310,20 +343,52 @@ pub fn parser_trace_errors_only(source: &str) -> String {
             output.push_str("✅ No errors - parse successful\n");
         }
         Err(e) => {
-            output.push_str("❌ ERROR detected:\n\n");
-            output.push_str(&format!("Error message: {:?}\n", e));
+            output.push_str(&format!("❌ ERROR detected{}:\n\n", location_suffix(&e)));
+            output.push_str(&format!("Error message: {}\n", e));
 
-            // Show some context from the source around potential error location
+            // Only show context around the error, not all successful parses
             output.push_str("\nSource context:\n");
-            let lines: Vec<&str> = source.lines().collect();
-            for (i, line) in lines.iter().enumerate() {
-                output.push_str(&format!("Line {}: {}\n", i + 1, line));
+            match e.span() {
+                Some(span) => output.push_str(&source_excerpt(source, span, ERROR_CONTEXT_LINES)),
+                None => output.push_str("(error has no source location)\n"),
             }
-
-            // Only show context around the error, not all successful parses
-            output.push_str("\n(Showing only error context, not successful parse steps)\n");
         }
     }
 
     output
 }
+
+/// " at line L, column C" for errors that carry a location
+fn location_suffix(error: &ParseError) -> String {
+    error
+        .span()
+        .map(|span| format!(" at line {}, column {}", span.line, span.column))
+        .unwrap_or_default()
+}
+
+/// Render the source lines around `span` with a caret under its column
+///
+/// `context` lines are shown before and after the failing line.
+fn source_excerpt(source: &str, span: Span, context: usize) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let first = span.line.saturating_sub(context).max(1);
+    // The failing token can be end-of-file on a line past the last one
+    let last = (span.line + context).min(lines.len()).max(span.line);
+    let width = last.to_string().len();
+
+    let mut output = String::new();
+    for number in first..=last {
+        let text = lines.get(number - 1).copied().unwrap_or("");
+        output.push_str(&format!("{:>width$} | {}\n", number, text));
+        if number == span.line {
+            // Keep tabs so the caret lines up with the token
+            let padding: String = text
+                .chars()
+                .take(span.column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            output.push_str(&format!("{:>width$} | {}^\n", "", padding));
+        }
+    }
+    output
+}
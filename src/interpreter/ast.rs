@@ -1,10 +1,12 @@
 // AST type definitions for the Ruchy parser.
 //
-// Contains: Ast, AstNode, BinaryOperator, UnaryOperator, MatchArm,
-// Pattern, StructField, ParseError, and their trait implementations.
+// Contains: Ast, AstNode, Span, BinaryOperator, UnaryOperator, MatchArm,
+// Pattern, StructField, EnumVariant, ParseError, and their trait implementations.
 //
 // Extracted from parser.rs for file-health compliance (<2000 lines).
 
+use super::visitor::{walk_node, Visitor};
+
 /// Abstract Syntax Tree
 #[derive(Debug, Clone, PartialEq)]
 pub struct Ast {
@@ -23,15 +25,25 @@ impl Ast {
         &self.nodes
     }
 
-    /// Visit all nodes in the AST with a callback
-    pub fn visit<F>(&self, mut callback: F)
+    /// Visit all nodes in the AST (pre-order) with a callback
+    ///
+    /// For analyses that need per-node-kind hooks, implement
+    /// [`Visitor`](super::visitor::Visitor) instead.
+    pub fn visit<F>(&self, callback: F)
     where
         F: FnMut(&AstNode),
     {
-        for node in &self.nodes {
-            callback(node);
-            node.visit_children(&mut callback);
+        /// Adapts a closure to the visitor traversal
+        struct CallbackVisitor<F>(F);
+
+        impl<F: FnMut(&AstNode)> Visitor for CallbackVisitor<F> {
+            fn visit_node(&mut self, node: &AstNode) {
+                (self.0)(node);
+                walk_node(self, node);
+            }
         }
+
+        CallbackVisitor(callback).visit_ast(self);
     }
 
     /// Emit AST back to source code (DEBUGGER-044: Property-based testing)
@@ -45,85 +57,10 @@ impl Ast {
     pub fn emit(&self) -> String {
         self.nodes
             .iter()
-            .map(|node| self.emit_node(node))
+            .map(AstNode::emit)
             .collect::<Vec<_>>()
             .join("\n")
     }
-
-    /// Emit a single AST node to source code
-    fn emit_node(&self, node: &AstNode) -> String {
-        match node {
-            AstNode::Empty => String::new(),
-
-            AstNode::IntegerLiteral(n) => n.to_string(),
-            AstNode::FloatLiteral(f) => f.to_string(),
-            AstNode::StringLiteral(s) => format!("\"{}\"", s),
-            AstNode::BooleanLiteral(b) => b.to_string(),
-            AstNode::Identifier(name) => name.clone(),
-
-            AstNode::BinaryOp { left, op, right } => {
-                format!(
-                    "{} {} {}",
-                    self.emit_node(left),
-                    self.emit_binop(op),
-                    self.emit_node(right)
-                )
-            }
-
-            AstNode::UnaryOp { op, operand } => {
-                format!("{}{}", self.emit_unaryop(op), self.emit_node(operand))
-            }
-
-            AstNode::LetDecl { name, value } => {
-                format!("let {} = {};", name, self.emit_node(value))
-            }
-
-            AstNode::Assignment { name, value } => {
-                format!("{} = {};", name, self.emit_node(value))
-            }
-
-            AstNode::FunctionCall { name, args } => {
-                let args_str = args
-                    .iter()
-                    .map(|arg| self.emit_node(arg))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("{}({})", name, args_str)
-            }
-
-            // For complex nodes, emit minimal representation
-            _ => format!("/* {:?} */", node),
-        }
-    }
-
-    /// Emit binary operator
-    fn emit_binop(&self, op: &BinaryOperator) -> &'static str {
-        match op {
-            BinaryOperator::Add => "+",
-            BinaryOperator::Subtract => "-",
-            BinaryOperator::Multiply => "*",
-            BinaryOperator::Divide => "/",
-            BinaryOperator::Modulo => "%",
-            BinaryOperator::Equal => "==",
-            BinaryOperator::NotEqual => "!=",
-            BinaryOperator::LessThan => "<",
-            BinaryOperator::LessEqual => "<=",
-            BinaryOperator::GreaterThan => ">",
-            BinaryOperator::GreaterEqual => ">=",
-            BinaryOperator::And => "&&",
-            BinaryOperator::Or => "||",
-        }
-    }
-
-    /// Emit unary operator
-    fn emit_unaryop(&self, op: &UnaryOperator) -> &'static str {
-        match op {
-            UnaryOperator::Negate => "-",
-            UnaryOperator::Not => "!",
-            UnaryOperator::Plus => "+",
-            UnaryOperator::Dereference => "*",
-        }
-    }
 }
 
 impl Default for Ast {
@@ -132,38 +69,91 @@ impl Default for Ast {
     }
 }
 
+/// Source location (1-based line and column)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    /// Line number (1-based)
+    pub line: usize,
+    /// Column number (1-based)
+    pub column: usize,
+}
+
 /// AST Node types
 #[derive(Debug, Clone, PartialEq)]
 pub enum AstNode {
     /// Empty node (for comparisons)
     Empty,
 
-    /// Function definition: fun name(params) { body }
+    /// Statement annotated with its source location (INTERP-080)
+    ///
+    /// Only produced when the parser is built with `Parser::with_spans()`.
+    Spanned {
+        /// Where the statement starts
+        span: Span,
+        /// The wrapped statement
+        node: Box<AstNode>,
+    },
+
+    /// Function definition: fun name(params) -> type { body }
     FunctionDef {
         /// Function name
         name: String,
         /// Parameter names
         params: Vec<String>,
+        /// Parameter type annotations, one per parameter (None if omitted)
+        param_types: Vec<Option<String>>,
+        /// Return type annotation
+        return_type: Option<String>,
         /// Function body statements
         body: Vec<AstNode>,
     },
 
-    /// Variable declaration: let name = expr
+    /// Test function: #[test] fun name() { body } (INTERP-115)
+    ///
+    /// Skipped by normal evaluation (like Rust's `cfg(test)`); run by
+    /// `run_tests`.
+    TestDef {
+        /// Test name
+        name: String,
+        /// Test body statements
+        body: Vec<AstNode>,
+    },
+
+    /// Variable declaration: let name = expr, let mut name = expr
     LetDecl {
         /// Variable name
         name: String,
+        /// Whether the binding was declared `mut`
+        mutable: bool,
         /// Initial value expression
         value: Box<AstNode>,
     },
 
-    /// Tuple destructuring: let (a, b, c) = expr
+    /// Tuple destructuring: let (a, b, c) = expr, let ((a, b), _) = expr
+    ///
+    /// Each element is a `Pattern::Identifier`, `Pattern::Wildcard` or a
+    /// nested `Pattern::Tuple` (INTERP-136).
     TupleDestruct {
-        /// Pattern variables (list of names to bind)
-        names: Vec<String>,
+        /// One pattern per tuple element
+        patterns: Vec<Pattern>,
         /// Value expression (must evaluate to tuple)
         value: Box<AstNode>,
     },
 
+    /// Refutable binding: let pattern = expr else { diverging block }
+    ///
+    /// Binds the pattern's variables in the current scope when it matches;
+    /// otherwise runs `else_block`, which must return, break, continue or
+    /// panic (checked by the parser).
+    LetElse {
+        /// Refutable pattern (e.g. `Some(x)`)
+        pattern: Box<Pattern>,
+        /// Value expression
+        value: Box<AstNode>,
+        /// Block run when the pattern does not match
+        else_block: Vec<AstNode>,
+    },
+
     /// Assignment: name = expr
     Assignment {
         /// Variable name
@@ -172,6 +162,29 @@ pub enum AstNode {
         value: Box<AstNode>,
     },
 
+    /// Index assignment: v[i] = expr, m["key"] = expr (INTERP-120)
+    IndexAssignment {
+        /// Collection being written (identifier or nested index: g[r][c] = x)
+        target: Box<AstNode>,
+        /// Index or key expression
+        index: Box<AstNode>,
+        /// New value expression
+        value: Box<AstNode>,
+    },
+
+    /// Field assignment: p.x = expr (INTERP-137)
+    ///
+    /// The field must already exist on a struct (KeyNotFound otherwise);
+    /// on a HashMap the key is inserted, as with `m["key"] = expr`.
+    FieldAssignment {
+        /// Value being written (identifier, field access or index access)
+        target: Box<AstNode>,
+        /// Field name
+        field: String,
+        /// New value expression
+        value: Box<AstNode>,
+    },
+
     /// Compound assignment: x += 5, *num -= 1
     CompoundAssignment {
         /// Left-hand side (identifier or expression like *num)
@@ -200,7 +213,25 @@ pub enum AstNode {
         else_branch: Option<Vec<AstNode>>,
     },
 
+    /// Pattern conditional: if let pattern = scrutinee { then } else { else }
+    ///
+    /// The pattern's variables are bound only inside `then_branch`; when the
+    /// pattern does not match, `else_branch` runs (INTERP-123).
+    IfLet {
+        /// Refutable pattern (e.g. `Some(x)`)
+        pattern: Box<Pattern>,
+        /// Value matched against the pattern
+        scrutinee: Box<AstNode>,
+        /// Statements run when the pattern matches
+        then_branch: Vec<AstNode>,
+        /// Statements run when it does not (optional)
+        else_branch: Option<Vec<AstNode>>,
+    },
+
     /// While loop: while condition { body }
+    ///
+    /// Loops are statements: a while loop evaluates to nil, also when used
+    /// in expression position (`let r = while c { .. }` binds nil).
     WhileLoop {
         /// Loop condition expression
         condition: Box<AstNode>,
@@ -208,7 +239,41 @@ pub enum AstNode {
         body: Vec<AstNode>,
     },
 
+    /// Pattern loop: while let pattern = scrutinee { body } (INTERP-123)
+    ///
+    /// Re-evaluates the scrutinee before each iteration and stops at the
+    /// first value that does not match. Like `WhileLoop`, it evaluates to nil.
+    WhileLet {
+        /// Refutable pattern (e.g. `Ok(v)`)
+        pattern: Box<Pattern>,
+        /// Value matched against the pattern each iteration
+        scrutinee: Box<AstNode>,
+        /// Loop body statements
+        body: Vec<AstNode>,
+    },
+
+    /// Repeat-until loop: repeat { body } until condition
+    ///
+    /// The body runs at least once; the loop exits once the condition is true.
+    RepeatUntil {
+        /// Loop body statements
+        body: Vec<AstNode>,
+        /// Exit condition, checked after each iteration
+        condition: Box<AstNode>,
+    },
+
+    /// Infinite loop: loop { body } (INTERP-121)
+    ///
+    /// Runs until a `break`; unlike the other loops it is an expression,
+    /// evaluating to the value given to `break` (nil for a bare `break`).
+    Loop {
+        /// Loop body statements
+        body: Vec<AstNode>,
+    },
+
     /// For loop: for var in expr { body }
+    ///
+    /// Like `WhileLoop`, a for loop always evaluates to nil.
     ForLoop {
         /// Loop variable name
         var: String,
@@ -234,6 +299,22 @@ pub enum AstNode {
         fields: Vec<StructField>,
     },
 
+    /// Impl block: impl Name { fun method(self) { ... } }
+    ImplBlock {
+        /// Type the methods belong to
+        type_name: String,
+        /// Method definitions (FunctionDef nodes)
+        methods: Vec<AstNode>,
+    },
+
+    /// Enum definition: enum Name { A, B = 5, ... }
+    EnumDef {
+        /// Enum name
+        name: String,
+        /// Variants in declaration order
+        variants: Vec<EnumVariant>,
+    },
+
     /// Struct instantiation: Name { field: value, ... }
     StructLiteral {
         /// Struct name
@@ -312,12 +393,14 @@ pub enum AstNode {
         target_type: String,
     },
 
-    /// Range expression: start..end
+    /// Range expression: start..end or start..=end
     Range {
         /// Range start expression
         start: Box<AstNode>,
         /// Range end expression
         end: Box<AstNode>,
+        /// Whether the end is included (..=) (COMPILE-003)
+        inclusive: bool,
     },
 
     /// Return statement: return expr
@@ -326,6 +409,18 @@ pub enum AstNode {
         value: Option<Box<AstNode>>,
     },
 
+    /// Break statement: exits the innermost enclosing loop
+    ///
+    /// Only a `loop` accepts a value (`break v;`), which becomes the value
+    /// of the loop expression (INTERP-121).
+    Break {
+        /// Break value (optional)
+        value: Option<Box<AstNode>>,
+    },
+
+    /// Continue statement: skips to the next iteration of the innermost loop
+    Continue,
+
     /// Identifier reference
     Identifier(String),
 
@@ -397,64 +492,240 @@ pub enum AstNode {
 }
 
 impl AstNode {
-    /// Visit all children of this node
-    fn visit_children<F>(&self, callback: &mut F)
-    where
-        F: FnMut(&AstNode),
-    {
+    /// Emit this node back to source code
+    ///
+    /// Expressions render with the minimal parentheses needed to parse back
+    /// to the same tree, so `dbg` (INTERP-092) can echo what the user wrote.
+    pub fn emit(&self) -> String {
+        self.emit_with(&mut |_| None)
+    }
+
+    /// Emit this node, offering each subexpression to `layout` first
+    ///
+    /// When `layout` returns text for a subexpression, that text is used in
+    /// its place; parentheses are still added here. The formatter uses this
+    /// to print closures and blocks across several lines.
+    pub(crate) fn emit_with(&self, layout: &mut Layout<'_>) -> String {
         match self {
-            AstNode::FunctionDef { body, .. } => {
-                for node in body {
-                    callback(node);
-                    node.visit_children(callback);
-                }
-            }
-            AstNode::LetDecl { value, .. } => {
-                callback(value);
-                value.visit_children(callback);
+            AstNode::Empty => String::new(),
+
+            AstNode::IntegerLiteral(n) => n.to_string(),
+            AstNode::FloatLiteral(f) => float_literal(*f),
+            AstNode::StringLiteral(s) => {
+                let body: String = s.chars().map(|c| escape_char(c, '"')).collect();
+                format!("\"{}\"", body)
             }
-            AstNode::Assignment { value, .. } => {
-                callback(value);
-                value.visit_children(callback);
+            AstNode::CharLiteral(c) => format!("'{}'", escape_char(*c, '\'')),
+            AstNode::BooleanLiteral(b) => b.to_string(),
+            AstNode::FString { content } => format!("f\"{}\"", content),
+            AstNode::Identifier(name) => name.clone(),
+            AstNode::PathExpr { segments } => segments.join("::"),
+
+            AstNode::BinaryOp { left, op, right } => {
+                let prec = op.precedence();
+                // Left-associative: a same-precedence child only needs
+                // parentheses on the right
+                let left_parens = left.binary_precedence().is_some_and(|child| child < prec);
+                let right_parens = right.binary_precedence().is_some_and(|child| child <= prec);
+                format!(
+                    "{} {} {}",
+                    left.emit_operand(left_parens || left.is_closure(), layout),
+                    op.symbol(),
+                    right.emit_operand(right_parens || right.is_closure(), layout)
+                )
             }
-            AstNode::FunctionCall { args, .. } => {
-                for arg in args {
-                    callback(arg);
-                    arg.visit_children(callback);
-                }
+
+            AstNode::UnaryOp { op, operand } => {
+                let parens = matches!(
+                    operand.without_span(),
+                    AstNode::BinaryOp { .. }
+                        | AstNode::Range { .. }
+                        | AstNode::TypeCast { .. }
+                        | AstNode::Closure { .. }
+                );
+                format!("{}{}", op.symbol(), operand.emit_operand(parens, layout))
             }
-            AstNode::IfExpr {
-                condition,
-                then_branch,
-                else_branch,
+
+            AstNode::LetDecl {
+                name,
+                mutable,
+                value,
             } => {
-                callback(condition);
-                condition.visit_children(callback);
-                for node in then_branch {
-                    callback(node);
-                    node.visit_children(callback);
-                }
-                if let Some(else_branch) = else_branch {
-                    for node in else_branch {
-                        callback(node);
-                        node.visit_children(callback);
-                    }
+                let keyword = if *mutable { "let mut" } else { "let" };
+                format!("{} {} = {};", keyword, name, emit_child(value, layout))
+            }
+
+            AstNode::Assignment { name, value } => {
+                format!("{} = {};", name, emit_child(value, layout))
+            }
+
+            AstNode::IndexAssignment {
+                target,
+                index,
+                value,
+            } => format!(
+                "{}[{}] = {};",
+                target.emit_postfix(layout),
+                emit_child(index, layout),
+                emit_child(value, layout)
+            ),
+
+            AstNode::FieldAssignment {
+                target,
+                field,
+                value,
+            } => format!(
+                "{}.{} = {};",
+                target.emit_postfix(layout),
+                field,
+                emit_child(value, layout)
+            ),
+
+            AstNode::Break { value: None } => "break;".to_string(),
+            AstNode::Break { value: Some(value) } => {
+                format!("break {};", emit_child(value, layout))
+            }
+            AstNode::Continue => "continue;".to_string(),
+
+            AstNode::FunctionCall { name, args } => {
+                format!("{}({})", name, emit_list(args, layout))
+            }
+
+            AstNode::MethodCall {
+                receiver,
+                method,
+                args,
+            } => format!(
+                "{}.{}({})",
+                receiver.emit_postfix(layout),
+                method,
+                emit_list(args, layout)
+            ),
+
+            AstNode::FieldAccess { expr, field } => {
+                format!("{}.{}", expr.emit_postfix(layout), field)
+            }
+
+            AstNode::IndexAccess { expr, index } => format!(
+                "{}[{}]",
+                expr.emit_postfix(layout),
+                emit_child(index, layout)
+            ),
+
+            AstNode::VectorLiteral { elements } => format!("[{}]", emit_list(elements, layout)),
+
+            AstNode::VecMacro {
+                elements,
+                repeat_count: Some(count),
+            } => format!(
+                "vec![{}; {}]",
+                emit_list(elements, layout),
+                emit_child(count, layout)
+            ),
+            AstNode::VecMacro {
+                elements,
+                repeat_count: None,
+            } => format!("vec![{}]", emit_list(elements, layout)),
+
+            AstNode::TupleLiteral { elements } => {
+                if elements.len() == 1 {
+                    format!("({},)", emit_child(&elements[0], layout))
+                } else {
+                    format!("({})", emit_list(elements, layout))
                 }
             }
-            AstNode::BinaryOp { left, right, .. } => {
-                callback(left);
-                left.visit_children(callback);
-                callback(right);
-                right.visit_children(callback);
+
+            AstNode::HashMapLiteral { pairs } => {
+                let pairs: Vec<String> = pairs
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", emit_child(k, layout), emit_child(v, layout)))
+                    .collect();
+                format!("{{{}}}", pairs.join(", "))
             }
-            AstNode::UnaryOp { operand, .. } => {
-                callback(operand);
-                operand.visit_children(callback);
+
+            AstNode::StructLiteral { name, fields } => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|(field, v)| format!("{}: {}", field, emit_child(v, layout)))
+                    .collect();
+                format!("{} {{ {} }}", name, fields.join(", "))
             }
-            _ => {
-                // Other node types: visit children as needed
-                // This is minimal for RED phase
+
+            AstNode::TypeCast { expr, target_type } => {
+                let parens = matches!(
+                    expr.without_span(),
+                    AstNode::BinaryOp { .. } | AstNode::Range { .. } | AstNode::Closure { .. }
+                );
+                format!("{} as {}", expr.emit_operand(parens, layout), target_type)
             }
+
+            AstNode::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let parens = matches!(
+                    start.without_span(),
+                    AstNode::BinaryOp { .. } | AstNode::Range { .. } | AstNode::Closure { .. }
+                );
+                let op = if *inclusive { "..=" } else { ".." };
+                format!(
+                    "{}{}{}",
+                    start.emit_operand(parens, layout),
+                    op,
+                    end.emit_postfix(layout)
+                )
+            }
+
+            AstNode::Spanned { node, .. } => node.emit_with(layout),
+
+            // For complex nodes, emit minimal representation
+            _ => format!("/* {:?} */", self),
+        }
+    }
+
+    /// Precedence of this node if it is a binary operation
+    fn binary_precedence(&self) -> Option<u8> {
+        match self {
+            AstNode::BinaryOp { op, .. } => Some(op.precedence()),
+            AstNode::Spanned { node, .. } => node.binary_precedence(),
+            _ => None,
+        }
+    }
+
+    /// Whether this node is a closure, which swallows any operator after it
+    fn is_closure(&self) -> bool {
+        matches!(self.without_span(), AstNode::Closure { .. })
+    }
+
+    /// Emit as an operand, in parentheses if `parens` is set
+    fn emit_operand(&self, parens: bool, layout: &mut Layout<'_>) -> String {
+        let text = emit_child(self, layout);
+        if parens {
+            format!("({})", text)
+        } else {
+            text
+        }
+    }
+
+    /// Emit as the operand of a postfix form (`.`, `[]`, the end of `..`)
+    fn emit_postfix(&self, layout: &mut Layout<'_>) -> String {
+        let parens = matches!(
+            self.without_span(),
+            AstNode::BinaryOp { .. }
+                | AstNode::UnaryOp { .. }
+                | AstNode::Range { .. }
+                | AstNode::TypeCast { .. }
+                | AstNode::Closure { .. }
+        );
+        self.emit_operand(parens, layout)
+    }
+
+    /// This node with any `Spanned` wrappers removed
+    pub(crate) fn without_span(&self) -> &AstNode {
+        match self {
+            AstNode::Spanned { node, .. } => node.without_span(),
+            other => other,
         }
     }
 }
@@ -488,6 +759,58 @@ pub enum BinaryOperator {
     And,
     /// Logical OR operator (||)
     Or,
+    /// Bitwise AND operator (&)
+    BitAnd,
+    /// Bitwise OR operator (|)
+    BitOr,
+    /// Bitwise XOR operator (^)
+    BitXor,
+    /// Left shift operator (<<)
+    Shl,
+    /// Arithmetic right shift operator (>>)
+    Shr,
+}
+
+impl BinaryOperator {
+    /// Source symbol for this operator
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::LessThan => "<",
+            BinaryOperator::LessEqual => "<=",
+            BinaryOperator::GreaterThan => ">",
+            BinaryOperator::GreaterEqual => ">=",
+            BinaryOperator::And => "&&",
+            BinaryOperator::Or => "||",
+            BinaryOperator::BitAnd => "&",
+            BinaryOperator::BitOr => "|",
+            BinaryOperator::BitXor => "^",
+            BinaryOperator::Shl => "<<",
+            BinaryOperator::Shr => ">>",
+        }
+    }
+
+    /// Binding strength used by the parser (higher binds tighter)
+    pub(crate) fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => 9,
+            BinaryOperator::Add | BinaryOperator::Subtract => 8,
+            BinaryOperator::Shl | BinaryOperator::Shr => 7,
+            BinaryOperator::BitAnd => 6,
+            BinaryOperator::BitXor => 5,
+            BinaryOperator::BitOr => 4,
+            BinaryOperator::And => 2,
+            BinaryOperator::Or => 1,
+            // Comparisons
+            _ => 3,
+        }
+    }
 }
 
 /// Unary operators
@@ -503,11 +826,69 @@ pub enum UnaryOperator {
     Dereference,
 }
 
+impl UnaryOperator {
+    /// Source symbol for this operator
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            UnaryOperator::Negate => "-",
+            UnaryOperator::Not => "!",
+            UnaryOperator::Plus => "+",
+            UnaryOperator::Dereference => "*",
+        }
+    }
+}
+
+/// Text for subexpressions chosen by the caller of `AstNode::emit_with`
+pub(crate) type Layout<'a> = dyn FnMut(&AstNode) -> Option<String> + 'a;
+
+/// Emit a subexpression, unless `layout` supplies its text
+fn emit_child(node: &AstNode, layout: &mut Layout<'_>) -> String {
+    match layout(node) {
+        Some(text) => text,
+        None => node.emit_with(layout),
+    }
+}
+
+/// Emit a comma-separated list of nodes
+fn emit_list(nodes: &[AstNode], layout: &mut Layout<'_>) -> String {
+    let items: Vec<String> = nodes.iter().map(|node| emit_child(node, layout)).collect();
+    items.join(", ")
+}
+
+/// A float literal the tokenizer reads back as the same value
+///
+/// `Display` never uses exponent notation (which the tokenizer does not
+/// accept), but drops the fraction of whole numbers.
+fn float_literal(f: f64) -> String {
+    let text = f.to_string();
+    if text.contains('.') {
+        text
+    } else {
+        format!("{}.0", text)
+    }
+}
+
+/// Write `c` as it appears inside a literal quoted by `quote` (INTERP-128, INTERP-129)
+fn escape_char(c: char, quote: char) -> String {
+    match c {
+        '\n' => "\\n".to_string(),
+        '\t' => "\\t".to_string(),
+        '\r' => "\\r".to_string(),
+        '\0' => "\\0".to_string(),
+        '\\' => "\\\\".to_string(),
+        c if c == quote => format!("\\{}", c),
+        c => c.to_string(),
+    }
+}
+
 /// Match arm in match expression
 #[derive(Debug, Clone, PartialEq)]
 pub struct MatchArm {
     /// Pattern to match
     pub pattern: Pattern,
+    /// Guard condition (`pattern if cond => ..`); the arm is taken only when
+    /// it is true, and sees the pattern's bindings (INTERP-124)
+    pub guard: Option<AstNode>,
     /// Statements to execute if pattern matches
     pub body: Vec<AstNode>,
 }
@@ -521,6 +902,33 @@ pub enum Pattern {
     Literal(AstNode),
     /// Identifier pattern (x) - binds variable
     Identifier(String),
+    /// Range pattern (1..5, 1..=5) - matches integers within the range
+    Range {
+        /// Range start (inclusive)
+        start: AstNode,
+        /// Range end
+        end: AstNode,
+        /// Whether the end is included (..=)
+        inclusive: bool,
+    },
+    /// As-pattern (x @ 1..=5) - binds the whole value when the sub-pattern matches
+    Binding {
+        /// Variable bound to the matched value
+        name: String,
+        /// Sub-pattern that must match
+        pattern: Box<Pattern>,
+    },
+    /// Variant pattern (Some(x), Ok(v), Err(e), None) - matches a variant by
+    /// name and, for data variants, its payload against the sub-pattern
+    Variant {
+        /// Variant name
+        name: String,
+        /// Pattern for the carried value (None for unit variants)
+        payload: Option<Box<Pattern>>,
+    },
+    /// Tuple pattern ((a, b), c) - matches a tuple of the same arity
+    /// element by element (INTERP-136)
+    Tuple(Vec<Pattern>),
 }
 
 /// Struct field definition
@@ -532,6 +940,15 @@ pub struct StructField {
     pub type_annotation: Option<String>,
 }
 
+/// Enum variant definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariant {
+    /// Variant name
+    pub name: String,
+    /// Optional explicit discriminant (`Name = expr`)
+    pub discriminant: Option<AstNode>,
+}
+
 /// Parse errors
 #[derive(Debug, Clone)]
 pub enum ParseError {
@@ -564,6 +981,20 @@ pub enum ParseError {
     Unimplemented(String),
 }
 
+impl ParseError {
+    /// Source location of the error, when it has one
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            ParseError::UnexpectedToken { line, column, .. }
+            | ParseError::InvalidSyntax { line, column, .. } => Some(Span {
+                line: *line,
+                column: *column,
+            }),
+            ParseError::UnexpectedEof | ParseError::Unimplemented(_) => None,
+        }
+    }
+}
+
 impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -156,8 +156,12 @@ impl BugDiscoveryAnalyzer {
             EvalError::UndefinedVariable { .. } | EvalError::UndefinedFunction { .. } => {
                 BugCategory::UndefinedIdentifier
             }
-            EvalError::StackOverflow => BugCategory::StackOverflow,
-            EvalError::NoMatchArm => BugCategory::NoMatchArm,
+            EvalError::StackOverflow | EvalError::MetaRecursionLimit { .. } => {
+                BugCategory::StackOverflow
+            }
+            EvalError::NoMatchArm | EvalError::NonExhaustiveMatch { .. } => {
+                BugCategory::NoMatchArm
+            }
             EvalError::UnsupportedOperation { .. } => BugCategory::UnsupportedOperation,
             _ => BugCategory::Other,
         }
@@ -214,6 +218,10 @@ impl BugDiscoveryAnalyzer {
             EvalError::UndefinedFunction { name } => format!("UndefinedFunction:{}", name),
             EvalError::StackOverflow => "StackOverflow".to_string(),
             EvalError::NoMatchArm => "NoMatchArm".to_string(),
+            EvalError::NonExhaustiveMatch {
+                enum_name,
+                unmatched_variant,
+            } => format!("NonExhaustiveMatch:{}::{}", enum_name, unmatched_variant),
             EvalError::UnsupportedOperation { operation } => {
                 format!("UnsupportedOperation:{}", operation)
             }
@@ -0,0 +1,826 @@
+// Built-in functions: println/format rendering and the builtin groups
+// (I/O and assertions, collections, concurrency, timing, metaprogramming).
+//
+// Extracted from eval_helpers.rs for file-health compliance (<2000 lines).
+
+use crate::interpreter::parser::AstNode;
+use crate::interpreter::value::{Value, ValueError};
+use super::evaluator::{ControlFlow, EvalError, Evaluator};
+
+/// Names handled by `try_call_builtin` (keep in sync with the builtin groups)
+const BUILTIN_FUNCTIONS: &[&str] = &[
+    // I/O
+    "read_file",
+    "write_file",
+    "read_bytes",
+    "write_bytes",
+    "print",
+    "println",
+    "format",
+    "dbg",
+    "assert",
+    "assert_eq",
+    "assert_approx",
+    "panic",
+    // Collections
+    "vec",
+    "String::new",
+    "String::from",
+    "HashMap::new",
+    "len",
+    "hash",
+    // Concurrency
+    "thread::spawn",
+    "Mutex::new",
+    "Arc::new",
+    "Arc::clone",
+    "mpsc::channel",
+    // Timing
+    "time_now",
+    "bench",
+    // Metaprogramming
+    "eval_str",
+    // Option/Result
+    "Some",
+    "Ok",
+    "Err",
+];
+
+impl Evaluator {
+    /// Whether a function name resolves to a builtin (INTERP-083)
+    pub(crate) fn is_builtin(name: &str) -> bool {
+        BUILTIN_FUNCTIONS.contains(&name)
+    }
+
+    /// Format a value for println, dispatching to user-defined `to_string`
+    ///
+    /// Structs whose impl block defines `to_string(self)` print its result;
+    /// everything else uses the default `Value::to_println_string` rendering.
+    pub(crate) fn format_for_println(&mut self, value: &Value) -> Result<String, EvalError> {
+        match value {
+            Value::Struct { name, .. } => {
+                let method_name = format!("{}::to_string", name);
+                if !self.functions.contains_key(&method_name) {
+                    return Ok(value.to_println_string());
+                }
+                let rendered = self.call_function_with_values(&method_name, vec![value.clone()])?;
+                Ok(rendered.as_string()?.to_string())
+            }
+            Value::Vector(elements) | Value::Tuple(elements) => {
+                let mut parts = Vec::with_capacity(elements.len());
+                for element in elements {
+                    parts.push(self.format_for_println(element)?);
+                }
+                Ok(if value.is_vector() {
+                    format!("[{}]", parts.join(", "))
+                } else {
+                    format!("({})", parts.join(", "))
+                })
+            }
+            _ => Ok(value.to_println_string()),
+        }
+    }
+
+    /// Expand a format template (INTERP-101)
+    ///
+    /// `{}` takes the next argument, `{N}` the argument at index N (so it can
+    /// be reused or reordered), and `{name}` the variable `name` in the
+    /// current scope. `{{` and `}}` are literal braces. Values render as in
+    /// println. An out-of-range index, an unknown name, or an unbalanced brace
+    /// is an error.
+    pub(crate) fn format_template(
+        &mut self,
+        template: &str,
+        args: &[Value],
+    ) -> Result<String, EvalError> {
+        let invalid = |message: String| {
+            EvalError::ValueError(ValueError::InvalidOperation {
+                operation: "format".to_string(),
+                message,
+            })
+        };
+        let mut result = String::new();
+        let mut next_implicit = 0;
+        let mut chars = template.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    result.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    result.push('}');
+                }
+                '}' => return Err(invalid("unmatched '}' in format string".to_string())),
+                '{' => {
+                    let mut spec = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => spec.push(c),
+                            None => {
+                                return Err(invalid("unterminated '{' in format string".to_string()))
+                            }
+                        }
+                    }
+                    let spec = spec.trim();
+                    let index = if spec.is_empty() {
+                        next_implicit += 1;
+                        Some(next_implicit - 1)
+                    } else {
+                        spec.parse::<usize>().ok()
+                    };
+                    let value = match index {
+                        Some(index) => args.get(index).cloned().ok_or_else(|| {
+                            invalid(format!(
+                                "placeholder {{{}}} is out of range ({} argument(s) given)",
+                                index,
+                                args.len()
+                            ))
+                        })?,
+                        None => self.scope.get_cloned(spec).map_err(|_| {
+                            EvalError::UndefinedVariable {
+                                name: spec.to_string(),
+                            }
+                        })?,
+                    };
+                    let rendered = self.format_for_println(&value)?;
+                    result.push_str(&rendered);
+                }
+                _ => result.push(ch),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Render the arguments of print/println: a single value as-is, or a
+    /// format template followed by its arguments
+    fn format_print_args(&mut self, function: &str, args: &[AstNode]) -> Result<String, EvalError> {
+        if args.is_empty() {
+            return Err(EvalError::ArgumentCountMismatch {
+                function: function.to_string(),
+                expected: 1,
+                actual: 0,
+            });
+        }
+        let mut values = Vec::with_capacity(args.len());
+        for arg in args {
+            values.push(self.eval(arg)?);
+        }
+        if values.len() == 1 {
+            return self.format_for_println(&values[0]);
+        }
+        let template = values[0].as_string()?.to_string();
+        self.format_template(&template, &values[1..])
+    }
+
+    /// I/O and diagnostic builtins: read_file, write_file, read_bytes, write_bytes,
+    /// print, println, format, dbg, assert, assert_eq, assert_approx, panic
+    fn call_io_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        match name {
+            "read_file" => {
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "read_file".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let path_val = self.eval(&args[0])?;
+                let path = path_val.as_string()?;
+                match std::fs::read_to_string(path) {
+                    Ok(content) => Ok(Some(Value::string(content))),
+                    Err(e) => Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "read_file".to_string(),
+                        message: format!("Failed to read file: {}", e),
+                    })),
+                }
+            }
+            "write_file" => {
+                if args.len() != 2 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "write_file".to_string(),
+                        expected: 2,
+                        actual: args.len(),
+                    });
+                }
+                let path_val = self.eval(&args[0])?;
+                let path = path_val.as_string()?;
+                let content_val = self.eval(&args[1])?;
+                let content = content_val.as_string()?;
+                match std::fs::write(path, content) {
+                    Ok(_) => Ok(Some(Value::nil())),
+                    Err(e) => Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "write_file".to_string(),
+                        message: format!("Failed to write file: {}", e),
+                    })),
+                }
+            }
+            "read_bytes" => {
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "read_bytes".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let path_val = self.eval(&args[0])?;
+                let path = path_val.as_string()?;
+                match std::fs::read(path) {
+                    Ok(bytes) => Ok(Some(Value::vector(
+                        bytes.into_iter().map(|b| Value::integer(b as i64)).collect(),
+                    ))),
+                    Err(e) => Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "read_bytes".to_string(),
+                        message: format!("Failed to read file: {}", e),
+                    })),
+                }
+            }
+            "write_bytes" => {
+                if args.len() != 2 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "write_bytes".to_string(),
+                        expected: 2,
+                        actual: args.len(),
+                    });
+                }
+                let path_val = self.eval(&args[0])?;
+                let path = path_val.as_string()?;
+                let bytes_val = self.eval(&args[1])?;
+                let mut bytes = Vec::new();
+                for (index, element) in bytes_val.as_vector()?.iter().enumerate() {
+                    let value = element.as_integer()?;
+                    let byte = u8::try_from(value).map_err(|_| {
+                        EvalError::ValueError(ValueError::InvalidOperation {
+                            operation: "write_bytes".to_string(),
+                            message: format!(
+                                "byte value {} at index {} is out of range 0-255",
+                                value, index
+                            ),
+                        })
+                    })?;
+                    bytes.push(byte);
+                }
+                match std::fs::write(path, bytes) {
+                    Ok(_) => Ok(Some(Value::nil())),
+                    Err(e) => Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "write_bytes".to_string(),
+                        message: format!("Failed to write file: {}", e),
+                    })),
+                }
+            }
+            "print" => {
+                // Like println without the newline; flushed by eval_program
+                let msg = self.format_print_args("print", args)?;
+                self.output.write_str(&msg);
+                Ok(Some(Value::nil()))
+            }
+            "panic" => {
+                // panic(), panic(msg) or panic("template {}", args...)
+                let message = if args.is_empty() {
+                    "explicit panic".to_string()
+                } else {
+                    self.format_print_args("panic", args)?
+                };
+                Err(EvalError::Panic { message })
+            }
+            "println" => {
+                let msg = self.format_print_args("println", args)?;
+                self.output.write_line(&msg);
+                Ok(Some(Value::nil()))
+            }
+            "format" => {
+                // INTERP-101: format(template, args...) -> String
+                if args.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "format".to_string(),
+                        expected: 1,
+                        actual: 0,
+                    });
+                }
+                let template_val = self.eval(&args[0])?;
+                let template = template_val.as_string()?.to_string();
+                let mut values = Vec::with_capacity(args.len() - 1);
+                for arg in &args[1..] {
+                    values.push(self.eval(arg)?);
+                }
+                let formatted = self.format_template(&template, &values)?;
+                Ok(Some(Value::string(formatted)))
+            }
+            "dbg" => {
+                // INTERP-092: echo the argument's source alongside its value
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "dbg".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let value = self.eval(&args[0])?;
+                self.debug_output
+                    .write_line(&format!("{} = {}", args[0].emit(), value));
+                Ok(Some(value))
+            }
+            "assert" => {
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "assert".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let cond_val = self.eval(&args[0])?;
+                let cond_bool = cond_val.as_boolean()?;
+                if !cond_bool {
+                    return Err(EvalError::AssertionFailed {
+                        message: args[0].emit(),
+                        line: self.current_line,
+                    });
+                }
+                Ok(Some(Value::nil()))
+            }
+            "assert_eq" => {
+                // INTERP-097: equality assertion reporting both sides
+                if args.len() != 2 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "assert_eq".to_string(),
+                        expected: 2,
+                        actual: args.len(),
+                    });
+                }
+                let left = self.eval(&args[0])?;
+                let right = self.eval(&args[1])?;
+                if left != right {
+                    return Err(EvalError::AssertionFailed {
+                        message: format!(
+                            "{} == {} (left: {}, right: {})",
+                            args[0].emit(),
+                            args[1].emit(),
+                            left,
+                            right
+                        ),
+                        line: self.current_line,
+                    });
+                }
+                Ok(Some(Value::nil()))
+            }
+            "assert_approx" => {
+                // INTERP-085: Float comparison with tolerance: |a - b| <= epsilon
+                if args.len() != 3 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "assert_approx".to_string(),
+                        expected: 3,
+                        actual: args.len(),
+                    });
+                }
+                let mut numbers = [0.0; 3];
+                for (number, arg) in numbers.iter_mut().zip(args) {
+                    *number = match self.eval(arg)? {
+                        Value::Integer(n) => n as f64,
+                        Value::Float(f) => f,
+                        other => {
+                            return Err(EvalError::ValueError(ValueError::TypeMismatch {
+                                expected: "Integer or Float".to_string(),
+                                found: other.type_name().to_string(),
+                                operation: "assert_approx".to_string(),
+                            }))
+                        }
+                    };
+                }
+                let [a, b, epsilon] = numbers;
+                // A NaN epsilon would make every comparison pass
+                if epsilon.is_nan() || epsilon < 0.0 {
+                    return Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "assert_approx".to_string(),
+                        message: format!("epsilon must be a non-negative number, got {}", epsilon),
+                    }));
+                }
+                let difference = (a - b).abs();
+                if difference.is_nan() || difference > epsilon {
+                    return Err(EvalError::AssertionFailed {
+                        message: format!(
+                            "assert_approx({}, {}, {}): difference {} exceeds epsilon",
+                            a, b, epsilon, difference
+                        ),
+                        line: self.current_line,
+                    });
+                }
+                Ok(Some(Value::nil()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Collection builtins: vec, String::new, String::from, HashMap::new, len, hash
+    fn call_collection_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        match name {
+            "vec" => {
+                let mut elements = Vec::new();
+                for arg in args {
+                    elements.push(self.eval(arg)?);
+                }
+                Ok(Some(Value::vector(elements)))
+            }
+            "String::new" => {
+                if !args.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "String::new".to_string(),
+                        expected: 0,
+                        actual: args.len(),
+                    });
+                }
+                Ok(Some(Value::string(String::new())))
+            }
+            "String::from" => {
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "String::from".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let val = self.eval(&args[0])?;
+                let s = val.as_string()?;
+                Ok(Some(Value::string(s.to_string())))
+            }
+            "HashMap::new" => {
+                if !args.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "HashMap::new".to_string(),
+                        expected: 0,
+                        actual: args.len(),
+                    });
+                }
+                use std::collections::HashMap;
+                Ok(Some(Value::HashMap(HashMap::new())))
+            }
+            "len" => {
+                // INTERP-091: free-function form of the .len() method
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "len".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let len = match self.eval(&args[0])? {
+                    Value::String(s) => s.len(),
+                    Value::Vector(elements) | Value::Tuple(elements) => elements.len(),
+                    Value::HashMap(map) | Value::Struct { fields: map, .. } => map.len(),
+                    other => {
+                        return Err(EvalError::ValueError(ValueError::TypeMismatch {
+                            expected: "String, Vector, Tuple, or HashMap".to_string(),
+                            found: other.type_name().to_string(),
+                            operation: "len".to_string(),
+                        }))
+                    }
+                };
+                Ok(Some(Value::integer(len as i64)))
+            }
+            "hash" => {
+                // INTERP-094: deterministic, non-cryptographic value hash
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "hash".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let value = self.eval(&args[0])?;
+                Ok(Some(Value::integer(value.stable_hash()?)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Concurrency builtins: thread::spawn, Mutex::new, Arc::new, Arc::clone, mpsc::channel
+    fn call_concurrency_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        match name {
+            "thread::spawn" => {
+                // INTERP-042: Mock implementation - execute closure synchronously
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "thread::spawn".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let result = match &args[0] {
+                    AstNode::Closure {
+                        is_move: _,
+                        params,
+                        body,
+                    } => {
+                        if !params.is_empty() {
+                            return Err(EvalError::UnsupportedOperation {
+                                operation: format!(
+                                    "thread::spawn closures with parameters not supported (found {} params)",
+                                    params.len()
+                                ),
+                            });
+                        }
+                        let mut last_value = Value::nil();
+                        for stmt in body {
+                            last_value = self.eval(stmt)?;
+                        }
+                        last_value
+                    }
+                    // INTERP-122: A closure value (e.g. stored in a variable)
+                    // runs in its captured environment
+                    other => match self.eval(other)? {
+                        Value::Closure {
+                            params,
+                            body,
+                            captured_env,
+                        } if params.is_empty() => {
+                            self.call_closure_with_values(&params, &body, &captured_env, Vec::new())?
+                        }
+                        _ => {
+                            return Err(EvalError::UnsupportedOperation {
+                                operation: "thread::spawn requires a closure argument".to_string(),
+                            });
+                        }
+                    },
+                };
+                use std::collections::HashMap;
+                let mut handle = HashMap::new();
+                handle.insert("_thread_id".to_string(), Value::integer(1));
+                handle.insert("_result".to_string(), result);
+                Ok(Some(Value::HashMap(handle)))
+            }
+            "Mutex::new" => {
+                // INTERP-041: Just wrap locally, NO arc_store (only Arc uses arc_store)
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "Mutex::new".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let val = self.eval(&args[0])?;
+                use std::collections::HashMap;
+                let mut wrapper = HashMap::new();
+                wrapper.insert("_inner".to_string(), val);
+                Ok(Some(Value::HashMap(wrapper)))
+            }
+            "Arc::new" => {
+                // INTERP-041: Store value in arc_store for shared references
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "Arc::new".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let val = self.eval(&args[0])?;
+                let arc_id = self.next_arc_id;
+                self.arc_store.insert(arc_id, val);
+                self.next_arc_id += 1;
+                use std::collections::HashMap;
+                let mut wrapper = HashMap::new();
+                wrapper.insert("_arc_id".to_string(), Value::integer(arc_id as i64));
+                Ok(Some(Value::HashMap(wrapper)))
+            }
+            "Arc::clone" => {
+                // INTERP-041: Return HashMap with same _arc_id (shared reference!)
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "Arc::clone".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let val = self.eval(&args[0])?;
+                if let Value::HashMap(ref map) = val {
+                    if let Some(Value::Integer(arc_id)) = map.get("_arc_id") {
+                        use std::collections::HashMap;
+                        let mut wrapper = HashMap::new();
+                        wrapper.insert("_arc_id".to_string(), Value::integer(*arc_id));
+                        return Ok(Some(Value::HashMap(wrapper)));
+                    }
+                }
+                // Fallback: if not an Arc, just clone (for backwards compatibility)
+                Ok(Some(val.clone()))
+            }
+            "mpsc::channel" => {
+                if !args.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "mpsc::channel".to_string(),
+                        expected: 0,
+                        actual: args.len(),
+                    });
+                }
+                // INTERP-095: both ends share a channel id into channel_buffers
+                use std::collections::HashMap;
+                let channel_id = self.channel_buffers.len();
+                self.channel_buffers.insert(channel_id, Default::default());
+                let mut sender = HashMap::new();
+                sender.insert("_type".to_string(), Value::string("Sender".to_string()));
+                sender.insert("_channel_id".to_string(), Value::integer(channel_id as i64));
+                let mut receiver = HashMap::new();
+                receiver.insert("_type".to_string(), Value::string("Receiver".to_string()));
+                receiver.insert("_channel_id".to_string(), Value::integer(channel_id as i64));
+                Ok(Some(Value::tuple(vec![
+                    Value::HashMap(sender),
+                    Value::HashMap(receiver),
+                ])))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Timing builtins: time_now, bench (INTERP-081)
+    ///
+    /// `time_now()` returns monotonic nanoseconds since the first call in this
+    /// process. `bench(closure, n)` runs a zero-argument closure n times and
+    /// returns the mean duration in nanoseconds.
+    fn call_timing_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        match name {
+            "time_now" => {
+                if !args.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "time_now".to_string(),
+                        expected: 0,
+                        actual: args.len(),
+                    });
+                }
+                static EPOCH: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+                let nanos = EPOCH.get_or_init(std::time::Instant::now).elapsed().as_nanos();
+                Ok(Some(Value::integer(nanos as i64)))
+            }
+            "bench" => {
+                if args.len() != 2 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "bench".to_string(),
+                        expected: 2,
+                        actual: args.len(),
+                    });
+                }
+                let (params, body, captured_env) = match self.eval(&args[0])? {
+                    Value::Closure {
+                        params,
+                        body,
+                        captured_env,
+                    } => (params, body, captured_env),
+                    other => {
+                        return Err(EvalError::ValueError(ValueError::TypeMismatch {
+                            expected: "Closure".to_string(),
+                            found: other.type_name().to_string(),
+                            operation: "bench".to_string(),
+                        }))
+                    }
+                };
+                let iterations = self.eval(&args[1])?.as_integer()?;
+                if iterations <= 0 {
+                    return Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "bench".to_string(),
+                        message: format!("iteration count must be positive, got {}", iterations),
+                    }));
+                }
+                let start = std::time::Instant::now();
+                for _ in 0..iterations {
+                    self.call_closure(&params, &body, &captured_env, &[])?;
+                }
+                let mean = start.elapsed().as_nanos() / iterations as u128;
+                Ok(Some(Value::integer(mean as i64)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Metaprogramming builtins: eval_str (INTERP-086)
+    ///
+    /// `eval_str(source)` parses and evaluates a program at runtime. It runs in a
+    /// child of the current scope, so it can read and assign outer variables while
+    /// its own `let` bindings stay local. Each nested `eval_str` counts toward the
+    /// call-depth and meta-evaluation limits.
+    fn call_meta_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        match name {
+            "eval_str" => {
+                if args.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "eval_str".to_string(),
+                        expected: 1,
+                        actual: args.len(),
+                    });
+                }
+                let source_val = self.eval(&args[0])?;
+                let source = source_val.as_string()?;
+                let ast = crate::interpreter::parser::Parser::new(source)
+                    .parse()
+                    .map_err(|e| {
+                        EvalError::ValueError(ValueError::InvalidOperation {
+                            operation: "eval_str".to_string(),
+                            message: format!("parse error: {}", e),
+                        })
+                    })?;
+
+                self.enter_meta_eval()?;
+                let child_scope = self.scope.create_child();
+                let saved_scope = std::mem::replace(&mut self.scope, child_scope);
+
+                let mut result = Ok(Value::nil());
+                for node in ast.nodes() {
+                    match self.eval_internal(node).and_then(ControlFlow::outside_loop) {
+                        Ok(ControlFlow::Value(v)) => result = Ok(v),
+                        Ok(ControlFlow::Return(v)) => {
+                            result = Ok(v);
+                            break;
+                        }
+                        Ok(ControlFlow::Break(_) | ControlFlow::Continue) => {
+                            unreachable!("loop jumps are rejected by outside_loop")
+                        }
+                        Err(e) => {
+                            result = Err(e);
+                            break;
+                        }
+                    }
+                }
+
+                self.scope = saved_scope;
+                self.exit_meta_eval();
+                result.map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Try to call a built-in function
+    ///
+    /// Built-in functions are checked before user-defined functions, allowing
+    /// core functionality like I/O to be available without explicit imports.
+    /// Dispatches to group-specific handlers: I/O, collection, concurrency, timing,
+    /// and metaprogramming.
+    ///
+    /// # Return Values
+    ///
+    /// - `Ok(Some(Value))` - Built-in function executed successfully
+    /// - `Ok(None)` - Not a built-in, should try user-defined functions
+    /// - `Err(EvalError)` - Built-in function call failed (I/O error, wrong args, etc.)
+    pub(crate) fn try_call_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        if let Some(result) = self.call_io_builtin(name, args)? {
+            return Ok(Some(result));
+        }
+        if let Some(result) = self.call_collection_builtin(name, args)? {
+            return Ok(Some(result));
+        }
+        if let Some(result) = self.call_concurrency_builtin(name, args)? {
+            return Ok(Some(result));
+        }
+        if let Some(result) = self.call_timing_builtin(name, args)? {
+            return Ok(Some(result));
+        }
+        if let Some(result) = self.call_meta_builtin(name, args)? {
+            return Ok(Some(result));
+        }
+        if let Some(result) = self.call_option_builtin(name, args)? {
+            return Ok(Some(result));
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::OutputSink;
+
+    #[test]
+    fn test_builtin_functions_are_all_dispatched() {
+        // Called without arguments, a builtin either runs or rejects its
+        // arguments; only a name no group handles comes back as None
+        let mut eval = Evaluator::new().with_output(OutputSink::buffer());
+        for name in BUILTIN_FUNCTIONS {
+            let result = eval.try_call_builtin(name, &[]);
+            assert!(!matches!(result, Ok(None)), "{} is not dispatched", name);
+        }
+        assert!(matches!(eval.try_call_builtin("not_a_builtin", &[]), Ok(None)));
+    }
+}
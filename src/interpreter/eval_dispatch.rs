@@ -2,7 +2,7 @@
 //
 // These methods handle individual AST node evaluation: f-strings, ranges,
 // method calls, blocks, compound assignment, index access, vec! macros,
-// tuple destructuring, HashMap/struct literals, and field access.
+// tuple destructuring, HashMap/struct literals, enum definitions, and field access.
 //
 // Extracted from evaluator.rs for file-health compliance (<500 lines per file).
 
@@ -12,65 +12,117 @@ use super::evaluator::{ControlFlow, EvalError, Evaluator};
 impl Evaluator {
     /// Evaluate f-string interpolation: f"text {expr} more"
     ///
-    /// Scans content for `{expr}` blocks, parses and evaluates each expression,
-    /// and concatenates the results into a single string.
+    /// Scans content for `{expr}` holes, parses and evaluates each expression,
+    /// and concatenates the results into a single string. Braces and quotes
+    /// inside string literals within a hole (including nested f-strings) do
+    /// not end the hole. Each hole counts against the call-depth and
+    /// meta-evaluation budgets, so runaway nesting is an error rather than a
+    /// host crash.
     pub(crate) fn eval_fstring(&mut self, content: &str) -> Result<ControlFlow, EvalError> {
-        use crate::interpreter::parser::Parser;
-
         let mut result = String::new();
-        let mut chars = content.chars().peekable();
-
-        while let Some(ch) = chars.next() {
-            if ch == '{' {
-                // Extract expression until '}'
-                let mut expr_str = String::new();
-                let mut depth = 1;
-                for ch in chars.by_ref() {
-                    if ch == '{' {
-                        depth += 1;
-                        expr_str.push(ch);
-                    } else if ch == '}' {
+        let mut chars = content.char_indices().peekable();
+
+        while let Some((offset, ch)) = chars.next() {
+            if ch != '{' {
+                result.push(ch);
+                continue;
+            }
+
+            // Extract the hole's expression up to the matching '}'
+            let mut expr_str = String::new();
+            let mut depth = 1;
+            let mut in_string = false;
+            for (_, ch) in chars.by_ref() {
+                match ch {
+                    '"' => in_string = !in_string,
+                    '{' if !in_string => depth += 1,
+                    '}' if !in_string => {
                         depth -= 1;
                         if depth == 0 {
                             break;
                         }
-                        expr_str.push(ch);
-                    } else {
-                        expr_str.push(ch);
                     }
+                    _ => {}
                 }
-
-                // Parse and evaluate the expression
-                let mut parser = Parser::new(&expr_str);
-                let ast = parser
-                    .parse()
-                    .map_err(|e| EvalError::UnsupportedOperation {
-                        operation: format!(
-                            "Failed to parse f-string expression '{}': {:?}",
-                            expr_str, e
-                        ),
-                    })?;
-
-                // Evaluate the expression
-                if let Some(node) = ast.nodes().first() {
-                    let value = self.eval(node)?;
-                    result.push_str(&value.to_println_string());
-                }
-            } else {
-                result.push(ch);
+                expr_str.push(ch);
             }
+            if depth != 0 {
+                return Err(EvalError::UnsupportedOperation {
+                    operation: format!(
+                        "unterminated f-string hole at offset {}: '{{{}'",
+                        offset, expr_str
+                    ),
+                });
+            }
+
+            let value = self.eval_fstring_hole(&expr_str, offset)?;
+            result.push_str(&value.to_println_string());
         }
 
         Ok(ControlFlow::Value(Value::string(result)))
     }
 
-    /// Evaluate a range expression: start..end
+    /// Parse and evaluate one f-string hole, charging it to the depth budgets
+    ///
+    /// `offset` is the position of the hole's `{` within the f-string content.
+    fn eval_fstring_hole(&mut self, expr_str: &str, offset: usize) -> Result<Value, EvalError> {
+        use crate::interpreter::parser::Parser;
+
+        let ast = Parser::new(expr_str)
+            .parse()
+            .map_err(|e| EvalError::UnsupportedOperation {
+                operation: format!(
+                    "Failed to parse f-string hole at offset {} '{}': {}",
+                    offset, expr_str, e
+                ),
+            })?;
+        let Some(node) = ast.nodes().first() else {
+            return Err(EvalError::UnsupportedOperation {
+                operation: format!("empty f-string hole at offset {}", offset),
+            });
+        };
+
+        self.enter_meta_eval()?;
+        let value = self.eval(node);
+        self.exit_meta_eval();
+        value
+    }
+
+    /// Enter an f-string hole or `eval_str` (INTERP-116)
+    ///
+    /// Charges one level to both the meta-evaluation and call-depth budgets.
+    /// Every successful call must be paired with `exit_meta_eval`.
+    pub(crate) fn enter_meta_eval(&mut self) -> Result<(), EvalError> {
+        use super::evaluator::MAX_META_DEPTH;
+
+        if self.meta_depth >= MAX_META_DEPTH {
+            return Err(EvalError::MetaRecursionLimit {
+                limit: MAX_META_DEPTH,
+            });
+        }
+        if self.call_depth >= self.max_call_depth {
+            return Err(EvalError::StackOverflow);
+        }
+        self.meta_depth += 1;
+        self.call_depth += 1;
+        Ok(())
+    }
+
+    /// Leave a level entered with `enter_meta_eval`
+    pub(crate) fn exit_meta_eval(&mut self) {
+        self.meta_depth -= 1;
+        self.call_depth -= 1;
+    }
+
+    /// Evaluate a range expression: start..end or start..=end
     ///
-    /// Creates a vector of integers from start (inclusive) to end (exclusive).
+    /// Creates a vector of integers from start (inclusive) to end (exclusive
+    /// unless `inclusive`).
     pub(crate) fn eval_range(
         &mut self,
         start: &crate::interpreter::parser::AstNode,
         end: &crate::interpreter::parser::AstNode,
+        inclusive: bool,
     ) -> Result<ControlFlow, EvalError> {
         let start_val = self.eval(start)?;
         let end_val = self.eval(end)?;
@@ -94,7 +146,8 @@ impl Evaluator {
                     ),
                 })?;
 
-        // Create vector of integers from start to end (exclusive)
+        // Create vector of integers from start to end
+        let end_int = if inclusive { end_int.saturating_add(1) } else { end_int };
         let mut elements = Vec::new();
         for i in start_int..end_int {
             elements.push(Value::integer(i));
@@ -105,8 +158,10 @@ impl Evaluator {
 
     /// Evaluate a method call on a receiver expression.
     ///
-    /// Handles mutating methods (push, push_str, pop, insert) that modify
-    /// the receiver in-place, then falls back to immutable method dispatch.
+    /// Handles mutating methods (push, push_str, pop, sort, insert, remove) that
+    /// modify a variable receiver in-place. Every other receiver expression (literals,
+    /// call results, field and index accesses) is evaluated once and
+    /// dispatched through `call_method`.
     pub(crate) fn eval_method_call(
         &mut self,
         receiver: &crate::interpreter::parser::AstNode,
@@ -258,6 +313,44 @@ impl Evaluator {
             }
         }
 
+        // Special handling for sort() - sorts the vector in place (INTERP-090)
+        if method == "sort" {
+            if let AstNode::Identifier(var_name) = receiver {
+                let mut current_val = self.scope.get_cloned(var_name).map_err(|_| {
+                    EvalError::UndefinedVariable {
+                        name: var_name.clone(),
+                    }
+                })?;
+
+                if !args.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "sort".to_string(),
+                        expected: 0,
+                        actual: args.len(),
+                    });
+                }
+
+                if let Value::Vector(ref mut arr) = current_val {
+                    // Value::compare is a total order, so NaN cannot break the sort
+                    arr.sort_by(|a, b| a.compare(b));
+
+                    self.scope.assign(var_name, current_val).map_err(|_| {
+                        EvalError::UndefinedVariable {
+                            name: var_name.clone(),
+                        }
+                    })?;
+                    return Ok(ControlFlow::Value(Value::nil()));
+                } else {
+                    return Err(EvalError::UnsupportedOperation {
+                        operation: format!(
+                            "sort() requires array, got {}",
+                            current_val.type_name()
+                        ),
+                    });
+                }
+            }
+        }
+
         // Special handling for insert() - it mutates the HashMap
         if method == "insert" {
             if let AstNode::Identifier(var_name) = receiver {
@@ -279,7 +372,9 @@ impl Evaluator {
                 let key = key_val.as_string()?;
                 let value_val = self.eval(&args[1])?;
 
-                if let Value::HashMap(ref mut map) = current_val {
+                if let Value::HashMap(ref mut map) | Value::Struct { fields: ref mut map, .. } =
+                    current_val
+                {
                     map.insert(key.to_string(), value_val);
 
                     self.scope.assign(var_name, current_val).map_err(|_| {
@@ -299,7 +394,39 @@ impl Evaluator {
             }
         }
 
-        // Default method call handling
+        // Special handling for remove() on a HashMap variable (INTERP-132)
+        if method == "remove" {
+            if let AstNode::Identifier(var_name) = receiver {
+                let mut current_val = self.scope.get_cloned(var_name).map_err(|_| {
+                    EvalError::UndefinedVariable {
+                        name: var_name.clone(),
+                    }
+                })?;
+
+                if let Value::HashMap(ref mut map) | Value::Struct { fields: ref mut map, .. } =
+                    current_val
+                {
+                    if args.len() != 1 {
+                        return Err(EvalError::ArgumentCountMismatch {
+                            function: "remove".to_string(),
+                            expected: 1,
+                            actual: args.len(),
+                        });
+                    }
+                    let key_val = self.eval(&args[0])?;
+                    let removed = map.remove(key_val.as_string()?).unwrap_or_else(Value::nil);
+
+                    self.scope.assign(var_name, current_val).map_err(|_| {
+                        EvalError::UndefinedVariable {
+                            name: var_name.clone(),
+                        }
+                    })?;
+                    return Ok(ControlFlow::Value(removed));
+                }
+            }
+        }
+
+        // Default method call handling: evaluate any receiver expression
         let receiver_val = self.eval(receiver)?;
         let result = self.call_method(receiver_val, method, args)?;
         Ok(ControlFlow::Value(result))
@@ -324,8 +451,9 @@ impl Evaluator {
         for stmt in statements {
             match self.eval_internal(stmt) {
                 Ok(ControlFlow::Value(v)) => last_value = v,
-                Ok(ControlFlow::Return(v)) => {
-                    early_exit = Some(Ok(ControlFlow::Return(v)));
+                Ok(jump) => {
+                    // Early return or loop jump
+                    early_exit = Some(Ok(jump));
                     break;
                 }
                 Err(e) => {
@@ -420,6 +548,11 @@ impl Evaluator {
                         .to_string(),
                 })
             }
+        } else if let AstNode::IndexAccess { expr, index } = lhs {
+            // For elements: v[i] += 1
+            let index_val = self.eval(index)?;
+            self.assign_index(expr, index_val, new_val)?;
+            Ok(ControlFlow::Value(Value::nil()))
         } else {
             Err(EvalError::UnsupportedOperation {
                 operation: format!("compound assignment to {}", "complex expression"),
@@ -427,6 +560,130 @@ impl Evaluator {
         }
     }
 
+    /// Store `new_val` at `index` of the collection `target` names (INTERP-120)
+    ///
+    /// Vectors take an in-bounds integer index; hashmaps take a string key
+    /// and insert it if absent. A nested target (`g[r][c] = x`) updates the
+    /// inner collection and writes it back into the outer one, down to the
+    /// variable, so every level is assigned through `Scope::assign`.
+    pub(crate) fn assign_index(
+        &mut self,
+        target: &crate::interpreter::parser::AstNode,
+        index_val: Value,
+        new_val: Value,
+    ) -> Result<(), EvalError> {
+        use crate::interpreter::value::ValueError;
+
+        let mut container = self.read_place(target, "index assignment")?;
+
+        match &mut container {
+            Value::Vector(elements) => {
+                let idx = index_val.as_integer()?;
+                if idx < 0 {
+                    return Err(EvalError::ValueError(ValueError::InvalidOperation {
+                        operation: "vector index".to_string(),
+                        message: "index cannot be negative".to_string(),
+                    }));
+                }
+                let len = elements.len();
+                let slot = elements
+                    .get_mut(idx as usize)
+                    .ok_or(ValueError::IndexOutOfBounds {
+                        index: idx as usize,
+                        len,
+                    })?;
+                *slot = new_val;
+            }
+            Value::HashMap(map) | Value::Struct { fields: map, .. } => {
+                let key = index_val.as_string()?.to_string();
+                map.insert(key, new_val);
+            }
+            other => {
+                return Err(EvalError::ValueError(ValueError::TypeMismatch {
+                    expected: "Vector or HashMap".to_string(),
+                    found: other.type_name().to_string(),
+                    operation: "index assignment".to_string(),
+                }))
+            }
+        }
+
+        self.write_place(target, container)
+    }
+
+    /// Assign `p.x = new_val` (INTERP-137)
+    ///
+    /// Struct fields must already exist; HashMap keys are inserted. The
+    /// updated value is written back to `target` like an index assignment.
+    pub(crate) fn assign_field(
+        &mut self,
+        target: &crate::interpreter::parser::AstNode,
+        field: &str,
+        new_val: Value,
+    ) -> Result<(), EvalError> {
+        use crate::interpreter::value::ValueError;
+
+        let mut container = self.read_place(target, "field assignment")?;
+        match &mut container {
+            Value::Struct { fields, .. } => {
+                let slot = fields.get_mut(field).ok_or_else(|| ValueError::KeyNotFound {
+                    key: field.to_string(),
+                })?;
+                *slot = new_val;
+            }
+            Value::HashMap(map) => {
+                map.insert(field.to_string(), new_val);
+            }
+            other => {
+                return Err(EvalError::UnsupportedOperation {
+                    operation: format!("field assignment on {}", other.type_name()),
+                })
+            }
+        }
+        self.write_place(target, container)
+    }
+
+    /// Current value of an assignment target (a variable, `a[i]` or `a.f`)
+    fn read_place(
+        &mut self,
+        target: &crate::interpreter::parser::AstNode,
+        operation: &str,
+    ) -> Result<Value, EvalError> {
+        use crate::interpreter::parser::AstNode;
+
+        match target.without_span() {
+            AstNode::Identifier(name) => self
+                .scope
+                .get_cloned(name)
+                .map_err(|_| EvalError::UndefinedVariable { name: name.clone() }),
+            AstNode::IndexAccess { .. } | AstNode::FieldAccess { .. } => self.eval(target),
+            _ => Err(EvalError::UnsupportedOperation {
+                operation: format!("{} to a temporary value", operation),
+            }),
+        }
+    }
+
+    /// Store an updated container back into the target `read_place` read
+    fn write_place(
+        &mut self,
+        target: &crate::interpreter::parser::AstNode,
+        container: Value,
+    ) -> Result<(), EvalError> {
+        use crate::interpreter::parser::AstNode;
+
+        match target.without_span() {
+            AstNode::Identifier(name) => self
+                .scope
+                .assign(name, container)
+                .map_err(|_| EvalError::UndefinedVariable { name: name.clone() }),
+            AstNode::IndexAccess { expr, index } => {
+                let outer_index = self.eval(index)?;
+                self.assign_index(expr, outer_index, container)
+            }
+            AstNode::FieldAccess { expr, field } => self.assign_field(expr, field, container),
+            _ => unreachable!("non-assignable targets are rejected by read_place"),
+        }
+    }
+
     /// Evaluate index access: vec[i] or map[key]
     ///
     /// For vectors, the index must be a non-negative integer.
@@ -453,7 +710,7 @@ impl Evaluator {
                 let result = container.index(idx as usize)?.clone();
                 Ok(ControlFlow::Value(result))
             }
-            Value::HashMap(_) => {
+            Value::HashMap(_) | Value::Struct { .. } => {
                 let result = container.get(&index_val)?.clone();
                 Ok(ControlFlow::Value(result))
             }
@@ -517,48 +774,165 @@ impl Evaluator {
         }
     }
 
+    /// Evaluate `let pattern = value else { ... }`
+    ///
+    /// On a match the pattern's variables are bound in the current scope;
+    /// otherwise the else block runs and must leave (return/break/continue).
+    pub(crate) fn eval_let_else(
+        &mut self,
+        pattern: &crate::interpreter::parser::Pattern,
+        value: &crate::interpreter::parser::AstNode,
+        else_block: &[crate::interpreter::parser::AstNode],
+    ) -> Result<ControlFlow, EvalError> {
+        let val = self.eval(value)?;
+        if self.match_pattern(pattern, &val)? {
+            return Ok(ControlFlow::Value(Value::nil()));
+        }
+        match self.eval_block(else_block)? {
+            ControlFlow::Value(_) => Err(EvalError::UnsupportedOperation {
+                operation: "let-else block must not fall through".to_string(),
+            }),
+            jump => Ok(jump),
+        }
+    }
+
+    /// Evaluate `if let pattern = scrutinee { .. } else { .. }` (INTERP-123)
+    ///
+    /// The pattern binds into a child scope that only the then-branch sees,
+    /// so a failed (partial) match leaves nothing behind for the else-branch.
+    pub(crate) fn eval_if_let(
+        &mut self,
+        pattern: &crate::interpreter::parser::Pattern,
+        scrutinee: &crate::interpreter::parser::AstNode,
+        then_branch: &[crate::interpreter::parser::AstNode],
+        else_branch: &Option<Vec<crate::interpreter::parser::AstNode>>,
+    ) -> Result<ControlFlow, EvalError> {
+        let val = self.eval(scrutinee)?;
+
+        let child_scope = self.scope.create_child();
+        let parent_scope = std::mem::replace(&mut self.scope, child_scope);
+        let result = match self.match_pattern(pattern, &val) {
+            Ok(true) => Some(self.eval_block(then_branch)),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        };
+        self.scope = parent_scope;
+
+        match (result, else_branch) {
+            (Some(result), _) => result,
+            (None, Some(else_branch)) => self.eval_block(else_branch),
+            (None, None) => Ok(ControlFlow::Value(Value::nil())),
+        }
+    }
+
+    /// Evaluate `while let pattern = scrutinee { body }` (INTERP-123)
+    ///
+    /// Each iteration matches a fresh scrutinee value in its own scope; the
+    /// loop ends at the first mismatch and evaluates to nil.
+    pub(crate) fn eval_while_let(
+        &mut self,
+        pattern: &crate::interpreter::parser::Pattern,
+        scrutinee: &crate::interpreter::parser::AstNode,
+        body: &[crate::interpreter::parser::AstNode],
+    ) -> Result<ControlFlow, EvalError> {
+        loop {
+            let val = self.eval(scrutinee)?;
+
+            let child_scope = self.scope.create_child();
+            let parent_scope = std::mem::replace(&mut self.scope, child_scope);
+            let exit = match self.match_pattern(pattern, &val) {
+                Ok(true) => self.eval_loop_body_impl(body),
+                Ok(false) => Ok(Some(ControlFlow::Break(None))),
+                Err(e) => Err(e),
+            };
+            self.scope = parent_scope;
+
+            match exit? {
+                Some(ControlFlow::Break(None)) => break,
+                Some(ControlFlow::Break(Some(_))) => return Err(ControlFlow::break_value_error()),
+                Some(exit) => return Ok(exit),
+                None => {}
+            }
+        }
+
+        Ok(ControlFlow::Value(Value::nil()))
+    }
+
     /// Evaluate tuple destructuring: let (a, b, c) = expr
     ///
     /// Evaluates the RHS, verifies it is a tuple with matching arity,
-    /// and binds each element to the corresponding name.
+    /// and binds each element to the corresponding pattern, recursing into
+    /// nested tuples (INTERP-136).
     pub(crate) fn eval_tuple_destruct(
         &mut self,
-        names: &[String],
+        patterns: &[crate::interpreter::parser::Pattern],
         value: &crate::interpreter::parser::AstNode,
     ) -> Result<ControlFlow, EvalError> {
         let tuple_val = self.eval(value)?;
+        self.destructure_tuple(patterns, &tuple_val, "")?;
+        Ok(ControlFlow::Value(Value::nil()))
+    }
 
-        let elements = match tuple_val {
-            Value::Tuple(ref elems) => elems.clone(),
-            _ => {
-                return Err(EvalError::UnsupportedOperation {
-                    operation: format!(
-                        "tuple destructuring requires tuple, got {}",
-                        tuple_val.type_name()
-                    ),
-                })
-            }
+    /// Bind `value` against tuple `patterns`, recursing into nested tuples
+    ///
+    /// `path` names the element being destructured (`.1.0`) so arity and
+    /// type errors say where in the pattern they happened.
+    fn destructure_tuple(
+        &mut self,
+        patterns: &[crate::interpreter::parser::Pattern],
+        value: &Value,
+        path: &str,
+    ) -> Result<(), EvalError> {
+        use crate::interpreter::parser::Pattern;
+
+        let at = if path.is_empty() {
+            String::new()
+        } else {
+            format!(" at {}", path)
+        };
+        let Value::Tuple(elements) = value else {
+            return Err(EvalError::UnsupportedOperation {
+                operation: format!(
+                    "tuple destructuring requires tuple{}, got {}",
+                    at,
+                    value.type_name()
+                ),
+            });
         };
 
-        if names.len() != elements.len() {
+        if patterns.len() != elements.len() {
             return Err(EvalError::UnsupportedOperation {
                 operation: format!(
-                    "tuple destructuring: expected {} elements, got {}",
-                    names.len(),
+                    "tuple destructuring: expected {} elements{}, got {}",
+                    patterns.len(),
+                    at,
                     elements.len()
                 ),
             });
         }
 
-        for (name, elem) in names.iter().zip(elements.iter()) {
-            self.scope.define(name.clone(), elem.clone()).map_err(|e| {
-                EvalError::UnsupportedOperation {
-                    operation: format!("define variable in tuple destructuring: {}", e),
+        for (index, (pattern, elem)) in patterns.iter().zip(elements.iter()).enumerate() {
+            match pattern {
+                Pattern::Tuple(inner) => {
+                    self.destructure_tuple(inner, elem, &format!("{}.{}", path, index))?
                 }
-            })?;
+                Pattern::Wildcard => {}
+                Pattern::Identifier(name) => {
+                    self.scope.define(name.clone(), elem.clone()).map_err(|e| {
+                        EvalError::UnsupportedOperation {
+                            operation: format!("define variable in tuple destructuring: {}", e),
+                        }
+                    })?
+                }
+                other => {
+                    return Err(EvalError::UnsupportedOperation {
+                        operation: format!("tuple destructuring: unsupported pattern {:?}", other),
+                    })
+                }
+            }
         }
 
-        Ok(ControlFlow::Value(Value::nil()))
+        Ok(())
     }
 
     /// Evaluate a HashMap literal: {key1: val1, key2: val2, ...}
@@ -577,9 +951,10 @@ impl Evaluator {
         Ok(ControlFlow::Value(Value::HashMap(map)))
     }
 
-    /// Evaluate a struct literal, creating it as a HashMap.
+    /// Evaluate a struct literal, creating a struct value tagged with its type.
     pub(crate) fn eval_struct_literal(
         &mut self,
+        name: &str,
         fields: &[(String, crate::interpreter::parser::AstNode)],
     ) -> Result<ControlFlow, EvalError> {
         use std::collections::HashMap;
@@ -588,7 +963,73 @@ impl Evaluator {
             let field_val = self.eval(field_val_node)?;
             map.insert(field_name.clone(), field_val);
         }
-        Ok(ControlFlow::Value(Value::HashMap(map)))
+        Ok(ControlFlow::Value(Value::Struct {
+            name: name.to_string(),
+            fields: map,
+        }))
+    }
+
+    /// Evaluate an enum definition, registering each variant as `Name::Variant`.
+    ///
+    /// Discriminants follow C rules: a variant without an explicit value
+    /// takes the previous discriminant plus one (starting at 0). Explicit
+    /// values must be integers, and no two variants may share a value.
+    pub(crate) fn eval_enum_def(
+        &mut self,
+        name: &str,
+        variants: &[crate::interpreter::parser::EnumVariant],
+    ) -> Result<ControlFlow, EvalError> {
+        let invalid = |message: String| {
+            EvalError::ValueError(crate::interpreter::value::ValueError::InvalidOperation {
+                operation: format!("enum {}", name),
+                message,
+            })
+        };
+
+        let mut next = Some(0i64);
+        let mut assigned: Vec<(i64, &str)> = Vec::with_capacity(variants.len());
+        let mut defined = Vec::with_capacity(variants.len());
+        for variant in variants {
+            let discriminant = match &variant.discriminant {
+                Some(expr) => match self.eval(expr)? {
+                    Value::Integer(n) => n,
+                    other => {
+                        return Err(invalid(format!(
+                            "discriminant of {}::{} must be an integer, found {}",
+                            name,
+                            variant.name,
+                            other.type_name()
+                        )))
+                    }
+                },
+                None => next.ok_or_else(|| {
+                    invalid(format!("discriminant of {}::{} overflows", name, variant.name))
+                })?,
+            };
+
+            if let Some((_, previous)) = assigned.iter().find(|(d, _)| *d == discriminant) {
+                return Err(invalid(format!(
+                    "discriminant {} of {}::{} is already used by {}::{}",
+                    discriminant, name, variant.name, name, previous
+                )));
+            }
+            assigned.push((discriminant, &variant.name));
+            next = discriminant.checked_add(1);
+
+            defined.push((
+                format!("{}::{}", name, variant.name),
+                Value::EnumVariant {
+                    enum_name: name.to_string(),
+                    variant: variant.name.clone(),
+                    discriminant,
+                    payload: None,
+                },
+            ));
+        }
+
+        // Register only once every discriminant is valid
+        self.enum_variants.extend(defined);
+        Ok(ControlFlow::Value(Value::nil()))
     }
 
     /// Evaluate field access on a struct/object value.
@@ -599,7 +1040,7 @@ impl Evaluator {
     ) -> Result<ControlFlow, EvalError> {
         let value = self.eval(expr)?;
         match &value {
-            Value::HashMap(_) => {
+            Value::HashMap(_) | Value::Struct { .. } => {
                 let key = Value::string(field.to_string());
                 let result = value.get(&key)?.clone();
                 Ok(ControlFlow::Value(result))
@@ -1,11 +1,12 @@
 // Evaluator helper methods: binary ops, unary ops, type casting,
-// function/closure/method calls, built-in functions, and control flow.
+// function/closure/method calls, and control flow.
 //
 // Extracted from evaluator.rs for file-health compliance (<2000 lines).
 
 use crate::interpreter::parser::{AstNode, BinaryOperator, UnaryOperator};
 use crate::interpreter::value::{Value, ValueError};
-use super::evaluator::{ControlFlow, EvalError, Evaluator, MAX_CALL_DEPTH};
+use super::evaluator::{ControlFlow, EvalError, Evaluator};
+use std::sync::atomic::Ordering;
 
 impl Evaluator {
     /// Evaluate a binary operation
@@ -14,6 +15,7 @@ impl Evaluator {
     /// - Arithmetic: +, -, *, /, %
     /// - Comparison: <, >, ==, !=, <=, >=
     /// - Logical: &&, ||
+    /// - Bitwise: &, |, ^, <<, >> (integers only)
     ///
     /// Type checking is performed by Value methods.
     pub(crate) fn eval_binary_op(
@@ -22,6 +24,28 @@ impl Evaluator {
         left: Value,
         right: Value,
     ) -> Result<Value, EvalError> {
+        let (left, right) = match op {
+            BinaryOperator::Add
+            | BinaryOperator::Subtract
+            | BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::Modulo
+                if matches!(left, Value::Boolean(_)) || matches!(right, Value::Boolean(_)) =>
+            {
+                (
+                    self.arithmetic_operand(left, op.symbol())?,
+                    self.arithmetic_operand(right, op.symbol())?,
+                )
+            }
+            _ => (left, right),
+        };
+
+        if self.wrapping_arithmetic {
+            if let Some(wrapped) = Self::wrapping_integer_op(op, &left, &right) {
+                return Ok(wrapped);
+            }
+        }
+
         match op {
             // Arithmetic operators
             BinaryOperator::Add => Ok(left.add(&right)?),
@@ -55,11 +79,105 @@ impl Evaluator {
             // Logical operators
             BinaryOperator::And => Ok(left.logical_and(&right)?),
             BinaryOperator::Or => Ok(left.logical_or(&right)?),
+
+            // Bitwise operators (INTERP-126)
+            BinaryOperator::BitAnd
+            | BinaryOperator::BitOr
+            | BinaryOperator::BitXor
+            | BinaryOperator::Shl
+            | BinaryOperator::Shr => self.eval_bitwise(op, left, right),
+        }
+    }
+
+    /// Evaluate a bitwise operator on two integers (INTERP-126)
+    ///
+    /// Shift amounts must be in 0..64; `>>` is arithmetic (sign-extending).
+    pub(crate) fn eval_bitwise(
+        &self,
+        op: BinaryOperator,
+        left: Value,
+        right: Value,
+    ) -> Result<Value, EvalError> {
+        let (Value::Integer(a), Value::Integer(b)) = (&left, &right) else {
+            return Err(EvalError::ValueError(ValueError::TypeMismatch {
+                expected: "Integer".to_string(),
+                found: format!("{} {} {}", left.type_name(), op.symbol(), right.type_name()),
+                operation: format!("bitwise ({})", op.symbol()),
+            }));
+        };
+        let shift = || {
+            u32::try_from(*b).ok().filter(|&n| n < i64::BITS).ok_or_else(|| {
+                EvalError::ValueError(ValueError::InvalidOperation {
+                    operation: op.symbol().to_string(),
+                    message: format!("shift amount {} is out of range 0..64", b),
+                })
+            })
+        };
+        let result = match op {
+            BinaryOperator::BitAnd => a & b,
+            BinaryOperator::BitOr => a | b,
+            BinaryOperator::BitXor => a ^ b,
+            BinaryOperator::Shl => a << shift()?,
+            BinaryOperator::Shr => a >> shift()?,
+            _ => unreachable!("eval_bitwise called with {:?}", op),
+        };
+        Ok(Value::integer(result))
+    }
+
+    /// Check a boolean arithmetic operand against the bool-arithmetic flag (INTERP-106)
+    ///
+    /// Booleans become 0/1 when the flag is set and are a type mismatch
+    /// otherwise; other values pass through unchanged.
+    fn arithmetic_operand(&self, value: Value, symbol: &str) -> Result<Value, EvalError> {
+        match value {
+            Value::Boolean(b) if self.bool_arithmetic => Ok(Value::integer(b as i64)),
+            Value::Boolean(_) => Err(EvalError::ValueError(ValueError::TypeMismatch {
+                expected: "Integer or Float".to_string(),
+                found: "Boolean".to_string(),
+                operation: format!("arithmetic ({})", symbol),
+            })),
+            other => Ok(other),
         }
     }
 
+    /// Integer arithmetic in wrapping mode (INTERP-133)
+    ///
+    /// Returns None for non-integer operands, other operators, and division
+    /// by zero, which take the normal checked path.
+    fn wrapping_integer_op(op: BinaryOperator, left: &Value, right: &Value) -> Option<Value> {
+        let (Value::Integer(a), Value::Integer(b)) = (left, right) else {
+            return None;
+        };
+        let result = match op {
+            BinaryOperator::Add => a.wrapping_add(*b),
+            BinaryOperator::Subtract => a.wrapping_sub(*b),
+            BinaryOperator::Multiply => a.wrapping_mul(*b),
+            BinaryOperator::Divide if *b != 0 => a.wrapping_div(*b),
+            BinaryOperator::Modulo if *b != 0 => a.wrapping_rem(*b),
+            _ => return None,
+        };
+        Some(Value::integer(result))
+    }
+
     /// Evaluate modulo operation
+    ///
+    /// Integers use truncated remainder; if either side is a float both are
+    /// taken as floats (INTERP-135). The result has the sign of the left
+    /// operand, like Rust's `%`. A zero divisor is an error for both.
     pub(crate) fn eval_modulo(&self, left: Value, right: Value) -> Result<Value, EvalError> {
+        if matches!(left, Value::Float(_)) || matches!(right, Value::Float(_)) {
+            let as_float = |value: &Value| match value {
+                Value::Integer(n) => Ok(*n as f64),
+                other => other.as_float(),
+            };
+            let dividend = as_float(&left)?;
+            let divisor = as_float(&right)?;
+            if divisor == 0.0 {
+                return Err(EvalError::ValueError(ValueError::DivisionByZero));
+            }
+            return Ok(Value::float(dividend % divisor));
+        }
+
         let left_int = left.as_integer()?;
         let right_int = right.as_integer()?;
 
@@ -67,23 +185,38 @@ impl Evaluator {
             return Err(EvalError::ValueError(ValueError::DivisionByZero));
         }
 
-        Ok(Value::integer(left_int % right_int))
+        // i64::MIN % -1 overflows (INTERP-133)
+        let result = left_int.checked_rem(right_int).ok_or_else(|| {
+            EvalError::ValueError(ValueError::IntegerOverflow {
+                operation: "modulo".to_string(),
+            })
+        })?;
+        Ok(Value::integer(result))
     }
 
     /// Evaluate a unary operation
     pub(crate) fn eval_unary_op(&self, op: UnaryOperator, operand: Value) -> Result<Value, EvalError> {
         match op {
             UnaryOperator::Negate => {
-                let n = operand.as_integer()?;
-                Ok(Value::integer(-n))
+                let n = self.arithmetic_operand(operand, "-")?.as_integer()?;
+                if self.wrapping_arithmetic {
+                    return Ok(Value::integer(n.wrapping_neg()));
+                }
+                // -i64::MIN does not fit in an i64 (INTERP-133)
+                let negated = n.checked_neg().ok_or_else(|| {
+                    EvalError::ValueError(ValueError::IntegerOverflow {
+                        operation: "negate".to_string(),
+                    })
+                })?;
+                Ok(Value::integer(negated))
             }
             UnaryOperator::Plus => {
                 // Unary plus is identity for integers
-                let n = operand.as_integer()?;
+                let n = self.arithmetic_operand(operand, "+")?.as_integer()?;
                 Ok(Value::integer(n))
             }
             UnaryOperator::Not => {
-                // Logical NOT for booleans
+                // Logical NOT for booleans (and integers: !0 is true)
                 Ok(operand.logical_not()?)
             }
             UnaryOperator::Dereference => {
@@ -124,21 +257,23 @@ impl Evaluator {
     /// Evaluate a type cast
     pub(crate) fn eval_type_cast(&mut self, value: Value, target_type: &str) -> Result<Value, EvalError> {
         match target_type {
-            "i32" => {
-                // Cast to integer
-                if let Ok(i) = value.as_integer() {
+            "i32" | "i64" => {
+                // Cast to integer (enum variants yield their discriminant)
+                if let Value::EnumVariant { discriminant, .. } = value {
+                    Ok(Value::integer(discriminant))
+                } else if let Ok(i) = value.as_integer() {
                     Ok(Value::integer(i))
                 } else if let Ok(f) = value.as_float() {
                     Ok(Value::integer(f as i64))
                 } else if let Ok(s) = value.as_string() {
                     s.parse::<i64>().map(Value::integer).map_err(|_| {
                         EvalError::UnsupportedOperation {
-                            operation: format!("cannot cast string '{}' to i32", s),
+                            operation: format!("cannot cast string '{}' to {}", s, target_type),
                         }
                     })
                 } else {
                     Err(EvalError::UnsupportedOperation {
-                        operation: format!("cannot cast {} to i32", value.type_name()),
+                        operation: format!("cannot cast {} to {}", value.type_name(), target_type),
                     })
                 }
             }
@@ -189,6 +324,8 @@ impl Evaluator {
     ///
     /// Returns the last expression value or explicit return value.
     pub(crate) fn call_function(&mut self, name: &str, args: &[AstNode]) -> Result<Value, EvalError> {
+        self.check_interrupt()?;
+
         // DEBUGGER-047: Track function calls if profiler is attached
         if let Some(ref profiler) = self.performance_profiler {
             profiler.record_function_call(name);
@@ -196,7 +333,7 @@ impl Evaluator {
         }
 
         // 1. Check stack depth before recursing (prevent stack overflow)
-        if self.call_depth >= MAX_CALL_DEPTH {
+        if self.call_depth >= self.max_call_depth {
             // DEBUGGER-047: Pop call stack before early return
             if let Some(ref profiler) = self.performance_profiler {
                 if let Some((func_name, duration)) = profiler.pop_call_stack() {
@@ -258,6 +395,63 @@ impl Evaluator {
             arg_values.push(self.eval(arg)?);
         }
 
+        self.invoke_function(name, &params, &body, arg_values)
+    }
+
+    /// Call a user-defined function with already-evaluated arguments
+    ///
+    /// Used for method dispatch (`receiver.method(args)` on structs), where the
+    /// receiver is passed as the first argument.
+    pub(crate) fn call_function_with_values(
+        &mut self,
+        name: &str,
+        arg_values: Vec<Value>,
+    ) -> Result<Value, EvalError> {
+        self.check_interrupt()?;
+
+        let (params, body) =
+            self.functions
+                .get(name)
+                .cloned()
+                .ok_or_else(|| EvalError::UndefinedFunction {
+                    name: name.to_string(),
+                })?;
+
+        if arg_values.len() != params.len() {
+            return Err(EvalError::ArgumentCountMismatch {
+                function: name.to_string(),
+                expected: params.len(),
+                actual: arg_values.len(),
+            });
+        }
+
+        // DEBUGGER-047: Track function calls if profiler is attached
+        if let Some(ref profiler) = self.performance_profiler {
+            profiler.record_function_call(name);
+            profiler.push_call_stack(name.to_string());
+        }
+
+        if self.call_depth >= self.max_call_depth {
+            // DEBUGGER-047: Pop call stack before early return
+            if let Some(ref profiler) = self.performance_profiler {
+                if let Some((func_name, duration)) = profiler.pop_call_stack() {
+                    profiler.record_eval_operation(func_name, duration);
+                }
+            }
+            return Err(EvalError::StackOverflow);
+        }
+
+        self.invoke_function(name, &params, &body, arg_values)
+    }
+
+    /// Bind arguments in a fresh scope and execute a function body (steps 6-8)
+    fn invoke_function(
+        &mut self,
+        name: &str,
+        params: &[String],
+        body: &[AstNode],
+        arg_values: Vec<Value>,
+    ) -> Result<Value, EvalError> {
         // INTERP-046: Collect param types ONLY if profiler is enabled (avoid overhead)
         let param_types_for_profiling: Option<Vec<String>> = if self.compiler_profiler.is_some() {
             Some(
@@ -273,7 +467,7 @@ impl Evaluator {
         // 6. Create new scope and bind parameters to argument values
         let saved_scope = std::mem::take(&mut self.scope);
         // INTERP-046: Use into_iter() to consume arg_values and avoid cloning
-        for (param, value) in params.iter().zip(arg_values.into_iter()) {
+        for (param, value) in params.iter().zip(arg_values) {
             self.scope.define(param.clone(), value).map_err(|e| {
                 EvalError::UnsupportedOperation {
                     operation: format!("define parameter: {}", e),
@@ -285,6 +479,10 @@ impl Evaluator {
         // This enables displaying the full call chain when errors occur,
         // making debugging much easier (shows which functions led to the error)
         self.call_stack.push(name.to_string());
+        // INTERP-080: Record where this frame was called from
+        self.call_sites.push(self.current_line.take());
+        // INTERP-114: Keep the caller's scope for backtraces
+        self.push_frame_scope(&saved_scope);
 
         // Increment call depth for recursion tracking
         self.call_depth += 1;
@@ -306,8 +504,8 @@ impl Evaluator {
 
         // 7. Execute function body, handling early returns
         let mut result = Value::nil();
-        for stmt in &body {
-            match self.eval_internal(stmt) {
+        for stmt in body {
+            match self.eval_internal(stmt).and_then(ControlFlow::outside_loop) {
                 Ok(ControlFlow::Value(v)) => {
                     // Normal evaluation - update result and continue
                     result = v;
@@ -317,6 +515,9 @@ impl Evaluator {
                     result = v;
                     break;
                 }
+                Ok(ControlFlow::Break(_) | ControlFlow::Continue) => {
+                    unreachable!("loop jumps are rejected by outside_loop")
+                }
                 Err(e) => {
                     // Error occurred during function body execution
                     //
@@ -324,10 +525,13 @@ impl Evaluator {
                     // The captured stack includes all functions in the call chain up to and
                     // including the current function where the error occurred.
                     let captured_stack = self.call_stack.clone();
+                    let captured_sites = self.call_sites.clone();
 
                     // Restore evaluator state (depth, stack, scope)
                     self.call_depth -= 1;
                     self.call_stack.pop(); // Remove current function from active stack
+                    self.current_line = self.call_sites.pop().flatten();
+                    self.pop_frame_scope();
                     self.scope = saved_scope;
 
                     // DEBUGGER-047: Pop profiler call stack on error
@@ -344,6 +548,7 @@ impl Evaluator {
                         _ => EvalError::WithCallStack {
                             error: Box::new(e),
                             call_stack: captured_stack, // Attach the call stack
+                            call_sites: captured_sites,
                         },
                     });
                 }
@@ -353,6 +558,8 @@ impl Evaluator {
         // 8. Restore previous scope, call depth, and call stack
         self.call_depth -= 1;
         self.call_stack.pop();
+        self.current_line = self.call_sites.pop().flatten();
+        self.pop_frame_scope();
         self.scope = saved_scope;
 
         // DEBUGGER-047: Pop profiler call stack on success and record timing
@@ -413,6 +620,27 @@ impl Evaluator {
             arg_values.push(self.eval(arg)?);
         }
 
+        self.call_closure_with_values(params, body, captured_env, arg_values)
+    }
+
+    /// Call a closure with already-evaluated arguments
+    ///
+    /// Used by methods that take a callback (e.g. `Option::map`).
+    pub(crate) fn call_closure_with_values(
+        &mut self,
+        params: &[String],
+        body: &[AstNode],
+        captured_env: &std::collections::HashMap<String, Value>,
+        arg_values: Vec<Value>,
+    ) -> Result<Value, EvalError> {
+        if arg_values.len() != params.len() {
+            return Err(EvalError::ArgumentCountMismatch {
+                function: "<closure>".to_string(),
+                expected: params.len(),
+                actual: arg_values.len(),
+            });
+        }
+
         // 3. Save current scope and create new child scope
         let child_scope = self.scope.create_child();
         let saved_scope = std::mem::replace(&mut self.scope, child_scope);
@@ -430,7 +658,7 @@ impl Evaluator {
         // 6. Execute closure body
         let mut result = Value::nil();
         for stmt in body {
-            match self.eval_internal(stmt) {
+            match self.eval_internal(stmt).and_then(ControlFlow::outside_loop) {
                 Ok(ControlFlow::Value(v)) => {
                     result = v;
                 }
@@ -439,6 +667,9 @@ impl Evaluator {
                     result = v;
                     break;
                 }
+                Ok(ControlFlow::Break(_) | ControlFlow::Continue) => {
+                    unreachable!("loop jumps are rejected by outside_loop")
+                }
                 Err(e) => {
                     // Error occurred - restore scope before propagating
                     self.scope = saved_scope;
@@ -455,7 +686,9 @@ impl Evaluator {
 
     /// Call a method on a string receiver
     ///
-    /// Handles: len, is_empty, contains, to_string
+    /// Handles: len, is_empty, contains, to_string, push_str (temporaries),
+    /// and the text methods (INTERP-130): trim, to_uppercase, to_lowercase,
+    /// starts_with, ends_with, split, replace
     fn call_string_method(
         s: &str,
         method: &str,
@@ -503,13 +736,75 @@ impl Evaluator {
                 }
                 Ok(Some(Value::string(s.to_string())))
             }
+            "push_str" => {
+                // In-place on variables (eval_method_call); a temporary is just discarded
+                if arg_values.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "push_str".to_string(),
+                        expected: 1,
+                        actual: arg_values.len(),
+                    });
+                }
+                arg_values[0].as_string()?;
+                Ok(Some(Value::nil()))
+            }
+            "trim" | "to_uppercase" | "to_lowercase" => {
+                if !arg_values.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: format!("String.{}()", method),
+                        expected: 0,
+                        actual: arg_values.len(),
+                    });
+                }
+                let result = match method {
+                    "trim" => s.trim().to_string(),
+                    "to_uppercase" => s.to_uppercase(),
+                    _ => s.to_lowercase(),
+                };
+                Ok(Some(Value::string(result)))
+            }
+            "starts_with" | "ends_with" | "split" => {
+                if arg_values.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: format!("String.{}()", method),
+                        expected: 1,
+                        actual: arg_values.len(),
+                    });
+                }
+                let pattern = arg_values[0].as_string()?;
+                Ok(Some(match method {
+                    "starts_with" => Value::boolean(s.starts_with(pattern)),
+                    "ends_with" => Value::boolean(s.ends_with(pattern)),
+                    _ => Value::vector(
+                        s.split(pattern)
+                            .map(|part| Value::string(part.to_string()))
+                            .collect(),
+                    ),
+                }))
+            }
+            "replace" => {
+                if arg_values.len() != 2 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "String.replace()".to_string(),
+                        expected: 2,
+                        actual: arg_values.len(),
+                    });
+                }
+                let from = arg_values[0].as_string()?;
+                let to = arg_values[1].as_string()?;
+                Ok(Some(Value::string(s.replace(from, to))))
+            }
             _ => Ok(None),
         }
     }
 
     /// Call a method on an array receiver
     ///
-    /// Handles: len, is_empty, push
+    /// Handles: len, is_empty, push, pop, sort, and the reductions (INTERP-098)
+    ///
+    /// push/pop/sort on a variable mutate it in place (eval_method_call); they
+    /// only reach here for temporaries such as literals and call results,
+    /// where the mutation is unobservable: pop returns the last element.
     fn call_array_method(
         arr: &[Value],
         method: &str,
@@ -549,20 +844,168 @@ impl Evaluator {
                 }
                 Ok(Some(Value::nil()))
             }
+            "pop" | "sort" => {
+                if !arg_values.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: method.to_string(),
+                        expected: 0,
+                        actual: arg_values.len(),
+                    });
+                }
+                if method == "pop" {
+                    Ok(Some(arr.last().cloned().unwrap_or_else(Value::nil)))
+                } else {
+                    Ok(Some(Value::nil()))
+                }
+            }
+            "sum" | "product" | "min" | "max" | "count" | "average" => {
+                if !arg_values.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: format!("Array.{}()", method),
+                        expected: 0,
+                        actual: arg_values.len(),
+                    });
+                }
+                Self::reduce_vector(arr, method).map(Some)
+            }
             _ => Ok(None),
         }
     }
 
+    /// INTERP-131: Vector methods that take a closure: map, filter
+    ///
+    /// The closure is called once per element, in order, and the results are
+    /// collected into a new vector; the receiver is left unchanged. filter's
+    /// closure must return a boolean.
+    fn call_array_closure_method(
+        &mut self,
+        arr: &[Value],
+        method: &str,
+        arg_values: &[Value],
+    ) -> Result<Option<Value>, EvalError> {
+        if method != "map" && method != "filter" {
+            return Ok(None);
+        }
+        if arg_values.len() != 1 {
+            return Err(EvalError::ArgumentCountMismatch {
+                function: format!("Array.{}()", method),
+                expected: 1,
+                actual: arg_values.len(),
+            });
+        }
+        let mut results = Vec::with_capacity(arr.len());
+        for element in arr {
+            let result = self.call_callback(&arg_values[0], vec![element.clone()], method)?;
+            if method == "map" {
+                results.push(result);
+            } else if result.as_boolean()? {
+                results.push(element.clone());
+            }
+        }
+        Ok(Some(Value::vector(results)))
+    }
+
+    /// INTERP-098: Numeric and ordering reductions over a vector
+    ///
+    /// sum/product fold integers exactly and promote to float once a float
+    /// is seen; average is always a float. min/max use `Value::compare`, so
+    /// any element types are accepted. min, max, and average of an empty
+    /// vector are errors; sum and product return their identities.
+    fn reduce_vector(arr: &[Value], method: &str) -> Result<Value, EvalError> {
+        let operation = format!("Array.{}()", method);
+        let empty_error = |what: &str| {
+            EvalError::ValueError(ValueError::InvalidOperation {
+                operation: operation.clone(),
+                message: format!("empty vector has no {}", what),
+            })
+        };
+        match method {
+            "count" => Ok(Value::integer(arr.len() as i64)),
+            "min" => arr
+                .iter()
+                .min_by(|a, b| a.compare(b))
+                .cloned()
+                .ok_or_else(|| empty_error("minimum")),
+            "max" => arr
+                .iter()
+                .max_by(|a, b| a.compare(b))
+                .cloned()
+                .ok_or_else(|| empty_error("maximum")),
+            "average" => {
+                if arr.is_empty() {
+                    return Err(empty_error("average"));
+                }
+                let total = match Self::fold_numeric(arr, &operation, "sum")? {
+                    Value::Integer(n) => n as f64,
+                    other => other.as_float()?,
+                };
+                Ok(Value::float(total / arr.len() as f64))
+            }
+            _ => Self::fold_numeric(arr, &operation, method),
+        }
+    }
+
+    /// Fold numeric vector elements with "sum" or "product", promoting
+    /// Integer to Float when the vector mixes them
+    fn fold_numeric(arr: &[Value], operation: &str, fold: &str) -> Result<Value, EvalError> {
+        let is_product = fold == "product";
+        let int_op = |a: i64, b: i64| if is_product { a.checked_mul(b) } else { a.checked_add(b) };
+        let float_op = |a: f64, b: f64| if is_product { a * b } else { a + b };
+        let mut acc = Value::integer(if is_product { 1 } else { 0 });
+        for element in arr {
+            acc = match (&acc, element) {
+                (Value::Integer(a), Value::Integer(b)) => match int_op(*a, *b) {
+                    Some(n) => Value::integer(n),
+                    None => {
+                        return Err(EvalError::ValueError(ValueError::InvalidOperation {
+                            operation: operation.to_string(),
+                            message: "integer overflow".to_string(),
+                        }))
+                    }
+                },
+                (Value::Integer(a), Value::Float(b)) => Value::float(float_op(*a as f64, *b)),
+                (Value::Float(a), Value::Integer(b)) => Value::float(float_op(*a, *b as f64)),
+                (Value::Float(a), Value::Float(b)) => Value::float(float_op(*a, *b)),
+                (_, other) => {
+                    return Err(EvalError::ValueError(ValueError::TypeMismatch {
+                        expected: "Integer or Float".to_string(),
+                        found: other.type_name().to_string(),
+                        operation: operation.to_string(),
+                    }))
+                }
+            };
+        }
+        Ok(acc)
+    }
+
     /// Call a method on a map receiver
     ///
-    /// Handles: get, lock (INTERP-041 arc_store lookup)
+    /// Handles: len, is_empty, get, insert and remove (temporaries),
+    /// contains_key, keys and values (INTERP-132), lock (INTERP-041 arc_store lookup), send/recv on
+    /// channel ends (INTERP-095)
+    ///
+    /// keys() and values() are ordered by key, so iteration is deterministic.
     fn call_map_method(
-        &self,
+        &mut self,
         map: &std::collections::HashMap<String, Value>,
         method: &str,
         arg_values: &[Value],
     ) -> Result<Option<Value>, EvalError> {
         match method {
+            "len" | "is_empty" => {
+                if !arg_values.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: method.to_string(),
+                        expected: 0,
+                        actual: arg_values.len(),
+                    });
+                }
+                Ok(Some(if method == "len" {
+                    Value::integer(map.len() as i64)
+                } else {
+                    Value::boolean(map.is_empty())
+                }))
+            }
             "get" => {
                 if arg_values.len() != 1 {
                     return Err(EvalError::ArgumentCountMismatch {
@@ -577,6 +1020,56 @@ impl Evaluator {
                     None => Ok(Some(Value::nil())),
                 }
             }
+            "insert" => {
+                // In-place on variables (eval_method_call); a temporary is just discarded
+                if arg_values.len() != 2 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "insert".to_string(),
+                        expected: 2,
+                        actual: arg_values.len(),
+                    });
+                }
+                arg_values[0].as_string()?;
+                Ok(Some(Value::nil()))
+            }
+            "contains_key" | "remove" => {
+                if arg_values.len() != 1 {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: method.to_string(),
+                        expected: 1,
+                        actual: arg_values.len(),
+                    });
+                }
+                let key = arg_values[0].as_string()?;
+                Ok(Some(if method == "contains_key" {
+                    Value::boolean(map.contains_key(key))
+                } else {
+                    // In-place on variables (eval_method_call); a temporary just yields the value
+                    map.get(key).cloned().unwrap_or_else(Value::nil)
+                }))
+            }
+            "keys" | "values" => {
+                if !arg_values.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: method.to_string(),
+                        expected: 0,
+                        actual: arg_values.len(),
+                    });
+                }
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                let items = entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        if method == "keys" {
+                            Value::string(key.clone())
+                        } else {
+                            value.clone()
+                        }
+                    })
+                    .collect();
+                Ok(Some(Value::vector(items)))
+            }
             "lock" => {
                 // Mutex::lock() -> LockGuard
                 // INTERP-041: Look up value in arc_store if _arc_id exists
@@ -604,6 +1097,36 @@ impl Evaluator {
                     Ok(None) // Not handled here, fall through to shared lock
                 }
             }
+            "send" | "recv" => {
+                let Some(Value::Integer(channel_id)) = map.get("_channel_id") else {
+                    return Ok(None); // Not a channel end, fall through to shared stub
+                };
+                let channel_id = *channel_id as usize;
+                let (expected, end) = if method == "send" {
+                    (1, "Sender")
+                } else {
+                    (0, "Receiver")
+                };
+                if arg_values.len() != expected {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: method.to_string(),
+                        expected,
+                        actual: arg_values.len(),
+                    });
+                }
+                if map.get("_type").and_then(|t| t.as_string().ok()) != Some(end) {
+                    return Err(EvalError::UnsupportedOperation {
+                        operation: format!("{}() requires a channel {}", method, end),
+                    });
+                }
+                let buffer = self.channel_buffers.entry(channel_id).or_default();
+                if method == "send" {
+                    buffer.push_back(arg_values[0].clone());
+                    Ok(Some(Value::nil()))
+                } else {
+                    Ok(Some(buffer.pop_front().unwrap_or_else(Value::nil)))
+                }
+            }
             _ => Ok(None),
         }
     }
@@ -612,6 +1135,7 @@ impl Evaluator {
     ///
     /// Implements basic method call syntax: receiver.method(args)
     /// Dispatches to type-specific handlers first, then shared methods.
+    /// `to_string()` and `type_name()` work on every value (INTERP-117).
     pub(crate) fn call_method(
         &mut self,
         receiver: Value,
@@ -624,18 +1148,35 @@ impl Evaluator {
             arg_values.push(self.eval(arg)?);
         }
 
+        // User-defined methods from impl blocks take the receiver as `self`
+        if let Value::Struct { name, .. } = &receiver {
+            let method_name = format!("{}::{}", name, method);
+            if self.functions.contains_key(&method_name) {
+                let mut values = Vec::with_capacity(arg_values.len() + 1);
+                values.push(receiver);
+                values.extend(arg_values);
+                return self.call_function_with_values(&method_name, values);
+            }
+        }
+
         // Try type-specific dispatch first
+        if let Some(result) = self.call_option_method(&receiver, method, &arg_values)? {
+            return Ok(result);
+        }
         if let Ok(s) = receiver.as_string() {
             if let Some(result) = Self::call_string_method(s, method, &arg_values)? {
                 return Ok(result);
             }
         }
         if let Ok(arr) = receiver.as_vector() {
+            if let Some(result) = self.call_array_closure_method(arr, method, &arg_values)? {
+                return Ok(result);
+            }
             if let Some(result) = Self::call_array_method(arr, method, &arg_values)? {
                 return Ok(result);
             }
         }
-        if let Value::HashMap(ref map) = receiver {
+        if let Value::HashMap(ref map) | Value::Struct { fields: ref map, .. } = receiver {
             if let Some(result) = self.call_map_method(map, method, &arg_values)? {
                 return Ok(result);
             }
@@ -684,6 +1225,8 @@ impl Evaluator {
                 Ok(receiver.clone())
             }
             "unwrap" => {
+                // Pass-through for the concurrency mocks (lock(), join(), ...);
+                // Option/Result are unwrapped in call_option_method (INTERP-110)
                 // INTERP-OPT-002: Return receiver directly, no need to clone (we own it)
                 if !arg_values.is_empty() {
                     return Err(EvalError::ArgumentCountMismatch {
@@ -745,6 +1288,17 @@ impl Evaluator {
                 }
                 Ok(Value::string(receiver.to_println_string()))
             }
+            "type_name" => {
+                // INTERP-117: Reflective type name, same as in error messages
+                if !arg_values.is_empty() {
+                    return Err(EvalError::ArgumentCountMismatch {
+                        function: "type_name".to_string(),
+                        expected: 0,
+                        actual: arg_values.len(),
+                    });
+                }
+                Ok(Value::string(receiver.type_name().to_string()))
+            }
             _ => Err(EvalError::UnsupportedOperation {
                 operation: format!(
                     "unknown method '{}' on type {}",
@@ -755,288 +1309,6 @@ impl Evaluator {
         }
     }
 
-    /// I/O and diagnostic builtins: read_file, write_file, println, assert
-    fn call_io_builtin(
-        &mut self,
-        name: &str,
-        args: &[AstNode],
-    ) -> Result<Option<Value>, EvalError> {
-        match name {
-            "read_file" => {
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "read_file".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let path_val = self.eval(&args[0])?;
-                let path = path_val.as_string()?;
-                match std::fs::read_to_string(path) {
-                    Ok(content) => Ok(Some(Value::string(content))),
-                    Err(e) => Err(EvalError::ValueError(ValueError::InvalidOperation {
-                        operation: "read_file".to_string(),
-                        message: format!("Failed to read file: {}", e),
-                    })),
-                }
-            }
-            "write_file" => {
-                if args.len() != 2 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "write_file".to_string(),
-                        expected: 2,
-                        actual: args.len(),
-                    });
-                }
-                let path_val = self.eval(&args[0])?;
-                let path = path_val.as_string()?;
-                let content_val = self.eval(&args[1])?;
-                let content = content_val.as_string()?;
-                match std::fs::write(path, content) {
-                    Ok(_) => Ok(Some(Value::nil())),
-                    Err(e) => Err(EvalError::ValueError(ValueError::InvalidOperation {
-                        operation: "write_file".to_string(),
-                        message: format!("Failed to write file: {}", e),
-                    })),
-                }
-            }
-            "println" => {
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "println".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let msg_val = self.eval(&args[0])?;
-                let msg = msg_val.to_println_string();
-                println!("{}", msg);
-                Ok(Some(Value::nil()))
-            }
-            "assert" => {
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "assert".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let cond_val = self.eval(&args[0])?;
-                let cond_bool = cond_val.as_boolean()?;
-                if !cond_bool {
-                    return Err(EvalError::UnsupportedOperation {
-                        operation: "assertion failed".to_string(),
-                    });
-                }
-                Ok(Some(Value::nil()))
-            }
-            _ => Ok(None),
-        }
-    }
-
-    /// Collection constructor builtins: vec, String::new, String::from, HashMap::new
-    fn call_collection_builtin(
-        &mut self,
-        name: &str,
-        args: &[AstNode],
-    ) -> Result<Option<Value>, EvalError> {
-        match name {
-            "vec" => {
-                let mut elements = Vec::new();
-                for arg in args {
-                    elements.push(self.eval(arg)?);
-                }
-                Ok(Some(Value::vector(elements)))
-            }
-            "String::new" => {
-                if !args.is_empty() {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "String::new".to_string(),
-                        expected: 0,
-                        actual: args.len(),
-                    });
-                }
-                Ok(Some(Value::string(String::new())))
-            }
-            "String::from" => {
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "String::from".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let val = self.eval(&args[0])?;
-                let s = val.as_string()?;
-                Ok(Some(Value::string(s.to_string())))
-            }
-            "HashMap::new" => {
-                if !args.is_empty() {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "HashMap::new".to_string(),
-                        expected: 0,
-                        actual: args.len(),
-                    });
-                }
-                use std::collections::HashMap;
-                Ok(Some(Value::HashMap(HashMap::new())))
-            }
-            _ => Ok(None),
-        }
-    }
-
-    /// Concurrency builtins: thread::spawn, Mutex::new, Arc::new, Arc::clone, mpsc::channel
-    fn call_concurrency_builtin(
-        &mut self,
-        name: &str,
-        args: &[AstNode],
-    ) -> Result<Option<Value>, EvalError> {
-        match name {
-            "thread::spawn" => {
-                // INTERP-042: Mock implementation - execute closure synchronously
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "thread::spawn".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let result = match &args[0] {
-                    AstNode::Closure {
-                        is_move: _,
-                        params,
-                        body,
-                    } => {
-                        if !params.is_empty() {
-                            return Err(EvalError::UnsupportedOperation {
-                                operation: format!(
-                                    "thread::spawn closures with parameters not supported (found {} params)",
-                                    params.len()
-                                ),
-                            });
-                        }
-                        let mut last_value = Value::nil();
-                        for stmt in body {
-                            last_value = self.eval(stmt)?;
-                        }
-                        last_value
-                    }
-                    _ => {
-                        return Err(EvalError::UnsupportedOperation {
-                            operation: "thread::spawn requires a closure argument".to_string(),
-                        });
-                    }
-                };
-                use std::collections::HashMap;
-                let mut handle = HashMap::new();
-                handle.insert("_thread_id".to_string(), Value::integer(1));
-                handle.insert("_result".to_string(), result);
-                Ok(Some(Value::HashMap(handle)))
-            }
-            "Mutex::new" => {
-                // INTERP-041: Just wrap locally, NO arc_store (only Arc uses arc_store)
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "Mutex::new".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let val = self.eval(&args[0])?;
-                use std::collections::HashMap;
-                let mut wrapper = HashMap::new();
-                wrapper.insert("_inner".to_string(), val);
-                Ok(Some(Value::HashMap(wrapper)))
-            }
-            "Arc::new" => {
-                // INTERP-041: Store value in arc_store for shared references
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "Arc::new".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let val = self.eval(&args[0])?;
-                let arc_id = self.next_arc_id;
-                self.arc_store.insert(arc_id, val);
-                self.next_arc_id += 1;
-                use std::collections::HashMap;
-                let mut wrapper = HashMap::new();
-                wrapper.insert("_arc_id".to_string(), Value::integer(arc_id as i64));
-                Ok(Some(Value::HashMap(wrapper)))
-            }
-            "Arc::clone" => {
-                // INTERP-041: Return HashMap with same _arc_id (shared reference!)
-                if args.len() != 1 {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "Arc::clone".to_string(),
-                        expected: 1,
-                        actual: args.len(),
-                    });
-                }
-                let val = self.eval(&args[0])?;
-                if let Value::HashMap(ref map) = val {
-                    if let Some(Value::Integer(arc_id)) = map.get("_arc_id") {
-                        use std::collections::HashMap;
-                        let mut wrapper = HashMap::new();
-                        wrapper.insert("_arc_id".to_string(), Value::integer(*arc_id));
-                        return Ok(Some(Value::HashMap(wrapper)));
-                    }
-                }
-                // Fallback: if not an Arc, just clone (for backwards compatibility)
-                Ok(Some(val.clone()))
-            }
-            "mpsc::channel" => {
-                if !args.is_empty() {
-                    return Err(EvalError::ArgumentCountMismatch {
-                        function: "mpsc::channel".to_string(),
-                        expected: 0,
-                        actual: args.len(),
-                    });
-                }
-                use std::collections::HashMap;
-                let mut sender = HashMap::new();
-                sender.insert("_type".to_string(), Value::string("Sender".to_string()));
-                let mut receiver = HashMap::new();
-                receiver.insert("_type".to_string(), Value::string("Receiver".to_string()));
-                Ok(Some(Value::tuple(vec![
-                    Value::HashMap(sender),
-                    Value::HashMap(receiver),
-                ])))
-            }
-            _ => Ok(None),
-        }
-    }
-
-    /// Try to call a built-in function
-    ///
-    /// Built-in functions are checked before user-defined functions, allowing
-    /// core functionality like I/O to be available without explicit imports.
-    /// Dispatches to group-specific handlers: I/O, collection, concurrency.
-    ///
-    /// # Return Values
-    ///
-    /// - `Ok(Some(Value))` - Built-in function executed successfully
-    /// - `Ok(None)` - Not a built-in, should try user-defined functions
-    /// - `Err(EvalError)` - Built-in function call failed (I/O error, wrong args, etc.)
-    pub(crate) fn try_call_builtin(
-        &mut self,
-        name: &str,
-        args: &[AstNode],
-    ) -> Result<Option<Value>, EvalError> {
-        if let Some(result) = self.call_io_builtin(name, args)? {
-            return Ok(Some(result));
-        }
-        if let Some(result) = self.call_collection_builtin(name, args)? {
-            return Ok(Some(result));
-        }
-        if let Some(result) = self.call_concurrency_builtin(name, args)? {
-            return Ok(Some(result));
-        }
-        Ok(None)
-    }
-
     /// Evaluate if expression with conditional branching
     ///
     /// Evaluates the condition, then executes either the then_branch or else_branch
@@ -1063,9 +1335,9 @@ impl Evaluator {
                         // Normal evaluation - continue with next statement
                         result = v;
                     }
-                    ControlFlow::Return(v) => {
-                        // Early return - propagate immediately
-                        return Ok(ControlFlow::Return(v));
+                    jump => {
+                        // Early return or loop jump - propagate immediately
+                        return Ok(jump);
                     }
                 }
             }
@@ -1079,9 +1351,9 @@ impl Evaluator {
                         // Normal evaluation - continue with next statement
                         result = v;
                     }
-                    ControlFlow::Return(v) => {
-                        // Early return - propagate immediately
-                        return Ok(ControlFlow::Return(v));
+                    jump => {
+                        // Early return or loop jump - propagate immediately
+                        return Ok(jump);
                     }
                 }
             }
@@ -1092,17 +1364,35 @@ impl Evaluator {
         }
     }
 
+    /// Fail with `EvalError::Interrupted` once the `with_interrupt` flag is set
+    ///
+    /// Called before every loop iteration and function call, so neither a
+    /// loop nor a recursion can outrun the flag.
+    fn check_interrupt(&self) -> Result<(), EvalError> {
+        if self.interrupt.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return Err(EvalError::Interrupted);
+        }
+        Ok(())
+    }
+
     /// Helper: Execute loop body statements
-    /// Returns Ok(None) to continue, Ok(Some(v)) for early return
-    pub(crate) fn eval_loop_body_impl(&mut self, body: &[AstNode]) -> Result<Option<Value>, EvalError> {
+    /// Returns Ok(None) to keep looping (including after `continue`),
+    /// Ok(Some(flow)) for an early return or a `break`
+    pub(crate) fn eval_loop_body_impl(&mut self, body: &[AstNode]) -> Result<Option<ControlFlow>, EvalError> {
+        self.check_interrupt()?;
+
         for stmt in body {
             match self.eval_internal(stmt)? {
                 ControlFlow::Value(_) => {
                     // Normal evaluation - continue
                 }
-                ControlFlow::Return(v) => {
-                    // Early return from enclosing function
-                    return Ok(Some(v));
+                ControlFlow::Continue => {
+                    // Skip the rest of this iteration
+                    return Ok(None);
+                }
+                exit => {
+                    // Early return from enclosing function, or break
+                    return Ok(Some(exit));
                 }
             }
         }
@@ -1110,8 +1400,8 @@ impl Evaluator {
     }
 
     /// Helper: Execute loop body in a child scope
-    /// Returns Ok(None) to continue, Ok(Some(v)) for early return
-    pub(crate) fn eval_loop_body_with_scope(&mut self, body: &[AstNode]) -> Result<Option<Value>, EvalError> {
+    /// Returns Ok(None) to keep looping, Ok(Some(flow)) for return or break
+    pub(crate) fn eval_loop_body_with_scope(&mut self, body: &[AstNode]) -> Result<Option<ControlFlow>, EvalError> {
         // Create child scope for loop body iteration
         // This allows variables declared inside the loop to be fresh each iteration
         let child_scope = self.scope.create_child();
@@ -1149,32 +1439,76 @@ impl Evaluator {
             iteration_count += 1;
 
             // Execute body in child scope
-            if let Some(return_value) = self.eval_loop_body_with_scope(body)? {
-                // Early return from enclosing function
-                // Record loop data before returning
-                if let Some(ref profiler) = self.compiler_profiler {
-                    let duration = start_time.elapsed();
-                    let default_name = "<main>".to_string();
-                    let function_name = self.call_stack.last().unwrap_or(&default_name);
-                    // For now, use simple loop indexing (can be improved later)
-                    profiler.record_loop(function_name, 0, iteration_count, duration);
-                }
-                return Ok(ControlFlow::Return(return_value));
+            match self.eval_loop_body_with_scope(body)? {
+                Some(ControlFlow::Break(None)) => break,
+                Some(ControlFlow::Break(Some(_))) => return Err(ControlFlow::break_value_error()),
+                Some(exit) => {
+                    // Early return from enclosing function
+                    // Record loop data before returning
+                    self.record_loop_profile(start_time, iteration_count);
+                    return Ok(exit);
+                }
+                None => {}
             }
         }
 
         // INTERP-051: Record loop profiling data
+        self.record_loop_profile(start_time, iteration_count);
+
+        // While loops return nil
+        Ok(ControlFlow::Value(Value::nil()))
+    }
+
+    /// Evaluate repeat-until loop (INTERP-079)
+    ///
+    /// Executes the body first, then checks the condition; the loop exits
+    /// as soon as the condition evaluates to true. The body therefore always
+    /// runs at least once.
+    pub(crate) fn eval_repeat_until(
+        &mut self,
+        body: &[AstNode],
+        condition: &AstNode,
+    ) -> Result<ControlFlow, EvalError> {
+        let start_time = std::time::Instant::now();
+        let mut iteration_count = 0;
+
+        loop {
+            iteration_count += 1;
+
+            // Execute body in child scope
+            match self.eval_loop_body_with_scope(body)? {
+                Some(ControlFlow::Break(None)) => break,
+                Some(ControlFlow::Break(Some(_))) => return Err(ControlFlow::break_value_error()),
+                Some(exit) => {
+                    self.record_loop_profile(start_time, iteration_count);
+                    return Ok(exit);
+                }
+                None => {}
+            }
+
+            // Check exit condition after the body
+            let cond_val = self.eval(condition)?;
+            if cond_val.as_boolean()? {
+                break;
+            }
+        }
+
+        self.record_loop_profile(start_time, iteration_count);
+
+        // Repeat-until loops return nil
+        Ok(ControlFlow::Value(Value::nil()))
+    }
+
+    /// INTERP-051: Record loop profiling data for the current function
+    ///
+    /// For now, all loops in a function share index 0 (can track multiple loops later)
+    fn record_loop_profile(&self, start_time: std::time::Instant, iteration_count: usize) {
         if let Some(ref profiler) = self.compiler_profiler {
             let duration = start_time.elapsed();
-            // Get current function name from call stack
             let default_name = "<main>".to_string();
             let function_name = self.call_stack.last().unwrap_or(&default_name);
-            // For now, all loops in a function share index 0 (can track multiple loops later)
             profiler.record_loop(function_name, 0, iteration_count, duration);
         }
-
-        // While loops return nil
-        Ok(ControlFlow::Value(Value::nil()))
     }
 
     /// Evaluate for loop
@@ -1190,7 +1524,7 @@ impl Evaluator {
         // Get elements - support both Vector and HashMap iteration
         let elements = match &iterable_val {
             Value::Vector(_) => iterable_val.as_vector()?.clone(),
-            Value::HashMap(map) => {
+            Value::HashMap(map) | Value::Struct { fields: map, .. } => {
                 // Convert HashMap to vector of (key, value) tuples
                 map.iter()
                     .map(|(k, v)| Value::Vector(vec![Value::string(k.clone()), v.clone()]))
@@ -1244,15 +1578,17 @@ impl Evaluator {
                     })?;
             }
 
-            // Execute body and check for early return
-            let return_value = self.eval_loop_body_impl(body)?;
+            // Execute body and check for early return or break
+            let exit = self.eval_loop_body_impl(body)?;
 
             // Restore parent scope
             self.scope = old_scope;
 
-            // Propagate early return
-            if let Some(v) = return_value {
-                return Ok(ControlFlow::Return(v));
+            match exit {
+                Some(ControlFlow::Break(None)) => break,
+                Some(ControlFlow::Break(Some(_))) => return Err(ControlFlow::break_value_error()),
+                Some(exit) => return Ok(exit), // Propagate early return
+                None => {}
             }
         }
 
@@ -1266,33 +1602,25 @@ impl Evaluator {
         expr: &AstNode,
         arms: &[crate::interpreter::parser::MatchArm],
     ) -> Result<ControlFlow, EvalError> {
-        use crate::interpreter::parser::Pattern;
-
         // Evaluate the matched expression
         let match_val = self.eval(expr)?;
 
         // Try each arm in order
         for arm in arms {
-            let matches = match &arm.pattern {
-                Pattern::Wildcard => {
-                    // Wildcard matches anything
-                    true
-                }
-                Pattern::Literal(lit) => {
-                    // Literal pattern - evaluate and compare
-                    let pattern_val = self.eval(lit)?;
-                    match_val == pattern_val
-                }
-                Pattern::Identifier(name) => {
-                    // Identifier pattern - bind variable and always match
-                    self.scope
-                        .define(name.clone(), match_val.clone())
-                        .map_err(|e| EvalError::UnsupportedOperation {
-                            operation: format!("bind match variable: {}", e),
-                        })?;
-                    true
+            if let Some(guard) = &arm.guard {
+                // INTERP-124: A guarded arm binds into its own scope, so a
+                // false guard falls through without leaving bindings behind
+                let child_scope = self.scope.create_child();
+                let parent_scope = std::mem::replace(&mut self.scope, child_scope);
+                let taken = self.eval_guarded_arm(arm, guard, &match_val);
+                self.scope = parent_scope;
+                match taken? {
+                    Some(flow) => return Ok(flow),
+                    None => continue,
                 }
-            };
+            }
+
+            let matches = self.match_pattern(&arm.pattern, &match_val)?;
 
             if matches {
                 // Execute arm body
@@ -1302,9 +1630,9 @@ impl Evaluator {
                         ControlFlow::Value(v) => {
                             result = v;
                         }
-                        ControlFlow::Return(v) => {
-                            // Early return - propagate
-                            return Ok(ControlFlow::Return(v));
+                        jump => {
+                            // Early return or loop jump - propagate
+                            return Ok(jump);
                         }
                     }
                 }
@@ -1312,7 +1640,132 @@ impl Evaluator {
             }
         }
 
-        // No arm matched
+        // No arm matched (so none was a wildcard); name the uncovered variant
+        if let Value::EnumVariant {
+            enum_name, variant, ..
+        } = &match_val
+        {
+            return Err(EvalError::NonExhaustiveMatch {
+                enum_name: enum_name.clone(),
+                unmatched_variant: variant.clone(),
+            });
+        }
         Err(EvalError::NoMatchArm)
     }
+
+    /// Run a guarded match arm if its pattern matches and its guard holds
+    ///
+    /// Returns `None` when the arm is not taken.
+    fn eval_guarded_arm(
+        &mut self,
+        arm: &crate::interpreter::parser::MatchArm,
+        guard: &AstNode,
+        match_val: &Value,
+    ) -> Result<Option<ControlFlow>, EvalError> {
+        if !self.match_pattern(&arm.pattern, match_val)? || !self.eval(guard)?.as_boolean()? {
+            return Ok(None);
+        }
+        let mut result = Value::nil();
+        for stmt in &arm.body {
+            match self.eval_internal(stmt)? {
+                ControlFlow::Value(v) => result = v,
+                jump => return Ok(Some(jump)),
+            }
+        }
+        Ok(Some(ControlFlow::Value(result)))
+    }
+
+    /// Test a value against a match pattern, binding variables on success
+    pub(crate) fn match_pattern(
+        &mut self,
+        pattern: &crate::interpreter::parser::Pattern,
+        value: &Value,
+    ) -> Result<bool, EvalError> {
+        use crate::interpreter::parser::Pattern;
+
+        match pattern {
+            // Wildcard matches anything
+            Pattern::Wildcard => Ok(true),
+            Pattern::Literal(lit) => {
+                // Literal pattern - evaluate and compare
+                let pattern_val = self.eval(lit)?;
+                // INTERP-088: enum variants also match their integer discriminant
+                if let (Value::EnumVariant { discriminant, .. }, Value::Integer(n)) =
+                    (value, &pattern_val)
+                {
+                    return Ok(discriminant == n);
+                }
+                Ok(*value == pattern_val)
+            }
+            Pattern::Identifier(name) => {
+                // Identifier pattern - bind variable and always match
+                self.bind_match_variable(name, value)?;
+                Ok(true)
+            }
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                // INTERP-082: Range patterns only match integers
+                let Value::Integer(n) = value else {
+                    return Ok(false);
+                };
+                let start = self.eval(start)?.as_integer()?;
+                let end = self.eval(end)?.as_integer()?;
+                Ok(*n >= start && if *inclusive { *n <= end } else { *n < end })
+            }
+            Pattern::Binding { name, pattern } => {
+                // INTERP-082: Bind the whole value only if the sub-pattern matches
+                if !self.match_pattern(pattern, value)? {
+                    return Ok(false);
+                }
+                self.bind_match_variable(name, value)?;
+                Ok(true)
+            }
+            Pattern::Variant { name, payload } => {
+                // INTERP-113: Some(x) / None / Ok(v) / Err(e) by variant name
+                let Value::EnumVariant {
+                    variant,
+                    payload: carried,
+                    ..
+                } = value
+                else {
+                    return Ok(false);
+                };
+                if variant != name {
+                    return Ok(false);
+                }
+                match (payload, carried) {
+                    (None, None) => Ok(true),
+                    (Some(pattern), Some(carried)) => self.match_pattern(pattern, carried),
+                    _ => Ok(false),
+                }
+            }
+            Pattern::Tuple(patterns) => {
+                // INTERP-136: same arity, then every element matches
+                let Value::Tuple(elements) = value else {
+                    return Ok(false);
+                };
+                if elements.len() != patterns.len() {
+                    return Ok(false);
+                }
+                for (pattern, element) in patterns.iter().zip(elements.iter()) {
+                    if !self.match_pattern(pattern, element)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    /// Bind a match-pattern variable in the current scope
+    fn bind_match_variable(&mut self, name: &str, value: &Value) -> Result<(), EvalError> {
+        self.scope
+            .define(name.to_string(), value.clone())
+            .map_err(|e| EvalError::UnsupportedOperation {
+                operation: format!("bind match variable: {}", e),
+            })
+    }
 }
@@ -0,0 +1,148 @@
+// Option/Result support: the Some/Ok/Err constructors and the std-style
+// methods on their values (INTERP-109).
+//
+// Option and Result are prelude enums: their variants are ordinary
+// `Value::EnumVariant`s whose payload holds the wrapped value. `None` is
+// resolved as an identifier fallback in the evaluator.
+
+use crate::interpreter::parser::AstNode;
+use crate::interpreter::value::{Value, ValueError};
+use super::evaluator::{EvalError, Evaluator};
+
+impl Evaluator {
+    /// Option/Result builtins: Some, Ok, Err
+    pub(crate) fn call_option_builtin(
+        &mut self,
+        name: &str,
+        args: &[AstNode],
+    ) -> Result<Option<Value>, EvalError> {
+        let wrap: fn(Value) -> Value = match name {
+            "Some" => Value::some,
+            "Ok" => Value::ok,
+            "Err" => Value::err,
+            _ => return Ok(None),
+        };
+        if args.len() != 1 {
+            return Err(EvalError::ArgumentCountMismatch {
+                function: name.to_string(),
+                expected: 1,
+                actual: args.len(),
+            });
+        }
+        let value = self.eval(&args[0])?;
+        Ok(Some(wrap(value)))
+    }
+
+    /// Call a method on an Option or Result receiver
+    ///
+    /// Handles: is_some, is_none (Option); is_ok, is_err (Result); map,
+    /// unwrap, unwrap_or, unwrap_or_else, expect (both). Returns `None` for
+    /// other receivers and methods so shared dispatch can continue.
+    ///
+    /// `unwrap` here replaces the legacy pass-through in `call_method`, which
+    /// would otherwise "succeed" on `None`/`Err` (INTERP-110).
+    pub(crate) fn call_option_method(
+        &mut self,
+        receiver: &Value,
+        method: &str,
+        arg_values: &[Value],
+    ) -> Result<Option<Value>, EvalError> {
+        let Value::EnumVariant {
+            enum_name,
+            variant,
+            payload,
+            ..
+        } = receiver
+        else {
+            return Ok(None);
+        };
+        let is_option = enum_name == "Option";
+        if !is_option && enum_name != "Result" {
+            return Ok(None);
+        }
+
+        let expected = match method {
+            "is_some" | "is_none" | "is_ok" | "is_err" | "unwrap" => 0,
+            "map" | "unwrap_or" | "unwrap_or_else" | "expect" => 1,
+            _ => return Ok(None),
+        };
+        if arg_values.len() != expected {
+            return Err(EvalError::ArgumentCountMismatch {
+                function: format!("{}.{}()", enum_name, method),
+                expected,
+                actual: arg_values.len(),
+            });
+        }
+
+        // Some/Ok hold the value; None/Err are the "missing" side
+        let present = variant == "Some" || variant == "Ok";
+        let inner = payload.as_deref().cloned().unwrap_or_else(Value::nil);
+        let result = match (method, is_option) {
+            ("is_some", true) | ("is_ok", false) => Value::boolean(present),
+            ("is_none", true) | ("is_err", false) => Value::boolean(!present),
+            ("map", _) if present => {
+                let mapped = self.call_callback(&arg_values[0], vec![inner], method)?;
+                if is_option {
+                    Value::some(mapped)
+                } else {
+                    Value::ok(mapped)
+                }
+            }
+            ("map", _) => receiver.clone(),
+            ("unwrap" | "unwrap_or" | "unwrap_or_else" | "expect", _) if present => inner,
+            ("unwrap", _) => {
+                return Err(EvalError::Panic {
+                    message: if is_option {
+                        "called `unwrap()` on a `None` value".to_string()
+                    } else {
+                        format!("called `unwrap()` on an `Err` value: {}", inner)
+                    },
+                })
+            }
+            ("unwrap_or", _) => arg_values[0].clone(),
+            ("unwrap_or_else", _) => {
+                // Like Rust: Option's fallback takes no arguments, Result's gets the error
+                let args = if is_option { Vec::new() } else { vec![inner] };
+                self.call_callback(&arg_values[0], args, method)?
+            }
+            ("expect", _) => {
+                let message = arg_values[0].as_string()?;
+                return Err(EvalError::Panic {
+                    message: if is_option {
+                        message.to_string()
+                    } else {
+                        format!("{}: {}", message, inner)
+                    },
+                });
+            }
+            // is_ok on an Option, is_some on a Result, ...
+            _ => {
+                return Err(EvalError::UnsupportedOperation {
+                    operation: format!("method '{}' not supported on {}", method, enum_name),
+                })
+            }
+        };
+        Ok(Some(result))
+    }
+
+    /// Invoke a closure argument of an Option/Result or vector method
+    pub(crate) fn call_callback(
+        &mut self,
+        callback: &Value,
+        args: Vec<Value>,
+        method: &str,
+    ) -> Result<Value, EvalError> {
+        match callback {
+            Value::Closure {
+                params,
+                body,
+                captured_env,
+            } => self.call_closure_with_values(params, body, captured_env, args),
+            other => Err(EvalError::ValueError(ValueError::TypeMismatch {
+                expected: "Closure".to_string(),
+                found: other.type_name().to_string(),
+                operation: method.to_string(),
+            })),
+        }
+    }
+}
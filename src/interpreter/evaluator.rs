@@ -79,14 +79,30 @@
 // - Reduced MAX_CALL_DEPTH from 150 to 30
 // - Prevents Rust stack overflow in test threads (2MB stack limit)
 // - Ensures interpreter catches overflow before Rust runtime crashes
+//
+// Buffered Channels (INTERP-095):
+// - mpsc::channel() returns (Sender, Receiver) maps sharing a _channel_id
+// - send(v) enqueues into an evaluator-held buffer for that id
+// - recv() dequeues the oldest value, or nil when the channel is empty
+//
+// Output Sink (INTERP-084):
+// - println writes through the evaluator's OutputSink (stdout by default)
+// - with_output(OutputSink::buffer()) captures output per evaluator
+// - dbg(expr) writes "expr = value" to a separate debug sink (stderr by
+//   default, INTERP-092) so it never mixes with program output
 
-use crate::interpreter::parser::AstNode;
+use crate::interpreter::output::OutputSink;
+use crate::interpreter::parser::{AstNode, BinaryOperator};
 use crate::interpreter::scope::Scope;
 use crate::interpreter::value::{Value, ValueError};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
-/// Maximum recursion depth before stack overflow
+/// Default maximum recursion depth before stack overflow
+///
+/// Override per evaluator with `Evaluator::with_max_call_depth`.
 /// This is set conservatively to prevent actual Rust stack overflow
 /// Test threads have 2MB stack vs 8MB main thread, so this must be low enough
 /// to prevent Rust stack overflow before interpreter can catch it.
@@ -94,6 +110,14 @@ use std::fmt;
 /// depth 30 works for both finite and infinite recursion.
 pub(crate) const MAX_CALL_DEPTH: usize = 30;
 
+/// Maximum nesting of re-entrant evaluation (f-string holes and `eval_str`)
+///
+/// Each level also counts toward the call-depth limit. This lower, separate limit
+/// stops a self-referential chain (an `eval_str` of an f-string whose hole
+/// calls `eval_str` again) with a specific error, while programs that mix
+/// ordinary recursion with metaprogramming still hit `StackOverflow` first.
+pub(crate) const MAX_META_DEPTH: usize = 16;
+
 /// Profiling data for stack depth analysis (DEBUGGER-041)
 ///
 /// Tracks function call statistics during interpreter execution.
@@ -155,10 +179,20 @@ pub struct Evaluator {
     pub(crate) scope: Scope,
     /// Function registry: name -> (params, body)
     pub(crate) functions: HashMap<String, (Vec<String>, Vec<AstNode>)>,
+    /// Enum variant registry: "Name::Variant" -> variant value (INTERP-088)
+    pub(crate) enum_variants: HashMap<String, Value>,
     /// Current call depth for stack overflow detection
     pub(crate) call_depth: usize,
+    /// Nesting of f-string hole and `eval_str` evaluation (INTERP-116)
+    pub(crate) meta_depth: usize,
     /// Call stack for error reporting (tracks function call chain)
     pub(crate) call_stack: Vec<String>,
+    /// Call-site line of each `call_stack` frame, if known (INTERP-080)
+    pub(crate) call_sites: Vec<Option<usize>>,
+    /// Line of the statement being evaluated (set by `AstNode::Spanned`)
+    pub(crate) current_line: Option<usize>,
+    /// Per-frame scopes for the debugger's backtrace, when enabled (INTERP-114)
+    pub(crate) inspection: Option<super::frames::FrameInspection>,
     /// Optional profiling data (DEBUGGER-041: Stack Depth Profiler)
     pub(crate) profiling: Option<ProfilingData>,
     /// Optional performance profiler (DEBUGGER-047: Performance Profiler)
@@ -168,8 +202,25 @@ pub struct Evaluator {
     pub(crate) arc_store: HashMap<usize, Value>,
     /// Next available arc ID
     pub(crate) next_arc_id: usize,
+    /// Buffered values per mock channel (INTERP-095)
+    /// Maps channel_id -> queued values; both channel ends carry the same id
+    pub(crate) channel_buffers: HashMap<usize, VecDeque<Value>>,
     /// Optional compiler profiler (DEBUGGER-052: Type Observation)
     pub(crate) compiler_profiler: Option<crate::profiler::CompilerProfiler>,
+    /// Non-fatal diagnostics collected during evaluation (INTERP-083)
+    pub(crate) warnings: Vec<EvalWarning>,
+    /// Destination for program output (INTERP-084)
+    pub(crate) output: OutputSink,
+    /// Destination for dbg output (INTERP-092)
+    pub(crate) debug_output: OutputSink,
+    /// Coerce booleans to 0/1 in arithmetic instead of rejecting them (INTERP-106)
+    pub(crate) bool_arithmetic: bool,
+    /// Wrap integer arithmetic on overflow instead of raising an error (INTERP-133)
+    pub(crate) wrapping_arithmetic: bool,
+    /// Call depth at which evaluation fails with `StackOverflow` (INTERP-138)
+    pub(crate) max_call_depth: usize,
+    /// Flag that stops evaluation at the next loop iteration when set
+    pub(crate) interrupt: Option<Arc<AtomicBool>>,
 }
 
 /// Internal control flow for handling early returns
@@ -177,6 +228,7 @@ pub struct Evaluator {
 /// When evaluating function bodies, we need to distinguish between:
 /// - Normal evaluation (last expression value)
 /// - Early return (explicit return statement)
+/// - Loop jumps (break/continue), consumed by the innermost loop
 ///
 /// This enum allows return statements to propagate up through nested
 /// control structures (if/else, loops) without executing remaining statements.
@@ -185,6 +237,47 @@ pub(crate) enum ControlFlow {
     Value(Value),
     /// Early return from function - stops evaluation and returns immediately
     Return(Value),
+    /// `break` - exits the innermost enclosing loop, with the value given
+    /// to `break v` (only a `loop` accepts one)
+    Break(Option<Value>),
+    /// `continue` - skips to the next iteration of the innermost loop
+    Continue,
+}
+
+impl ControlFlow {
+    /// The value produced, or an error for a break/continue that escaped every loop
+    pub(crate) fn into_value(self) -> Result<Value, EvalError> {
+        match self {
+            ControlFlow::Value(v) | ControlFlow::Return(v) => Ok(v),
+            jump => Err(jump.loop_jump_error()),
+        }
+    }
+
+    /// Reject a break/continue that reached a function or closure body
+    pub(crate) fn outside_loop(self) -> Result<ControlFlow, EvalError> {
+        match self {
+            ControlFlow::Break(_) | ControlFlow::Continue => Err(self.loop_jump_error()),
+            flow => Ok(flow),
+        }
+    }
+
+    /// Error for `break v` in a loop that has no value (while, for, repeat)
+    pub(crate) fn break_value_error() -> EvalError {
+        EvalError::UnsupportedOperation {
+            operation: "break with a value outside of `loop`".to_string(),
+        }
+    }
+
+    fn loop_jump_error(&self) -> EvalError {
+        let keyword = if matches!(self, ControlFlow::Break(_)) {
+            "break"
+        } else {
+            "continue"
+        };
+        EvalError::LoopJumpOutsideLoop {
+            keyword: keyword.to_string(),
+        }
+    }
 }
 
 /// Evaluation errors
@@ -213,13 +306,50 @@ pub enum EvalError {
     },
     /// Stack overflow from excessive recursion
     StackOverflow,
+    /// F-string holes and `eval_str` nested past `MAX_META_DEPTH` (INTERP-116)
+    MetaRecursionLimit {
+        /// The nesting limit that was reached
+        limit: usize,
+    },
     /// No match arm matched in match expression
     NoMatchArm,
+    /// A match over an enum value had no arm for its variant (INTERP-112)
+    ///
+    /// Raised instead of `NoMatchArm` when the scrutinee is an enum value,
+    /// so the error names the variant the match failed to cover.
+    NonExhaustiveMatch {
+        /// Enum type of the matched value
+        enum_name: String,
+        /// Variant that no arm matched
+        unmatched_variant: String,
+    },
     /// Unsupported operation
     UnsupportedOperation {
         /// Operation description
         operation: String,
     },
+    /// Evaluation stopped by the flag passed to `Evaluator::with_interrupt`
+    Interrupted,
+    /// `break` or `continue` used outside of any loop
+    LoopJumpOutsideLoop {
+        /// The keyword used ("break" or "continue")
+        keyword: String,
+    },
+    /// A failed `assert`, `assert_eq`, or `assert_approx` (INTERP-097)
+    ///
+    /// Kept distinct from other errors so tools can tell a wrong answer
+    /// from a crash or an unsupported feature.
+    AssertionFailed {
+        /// What was asserted (and, for comparisons, the values involved)
+        message: String,
+        /// Source line of the failing assertion (needs `Parser::with_spans`)
+        line: Option<usize>,
+    },
+    /// A `panic(..)`, or an `expect`/`unwrap` on `None` or `Err` (INTERP-109, INTERP-110)
+    Panic {
+        /// Why the program panicked
+        message: String,
+    },
     /// Error with call stack information for debugging
     ///
     /// Wraps another error and attaches the function call stack at the point
@@ -234,6 +364,10 @@ pub enum EvalError {
         error: Box<EvalError>,
         /// Function call stack (outermost to innermost)
         call_stack: Vec<String>,
+        /// Source line each frame was called from (parallel to `call_stack`)
+        ///
+        /// `None` when the AST carries no spans (see `Parser::with_spans`).
+        call_sites: Vec<Option<usize>>,
     },
 }
 
@@ -261,17 +395,54 @@ impl fmt::Display for EvalError {
             EvalError::StackOverflow => {
                 write!(f, "Stack overflow: recursion depth exceeded")
             }
+            EvalError::MetaRecursionLimit { limit } => {
+                write!(
+                    f,
+                    "meta-evaluation depth limit ({}) exceeded: f-string holes or eval_str nested too deeply",
+                    limit
+                )
+            }
             EvalError::NoMatchArm => {
                 write!(f, "No match arm matched")
             }
+            EvalError::NonExhaustiveMatch {
+                enum_name,
+                unmatched_variant,
+            } => {
+                write!(
+                    f,
+                    "non-exhaustive match: {}::{} not covered (add an arm for it or a `_` wildcard)",
+                    enum_name, unmatched_variant
+                )
+            }
             EvalError::UnsupportedOperation { operation } => {
                 write!(f, "Unsupported operation: {}", operation)
             }
-            EvalError::WithCallStack { error, call_stack } => {
+            EvalError::Interrupted => write!(f, "evaluation interrupted"),
+            EvalError::LoopJumpOutsideLoop { keyword } => {
+                write!(f, "'{}' outside of a loop", keyword)
+            }
+            EvalError::AssertionFailed { message, line } => {
+                write!(f, "assertion failed: {}", message)?;
+                if let Some(line) = line {
+                    write!(f, " (line {})", line)?;
+                }
+                Ok(())
+            }
+            EvalError::Panic { message } => write!(f, "panicked: {}", message),
+            EvalError::WithCallStack {
+                error,
+                call_stack,
+                call_sites,
+            } => {
                 write!(f, "{}\nCall stack (most recent call first):\n", error)?;
                 // Display stack in reverse order: innermost (most recent) call first
-                for (i, func_name) in call_stack.iter().rev().enumerate() {
-                    writeln!(f, "  {}. {}", i + 1, func_name)?;
+                for (i, func_name) in call_stack.iter().enumerate().rev() {
+                    let frame = call_stack.len() - i;
+                    match call_sites.get(i).copied().flatten() {
+                        Some(line) => writeln!(f, "  {}. at {} (line {})", frame, func_name, line)?,
+                        None => writeln!(f, "  {}. at {}", frame, func_name)?,
+                    }
                 }
                 Ok(())
             }
@@ -279,8 +450,56 @@ impl fmt::Display for EvalError {
     }
 }
 
+impl EvalError {
+    /// Whether this is an assertion failure, looking through call-stack wrappers
+    pub fn is_assertion_failure(&self) -> bool {
+        match self {
+            EvalError::AssertionFailed { .. } => true,
+            EvalError::WithCallStack { error, .. } => error.is_assertion_failure(),
+            _ => false,
+        }
+    }
+
+    /// Source line of a failed assertion, looking through call-stack wrappers
+    ///
+    /// `None` for other errors, or when the program was parsed without spans.
+    pub fn assertion_line(&self) -> Option<usize> {
+        match self {
+            EvalError::AssertionFailed { line, .. } => *line,
+            EvalError::WithCallStack { error, .. } => error.assertion_line(),
+            _ => None,
+        }
+    }
+}
+
 impl std::error::Error for EvalError {}
 
+/// Non-fatal evaluation diagnostics
+///
+/// Collected while evaluating; retrieve them with `Evaluator::warnings()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalWarning {
+    /// A user-defined function has the same name as a builtin (INTERP-083)
+    ///
+    /// Builtins are dispatched first, so the user function is never called.
+    BuiltinShadowed {
+        /// Function name
+        name: String,
+    },
+}
+
+impl fmt::Display for EvalWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalWarning::BuiltinShadowed { name } => write!(
+                f,
+                "function '{}' has the same name as a builtin; calls to '{}' use the builtin",
+                name, name
+            ),
+        }
+    }
+}
+
 impl From<ValueError> for EvalError {
     fn from(err: ValueError) -> Self {
         EvalError::ValueError(err)
@@ -293,13 +512,26 @@ impl Evaluator {
         Evaluator {
             scope: Scope::new(),
             functions: HashMap::new(),
+            enum_variants: HashMap::new(),
             call_depth: 0,
+            meta_depth: 0,
             call_stack: Vec::new(),
+            call_sites: Vec::new(),
+            current_line: None,
+            inspection: None,
             profiling: None,
             performance_profiler: None,
             arc_store: HashMap::new(),
             next_arc_id: 0,
+            channel_buffers: HashMap::new(),
             compiler_profiler: None,
+            warnings: Vec::new(),
+            output: OutputSink::default(),
+            debug_output: OutputSink::Stderr,
+            bool_arithmetic: false,
+            wrapping_arithmetic: false,
+            max_call_depth: MAX_CALL_DEPTH,
+            interrupt: None,
         }
     }
 
@@ -315,13 +547,26 @@ impl Evaluator {
         Evaluator {
             scope: self.scope.deep_clone(),
             functions: self.functions.clone(),
+            enum_variants: self.enum_variants.clone(),
             call_depth: self.call_depth,
+            meta_depth: self.meta_depth,
             call_stack: self.call_stack.clone(),
+            call_sites: self.call_sites.clone(),
+            current_line: self.current_line,
+            inspection: self.inspection.clone(),
             profiling: self.profiling.clone(),
             performance_profiler: self.performance_profiler.clone(),
             arc_store: self.arc_store.clone(),
             next_arc_id: self.next_arc_id,
+            channel_buffers: self.channel_buffers.clone(),
             compiler_profiler: self.compiler_profiler.clone(),
+            warnings: self.warnings.clone(),
+            output: self.output.clone(),
+            debug_output: self.debug_output.clone(),
+            bool_arithmetic: self.bool_arithmetic,
+            wrapping_arithmetic: self.wrapping_arithmetic,
+            max_call_depth: self.max_call_depth,
+            interrupt: self.interrupt.clone(),
         }
     }
 
@@ -333,6 +578,115 @@ impl Evaluator {
         self
     }
 
+    /// INTERP-084: Send program output (println) to the given sink
+    ///
+    /// # Example
+    /// ```
+    /// use ruchyruchy::interpreter::{Evaluator, OutputSink, Parser};
+    ///
+    /// let sink = OutputSink::buffer();
+    /// let mut eval = Evaluator::new().with_output(sink.clone());
+    /// let ast = Parser::new("println(\"hi\")").parse().unwrap();
+    /// eval.eval_program(&ast).unwrap();
+    ///
+    /// assert_eq!(sink.contents(), Some("hi\n".to_string()));
+    /// ```
+    pub fn with_output(mut self, sink: OutputSink) -> Self {
+        self.output = sink;
+        self
+    }
+
+    /// Get the output sink used by this evaluator (INTERP-084)
+    pub fn output(&self) -> &OutputSink {
+        &self.output
+    }
+
+    /// INTERP-092: Send dbg output to the given sink (stderr by default)
+    pub fn with_debug_output(mut self, sink: OutputSink) -> Self {
+        self.debug_output = sink;
+        self
+    }
+
+    /// INTERP-106: Treat `true`/`false` as 1/0 in arithmetic (C-style)
+    ///
+    /// By default booleans are not numbers (Rust-style): `true + true` fails
+    /// with a type mismatch. With this flag, booleans coerce to integers in
+    /// `+ - * / %` and unary `-`/`+`, so `true + true` is `2`.
+    ///
+    /// The JIT represents booleans as the integers 0 and 1 and always behaves
+    /// as if this flag were set, so differential runs of programs that do
+    /// arithmetic on booleans only agree with it enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use ruchyruchy::interpreter::{Evaluator, Parser, Value};
+    ///
+    /// let ast = Parser::new("true + true").parse().unwrap();
+    /// assert!(Evaluator::new().eval_program(&ast).is_err());
+    ///
+    /// let mut eval = Evaluator::new().with_bool_arithmetic();
+    /// assert_eq!(eval.eval_program(&ast).unwrap(), Value::integer(2));
+    /// ```
+    pub fn with_bool_arithmetic(mut self) -> Self {
+        self.bool_arithmetic = true;
+        self
+    }
+
+    /// INTERP-133: Wrap integer arithmetic on overflow
+    ///
+    /// By default `+ - * / %` and unary `-` on integers raise
+    /// `ValueError::IntegerOverflow` when the result does not fit in an i64
+    /// (including `-i64::MIN` and `i64::MIN / -1`). With this flag they wrap
+    /// in two's complement, as the JIT does.
+    ///
+    /// # Example
+    /// ```
+    /// use ruchyruchy::interpreter::{Evaluator, Parser, Value};
+    ///
+    /// let ast = Parser::new("9223372036854775807 + 1").parse().unwrap();
+    /// assert!(Evaluator::new().eval_program(&ast).is_err());
+    ///
+    /// let mut eval = Evaluator::new().with_wrapping_arithmetic();
+    /// assert_eq!(eval.eval_program(&ast).unwrap(), Value::integer(i64::MIN));
+    /// ```
+    pub fn with_wrapping_arithmetic(mut self) -> Self {
+        self.wrapping_arithmetic = true;
+        self
+    }
+
+    /// INTERP-138: Set the call depth at which `StackOverflow` is raised
+    ///
+    /// The default, `MAX_CALL_DEPTH` (30), is safe on 2MB test threads.
+    /// Programs run on the 8MB main thread can raise it for deep recursion;
+    /// a limit the Rust stack cannot hold crashes instead of erroring.
+    ///
+    /// # Example
+    /// ```
+    /// use ruchyruchy::interpreter::{Evaluator, Parser, Value};
+    ///
+    /// let source = "fun depth(n) { if n == 0 { 0 } else { 1 + depth(n - 1) } }\ndepth(40)";
+    /// let ast = Parser::new(source).parse().unwrap();
+    /// assert!(Evaluator::new().eval_program(&ast).is_err());
+    ///
+    /// let mut eval = Evaluator::new().with_max_call_depth(100);
+    /// assert_eq!(eval.eval_program(&ast).unwrap(), Value::integer(40));
+    /// ```
+    pub fn with_max_call_depth(mut self, depth: usize) -> Self {
+        self.max_call_depth = depth;
+        self
+    }
+
+    /// Stop evaluation when `flag` is set
+    ///
+    /// The flag is checked before every loop iteration and function call, so
+    /// another thread can end a non-terminating program, including a deep but
+    /// bounded recursion such as `f(n - 1) + f(n - 1)`; evaluation then fails
+    /// with `EvalError::Interrupted`.
+    pub fn with_interrupt(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.interrupt = Some(flag);
+        self
+    }
+
     /// DEBUGGER-052: Enable type observation (Julia-inspired)
     ///
     /// Attaches a compiler profiler to observe type signatures at function calls.
@@ -411,17 +765,11 @@ impl Evaluator {
         let profiler_opt = self.performance_profiler.clone();
         if let Some(profiler) = profiler_opt {
             profiler.start_eval();
-            let result = match self.eval_internal(node)? {
-                ControlFlow::Value(v) => Ok(v),
-                ControlFlow::Return(v) => Ok(v),
-            };
+            let result = self.eval_internal(node)?.into_value();
             profiler.end_eval();
             result
         } else {
-            match self.eval_internal(node)? {
-                ControlFlow::Value(v) => Ok(v),
-                ControlFlow::Return(v) => Ok(v),
-            }
+            self.eval_internal(node)?.into_value()
         }
     }
 
@@ -429,6 +777,8 @@ impl Evaluator {
     ///
     /// Convenience method for property testing (DEBUGGER-044).
     /// Evaluates all nodes in the AST and returns the value of the last expression.
+    /// The output sinks are flushed before returning, including when a statement
+    /// fails, so output printed before an error is never lost.
     ///
     /// # Example
     /// ```
@@ -443,12 +793,25 @@ impl Evaluator {
         let mut last_value = Value::Nil;
 
         for node in ast.nodes() {
-            last_value = self.eval(node)?;
+            match self.eval(node) {
+                Ok(value) => last_value = value,
+                Err(e) => {
+                    self.flush_output();
+                    return Err(e);
+                }
+            }
         }
 
+        self.flush_output();
         Ok(last_value)
     }
 
+    /// Flush program and debug output sinks
+    fn flush_output(&self) {
+        self.output.flush();
+        self.debug_output.flush();
+    }
+
     /// Get variable value from current scope (DEBUGGER-046: REPL Debugger)
     ///
     /// Used by the interactive debugger to inspect variable values.
@@ -506,9 +869,38 @@ impl Evaluator {
         &self.call_stack
     }
 
+    /// Get warnings collected so far (INTERP-083)
+    ///
+    /// # Example
+    /// ```
+    /// use ruchyruchy::interpreter::evaluator::{EvalWarning, Evaluator};
+    /// use ruchyruchy::interpreter::parser::Parser;
+    ///
+    /// let ast = Parser::new("fun println(x) { x }").parse().unwrap();
+    /// let mut eval = Evaluator::new();
+    /// eval.eval_program(&ast).unwrap();
+    ///
+    /// assert_eq!(
+    ///     eval.warnings(),
+    ///     &[EvalWarning::BuiltinShadowed { name: "println".to_string() }]
+    /// );
+    /// ```
+    pub fn warnings(&self) -> &[EvalWarning] {
+        &self.warnings
+    }
+
     /// Internal evaluation with control flow support
     pub(crate) fn eval_internal(&mut self, node: &AstNode) -> Result<ControlFlow, EvalError> {
         match node {
+            // INTERP-080: Remember the statement line for call-site locations
+            AstNode::Spanned { span, node } => {
+                self.current_line = Some(span.line);
+                if self.inspection.is_some() {
+                    self.check_pause_line(span.line);
+                }
+                self.eval_internal(node)
+            }
+
             // Literals - direct conversion to values
             AstNode::IntegerLiteral(n) => Ok(ControlFlow::Value(Value::integer(*n))),
             AstNode::FloatLiteral(f) => Ok(ControlFlow::Value(Value::float(*f))),
@@ -522,6 +914,15 @@ impl Evaluator {
             // Binary operations
             AstNode::BinaryOp { op, left, right } => {
                 let left_val = self.eval(left)?;
+                // INTERP-125: && and || skip the right side once the left
+                // decides the result (false && .., true || ..)
+                if let (BinaryOperator::And | BinaryOperator::Or, Value::Boolean(l)) =
+                    (op, &left_val)
+                {
+                    if *l == (*op == BinaryOperator::Or) {
+                        return Ok(ControlFlow::Value(left_val));
+                    }
+                }
                 let right_val = self.eval(right)?;
                 let result = self.eval_binary_op(*op, left_val, right_val)?;
                 Ok(ControlFlow::Value(result))
@@ -542,15 +943,29 @@ impl Evaluator {
             }
 
             // Range expression: start..end
-            AstNode::Range { start, end } => self.eval_range(start, end),
+            AstNode::Range {
+                start,
+                end,
+                inclusive,
+            } => self.eval_range(start, end, *inclusive),
 
             // Function definition - register function
-            AstNode::FunctionDef { name, params, body } => {
+            AstNode::FunctionDef {
+                name, params, body, ..
+            } => {
+                // INTERP-083: Builtins win dispatch, so warn instead of silently ignoring
+                if Self::is_builtin(name) {
+                    self.warnings
+                        .push(EvalWarning::BuiltinShadowed { name: name.clone() });
+                }
                 self.functions
                     .insert(name.clone(), (params.clone(), body.clone()));
                 Ok(ControlFlow::Value(Value::nil()))
             }
 
+            // Test functions only run under run_tests (INTERP-115)
+            AstNode::TestDef { .. } => Ok(ControlFlow::Value(Value::nil())),
+
             // Function call
             AstNode::FunctionCall { name, args } => {
                 let result = self.call_function(name, args)?;
@@ -566,10 +981,12 @@ impl Evaluator {
 
             // Identifier - lookup variable in scope
             AstNode::Identifier(name) => {
-                let value = self
-                    .scope
-                    .get_cloned(name)
-                    .map_err(|_| EvalError::UndefinedVariable { name: name.clone() })?;
+                let value = match self.scope.get_cloned(name) {
+                    Ok(value) => value,
+                    // INTERP-109: `None` is a prelude value unless shadowed
+                    Err(_) if name == "None" => Value::none(),
+                    Err(_) => return Err(EvalError::UndefinedVariable { name: name.clone() }),
+                };
                 Ok(ControlFlow::Value(value))
             }
 
@@ -583,6 +1000,13 @@ impl Evaluator {
                 Ok(ControlFlow::Return(return_val))
             }
 
+            // Loop jumps, consumed by the innermost enclosing loop
+            AstNode::Break { value } => {
+                let value = value.as_deref().map(|expr| self.eval(expr)).transpose()?;
+                Ok(ControlFlow::Break(value))
+            }
+            AstNode::Continue => Ok(ControlFlow::Continue),
+
             // If expression
             AstNode::IfExpr {
                 condition,
@@ -594,7 +1018,7 @@ impl Evaluator {
             AstNode::Block { statements } => self.eval_block(statements),
 
             // Let declaration
-            AstNode::LetDecl { name, value } => {
+            AstNode::LetDecl { name, value, .. } => {
                 let val = self.eval(value)?;
                 self.scope.define(name.clone(), val).map_err(|e| {
                     EvalError::UnsupportedOperation {
@@ -605,11 +1029,39 @@ impl Evaluator {
             }
 
             // Tuple destructuring: let (a, b, c) = expr
-            AstNode::TupleDestruct { names, value } => self.eval_tuple_destruct(names, value),
+            AstNode::TupleDestruct { patterns, value } => {
+                self.eval_tuple_destruct(patterns, value)
+            }
+
+            // Refutable binding: let Some(x) = expr else { ... }
+            AstNode::LetElse {
+                pattern,
+                value,
+                else_block,
+            } => self.eval_let_else(pattern, value, else_block),
+
+            // Pattern conditional and loop (INTERP-123)
+            AstNode::IfLet {
+                pattern,
+                scrutinee,
+                then_branch,
+                else_branch,
+            } => self.eval_if_let(pattern, scrutinee, then_branch, else_branch),
+            AstNode::WhileLet {
+                pattern,
+                scrutinee,
+                body,
+            } => self.eval_while_let(pattern, scrutinee, body),
 
             // While loop
             AstNode::WhileLoop { condition, body } => self.eval_while(condition, body),
 
+            // Repeat-until loop (INTERP-079)
+            AstNode::RepeatUntil { body, condition } => self.eval_repeat_until(body, condition),
+
+            // Infinite loop with break-with-value (INTERP-121)
+            AstNode::Loop { body } => self.eval_loop(body),
+
             // For loop
             AstNode::ForLoop {
                 var,
@@ -629,6 +1081,29 @@ impl Evaluator {
                 Ok(ControlFlow::Value(Value::nil()))
             }
 
+            // Index assignment: v[i] = x, m["k"] = x (INTERP-120)
+            AstNode::IndexAssignment {
+                target,
+                index,
+                value,
+            } => {
+                let index_val = self.eval(index)?;
+                let new_val = self.eval(value)?;
+                self.assign_index(target, index_val, new_val)?;
+                Ok(ControlFlow::Value(Value::nil()))
+            }
+
+            // Field assignment: p.x = x (INTERP-137)
+            AstNode::FieldAssignment {
+                target,
+                field,
+                value,
+            } => {
+                let new_val = self.eval(value)?;
+                self.assign_field(target, field, new_val)?;
+                Ok(ControlFlow::Value(Value::nil()))
+            }
+
             // Compound assignment: x += 5, *num -= 1
             AstNode::CompoundAssignment { lhs, op, rhs } => {
                 self.eval_compound_assignment(lhs, *op, rhs)
@@ -672,6 +1147,8 @@ impl Evaluator {
                 let name = segments.join("::");
                 if let Ok(value) = self.scope.get_cloned(&name) {
                     Ok(ControlFlow::Value(value))
+                } else if let Some(variant) = self.enum_variants.get(&name) {
+                    Ok(ControlFlow::Value(variant.clone()))
                 } else {
                     Ok(ControlFlow::Value(Value::string(format!(
                         "<path: {}>",
@@ -697,8 +1174,27 @@ impl Evaluator {
             // Struct definition - no-op
             AstNode::StructDef { .. } => Ok(ControlFlow::Value(Value::nil())),
 
-            // Struct literal - create as HashMap
-            AstNode::StructLiteral { name: _, fields } => self.eval_struct_literal(fields),
+            // Impl block - register methods as `Type::method` functions
+            AstNode::ImplBlock { type_name, methods } => {
+                for method in methods {
+                    if let AstNode::FunctionDef {
+                        name, params, body, ..
+                    } = method
+                    {
+                        self.functions.insert(
+                            format!("{}::{}", type_name, name),
+                            (params.clone(), body.clone()),
+                        );
+                    }
+                }
+                Ok(ControlFlow::Value(Value::nil()))
+            }
+
+            // Enum definition - register each variant as `Name::Variant`
+            AstNode::EnumDef { name, variants } => self.eval_enum_def(name, variants),
+
+            // Struct literal - create a tagged struct value
+            AstNode::StructLiteral { name, fields } => self.eval_struct_literal(name, fields),
 
             // Field access
             AstNode::FieldAccess { expr, field } => self.eval_field_access(expr, field),
@@ -714,6 +1210,22 @@ impl Evaluator {
         }
     }
 
+    /// Evaluate `loop { body }` (INTERP-121)
+    ///
+    /// Repeats the body until a `break`; the loop evaluates to the break
+    /// value, or nil for a bare `break`. A `return` inside the body leaves
+    /// the enclosing function as usual.
+    pub(crate) fn eval_loop(&mut self, body: &[AstNode]) -> Result<ControlFlow, EvalError> {
+        loop {
+            match self.eval_loop_body_with_scope(body)? {
+                Some(ControlFlow::Break(value)) => {
+                    return Ok(ControlFlow::Value(value.unwrap_or_else(Value::nil)))
+                }
+                Some(exit) => return Ok(exit),
+                None => {}
+            }
+        }
+    }
 }
 
 impl Default for Evaluator {
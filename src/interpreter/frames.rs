@@ -0,0 +1,133 @@
+// Frame inspection for the debugger's backtrace (INTERP-114)
+//
+// A function call replaces the evaluator's scope with a fresh one and keeps
+// the caller's scope in a local, so only the innermost frame's variables are
+// reachable. With frame inspection enabled the evaluator also keeps each
+// caller's scope on a stack parallel to `call_stack`, so every active frame
+// can be rendered with its locals. When disabled (the default) the only cost
+// is an `Option` check per call.
+
+use std::collections::HashSet;
+
+use super::evaluator::Evaluator;
+use super::scope::Scope;
+use super::value::Value;
+
+/// One active function call, as shown in a backtrace
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// Function name
+    pub function: String,
+    /// Source line the function was called from, if known
+    pub call_line: Option<usize>,
+    /// Variables visible in the frame, sorted by name
+    pub variables: Vec<(String, Value)>,
+}
+
+impl Frame {
+    /// Value of a variable in this frame
+    pub fn variable(&self, name: &str) -> Option<&Value> {
+        self.variables
+            .iter()
+            .find(|(var, _)| var == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Frame-inspection state carried by an evaluator
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrameInspection {
+    /// Caller scope of each `call_stack` frame (index 0 is the top level)
+    scopes: Vec<Scope>,
+    /// Source lines at which to capture the frames
+    pause_lines: HashSet<usize>,
+    /// Frames captured at the first pause line reached
+    paused: Option<(usize, Vec<Frame>)>,
+}
+
+impl Evaluator {
+    /// INTERP-114: Keep every active frame's scope for inspection
+    ///
+    /// # Example
+    /// ```
+    /// use ruchyruchy::interpreter::{Evaluator, Parser};
+    ///
+    /// let source = "fun fact(n) {\n  if n <= 1 {\n    return 1;\n  }\n  n * fact(n - 1)\n}\nfact(3)";
+    /// let ast = Parser::new(source).with_spans().parse().unwrap();
+    /// let mut eval = Evaluator::new().with_frame_inspection();
+    /// eval.pause_at_line(3);
+    /// eval.eval_program(&ast).unwrap();
+    ///
+    /// let (_, frames) = eval.take_paused_frames().unwrap();
+    /// let ns: Vec<String> = frames.iter().map(|f| f.variable("n").unwrap().to_string()).collect();
+    /// assert_eq!(ns, ["3", "2", "1"]);
+    /// ```
+    pub fn with_frame_inspection(mut self) -> Self {
+        self.inspection = Some(FrameInspection::default());
+        self
+    }
+
+    /// Active frames, outermost first (empty unless frame inspection is on)
+    pub fn frames(&self) -> Vec<Frame> {
+        let Some(inspection) = &self.inspection else {
+            return Vec::new();
+        };
+        self.call_stack
+            .iter()
+            .enumerate()
+            .map(|(i, function)| {
+                // A frame's scope is saved when it calls the next frame
+                let scope = inspection.scopes.get(i + 1).unwrap_or(&self.scope);
+                Frame {
+                    function: function.clone(),
+                    call_line: self.call_sites.get(i).copied().flatten(),
+                    variables: scope.visible_variables(),
+                }
+            })
+            .collect()
+    }
+
+    /// Capture the frames the first time execution reaches a source line
+    ///
+    /// Enables frame inspection. Execution is not suspended: the frames are
+    /// snapshotted when the line starts and can be read afterwards with
+    /// [`Evaluator::take_paused_frames`]. Needs `Parser::with_spans`.
+    pub fn pause_at_line(&mut self, line: usize) {
+        self.inspection
+            .get_or_insert_with(FrameInspection::default)
+            .pause_lines
+            .insert(line);
+    }
+
+    /// Take the frames captured at a pause line: `(line, frames)`
+    pub fn take_paused_frames(&mut self) -> Option<(usize, Vec<Frame>)> {
+        self.inspection.as_mut()?.paused.take()
+    }
+
+    /// Record the caller's scope as a function frame is entered
+    pub(crate) fn push_frame_scope(&mut self, caller: &Scope) {
+        if let Some(inspection) = &mut self.inspection {
+            inspection.scopes.push(caller.clone());
+        }
+    }
+
+    /// Drop the caller scope recorded for the frame being left
+    pub(crate) fn pop_frame_scope(&mut self) {
+        if let Some(inspection) = &mut self.inspection {
+            inspection.scopes.pop();
+        }
+    }
+
+    /// Snapshot the frames if `line` is a pause line not yet reached
+    pub(crate) fn check_pause_line(&mut self, line: usize) {
+        let Some(inspection) = &self.inspection else {
+            return;
+        };
+        if inspection.paused.is_none() && inspection.pause_lines.contains(&line) {
+            let frames = self.frames();
+            if let Some(inspection) = &mut self.inspection {
+                inspection.paused = Some((line, frames));
+            }
+        }
+    }
+}
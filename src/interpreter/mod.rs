@@ -19,12 +19,22 @@ pub mod bug_discovery;
 pub mod evaluator;
 /// Evaluator helper methods (binary ops, calls, control flow)
 mod eval_helpers;
+/// Built-in functions and println/format rendering
+mod eval_builtins;
 /// Evaluator dispatch helpers (per-node-type evaluators)
 mod eval_dispatch;
+/// Option/Result constructors and methods (INTERP-109)
+mod eval_option;
+/// Per-frame scope inspection for debugger backtraces (INTERP-114)
+pub mod frames;
 /// AST type definitions (AstNode, Ast, operators, patterns, errors)
 pub mod ast;
+/// Injectable output sink for program output (INTERP-084)
+pub mod output;
 /// Parser and AST definitions
 pub mod parser;
+/// Tokenizer used by the parser, lex() and needs_more_input()
+mod tokenizer;
 /// Pathological input detector (DEBUGGER-042: Performance cliff detection)
 pub mod pathological_detector;
 /// Regression and hang detector (DEBUGGER-043: Regression & hang detection)
@@ -33,13 +43,21 @@ pub mod regression_hang_detector;
 pub mod scope;
 /// NASA-level soak and performance testing infrastructure
 pub mod soak_test;
+/// Tail-position call detection (INTERP-093)
+pub mod tail_calls;
+/// `#[test]` function discovery and execution (INTERP-115)
+pub mod test_runner;
 /// Runtime value types
 pub mod value;
+/// Reusable AST visitors (INTERP-103)
+pub mod visitor;
 
 // Re-export main types for convenience
 pub use bug_discovery::{BugDiscoveryAnalyzer, InterpreterBugReport};
-pub use evaluator::{EvalError, Evaluator};
-pub use parser::{Ast, AstNode, ParseError, Parser};
+pub use evaluator::{EvalError, EvalWarning, Evaluator};
+pub use frames::Frame;
+pub use output::OutputSink;
+pub use parser::{needs_more_input, Ast, AstNode, ParseError, Parser};
 pub use pathological_detector::{
     PathologicalCategory, PathologicalDetection, PathologicalDetector,
 };
@@ -51,4 +69,7 @@ pub use soak_test::{
     SoakConfig, SoakResult, SoakTestRunner, TelemetryCollector, TelemetrySnapshot,
     WorkloadDistribution,
 };
+pub use tail_calls::{find_all_tail_calls, find_tail_calls, TailCall};
+pub use test_runner::{run_tests, TestResult};
 pub use value::{Value, ValueError};
+pub use visitor::{walk_node, walk_node_mut, Visitor, VisitorMut};
@@ -0,0 +1,95 @@
+// INTERP-084: Injectable Output Sink
+//
+// Program output (print, println) goes through an OutputSink instead of writing
+// to stdout directly. Tests, the conformance runner, and embedders can
+// capture output per evaluator without redirecting the process stdout.
+//
+// Design:
+// - Stdout (default): writes straight to the process stdout
+// - Stderr: writes to the process stderr (default for dbg, INTERP-092)
+// - Buffer: appends to an in-memory String shared between clones, so a
+//   caller can keep a handle and read what the program printed
+
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+/// Destination for program output
+#[derive(Debug, Clone, Default)]
+pub enum OutputSink {
+    /// Write to the process stdout
+    #[default]
+    Stdout,
+    /// Write to the process stderr
+    Stderr,
+    /// Capture into a shared in-memory buffer
+    Buffer(Rc<RefCell<String>>),
+}
+
+impl OutputSink {
+    /// Create a capturing sink with an empty buffer
+    pub fn buffer() -> Self {
+        OutputSink::Buffer(Rc::new(RefCell::new(String::new())))
+    }
+
+    /// Write text without a trailing newline
+    pub fn write_str(&self, text: &str) {
+        match self {
+            OutputSink::Stdout => print!("{}", text),
+            OutputSink::Stderr => eprint!("{}", text),
+            OutputSink::Buffer(buffer) => buffer.borrow_mut().push_str(text),
+        }
+    }
+
+    /// Write a line of text followed by a newline
+    pub fn write_line(&self, text: &str) {
+        self.write_str(text);
+        self.write_str("\n");
+    }
+
+    /// Flush pending output (no-op for buffers)
+    pub fn flush(&self) {
+        match self {
+            OutputSink::Stdout => {
+                let _ = std::io::stdout().flush();
+            }
+            OutputSink::Stderr => {
+                let _ = std::io::stderr().flush();
+            }
+            OutputSink::Buffer(_) => {}
+        }
+    }
+
+    /// Captured text so far, or `None` when writing to stdout/stderr
+    pub fn contents(&self) -> Option<String> {
+        match self {
+            OutputSink::Stdout | OutputSink::Stderr => None,
+            OutputSink::Buffer(buffer) => Some(buffer.borrow().clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_captures_lines() {
+        let sink = OutputSink::buffer();
+        sink.write_line("hello");
+        sink.write_str("world");
+        assert_eq!(sink.contents(), Some("hello\nworld".to_string()));
+    }
+
+    #[test]
+    fn test_clones_share_buffer() {
+        let sink = OutputSink::buffer();
+        sink.clone().write_line("shared");
+        assert_eq!(sink.contents(), Some("shared\n".to_string()));
+    }
+
+    #[test]
+    fn test_stdout_has_no_contents() {
+        assert_eq!(OutputSink::Stdout.contents(), None);
+    }
+}
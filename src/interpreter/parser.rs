@@ -46,87 +46,123 @@
 // - let (a, b, c) = (1, 2, 3) (3-tuple destructuring)
 // - let (tx, rx) = mpsc::channel() (function return destructuring)
 // - Note: Nested patterns like ((a, b), c) not yet supported
+//
+// Repeat-until loops (INTERP-079):
+// - repeat { body } until condition
+// - Body always executes at least once before the condition is checked
+//
+// Source spans (INTERP-080):
+// - Every token records the 1-based line/column where it starts
+// - Parser::new(src).with_spans() wraps each statement in AstNode::Spanned
+// - The evaluator uses statement lines for call-site locations in stack traces
+//
+// Expression-bodied closures (INTERP-081):
+// - || 1 + 1 and |x| x * 2 (body without braces)
+//
+// Match patterns (INTERP-082):
+// - Range patterns: 1..5 (exclusive), 1..=5 (inclusive)
+// - As-patterns: x @ 1..=5 binds x to the whole value when the sub-pattern matches
+// - Guards (INTERP-124): x if x > 0 => .. falls through to later arms when false
+//
+// REPL continuation (INTERP-108):
+// - needs_more_input(src) is true for an open (, [, { or string literal
+// - ...and for input ending mid-expression (after an operator, `=`, `else`, ...)
+//
+// Pattern conditionals (INTERP-123):
+// - if let Some(x) = opt { .. } else { .. } binds x only in the then-branch
+// - while let Ok(v) = next() { .. } loops while the pattern matches
+//
+// Bitwise operators (INTERP-126):
+// - & | ^ << >> on integers, with Rust precedence: shifts bind looser than
+//   + and -, then &, ^, |, all tighter than comparisons
+// - A prefix & or &mut (e.g. &self, f(&v)) is accepted and ignored
+//
+// Integer literals (INTERP-127):
+// - Hexadecimal, octal and binary: 0xFF, 0o17, 0b1010
+// - Underscore digit separators: 1_000_000, 0xDE_AD_BE_EF
+// - Invalid digits (0b1012) and out-of-range values are parse errors
+//
+// Character literals (INTERP-128):
+// - 'a' plus the escapes '\n', '\t', '\r', '\0', '\\', '\'' and '\"'
+// - Empty, multi-character and unterminated literals are parse errors
+//
+// String escapes (INTERP-129):
+// - "a\nb" holds a real newline; \t, \r, \0, \\ and \" are decoded too
+// - An unknown escape or an unterminated string is a parse error
+//
+// Error recovery (INTERP-134):
+// - parse_recovering() collects every top-level error, skipping each failed
+//   item up to the next fun/struct, `;`, or its closing `}`
+//
+// Field assignment (INTERP-137):
+// - p.x = 10 updates an existing struct field; outer.inner.x = 1 and
+//   rows[0].x = 1 write back through every level
+//
+// Nested tuple destructuring (INTERP-136):
+// - let ((a, b), c) = ((1, 2), 3) and let (tx, (a, b)) = .. bind at any depth
+//
+// Infinite loops (INTERP-121):
+// - loop { body } repeats until a break; `break value` sets the loop's value
+// - loop is also an expression: let x = loop { break 5; }
+//
+// Lexing for editors:
+// - lex(src) returns each token's TokenClass with its start and end span
+//   (used by LSP semantic highlighting)
 
 pub use super::ast::*;
+pub use super::tokenizer::{lex, needs_more_input, LexedToken, TokenClass};
+use super::tokenizer::{tokenize, Token};
 
 /// Parser for Ruchy source code
 pub struct Parser {
     source: String,
     tokens: Vec<Token>,
+    /// Start position of each token (parallel to `tokens`)
+    spans: Vec<Span>,
     pos: usize,
+    /// Wrap statements in `AstNode::Spanned` (INTERP-080)
+    emit_spans: bool,
 }
 
-/// Token types
-#[derive(Debug, Clone, PartialEq)]
-enum Token {
-    // Keywords
-    Fun,
-    Let,
-    If,
-    Else,
-    While,
-    For,
-    In,
-    Match,
-    Return,
-    Struct,
-    As,
-    Mut,
-    Use,
-    Move,
-
-    // Identifiers and literals
-    Identifier(String),
-    Integer(i64),
-    Float(f64),
-    StringLit(String),
-    CharLit(char),   // Character literal: 'a'
-    FString(String), // F-string with interpolation: f"text {expr}"
-    True,
-    False,
-
-    // Operators
-    Plus,
-    Minus,
-    Star,
-    Slash,
-    Percent,
-    Not,
-    EqualEqual,
-    NotEqual,
-    LessThan,
-    GreaterThan,
-    LessEqual,
-    GreaterEqual,
-    AndAnd,
-    OrOr,
-    Pipe,
-
-    // Delimiters
-    LeftParen,
-    RightParen,
-    LeftBrace,
-    RightBrace,
-    LeftBracket,
-    RightBracket,
-    Comma,
-    Semicolon,
-    Colon,
-    ColonColon,
-    Arrow,
-    FatArrow,
-    Dot,
-    DotDot,
-    Equal,
-    PlusEqual,    // +=
-    MinusEqual,   // -=
-    StarEqual,    // *=
-    SlashEqual,   // /=
-    PercentEqual, // %=
-    Underscore,
-
-    // End of file
-    Eof,
+/// Binary operator spelled by `token`
+fn binary_operator(token: &Token) -> Option<BinaryOperator> {
+    let operator = match token {
+        Token::OrOr => BinaryOperator::Or,
+        Token::AndAnd => BinaryOperator::And,
+        Token::EqualEqual => BinaryOperator::Equal,
+        Token::NotEqual => BinaryOperator::NotEqual,
+        Token::LessThan => BinaryOperator::LessThan,
+        Token::GreaterThan => BinaryOperator::GreaterThan,
+        Token::LessEqual => BinaryOperator::LessEqual,
+        Token::GreaterEqual => BinaryOperator::GreaterEqual,
+        Token::Pipe => BinaryOperator::BitOr,
+        Token::Caret => BinaryOperator::BitXor,
+        Token::Ampersand => BinaryOperator::BitAnd,
+        Token::Shl => BinaryOperator::Shl,
+        Token::Shr => BinaryOperator::Shr,
+        Token::Plus => BinaryOperator::Add,
+        Token::Minus => BinaryOperator::Subtract,
+        Token::Star => BinaryOperator::Multiply,
+        Token::Slash => BinaryOperator::Divide,
+        Token::Percent => BinaryOperator::Modulo,
+        _ => return None,
+    };
+    Some(operator)
+}
+
+/// Whether a statement list always leaves its block: it ends in return,
+/// break, continue, a `panic(..)` call, or an if/else or block that does
+fn diverges(statements: &[AstNode]) -> bool {
+    match statements.last().map(AstNode::without_span) {
+        Some(AstNode::Return { .. } | AstNode::Break { .. } | AstNode::Continue) => true,
+        Some(AstNode::FunctionCall { name, .. }) => name == "panic",
+        Some(AstNode::IfExpr { then_branch, else_branch: Some(else_branch), .. }
+            | AstNode::IfLet { then_branch, else_branch: Some(else_branch), .. }) => {
+            diverges(then_branch) && diverges(else_branch)
+        }
+        Some(AstNode::Block { statements }) => diverges(statements),
+        _ => false,
+    }
 }
 
 impl Parser {
@@ -135,10 +171,21 @@ impl Parser {
         Parser {
             source: source.to_string(),
             tokens: Vec::new(),
+            spans: Vec::new(),
             pos: 0,
+            emit_spans: false,
         }
     }
 
+    /// INTERP-080: Wrap every parsed statement in `AstNode::Spanned`
+    ///
+    /// Lets the evaluator report source lines (e.g. call sites in stack
+    /// traces). Off by default so AST shapes stay unchanged for existing users.
+    pub fn with_spans(mut self) -> Self {
+        self.emit_spans = true;
+        self
+    }
+
     /// Parse the source code into an AST
     ///
     /// Tokenizes the source and parses top-level declarations (functions and structs)
@@ -164,6 +211,63 @@ impl Parser {
         Ok(Ast { nodes })
     }
 
+    /// INTERP-134: Parse, collecting every top-level error instead of stopping at the first
+    ///
+    /// After a failed item the parser skips past it (see `synchronize`) and
+    /// resumes, so the returned AST holds every item that parsed. A tokenizer
+    /// error leaves nothing to parse and is returned alone.
+    pub fn parse_recovering(&mut self) -> (Ast, Vec<ParseError>) {
+        if let Err(err) = self.tokenize() {
+            return (Ast { nodes: Vec::new() }, vec![err]);
+        }
+
+        let mut nodes = Vec::new();
+        let mut errors = Vec::new();
+        while !self.is_at_end() {
+            if self.check(&Token::Eof) {
+                break;
+            }
+            let start = self.pos;
+            match self.parse_top_level() {
+                Ok(node) => nodes.push(node),
+                Err(err) => {
+                    errors.push(err);
+                    self.synchronize(start);
+                }
+            }
+        }
+
+        (Ast { nodes }, errors)
+    }
+
+    /// Skip the failed item that began at token `start` (INTERP-134)
+    ///
+    /// Stops before the next `fun` or `struct`, or just after a `;` or the
+    /// `}` that closes the item's outermost brace. Always consumes at least
+    /// one token so recovery cannot loop.
+    fn synchronize(&mut self, start: usize) {
+        self.pos = start;
+        self.advance();
+        let mut depth = usize::from(self.tokens.get(start) == Some(&Token::LeftBrace));
+        while !self.is_at_end() {
+            match self.current() {
+                Some(Token::Fun | Token::Struct) => return,
+                Some(Token::LeftBrace) => depth += 1,
+                Some(Token::RightBrace) if depth <= 1 => {
+                    self.advance();
+                    return;
+                }
+                Some(Token::RightBrace) => depth -= 1,
+                Some(Token::Semicolon) if depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
     /// DEBUGGER-047: Parse with performance profiling
     ///
     /// Parses source code while tracking timing and operations for performance analysis
@@ -203,236 +307,68 @@ impl Parser {
         Ok(Ast { nodes })
     }
 
-    /// Tokenize the source code into a vector of tokens
-    ///
-    /// Handles whitespace, comments, string literals, numbers, identifiers,
-    /// keywords, and operators/delimiters
+    /// Tokenize the source code into `tokens` and `spans`
     fn tokenize(&mut self) -> Result<(), ParseError> {
-        let mut chars = self.source.chars().peekable();
-        // INTERP-048: Pre-allocate tokens Vec (estimate: 1 token per 4 chars, min 16)
-        let estimated_tokens = (self.source.len() / 4).max(16);
-        let mut tokens = Vec::with_capacity(estimated_tokens);
-
-        while let Some(&ch) = chars.peek() {
-            match ch {
-                ' ' | '\t' | '\n' | '\r' => { chars.next(); }
-                '/' if chars.clone().nth(1) == Some('/') => Self::scan_comment(&mut chars),
-                '"' => Self::scan_string(&mut chars, &mut tokens),
-                '\'' => Self::scan_char(&mut chars, &mut tokens),
-                '0'..='9' => Self::scan_number(&mut chars, &mut tokens),
-                'f' if chars.clone().nth(1) == Some('"') => Self::scan_fstring(&mut chars, &mut tokens),
-                'a'..='z' | 'A'..='Z' | '_' => Self::scan_identifier(&mut chars, &mut tokens),
-                _ => Self::scan_operator(&mut chars, &mut tokens),
-            }
-        }
-
-        tokens.push(Token::Eof);
+        let (tokens, spans) = tokenize(&self.source)?;
         self.tokens = tokens;
+        self.spans = spans;
         Ok(())
     }
 
-    /// Skip a line comment (// ... \n)
-    fn scan_comment(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
-        chars.next(); // /
-        chars.next(); // /
-        while let Some(&ch) = chars.peek() {
-            chars.next();
-            if ch == '\n' {
-                break;
-            }
-        }
-    }
-
-    /// Scan a double-quoted string literal
-    fn scan_string(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, tokens: &mut Vec<Token>) {
-        chars.next(); // Opening "
-        let mut string = String::new();
-        while let Some(&ch) = chars.peek() {
-            chars.next();
-            if ch == '"' {
-                break;
-            }
-            string.push(ch);
-        }
-        tokens.push(Token::StringLit(string));
-    }
-
-    /// Scan a character literal: 'a', '!', etc.
-    fn scan_char(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, tokens: &mut Vec<Token>) {
-        chars.next(); // Opening '
-        if let Some(&ch) = chars.peek() {
-            let character = ch;
-            chars.next(); // consume character
-            if chars.peek() == Some(&'\'') {
-                chars.next(); // consume closing '
-                tokens.push(Token::CharLit(character));
-            }
+    /// Parse a top-level item (function, struct, enum, impl, use statement, or statement)
+    ///
+    /// Supports REPL-style programming by allowing top-level statements
+    /// like `println("Hello")` or `let x = 42` in addition to function/struct declarations.
+    fn parse_top_level(&mut self) -> Result<AstNode, ParseError> {
+        match self.current() {
+            Some(Token::Use) => self.parse_use(),
+            Some(Token::Fun) => self.parse_function(),
+            Some(Token::Struct) => self.parse_struct(),
+            Some(Token::Enum) => self.parse_enum(),
+            Some(Token::Impl) => self.parse_impl(),
+            Some(Token::Hash) => self.parse_attributed(),
+            // Allow top-level statements for REPL-style programming
+            // This includes: let declarations, function calls, expressions, etc.
+            _ => self.parse_statement(),
         }
     }
 
-    /// Scan a numeric literal (integer or float)
-    fn scan_number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, tokens: &mut Vec<Token>) {
-        let mut num = String::new();
-        let mut is_float = false;
-
-        // Parse integer part
-        while let Some(&ch) = chars.peek() {
-            if ch.is_ascii_digit() {
-                num.push(ch);
-                chars.next();
-            } else {
-                break;
+    /// Parse `#[attr]` and the item it annotates
+    ///
+    /// `#[test]` turns the following function into an `AstNode::TestDef`;
+    /// other attributes (e.g. `#[derive(Debug)]`) are accepted and ignored.
+    fn parse_attributed(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Hash)?;
+        self.consume(&Token::LeftBracket)?;
+        let is_test = matches!(self.current(), Some(Token::Identifier(name)) if name == "test")
+            && self.tokens.get(self.pos + 1) == Some(&Token::RightBracket);
+
+        // Skip the attribute body up to its closing bracket
+        let mut depth = 1;
+        while depth > 0 && !self.is_at_end() {
+            match self.current() {
+                Some(Token::LeftBracket) => depth += 1,
+                Some(Token::RightBracket) => depth -= 1,
+                _ => {}
             }
+            self.advance();
         }
 
-        // Check for decimal point
-        if chars.peek() == Some(&'.') {
-            // Look ahead to see if next char is a digit (not a method call like "42.abs()")
-            let mut chars_clone = chars.clone();
-            if let Some(next_ch) = chars_clone.nth(1) {
-                if next_ch.is_ascii_digit() {
-                    is_float = true;
-                    num.push('.');
-                    chars.next(); // consume '.'
-
-                    // Parse fractional part
-                    while let Some(&ch) = chars.peek() {
-                        if ch.is_ascii_digit() {
-                            num.push(ch);
-                            chars.next();
-                        } else {
-                            break;
-                        }
-                    }
-                }
-            }
+        if !is_test {
+            return self.parse_top_level();
         }
-
-        // Create appropriate token
-        if is_float {
-            if let Ok(f) = num.parse::<f64>() {
-                tokens.push(Token::Float(f));
-            }
-        } else if let Ok(n) = num.parse::<i64>() {
-            tokens.push(Token::Integer(n));
+        if !self.check(&Token::Fun) {
+            return Err(self.unexpected_token("function after #[test]".to_string()));
         }
-    }
-
-    /// Scan an f-string: f"text {expr} more"
-    fn scan_fstring(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, tokens: &mut Vec<Token>) {
-        chars.next(); // consume 'f'
-        chars.next(); // consume opening "
-
-        let mut content = String::new();
-        while let Some(&ch) = chars.peek() {
-            chars.next();
-            if ch == '"' {
-                break;
+        match self.parse_function()? {
+            AstNode::FunctionDef { name, params, .. } if !params.is_empty() => {
+                Err(self.invalid_syntax(&format!(
+                    "test function '{}' must not take parameters",
+                    name
+                )))
             }
-            content.push(ch);
-        }
-        tokens.push(Token::FString(content));
-    }
-
-    /// Scan an identifier or keyword
-    fn scan_identifier(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, tokens: &mut Vec<Token>) {
-        let mut ident = String::new();
-        while let Some(&ch) = chars.peek() {
-            if ch.is_alphanumeric() || ch == '_' {
-                ident.push(ch);
-                chars.next();
-            } else {
-                break;
-            }
-        }
-
-        let token = match ident.as_str() {
-            "fun" => Token::Fun,
-            "let" => Token::Let,
-            "mut" => Token::Mut,
-            "if" => Token::If,
-            "else" => Token::Else,
-            "while" => Token::While,
-            "for" => Token::For,
-            "in" => Token::In,
-            "match" => Token::Match,
-            "return" => Token::Return,
-            "struct" => Token::Struct,
-            "as" => Token::As,
-            "use" => Token::Use,
-            "move" => Token::Move,
-            "true" => Token::True,
-            "false" => Token::False,
-            _ => Token::Identifier(ident),
-        };
-        tokens.push(token);
-    }
-
-    /// Scan an operator, delimiter, or skip unknown character
-    fn scan_operator(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, tokens: &mut Vec<Token>) {
-        let ch = *chars.peek().unwrap();
-        let next = chars.clone().nth(1);
-
-        match (ch, next) {
-            // Two-character operators (check first)
-            ('+', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::PlusEqual); }
-            ('-', Some('>')) => { chars.next(); chars.next(); tokens.push(Token::Arrow); }
-            ('-', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::MinusEqual); }
-            ('*', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::StarEqual); }
-            ('/', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::SlashEqual); }
-            ('%', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::PercentEqual); }
-            (':', Some(':')) => { chars.next(); chars.next(); tokens.push(Token::ColonColon); }
-            ('.', Some('.')) => { chars.next(); chars.next(); tokens.push(Token::DotDot); }
-            ('=', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::EqualEqual); }
-            ('=', Some('>')) => { chars.next(); chars.next(); tokens.push(Token::FatArrow); }
-            ('!', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::NotEqual); }
-            ('<', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::LessEqual); }
-            ('>', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::GreaterEqual); }
-            ('&', Some('&')) => { chars.next(); chars.next(); tokens.push(Token::AndAnd); }
-            ('|', Some('|')) => { chars.next(); chars.next(); tokens.push(Token::OrOr); }
-
-            // Single-character operators and delimiters
-            ('+', _) => { chars.next(); tokens.push(Token::Plus); }
-            ('-', _) => { chars.next(); tokens.push(Token::Minus); }
-            ('*', _) => { chars.next(); tokens.push(Token::Star); }
-            ('/', _) => { chars.next(); tokens.push(Token::Slash); }
-            ('%', _) => { chars.next(); tokens.push(Token::Percent); }
-            ('(', _) => { chars.next(); tokens.push(Token::LeftParen); }
-            (')', _) => { chars.next(); tokens.push(Token::RightParen); }
-            ('{', _) => { chars.next(); tokens.push(Token::LeftBrace); }
-            ('}', _) => { chars.next(); tokens.push(Token::RightBrace); }
-            ('[', _) => { chars.next(); tokens.push(Token::LeftBracket); }
-            (']', _) => { chars.next(); tokens.push(Token::RightBracket); }
-            (',', _) => { chars.next(); tokens.push(Token::Comma); }
-            (';', _) => { chars.next(); tokens.push(Token::Semicolon); }
-            (':', _) => { chars.next(); tokens.push(Token::Colon); }
-            ('.', _) => { chars.next(); tokens.push(Token::Dot); }
-            ('=', _) => { chars.next(); tokens.push(Token::Equal); }
-            ('!', _) => { chars.next(); tokens.push(Token::Not); }
-            ('<', _) => { chars.next(); tokens.push(Token::LessThan); }
-            ('>', _) => { chars.next(); tokens.push(Token::GreaterThan); }
-            ('|', _) => { chars.next(); tokens.push(Token::Pipe); }
-
-            // Unknown character - skip
-            _ => { chars.next(); }
-        }
-    }
-
-    /// Parse a top-level item (function, struct, use statement, or statement)
-    ///
-    /// Supports REPL-style programming by allowing top-level statements
-    /// like `println("Hello")` or `let x = 42` in addition to function/struct declarations.
-    fn parse_top_level(&mut self) -> Result<AstNode, ParseError> {
-        if self.check(&Token::Use) {
-            self.parse_use()
-        } else if self.check(&Token::Fun) {
-            self.parse_function()
-        } else if self.check(&Token::Struct) {
-            self.parse_struct()
-        } else {
-            // Allow top-level statements for REPL-style programming
-            // This includes: let declarations, function calls, expressions, etc.
-            self.parse_statement()
+            AstNode::FunctionDef { name, body, .. } => Ok(AstNode::TestDef { name, body }),
+            other => Ok(other),
         }
     }
 
@@ -444,51 +380,42 @@ impl Parser {
             self.advance();
             n
         } else {
-            return Err(ParseError::InvalidSyntax {
-                message: "Expected function name".to_string(),
-                line: 0,
-                column: 0,
-            });
+            return Err(self.invalid_syntax("Expected function name"));
         };
 
         self.consume(&Token::LeftParen)?;
 
         let mut params = Vec::new();
+        let mut param_types = Vec::new();
         while !self.check(&Token::RightParen) && !self.is_at_end() {
-            if let Some(Token::Identifier(p)) = self.current() {
-                params.push(p.clone());
-                self.advance();
-
-                // Skip optional type annotation
-                // Note: '&' in '&str' is not tokenized (skipped by tokenizer)
-                // so '&str' appears as just 'str' token, making it a single-token type
-                if self.check(&Token::Colon) {
-                    self.advance(); // consume ':'
-
-                    // Skip the type name (one token: 'i32', 'str', 'bool', etc.)
-                    if !self.is_at_end() {
-                        self.advance();
-                    }
-                }
+            // `&self` and `&mut self` bind plain `self`
+            self.skip_reference();
+            if !matches!(self.current(), Some(Token::Identifier(_) | Token::Underscore)) {
+                return Err(self.invalid_syntax("Expected parameter name"));
             }
+            params.push(self.expect_binding());
 
-            if self.check(&Token::Comma) {
-                self.advance();
-            }
+            // Optional type annotation
+            let param_type = if self.check(&Token::Colon) {
+                self.advance(); // consume ':'
+                self.function_type()
+            } else {
+                None
+            };
+            param_types.push(param_type);
+
+            self.list_separator(&Token::RightParen, "parameter")?;
         }
 
         self.consume(&Token::RightParen)?;
 
-        // Skip optional return type annotation
-        // Note: '&' is not tokenized, so '&str' appears as just 'str' token, making it a single token
-        if self.check(&Token::Arrow) {
+        // Optional return type annotation
+        let return_type = if self.check(&Token::Arrow) {
             self.advance(); // consume '->'
-
-            // Skip the return type name (one token: 'i32', 'str', 'bool', etc.)
-            if !self.is_at_end() {
-                self.advance();
-            }
-        }
+            self.function_type()
+        } else {
+            None
+        };
 
         self.consume(&Token::LeftBrace)?;
 
@@ -499,7 +426,40 @@ impl Parser {
 
         self.consume(&Token::RightBrace)?;
 
-        Ok(AstNode::FunctionDef { name, params, body })
+        Ok(AstNode::FunctionDef {
+            name,
+            params,
+            param_types,
+            return_type,
+            body,
+        })
+    }
+
+    /// Parse a parameter or return type: one token such as `i32` or `str`,
+    /// kept with any `&` or `&mut` prefix
+    ///
+    /// A token that is not a type name is skipped and gives None.
+    fn function_type(&mut self) -> Option<String> {
+        let mut annotation = String::new();
+        if self.check(&Token::Ampersand) {
+            self.advance();
+            annotation.push('&');
+            if self.check(&Token::Mut) {
+                self.advance();
+                annotation.push_str("mut ");
+            }
+        }
+        let type_name = match self.current() {
+            Some(Token::Identifier(name)) => {
+                annotation.push_str(name);
+                Some(annotation)
+            }
+            _ => None,
+        };
+        if !self.is_at_end() {
+            self.advance();
+        }
+        type_name
     }
 
     /// Parse a struct definition
@@ -510,11 +470,7 @@ impl Parser {
             self.advance();
             n
         } else {
-            return Err(ParseError::InvalidSyntax {
-                message: "Expected struct name".to_string(),
-                line: 0,
-                column: 0,
-            });
+            return Err(self.invalid_syntax("Expected struct name"));
         };
 
         self.consume(&Token::LeftBrace)?;
@@ -527,6 +483,7 @@ impl Parser {
 
                 let type_annotation = if self.check(&Token::Colon) {
                     self.advance();
+                    self.skip_reference();
                     if let Some(Token::Identifier(ty)) = self.current() {
                         let ty = ty.clone();
                         self.advance();
@@ -544,6 +501,70 @@ impl Parser {
                 });
             }
 
+            self.list_separator(&Token::RightBrace, "struct field")?;
+        }
+
+        self.consume(&Token::RightBrace)?;
+
+        Ok(AstNode::StructDef { name, fields })
+    }
+
+    /// Parse an impl block: impl Point { fun to_string(self) { ... } }
+    fn parse_impl(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Impl)?;
+
+        let type_name = if let Some(Token::Identifier(n)) = self.current().cloned() {
+            self.advance();
+            n
+        } else {
+            return Err(self.invalid_syntax("Expected type name after impl"));
+        };
+
+        self.consume(&Token::LeftBrace)?;
+
+        let mut methods = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            methods.push(self.parse_function()?);
+        }
+
+        self.consume(&Token::RightBrace)?;
+
+        Ok(AstNode::ImplBlock { type_name, methods })
+    }
+
+    /// Parse an enum definition: enum Status { Ok = 0, Err = 1 }
+    fn parse_enum(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Enum)?;
+
+        let name = if let Some(Token::Identifier(n)) = self.current().cloned() {
+            self.advance();
+            n
+        } else {
+            return Err(self.invalid_syntax("Expected enum name"));
+        };
+
+        self.consume(&Token::LeftBrace)?;
+
+        let mut variants = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            let Some(Token::Identifier(variant_name)) = self.current().cloned() else {
+                return Err(self.invalid_syntax(&format!("Expected variant name in enum {}", name)));
+            };
+            self.advance();
+
+            // Optional explicit discriminant: Variant = expr
+            let discriminant = if self.check(&Token::Equal) {
+                self.advance();
+                Some(self.parse_expression()?)
+            } else {
+                None
+            };
+
+            variants.push(EnumVariant {
+                name: variant_name,
+                discriminant,
+            });
+
             if self.check(&Token::Comma) {
                 self.advance();
             }
@@ -551,7 +572,7 @@ impl Parser {
 
         self.consume(&Token::RightBrace)?;
 
-        Ok(AstNode::StructDef { name, fields })
+        Ok(AstNode::EnumDef { name, variants })
     }
 
     /// Parse a use statement: use std::sync::Mutex;
@@ -579,9 +600,7 @@ impl Parser {
             while !self.check(&Token::RightBrace) && !self.is_at_end() {
                 items.push(self.expect_identifier());
 
-                if self.check(&Token::Comma) {
-                    self.advance();
-                }
+                self.list_separator(&Token::RightBrace, "use item")?;
             }
 
             self.consume(&Token::RightBrace)?;
@@ -605,66 +624,61 @@ impl Parser {
         Ok(AstNode::UseDecl { path })
     }
 
-    /// Parse a statement
+    /// Parse a statement, attaching its source span when enabled
     fn parse_statement(&mut self) -> Result<AstNode, ParseError> {
-        if self.check(&Token::Let) {
-            self.parse_let()
-        } else if self.check(&Token::If) {
-            self.parse_if()
-        } else if self.check(&Token::While) {
-            self.parse_while()
-        } else if self.check(&Token::For) {
-            self.parse_for()
-        } else if self.check(&Token::Match) {
-            self.parse_match()
-        } else if self.check(&Token::Return) {
-            self.parse_return()
+        let span = self.current_span();
+        let statement = self.parse_statement_kind()?;
+        if self.emit_spans {
+            Ok(AstNode::Spanned {
+                span,
+                node: Box::new(statement),
+            })
         } else {
-            // Check for assignment or compound assignment
-            if let Some(Token::Identifier(name)) = self.current().cloned() {
-                let next_token = self.tokens.get(self.pos + 1);
-
-                // Check for regular assignment: identifier = expr
-                if next_token == Some(&Token::Equal) {
-                    self.advance(); // consume identifier
-                    self.advance(); // consume =
-                    let value = Box::new(self.parse_expression()?);
-                    if self.check(&Token::Semicolon) {
-                        self.advance();
-                    }
-                    return Ok(AstNode::Assignment { name, value });
-                }
-
-                // Check for compound assignment: identifier += expr
-                if let Some(op_token) = next_token {
-                    let op = match op_token {
-                        Token::PlusEqual => Some(BinaryOperator::Add),
-                        Token::MinusEqual => Some(BinaryOperator::Subtract),
-                        Token::StarEqual => Some(BinaryOperator::Multiply),
-                        Token::SlashEqual => Some(BinaryOperator::Divide),
-                        Token::PercentEqual => Some(BinaryOperator::Modulo),
-                        _ => None,
-                    };
+            Ok(statement)
+        }
+    }
 
-                    if let Some(op) = op {
-                        self.advance(); // consume identifier
-                        self.advance(); // consume compound operator
-                        let lhs = Box::new(AstNode::Identifier(name));
-                        let rhs = Box::new(self.parse_expression()?);
-                        if self.check(&Token::Semicolon) {
-                            self.advance();
-                        }
-                        return Ok(AstNode::CompoundAssignment { lhs, op, rhs });
-                    }
+    /// Parse the statement itself (let, control flow, assignment, expression)
+    fn parse_statement_kind(&mut self) -> Result<AstNode, ParseError> {
+        match self.current() {
+            Some(Token::Let) => self.parse_let(),
+            Some(Token::If) => self.parse_if(),
+            Some(Token::While) => self.parse_while(),
+            Some(Token::For) => self.parse_for(),
+            Some(Token::Repeat) => self.parse_repeat(),
+            Some(Token::Loop) => {
+                let node = self.parse_loop()?;
+                if self.check(&Token::Semicolon) {
+                    self.advance();
                 }
+                Ok(node)
             }
+            Some(Token::Match) => self.parse_match(),
+            Some(Token::Return) => self.parse_return(),
+            Some(Token::Break | Token::Continue) => self.parse_loop_jump(),
+            _ => self.parse_expression_statement(),
+        }
+    }
 
-            // Check for compound assignment with dereference: *expr += value
-            // Parse as expression first, then check for compound operator
-            let expr = self.parse_expression()?;
+    /// Parse an assignment, compound assignment or expression statement
+    fn parse_expression_statement(&mut self) -> Result<AstNode, ParseError> {
+        // Check for assignment or compound assignment
+        if let Some(Token::Identifier(_)) = self.current() {
+            let next_token = self.tokens.get(self.pos + 1);
+
+            // Check for regular assignment: identifier = expr
+            if next_token == Some(&Token::Equal) {
+                let name = self.expect_identifier();
+                self.advance(); // consume =
+                let value = Box::new(self.parse_expression()?);
+                if self.check(&Token::Semicolon) {
+                    self.advance();
+                }
+                return Ok(AstNode::Assignment { name, value });
+            }
 
-            // Check if this is followed by a compound assignment operator
-            if let Some(op_token) = self.current().cloned() {
+            // Check for compound assignment: identifier += expr
+            if let Some(op_token) = next_token {
                 let op = match op_token {
                     Token::PlusEqual => Some(BinaryOperator::Add),
                     Token::MinusEqual => Some(BinaryOperator::Subtract),
@@ -675,8 +689,9 @@ impl Parser {
                 };
 
                 if let Some(op) = op {
+                    let name = self.expect_identifier();
                     self.advance(); // consume compound operator
-                    let lhs = Box::new(expr);
+                    let lhs = Box::new(AstNode::Identifier(name));
                     let rhs = Box::new(self.parse_expression()?);
                     if self.check(&Token::Semicolon) {
                         self.advance();
@@ -684,13 +699,60 @@ impl Parser {
                     return Ok(AstNode::CompoundAssignment { lhs, op, rhs });
                 }
             }
+        }
 
-            // Regular expression statement
-            if self.check(&Token::Semicolon) {
-                self.advance();
+        // Check for compound assignment with dereference: *expr += value
+        // Parse as expression first, then check for compound operator
+        let expr = self.parse_expression()?;
+
+        // Index assignment: v[i] = value (INTERP-120)
+        if self.check(&Token::Equal) {
+            if let AstNode::IndexAccess { expr: target, index } = expr {
+                self.advance(); // consume =
+                let value = Box::new(self.parse_expression()?);
+                if self.check(&Token::Semicolon) {
+                    self.advance();
+                }
+                return Ok(AstNode::IndexAssignment { target, index, value });
+            }
+            // Field assignment: p.x = value (INTERP-137)
+            if let AstNode::FieldAccess { expr: target, field } = expr {
+                self.advance(); // consume =
+                let value = Box::new(self.parse_expression()?);
+                if self.check(&Token::Semicolon) {
+                    self.advance();
+                }
+                return Ok(AstNode::FieldAssignment { target, field, value });
             }
-            Ok(expr)
         }
+
+        // Check if this is followed by a compound assignment operator
+        if let Some(op_token) = self.current() {
+            let op = match op_token {
+                Token::PlusEqual => Some(BinaryOperator::Add),
+                Token::MinusEqual => Some(BinaryOperator::Subtract),
+                Token::StarEqual => Some(BinaryOperator::Multiply),
+                Token::SlashEqual => Some(BinaryOperator::Divide),
+                Token::PercentEqual => Some(BinaryOperator::Modulo),
+                _ => None,
+            };
+
+            if let Some(op) = op {
+                self.advance(); // consume compound operator
+                let lhs = Box::new(expr);
+                let rhs = Box::new(self.parse_expression()?);
+                if self.check(&Token::Semicolon) {
+                    self.advance();
+                }
+                return Ok(AstNode::CompoundAssignment { lhs, op, rhs });
+            }
+        }
+
+        // Regular expression statement
+        if self.check(&Token::Semicolon) {
+            self.advance();
+        }
+        Ok(expr)
     }
 
     /// Parse let declaration
@@ -698,22 +760,14 @@ impl Parser {
         self.consume(&Token::Let)?;
 
         // Check for optional 'mut' keyword
-        if self.check(&Token::Mut) {
+        let mutable = self.check(&Token::Mut);
+        if mutable {
             self.advance();
         }
 
         // Check for tuple destructuring: let (a, b) = expr
         if self.check(&Token::LeftParen) {
-            self.advance(); // consume (
-
-            let mut names = Vec::new();
-            while !self.check(&Token::RightParen) && !self.is_at_end() {
-                names.push(self.expect_identifier());
-                if self.check(&Token::Comma) {
-                    self.advance();
-                }
-            }
-            self.consume(&Token::RightParen)?;
+            let patterns = self.parse_tuple_binding()?;
 
             self.consume(&Token::Equal)?;
             let value = Box::new(self.parse_expression()?);
@@ -722,11 +776,16 @@ impl Parser {
                 self.advance();
             }
 
-            return Ok(AstNode::TupleDestruct { names, value });
+            return Ok(AstNode::TupleDestruct { patterns, value });
+        }
+
+        // Refutable binding: let Some(x) = expr else { ... }
+        if self.at_refutable_pattern() {
+            return self.parse_let_else();
         }
 
         // Regular let declaration: let name = expr
-        let name = self.expect_identifier();
+        let name = self.expect_binding();
 
         self.consume(&Token::Equal)?;
         let value = Box::new(self.parse_expression()?);
@@ -735,13 +794,81 @@ impl Parser {
             self.advance();
         }
 
-        Ok(AstNode::LetDecl { name, value })
+        Ok(AstNode::LetDecl {
+            name,
+            mutable,
+            value,
+        })
+    }
+
+    /// Parse a parenthesized `let` binding list: `(a, _, (b, c))`
+    ///
+    /// INTERP-136: elements are names, `_` or nested tuples.
+    fn parse_tuple_binding(&mut self) -> Result<Vec<Pattern>, ParseError> {
+        self.consume(&Token::LeftParen)?;
+        let mut patterns = Vec::new();
+        while !self.check(&Token::RightParen) && !self.is_at_end() {
+            if self.check(&Token::LeftParen) {
+                patterns.push(Pattern::Tuple(self.parse_tuple_binding()?));
+            } else if self.check(&Token::Underscore) {
+                self.advance();
+                patterns.push(Pattern::Wildcard);
+            } else {
+                patterns.push(Pattern::Identifier(self.expect_identifier()));
+            }
+            self.list_separator(&Token::RightParen, "tuple pattern")?;
+        }
+        self.consume(&Token::RightParen)?;
+        Ok(patterns)
+    }
+
+    /// Whether the current tokens start a refutable `let` pattern
+    /// (`Some(x)`, `None`, `Color::Red`) rather than a plain name
+    fn at_refutable_pattern(&self) -> bool {
+        match (self.current(), self.tokens.get(self.pos + 1)) {
+            (Some(Token::Identifier(id)), _) if id == "None" => true,
+            (Some(Token::Identifier(_)), Some(Token::LeftParen | Token::ColonColon)) => true,
+            _ => false,
+        }
+    }
+
+    /// Parse the rest of `let pattern = expr else { ... }` (after `let`)
+    fn parse_let_else(&mut self) -> Result<AstNode, ParseError> {
+        let pattern = Box::new(self.parse_pattern()?);
+        self.consume(&Token::Equal)?;
+        let value = Box::new(self.parse_expression()?);
+
+        if !self.check(&Token::Else) {
+            return Err(self.unexpected_token("'else' after refutable let pattern".to_string()));
+        }
+        self.advance();
+        self.consume(&Token::LeftBrace)?;
+        let mut else_block = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            else_block.push(self.parse_statement()?);
+        }
+        if !diverges(&else_block) {
+            return Err(self.invalid_syntax(
+                "let-else block must diverge (end in return, break, continue or panic)",
+            ));
+        }
+        self.consume(&Token::RightBrace)?;
+
+        if self.check(&Token::Semicolon) {
+            self.advance();
+        }
+
+        Ok(AstNode::LetElse { pattern, value, else_block })
     }
 
     /// Parse if expression
     fn parse_if(&mut self) -> Result<AstNode, ParseError> {
         self.consume(&Token::If)?;
 
+        if self.check(&Token::Let) {
+            return self.parse_if_let();
+        }
+
         let condition = Box::new(self.parse_expression()?);
 
         self.consume(&Token::LeftBrace)?;
@@ -752,29 +879,7 @@ impl Parser {
         }
         self.consume(&Token::RightBrace)?;
 
-        let else_branch = if self.check(&Token::Else) {
-            self.advance();
-
-            // Check for 'else if' (two separate tokens)
-            if self.check(&Token::If) {
-                // Recursively parse the 'if' as the else branch
-                // This handles 'else if' chains naturally
-                let else_if = self.parse_if()?;
-                Some(vec![else_if])
-            } else {
-                // Regular 'else { ... }' block
-                self.consume(&Token::LeftBrace)?;
-                // INTERP-OPT-003: Pre-allocate capacity for typical else blocks (4 statements)
-                let mut else_body = Vec::with_capacity(4);
-                while !self.check(&Token::RightBrace) && !self.is_at_end() {
-                    else_body.push(self.parse_statement()?);
-                }
-                self.consume(&Token::RightBrace)?;
-                Some(else_body)
-            }
-        } else {
-            None
-        };
+        let else_branch = self.parse_else_branch()?;
 
         Ok(AstNode::IfExpr {
             condition,
@@ -783,10 +888,64 @@ impl Parser {
         })
     }
 
+    /// Parse the rest of `if let pattern = expr { .. }` (after `if`)
+    fn parse_if_let(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Let)?;
+        let pattern = Box::new(self.parse_pattern()?);
+        self.consume(&Token::Equal)?;
+        let scrutinee = Box::new(self.parse_expression()?);
+
+        self.consume(&Token::LeftBrace)?;
+        let mut then_branch = Vec::with_capacity(4);
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            then_branch.push(self.parse_statement()?);
+        }
+        self.consume(&Token::RightBrace)?;
+
+        let else_branch = self.parse_else_branch()?;
+
+        Ok(AstNode::IfLet {
+            pattern,
+            scrutinee,
+            then_branch,
+            else_branch,
+        })
+    }
+
+    /// Parse an optional `else { .. }` or `else if ..` after an if's then-branch
+    fn parse_else_branch(&mut self) -> Result<Option<Vec<AstNode>>, ParseError> {
+        if !self.check(&Token::Else) {
+            return Ok(None);
+        }
+        self.advance();
+
+        // Check for 'else if' (two separate tokens)
+        if self.check(&Token::If) {
+            // Recursively parse the 'if' as the else branch
+            // This handles 'else if' chains naturally
+            let else_if = self.parse_if()?;
+            return Ok(Some(vec![else_if]));
+        }
+
+        // Regular 'else { ... }' block
+        self.consume(&Token::LeftBrace)?;
+        // INTERP-OPT-003: Pre-allocate capacity for typical else blocks (4 statements)
+        let mut else_body = Vec::with_capacity(4);
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            else_body.push(self.parse_statement()?);
+        }
+        self.consume(&Token::RightBrace)?;
+        Ok(Some(else_body))
+    }
+
     /// Parse while loop
     fn parse_while(&mut self) -> Result<AstNode, ParseError> {
         self.consume(&Token::While)?;
 
+        if self.check(&Token::Let) {
+            return self.parse_while_let();
+        }
+
         let condition = Box::new(self.parse_expression()?);
 
         self.consume(&Token::LeftBrace)?;
@@ -800,6 +959,62 @@ impl Parser {
         Ok(AstNode::WhileLoop { condition, body })
     }
 
+    /// Parse the rest of `while let pattern = expr { body }` (after `while`)
+    fn parse_while_let(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Let)?;
+        let pattern = Box::new(self.parse_pattern()?);
+        self.consume(&Token::Equal)?;
+        let scrutinee = Box::new(self.parse_expression()?);
+
+        self.consume(&Token::LeftBrace)?;
+        let mut body = Vec::with_capacity(4);
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            body.push(self.parse_statement()?);
+        }
+        self.consume(&Token::RightBrace)?;
+
+        Ok(AstNode::WhileLet {
+            pattern,
+            scrutinee,
+            body,
+        })
+    }
+
+    /// Parse repeat-until loop: repeat { body } until condition
+    fn parse_repeat(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Repeat)?;
+
+        self.consume(&Token::LeftBrace)?;
+        let mut body = Vec::with_capacity(4);
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            body.push(self.parse_statement()?);
+        }
+        self.consume(&Token::RightBrace)?;
+
+        self.consume(&Token::Until)?;
+        let condition = Box::new(self.parse_expression()?);
+
+        if self.check(&Token::Semicolon) {
+            self.advance();
+        }
+
+        Ok(AstNode::RepeatUntil { body, condition })
+    }
+
+    /// Parse infinite loop: loop { body }
+    fn parse_loop(&mut self) -> Result<AstNode, ParseError> {
+        self.consume(&Token::Loop)?;
+
+        self.consume(&Token::LeftBrace)?;
+        let mut body = Vec::with_capacity(4);
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            body.push(self.parse_statement()?);
+        }
+        self.consume(&Token::RightBrace)?;
+
+        Ok(AstNode::Loop { body })
+    }
+
     /// Parse for loop
     fn parse_for(&mut self) -> Result<AstNode, ParseError> {
         self.consume(&Token::For)?;
@@ -807,14 +1022,14 @@ impl Parser {
         // Check for tuple destructuring: for (a, b) in ...
         let var = if self.check(&Token::LeftParen) {
             self.advance(); // consume (
-            let first = self.expect_identifier();
+            let first = self.expect_binding();
             self.consume(&Token::Comma)?;
-            let second = self.expect_identifier();
+            let second = self.expect_binding();
             self.consume(&Token::RightParen)?;
             // Create compound identifier for tuple destructuring
             format!("({}, {})", first, second)
         } else {
-            self.expect_identifier()
+            self.expect_binding()
         };
 
         self.consume(&Token::In)?;
@@ -846,26 +1061,20 @@ impl Parser {
 
         let mut arms = Vec::new();
         while !self.check(&Token::RightBrace) && !self.is_at_end() {
-            let pattern = if self.check(&Token::Underscore) {
-                self.advance();
-                Pattern::Wildcard
-            } else if let Some(Token::Integer(n)) = self.current() {
-                let n = *n;
-                self.advance();
-                Pattern::Literal(AstNode::IntegerLiteral(n))
-            } else if let Some(Token::Identifier(id)) = self.current() {
-                let id = id.clone();
+            let pattern = self.parse_pattern()?;
+
+            let guard = if self.check(&Token::If) {
                 self.advance();
-                Pattern::Identifier(id)
+                Some(self.parse_expression()?)
             } else {
-                Pattern::Wildcard
+                None
             };
 
             self.consume(&Token::FatArrow)?;
 
             let body = vec![self.parse_expression()?];
 
-            arms.push(MatchArm { pattern, body });
+            arms.push(MatchArm { pattern, guard, body });
 
             if self.check(&Token::Comma) {
                 self.advance();
@@ -877,6 +1086,69 @@ impl Parser {
         Ok(AstNode::MatchExpr { expr, arms })
     }
 
+    /// Parse a match arm pattern
+    ///
+    /// Supports `_`, identifiers, integer literals, integer ranges
+    /// (`1..5`, `1..=5`) and as-patterns (`x @ pattern`).
+    fn parse_pattern(&mut self) -> Result<Pattern, ParseError> {
+        if self.check(&Token::Underscore) {
+            self.advance();
+            Ok(Pattern::Wildcard)
+        } else if let Some(Token::Integer(n)) = self.current() {
+            let n = *n;
+            self.advance();
+            let inclusive = self.check(&Token::DotDotEqual);
+            if !inclusive && !self.check(&Token::DotDot) {
+                return Ok(Pattern::Literal(AstNode::IntegerLiteral(n)));
+            }
+            self.advance(); // consume .. or ..=
+            let end = match self.current() {
+                Some(Token::Integer(end)) => *end,
+                _ => return Err(self.unexpected_token("integer range end".to_string())),
+            };
+            self.advance();
+            Ok(Pattern::Range {
+                start: AstNode::IntegerLiteral(n),
+                end: AstNode::IntegerLiteral(end),
+                inclusive,
+            })
+        } else if let Some(Token::Float(f)) = self.current() {
+            let f = *f;
+            self.advance();
+            Ok(Pattern::Literal(AstNode::FloatLiteral(f)))
+        } else if let Some(Token::Identifier(id)) = self.current() {
+            let id = id.clone();
+            self.advance();
+            if self.check(&Token::ColonColon) {
+                // Path pattern: Status::Err
+                let mut segments = vec![id];
+                while self.check(&Token::ColonColon) {
+                    self.advance();
+                    segments.push(self.expect_identifier());
+                }
+                return Ok(Pattern::Literal(AstNode::PathExpr { segments }));
+            }
+            if self.check(&Token::At) {
+                self.advance();
+                let pattern = Box::new(self.parse_pattern()?);
+                return Ok(Pattern::Binding { name: id, pattern });
+            }
+            if self.check(&Token::LeftParen) {
+                // Data variant pattern: Some(x), Err(e)
+                self.advance();
+                let payload = Box::new(self.parse_pattern()?);
+                self.consume(&Token::RightParen)?;
+                return Ok(Pattern::Variant { name: id, payload: Some(payload) });
+            }
+            if id == "None" {
+                return Ok(Pattern::Variant { name: id, payload: None });
+            }
+            Ok(Pattern::Identifier(id))
+        } else {
+            Ok(Pattern::Wildcard)
+        }
+    }
+
     /// Parse return statement
     fn parse_return(&mut self) -> Result<AstNode, ParseError> {
         self.consume(&Token::Return)?;
@@ -894,79 +1166,48 @@ impl Parser {
         Ok(AstNode::Return { value })
     }
 
-    /// Parse expression
-    fn parse_expression(&mut self) -> Result<AstNode, ParseError> {
-        self.parse_comparison()
-    }
-
-    /// Parse comparison
-    fn parse_comparison(&mut self) -> Result<AstNode, ParseError> {
-        let mut left = self.parse_term()?;
-
-        while let Some(token) = self.current() {
-            let op = match token {
-                Token::EqualEqual => BinaryOperator::Equal,
-                Token::NotEqual => BinaryOperator::NotEqual,
-                Token::LessThan => BinaryOperator::LessThan,
-                Token::GreaterThan => BinaryOperator::GreaterThan,
-                Token::LessEqual => BinaryOperator::LessEqual,
-                Token::GreaterEqual => BinaryOperator::GreaterEqual,
-                Token::AndAnd => BinaryOperator::And,
-                Token::OrOr => BinaryOperator::Or,
-                _ => break,
+    /// Parse `break`, `break value` or `continue` (labels are not supported)
+    fn parse_loop_jump(&mut self) -> Result<AstNode, ParseError> {
+        let node = if self.check(&Token::Break) {
+            self.advance();
+            let value = if !self.check(&Token::Semicolon) && !self.check(&Token::RightBrace) && !self.is_at_end() {
+                Some(Box::new(self.parse_expression()?))
+            } else {
+                None
             };
-
+            AstNode::Break { value }
+        } else {
             self.advance();
-            let right = self.parse_term()?;
+            AstNode::Continue
+        };
 
-            left = AstNode::BinaryOp {
-                op,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+        if self.check(&Token::Semicolon) {
+            self.advance();
         }
 
-        Ok(left)
+        Ok(node)
     }
 
-    /// Parse term (+ -)
-    fn parse_term(&mut self) -> Result<AstNode, ParseError> {
-        let mut left = self.parse_factor()?;
-
-        while let Some(token) = self.current() {
-            let op = match token {
-                Token::Plus => BinaryOperator::Add,
-                Token::Minus => BinaryOperator::Subtract,
-                _ => break,
-            };
-
-            self.advance();
-            let right = self.parse_factor()?;
-
-            left = AstNode::BinaryOp {
-                op,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
-        }
-
-        Ok(left)
+    /// Parse expression
+    fn parse_expression(&mut self) -> Result<AstNode, ParseError> {
+        self.parse_binary(0)
     }
 
-    /// Parse factor (* / %)
-    fn parse_factor(&mut self) -> Result<AstNode, ParseError> {
+    /// Parse binary operators binding at least as tightly as `min_precedence`
+    /// (see `BinaryOperator::precedence`)
+    ///
+    /// Precedence climbing: every level is left-associative, and an operand
+    /// without operators costs one call instead of one per level.
+    fn parse_binary(&mut self, min_precedence: u8) -> Result<AstNode, ParseError> {
         let mut left = self.parse_cast()?;
 
-        while let Some(token) = self.current() {
-            let op = match token {
-                Token::Star => BinaryOperator::Multiply,
-                Token::Slash => BinaryOperator::Divide,
-                Token::Percent => BinaryOperator::Modulo,
-                _ => break,
-            };
-
+        while let Some(op) = self.current().and_then(binary_operator) {
+            let precedence = op.precedence();
+            if precedence < min_precedence {
+                break;
+            }
             self.advance();
-            let right = self.parse_cast()?;
+            let right = self.parse_binary(precedence + 1)?;
 
             left = AstNode::BinaryOp {
                 op,
@@ -995,13 +1236,15 @@ impl Parser {
             };
         }
 
-        // Check for range: expr..expr
-        if self.check(&Token::DotDot) {
-            self.advance(); // consume '..'
+        // Check for range: expr..expr or expr..=expr
+        let inclusive = self.check(&Token::DotDotEqual);
+        if inclusive || self.check(&Token::DotDot) {
+            self.advance(); // consume '..' or '..='
             let end = Box::new(self.parse_primary()?);
             expr = AstNode::Range {
                 start: Box::new(expr),
                 end,
+                inclusive,
             };
         }
 
@@ -1103,7 +1346,16 @@ impl Parser {
             }
             Some(Token::LeftBracket) => self.parse_vector_literal(),
             Some(Token::LeftBrace) => self.parse_brace_expr(),
+            // Loops in expression position (e.g. `let r = while ..`) evaluate to nil
+            Some(Token::While) => self.parse_while(),
+            Some(Token::For) => self.parse_for(),
+            Some(Token::Loop) => self.parse_loop(),
             Some(Token::LeftParen) => self.parse_paren_expr(),
+            Some(Token::Ampersand) => {
+                // &x and &mut x evaluate to x (references are plain values)
+                self.skip_reference();
+                self.parse_primary_base()
+            }
             Some(Token::Minus) => {
                 self.advance();
                 let operand = Box::new(self.parse_primary()?);
@@ -1131,13 +1383,7 @@ impl Parser {
             Some(Token::OrOr) => self.parse_closure_no_params(),
             Some(Token::Pipe) | Some(Token::Move) => self.parse_closure_with_params(),
             _ => {
-                let found = format!("{:?}", self.current());
-                Err(ParseError::UnexpectedToken {
-                    expected: "expression".to_string(),
-                    found,
-                    line: 1,
-                    column: 1,
-                })
+                Err(self.unexpected_token("expression".to_string()))
             }
         }
     }
@@ -1257,9 +1503,7 @@ impl Parser {
                     let value = self.parse_expression()?;
                     fields.push((field_name, value));
                 }
-                if self.check(&Token::Comma) {
-                    self.advance();
-                }
+                self.list_separator(&Token::RightBrace, "struct literal field")?;
             }
             self.consume(&Token::RightBrace)?;
             Ok(AstNode::StructLiteral { name: id, fields })
@@ -1295,8 +1539,12 @@ impl Parser {
                 | Some(Token::If)
                 | Some(Token::While)
                 | Some(Token::For)
+                | Some(Token::Repeat)
+                | Some(Token::Loop)
                 | Some(Token::Match)
                 | Some(Token::Return)
+                | Some(Token::Break)
+                | Some(Token::Continue)
         );
 
         if is_block {
@@ -1355,13 +1603,7 @@ impl Parser {
 
     fn parse_closure_no_params(&mut self) -> Result<AstNode, ParseError> {
         self.advance(); // consume ||
-
-        self.consume(&Token::LeftBrace)?;
-        let mut body = Vec::new();
-        while !self.check(&Token::RightBrace) && !self.is_at_end() {
-            body.push(self.parse_statement()?);
-        }
-        self.consume(&Token::RightBrace)?;
+        let body = self.parse_closure_body()?;
 
         Ok(AstNode::Closure {
             is_move: false,
@@ -1375,12 +1617,7 @@ impl Parser {
             self.advance();
             if self.check(&Token::OrOr) {
                 self.advance();
-                self.consume(&Token::LeftBrace)?;
-                let mut body = Vec::new();
-                while !self.check(&Token::RightBrace) && !self.is_at_end() {
-                    body.push(self.parse_statement()?);
-                }
-                self.consume(&Token::RightBrace)?;
+                let body = self.parse_closure_body()?;
                 return Ok(AstNode::Closure {
                     is_move: true,
                     params: Vec::new(),
@@ -1395,19 +1632,12 @@ impl Parser {
         self.consume(&Token::Pipe)?;
         let mut params = Vec::new();
         while !self.check(&Token::Pipe) && !self.is_at_end() {
-            params.push(self.expect_identifier());
-            if self.check(&Token::Comma) {
-                self.advance();
-            }
+            params.push(self.expect_binding());
+            self.list_separator(&Token::Pipe, "closure parameter")?;
         }
         self.consume(&Token::Pipe)?;
 
-        self.consume(&Token::LeftBrace)?;
-        let mut body = Vec::new();
-        while !self.check(&Token::RightBrace) && !self.is_at_end() {
-            body.push(self.parse_statement()?);
-        }
-        self.consume(&Token::RightBrace)?;
+        let body = self.parse_closure_body()?;
 
         Ok(AstNode::Closure {
             is_move,
@@ -1416,12 +1646,52 @@ impl Parser {
         })
     }
 
+    /// Parse a closure body: `{ stmts }` or a single expression (`|| 1 + 1`)
+    fn parse_closure_body(&mut self) -> Result<Vec<AstNode>, ParseError> {
+        if !self.check(&Token::LeftBrace) {
+            return Ok(vec![self.parse_expression()?]);
+        }
+        self.consume(&Token::LeftBrace)?;
+        let mut body = Vec::new();
+        while !self.check(&Token::RightBrace) && !self.is_at_end() {
+            body.push(self.parse_statement()?);
+        }
+        self.consume(&Token::RightBrace)?;
+        Ok(body)
+    }
+
     // Helper methods
 
     fn current(&self) -> Option<&Token> {
         self.tokens.get(self.pos)
     }
 
+    /// Source position of the current token
+    fn current_span(&self) -> Span {
+        self.spans.get(self.pos).copied().unwrap_or_default()
+    }
+
+    /// Error for a current token that does not fit, located at that token
+    fn unexpected_token(&self, expected: String) -> ParseError {
+        let span = self.current_span();
+        ParseError::UnexpectedToken {
+            expected,
+            found: format!("{:?}", self.current()),
+            line: span.line,
+            column: span.column,
+        }
+    }
+
+    /// Syntax error located at the current token
+    fn invalid_syntax(&self, message: &str) -> ParseError {
+        let span = self.current_span();
+        ParseError::InvalidSyntax {
+            message: message.to_string(),
+            line: span.line,
+            column: span.column,
+        }
+    }
+
     fn advance(&mut self) {
         if !self.is_at_end() {
             self.pos += 1;
@@ -1441,12 +1711,7 @@ impl Parser {
             self.advance();
             Ok(())
         } else {
-            Err(ParseError::UnexpectedToken {
-                expected: format!("{:?}", token),
-                found: format!("{:?}", self.current()),
-                line: 0,
-                column: 0,
-            })
+            Err(self.unexpected_token(format!("{:?}", token)))
         }
     }
 
@@ -1464,6 +1729,42 @@ impl Parser {
         }
     }
 
+    /// Consume the `,` after a list item, or stop before `close`
+    ///
+    /// Any other token is an error, so a list loop always makes progress.
+    fn list_separator(&mut self, close: &Token, item: &str) -> Result<(), ParseError> {
+        if self.check(&Token::Comma) {
+            self.advance();
+            Ok(())
+        } else if self.check(close) || self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.invalid_syntax(&format!("Expected ',' after {}", item)))
+        }
+    }
+
+    /// Skip a `&` or `&mut` reference prefix: references are plain values
+    fn skip_reference(&mut self) {
+        if self.check(&Token::Ampersand) {
+            self.advance();
+            if self.check(&Token::Mut) {
+                self.advance();
+            }
+        }
+    }
+
+    /// Extract a binding name (an identifier or `_`) and advance
+    ///
+    /// `_` is kept as the name "_", which the scope never binds.
+    fn expect_binding(&mut self) -> String {
+        if self.check(&Token::Underscore) {
+            self.advance();
+            "_".to_string()
+        } else {
+            self.expect_identifier()
+        }
+    }
+
     // DEBUGGER-050: Token debugging methods (GREEN Phase Priority 1)
 
     /// Get the internal token list for debugging
@@ -1476,6 +1777,21 @@ impl Parser {
         Ok(self.tokens.iter().map(|t| format!("{:?}", t)).collect())
     }
 
+    /// Get the token list paired with each token's source position
+    ///
+    /// DEBUGGER-050: Lets parser traces point at lines and columns
+    pub fn debug_get_token_spans(&mut self) -> Result<Vec<(String, Span)>, ParseError> {
+        if self.tokens.is_empty() {
+            self.tokenize()?;
+        }
+        Ok(self
+            .tokens
+            .iter()
+            .zip(&self.spans)
+            .map(|(t, span)| (format!("{:?}", t), *span))
+            .collect())
+    }
+
     /// Check if a token at the given position looks like an error recovery token
     ///
     /// DEBUGGER-050: Error detection (no Bang token in this parser, return false)
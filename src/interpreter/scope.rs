@@ -16,9 +16,20 @@
 // Uses interior mutability (RefCell) wrapped in reference counting (Rc) to allow
 // multiple scopes to share parent scope references while maintaining mutability.
 // This enables assignment to update variables in parent scopes when not shadowed.
+//
+// Resolution Cache (INTERP-087):
+// Loops push a fresh child scope per iteration, so the scope enclosing a loop
+// sees the same outer-variable lookups over and over. Once a scope has spawned
+// several children it starts remembering which ancestor frame resolved each name
+// looked up through it, so later iterations skip the rest of the parent-chain
+// walk. Short-lived scopes never allocate a cache. Local variables are always
+// checked first, so a new local binding shadows any cached entry, and a pushed
+// child starts without a cache that is dropped again on pop. This relies on
+// ancestors not gaining new bindings while a child is active, which holds because
+// the evaluator only defines into the current scope.
 
 use crate::interpreter::value::Value;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::rc::Rc;
@@ -27,6 +38,10 @@ use std::rc::Rc;
 type Variables = Rc<RefCell<HashMap<String, Value>>>;
 type ParentScope = Option<Rc<RefCell<Scope>>>;
 type ReferencedVars = Rc<RefCell<HashSet<String>>>;
+type ResolutionCache = RefCell<Option<Rc<RefCell<HashMap<String, Variables>>>>>;
+
+/// Number of child scopes a scope spawns before it starts caching resolutions
+const CACHE_AFTER_CHILDREN: usize = 8;
 
 /// Scope represents a lexical scope with variable bindings
 ///
@@ -44,6 +59,11 @@ pub struct Scope {
     depth: usize,
     /// Variables referenced in this scope (for closure capture)
     referenced: ReferencedVars,
+    /// Ancestor frame that resolved each name looked up by child scopes
+    /// (allocated once enough children were spawned, shared with shallow clones)
+    resolved: ResolutionCache,
+    /// Number of child scopes created from this scope
+    children: Cell<usize>,
 }
 
 /// Scope-related errors
@@ -86,16 +106,29 @@ impl Scope {
             parent: None,
             depth: 0,
             referenced: Rc::new(RefCell::new(HashSet::new())),
+            resolved: RefCell::new(None),
+            children: Cell::new(0),
         }
     }
 
     /// Create a child scope
     pub fn create_child(&self) -> Self {
+        // Scopes that keep spawning children (loop headers) share one
+        // resolution cache with every parent copy
+        let spawned = self.children.get() + 1;
+        self.children.set(spawned);
+        if spawned >= CACHE_AFTER_CHILDREN {
+            self.resolved
+                .borrow_mut()
+                .get_or_insert_with(|| Rc::new(RefCell::new(HashMap::new())));
+        }
         Scope {
             variables: Rc::new(RefCell::new(HashMap::new())),
             parent: Some(Rc::new(RefCell::new(self.clone()))),
             depth: self.depth + 1,
             referenced: Rc::new(RefCell::new(HashSet::new())),
+            resolved: RefCell::new(None),
+            children: Cell::new(0),
         }
     }
 
@@ -127,6 +160,9 @@ impl Scope {
             parent: new_parent,
             depth: self.depth,
             referenced: new_referenced,
+            // Cached frames point into the original tree, so start fresh
+            resolved: RefCell::new(None),
+            children: Cell::new(0),
         }
     }
 
@@ -145,7 +181,13 @@ impl Scope {
     // ===== Variable Operations =====
 
     /// Define a new variable in this scope
+    ///
+    /// Defining the throwaway name `_` discards the value, so `let _ = f()`
+    /// and repeated `_` parameters bind nothing.
     pub fn define(&mut self, name: String, value: Value) -> Result<(), ScopeError> {
+        if name == "_" {
+            return Ok(());
+        }
         let mut vars = self.variables.borrow_mut();
         if vars.contains_key(&name) {
             return Err(ScopeError::AlreadyDefined { name });
@@ -175,13 +217,15 @@ impl Scope {
             return Ok(());
         }
 
-        // Search parent scopes recursively
-        if let Some(parent_rc) = &self.parent {
-            parent_rc.borrow_mut().assign(name, value)
-        } else {
-            Err(ScopeError::Undefined {
+        // Update the ancestor frame that defines the variable
+        match self.resolve_in_parents(name) {
+            Some(frame) => {
+                frame.borrow_mut().insert(name.to_string(), value);
+                Ok(())
+            }
+            None => Err(ScopeError::Undefined {
                 name: name.to_string(),
-            })
+            }),
         }
     }
 
@@ -206,14 +250,61 @@ impl Scope {
             return Ok(value.clone());
         }
 
-        // Recursively search parent scopes
-        if let Some(parent_rc) = &self.parent {
-            parent_rc.borrow().get_cloned(name)
-        } else {
-            Err(ScopeError::Undefined {
+        // Search parent scopes (cached in loop headers after the first lookup)
+        self.parent
+            .as_ref()
+            .and_then(|parent| parent.borrow().get_for_child(name))
+            .ok_or_else(|| ScopeError::Undefined {
                 name: name.to_string(),
             })
+    }
+
+    /// Look up `name` on behalf of a child scope
+    fn get_for_child(&self, name: &str) -> Option<Value> {
+        if let Some(value) = self.variables.borrow().get(name) {
+            return Some(value.clone());
+        }
+
+        let cache = self.resolved.borrow();
+        let Some(cache) = cache.as_ref() else {
+            return self.parent.as_ref()?.borrow().get_for_child(name);
+        };
+        if let Some(frame) = cache.borrow().get(name) {
+            return frame.borrow().get(name).cloned();
+        }
+
+        let frame = self.resolve_in_parents(name)?;
+        let value = frame.borrow().get(name).cloned();
+        cache.borrow_mut().insert(name.to_string(), frame);
+        value
+    }
+
+    /// Find the ancestor frame defining `name`
+    fn resolve_in_parents(&self, name: &str) -> Option<Variables> {
+        self.parent.as_ref()?.borrow().resolve_for_child(name)
+    }
+
+    /// Find the frame defining `name` on behalf of a child scope
+    ///
+    /// Frames found above this scope are cached here, so later lookups from
+    /// this scope's children stop at this level.
+    fn resolve_for_child(&self, name: &str) -> Option<Variables> {
+        if self.variables.borrow().contains_key(name) {
+            return Some(Rc::clone(&self.variables));
         }
+
+        let cache = self.resolved.borrow().clone();
+        if let Some(frame) = cache.as_ref().and_then(|c| c.borrow().get(name).cloned()) {
+            return Some(frame);
+        }
+
+        let frame = self.resolve_in_parents(name)?;
+        if let Some(cache) = cache {
+            cache
+                .borrow_mut()
+                .insert(name.to_string(), Rc::clone(&frame));
+        }
+        Some(frame)
     }
 
     // ===== Scope Introspection =====
@@ -228,6 +319,17 @@ impl Scope {
         self.variables.borrow().keys().cloned().collect()
     }
 
+    /// Get all variables visible from this scope, sorted by name
+    ///
+    /// Inner bindings shadow outer ones, as in lookups.
+    pub fn visible_variables(&self) -> Vec<(String, Value)> {
+        let mut visible = HashMap::new();
+        self.capture_all_from_parent(&mut visible);
+        let mut variables: Vec<(String, Value)> = visible.into_iter().collect();
+        variables.sort_by(|a, b| a.0.cmp(&b.0));
+        variables
+    }
+
     // ===== Closure Support =====
 
     /// Mark a variable as referenced (for closure capture)
@@ -291,4 +393,37 @@ mod tests {
         assert!(!child.is_global());
         assert_eq!(child.depth(), 1);
     }
+
+    #[test]
+    fn test_cached_lookup_sees_assignments() {
+        let mut global = Scope::new();
+        global.define("x".to_string(), Value::integer(0)).unwrap();
+        let header = global.create_child();
+
+        // Enough iterations for the loop header to start caching
+        for i in 1..=(CACHE_AFTER_CHILDREN as i64 * 2) {
+            let mut body = header.create_child();
+            let x = body.get_cloned("x").unwrap();
+            assert_eq!(x, Value::integer(i - 1));
+            body.assign("x", Value::integer(i)).unwrap();
+        }
+        assert!(header.resolved.borrow().is_some());
+        let expected = Value::integer(CACHE_AFTER_CHILDREN as i64 * 2);
+        assert_eq!(global.get_cloned("x").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_local_define_shadows_cached_lookup() {
+        let mut global = Scope::new();
+        global.define("x".to_string(), Value::integer(1)).unwrap();
+        let header = global.create_child();
+        for _ in 0..CACHE_AFTER_CHILDREN {
+            let body = header.create_child();
+            assert_eq!(body.get_cloned("x").unwrap(), Value::integer(1));
+        }
+
+        let mut body = header.create_child();
+        body.define("x".to_string(), Value::integer(5)).unwrap();
+        assert_eq!(body.get_cloned("x").unwrap(), Value::integer(5));
+    }
 }
@@ -0,0 +1,182 @@
+// INTERP-093: Tail-Position Call Detection
+//
+// Static analysis that finds which calls in a function are in tail position,
+// i.e. the call's result is returned directly with no work left to do. These
+// are the recursions a TCO pass could turn into loops, which makes this useful
+// for teaching even though the evaluator itself does not optimize them.
+//
+// Tail position flows through:
+// - The last statement of the function body
+// - The value of a `return` statement (anywhere in the body)
+// - The last statement of each `if`/`else` branch, `match` arm, and block
+//
+// It does NOT flow through operators or arguments: in `1 + f(n - 1)` the
+// addition still runs after the call returns. Loop bodies are never tail
+// position (another iteration may follow), but `return` inside a loop is.
+
+use std::fmt;
+
+use super::ast::{Ast, AstNode};
+use super::visitor::{walk_node, Visitor};
+
+/// A call found in tail position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TailCall {
+    /// Function containing the call
+    pub caller: String,
+    /// Function being called
+    pub callee: String,
+    /// Line of the enclosing statement (requires `Parser::with_spans`)
+    pub line: Option<usize>,
+}
+
+impl TailCall {
+    /// Whether the call is the caller calling itself
+    pub fn is_recursive(&self) -> bool {
+        self.caller == self.callee
+    }
+}
+
+impl fmt::Display for TailCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "info: call to '{}' in '{}'", self.callee, self.caller)?;
+        if let Some(line) = self.line {
+            write!(f, " at line {}", line)?;
+        }
+        write!(f, " is in tail position")?;
+        if self.is_recursive() {
+            write!(f, " (tail recursion, could be optimized into a loop)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the calls in tail position inside a function definition
+///
+/// Returns an empty list for nodes that are not `FunctionDef` (a `Spanned`
+/// wrapper around one is accepted).
+///
+/// # Example
+/// ```
+/// use ruchyruchy::interpreter::parser::Parser;
+/// use ruchyruchy::interpreter::tail_calls::find_tail_calls;
+///
+/// let ast = Parser::new("fun f(n) { if n == 0 { 0 } else { f(n - 1) } }")
+///     .parse()
+///     .unwrap();
+/// let calls = find_tail_calls(&ast.nodes()[0]);
+///
+/// assert_eq!(calls.len(), 1);
+/// assert!(calls[0].is_recursive());
+/// ```
+pub fn find_tail_calls(function: &AstNode) -> Vec<TailCall> {
+    let mut calls = Vec::new();
+    match function {
+        AstNode::FunctionDef { name, body, .. } => {
+            let mut finder = TailCallFinder {
+                caller: name,
+                calls: &mut calls,
+            };
+            finder.body(body, true, None);
+        }
+        AstNode::Spanned { node, .. } => return find_tail_calls(node),
+        _ => {}
+    }
+    calls
+}
+
+/// Find the tail calls of every top-level function (and impl method) in a program
+pub fn find_all_tail_calls(ast: &Ast) -> Vec<TailCall> {
+    let mut calls = Vec::new();
+    for node in ast.nodes() {
+        match node {
+            AstNode::ImplBlock { methods, .. } => {
+                calls.extend(methods.iter().flat_map(find_tail_calls));
+            }
+            other => calls.extend(find_tail_calls(other)),
+        }
+    }
+    calls
+}
+
+/// Walks one function body, recording calls in tail position
+struct TailCallFinder<'a> {
+    caller: &'a str,
+    calls: &'a mut Vec<TailCall>,
+}
+
+impl TailCallFinder<'_> {
+    /// Visit a statement list; only the last statement inherits `tail`
+    fn body(&mut self, statements: &[AstNode], tail: bool, line: Option<usize>) {
+        let last = statements.len().saturating_sub(1);
+        for (i, statement) in statements.iter().enumerate() {
+            self.node(statement, tail && i == last, line);
+        }
+    }
+
+    /// Visit a statement or expression
+    ///
+    /// Nodes in non-tail position are still visited when they can contain
+    /// a `return`, since a returned call is always in tail position.
+    fn node(&mut self, node: &AstNode, tail: bool, line: Option<usize>) {
+        match node {
+            AstNode::Spanned { span, node } => self.node(node, tail, Some(span.line)),
+            AstNode::Return { value: Some(value) } => self.node(value, true, line),
+            AstNode::FunctionCall { name, args } if tail => {
+                self.calls.push(TailCall {
+                    caller: self.caller.to_string(),
+                    callee: name.clone(),
+                    line,
+                });
+                self.body(args, false, line);
+            }
+            AstNode::IfExpr {
+                condition: head,
+                then_branch,
+                else_branch,
+            }
+            | AstNode::IfLet {
+                scrutinee: head,
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                self.node(head, false, line);
+                self.body(then_branch, tail, line);
+                if let Some(else_branch) = else_branch {
+                    self.body(else_branch, tail, line);
+                }
+            }
+            AstNode::MatchExpr { expr, arms } => {
+                self.node(expr, false, line);
+                for arm in arms {
+                    if let Some(guard) = &arm.guard {
+                        self.node(guard, false, line);
+                    }
+                    self.body(&arm.body, tail, line);
+                }
+            }
+            AstNode::Block { statements } => self.body(statements, tail, line),
+            _ => walk_node(&mut Children { finder: self, line }, node),
+        }
+    }
+}
+
+/// Visits the children of a node, none of which are in tail position
+///
+/// Nested functions and closures are skipped: a `return` inside them
+/// leaves the inner function, not the one being analyzed.
+struct Children<'f, 'a> {
+    finder: &'f mut TailCallFinder<'a>,
+    line: Option<usize>,
+}
+
+impl Visitor for Children<'_, '_> {
+    fn visit_node(&mut self, node: &AstNode) {
+        self.finder.node(node, false, self.line);
+    }
+
+    fn visit_function_def(&mut self, _name: &str, _params: &[String], _body: &[AstNode]) {}
+
+    fn visit_closure(&mut self, _params: &[String], _body: &[AstNode]) {}
+}
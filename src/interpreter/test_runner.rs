@@ -0,0 +1,105 @@
+// INTERP-115: Test Function Discovery and Execution
+//
+// `#[test] fun name() { ... }` declares a test. Normal evaluation skips test
+// functions; `run_tests` evaluates the rest of the program once (so tests can
+// call its functions), then runs each test as a zero-argument function in its
+// own copy of that evaluator, so one test cannot affect the next. Like any
+// function, a test body does not see top-level `let` bindings.
+//
+// A test passes when its body finishes without an error. Any error (failed
+// assert, panic, runtime error) fails the test and is reported as its message.
+// Program output during the run is captured rather than printed.
+
+use super::ast::AstNode;
+use super::evaluator::Evaluator;
+use super::output::OutputSink;
+use super::parser::{ParseError, Parser};
+
+/// Outcome of one `#[test]` function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    /// Test function name
+    pub name: String,
+    /// Whether the test body completed without error
+    pub passed: bool,
+    /// Error message for a failing test
+    pub failure: Option<String>,
+}
+
+/// Discover and run every `#[test]` function in a program, in source order
+///
+/// Returns a parse error if the program (or a test declaration) is invalid.
+/// A setup error in the non-test part of the program fails every test.
+///
+/// # Example
+/// ```
+/// use ruchyruchy::interpreter::run_tests;
+///
+/// let source = "#[test]\nfun ok() { assert_eq(1 + 1, 2) }\n#[test]\nfun bad() { assert(false) }";
+/// let results = run_tests(source).unwrap();
+///
+/// assert!(results[0].passed);
+/// assert!(!results[1].passed);
+/// ```
+pub fn run_tests(source: &str) -> Result<Vec<TestResult>, ParseError> {
+    let ast = Parser::new(source).with_spans().parse()?;
+
+    let mut tests = Vec::new();
+    let mut program = Vec::new();
+    for node in ast.nodes() {
+        match unspanned(node) {
+            AstNode::TestDef { name, body } => tests.push((name, body)),
+            _ => program.push(node.clone()),
+        }
+    }
+
+    let mut setup = Evaluator::new().with_output(OutputSink::buffer());
+    let setup_error = program
+        .iter()
+        .try_for_each(|node| setup.eval(node).map(drop))
+        .err()
+        .map(|e| format!("program setup failed: {}", e));
+
+    Ok(tests
+        .into_iter()
+        .map(|(name, body)| {
+            let outcome = match &setup_error {
+                Some(message) => Err(message.clone()),
+                None => run_test(&setup, name, body),
+            };
+            TestResult {
+                name: name.clone(),
+                passed: outcome.is_ok(),
+                failure: outcome.err(),
+            }
+        })
+        .collect())
+}
+
+/// Run one test body as a zero-argument function in a copy of `setup`
+fn run_test(setup: &Evaluator, name: &str, body: &[AstNode]) -> Result<(), String> {
+    let mut eval = setup.deep_clone();
+    let def = AstNode::FunctionDef {
+        name: name.to_string(),
+        params: Vec::new(),
+        param_types: Vec::new(),
+        return_type: None,
+        body: body.to_vec(),
+    };
+    let call = AstNode::FunctionCall {
+        name: name.to_string(),
+        args: Vec::new(),
+    };
+    eval.eval(&def)
+        .and_then(|_| eval.eval(&call))
+        .map(drop)
+        .map_err(|e| e.to_string())
+}
+
+/// Strip the span wrapper `Parser::with_spans` puts around statements
+fn unspanned(node: &AstNode) -> &AstNode {
+    match node {
+        AstNode::Spanned { node, .. } => unspanned(node),
+        other => other,
+    }
+}
@@ -0,0 +1,758 @@
+// Tokenizer for the Ruchy parser: source characters to tokens with spans.
+//
+// Contains: Token, the literal/escape/comment scanners, lex() for editor
+// highlighting and needs_more_input() for REPL continuation.
+//
+// Extracted from parser.rs for file-health compliance (<2000 lines).
+
+use super::ast::{ParseError, Span};
+
+/// Character cursor that tracks the line/column of the next character
+///
+/// Walks the source by byte offset, decoding only non-ASCII characters,
+/// so identifiers can be sliced out of the source instead of rebuilt.
+#[derive(Clone)]
+struct SourceCursor<'a> {
+    source: &'a str,
+    /// Byte offset of the next character
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> SourceCursor<'a> {
+    fn new(source: &'a str) -> Self {
+        SourceCursor {
+            source,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Character starting at byte `offset`
+    fn char_at(&self, offset: usize) -> Option<char> {
+        let bytes = self.source.as_bytes();
+        if offset >= bytes.len() {
+            None
+        } else if bytes[offset] < 0x80 {
+            Some(bytes[offset] as char)
+        } else {
+            self.source[offset..].chars().next()
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.char_at(self.offset)
+    }
+
+    /// The character after the next one
+    fn peek_second(&self) -> Option<char> {
+        let next = self.peek()?;
+        self.char_at(self.offset + next.len_utf8())
+    }
+
+    /// Source text from byte `start` up to the next character
+    fn slice_from(&self, start: usize) -> &'a str {
+        &self.source[start..self.offset]
+    }
+
+    /// Skip spaces, tabs and line breaks
+    fn skip_whitespace(&mut self) {
+        let bytes = self.source.as_bytes();
+        while let Some(&byte) = bytes.get(self.offset) {
+            match byte {
+                b'\n' => {
+                    self.line += 1;
+                    self.column = 1;
+                }
+                b' ' | b'\t' | b'\r' => self.column += 1,
+                _ => break,
+            }
+            self.offset += 1;
+        }
+    }
+
+    /// Skip identifier characters (alphanumeric or `_`)
+    fn skip_identifier(&mut self) {
+        let bytes = self.source.as_bytes();
+        while let Some(&byte) = bytes.get(self.offset) {
+            match byte {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' => {
+                    self.offset += 1;
+                    self.column += 1;
+                }
+                0x80.. if self.peek().is_some_and(char::is_alphanumeric) => {
+                    self.next();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Position of the next character
+    fn span(&self) -> Span {
+        Span {
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+impl Iterator for SourceCursor<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.offset += if ch < '\u{80}' { 1 } else { ch.len_utf8() };
+        if ch == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(ch)
+    }
+}
+
+/// Token types
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Token {
+    // Keywords
+    Fun,
+    Let,
+    If,
+    Else,
+    While,
+    For,
+    In,
+    Match,
+    Return,
+    Struct,
+    Enum,
+    Impl,
+    As,
+    Mut,
+    Use,
+    Move,
+    Repeat,
+    Until,
+    Loop,
+    Break,
+    Continue,
+
+    // Identifiers and literals
+    Identifier(String),
+    Integer(i64),
+    Float(f64),
+    StringLit(String),
+    CharLit(char),   // Character literal: 'a'
+    FString(String), // F-string with interpolation: f"text {expr}"
+    True,
+    False,
+
+    // Operators
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Not,
+    EqualEqual,
+    NotEqual,
+    LessThan,
+    GreaterThan,
+    LessEqual,
+    GreaterEqual,
+    AndAnd,
+    OrOr,
+    Pipe,      // | (closure parameters, bitwise or)
+    Ampersand, // & (bitwise and, reference prefix)
+    Caret,     // ^
+    Shl,       // <<
+    Shr,       // >>
+
+    // Delimiters
+    LeftParen,
+    RightParen,
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Comma,
+    Semicolon,
+    Colon,
+    ColonColon,
+    Arrow,
+    FatArrow,
+    Dot,
+    DotDot,
+    DotDotEqual, // ..=
+    At,          // @
+    Hash,        // # (attributes: #[test])
+    Equal,
+    PlusEqual,    // +=
+    MinusEqual,   // -=
+    StarEqual,    // *=
+    SlashEqual,   // /=
+    PercentEqual, // %=
+    Underscore,
+
+    // End of file
+    Eof,
+}
+
+impl Token {
+    /// Whether input ending with this token is an unfinished construct
+    fn expects_continuation(&self) -> bool {
+        use Token::*;
+        matches!(
+            self,
+            Fun | Let | If | Else | While | For | In | Match | Struct | Enum | Impl | As | Mut
+                | Use | Move | Repeat | Until | Loop
+                | Plus | Minus | Star | Slash | Percent | Not
+                | EqualEqual | NotEqual | LessThan | GreaterThan | LessEqual | GreaterEqual
+                | AndAnd | OrOr | Pipe | Ampersand | Caret | Shl | Shr
+                | Comma | Colon | ColonColon | Arrow | FatArrow | Dot | DotDot | DotDotEqual | At
+                | Equal | PlusEqual | MinusEqual | StarEqual | SlashEqual | PercentEqual
+        )
+    }
+}
+
+impl Token {
+    /// Highlighting class of this token
+    fn class(&self) -> TokenClass {
+        use Token::*;
+        match self {
+            Fun | Let | If | Else | While | For | In | Match | Return | Struct | Enum | Impl
+                | As | Mut | Use | Move | Repeat | Until | Loop | Break | Continue
+                | True | False => TokenClass::Keyword,
+            Identifier(_) => TokenClass::Identifier,
+            Integer(_) | Float(_) => TokenClass::Number,
+            StringLit(_) | CharLit(_) | FString(_) => TokenClass::String,
+            Plus | Minus | Star | Slash | Percent | Not
+                | EqualEqual | NotEqual | LessThan | GreaterThan | LessEqual | GreaterEqual
+                | AndAnd | OrOr | Pipe | Ampersand | Caret | Shl | Shr | DotDot | DotDotEqual
+                | Equal | PlusEqual | MinusEqual | StarEqual | SlashEqual | PercentEqual => {
+                TokenClass::Operator
+            }
+            _ => TokenClass::Punctuation,
+        }
+    }
+}
+
+/// Highlighting class of a lexed token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenClass {
+    /// Keyword, including `true` and `false`
+    Keyword,
+    /// Identifier (variable, function, type, field, ...)
+    Identifier,
+    /// Integer or float literal
+    Number,
+    /// String, f-string or character literal
+    String,
+    /// Arithmetic, comparison, logical, bitwise, range or assignment operator
+    Operator,
+    /// Delimiters and separators: parens, braces, `,`, `;`, `::`, `=>`, ...
+    Punctuation,
+}
+
+/// A token's class and where it sits in the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexedToken {
+    /// Highlighting class
+    pub class: TokenClass,
+    /// Position of the first character
+    pub start: Span,
+    /// Position just past the last character
+    pub end: Span,
+}
+
+/// Tokenize `source` with the parser's lexer, keeping each token's extent
+///
+/// Comments and whitespace produce no tokens. Fails like `Parser::parse`
+/// on an unterminated or malformed literal.
+///
+/// # Example
+///
+/// ```
+/// use ruchyruchy::interpreter::parser::{lex, TokenClass};
+///
+/// let tokens = lex("let x = 1;").unwrap();
+/// assert_eq!(tokens[0].class, TokenClass::Keyword);
+/// assert_eq!((tokens[1].start.column, tokens[1].end.column), (5, 6));
+/// ```
+pub fn lex(source: &str) -> Result<Vec<LexedToken>, ParseError> {
+    let mut chars = SourceCursor::new(source);
+    let mut tokens = Vec::new();
+    let mut lexed = Vec::new();
+    while let Some(first) = chars.peek() {
+        let start = chars.span();
+        scan_checked(&mut chars, &mut tokens, first, start)?;
+        let end = chars.span();
+        lexed.extend(tokens.drain(..).map(|token| LexedToken {
+            class: token.class(),
+            start,
+            end,
+        }));
+    }
+    Ok(lexed)
+}
+
+/// Whether a REPL should read another line before parsing `source` (INTERP-108)
+///
+/// True when a `(`, `[`, `{`, string, or f-string is still open, or when the
+/// input ends mid-expression (e.g. after `=`, `+`, or `else`). A complete
+/// statement, or input that is already malformed (like a stray `}`), returns
+/// false so the parser can report it.
+///
+/// # Example
+///
+/// ```
+/// use ruchyruchy::interpreter::parser::needs_more_input;
+///
+/// assert!(needs_more_input("fun f() {"));
+/// assert!(!needs_more_input("fun f() { 1 }"));
+/// ```
+pub fn needs_more_input(source: &str) -> bool {
+    let mut chars = SourceCursor::new(source);
+    let mut tokens = Vec::new();
+    while let Some(first) = chars.peek() {
+        match scan_token(&mut chars, &mut tokens, first) {
+            Ok(true) => {}
+            Ok(false) => return true,
+            Err(_) => return false,
+        }
+    }
+
+    let mut open = Vec::new();
+    for token in &tokens {
+        let closer = match token {
+            Token::LeftParen => Token::RightParen,
+            Token::LeftBrace => Token::RightBrace,
+            Token::LeftBracket => Token::RightBracket,
+            Token::RightParen | Token::RightBrace | Token::RightBracket => {
+                if open.pop().as_ref() != Some(token) {
+                    return false;
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        open.push(closer);
+    }
+    !open.is_empty() || tokens.last().is_some_and(Token::expects_continuation)
+}
+
+/// Tokenize the source code into tokens and the start position of each
+///
+/// Handles whitespace, comments, string literals, numbers, identifiers,
+/// keywords, and operators/delimiters. The last token is `Token::Eof`.
+pub(super) fn tokenize(source: &str) -> Result<(Vec<Token>, Vec<Span>), ParseError> {
+    let mut chars = SourceCursor::new(source);
+    // INTERP-048: Pre-allocate tokens Vec (estimate: 1 token per 4 chars, min 16)
+    let estimated_tokens = (source.len() / 4).max(16);
+    let mut tokens = Vec::with_capacity(estimated_tokens);
+    let mut spans = Vec::with_capacity(estimated_tokens);
+
+    while let Some(first) = chars.peek() {
+        let start = chars.span();
+        scan_checked(&mut chars, &mut tokens, first, start)?;
+        // A token scan pushes at most one token
+        if spans.len() < tokens.len() {
+            spans.push(start);
+        }
+    }
+
+    tokens.push(Token::Eof);
+    spans.push(chars.span());
+    Ok((tokens, spans))
+}
+
+/// Scan the next token, reporting an unterminated or malformed literal
+/// (or block comment) as a parse error at its start
+///
+/// `first` is the next character and `start` its position.
+fn scan_checked(
+    chars: &mut SourceCursor<'_>,
+    tokens: &mut Vec<Token>,
+    first: char,
+    start: Span,
+) -> Result<(), ParseError> {
+    // Only a block comment, string or f-string can be left open
+    let message = match scan_token(chars, tokens, first) {
+        Ok(false) if first == '/' => "unterminated block comment".to_string(),
+        Ok(false) if first == '"' => "unterminated string literal".to_string(),
+        Ok(_) => return Ok(()),
+        Err(message) => message,
+    };
+    Err(ParseError::InvalidSyntax {
+        message,
+        line: start.line,
+        column: start.column,
+    })
+}
+
+/// Scan the next token (or skip whitespace/comments)
+///
+/// `ch` is the next character. Returns false if the input ended inside a
+/// string or f-string literal or a block comment, and an error message for
+/// a malformed literal.
+fn scan_token(
+    chars: &mut SourceCursor<'_>,
+    tokens: &mut Vec<Token>,
+    ch: char,
+) -> Result<bool, String> {
+    match ch {
+        ' ' | '\t' | '\n' | '\r' => chars.skip_whitespace(),
+        '/' if chars.peek_second() == Some('/') => scan_comment(chars),
+        '/' if chars.peek_second() == Some('*') => return Ok(scan_block_comment(chars)),
+        '"' => return scan_string(chars, tokens),
+        '\'' => scan_char(chars, tokens)?,
+        '0'..='9' => scan_number(chars, tokens)?,
+        'f' if chars.peek_second() == Some('"') => return Ok(scan_fstring(chars, tokens)),
+        'a'..='z' | 'A'..='Z' | '_' => scan_identifier(chars, tokens),
+        _ => scan_operator(chars, tokens),
+    }
+    Ok(true)
+}
+
+/// Skip a line comment (// ... \n)
+fn scan_comment(chars: &mut SourceCursor<'_>) {
+    chars.next(); // /
+    chars.next(); // /
+    while let Some(ch) = chars.peek() {
+        chars.next();
+        if ch == '\n' {
+            break;
+        }
+    }
+}
+
+/// Skip a block comment (/* ... */), which may nest, reporting whether it was closed
+fn scan_block_comment(chars: &mut SourceCursor<'_>) -> bool {
+    chars.next(); // /
+    chars.next(); // *
+    let mut depth = 1;
+    while let Some(ch) = chars.next() {
+        match (ch, chars.peek()) {
+            ('/', Some('*')) => { chars.next(); depth += 1; }
+            ('*', Some('/')) => {
+                chars.next();
+                depth -= 1;
+                if depth == 0 {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Scan a double-quoted string literal, reporting whether it was closed
+///
+/// Backslash escapes (`\n`, `\"`, ...) are decoded as in char literals.
+fn scan_string(chars: &mut SourceCursor<'_>, tokens: &mut Vec<Token>) -> Result<bool, String> {
+    chars.next(); // Opening "
+    let mut string = String::new();
+    let mut closed = false;
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => {
+                closed = true;
+                break;
+            }
+            '\\' if chars.peek().is_none() => break,
+            '\\' => string.push(scan_escape(chars)?),
+            _ => string.push(ch),
+        }
+    }
+    tokens.push(Token::StringLit(string));
+    Ok(closed)
+}
+
+/// Scan a character literal: 'a', '!', '\n', '\'', etc.
+fn scan_char(chars: &mut SourceCursor<'_>, tokens: &mut Vec<Token>) -> Result<(), String> {
+    chars.next(); // Opening '
+    let character = match chars.next() {
+        None | Some('\n') => return Err("unterminated character literal".to_string()),
+        Some('\'') => return Err("empty character literal".to_string()),
+        Some('\\') => scan_escape(chars)?,
+        Some(ch) => ch,
+    };
+    if chars.peek() == Some('\'') {
+        chars.next(); // consume closing '
+        tokens.push(Token::CharLit(character));
+        return Ok(());
+    }
+    // Tell 'ab' apart from a quote that is never closed on this line
+    for ch in chars.by_ref() {
+        match ch {
+            '\'' => {
+                return Err("character literal may only contain one character".to_string())
+            }
+            '\n' => break,
+            _ => {}
+        }
+    }
+    Err("unterminated character literal".to_string())
+}
+
+/// Decode the character after a backslash in a char or string literal
+fn scan_escape(chars: &mut SourceCursor<'_>) -> Result<char, String> {
+    match chars.next() {
+        Some('n') => Ok('\n'),
+        Some('t') => Ok('\t'),
+        Some('r') => Ok('\r'),
+        Some('0') => Ok('\0'),
+        Some(ch @ ('\\' | '\'' | '"')) => Ok(ch),
+        Some(ch) => Err(format!("unknown escape sequence '\\{}'", ch)),
+        None => Err("unterminated escape sequence".to_string()),
+    }
+}
+
+/// Scan a numeric literal: a decimal integer or float, or a 0x/0o/0b integer
+///
+/// Underscores separate digits (`1_000_000`) and are ignored.
+fn scan_number(chars: &mut SourceCursor<'_>, tokens: &mut Vec<Token>) -> Result<(), String> {
+    let radix = match chars.peek() {
+        Some('0') => match chars.peek_second() {
+            Some('x' | 'X') => Some(16),
+            Some('o' | 'O') => Some(8),
+            Some('b' | 'B') => Some(2),
+            _ => None,
+        },
+        _ => None,
+    };
+    if let Some(radix) = radix {
+        chars.next(); // consume '0'
+        chars.next(); // consume radix letter
+        return scan_radix_integer(chars, tokens, radix);
+    }
+
+    let mut num = String::new();
+    let mut is_float = false;
+
+    // Parse integer part
+    scan_digits(chars, &mut num);
+
+    // Check for decimal point
+    if chars.peek() == Some('.') {
+        // Look ahead to see if next char is a digit (not a method call like "42.abs()")
+        if let Some(next_ch) = chars.peek_second() {
+            if next_ch.is_ascii_digit() {
+                is_float = true;
+                num.push('.');
+                chars.next(); // consume '.'
+
+                // Parse fractional part
+                scan_digits(chars, &mut num);
+            }
+        }
+    }
+
+    // Create appropriate token
+    if is_float {
+        let f = num.parse::<f64>().map_err(|_| format!("invalid float literal {}", num))?;
+        tokens.push(Token::Float(f));
+    } else {
+        let n = num.parse::<i64>().map_err(|_| format!("integer literal {} is out of range", num))?;
+        tokens.push(Token::Integer(n));
+    }
+    Ok(())
+}
+
+/// Append decimal digits to `num`, skipping `_` separators
+fn scan_digits(chars: &mut SourceCursor<'_>, num: &mut String) {
+    while let Some(ch) = chars.peek() {
+        match ch {
+            '0'..='9' => num.push(ch),
+            '_' => {}
+            _ => break,
+        }
+        chars.next();
+    }
+}
+
+/// Scan the digits of a 0x/0o/0b integer literal after its prefix
+///
+/// The literal runs to the next non-alphanumeric character, so a stray
+/// digit like the 2 in `0b1012` is reported instead of starting a new token.
+fn scan_radix_integer(
+    chars: &mut SourceCursor<'_>,
+    tokens: &mut Vec<Token>,
+    radix: u32,
+) -> Result<(), String> {
+    let mut digits = String::new();
+    while let Some(ch) = chars.peek() {
+        if !ch.is_ascii_alphanumeric() && ch != '_' {
+            break;
+        }
+        if ch != '_' {
+            if !ch.is_digit(radix) {
+                return Err(format!("invalid digit '{}' in base {} literal", ch, radix));
+            }
+            digits.push(ch);
+        }
+        chars.next();
+    }
+    if digits.is_empty() {
+        return Err(format!("missing digits in base {} literal", radix));
+    }
+    let n = i64::from_str_radix(&digits, radix)
+        .map_err(|_| format!("base {} literal {} is out of range", radix, digits))?;
+    tokens.push(Token::Integer(n));
+    Ok(())
+}
+
+/// Scan an f-string: f"text {expr} more"
+fn scan_fstring(chars: &mut SourceCursor<'_>, tokens: &mut Vec<Token>) -> bool {
+    chars.next(); // consume 'f'
+    chars.next(); // consume opening "
+
+    let mut content = String::new();
+    let closed = scan_fstring_content(chars, &mut content);
+    tokens.push(Token::FString(content));
+    closed
+}
+
+/// Copy f-string content up to (and consuming) its closing quote
+///
+/// A quote inside a `{hole}` opens a nested string literal, or a nested
+/// f-string when preceded by `f`, so it does not end the outer f-string.
+/// Returns false if the input ends before the closing quote.
+fn scan_fstring_content(chars: &mut SourceCursor<'_>, content: &mut String) -> bool {
+    let mut depth = 0usize;
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if depth == 0 => return true,
+            '"' => {
+                let nested_fstring = content.ends_with('f');
+                content.push('"');
+                if nested_fstring {
+                    if !scan_fstring_content(chars, content) {
+                        return false;
+                    }
+                } else {
+                    for ch in chars.by_ref() {
+                        if ch == '"' {
+                            break;
+                        }
+                        content.push(ch);
+                    }
+                }
+                content.push('"');
+            }
+            '{' => {
+                depth += 1;
+                content.push(ch);
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                content.push(ch);
+            }
+            _ => content.push(ch),
+        }
+    }
+    false
+}
+
+/// Scan an identifier or keyword
+fn scan_identifier(chars: &mut SourceCursor<'_>, tokens: &mut Vec<Token>) {
+    let start = chars.offset;
+    chars.skip_identifier();
+    let ident = chars.slice_from(start);
+
+    // Byte-string patterns compile to a decision tree rather than one
+    // string comparison per keyword
+    let token = match ident.as_bytes() {
+        b"fun" => Token::Fun,
+        b"let" => Token::Let,
+        b"mut" => Token::Mut,
+        b"if" => Token::If,
+        b"else" => Token::Else,
+        b"while" => Token::While,
+        b"for" => Token::For,
+        b"in" => Token::In,
+        b"match" => Token::Match,
+        b"return" => Token::Return,
+        b"struct" => Token::Struct,
+        b"enum" => Token::Enum,
+        b"impl" => Token::Impl,
+        b"as" => Token::As,
+        b"use" => Token::Use,
+        b"move" => Token::Move,
+        b"repeat" => Token::Repeat,
+        b"until" => Token::Until,
+        b"loop" => Token::Loop,
+        b"break" => Token::Break,
+        b"continue" => Token::Continue,
+        b"true" => Token::True,
+        b"false" => Token::False,
+        b"_" => Token::Underscore,
+        _ => Token::Identifier(ident.to_string()),
+    };
+    tokens.push(token);
+}
+
+/// Scan an operator, delimiter, or skip unknown character
+fn scan_operator(chars: &mut SourceCursor<'_>, tokens: &mut Vec<Token>) {
+    let ch = chars.peek().unwrap();
+    let next = chars.peek_second();
+
+    match (ch, next) {
+        // Three-character operators (check first)
+        ('.', Some('.')) if chars.clone().nth(2) == Some('=') => {
+            chars.next(); chars.next(); chars.next(); tokens.push(Token::DotDotEqual);
+        }
+        // Two-character operators
+        ('+', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::PlusEqual); }
+        ('-', Some('>')) => { chars.next(); chars.next(); tokens.push(Token::Arrow); }
+        ('-', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::MinusEqual); }
+        ('*', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::StarEqual); }
+        ('/', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::SlashEqual); }
+        ('%', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::PercentEqual); }
+        (':', Some(':')) => { chars.next(); chars.next(); tokens.push(Token::ColonColon); }
+        ('.', Some('.')) => { chars.next(); chars.next(); tokens.push(Token::DotDot); }
+        ('=', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::EqualEqual); }
+        ('=', Some('>')) => { chars.next(); chars.next(); tokens.push(Token::FatArrow); }
+        ('!', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::NotEqual); }
+        ('<', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::LessEqual); }
+        ('>', Some('=')) => { chars.next(); chars.next(); tokens.push(Token::GreaterEqual); }
+        ('&', Some('&')) => { chars.next(); chars.next(); tokens.push(Token::AndAnd); }
+        ('|', Some('|')) => { chars.next(); chars.next(); tokens.push(Token::OrOr); }
+        ('<', Some('<')) => { chars.next(); chars.next(); tokens.push(Token::Shl); }
+        ('>', Some('>')) => { chars.next(); chars.next(); tokens.push(Token::Shr); }
+
+        // Single-character operators and delimiters
+        ('+', _) => { chars.next(); tokens.push(Token::Plus); }
+        ('-', _) => { chars.next(); tokens.push(Token::Minus); }
+        ('*', _) => { chars.next(); tokens.push(Token::Star); }
+        ('/', _) => { chars.next(); tokens.push(Token::Slash); }
+        ('%', _) => { chars.next(); tokens.push(Token::Percent); }
+        ('(', _) => { chars.next(); tokens.push(Token::LeftParen); }
+        (')', _) => { chars.next(); tokens.push(Token::RightParen); }
+        ('{', _) => { chars.next(); tokens.push(Token::LeftBrace); }
+        ('}', _) => { chars.next(); tokens.push(Token::RightBrace); }
+        ('[', _) => { chars.next(); tokens.push(Token::LeftBracket); }
+        (']', _) => { chars.next(); tokens.push(Token::RightBracket); }
+        (',', _) => { chars.next(); tokens.push(Token::Comma); }
+        (';', _) => { chars.next(); tokens.push(Token::Semicolon); }
+        (':', _) => { chars.next(); tokens.push(Token::Colon); }
+        ('.', _) => { chars.next(); tokens.push(Token::Dot); }
+        ('=', _) => { chars.next(); tokens.push(Token::Equal); }
+        ('!', _) => { chars.next(); tokens.push(Token::Not); }
+        ('<', _) => { chars.next(); tokens.push(Token::LessThan); }
+        ('>', _) => { chars.next(); tokens.push(Token::GreaterThan); }
+        ('|', _) => { chars.next(); tokens.push(Token::Pipe); }
+        ('&', _) => { chars.next(); tokens.push(Token::Ampersand); }
+        ('^', _) => { chars.next(); tokens.push(Token::Caret); }
+        ('@', _) => { chars.next(); tokens.push(Token::At); }
+        ('#', _) => { chars.next(); tokens.push(Token::Hash); }
+
+        // Unknown character - skip
+        _ => { chars.next(); }
+    }
+}
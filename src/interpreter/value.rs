@@ -7,8 +7,30 @@
 // All values are dynamically typed at runtime with type safety enforced
 // through Result types. Supports 6 value types: Integer, String, Boolean,
 // Vector, HashMap, and Function.
+//
+// Float Semantics (INTERP-090):
+// Float arithmetic follows IEEE 754: `1.0 / 0.0` is Infinity and `0.0 / 0.0`
+// is NaN (integer division by zero is still an error). Comparisons involving
+// NaN are false, except `!=` which is true, so NaN is never `==` to itself
+// and never matches a float literal pattern. `Value::compare` is a total
+// order for sorting: NaN compares equal to NaN and after every number.
+//
+// Hashing (INTERP-094):
+// `Value::stable_hash` is FNV-1a over a type-tagged encoding of the value,
+// with no per-process seed, so the same value hashes the same in every run.
+// It is stable within a version of this crate but NOT cryptographic.
+// Values equal under `==` hash equally (0.0 and -0.0 included, HashMap keys
+// are hashed in sorted order); functions, closures and NaN are unhashable.
+//
+// Number Formatting (INTERP-096):
+// Program output never depends on the system locale. Every display path
+// (Display, println, f-strings, to_string) renders numbers with Rust's core
+// formatting, which never consults LC_NUMERIC: ASCII digits, `.` as the
+// decimal separator, no digit grouping. Expected outputs in the conformance
+// suite therefore match on any machine.
 
 use crate::interpreter::parser::AstNode;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 
@@ -45,6 +67,24 @@ pub enum Value {
     },
     /// Tuple value (ordered collection of heterogeneous values)
     Tuple(Vec<Value>),
+    /// Struct instance (fields keyed by name, tagged with its type)
+    Struct {
+        /// Struct type name
+        name: String,
+        /// Field values
+        fields: HashMap<String, Value>,
+    },
+    /// Enum variant with its integer discriminant
+    EnumVariant {
+        /// Enum type name
+        enum_name: String,
+        /// Variant name
+        variant: String,
+        /// Integer discriminant (explicit or auto-incremented)
+        discriminant: i64,
+        /// Carried value of a data variant: `Some(x)`, `Ok(x)`, `Err(e)` (INTERP-109)
+        payload: Option<Box<Value>>,
+    },
     /// Nil/Unit value (represents absence of value)
     Nil,
 }
@@ -82,6 +122,11 @@ pub enum ValueError {
         /// Error message
         message: String,
     },
+    /// Integer arithmetic result does not fit in an i64 (INTERP-133)
+    IntegerOverflow {
+        /// Operation that overflowed
+        operation: String,
+    },
 }
 
 impl fmt::Display for ValueError {
@@ -110,12 +155,22 @@ impl fmt::Display for ValueError {
             ValueError::InvalidOperation { operation, message } => {
                 write!(f, "Invalid operation {}: {}", operation, message)
             }
+            ValueError::IntegerOverflow { operation } => {
+                write!(f, "Integer overflow in {}", operation)
+            }
         }
     }
 }
 
 impl std::error::Error for ValueError {}
 
+/// Wrap a checked integer result, reporting `None` as an overflow in `operation`
+fn checked(result: Option<i64>, operation: &str) -> Result<Value, ValueError> {
+    result.map(Value::Integer).ok_or_else(|| ValueError::IntegerOverflow {
+        operation: operation.to_string(),
+    })
+}
+
 impl Value {
     // ===== Constructors =====
 
@@ -164,6 +219,41 @@ impl Value {
         Value::Nil
     }
 
+    /// Create `Some(value)` (INTERP-109)
+    pub fn some(value: Value) -> Self {
+        Self::prelude_variant("Option", "Some", 1, Some(value))
+    }
+
+    /// Create `None` (INTERP-109)
+    pub fn none() -> Self {
+        Self::prelude_variant("Option", "None", 0, None)
+    }
+
+    /// Create `Ok(value)` (INTERP-109)
+    pub fn ok(value: Value) -> Self {
+        Self::prelude_variant("Result", "Ok", 0, Some(value))
+    }
+
+    /// Create `Err(error)` (INTERP-109)
+    pub fn err(error: Value) -> Self {
+        Self::prelude_variant("Result", "Err", 1, Some(error))
+    }
+
+    /// Build a variant of the built-in Option/Result enums
+    fn prelude_variant(
+        enum_name: &str,
+        variant: &str,
+        discriminant: i64,
+        payload: Option<Value>,
+    ) -> Self {
+        Value::EnumVariant {
+            enum_name: enum_name.to_string(),
+            variant: variant.to_string(),
+            discriminant,
+            payload: payload.map(Box::new),
+        }
+    }
+
     // ===== Type Checking =====
 
     /// Check if value is an integer
@@ -210,6 +300,8 @@ impl Value {
             Value::Boolean(_) => "Boolean",
             Value::Vector(_) => "Vector",
             Value::Tuple(_) => "Tuple",
+            Value::Struct { name, .. } => name,
+            Value::EnumVariant { .. } => "Enum",
             Value::HashMap(_) => "HashMap",
             Value::Function { .. } => "Function",
             Value::Closure { .. } => "Closure",
@@ -282,7 +374,7 @@ impl Value {
     /// Extract hashmap value (mutable)
     pub fn as_hashmap(&mut self) -> Result<&mut HashMap<String, Value>, ValueError> {
         match self {
-            Value::HashMap(m) => Ok(m),
+            Value::HashMap(m) | Value::Struct { fields: m, .. } => Ok(m),
             _ => Err(ValueError::TypeMismatch {
                 expected: "HashMap".to_string(),
                 found: self.type_name().to_string(),
@@ -304,11 +396,14 @@ impl Value {
     }
 
     // ===== Arithmetic Operations =====
+    //
+    // Integer results are checked: a result outside i64 is an
+    // IntegerOverflow error rather than a wrapped value (INTERP-133).
 
     /// Add two values
     pub fn add(&self, other: &Value) -> Result<Value, ValueError> {
         match (self, other) {
-            (Value::Integer(a), Value::Integer(b)) => Ok(Value::Integer(a + b)),
+            (Value::Integer(a), Value::Integer(b)) => checked(a.checked_add(*b), "add"),
             (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a + b)),
             (Value::String(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
             _ => Err(ValueError::TypeMismatch {
@@ -322,7 +417,7 @@ impl Value {
     /// Subtract two values
     pub fn subtract(&self, other: &Value) -> Result<Value, ValueError> {
         match (self, other) {
-            (Value::Integer(a), Value::Integer(b)) => Ok(Value::Integer(a - b)),
+            (Value::Integer(a), Value::Integer(b)) => checked(a.checked_sub(*b), "subtract"),
             (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a - b)),
             _ => Err(ValueError::TypeMismatch {
                 expected: "Integer or Float".to_string(),
@@ -335,7 +430,7 @@ impl Value {
     /// Multiply two values
     pub fn multiply(&self, other: &Value) -> Result<Value, ValueError> {
         match (self, other) {
-            (Value::Integer(a), Value::Integer(b)) => Ok(Value::Integer(a * b)),
+            (Value::Integer(a), Value::Integer(b)) => checked(a.checked_mul(*b), "multiply"),
             (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a * b)),
             _ => Err(ValueError::TypeMismatch {
                 expected: "Integer or Float".to_string(),
@@ -349,14 +444,9 @@ impl Value {
     pub fn divide(&self, other: &Value) -> Result<Value, ValueError> {
         match (self, other) {
             (Value::Integer(_), Value::Integer(0)) => Err(ValueError::DivisionByZero),
-            (Value::Integer(a), Value::Integer(b)) => Ok(Value::Integer(a / b)),
-            (Value::Float(a), Value::Float(b)) => {
-                if *b == 0.0 {
-                    Err(ValueError::DivisionByZero)
-                } else {
-                    Ok(Value::Float(a / b))
-                }
-            }
+            (Value::Integer(a), Value::Integer(b)) => checked(a.checked_div(*b), "divide"),
+            // IEEE 754: x / 0.0 is +/-Infinity, 0.0 / 0.0 is NaN
+            (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a / b)),
             _ => Err(ValueError::TypeMismatch {
                 expected: "Integer or Float".to_string(),
                 found: format!("{} / {}", self.type_name(), other.type_name()),
@@ -392,9 +482,15 @@ impl Value {
     }
 
     /// Logical NOT
+    ///
+    /// Defined on booleans and, integer-logically, on integers: `!0` is
+    /// true and `!n` for any other integer is false (so `!!5` is true).
+    /// The JIT, where booleans are 0/1 integers, uses the same rule. Any
+    /// other operand (float, string, ...) is a type error in both.
     pub fn logical_not(&self) -> Result<Value, ValueError> {
         match self {
             Value::Boolean(b) => Ok(Value::Boolean(!b)),
+            Value::Integer(n) => Ok(Value::Boolean(*n == 0)),
             _ => Err(ValueError::TypeMismatch {
                 expected: "Boolean".to_string(),
                 found: self.type_name().to_string(),
@@ -409,8 +505,9 @@ impl Value {
     pub fn less_than(&self, other: &Value) -> Result<Value, ValueError> {
         match (self, other) {
             (Value::Integer(a), Value::Integer(b)) => Ok(Value::Boolean(a < b)),
+            (Value::Float(a), Value::Float(b)) => Ok(Value::Boolean(a < b)),
             _ => Err(ValueError::TypeMismatch {
-                expected: "Integer".to_string(),
+                expected: "Integer or Float".to_string(),
                 found: format!("{} < {}", self.type_name(), other.type_name()),
                 operation: "less_than".to_string(),
             }),
@@ -421,8 +518,9 @@ impl Value {
     pub fn greater_than(&self, other: &Value) -> Result<Value, ValueError> {
         match (self, other) {
             (Value::Integer(a), Value::Integer(b)) => Ok(Value::Boolean(a > b)),
+            (Value::Float(a), Value::Float(b)) => Ok(Value::Boolean(a > b)),
             _ => Err(ValueError::TypeMismatch {
-                expected: "Integer".to_string(),
+                expected: "Integer or Float".to_string(),
                 found: format!("{} > {}", self.type_name(), other.type_name()),
                 operation: "greater_than".to_string(),
             }),
@@ -435,6 +533,148 @@ impl Value {
         Ok(Value::Boolean(self == other))
     }
 
+    /// Total ordering used for sorting
+    ///
+    /// Numbers compare by value (integers and floats interleave), with NaN
+    /// equal to NaN and greater than every other number. Strings, booleans,
+    /// vectors and tuples compare naturally; values of different kinds are
+    /// ordered by kind (nil, bool, number, string, vector, tuple, other).
+    pub fn compare(&self, other: &Value) -> Ordering {
+        fn rank(value: &Value) -> u8 {
+            match value {
+                Value::Nil => 0,
+                Value::Boolean(_) => 1,
+                Value::Integer(_) | Value::Float(_) => 2,
+                Value::String(_) => 3,
+                Value::Vector(_) => 4,
+                Value::Tuple(_) => 5,
+                _ => 6,
+            }
+        }
+        fn compare_floats(a: f64, b: f64) -> Ordering {
+            match (a.is_nan(), b.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            }
+        }
+        fn compare_all(a: &[Value], b: &[Value]) -> Ordering {
+            a.iter()
+                .zip(b)
+                .map(|(x, y)| x.compare(y))
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+
+        match (self, other) {
+            (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+            (Value::Integer(a), Value::Float(b)) => compare_floats(*a as f64, *b),
+            (Value::Float(a), Value::Integer(b)) => compare_floats(*a, *b as f64),
+            (Value::Float(a), Value::Float(b)) => compare_floats(*a, *b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+            (Value::Vector(a), Value::Vector(b)) | (Value::Tuple(a), Value::Tuple(b)) => {
+                compare_all(a, b)
+            }
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+
+    /// Deterministic 64-bit hash of this value (INTERP-094)
+    ///
+    /// Equal values always hash equally, and the result is reproducible
+    /// across runs. Not suitable for cryptographic use. Errors for values
+    /// that have no meaningful equality: functions, closures and NaN.
+    pub fn stable_hash(&self) -> Result<i64, ValueError> {
+        let mut hasher = StableHasher::new();
+        self.hash_into(&mut hasher)?;
+        Ok(hasher.finish() as i64)
+    }
+
+    fn hash_into(&self, hasher: &mut StableHasher) -> Result<(), ValueError> {
+        match self {
+            Value::Integer(n) => {
+                hasher.write_tag(0);
+                hasher.write(&n.to_le_bytes());
+            }
+            Value::Float(f) => {
+                if f.is_nan() {
+                    return Err(ValueError::InvalidOperation {
+                        operation: "hash".to_string(),
+                        message: "NaN is not hashable (NaN != NaN)".to_string(),
+                    });
+                }
+                // -0.0 == 0.0, so both must hash the same
+                let f = if *f == 0.0 { 0.0 } else { *f };
+                hasher.write_tag(1);
+                hasher.write(&f.to_bits().to_le_bytes());
+            }
+            Value::String(s) => {
+                hasher.write_tag(2);
+                hasher.write_str(s);
+            }
+            Value::Boolean(b) => {
+                hasher.write_tag(3);
+                hasher.write(&[*b as u8]);
+            }
+            Value::Vector(elements) | Value::Tuple(elements) => {
+                hasher.write_tag(if matches!(self, Value::Vector(_)) { 4 } else { 5 });
+                hasher.write(&(elements.len() as u64).to_le_bytes());
+                for element in elements {
+                    element.hash_into(hasher)?;
+                }
+            }
+            Value::HashMap(map) => {
+                hasher.write_tag(6);
+                Self::hash_fields(map, hasher)?;
+            }
+            Value::Struct { name, fields } => {
+                hasher.write_tag(7);
+                hasher.write_str(name);
+                Self::hash_fields(fields, hasher)?;
+            }
+            Value::EnumVariant {
+                enum_name,
+                variant,
+                discriminant,
+                payload,
+            } => {
+                hasher.write_tag(8);
+                hasher.write_str(enum_name);
+                hasher.write_str(variant);
+                hasher.write(&discriminant.to_le_bytes());
+                if let Some(payload) = payload {
+                    payload.hash_into(hasher)?;
+                }
+            }
+            Value::Nil => hasher.write_tag(9),
+            Value::Function { .. } | Value::Closure { .. } => {
+                return Err(ValueError::TypeMismatch {
+                    expected: "hashable value".to_string(),
+                    found: self.type_name().to_string(),
+                    operation: "hash".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Hash map entries in key order, so insertion order does not matter
+    fn hash_fields(
+        map: &HashMap<String, Value>,
+        hasher: &mut StableHasher,
+    ) -> Result<(), ValueError> {
+        let mut keys: Vec<&String> = map.keys().collect();
+        keys.sort();
+        hasher.write(&(keys.len() as u64).to_le_bytes());
+        for key in keys {
+            hasher.write_str(key);
+            map[key].hash_into(hasher)?;
+        }
+        Ok(())
+    }
+
     // ===== Collection Operations =====
 
     /// Index into vector
@@ -470,7 +710,7 @@ impl Value {
     /// Insert key-value pair into hashmap
     pub fn insert(&mut self, key: Value, value: Value) -> Result<(), ValueError> {
         match self {
-            Value::HashMap(m) => {
+            Value::HashMap(m) | Value::Struct { fields: m, .. } => {
                 let key_str = match key {
                     Value::String(s) => s,
                     _ => {
@@ -495,7 +735,7 @@ impl Value {
     /// Get value from hashmap by key
     pub fn get(&self, key: &Value) -> Result<&Value, ValueError> {
         match self {
-            Value::HashMap(m) => {
+            Value::HashMap(m) | Value::Struct { fields: m, .. } => {
                 let key_str = match key {
                     Value::String(s) => s,
                     _ => {
@@ -532,7 +772,7 @@ impl Value {
                 let elements: Vec<String> = t.iter().map(|val| val.to_println_string()).collect();
                 format!("({})", elements.join(", "))
             }
-            Value::HashMap(m) => {
+            Value::HashMap(m) | Value::Struct { fields: m, .. } => {
                 let pairs: Vec<String> = m
                     .iter()
                     .map(|(k, v)| format!("\"{}\": {}", k, v.to_println_string()))
@@ -545,6 +785,39 @@ impl Value {
     }
 }
 
+/// FNV-1a (64-bit) with a fixed offset basis, used by `Value::stable_hash`
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        StableHasher(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_tag(&mut self, tag: u8) {
+        self.write(&[tag]);
+    }
+
+    /// Length-prefixed, so ("ab", "c") and ("a", "bc") differ
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -572,7 +845,7 @@ impl fmt::Display for Value {
                 }
                 write!(f, ")")
             }
-            Value::HashMap(m) => {
+            Value::HashMap(m) | Value::Struct { fields: m, .. } => {
                 write!(f, "{{")?;
                 for (i, (k, v)) in m.iter().enumerate() {
                     if i > 0 {
@@ -588,6 +861,21 @@ impl fmt::Display for Value {
             Value::Closure { params, .. } => {
                 write!(f, "|{}| {{ <closure> }}", params.join(", "))
             }
+            // Option/Result print like Rust: Some(5), None, Err(oops)
+            Value::EnumVariant {
+                enum_name,
+                variant,
+                payload,
+                ..
+            } => {
+                if enum_name != "Option" && enum_name != "Result" {
+                    write!(f, "{}::", enum_name)?;
+                }
+                match payload {
+                    Some(payload) => write!(f, "{}({})", variant, payload),
+                    None => write!(f, "{}", variant),
+                }
+            }
             Value::Nil => write!(f, "nil"),
         }
     }
@@ -604,4 +892,34 @@ mod tests {
         assert_eq!(val.as_integer().unwrap(), 42);
         assert!(val.is_integer());
     }
+
+    #[test]
+    fn test_compare_places_nan_after_numbers() {
+        let nan = Value::float(f64::NAN);
+        assert_eq!(nan.compare(&Value::float(f64::INFINITY)), Ordering::Greater);
+        assert_eq!(Value::integer(3).compare(&nan), Ordering::Less);
+        assert_eq!(nan.compare(&Value::float(-f64::NAN)), Ordering::Equal);
+        assert_eq!(Value::integer(2).compare(&Value::float(2.5)), Ordering::Less);
+    }
+
+    #[test]
+    fn test_stable_hash_is_fixed_fnv() {
+        // Pinned so an accidental encoding change shows up as a test failure
+        let pinned = [
+            (Value::integer(0), -1872228358846142017),
+            (Value::integer(42), -8101521626687829867),
+            (Value::string("abc".to_string()), -5103385329942253230),
+            (
+                Value::vector(vec![Value::integer(1), Value::string("a".to_string())]),
+                7215315095670838592,
+            ),
+        ];
+        for (value, hash) in pinned {
+            assert_eq!(value.stable_hash().unwrap(), hash, "{}", value);
+        }
+        assert_eq!(
+            Value::float(-0.0).stable_hash().unwrap(),
+            Value::float(0.0).stable_hash().unwrap()
+        );
+    }
 }
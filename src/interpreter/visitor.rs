@@ -0,0 +1,602 @@
+// INTERP-103: AST Visitors
+//
+// Reusable traversal over `AstNode` so analyses and transforms do not each
+// hand-roll their own recursive `match`.
+//
+// - `Visitor` walks a tree immutably (analyses: unused variables, counting,
+//   call graphs). Common node kinds have their own hook with the node's fields
+//   destructured; every hook's default visits the node's children.
+// - `VisitorMut` walks a tree mutably (transforms: constant folding, dead code
+//   removal). Transforms usually replace whole nodes, so it hooks `visit_node`
+//   and statement lists rather than individual node kinds.
+//
+// Overriding a hook replaces the default traversal for that node. To keep
+// descending, call the matching `visit_*` methods on the children (or
+// `walk_node`/`walk_node_mut` from an overridden `visit_node`).
+
+use super::ast::{Ast, AstNode, BinaryOperator, MatchArm, Pattern, UnaryOperator};
+
+/// Immutable AST visitor with default recursive traversal
+///
+/// # Example
+/// ```
+/// use ruchyruchy::interpreter::parser::{AstNode, Parser};
+/// use ruchyruchy::interpreter::visitor::{walk_node, Visitor};
+///
+/// /// Counts every node in a tree
+/// struct NodeCounter {
+///     count: usize,
+/// }
+///
+/// impl Visitor for NodeCounter {
+///     fn visit_node(&mut self, node: &AstNode) {
+///         self.count += 1;
+///         walk_node(self, node);
+///     }
+/// }
+///
+/// let ast = Parser::new("let x = 1 + 2; println(x)").parse().unwrap();
+/// let mut counter = NodeCounter { count: 0 };
+/// counter.visit_ast(&ast);
+///
+/// // let, +, 1, 2, println(..), x
+/// assert_eq!(counter.count, 6);
+/// ```
+pub trait Visitor {
+    /// Visit every top-level node of a program
+    fn visit_ast(&mut self, ast: &Ast) {
+        self.visit_statements(ast.nodes());
+    }
+
+    /// Visit any node; the default dispatches to the node kind's hook
+    fn visit_node(&mut self, node: &AstNode) {
+        walk_node(self, node);
+    }
+
+    /// Visit a statement list (function, loop, branch, and block bodies)
+    fn visit_statements(&mut self, statements: &[AstNode]) {
+        for statement in statements {
+            self.visit_node(statement);
+        }
+    }
+
+    /// Visit `fun name(params) { body }`
+    fn visit_function_def(&mut self, _name: &str, _params: &[String], body: &[AstNode]) {
+        self.visit_statements(body);
+    }
+
+    /// Visit `let name = value`
+    fn visit_let_decl(&mut self, _name: &str, value: &AstNode) {
+        self.visit_node(value);
+    }
+
+    /// Visit `name = value`
+    fn visit_assignment(&mut self, _name: &str, value: &AstNode) {
+        self.visit_node(value);
+    }
+
+    /// Visit a variable reference
+    fn visit_identifier(&mut self, _name: &str) {}
+
+    /// Visit an integer, float, string, char, or boolean literal
+    fn visit_literal(&mut self, _literal: &AstNode) {}
+
+    /// Visit `name(args)`
+    fn visit_function_call(&mut self, _name: &str, args: &[AstNode]) {
+        self.visit_statements(args);
+    }
+
+    /// Visit `receiver.method(args)`
+    fn visit_method_call(&mut self, receiver: &AstNode, _method: &str, args: &[AstNode]) {
+        self.visit_node(receiver);
+        self.visit_statements(args);
+    }
+
+    /// Visit `left op right`
+    fn visit_binary_op(&mut self, _op: BinaryOperator, left: &AstNode, right: &AstNode) {
+        self.visit_node(left);
+        self.visit_node(right);
+    }
+
+    /// Visit `op operand`
+    fn visit_unary_op(&mut self, _op: UnaryOperator, operand: &AstNode) {
+        self.visit_node(operand);
+    }
+
+    /// Visit `if condition { then_branch } else { else_branch }`
+    fn visit_if(
+        &mut self,
+        condition: &AstNode,
+        then_branch: &[AstNode],
+        else_branch: Option<&[AstNode]>,
+    ) {
+        self.visit_node(condition);
+        self.visit_statements(then_branch);
+        if let Some(else_branch) = else_branch {
+            self.visit_statements(else_branch);
+        }
+    }
+
+    /// Visit `while condition { body }`
+    fn visit_while_loop(&mut self, condition: &AstNode, body: &[AstNode]) {
+        self.visit_node(condition);
+        self.visit_statements(body);
+    }
+
+    /// Visit `for var in iterable { body }`
+    fn visit_for_loop(&mut self, _var: &str, iterable: &AstNode, body: &[AstNode]) {
+        self.visit_node(iterable);
+        self.visit_statements(body);
+    }
+
+    /// Visit `match expr { arms }`
+    fn visit_match(&mut self, expr: &AstNode, arms: &[MatchArm]) {
+        self.visit_node(expr);
+        for arm in arms {
+            self.visit_match_arm(arm);
+        }
+    }
+
+    /// Visit one match arm: its pattern, then its body
+    fn visit_match_arm(&mut self, arm: &MatchArm) {
+        self.visit_pattern(&arm.pattern);
+        if let Some(guard) = &arm.guard {
+            self.visit_node(guard);
+        }
+        self.visit_statements(&arm.body);
+    }
+
+    /// Visit a match pattern and the literal nodes inside it
+    fn visit_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Wildcard | Pattern::Identifier(_) => {}
+            Pattern::Literal(node) => self.visit_node(node),
+            Pattern::Range { start, end, .. } => {
+                self.visit_node(start);
+                self.visit_node(end);
+            }
+            Pattern::Binding { pattern, .. } => self.visit_pattern(pattern),
+            Pattern::Variant { payload, .. } => {
+                if let Some(pattern) = payload {
+                    self.visit_pattern(pattern);
+                }
+            }
+            Pattern::Tuple(elements) => {
+                for pattern in elements {
+                    self.visit_pattern(pattern);
+                }
+            }
+        }
+    }
+
+    /// Visit `|params| body`
+    fn visit_closure(&mut self, _params: &[String], body: &[AstNode]) {
+        self.visit_statements(body);
+    }
+
+    /// Visit `{ statements }`
+    fn visit_block(&mut self, statements: &[AstNode]) {
+        self.visit_statements(statements);
+    }
+
+    /// Visit `return value`
+    fn visit_return(&mut self, value: Option<&AstNode>) {
+        if let Some(value) = value {
+            self.visit_node(value);
+        }
+    }
+}
+
+/// Default handling of a node for `Visitor`
+///
+/// Node kinds with a hook go to that hook; the others have their children
+/// visited directly.
+pub fn walk_node<V: Visitor + ?Sized>(visitor: &mut V, node: &AstNode) {
+    match node {
+        AstNode::FunctionDef {
+            name, params, body, ..
+        } => visitor.visit_function_def(name, params, body),
+        AstNode::LetDecl { name, value, .. } => visitor.visit_let_decl(name, value),
+        AstNode::Assignment { name, value } => visitor.visit_assignment(name, value),
+        AstNode::Identifier(name) => visitor.visit_identifier(name),
+        AstNode::IntegerLiteral(_)
+        | AstNode::FloatLiteral(_)
+        | AstNode::StringLiteral(_)
+        | AstNode::CharLiteral(_)
+        | AstNode::BooleanLiteral(_) => visitor.visit_literal(node),
+        AstNode::FunctionCall { name, args } => visitor.visit_function_call(name, args),
+        AstNode::MethodCall {
+            receiver,
+            method,
+            args,
+        } => visitor.visit_method_call(receiver, method, args),
+        AstNode::BinaryOp { op, left, right } => visitor.visit_binary_op(*op, left, right),
+        AstNode::UnaryOp { op, operand } => visitor.visit_unary_op(*op, operand),
+        AstNode::IfExpr {
+            condition,
+            then_branch,
+            else_branch,
+        } => visitor.visit_if(condition, then_branch, else_branch.as_deref()),
+        AstNode::WhileLoop { condition, body } => visitor.visit_while_loop(condition, body),
+        AstNode::ForLoop {
+            var,
+            iterable,
+            body,
+        } => visitor.visit_for_loop(var, iterable, body),
+        AstNode::MatchExpr { expr, arms } => visitor.visit_match(expr, arms),
+        AstNode::Closure { params, body, .. } => visitor.visit_closure(params, body),
+        AstNode::Block { statements } => visitor.visit_block(statements),
+        AstNode::Return { value } => visitor.visit_return(value.as_deref()),
+
+        AstNode::Spanned { node, .. } => visitor.visit_node(node),
+        AstNode::TestDef { body, .. } => visitor.visit_statements(body),
+        AstNode::TupleDestruct { patterns, value } => {
+            visitor.visit_node(value);
+            for pattern in patterns {
+                visitor.visit_pattern(pattern);
+            }
+        }
+        AstNode::LetElse {
+            pattern,
+            value,
+            else_block,
+        } => {
+            visitor.visit_node(value);
+            visitor.visit_pattern(pattern);
+            visitor.visit_statements(else_block);
+        }
+        AstNode::IfLet {
+            pattern,
+            scrutinee,
+            then_branch,
+            else_branch,
+        } => {
+            visitor.visit_node(scrutinee);
+            visitor.visit_pattern(pattern);
+            visitor.visit_statements(then_branch);
+            if let Some(else_branch) = else_branch {
+                visitor.visit_statements(else_branch);
+            }
+        }
+        AstNode::WhileLet {
+            pattern,
+            scrutinee,
+            body,
+        } => {
+            visitor.visit_node(scrutinee);
+            visitor.visit_pattern(pattern);
+            visitor.visit_statements(body);
+        }
+        AstNode::CompoundAssignment { lhs, rhs, .. } => {
+            visitor.visit_node(lhs);
+            visitor.visit_node(rhs);
+        }
+        AstNode::IndexAssignment {
+            target,
+            index,
+            value,
+        } => {
+            visitor.visit_node(target);
+            visitor.visit_node(index);
+            visitor.visit_node(value);
+        }
+        AstNode::FieldAssignment { target, value, .. } => {
+            visitor.visit_node(target);
+            visitor.visit_node(value);
+        }
+        AstNode::RepeatUntil { body, condition } => {
+            visitor.visit_statements(body);
+            visitor.visit_node(condition);
+        }
+        AstNode::Loop { body } => visitor.visit_statements(body),
+        AstNode::Break { value } => {
+            if let Some(value) = value {
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::ImplBlock { methods, .. } => visitor.visit_statements(methods),
+        AstNode::EnumDef { variants, .. } => {
+            for discriminant in variants.iter().filter_map(|v| v.discriminant.as_ref()) {
+                visitor.visit_node(discriminant);
+            }
+        }
+        AstNode::StructLiteral { fields, .. } => {
+            for (_, value) in fields {
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::FieldAccess { expr, .. } | AstNode::TypeCast { expr, .. } => {
+            visitor.visit_node(expr)
+        }
+        AstNode::VectorLiteral { elements } | AstNode::TupleLiteral { elements } => {
+            visitor.visit_statements(elements)
+        }
+        AstNode::HashMapLiteral { pairs } => {
+            for (key, value) in pairs {
+                visitor.visit_node(key);
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::IndexAccess { expr, index } => {
+            visitor.visit_node(expr);
+            visitor.visit_node(index);
+        }
+        AstNode::Range { start, end, .. } => {
+            visitor.visit_node(start);
+            visitor.visit_node(end);
+        }
+        AstNode::VecMacro {
+            elements,
+            repeat_count,
+        } => {
+            visitor.visit_statements(elements);
+            if let Some(count) = repeat_count {
+                visitor.visit_node(count);
+            }
+        }
+
+        // Leaves (f-string holes are source text, parsed at evaluation time)
+        AstNode::Empty
+        | AstNode::Continue
+        | AstNode::FString { .. }
+        | AstNode::StructDef { .. }
+        | AstNode::UseDecl { .. }
+        | AstNode::GroupedUseDecl { .. }
+        | AstNode::PathExpr { .. } => {}
+    }
+}
+
+/// Mutable AST visitor with default recursive traversal
+///
+/// A transform overrides `visit_node`, calls `walk_node_mut` to rewrite the
+/// children first, then replaces the node itself if it wants to.
+///
+/// # Example
+/// ```
+/// use ruchyruchy::interpreter::parser::{AstNode, BinaryOperator, Parser};
+/// use ruchyruchy::interpreter::visitor::{walk_node_mut, VisitorMut};
+///
+/// /// Folds integer additions of two literals
+/// struct FoldAdditions;
+///
+/// impl VisitorMut for FoldAdditions {
+///     fn visit_node(&mut self, node: &mut AstNode) {
+///         walk_node_mut(self, node);
+///         if let AstNode::BinaryOp { op: BinaryOperator::Add, left, right } = node {
+///             if let (AstNode::IntegerLiteral(a), AstNode::IntegerLiteral(b)) =
+///                 (left.as_ref(), right.as_ref())
+///             {
+///                 *node = AstNode::IntegerLiteral(a + b);
+///             }
+///         }
+///     }
+/// }
+///
+/// let mut ast = Parser::new("let x = 1 + 2 + 3").parse().unwrap();
+/// FoldAdditions.visit_ast(&mut ast);
+/// assert_eq!(ast.nodes()[0].emit(), "let x = 6;");
+/// ```
+pub trait VisitorMut {
+    /// Visit every top-level node of a program
+    fn visit_ast(&mut self, ast: &mut Ast) {
+        self.visit_statements(&mut ast.nodes);
+    }
+
+    /// Visit any node; the default visits its children
+    fn visit_node(&mut self, node: &mut AstNode) {
+        walk_node_mut(self, node);
+    }
+
+    /// Visit a statement list; transforms may add or remove statements here
+    fn visit_statements(&mut self, statements: &mut Vec<AstNode>) {
+        for statement in statements.iter_mut() {
+            self.visit_node(statement);
+        }
+    }
+
+    /// Visit one match arm: its pattern, then its body
+    fn visit_match_arm(&mut self, arm: &mut MatchArm) {
+        self.visit_pattern(&mut arm.pattern);
+        if let Some(guard) = &mut arm.guard {
+            self.visit_node(guard);
+        }
+        self.visit_statements(&mut arm.body);
+    }
+
+    /// Visit a match pattern and the literal nodes inside it
+    fn visit_pattern(&mut self, pattern: &mut Pattern) {
+        match pattern {
+            Pattern::Wildcard | Pattern::Identifier(_) => {}
+            Pattern::Literal(node) => self.visit_node(node),
+            Pattern::Range { start, end, .. } => {
+                self.visit_node(start);
+                self.visit_node(end);
+            }
+            Pattern::Binding { pattern, .. } => self.visit_pattern(pattern),
+            Pattern::Variant { payload, .. } => {
+                if let Some(pattern) = payload {
+                    self.visit_pattern(pattern);
+                }
+            }
+            Pattern::Tuple(elements) => {
+                for pattern in elements {
+                    self.visit_pattern(pattern);
+                }
+            }
+        }
+    }
+}
+
+/// Default handling of a node for `VisitorMut`: visit each of its children
+pub fn walk_node_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut AstNode) {
+    match node {
+        AstNode::Spanned { node, .. } => visitor.visit_node(node),
+        AstNode::FunctionDef { body, .. }
+        | AstNode::TestDef { body, .. }
+        | AstNode::Closure { body, .. }
+        | AstNode::Block {
+            statements: body, ..
+        }
+        | AstNode::ImplBlock { methods: body, .. } => visitor.visit_statements(body),
+        AstNode::LetDecl { value, .. } | AstNode::Assignment { value, .. } => {
+            visitor.visit_node(value)
+        }
+        AstNode::TupleDestruct { patterns, value } => {
+            visitor.visit_node(value);
+            for pattern in patterns {
+                visitor.visit_pattern(pattern);
+            }
+        }
+        AstNode::CompoundAssignment { lhs, rhs, .. } => {
+            visitor.visit_node(lhs);
+            visitor.visit_node(rhs);
+        }
+        AstNode::IndexAssignment {
+            target,
+            index,
+            value,
+        } => {
+            visitor.visit_node(target);
+            visitor.visit_node(index);
+            visitor.visit_node(value);
+        }
+        AstNode::FieldAssignment { target, value, .. } => {
+            visitor.visit_node(target);
+            visitor.visit_node(value);
+        }
+        AstNode::FunctionCall { args, .. } => visitor.visit_statements(args),
+        AstNode::MethodCall { receiver, args, .. } => {
+            visitor.visit_node(receiver);
+            visitor.visit_statements(args);
+        }
+        AstNode::BinaryOp { left, right, .. } => {
+            visitor.visit_node(left);
+            visitor.visit_node(right);
+        }
+        AstNode::UnaryOp { operand, .. } => visitor.visit_node(operand),
+        AstNode::IfExpr {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            visitor.visit_node(condition);
+            visitor.visit_statements(then_branch);
+            if let Some(else_branch) = else_branch {
+                visitor.visit_statements(else_branch);
+            }
+        }
+        AstNode::WhileLoop { condition, body } => {
+            visitor.visit_node(condition);
+            visitor.visit_statements(body);
+        }
+        AstNode::RepeatUntil { body, condition } => {
+            visitor.visit_statements(body);
+            visitor.visit_node(condition);
+        }
+        AstNode::Loop { body } => visitor.visit_statements(body),
+        AstNode::Break { value } => {
+            if let Some(value) = value {
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::ForLoop { iterable, body, .. } => {
+            visitor.visit_node(iterable);
+            visitor.visit_statements(body);
+        }
+        AstNode::MatchExpr { expr, arms } => {
+            visitor.visit_node(expr);
+            for arm in arms {
+                visitor.visit_match_arm(arm);
+            }
+        }
+        AstNode::LetElse {
+            pattern,
+            value,
+            else_block,
+        } => {
+            visitor.visit_node(value);
+            visitor.visit_pattern(pattern);
+            visitor.visit_statements(else_block);
+        }
+        AstNode::IfLet {
+            pattern,
+            scrutinee,
+            then_branch,
+            else_branch,
+        } => {
+            visitor.visit_node(scrutinee);
+            visitor.visit_pattern(pattern);
+            visitor.visit_statements(then_branch);
+            if let Some(else_branch) = else_branch {
+                visitor.visit_statements(else_branch);
+            }
+        }
+        AstNode::WhileLet {
+            pattern,
+            scrutinee,
+            body,
+        } => {
+            visitor.visit_node(scrutinee);
+            visitor.visit_pattern(pattern);
+            visitor.visit_statements(body);
+        }
+        AstNode::EnumDef { variants, .. } => {
+            for discriminant in variants.iter_mut().filter_map(|v| v.discriminant.as_mut()) {
+                visitor.visit_node(discriminant);
+            }
+        }
+        AstNode::StructLiteral { fields, .. } => {
+            for (_, value) in fields {
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::FieldAccess { expr, .. } | AstNode::TypeCast { expr, .. } => {
+            visitor.visit_node(expr)
+        }
+        AstNode::VectorLiteral { elements } | AstNode::TupleLiteral { elements } => {
+            visitor.visit_statements(elements)
+        }
+        AstNode::HashMapLiteral { pairs } => {
+            for (key, value) in pairs {
+                visitor.visit_node(key);
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::IndexAccess { expr, index } => {
+            visitor.visit_node(expr);
+            visitor.visit_node(index);
+        }
+        AstNode::Range { start, end, .. } => {
+            visitor.visit_node(start);
+            visitor.visit_node(end);
+        }
+        AstNode::Return { value } => {
+            if let Some(value) = value {
+                visitor.visit_node(value);
+            }
+        }
+        AstNode::VecMacro {
+            elements,
+            repeat_count,
+        } => {
+            visitor.visit_statements(elements);
+            if let Some(count) = repeat_count {
+                visitor.visit_node(count);
+            }
+        }
+
+        AstNode::Empty
+        | AstNode::Continue
+        | AstNode::Identifier(_)
+        | AstNode::IntegerLiteral(_)
+        | AstNode::FloatLiteral(_)
+        | AstNode::StringLiteral(_)
+        | AstNode::CharLiteral(_)
+        | AstNode::BooleanLiteral(_)
+        | AstNode::FString { .. }
+        | AstNode::StructDef { .. }
+        | AstNode::UseDecl { .. }
+        | AstNode::GroupedUseDecl { .. }
+        | AstNode::PathExpr { .. } => {}
+    }
+}
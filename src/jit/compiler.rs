@@ -78,7 +78,7 @@ impl JitCompiler {
             AstNode::Block { statements } => {
                 Self::compile_block(statements, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
             }
-            AstNode::LetDecl { name, value } => {
+            AstNode::LetDecl { name, value, .. } => {
                 Self::compile_let_decl(name, value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
             }
             AstNode::Assignment { name, value } => {
@@ -149,8 +149,8 @@ impl JitCompiler {
             AstNode::MethodCall { receiver, method, args } => {
                 Self::compile_method_call(receiver, method, args, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
             }
-            AstNode::TupleDestruct { names, value } => {
-                Self::compile_tuple_destruct(names, value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
+            AstNode::TupleDestruct { patterns, value } => {
+                Self::compile_tuple_destruct(patterns, value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
             }
             AstNode::TypeCast { expr, target_type } => {
                 Self::compile_type_cast(expr, target_type, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
@@ -158,6 +158,8 @@ impl JitCompiler {
             AstNode::VecMacro { elements, repeat_count } => {
                 Self::compile_vec_macro(elements, repeat_count, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs)
             }
+            AstNode::Break { value: None } => Self::compile_loop_jump("break", builder, string_ctx),
+            AstNode::Continue => Self::compile_loop_jump("continue", builder, string_ctx),
             _ => Err(JitError::UnsupportedNode(format!(
                 "Cannot compile AST node: {:?}",
                 ast
@@ -183,20 +185,33 @@ impl JitCompiler {
                 expr, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
             )?;
 
-            let return_value = if builder.func.dfg.value_type(return_value) == types::F64 {
-                builder.ins().bitcast(types::I64, MemFlags::new(), return_value)
-            } else {
-                return_value
-            };
-
+            let return_value = Self::coerce_to_return_type(return_value, builder);
             builder.ins().return_(&[return_value]);
         } else {
             let zero = builder.ins().iconst(types::I64, 0);
+            let zero = Self::coerce_to_return_type(zero, builder);
             builder.ins().return_(&[zero]);
         }
         Ok(dummy)
     }
 
+    /// Convert a value to the function's declared return type
+    ///
+    /// A float returned from an i64 function keeps its bits (callers read it
+    /// with `f64::from_bits`); an integer returned from an f64 function
+    /// (JIT-030) is converted numerically.
+    pub(crate) fn coerce_to_return_type(value: Value, builder: &mut FunctionBuilder) -> Value {
+        let return_type = builder.func.signature.returns[0].value_type;
+        let value_type = builder.func.dfg.value_type(value);
+        if value_type == return_type {
+            value
+        } else if return_type == types::F64 {
+            builder.ins().fcvt_from_sint(types::F64, value)
+        } else {
+            builder.ins().bitcast(types::I64, MemFlags::new(), value)
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn compile_block(
         statements: &[AstNode],
@@ -236,6 +251,9 @@ impl JitCompiler {
         let value_type = builder.func.dfg.value_type(init_value);
         let var = builder.declare_var(value_type);
         builder.def_var(var, init_value);
+        if Self::is_string_expr(value, local_vars, string_ctx) {
+            string_ctx.string_locals.insert(var.as_u32());
+        }
         local_vars.insert(name.to_string(), var);
 
         Ok(builder.ins().iconst(types::I64, 0))
@@ -261,10 +279,27 @@ impl JitCompiler {
             value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
 
+        if Self::is_string_expr(value, local_vars, string_ctx) {
+            string_ctx.string_locals.insert(var.as_u32());
+        } else {
+            string_ctx.string_locals.remove(&var.as_u32());
+        }
         builder.def_var(var, new_value);
         Ok(builder.ins().iconst(types::I64, 0))
     }
 
+    /// Whether `node` is known to evaluate to a string: a literal, an
+    /// f-string, or a local last assigned one
+    fn is_string_expr(node: &AstNode, local_vars: &HashMap<String, Variable>, string_ctx: &StringContext) -> bool {
+        match node {
+            AstNode::StringLiteral(_) | AstNode::FString { .. } => true,
+            AstNode::Identifier(name) => local_vars
+                .get(name)
+                .is_some_and(|var| string_ctx.string_locals.contains(&var.as_u32())),
+            _ => false,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn compile_fstring(
         content: &str,
@@ -360,6 +395,12 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
+        if matches!(op, BinaryOperator::And | BinaryOperator::Or) {
+            return Self::compile_logical_op(
+                left, op, right, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+            );
+        }
+
         let lhs = Self::compile_expr_with_context(
             left, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
@@ -380,6 +421,74 @@ impl JitCompiler {
         Ok(result)
     }
 
+    /// Compile `&&` / `||` with short-circuit control flow (JIT-032)
+    ///
+    /// The right operand gets its own block, entered only when the left one
+    /// does not decide the result; both paths join in a merge block whose
+    /// parameter is the 0/1 result.
+    #[allow(clippy::too_many_arguments)]
+    fn compile_logical_op(
+        left: &AstNode,
+        op: &BinaryOperator,
+        right: &AstNode,
+        builder: &mut FunctionBuilder,
+        parameters: &HashMap<String, Value>,
+        local_vars: &mut HashMap<String, Variable>,
+        var_counter: &mut usize,
+        compiled_functions: &HashMap<String, *const u8>,
+        string_ctx: &mut StringContext,
+        struct_defs: &mut HashMap<String, Vec<String>>,
+    ) -> Result<Value, JitError> {
+        let float_error = || {
+            JitError::UnsupportedNode("Logical operators not supported for floats".to_string())
+        };
+
+        let lhs = Self::compile_expr_with_context(
+            left, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+        )?;
+        if builder.func.dfg.value_type(lhs) == types::F64 {
+            return Err(float_error());
+        }
+
+        let rhs_block = builder.create_block();
+        let merge_block = builder.create_block();
+        builder.append_block_param(merge_block, types::I64);
+
+        let zero = builder.ins().iconst(types::I64, 0);
+        let lhs_true = builder.ins().icmp(IntCC::NotEqual, lhs, zero);
+        if *op == BinaryOperator::And {
+            // false && _ is false without evaluating the right side
+            builder
+                .ins()
+                .brif(lhs_true, rhs_block, &[], merge_block, &[BlockArg::Value(zero)]);
+        } else {
+            // true || _ is true without evaluating the right side
+            let one = builder.ins().iconst(types::I64, 1);
+            builder
+                .ins()
+                .brif(lhs_true, merge_block, &[BlockArg::Value(one)], rhs_block, &[]);
+        }
+
+        builder.switch_to_block(rhs_block);
+        builder.seal_block(rhs_block);
+        let rhs = Self::compile_expr_with_context(
+            right, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+        )?;
+        if builder.func.dfg.value_type(rhs) == types::F64 {
+            return Err(float_error());
+        }
+        let zero = builder.ins().iconst(types::I64, 0);
+        let rhs_true = builder.ins().icmp(IntCC::NotEqual, rhs, zero);
+        let rhs_value = builder.ins().uextend(types::I64, rhs_true);
+        builder
+            .ins()
+            .jump(merge_block, &[BlockArg::Value(rhs_value)]);
+
+        builder.switch_to_block(merge_block);
+        builder.seal_block(merge_block);
+        Ok(builder.block_params(merge_block)[0])
+    }
+
     fn compile_float_binary_op(
         op: &BinaryOperator,
         lhs: Value,
@@ -425,6 +534,13 @@ impl JitCompiler {
                     "Logical operators not supported for floats".to_string(),
                 ))
             }
+            BinaryOperator::BitAnd
+            | BinaryOperator::BitOr
+            | BinaryOperator::BitXor
+            | BinaryOperator::Shl
+            | BinaryOperator::Shr => Err(JitError::UnsupportedNode(
+                "Bitwise operators not supported for floats".to_string(),
+            )),
         }
     }
 
@@ -440,6 +556,11 @@ impl JitCompiler {
             BinaryOperator::Multiply => builder.ins().imul(lhs, rhs),
             BinaryOperator::Divide => builder.ins().sdiv(lhs, rhs),
             BinaryOperator::Modulo => builder.ins().srem(lhs, rhs),
+            BinaryOperator::BitAnd => builder.ins().band(lhs, rhs),
+            BinaryOperator::BitOr => builder.ins().bor(lhs, rhs),
+            BinaryOperator::BitXor => builder.ins().bxor(lhs, rhs),
+            BinaryOperator::Shl => builder.ins().ishl(lhs, rhs),
+            BinaryOperator::Shr => builder.ins().sshr(lhs, rhs),
             BinaryOperator::Equal => {
                 let cmp = builder.ins().icmp(IntCC::Equal, lhs, rhs);
                 builder.ins().uextend(types::I64, cmp)
@@ -466,19 +587,8 @@ impl JitCompiler {
                     .icmp(IntCC::SignedGreaterThanOrEqual, lhs, rhs);
                 builder.ins().uextend(types::I64, cmp)
             }
-            BinaryOperator::And => {
-                let zero = builder.ins().iconst(types::I64, 0);
-                let lhs_bool = builder.ins().icmp(IntCC::NotEqual, lhs, zero);
-                let rhs_bool = builder.ins().icmp(IntCC::NotEqual, rhs, zero);
-                let and = builder.ins().band(lhs_bool, rhs_bool);
-                builder.ins().uextend(types::I64, and)
-            }
-            BinaryOperator::Or => {
-                let zero = builder.ins().iconst(types::I64, 0);
-                let lhs_bool = builder.ins().icmp(IntCC::NotEqual, lhs, zero);
-                let rhs_bool = builder.ins().icmp(IntCC::NotEqual, rhs, zero);
-                let or = builder.ins().bor(lhs_bool, rhs_bool);
-                builder.ins().uextend(types::I64, or)
+            BinaryOperator::And | BinaryOperator::Or => {
+                unreachable!("&& and || are compiled by compile_logical_op")
             }
         }
     }
@@ -495,6 +605,14 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
+        // `!` is integer-logical, matching Value::logical_not: strings are
+        // pointers here, so reject them instead of negating the address
+        if *op == UnaryOperator::Not && Self::is_string_expr(operand, local_vars, string_ctx) {
+            return Err(JitError::CompilationFailed(
+                "type mismatch in logical_not: expected Boolean or Integer, found String".to_string(),
+            ));
+        }
+
         let value = Self::compile_expr_with_context(
             operand, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
@@ -509,6 +627,12 @@ impl JitCompiler {
                 }
             }
             UnaryOperator::Not => {
+                // 0 is false, any other integer is true; result is 0/1
+                if builder.func.dfg.value_type(value) == types::F64 {
+                    return Err(JitError::CompilationFailed(
+                        "type mismatch in logical_not: expected Boolean or Integer, found Float".to_string(),
+                    ));
+                }
                 let zero = builder.ins().iconst(types::I64, 0);
                 let is_zero = builder.ins().icmp(IntCC::Equal, value, zero);
                 builder.ins().uextend(types::I64, is_zero)
@@ -523,6 +647,31 @@ impl JitCompiler {
         Ok(result)
     }
 
+    /// Compile `break`/`continue` as a jump to the innermost loop's exit or continue block
+    ///
+    /// Labeled jumps are not supported by the parser, so only the innermost loop is targeted.
+    fn compile_loop_jump(
+        keyword: &str,
+        builder: &mut FunctionBuilder,
+        string_ctx: &mut StringContext,
+    ) -> Result<Value, JitError> {
+        let Some(&(continue_target, break_target)) = string_ctx.loops.last() else {
+            return Err(JitError::UnsupportedNode(format!(
+                "'{}' outside of a loop",
+                keyword
+            )));
+        };
+        let target = if keyword == "break" { break_target } else { continue_target };
+        builder.ins().jump(target, &[]);
+
+        // Anything after the jump is dead code; give it its own block so it stays well-formed
+        let after_jump = builder.create_block();
+        builder.switch_to_block(after_jump);
+        builder.seal_block(after_jump);
+
+        Ok(builder.ins().iconst(types::I64, 0))
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn compile_while_loop(
         condition: &AstNode,
@@ -555,11 +704,13 @@ impl JitCompiler {
         builder.switch_to_block(loop_body);
         builder.seal_block(loop_body);
 
+        string_ctx.loops.push((loop_header, loop_exit));
         for stmt in body {
             Self::compile_expr_with_context(
                 stmt, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
             )?;
         }
+        string_ctx.loops.pop();
 
         builder.ins().jump(loop_header, &[]);
         builder.seal_block(loop_header);
@@ -567,6 +718,7 @@ impl JitCompiler {
         builder.switch_to_block(loop_exit);
         builder.seal_block(loop_exit);
 
+        // Loops evaluate to nil, which the JIT represents as 0
         let result = builder.ins().iconst(types::I64, 0);
         Ok(result)
     }
@@ -584,8 +736,8 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
-        let (start_expr, end_expr) = match iterable {
-            AstNode::Range { start, end } => (start, end),
+        let (start_expr, end_expr, inclusive) = match iterable {
+            AstNode::Range { start, end, inclusive } => (start, end, *inclusive),
             _ => {
                 return Err(JitError::UnsupportedNode(
                     "For loop iterable must be a Range".to_string(),
@@ -603,6 +755,7 @@ impl JitCompiler {
 
         let loop_header = builder.create_block();
         let loop_body = builder.create_block();
+        let loop_increment = builder.create_block();
         let loop_exit = builder.create_block();
 
         builder.ins().jump(loop_header, &[]);
@@ -615,9 +768,12 @@ impl JitCompiler {
             end_expr, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
 
-        let condition = builder
-            .ins()
-            .icmp(IntCC::SignedLessThan, current_var, end_value);
+        let cond_code = if inclusive {
+            IntCC::SignedLessThanOrEqual
+        } else {
+            IntCC::SignedLessThan
+        };
+        let condition = builder.ins().icmp(cond_code, current_var, end_value);
 
         builder
             .ins()
@@ -626,11 +782,18 @@ impl JitCompiler {
         builder.switch_to_block(loop_body);
         builder.seal_block(loop_body);
 
+        // `continue` must still advance the loop variable, so it targets the increment
+        string_ctx.loops.push((loop_increment, loop_exit));
         for stmt in body {
             Self::compile_expr_with_context(
                 stmt, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
             )?;
         }
+        string_ctx.loops.pop();
+
+        builder.ins().jump(loop_increment, &[]);
+        builder.switch_to_block(loop_increment);
+        builder.seal_block(loop_increment);
 
         let current_var = builder.use_var(loop_var);
         let one = builder.ins().iconst(types::I64, 1);
@@ -643,6 +806,7 @@ impl JitCompiler {
         builder.switch_to_block(loop_exit);
         builder.seal_block(loop_exit);
 
+        // Loops evaluate to nil, which the JIT represents as 0
         let result = builder.ins().iconst(types::I64, 0);
         Ok(result)
     }
@@ -751,10 +915,6 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
-        let func_ptr = compiled_functions.get(name).ok_or_else(|| {
-            JitError::UnsupportedNode(format!("Function '{}' not registered", name))
-        })?;
-
         let mut arg_values = Vec::new();
         for arg in args {
             let arg_value = Self::compile_expr_with_context(
@@ -763,6 +923,25 @@ impl JitCompiler {
             arg_values.push(arg_value);
         }
 
+        // JIT-031: a call to the function being compiled is a direct self-call
+        if let Some((_, func_ref)) = string_ctx.current_function.filter(|(current, _)| *current == name) {
+            let expected = builder.func.signature.params.len();
+            if args.len() != expected {
+                return Err(JitError::CompilationFailed(format!(
+                    "'{}' expects {} arguments, got {}",
+                    name,
+                    expected,
+                    args.len()
+                )));
+            }
+            let call = builder.ins().call(func_ref, &arg_values);
+            return Ok(builder.inst_results(call)[0]);
+        }
+
+        let func_ptr = compiled_functions.get(name).ok_or_else(|| {
+            JitError::UnsupportedNode(format!("Function '{}' not registered", name))
+        })?;
+
         let mut sig = Signature::new(builder.func.signature.call_conv);
         for _ in 0..args.len() {
             sig.params.push(AbiParam::new(types::I64));
@@ -790,18 +969,7 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
-        if elements.is_empty() {
-            return Ok(builder.ins().iconst(types::I64, 0));
-        }
-
-        let array_size = elements.len() * 8;
-        let stack_slot = builder.create_sized_stack_slot(StackSlotData::new(
-            StackSlotKind::ExplicitSlot,
-            array_size as u32,
-            3,
-        ));
-
-        let array_addr = builder.ins().stack_addr(types::I64, stack_slot, 0);
+        let array_addr = Self::alloc_stack_array(elements.len(), builder);
 
         for (i, elem) in elements.iter().enumerate() {
             let elem_value = Self::compile_expr_with_context(
@@ -818,6 +986,50 @@ impl JitCompiler {
         Ok(array_addr)
     }
 
+    /// Stack slot for a `len`-element array with its length header (JIT-035)
+    ///
+    /// Returns the address of element 0. Empty arrays still get one element
+    /// word so the HashMap magic check in index access stays in the slot.
+    fn alloc_stack_array(len: usize, builder: &mut FunctionBuilder) -> Value {
+        let header = super::ARRAY_HEADER_BYTES;
+        let slot_size = header as usize + len.max(1) * 8;
+        let stack_slot = builder.create_sized_stack_slot(StackSlotData::new(
+            StackSlotKind::ExplicitSlot,
+            slot_size as u32,
+            3,
+        ));
+
+        let len_value = builder.ins().iconst(types::I64, len as i64);
+        builder.ins().stack_store(len_value, stack_slot, 0);
+        builder.ins().stack_addr(types::I64, stack_slot, header)
+    }
+
+    /// Trap unless `0 <= index < len` when bounds checks are on (JIT-035)
+    ///
+    /// The unsigned comparison also catches negative indices.
+    fn check_array_bounds(
+        array_addr: Value,
+        index: Value,
+        builder: &mut FunctionBuilder,
+        string_ctx: &StringContext,
+    ) {
+        if !string_ctx.bounds_checks {
+            return;
+        }
+        let len = builder.ins().load(
+            types::I64,
+            MemFlags::trusted(),
+            array_addr,
+            -super::ARRAY_HEADER_BYTES,
+        );
+        let out_of_bounds = builder
+            .ins()
+            .icmp(IntCC::UnsignedGreaterThanOrEqual, index, len);
+        builder
+            .ins()
+            .trapnz(out_of_bounds, TrapCode::HEAP_OUT_OF_BOUNDS);
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn compile_tuple_literal(
         elements: &[AstNode],
@@ -974,6 +1186,7 @@ impl JitCompiler {
         builder.switch_to_block(array_block);
         builder.seal_block(array_block);
         {
+            Self::check_array_bounds(coll_addr, key_value, builder, string_ctx);
             let eight = builder.ins().iconst(types::I64, 8);
             let byte_offset = builder.ins().imul(key_value, eight);
             let elem_addr = builder.ins().iadd(coll_addr, byte_offset);
@@ -1039,6 +1252,8 @@ impl JitCompiler {
             index, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
 
+        Self::check_array_bounds(array_addr, index_value, builder, string_ctx);
+
         let eight = builder.ins().iconst(types::I64, 8);
         let byte_offset = builder.ins().imul(index_value, eight);
         let elem_addr = builder.ins().iadd(array_addr, byte_offset);
@@ -1306,6 +1521,12 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
+        if arms.iter().any(|arm| arm.guard.is_some()) {
+            return Err(JitError::UnsupportedNode(
+                "match guards are not supported by the JIT".to_string(),
+            ));
+        }
+
         let match_value = Self::compile_expr_with_context(
             expr, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
@@ -1326,25 +1547,14 @@ impl JitCompiler {
                 builder.switch_to_block(block);
             }
 
-            match &arm.pattern {
-                Pattern::Literal(pattern_ast) => {
-                    let pattern_value = Self::compile_expr_with_context(
-                        pattern_ast, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
-                    )?;
-
-                    let matches =
-                        builder.ins().icmp(IntCC::Equal, match_value, pattern_value);
-
-                    if let Some(next) = next_arm_block {
-                        builder.ins().brif(matches, arm_body_block, &[], next, &[]);
-                    } else {
-                        builder.ins().jump(arm_body_block, &[]);
-                    }
-                }
-                Pattern::Identifier(_name) => {
-                    builder.ins().jump(arm_body_block, &[]);
+            let condition = Self::compile_pattern_condition(
+                &arm.pattern, match_value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+            )?;
+            match (condition, next_arm_block) {
+                (Some(matches), Some(next)) => {
+                    builder.ins().brif(matches, arm_body_block, &[], next, &[]);
                 }
-                Pattern::Wildcard => {
+                _ => {
                     builder.ins().jump(arm_body_block, &[]);
                 }
             }
@@ -1356,7 +1566,7 @@ impl JitCompiler {
             builder.switch_to_block(arm_body_block);
             builder.seal_block(arm_body_block);
 
-            if let Pattern::Identifier(name) = &arm.pattern {
+            if let Pattern::Identifier(name) | Pattern::Binding { name, .. } = &arm.pattern {
                 let value_type = builder.func.dfg.value_type(match_value);
                 let var = builder.declare_var(value_type);
                 builder.def_var(var, match_value);
@@ -1388,6 +1598,60 @@ impl JitCompiler {
         Ok(result)
     }
 
+    /// Compile the test for a match pattern
+    ///
+    /// Returns `None` for irrefutable patterns (identifier, wildcard).
+    #[allow(clippy::too_many_arguments)]
+    fn compile_pattern_condition(
+        pattern: &Pattern,
+        match_value: Value,
+        builder: &mut FunctionBuilder,
+        parameters: &HashMap<String, Value>,
+        local_vars: &mut HashMap<String, Variable>,
+        var_counter: &mut usize,
+        compiled_functions: &HashMap<String, *const u8>,
+        string_ctx: &mut StringContext,
+        struct_defs: &mut HashMap<String, Vec<String>>,
+    ) -> Result<Option<Value>, JitError> {
+        match pattern {
+            Pattern::Literal(pattern_ast) => {
+                let pattern_value = Self::compile_expr_with_context(
+                    pattern_ast, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+                )?;
+                Ok(Some(builder.ins().icmp(IntCC::Equal, match_value, pattern_value)))
+            }
+            Pattern::Range { start, end, inclusive } => {
+                let start_value = Self::compile_expr_with_context(
+                    start, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+                )?;
+                let end_value = Self::compile_expr_with_context(
+                    end, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+                )?;
+                let above = builder
+                    .ins()
+                    .icmp(IntCC::SignedGreaterThanOrEqual, match_value, start_value);
+                let end_cc = if *inclusive {
+                    IntCC::SignedLessThanOrEqual
+                } else {
+                    IntCC::SignedLessThan
+                };
+                let below = builder.ins().icmp(end_cc, match_value, end_value);
+                Ok(Some(builder.ins().band(above, below)))
+            }
+            Pattern::Binding { pattern, .. } => Self::compile_pattern_condition(
+                pattern, match_value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
+            ),
+            Pattern::Identifier(_) | Pattern::Wildcard => Ok(None),
+            Pattern::Variant { name, .. } => Err(JitError::UnsupportedNode(format!(
+                "variant pattern '{}' (Option/Result values are interpreter-only)",
+                name
+            ))),
+            Pattern::Tuple(_) => Err(JitError::UnsupportedNode(
+                "tuple pattern (tuple values are interpreter-only)".to_string(),
+            )),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn compile_method_call(
         receiver: &AstNode,
@@ -1434,7 +1698,7 @@ impl JitCompiler {
 
     #[allow(clippy::too_many_arguments)]
     fn compile_tuple_destruct(
-        names: &[String],
+        patterns: &[Pattern],
         value: &AstNode,
         builder: &mut FunctionBuilder,
         parameters: &HashMap<String, Value>,
@@ -1448,7 +1712,26 @@ impl JitCompiler {
             value, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
 
-        for (i, name) in names.iter().enumerate() {
+        Self::bind_tuple_elements(patterns, tuple_addr, builder, local_vars)?;
+
+        Ok(builder.ins().iconst(types::I64, 0))
+    }
+
+    /// Bind the elements of the tuple at `tuple_addr` to `patterns`
+    ///
+    /// A nested tuple element holds the address of its own tuple, so nested
+    /// patterns recurse on the loaded value (INTERP-136).
+    fn bind_tuple_elements(
+        patterns: &[Pattern],
+        tuple_addr: Value,
+        builder: &mut FunctionBuilder,
+        local_vars: &mut HashMap<String, Variable>,
+    ) -> Result<(), JitError> {
+        for (i, pattern) in patterns.iter().enumerate() {
+            if let Pattern::Wildcard = pattern {
+                continue;
+            }
+
             let offset = (i * 8) as i32;
 
             let field_value =
@@ -1456,15 +1739,28 @@ impl JitCompiler {
                     .ins()
                     .load(types::I64, MemFlags::trusted(), tuple_addr, offset);
 
-            let value_type = builder.func.dfg.value_type(field_value);
-            let var = builder.declare_var(value_type);
+            match pattern {
+                Pattern::Identifier(name) => {
+                    let value_type = builder.func.dfg.value_type(field_value);
+                    let var = builder.declare_var(value_type);
 
-            builder.def_var(var, field_value);
+                    builder.def_var(var, field_value);
 
-            local_vars.insert(name.clone(), var);
+                    local_vars.insert(name.clone(), var);
+                }
+                Pattern::Tuple(inner) => {
+                    Self::bind_tuple_elements(inner, field_value, builder, local_vars)?
+                }
+                other => {
+                    return Err(JitError::UnsupportedNode(format!(
+                        "Cannot compile tuple destructuring pattern: {:?}",
+                        other
+                    )))
+                }
+            }
         }
 
-        Ok(builder.ins().iconst(types::I64, 0))
+        Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1548,24 +1844,7 @@ impl JitCompiler {
         string_ctx: &mut StringContext,
         struct_defs: &mut HashMap<String, Vec<String>>,
     ) -> Result<Value, JitError> {
-        if elements.is_empty() {
-            let stack_slot = builder.create_sized_stack_slot(StackSlotData::new(
-                StackSlotKind::ExplicitSlot,
-                8,
-                3,
-            ));
-            let array_addr = builder.ins().stack_addr(types::I64, stack_slot, 0);
-            return Ok(array_addr);
-        }
-
-        let array_size = elements.len() * 8;
-        let stack_slot = builder.create_sized_stack_slot(StackSlotData::new(
-            StackSlotKind::ExplicitSlot,
-            array_size as u32,
-            3,
-        ));
-
-        let array_addr = builder.ins().stack_addr(types::I64, stack_slot, 0);
+        let array_addr = Self::alloc_stack_array(elements.len(), builder);
 
         for (i, elem) in elements.iter().enumerate() {
             let elem_value = Self::compile_expr_with_context(
@@ -1605,14 +1884,16 @@ impl JitCompiler {
             count_expr, builder, parameters, local_vars, var_counter, compiled_functions, string_ctx, struct_defs,
         )?;
 
-        let max_size = 1024 * 8;
-        let stack_slot = builder.create_sized_stack_slot(StackSlotData::new(
-            StackSlotKind::ExplicitSlot,
-            max_size,
-            3,
-        ));
-
-        let array_addr = builder.ins().stack_addr(types::I64, stack_slot, 0);
+        // JIT-034: size the array at run time on the heap
+        let mut alloc_sig = Signature::new(builder.func.signature.call_conv);
+        alloc_sig.params.push(AbiParam::new(types::I64));
+        alloc_sig.returns.push(AbiParam::new(types::I64));
+        let alloc_sig = builder.import_signature(alloc_sig);
+        let alloc_addr = builder
+            .ins()
+            .iconst(types::I64, super::jit_alloc_words as *const u8 as i64);
+        let call = builder.ins().call_indirect(alloc_sig, alloc_addr, &[count]);
+        let array_addr = builder.inst_results(call)[0];
 
         let loop_header = builder.create_block();
         let loop_body = builder.create_block();
@@ -8,6 +8,18 @@
 // 3. Compilation: Cranelift IR → Machine code
 // 4. Execution: Call compiled code via function pointer
 //
+// Booleans compile to the integers 0 and 1, so arithmetic on them is C-style
+// (`true + true` is 2). The interpreter rejects that by default; see
+// `Evaluator::with_bool_arithmetic` for the matching opt-in.
+//
+// Function parameters and return values are i64 unless declared otherwise
+// with `compile_function_with_types` (JIT-030): a `JitType::Float` slot uses
+// the native f64 ABI, so the function can be called as `fn(f64, f64) -> f64`.
+//
+// `ObjectCompiler` (JIT-033) runs the same code generator into an object
+// file instead of memory; see `object.rs` for the subset it supports.
+// Enable with `--features object-output`.
+//
 // References:
 // - Cranelift documentation: https://cranelift.dev/
 // - JIT compilation techniques: Aycock (2003) "A Brief History of Just-In-Time"
@@ -15,10 +27,15 @@
 
 use cranelift::prelude::*;
 use cranelift_jit::{JITBuilder, JITModule};
-use cranelift_module::{Linkage, Module};
-use std::collections::HashMap;
+use cranelift_module::{FuncId, Linkage, Module};
+use std::collections::{HashMap, HashSet};
 
 mod compiler;
+#[cfg(feature = "object-output")]
+mod object;
+
+#[cfg(feature = "object-output")]
+pub use object::ObjectCompiler;
 
 use crate::interpreter::parser::AstNode;
 
@@ -45,12 +62,41 @@ impl std::fmt::Display for JitError {
 
 impl std::error::Error for JitError {}
 
+/// Type of a JIT function parameter or return value (JIT-030)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JitType {
+    /// 64-bit integer (also booleans, strings and other pointers)
+    Int,
+    /// 64-bit float, passed in float registers
+    Float,
+}
+
+impl JitType {
+    /// The Cranelift type for values of this type
+    fn cranelift_type(self) -> Type {
+        match self {
+            JitType::Int => types::I64,
+            JitType::Float => types::F64,
+        }
+    }
+}
+
 /// String compilation context (passed through compilation)
 pub(crate) struct StringContext<'a> {
     /// String literals storage (kept alive for JIT lifetime)
     pub(crate) literals: &'a mut Vec<Box<[u8]>>,
     /// String interning map (content → pointer)
     pub(crate) intern: &'a mut HashMap<String, i64>,
+    /// Enclosing loops' (continue, break) targets, innermost last
+    pub(crate) loops: Vec<(Block, Block)>,
+    /// Name and self-reference of the function being compiled, so calls
+    /// to it resolve before it is finalized (JIT-031)
+    pub(crate) current_function: Option<(&'a str, codegen::ir::FuncRef)>,
+    /// Check array indices against the length header (JIT-035)
+    pub(crate) bounds_checks: bool,
+    /// Locals currently holding a string (by `Variable::as_u32`), which `!`
+    /// rejects (JIT-026)
+    pub(crate) string_locals: HashSet<u32>,
 }
 
 impl<'a> StringContext<'a> {
@@ -104,6 +150,8 @@ pub struct JitCompiler {
     pub(crate) string_intern: HashMap<String, i64>,
     /// Struct definitions (name → ordered field names)
     pub(crate) struct_defs: HashMap<String, Vec<String>>,
+    /// Trap on out-of-range array indices (JIT-035)
+    pub(crate) bounds_checks: bool,
 }
 
 impl JitCompiler {
@@ -122,9 +170,20 @@ impl JitCompiler {
             string_literals: Vec::new(),
             string_intern: HashMap::new(),
             struct_defs: HashMap::new(),
+            bounds_checks: false,
         })
     }
 
+    /// Enable or disable array bounds checks for functions compiled from now on
+    ///
+    /// When enabled, indexing an array (`v[i]`, `v[i] += x`) with `i < 0` or
+    /// `i >= len` executes a Cranelift trap (`heap_oob`) instead of reading
+    /// or writing outside the array. The trap is not recoverable: it raises
+    /// SIGILL/SIGTRAP and terminates the process. Off by default.
+    pub fn set_bounds_checks(&mut self, enabled: bool) {
+        self.bounds_checks = enabled;
+    }
+
     /// Register a compiled function for use in function calls
     ///
     /// This allows JIT-compiled code to call other JIT-compiled functions
@@ -168,14 +227,26 @@ impl JitCompiler {
             let mut string_ctx = StringContext {
                 literals: &mut self.string_literals,
                 intern: &mut self.string_intern,
+                loops: Vec::new(),
+                current_function: None,
+                bounds_checks: self.bounds_checks,
+                string_locals: HashSet::new(),
             };
-            let result = Self::compile_expr(
+            let result = match Self::compile_expr(
                 ast,
                 &mut builder,
                 &self.compiled_functions,
                 &mut string_ctx,
                 &mut self.struct_defs,
-            )?;
+            ) {
+                Ok(value) => value,
+                Err(e) => {
+                    // A half-built function leaves the shared builder context
+                    // dirty; reset it so later compilations start clean
+                    self.builder_context = FunctionBuilderContext::new();
+                    return Err(e);
+                }
+            };
 
             // Return the result
             builder.ins().return_(&[result]);
@@ -217,87 +288,97 @@ impl JitCompiler {
         param_names: &[String],
         body: &AstNode,
     ) -> Result<T, JitError> {
+        let param_types = vec![JitType::Int; param_names.len()];
+        self.compile_function_with_types(param_names, &param_types, JitType::Int, body)
+    }
+
+    /// Compile a named function that may call itself (JIT-031)
+    ///
+    /// Calls to `name` inside `body` go straight to the function being
+    /// compiled, so `fun factorial(n) { .. factorial(n - 1) }` works without
+    /// registering it first. The finished function is also registered under
+    /// `name` for later compilations to call. Every slot is i64, as with
+    /// `compile_function_with_params`.
+    pub fn compile_named_function<T>(
+        &mut self,
+        name: &str,
+        param_names: &[String],
+        body: &AstNode,
+    ) -> Result<T, JitError> {
+        let param_types = vec![JitType::Int; param_names.len()];
+        let code_ptr =
+            self.compile_function_impl(Some(name), param_names, &param_types, JitType::Int, body)?;
+        self.compiled_functions.insert(name.to_string(), code_ptr);
+
+        let func: T = unsafe { std::mem::transmute_copy(&code_ptr) };
+        Ok(func)
+    }
+
+    /// Compile function with declared parameter and return types (JIT-030)
+    ///
+    /// Like `compile_function_with_params`, but each `JitType::Float` slot is
+    /// an f64 in the signature: `fun avg(a, b) -> f64 { (a + b) / 2.0 }` with
+    /// `[Float, Float]` and `Float` is called as `fn(f64, f64) -> f64`. An
+    /// integer result returned from a float function is converted to f64.
+    pub fn compile_function_with_types<T>(
+        &mut self,
+        param_names: &[String],
+        param_types: &[JitType],
+        return_type: JitType,
+        body: &AstNode,
+    ) -> Result<T, JitError> {
+        let code_ptr =
+            self.compile_function_impl(None, param_names, param_types, return_type, body)?;
+
+        // Return as generic function pointer (caller must cast to correct type)
+        let func: T = unsafe { std::mem::transmute_copy(&code_ptr) };
+        Ok(func)
+    }
+
+    /// Compile a function body to machine code and return its address
+    ///
+    /// `name`, when given, lets the body call the function recursively.
+    fn compile_function_impl(
+        &mut self,
+        name: Option<&str>,
+        param_names: &[String],
+        param_types: &[JitType],
+        return_type: JitType,
+        body: &AstNode,
+    ) -> Result<*const u8, JitError> {
+        if param_types.len() != param_names.len() {
+            return Err(JitError::CompilationFailed(format!(
+                "{} parameter types given for {} parameters",
+                param_types.len(),
+                param_names.len()
+            )));
+        }
+
         // Create unique function name
         let func_name = format!("__jit_func_{}", self.function_counter);
         self.function_counter += 1;
 
-        // Create function signature: (i64, i64, ...) -> i64
-        let mut sig = self.module.make_signature();
-        for _ in param_names {
-            sig.params.push(AbiParam::new(types::I64));
-        }
-        sig.returns.push(AbiParam::new(types::I64));
-
-        // Declare the function
+        // Declare the function with a signature from the declared types
+        let sig = function_signature(&self.module, param_types, return_type);
         let func_id = self
             .module
             .declare_function(&func_name, Linkage::Export, &sig)
             .map_err(|e| JitError::ModuleError(e.to_string()))?;
 
-        // Create compilation context
-        let mut ctx = self.module.make_context();
-        ctx.func.signature = sig;
-
-        // Build function body
-        {
-            let mut builder = FunctionBuilder::new(&mut ctx.func, &mut self.builder_context);
-
-            // Create entry block
-            let entry_block = builder.create_block();
-
-            // Add block parameters for function parameters
-            builder.append_block_params_for_function_params(entry_block);
-
-            builder.switch_to_block(entry_block);
-            builder.seal_block(entry_block);
-
-            // Get parameter values from the entry block
-            let param_values: Vec<Value> = (0..param_names.len())
-                .map(|i| builder.block_params(entry_block)[i])
-                .collect();
-
-            // Create variable map (parameter name → Cranelift value)
-            let mut variables = HashMap::new();
-            for (name, &value) in param_names.iter().zip(param_values.iter()) {
-                variables.insert(name.clone(), value);
-            }
-
-            // Compile body expression with variable context
-            let mut string_ctx = StringContext {
-                literals: &mut self.string_literals,
-                intern: &mut self.string_intern,
-            };
-            let result = Self::compile_expr_with_vars(
-                body,
-                &mut builder,
-                &variables,
-                &self.compiled_functions,
-                &mut string_ctx,
-                &mut self.struct_defs,
-            )?;
-
-            // Check if body ends with explicit return (to avoid double-return error)
-            let has_explicit_return = match body {
-                AstNode::Return { .. } => true,
-                AstNode::Block { statements } => statements
-                    .last()
-                    .is_some_and(|stmt| matches!(stmt, AstNode::Return { .. })),
-                _ => false,
-            };
-
-            // Return the result (only if no explicit return in body)
-            if !has_explicit_return {
-                builder.ins().return_(&[result]);
-            }
-
-            // Finalize function
-            builder.finalize();
-        }
-
-        // Define the function in the module
-        self.module
-            .define_function(func_id, &mut ctx)
-            .map_err(|e| JitError::CompilationFailed(e.to_string()))?;
+        define_function(
+            &mut self.module,
+            &mut self.builder_context,
+            func_id,
+            sig,
+            name,
+            param_names,
+            body,
+            &self.compiled_functions,
+            &mut self.string_literals,
+            &mut self.string_intern,
+            &mut self.struct_defs,
+            self.bounds_checks,
+        )?;
 
         // Finalize definitions (compile to machine code)
         self.module
@@ -310,9 +391,155 @@ impl JitCompiler {
         // Cache the compiled function
         self.compiled_functions.insert(func_name.clone(), code_ptr);
 
-        // Return as generic function pointer (caller must cast to correct type)
-        let func: T = unsafe { std::mem::transmute_copy(&code_ptr) };
-        Ok(func)
+        Ok(code_ptr)
+    }
+}
+
+/// Bytes before an array's first element that hold its length (JIT-035)
+///
+/// Array values point at element 0; the length word sits just below it so
+/// element offsets are unchanged and bounds checks can read it.
+pub(crate) const ARRAY_HEADER_BYTES: i32 = 8;
+
+/// Allocate a length-prefixed array of `count` zeroed words for
+/// `vec![value; count]` (JIT-034)
+///
+/// Returns a pointer to element 0 with `count` stored in the header word
+/// below it (JIT-035). Called from JIT code. The MVP never frees: arrays
+/// are returned and indexed as raw pointers with no owner, so each
+/// allocation is leaked. A non-positive `count` stores length 0 but still
+/// gets one element word, keeping the pointer valid.
+pub(crate) extern "C" fn jit_alloc_words(count: i64) -> i64 {
+    let len = usize::try_from(count).unwrap_or(0);
+    let words = len.max(1) + 1;
+    let layout = match std::alloc::Layout::array::<i64>(words) {
+        Ok(layout) => layout,
+        Err(_) => std::alloc::handle_alloc_error(std::alloc::Layout::new::<i64>()),
+    };
+    // SAFETY: the layout has a non-zero size
+    let ptr = unsafe { std::alloc::alloc_zeroed(layout) } as *mut i64;
+    if ptr.is_null() {
+        std::alloc::handle_alloc_error(layout);
+    }
+    // SAFETY: the allocation holds at least two words
+    unsafe {
+        ptr.write(len as i64);
+        ptr.add(1) as i64
+    }
+}
+
+/// Signature with one parameter per declared type and a single result
+fn function_signature<M: Module>(
+    module: &M,
+    param_types: &[JitType],
+    return_type: JitType,
+) -> Signature {
+    let mut sig = module.make_signature();
+    for param_type in param_types {
+        sig.params.push(AbiParam::new(param_type.cranelift_type()));
+    }
+    sig.returns.push(AbiParam::new(return_type.cranelift_type()));
+    sig
+}
+
+/// Build `body` and define it as `func_id` in `module`
+///
+/// Shared by the in-memory JIT and `ObjectCompiler` (JIT-033): both lower
+/// the AST with `compile_expr_with_vars`. `self_name` lets the body call
+/// the function recursively (JIT-031); `bounds_checks` turns on array
+/// index checks (JIT-035).
+#[allow(clippy::too_many_arguments)]
+fn define_function<M: Module>(
+    module: &mut M,
+    builder_context: &mut FunctionBuilderContext,
+    func_id: FuncId,
+    sig: Signature,
+    self_name: Option<&str>,
+    param_names: &[String],
+    body: &AstNode,
+    compiled_functions: &HashMap<String, *const u8>,
+    string_literals: &mut Vec<Box<[u8]>>,
+    string_intern: &mut HashMap<String, i64>,
+    struct_defs: &mut HashMap<String, Vec<String>>,
+    bounds_checks: bool,
+) -> Result<(), JitError> {
+    // Create compilation context
+    let mut ctx = module.make_context();
+    ctx.func.signature = sig;
+
+    // Build function body
+    {
+        let mut builder = FunctionBuilder::new(&mut ctx.func, builder_context);
+
+        // Create entry block
+        let entry_block = builder.create_block();
+
+        // Add block parameters for function parameters
+        builder.append_block_params_for_function_params(entry_block);
+
+        builder.switch_to_block(entry_block);
+        builder.seal_block(entry_block);
+
+        // Get parameter values from the entry block
+        let param_values: Vec<Value> = (0..param_names.len())
+            .map(|i| builder.block_params(entry_block)[i])
+            .collect();
+
+        // Create variable map (parameter name → Cranelift value)
+        let mut variables = HashMap::new();
+        for (name, &value) in param_names.iter().zip(param_values.iter()) {
+            variables.insert(name.clone(), value);
+        }
+
+        // Compile body expression with variable context
+        let current_function =
+            self_name.map(|name| (name, module.declare_func_in_func(func_id, builder.func)));
+        let mut string_ctx = StringContext {
+            literals: string_literals,
+            intern: string_intern,
+            loops: Vec::new(),
+            current_function,
+            bounds_checks,
+            string_locals: HashSet::new(),
+        };
+        let result = match JitCompiler::compile_expr_with_vars(
+            body,
+            &mut builder,
+            &variables,
+            compiled_functions,
+            &mut string_ctx,
+            struct_defs,
+        ) {
+            Ok(value) => value,
+            Err(e) => {
+                // A half-built function leaves the shared builder context
+                // dirty; reset it so later compilations start clean
+                *builder_context = FunctionBuilderContext::new();
+                return Err(e);
+            }
+        };
+
+        // Check if body ends with explicit return (to avoid double-return error)
+        let has_explicit_return = match body {
+            AstNode::Return { .. } => true,
+            AstNode::Block { statements } => statements
+                .last()
+                .is_some_and(|stmt| matches!(stmt, AstNode::Return { .. })),
+            _ => false,
+        };
+
+        // Return the result (only if no explicit return in body)
+        if !has_explicit_return {
+            let result = JitCompiler::coerce_to_return_type(result, &mut builder);
+            builder.ins().return_(&[result]);
+        }
+
+        // Finalize function
+        builder.finalize();
     }
 
+    // Define the function in the module
+    module
+        .define_function(func_id, &mut ctx)
+        .map_err(|e| JitError::CompilationFailed(e.to_string()))
 }
@@ -0,0 +1,190 @@
+// JIT-033: Ahead-of-Time Object File Output
+//
+// **Note**: Enable with `--features object-output`.
+//
+// ObjectCompiler lowers AST functions with the same code generator as the
+// JIT, but into a `cranelift_object::ObjectModule` for the host target, and
+// writes a relocatable object file (ELF on Linux, Mach-O on macOS) that a C
+// program can link against:
+//
+//     // main.c: long add(long a, long b);  int main(void) { return add(2, 3); }
+//     // cc main.c add.o -o demo
+//
+// Supported subset (everything must be self-contained in the object):
+// - Integer and float arithmetic, comparisons, bitwise and logical operators
+// - let, assignment, if/else, while, for over ranges, loop, break, continue,
+//   return, match on integers
+// - Stack-allocated arrays, tuples and structs used within the function
+// - Calls to the function itself (recursion)
+//
+// Not supported: string literals, f-strings and `vec![x; n]` (the JIT
+// embeds host addresses for them), and calls to any other function or method.
+
+use std::path::Path;
+
+use cranelift::prelude::*;
+use cranelift_module::{Linkage, Module};
+use cranelift_object::{ObjectBuilder, ObjectModule};
+use std::collections::HashMap;
+
+use super::{define_function, function_signature, JitError, JitType};
+use crate::interpreter::parser::AstNode;
+use crate::interpreter::visitor::{walk_node, Visitor};
+
+/// Compiles AST functions into a native object file
+///
+/// # Example
+/// ```no_run
+/// use ruchyruchy::interpreter::parser::{AstNode, BinaryOperator};
+/// use ruchyruchy::jit::ObjectCompiler;
+///
+/// // fun add(a, b) { a + b }
+/// let body = AstNode::BinaryOp {
+///     left: Box::new(AstNode::Identifier("a".to_string())),
+///     op: BinaryOperator::Add,
+///     right: Box::new(AstNode::Identifier("b".to_string())),
+/// };
+/// let mut compiler = ObjectCompiler::new("add").unwrap();
+/// compiler
+///     .compile_function("add", &["a".to_string(), "b".to_string()], &body)
+///     .unwrap();
+/// compiler.compile_to_object(std::path::Path::new("add.o")).unwrap();
+/// ```
+pub struct ObjectCompiler {
+    /// Cranelift object module for the host target
+    module: ObjectModule,
+    /// Function builder context (reused across compilations)
+    builder_context: FunctionBuilderContext,
+    /// Required by the shared code generator; always empty here
+    string_literals: Vec<Box<[u8]>>,
+    /// Required by the shared code generator; always empty here
+    string_intern: HashMap<String, i64>,
+    /// Struct definitions (name → ordered field names)
+    struct_defs: HashMap<String, Vec<String>>,
+}
+
+impl ObjectCompiler {
+    /// Create a compiler for an object named `object_name`, targeting the host
+    pub fn new(object_name: &str) -> Result<Self, JitError> {
+        let mut flags = settings::builder();
+        // Position-independent code links into PIE executables
+        flags
+            .set("is_pic", "true")
+            .map_err(|e| JitError::ModuleError(e.to_string()))?;
+        let isa = cranelift_native::builder()
+            .map_err(|e| JitError::ModuleError(e.to_string()))?
+            .finish(settings::Flags::new(flags))
+            .map_err(|e| JitError::ModuleError(e.to_string()))?;
+
+        let builder = ObjectBuilder::new(
+            isa,
+            object_name,
+            cranelift_module::default_libcall_names(),
+        )
+        .map_err(|e| JitError::ModuleError(e.to_string()))?;
+
+        Ok(Self {
+            module: ObjectModule::new(builder),
+            builder_context: FunctionBuilderContext::new(),
+            string_literals: Vec::new(),
+            string_intern: HashMap::new(),
+            struct_defs: HashMap::new(),
+        })
+    }
+
+    /// Compile an exported function `name` whose parameters and result are i64
+    pub fn compile_function(
+        &mut self,
+        name: &str,
+        param_names: &[String],
+        body: &AstNode,
+    ) -> Result<(), JitError> {
+        let param_types = vec![JitType::Int; param_names.len()];
+        self.compile_function_with_types(name, param_names, &param_types, JitType::Int, body)
+    }
+
+    /// Compile an exported function with declared parameter and return types
+    ///
+    /// `JitType::Float` slots use the C `double` ABI, as in
+    /// `JitCompiler::compile_function_with_types`.
+    pub fn compile_function_with_types(
+        &mut self,
+        name: &str,
+        param_names: &[String],
+        param_types: &[JitType],
+        return_type: JitType,
+        body: &AstNode,
+    ) -> Result<(), JitError> {
+        if param_types.len() != param_names.len() {
+            return Err(JitError::CompilationFailed(format!(
+                "{} parameter types given for {} parameters",
+                param_types.len(),
+                param_names.len()
+            )));
+        }
+        if let Some(found) = host_only_node(body) {
+            return Err(JitError::UnsupportedNode(format!(
+                "{} cannot be compiled to an object file",
+                found
+            )));
+        }
+
+        let sig = function_signature(&self.module, param_types, return_type);
+        let func_id = self
+            .module
+            .declare_function(name, Linkage::Export, &sig)
+            .map_err(|e| JitError::ModuleError(e.to_string()))?;
+
+        define_function(
+            &mut self.module,
+            &mut self.builder_context,
+            func_id,
+            sig,
+            Some(name),
+            param_names,
+            body,
+            &HashMap::new(),
+            &mut self.string_literals,
+            &mut self.string_intern,
+            &mut self.struct_defs,
+            false,
+        )
+    }
+
+    /// Write every compiled function to the object file at `path`
+    pub fn compile_to_object(self, path: &Path) -> Result<(), JitError> {
+        let bytes = self
+            .module
+            .finish()
+            .emit()
+            .map_err(|e| JitError::ModuleError(e.to_string()))?;
+        std::fs::write(path, bytes)
+            .map_err(|e| JitError::ModuleError(format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// The first node in `body` whose JIT code embeds a host address, if any
+fn host_only_node(body: &AstNode) -> Option<&'static str> {
+    /// Finds string literals, f-strings and heap-allocated arrays
+    struct HostOnlyScan {
+        found: Option<&'static str>,
+    }
+
+    impl Visitor for HostOnlyScan {
+        fn visit_node(&mut self, node: &AstNode) {
+            match node {
+                AstNode::StringLiteral(_) => self.found = Some("string literal"),
+                AstNode::FString { .. } => self.found = Some("f-string"),
+                AstNode::VecMacro {
+                    repeat_count: Some(_),
+                    ..
+                } => self.found = Some("vec![value; count]"),
+                _ => walk_node(self, node),
+            }
+        }
+    }
+
+    let mut scan = HostOnlyScan { found: None };
+    scan.visit_node(body);
+    scan.found
+}
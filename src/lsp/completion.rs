@@ -1,7 +1,12 @@
 // Code Completion Provider
 // Provides intelligent code suggestions
+//
+// Results combine keywords, types, builtins, and the functions and variables
+// defined in the document, sorted by kind then label and de-duplicated so the
+// list is identical on every request.
 
 use super::protocol::{CompletionItem, CompletionItemKind, Position};
+use super::symbols::{SymbolKind, SymbolTable};
 
 /// Completion provider
 pub struct CompletionProvider;
@@ -13,11 +18,10 @@ impl CompletionProvider {
     }
 
     /// Get completion items at a given position
-    pub fn get_completions(
-        &self,
-        _document_text: &str,
-        _position: Position,
-    ) -> Vec<CompletionItem> {
+    ///
+    /// Sorted by kind then label; when a label repeats within a kind (e.g. a
+    /// document function shadowing a builtin) only the first is kept.
+    pub fn get_completions(&self, document_text: &str, _position: Position) -> Vec<CompletionItem> {
         let mut items = Vec::new();
 
         // Add keyword completions
@@ -29,9 +33,39 @@ impl CompletionProvider {
         // Add function completions
         items.extend(self.get_function_completions());
 
+        // Add functions and variables defined in the document
+        items.extend(self.get_document_completions(document_text));
+
+        // Stable sort: builtins stay ahead of same-named document symbols
+        items.sort_by(|a, b| (a.kind, &a.label).cmp(&(b.kind, &b.label)));
+        items.dedup_by(|a, b| a.kind == b.kind && a.label == b.label);
         items
     }
 
+    /// Get completions for symbols defined in the document
+    fn get_document_completions(&self, document_text: &str) -> Vec<CompletionItem> {
+        let mut table = SymbolTable::new();
+        table.parse_document("", document_text);
+        table
+            .symbols()
+            .into_iter()
+            .filter_map(|symbol| {
+                let (kind, detail) = match symbol.kind {
+                    SymbolKind::Function => (
+                        CompletionItemKind::Function,
+                        "Function defined in this document",
+                    ),
+                    SymbolKind::Variable => (
+                        CompletionItemKind::Variable,
+                        "Variable defined in this document",
+                    ),
+                    SymbolKind::Type | SymbolKind::Constant => return None,
+                };
+                Some(CompletionItem::new(symbol.name.clone(), kind).with_detail(detail.to_string()))
+            })
+            .collect()
+    }
+
     /// Get keyword completions
     fn get_keyword_completions(&self) -> Vec<CompletionItem> {
         vec![
@@ -212,6 +246,44 @@ mod tests {
         assert!(functions.iter().any(|f| f.label == "print"));
     }
 
+    #[test]
+    fn test_completions_sorted_and_deduplicated() {
+        let provider = CompletionProvider::new();
+        let program = "fun square(n) { n * n }\nlet total = 0\nfun area(w, h) { w * h }\nlet mut count = 1\nfun println(x) { x }\nlet total = 2";
+
+        let completions = provider.get_completions(program, Position::new(0, 0));
+        let labels = |kind| {
+            completions
+                .iter()
+                .filter(|c| c.kind == Some(kind))
+                .map(|c| c.label.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        // User-defined println does not duplicate the builtin
+        assert_eq!(
+            labels(CompletionItemKind::Function),
+            vec!["area", "print", "println", "square"]
+        );
+        assert_eq!(labels(CompletionItemKind::Variable), vec!["count", "total"]);
+
+        // Kinds are grouped in protocol order, labels sorted within each kind
+        let keys: Vec<_> = completions
+            .iter()
+            .map(|c| (c.kind, c.label.clone()))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+
+        // Same order on every request
+        assert_eq!(
+            completions,
+            provider.get_completions(program, Position::new(0, 0))
+        );
+    }
+
     #[test]
     fn test_completion_has_details() {
         let provider = CompletionProvider::new();
@@ -0,0 +1,1202 @@
+// Document Formatting
+// Pretty-prints Ruchy source (textDocument/formatting)
+//
+// The document is parsed with `Parser` and the AST printed back with 4-space
+// indentation, one statement per line and single spaces around binary
+// operators. Single blank lines between statements are kept, and top-level
+// declarations are always separated by one.
+//
+// The parser drops comments, so they are recovered from the source and
+// re-attached by line: a comment on its own line goes before the next
+// statement (or match arm), one after code stays at the end of that
+// statement's first line, and one after the last statement of a block stays
+// at the end of the block.
+//
+// Expressions are printed by `AstNode::emit`, which owns the precedence and
+// parenthesization rules; the printer only lays out the closures, blocks and
+// statements nested in them.
+//
+// Some syntax has no AST representation: `&` outside type annotations (as in
+// `&self`), `mut` in tuple and refutable bindings, and attributes other than
+// `#[test]`. Printing such a document would delete it, so the result is
+// checked against the source (same AST, same tokens apart from punctuation,
+// same comments) and the source is returned unchanged when the check fails.
+// Documents that do not parse are returned unchanged too.
+
+use crate::interpreter::ast::{AstNode, MatchArm, Pattern};
+use crate::interpreter::Parser;
+use std::collections::VecDeque;
+
+/// One level of indentation
+const INDENT: &str = "    ";
+
+/// Tokens the printer may add or drop without changing the program
+const PUNCTUATION: &[&str] = &[
+    "Semicolon",
+    "Comma",
+    "LeftParen",
+    "RightParen",
+    "LeftBrace",
+    "RightBrace",
+];
+
+/// Format a Ruchy document
+///
+/// Formatting is idempotent: formatting the result again returns it
+/// unchanged. See the module comment for what is preserved.
+pub fn format_document(source: &str) -> String {
+    let Ok(ast) = Parser::new(source).with_spans().parse() else {
+        return source.to_string();
+    };
+
+    let mut printer = Printer::new(source);
+    let formatted = printer.document(ast.nodes());
+    if preserves_source(source, &formatted) {
+        formatted
+    } else {
+        source.to_string()
+    }
+}
+
+/// Whether `formatted` keeps everything `source` says: the same AST, the
+/// same tokens apart from punctuation and the same comments
+fn preserves_source(source: &str, formatted: &str) -> bool {
+    let (Ok(before), Ok(after)) = (Parser::new(source).parse(), Parser::new(formatted).parse())
+    else {
+        return false;
+    };
+    let comment_texts = |text: &str| -> Vec<String> {
+        scan_layout(text)
+            .comments
+            .into_iter()
+            .map(|comment| comment.text)
+            .collect()
+    };
+
+    before == after
+        && significant_tokens(source) == significant_tokens(formatted)
+        && comment_texts(source) == comment_texts(formatted)
+}
+
+/// Token stream of `text` without the punctuation the printer normalizes
+fn significant_tokens(text: &str) -> Option<Vec<String>> {
+    let tokens = Parser::new(text).debug_get_tokens().ok()?;
+    Some(
+        tokens
+            .into_iter()
+            .filter(|token| !PUNCTUATION.contains(&token.as_str()))
+            .collect(),
+    )
+}
+
+/// A comment recovered from the source
+#[derive(Debug, Clone, PartialEq)]
+struct Comment {
+    /// Comment text including its delimiters (`//`, `/* */`)
+    text: String,
+    /// Line the comment starts on (1-based, like `Span`)
+    line: usize,
+    /// Number of braces open at the comment
+    depth: usize,
+    /// Line of the innermost open brace (0 at top level)
+    open_line: usize,
+    /// Whether code precedes the comment on its line
+    trailing: bool,
+}
+
+/// Source lines the AST does not record
+#[derive(Debug, Default)]
+struct Layout {
+    /// Comments in source order
+    comments: VecDeque<Comment>,
+    /// Start line of each `fun`, `struct`, `enum`, `impl`, `use` and
+    /// attributed item, in source order
+    declarations: VecDeque<usize>,
+    /// Line of each match arm's `=>`, in source order
+    arms: VecDeque<usize>,
+    /// Start line of each struct field and enum variant, in source order
+    items: VecDeque<usize>,
+}
+
+/// Collect comments and the lines of declarations, match arms, struct
+/// fields and enum variants
+///
+/// Strings, f-strings and char literals are skipped so braces and `//`
+/// inside them are not mistaken for code.
+fn scan_layout(source: &str) -> Layout {
+    let chars: Vec<char> = source.chars().collect();
+    let mut layout = Layout::default();
+    let mut line = 1;
+    let mut line_has_code = false;
+    let mut braces: Vec<usize> = Vec::new();
+    // Whether each open brace holds struct fields or enum variants
+    let mut item_lists: Vec<bool> = Vec::new();
+    let mut expect_item_list = false;
+    let mut after_attribute = false;
+    let mut previous = ' ';
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let start_line = line;
+        match (c, next) {
+            ('\n', _) => {
+                line += 1;
+                line_has_code = false;
+                i += 1;
+                continue;
+            }
+            ('/', Some('/')) => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|&ch| ch == '\n')
+                    .map_or(chars.len(), |n| i + n);
+                let text: String = chars[i..end].iter().collect();
+                layout.comments.push_back(Comment {
+                    text: text.trim_end().to_string(),
+                    line,
+                    depth: braces.len(),
+                    open_line: braces.last().copied().unwrap_or(0),
+                    trailing: line_has_code,
+                });
+                i = end;
+                continue;
+            }
+            ('/', Some('*')) => {
+                let start = i;
+                i = skip_block_comment(&chars, i, &mut line);
+                layout.comments.push_back(Comment {
+                    text: chars[start..i].iter().collect(),
+                    line: start_line,
+                    depth: braces.len(),
+                    open_line: braces.last().copied().unwrap_or(0),
+                    trailing: line_has_code,
+                });
+                continue;
+            }
+            ('"', _) => {
+                i = skip_string(&chars, i + 1, &mut line);
+            }
+            ('\'', _) => {
+                i += if next == Some('\\') { 4 } else { 3 };
+            }
+            ('{', _) => {
+                braces.push(line);
+                item_lists.push(expect_item_list);
+                expect_item_list = false;
+                i += 1;
+            }
+            ('}', _) => {
+                braces.pop();
+                item_lists.pop();
+                i += 1;
+            }
+            ('#', _) => {
+                layout.declarations.push_back(line);
+                after_attribute = true;
+                i += 1;
+            }
+            ('=', Some('>')) => {
+                layout.arms.push_back(line);
+                i += 2;
+            }
+            _ if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                if item_lists.last() == Some(&true) && matches!(previous, '{' | ',') {
+                    layout.items.push_back(line);
+                }
+                match word.as_str() {
+                    "f" if chars.get(i) == Some(&'"') => {
+                        i = skip_fstring(&chars, i + 1, &mut line);
+                    }
+                    "fun" | "struct" | "enum" | "impl" | "use" => {
+                        expect_item_list = matches!(word.as_str(), "struct" | "enum");
+                        if after_attribute {
+                            after_attribute = false;
+                        } else {
+                            layout.declarations.push_back(line);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {
+                i += 1;
+            }
+        }
+        if !c.is_whitespace() {
+            line_has_code = true;
+            previous = c;
+        }
+    }
+
+    layout
+}
+
+/// Index just past the (possibly nested) block comment starting at `start`
+fn skip_block_comment(chars: &[char], start: usize, line: &mut usize) -> usize {
+    let mut i = start + 2;
+    let mut depth = 1;
+    while i < chars.len() && depth > 0 {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('*')) => {
+                depth += 1;
+                i += 2;
+            }
+            ('*', Some('/')) => {
+                depth -= 1;
+                i += 2;
+            }
+            ('\n', _) => {
+                *line += 1;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Index just past the closing quote of a string whose body starts at `start`
+fn skip_string(chars: &[char], start: usize, line: &mut usize) -> usize {
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '"' => return i + 1,
+            '\\' => i += 2,
+            '\n' => {
+                *line += 1;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Index just past the closing quote of an f-string whose body starts at
+/// `start`; quotes inside `{holes}` open nested (f-)strings
+fn skip_fstring(chars: &[char], start: usize, line: &mut usize) -> usize {
+    let mut i = start;
+    let mut depth = 0usize;
+    while i < chars.len() {
+        match chars[i] {
+            '"' if depth == 0 => return i + 1,
+            '"' if i > 0 && chars[i - 1] == 'f' => i = skip_fstring(chars, i + 1, line),
+            '"' => {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            '{' => {
+                depth += 1;
+                i += 1;
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                i += 1;
+            }
+            '\n' => {
+                *line += 1;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Prints an AST back to source, re-inserting comments from the layout
+///
+/// Statement text is built as strings: the first line without indentation
+/// (the caller places it), later lines with their absolute indentation.
+struct Printer {
+    layout: Layout,
+    /// Whether each source line is blank (index 0 is line 1)
+    blank_lines: Vec<bool>,
+    /// Line of the declaration being printed
+    current_declaration: Option<usize>,
+}
+
+impl Printer {
+    fn new(source: &str) -> Self {
+        Self {
+            layout: scan_layout(source),
+            blank_lines: source.lines().map(|l| l.trim().is_empty()).collect(),
+            current_declaration: None,
+        }
+    }
+
+    /// Print a whole document
+    fn document(&mut self, nodes: &[AstNode]) -> String {
+        let mut out = String::new();
+        let mut first = true;
+        for (i, node) in nodes.iter().enumerate() {
+            let separate = i > 0 && separated(&nodes[i - 1], node);
+            self.statement(&mut out, node, 0, false, &mut first, separate);
+        }
+        while let Some(comment) = self.layout.comments.pop_front() {
+            self.emit_comment(&mut out, &comment, 0, &mut first);
+        }
+        out
+    }
+
+    /// Whether the source line before `line` is blank
+    fn blank_before(&self, line: usize) -> bool {
+        line >= 2 && self.blank_lines.get(line - 2).copied().unwrap_or(false)
+    }
+
+    /// Append one comment on its own line(s)
+    fn emit_comment(&self, out: &mut String, comment: &Comment, indent: usize, first: &mut bool) {
+        if !*first && self.blank_before(comment.line) {
+            out.push('\n');
+        }
+        *first = false;
+        out.push_str(&INDENT.repeat(indent));
+        out.push_str(&comment.text);
+        out.push('\n');
+    }
+
+    /// Append a statement with its leading and trailing comments
+    ///
+    /// `value` marks the last statement of a block whose value is used, which
+    /// is printed without a semicolon. `separate` forces a blank line before
+    /// the statement (and its comments) unless it is the first in its block.
+    fn statement(
+        &mut self,
+        out: &mut String,
+        node: &AstNode,
+        indent: usize,
+        value: bool,
+        first: &mut bool,
+        separate: bool,
+    ) {
+        let line = self.node_line(node);
+        if separate && !*first {
+            out.push('\n');
+            *first = true;
+        }
+
+        let trailing = match line {
+            Some(line) => self.comments_at(out, line, indent, first),
+            None => Vec::new(),
+        };
+        *first = false;
+
+        let text = self.statement_text(node, indent, value);
+        push_line(out, indent, &text, &trailing);
+    }
+
+    /// Append the comments before `line` and take those after code on it
+    ///
+    /// A comment starting `line` before any code (`/* c */ let x`) counts as
+    /// before it. The returned trailing comments go at the end of the first
+    /// line printed for `line`.
+    fn comments_at(
+        &mut self,
+        out: &mut String,
+        line: usize,
+        indent: usize,
+        first: &mut bool,
+    ) -> Vec<String> {
+        while let Some(comment) = self.layout.comments.front() {
+            if comment.line > line || (comment.line == line && comment.trailing) {
+                break;
+            }
+            let comment = self.layout.comments.pop_front().unwrap();
+            self.emit_comment(out, &comment, indent, first);
+        }
+        let mut trailing = Vec::new();
+        while let Some(comment) = self.layout.comments.front() {
+            if comment.line != line || !comment.trailing {
+                break;
+            }
+            trailing.push(self.layout.comments.pop_front().unwrap().text);
+        }
+        if !*first && self.blank_before(line) {
+            out.push('\n');
+        }
+        trailing
+    }
+
+    /// Source line of a statement or declaration, when known
+    ///
+    /// Declarations consume their line from the layout, so this must be
+    /// called once per declaration, in source order.
+    fn node_line(&mut self, node: &AstNode) -> Option<usize> {
+        match node {
+            AstNode::Spanned { span, .. } => Some(span.line),
+            node if is_declaration(node) => {
+                self.current_declaration = self.layout.declarations.pop_front();
+                self.current_declaration
+            }
+            _ => None,
+        }
+    }
+
+    /// Append the comments that trail the last line of a block
+    ///
+    /// Takes comments one brace deeper than the block's owner whose brace
+    /// opened at or before `anchor` (the block's last statement, or its
+    /// owner's line when empty) and that come after it.
+    fn closing_comments(
+        &mut self,
+        out: &mut String,
+        inner: usize,
+        anchor: Option<usize>,
+        first: &mut bool,
+    ) {
+        let Some(anchor) = anchor else {
+            return;
+        };
+        while let Some(comment) = self.layout.comments.front() {
+            if comment.depth != inner || comment.open_line > anchor || comment.line < anchor {
+                break;
+            }
+            let comment = self.layout.comments.pop_front().unwrap();
+            self.emit_comment(out, &comment, inner, first);
+        }
+    }
+
+    /// A `{ ... }` block whose statements sit one level deeper than `indent`
+    ///
+    /// `owner` is the line of the construct the block belongs to, used to
+    /// place comments in an otherwise empty block.
+    fn block(
+        &mut self,
+        statements: &[AstNode],
+        indent: usize,
+        owner: Option<usize>,
+        value: bool,
+    ) -> String {
+        let mut body = String::new();
+        let mut first = true;
+        let mut anchor = owner;
+        for (i, statement) in statements.iter().enumerate() {
+            if let AstNode::Spanned { span, .. } = statement {
+                anchor = Some(span.line);
+            }
+            let tail = value && i + 1 == statements.len();
+            self.statement(&mut body, statement, indent + 1, tail, &mut first, false);
+        }
+        self.closing_comments(&mut body, indent + 1, anchor, &mut first);
+
+        if body.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}{}}}", body, INDENT.repeat(indent))
+        }
+    }
+
+    /// A statement's text; `value` omits the semicolon of an expression
+    fn statement_text(&mut self, node: &AstNode, indent: usize, value: bool) -> String {
+        let line = match node {
+            AstNode::Spanned { span, .. } => Some(span.line),
+            _ => None,
+        };
+        match node.without_span() {
+            AstNode::FunctionDef {
+                name,
+                params,
+                param_types,
+                return_type,
+                body,
+            } => {
+                let line = self.last_declaration_line();
+                let params: Vec<String> = params
+                    .iter()
+                    .zip(param_types)
+                    .map(|(param, ty)| match ty {
+                        Some(ty) => format!("{}: {}", param, ty),
+                        None => param.clone(),
+                    })
+                    .collect();
+                let return_type = match return_type {
+                    Some(ty) => format!(" -> {}", ty),
+                    None => String::new(),
+                };
+                format!(
+                    "fun {}({}){} {}",
+                    name,
+                    params.join(", "),
+                    return_type,
+                    self.block(body, indent, line, true)
+                )
+            }
+            AstNode::TestDef { name, body } => {
+                let line = self.last_declaration_line();
+                format!(
+                    "#[test]\n{}fun {}() {}",
+                    INDENT.repeat(indent),
+                    name,
+                    self.block(body, indent, line, true)
+                )
+            }
+            AstNode::StructDef { name, fields } => {
+                let items = fields
+                    .iter()
+                    .map(|field| match &field.type_annotation {
+                        Some(ty) => format!("{}: {}", field.name, ty),
+                        None => field.name.clone(),
+                    })
+                    .collect();
+                format!("struct {} {}", name, self.item_list(items, indent))
+            }
+            AstNode::EnumDef { name, variants } => {
+                let items = variants
+                    .iter()
+                    .map(|variant| match &variant.discriminant {
+                        Some(value) => format!("{} = {}", variant.name, self.expr(value, indent)),
+                        None => variant.name.clone(),
+                    })
+                    .collect();
+                format!("enum {} {}", name, self.item_list(items, indent))
+            }
+            AstNode::ImplBlock { type_name, methods } => {
+                let owner = self.last_declaration_line();
+                let mut body = String::new();
+                let mut first = true;
+                let mut anchor = owner;
+                for (i, method) in methods.iter().enumerate() {
+                    self.statement(&mut body, method, indent + 1, false, &mut first, i > 0);
+                    anchor = self.last_declaration_line().or(anchor);
+                }
+                self.closing_comments(&mut body, indent + 1, anchor, &mut first);
+                if body.is_empty() {
+                    format!("impl {} {{}}", type_name)
+                } else {
+                    format!("impl {} {{\n{}{}}}", type_name, body, INDENT.repeat(indent))
+                }
+            }
+            AstNode::UseDecl { path } => format!("use {};", path.join("::")),
+            // The parser keeps the empty segment after `::` in `use a::{B, C}`
+            AstNode::GroupedUseDecl { base_path, items } => {
+                let separator = if base_path.last().is_some_and(String::is_empty) {
+                    ""
+                } else {
+                    " "
+                };
+                format!(
+                    "use {}{}{{{}}};",
+                    base_path.join("::"),
+                    separator,
+                    items.join(", ")
+                )
+            }
+
+            AstNode::LetDecl { .. }
+            | AstNode::Assignment { .. }
+            | AstNode::IndexAssignment { .. }
+            | AstNode::FieldAssignment { .. }
+            | AstNode::Break { .. }
+            | AstNode::Continue => self.emit(node, indent),
+            AstNode::TupleDestruct { patterns, value } => {
+                let patterns: Vec<String> = patterns.iter().map(|p| self.pattern(p)).collect();
+                format!(
+                    "let ({}) = {};",
+                    patterns.join(", "),
+                    self.expr(value, indent)
+                )
+            }
+            AstNode::LetElse {
+                pattern,
+                value,
+                else_block,
+            } => format!(
+                "let {} = {} else {};",
+                self.pattern(pattern),
+                self.expr(value, indent),
+                self.block(else_block, indent, line, false)
+            ),
+            AstNode::CompoundAssignment { lhs, op, rhs } => format!(
+                "{} {}= {};",
+                self.expr(lhs, indent),
+                op.symbol(),
+                self.expr(rhs, indent)
+            ),
+
+            AstNode::IfExpr {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let head = format!(
+                    "if {} {}",
+                    self.expr(condition, indent),
+                    self.block(then_branch, indent, line, true)
+                );
+                head + &self.else_branch(else_branch.as_deref(), indent)
+            }
+            AstNode::IfLet {
+                pattern,
+                scrutinee,
+                then_branch,
+                else_branch,
+            } => {
+                let head = format!(
+                    "if let {} = {} {}",
+                    self.pattern(pattern),
+                    self.expr(scrutinee, indent),
+                    self.block(then_branch, indent, line, true)
+                );
+                head + &self.else_branch(else_branch.as_deref(), indent)
+            }
+            AstNode::WhileLoop { condition, body } => format!(
+                "while {} {}",
+                self.expr(condition, indent),
+                self.block(body, indent, line, false)
+            ),
+            AstNode::WhileLet {
+                pattern,
+                scrutinee,
+                body,
+            } => format!(
+                "while let {} = {} {}",
+                self.pattern(pattern),
+                self.expr(scrutinee, indent),
+                self.block(body, indent, line, false)
+            ),
+            AstNode::ForLoop {
+                var,
+                iterable,
+                body,
+            } => format!(
+                "for {} in {} {}",
+                var,
+                self.expr(iterable, indent),
+                self.block(body, indent, line, false)
+            ),
+            AstNode::RepeatUntil { body, condition } => format!(
+                "repeat {} until {};",
+                self.block(body, indent, line, false),
+                self.expr(condition, indent)
+            ),
+            AstNode::Loop { body } => format!("loop {}", self.block(body, indent, line, true)),
+            AstNode::MatchExpr { expr, arms } => {
+                let scrutinee = self.expr(expr, indent);
+                format!(
+                    "match {} {}",
+                    scrutinee,
+                    self.match_arms(arms, indent, line)
+                )
+            }
+
+            AstNode::Return { value: None } => "return;".to_string(),
+            AstNode::Return { value: Some(v) } => format!("return {};", self.expr(v, indent)),
+
+            expression => {
+                let text = self.expr(expression, indent);
+                if value {
+                    text
+                } else {
+                    text + ";"
+                }
+            }
+        }
+    }
+
+    /// Line of the declaration most recently taken from the layout
+    ///
+    /// `node_line` takes declarations as they are printed, so while printing
+    /// a declaration's body this is the declaration's own line.
+    fn last_declaration_line(&self) -> Option<usize> {
+        self.current_declaration
+    }
+
+    /// ` else { .. }` or ` else if ..` after an if's then-branch
+    fn else_branch(&mut self, branch: Option<&[AstNode]>, indent: usize) -> String {
+        match branch {
+            None => String::new(),
+            Some([single])
+                if matches!(
+                    single.without_span(),
+                    AstNode::IfExpr { .. } | AstNode::IfLet { .. }
+                ) =>
+            {
+                format!(" else {}", self.statement_text(single, indent, true))
+            }
+            Some(statements) => format!(" else {}", self.block(statements, indent, None, true)),
+        }
+    }
+
+    /// Struct fields or enum variants, one per line with trailing commas
+    fn item_list(&mut self, items: Vec<String>, indent: usize) -> String {
+        let inner = indent + 1;
+        let mut body = String::new();
+        let mut first = true;
+        let mut anchor = self.last_declaration_line();
+        for item in items {
+            let item_line = self.layout.items.pop_front();
+            let trailing = match item_line {
+                Some(line) => self.comments_at(&mut body, line, inner, &mut first),
+                None => Vec::new(),
+            };
+            anchor = item_line.or(anchor);
+            first = false;
+            push_line(&mut body, inner, &format!("{},", item), &trailing);
+        }
+        self.closing_comments(&mut body, inner, anchor, &mut first);
+        if body.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}{}}}", body, INDENT.repeat(indent))
+        }
+    }
+
+    /// The `{ arms }` of a match, one arm per line
+    fn match_arms(&mut self, arms: &[MatchArm], indent: usize, line: Option<usize>) -> String {
+        let inner = indent + 1;
+        let mut body = String::new();
+        let mut first = true;
+        let mut anchor = line;
+        for arm in arms {
+            let arm_line = self.layout.arms.pop_front();
+            let trailing = match arm_line {
+                Some(line) => self.comments_at(&mut body, line, inner, &mut first),
+                None => Vec::new(),
+            };
+            anchor = arm_line.or(anchor);
+            first = false;
+
+            let mut head = self.pattern(&arm.pattern);
+            if let Some(guard) = &arm.guard {
+                head = format!("{} if {}", head, self.expr(guard, inner));
+            }
+            let arm_body = match arm.body.as_slice() {
+                [single] => self.expr(single, inner),
+                statements => self.block(statements, inner, arm_line, true),
+            };
+            push_line(
+                &mut body,
+                inner,
+                &format!("{} => {},", head, arm_body),
+                &trailing,
+            );
+        }
+        self.closing_comments(&mut body, inner, anchor, &mut first);
+
+        if body.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}{}}}", body, INDENT.repeat(indent))
+        }
+    }
+
+    /// An expression; nested blocks are indented relative to `indent`
+    fn expr(&mut self, node: &AstNode, indent: usize) -> String {
+        match self.layout(node, indent) {
+            Some(text) => text,
+            None => self.emit(node, indent),
+        }
+    }
+
+    /// `AstNode::emit` text with nested closures, blocks and statements laid
+    /// out by the printer
+    fn emit(&mut self, node: &AstNode, indent: usize) -> String {
+        node.emit_with(&mut |child| self.layout(child, indent))
+    }
+
+    /// Text for a closure, block or statement in expression position;
+    /// None for the expressions `AstNode::emit` prints on one line
+    fn layout(&mut self, node: &AstNode, indent: usize) -> Option<String> {
+        let text = match node.without_span() {
+            AstNode::Closure {
+                is_move,
+                params,
+                body,
+            } => {
+                let mut head = String::new();
+                if *is_move {
+                    head.push_str("move ");
+                }
+                if params.is_empty() {
+                    head.push_str("||");
+                } else {
+                    head.push_str(&format!("|{}|", params.join(", ")));
+                }
+                match body.as_slice() {
+                    [single] if is_bare_expression(single) => {
+                        format!("{} {}", head, self.expr(single, indent))
+                    }
+                    statements => {
+                        format!("{} {}", head, self.block(statements, indent, None, true))
+                    }
+                }
+            }
+            AstNode::Block { statements } => self.block(statements, indent, None, true),
+            expression if is_inline_expression(expression) => return None,
+            // Statements in expression position (loops, and anything else
+            // the parser only accepts as a statement)
+            statement => self.statement_text(statement, indent, true),
+        };
+        Some(text)
+    }
+
+    /// A match, `if let` or `let` pattern
+    fn pattern(&mut self, pattern: &Pattern) -> String {
+        match pattern {
+            Pattern::Wildcard => "_".to_string(),
+            Pattern::Literal(node) => self.expr(node, 0),
+            Pattern::Identifier(name) => name.clone(),
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let op = if *inclusive { "..=" } else { ".." };
+                format!("{}{}{}", self.expr(start, 0), op, self.expr(end, 0))
+            }
+            Pattern::Binding { name, pattern } => format!("{} @ {}", name, self.pattern(pattern)),
+            Pattern::Variant {
+                name,
+                payload: Some(payload),
+            } => format!("{}({})", name, self.pattern(payload)),
+            Pattern::Variant {
+                name,
+                payload: None,
+            } => name.clone(),
+            Pattern::Tuple(patterns) => {
+                let items: Vec<String> = patterns.iter().map(|p| self.pattern(p)).collect();
+                format!("({})", items.join(", "))
+            }
+        }
+    }
+}
+
+/// Append `text` at `indent`, with `trailing` comments after its first line
+///
+/// Lines after the first already carry their indentation.
+fn push_line(out: &mut String, indent: usize, text: &str, trailing: &[String]) {
+    let (head, rest) = match text.split_once('\n') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (text, None),
+    };
+    out.push_str(&INDENT.repeat(indent));
+    out.push_str(head);
+    for comment in trailing {
+        out.push(' ');
+        out.push_str(comment);
+    }
+    out.push('\n');
+    if let Some(rest) = rest {
+        out.push_str(rest);
+        out.push('\n');
+    }
+}
+
+/// Declarations, which are separated from their neighbours by a blank line
+fn is_declaration(node: &AstNode) -> bool {
+    matches!(
+        node,
+        AstNode::FunctionDef { .. }
+            | AstNode::TestDef { .. }
+            | AstNode::StructDef { .. }
+            | AstNode::EnumDef { .. }
+            | AstNode::ImplBlock { .. }
+            | AstNode::UseDecl { .. }
+            | AstNode::GroupedUseDecl { .. }
+    )
+}
+
+/// Whether a blank line goes between two top-level items: around every
+/// declaration except between consecutive `use`s
+fn separated(previous: &AstNode, next: &AstNode) -> bool {
+    let is_use = |node: &AstNode| {
+        matches!(
+            node,
+            AstNode::UseDecl { .. } | AstNode::GroupedUseDecl { .. }
+        )
+    };
+    (is_declaration(previous) || is_declaration(next)) && !(is_use(previous) && is_use(next))
+}
+
+/// Whether a closure body can be written without braces (`|x| x + 1`)
+fn is_bare_expression(node: &AstNode) -> bool {
+    !matches!(
+        node.without_span(),
+        AstNode::LetDecl { .. }
+            | AstNode::TupleDestruct { .. }
+            | AstNode::LetElse { .. }
+            | AstNode::Assignment { .. }
+            | AstNode::IndexAssignment { .. }
+            | AstNode::FieldAssignment { .. }
+            | AstNode::CompoundAssignment { .. }
+            | AstNode::IfExpr { .. }
+            | AstNode::IfLet { .. }
+            | AstNode::WhileLoop { .. }
+            | AstNode::WhileLet { .. }
+            | AstNode::ForLoop { .. }
+            | AstNode::RepeatUntil { .. }
+            | AstNode::Loop { .. }
+            | AstNode::MatchExpr { .. }
+            | AstNode::Return { .. }
+            | AstNode::Break { .. }
+            | AstNode::Continue
+            | AstNode::Block { .. }
+            | AstNode::HashMapLiteral { .. }
+    )
+}
+
+/// Whether `AstNode::emit` prints this expression on one line
+fn is_inline_expression(node: &AstNode) -> bool {
+    matches!(
+        node,
+        AstNode::IntegerLiteral(_)
+            | AstNode::FloatLiteral(_)
+            | AstNode::StringLiteral(_)
+            | AstNode::CharLiteral(_)
+            | AstNode::BooleanLiteral(_)
+            | AstNode::FString { .. }
+            | AstNode::Identifier(_)
+            | AstNode::PathExpr { .. }
+            | AstNode::BinaryOp { .. }
+            | AstNode::UnaryOp { .. }
+            | AstNode::TypeCast { .. }
+            | AstNode::Range { .. }
+            | AstNode::FunctionCall { .. }
+            | AstNode::MethodCall { .. }
+            | AstNode::FieldAccess { .. }
+            | AstNode::IndexAccess { .. }
+            | AstNode::VectorLiteral { .. }
+            | AstNode::VecMacro { .. }
+            | AstNode::TupleLiteral { .. }
+            | AstNode::HashMapLiteral { .. }
+            | AstNode::StructLiteral { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Snippets covering every statement form and the expression forms
+    /// that need parentheses
+    const SNIPPETS: &[&str] = &[
+        "fun fib(n){if n<2{n}else{fib(n-1)+fib(n-2)}}\nlet x=fib(10);println(x)",
+        "struct Point { x: i32, y }\nimpl Point { fun sum(self) { self.x + self.y } }\nenum Color { Red, Green = 5 }",
+        "use std::sync::{Arc, Mutex};\nuse std::thread;\n#[test]\nfun t() { assert(true) }",
+        "let f = |x| x + 1;\nlet g = move || { let a = 1; a };\nlet m = {\"a\": 1};\nlet t = (1,);\nlet (a, (b, _)) = (1, (2, 3));\nlet Some(z) = opt else { return; };",
+        "while let Some(v) = it.next() { total += v }\nrepeat { i += 1; } until i > 10\nlet l = loop { break 5; };\nfor (k, v) in pairs { m[k] = v; p.x = k }",
+        "match x { 0 => \"zero\", n if n > 0 => { let y = n; y } _ => \"neg\" }\nif let Some(k) = m.get(\"a\") { k } else if c { 1 } else { 2 }",
+        "let a = x - (y - z) - w;\nlet b = (p || q) && r;\nlet c = !(a == b);\nlet d = (-x).abs();\nlet e = -(x as f64);\nlet r = (a + 1)..=(b * 2);",
+        "let n = 0x10 + 1_000;\nlet f = 2.50 + 1.0;\nlet s = \"a\\nb\\\"c\";\nlet i = f\"{x} and {y}\";\nlet c = '\\'';\nlet v = vec![0; 3];",
+    ];
+
+    fn parse(source: &str) -> crate::interpreter::ast::Ast {
+        Parser::new(source).parse().unwrap()
+    }
+
+    #[test]
+    fn test_format_normalizes_spacing_and_indentation() {
+        let formatted = format_document(
+            "fun fib(n){if n<2{n}else{fib(n-1)+fib(n-2)}}\nlet x=fib(10);println(x)",
+        );
+        assert_eq!(
+            formatted,
+            "fun fib(n) {
+    if n < 2 {
+        n
+    } else {
+        fib(n - 1) + fib(n - 2)
+    }
+}
+
+let x = fib(10);
+println(x);
+"
+        );
+    }
+
+    #[test]
+    fn test_format_declarations() {
+        let formatted = format_document(SNIPPETS[1]);
+        assert_eq!(
+            formatted,
+            "struct Point {
+    x: i32,
+    y,
+}
+
+impl Point {
+    fun sum(self) {
+        self.x + self.y
+    }
+}
+
+enum Color {
+    Red,
+    Green = 5,
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_format_round_trips_to_same_ast() {
+        for source in SNIPPETS {
+            let formatted = format_document(source);
+            assert_ne!(&formatted, source, "snippet was not formatted: {}", source);
+            assert_eq!(
+                parse(&formatted),
+                parse(source),
+                "AST changed for: {}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_is_idempotent() {
+        for source in SNIPPETS {
+            let once = format_document(source);
+            assert_eq!(
+                format_document(&once),
+                once,
+                "not idempotent for: {}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_keeps_minimal_parentheses() {
+        let formatted = format_document(SNIPPETS[6]);
+        assert_eq!(
+            formatted,
+            "let a = x - (y - z) - w;
+let b = (p || q) && r;
+let c = !(a == b);
+let d = (-x).abs();
+let e = -(x as f64);
+let r = (a + 1)..=(b * 2);
+"
+        );
+    }
+
+    #[test]
+    fn test_format_preserves_comments() {
+        let source = "// header
+
+fun main() { // entry
+    let v = vec![1,2,3]; // nums
+    for i in 0..3 { println(v[i]) }
+    // done
+}
+fun todo() {
+    // TODO
+}
+match x {
+    // zero
+    0 => a,
+    _ => b // other
+}
+struct P {
+    x, /* first */
+    // second
+    y
+}
+";
+        let formatted = format_document(source);
+        assert_eq!(
+            formatted,
+            "// header
+
+fun main() { // entry
+    let v = vec![1, 2, 3]; // nums
+    for i in 0..3 {
+        println(v[i]);
+    }
+    // done
+}
+
+fun todo() {
+    // TODO
+}
+
+match x {
+    // zero
+    0 => a,
+    _ => b, // other
+}
+
+struct P {
+    x, /* first */
+    // second
+    y,
+}
+"
+        );
+        assert_eq!(format_document(&formatted), formatted);
+    }
+
+    #[test]
+    fn test_format_ignores_comment_markers_in_literals() {
+        let source =
+            "let s = \"// not a comment {\";\nlet c = '{';\nlet f = f\"{s} // still not\";";
+        assert_eq!(
+            format_document(source),
+            "let s = \"// not a comment {\";\nlet c = '{';\nlet f = f\"{s} // still not\";\n"
+        );
+    }
+
+    #[test]
+    fn test_format_keeps_single_blank_lines() {
+        let source = "let a = 1;\n\n\n// b\nlet b = 2;\nlet c = 3;";
+        assert_eq!(
+            format_document(source),
+            "let a = 1;\n\n// b\nlet b = 2;\nlet c = 3;\n"
+        );
+    }
+
+    #[test]
+    fn test_format_leaves_invalid_source_unchanged() {
+        let source = "let x = ;\nfun ok() {}";
+        assert_eq!(format_document(source), source);
+    }
+
+    #[test]
+    fn test_format_leaves_half_typed_parameters_unchanged() {
+        // Code being edited: formatting must return, not hang
+        for source in [
+            "fun broken( {",
+            "fun f(x: Option<i64>) {\n    x\n}",
+            "fun g() { 1 }\nfun h(a,",
+        ] {
+            assert_eq!(format_document(source), source);
+        }
+    }
+
+    #[test]
+    fn test_format_keeps_mut_and_type_annotations() {
+        assert_eq!(format_document("let mut x=1+2;"), "let mut x = 1 + 2;\n");
+        assert_eq!(
+            format_document("fun f(a: i64)->i64{a*2}"),
+            "fun f(a: i64) -> i64 {\n    a * 2\n}\n"
+        );
+        let formatted = format_document("fun g(s:&str,v:&mut Vec,n)->&str{s}");
+        assert_eq!(
+            formatted,
+            "fun g(s: &str, v: &mut Vec, n) -> &str {\n    s\n}\n"
+        );
+        assert_eq!(format_document(&formatted), formatted);
+    }
+
+    #[test]
+    fn test_format_leaves_unrepresentable_syntax_unchanged() {
+        // The AST has no room for these, so printing it would delete them
+        for source in [
+            "let mut (a, b)  =  (1, 2)",
+            "fun g(v) { len(&v) }",
+            "#[derive(Debug)]\nstruct S {x}",
+        ] {
+            assert_eq!(format_document(source), source);
+        }
+    }
+}
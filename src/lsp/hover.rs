@@ -0,0 +1,120 @@
+// Hover Provider
+// Shows the declaration of the symbol under the cursor (textDocument/hover)
+
+use super::inlay_hints::infer_binding_type;
+use super::protocol::{Hover, Position};
+use super::symbols::{word_at_position, SymbolKind, SymbolTable};
+
+/// Hover provider
+pub struct HoverProvider;
+
+impl HoverProvider {
+    /// Create a new hover provider
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get the hover for the symbol at a position
+    ///
+    /// Functions show their signature (`fun factorial(n)`); variables show
+    /// their binding with the declared type, or the inferred one when the
+    /// initializer allows it (`let x: i64`). Returns `None` when the cursor
+    /// is not on a symbol declared in the document.
+    pub fn get_hover(
+        &self,
+        document_text: &str,
+        symbols: &SymbolTable,
+        position: Position,
+    ) -> Option<Hover> {
+        let (name, range) = word_at_position(document_text, position)?;
+        let symbol = symbols.get_symbol(&name)?;
+
+        let location = symbol.location.range.start;
+        let line = document_text.lines().nth(location.line as usize)?;
+        let declaration = line.get(location.character as usize..)?;
+
+        let code = match symbol.kind {
+            SymbolKind::Function => function_signature(declaration),
+            SymbolKind::Variable => variable_binding(declaration, line, &name),
+            SymbolKind::Type | SymbolKind::Constant => name,
+        };
+        Some(Hover::code(&code, range))
+    }
+}
+
+impl Default for HoverProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `fun name(params) -> Type` from a line starting at `fun`
+pub(crate) fn function_signature(declaration: &str) -> String {
+    let end = declaration.find('{').unwrap_or(declaration.len());
+    declaration[..end].trim_end().to_string()
+}
+
+/// `let name: Type` from a line starting at `let`
+fn variable_binding(declaration: &str, line: &str, name: &str) -> String {
+    let end = declaration.find('=').unwrap_or(declaration.len());
+    let binding = declaration[..end].trim_end().trim_end_matches(';');
+    if binding.contains(':') {
+        return binding.to_string();
+    }
+    match infer_binding_type(line, name) {
+        Some(type_name) => format!("{}: {}", binding, type_name),
+        None => binding.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Helper: hover text for `text` at a position
+    fn hover_text(text: &str, line: u32, character: u32) -> Option<String> {
+        let mut table = SymbolTable::new();
+        table.parse_document("file:///test.ruchy", text);
+        HoverProvider::new()
+            .get_hover(text, &table, Position::new(line, character))
+            .map(|hover| hover.contents.value)
+    }
+
+    #[test]
+    fn test_hover_function_signature() {
+        let text = "fun add(a: i64, b: i64) -> i64 {\n    a + b\n}\nadd(1, 2)";
+        let value = hover_text(text, 3, 1).unwrap();
+        assert_eq!(value, "```ruchy\nfun add(a: i64, b: i64) -> i64\n```");
+    }
+
+    #[test]
+    fn test_hover_variable_with_inferred_type() {
+        let text = "let count = 1 + 2;\nprintln(count)";
+        assert_eq!(
+            hover_text(text, 1, 9).unwrap(),
+            "```ruchy\nlet count: i64\n```"
+        );
+    }
+
+    #[test]
+    fn test_hover_variable_with_declared_type() {
+        let text = "let mut name: String = greet();\nname";
+        assert_eq!(
+            hover_text(text, 1, 0).unwrap(),
+            "```ruchy\nlet mut name: String\n```"
+        );
+    }
+
+    #[test]
+    fn test_hover_variable_without_inferable_type() {
+        let text = "let v = compute();\nv";
+        assert_eq!(hover_text(text, 1, 0).unwrap(), "```ruchy\nlet v\n```");
+    }
+
+    #[test]
+    fn test_hover_unknown_word() {
+        let text = "fun main() {\n    undefined_thing\n}";
+        assert_eq!(hover_text(text, 1, 6), None);
+        assert_eq!(hover_text(text, 5, 0), None);
+    }
+}
@@ -0,0 +1,303 @@
+// Inlay Hint Provider
+// Annotates let bindings that omit a type with their inferred type
+
+use super::protocol::{InlayHint, Position, Range};
+use crate::interpreter::ast::{AstNode, BinaryOperator, UnaryOperator};
+use crate::interpreter::Parser;
+
+/// Inlay hint provider
+pub struct InlayHintProvider;
+
+impl InlayHintProvider {
+    /// Create a new inlay hint provider
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get type hints for `let` bindings within a range
+    ///
+    /// A hint is only produced when the binding has no explicit type and the
+    /// initializer's type can be inferred with confidence (literals and
+    /// operators over them). Hints are placed right after the binding name.
+    pub fn get_inlay_hints(&self, document_text: &str, range: Range) -> Vec<InlayHint> {
+        let mut hints = Vec::new();
+
+        for (line_num, line) in document_text.lines().enumerate() {
+            let line_num = line_num as u32;
+            if line_num < range.start.line || line_num > range.end.line {
+                continue;
+            }
+
+            for binding in find_untyped_bindings(line) {
+                let Some(type_name) = infer_expression_type(&binding.initializer) else {
+                    continue;
+                };
+                let position = Position::new(line_num, binding.name_end as u32);
+                if position_in_range(position, range) {
+                    hints.push(InlayHint::type_hint(position, type_name));
+                }
+            }
+        }
+
+        hints
+    }
+}
+
+impl Default for InlayHintProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inferred type of the untyped `let name = ..` binding on `line`, if any
+pub(crate) fn infer_binding_type(line: &str, name: &str) -> Option<&'static str> {
+    let chars: Vec<char> = line.chars().collect();
+    find_untyped_bindings(line)
+        .into_iter()
+        .find(|binding| {
+            let start = binding.name_end.checked_sub(name.chars().count());
+            start.is_some_and(|start| {
+                chars[start..binding.name_end]
+                    .iter()
+                    .copied()
+                    .eq(name.chars())
+            })
+        })
+        .and_then(|binding| infer_expression_type(&binding.initializer))
+}
+
+/// A `let` binding without a type annotation
+struct UntypedBinding {
+    /// Character offset just past the binding name
+    name_end: usize,
+    /// Initializer source text
+    initializer: String,
+}
+
+/// Find all `let name = expr` bindings on a line that omit a type
+fn find_untyped_bindings(line: &str) -> Vec<UntypedBinding> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut bindings = Vec::new();
+    let mut i = 0;
+    let mut in_string = false;
+
+    while i < chars.len() {
+        if chars[i] == '"' {
+            in_string = !in_string;
+            i += 1;
+            continue;
+        }
+        if in_string || !starts_keyword(&chars, i, "let") {
+            i += 1;
+            continue;
+        }
+
+        let mut pos = skip_whitespace(&chars, i + 3);
+        if starts_keyword(&chars, pos, "mut") {
+            pos = skip_whitespace(&chars, pos + 3);
+        }
+
+        let name_start = pos;
+        while pos < chars.len() && is_identifier_char(chars[pos]) {
+            pos += 1;
+        }
+        let name_end = pos;
+        i = pos.max(i + 3);
+        if name_start == name_end {
+            continue; // destructuring pattern or malformed binding
+        }
+
+        // Only `=` (not `==`) directly after the name; `:` means a typed binding
+        pos = skip_whitespace(&chars, pos);
+        if chars.get(pos) != Some(&'=') || chars.get(pos + 1) == Some(&'=') {
+            continue;
+        }
+
+        let initializer = initializer_text(&chars[pos + 1..]);
+        bindings.push(UntypedBinding {
+            name_end,
+            initializer,
+        });
+    }
+
+    bindings
+}
+
+/// Initializer text up to the terminating `;` (outside string literals)
+fn initializer_text(chars: &[char]) -> String {
+    let mut in_string = false;
+    let mut text = String::new();
+    for &c in chars {
+        if c == '"' {
+            in_string = !in_string;
+        } else if c == ';' && !in_string {
+            break;
+        }
+        text.push(c);
+    }
+    text.trim().to_string()
+}
+
+/// Infer the type of an initializer expression, if it is unambiguous
+fn infer_expression_type(source: &str) -> Option<&'static str> {
+    if source.is_empty() {
+        return None;
+    }
+    let ast = Parser::new(source).parse().ok()?;
+    match ast.nodes() {
+        [node] => infer_node_type(node),
+        _ => None,
+    }
+}
+
+/// Infer the type of an expression node built from literals
+fn infer_node_type(node: &AstNode) -> Option<&'static str> {
+    match node {
+        AstNode::IntegerLiteral(_) => Some("i64"),
+        AstNode::FloatLiteral(_) => Some("f64"),
+        AstNode::StringLiteral(_) | AstNode::FString { .. } => Some("String"),
+        AstNode::BooleanLiteral(_) => Some("bool"),
+        AstNode::CharLiteral(_) => Some("char"),
+        AstNode::UnaryOp { op, operand } => {
+            let operand_type = infer_node_type(operand)?;
+            match op {
+                UnaryOperator::Not if operand_type == "bool" => Some("bool"),
+                UnaryOperator::Negate | UnaryOperator::Plus
+                    if matches!(operand_type, "i64" | "f64") =>
+                {
+                    Some(operand_type)
+                }
+                _ => None,
+            }
+        }
+        AstNode::BinaryOp { op, left, right } => {
+            let left_type = infer_node_type(left)?;
+            let right_type = infer_node_type(right)?;
+            if left_type != right_type {
+                return None;
+            }
+            match op {
+                BinaryOperator::Equal
+                | BinaryOperator::NotEqual
+                | BinaryOperator::LessThan
+                | BinaryOperator::GreaterThan
+                | BinaryOperator::LessEqual
+                | BinaryOperator::GreaterEqual => Some("bool"),
+                BinaryOperator::And | BinaryOperator::Or if left_type == "bool" => Some("bool"),
+                BinaryOperator::Add if left_type == "String" => Some("String"),
+                BinaryOperator::Add
+                | BinaryOperator::Subtract
+                | BinaryOperator::Multiply
+                | BinaryOperator::Divide
+                | BinaryOperator::Modulo
+                    if matches!(left_type, "i64" | "f64") =>
+                {
+                    Some(left_type)
+                }
+                BinaryOperator::BitAnd
+                | BinaryOperator::BitOr
+                | BinaryOperator::BitXor
+                | BinaryOperator::Shl
+                | BinaryOperator::Shr
+                    if left_type == "i64" =>
+                {
+                    Some("i64")
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Check whether the keyword starts at `pos` as a whole word
+fn starts_keyword(chars: &[char], pos: usize, keyword: &str) -> bool {
+    let len = keyword.len();
+    if pos + len > chars.len() {
+        return false;
+    }
+    let matches = chars[pos..pos + len].iter().copied().eq(keyword.chars());
+    let boundary_before = pos == 0 || !is_identifier_char(chars[pos - 1]);
+    let boundary_after = chars.get(pos + len).is_none_or(|c| !is_identifier_char(*c));
+    matches && boundary_before && boundary_after
+}
+
+fn skip_whitespace(chars: &[char], mut pos: usize) -> usize {
+    while pos < chars.len() && chars[pos].is_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn position_in_range(position: Position, range: Range) -> bool {
+    let after_start =
+        (position.line, position.character) >= (range.start.line, range.start.character);
+    let before_end = (position.line, position.character) <= (range.end.line, range.end.character);
+    after_start && before_end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::protocol::InlayHintKind;
+
+    fn whole_document() -> Range {
+        Range::new(Position::new(0, 0), Position::new(u32::MAX, u32::MAX))
+    }
+
+    #[test]
+    fn test_hint_for_simple_binding() {
+        let provider = InlayHintProvider::new();
+        let hints = provider.get_inlay_hints("let x = 5;", whole_document());
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].label, ": i64");
+        assert_eq!(hints[0].position, Position::new(0, 5));
+        assert_eq!(hints[0].kind, Some(InlayHintKind::Type));
+    }
+
+    #[test]
+    fn test_no_hint_for_explicit_type() {
+        let provider = InlayHintProvider::new();
+        let hints = provider.get_inlay_hints("let x: i64 = 5;", whole_document());
+        assert!(hints.is_empty());
+    }
+
+    #[test]
+    fn test_no_hint_when_type_is_uncertain() {
+        let provider = InlayHintProvider::new();
+        let text = "let a = foo();\nlet b = y + 1;\nlet c = 1 + 2.0;\nlet (d, e) = (1, 2);";
+        assert!(provider.get_inlay_hints(text, whole_document()).is_empty());
+    }
+
+    #[test]
+    fn test_hints_for_operators_and_mut() {
+        let provider = InlayHintProvider::new();
+        let text = "    let mut total = -1.5 * 2.0;\nlet ok = 1 < 2; let s = \"a;b\";";
+        let hints = provider.get_inlay_hints(text, whole_document());
+
+        let labels: Vec<(u32, u32, &str)> = hints
+            .iter()
+            .map(|h| (h.position.line, h.position.character, h.label.as_str()))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![(0, 17, ": f64"), (1, 6, ": bool"), (1, 21, ": String")]
+        );
+    }
+
+    #[test]
+    fn test_hints_limited_to_range() {
+        let provider = InlayHintProvider::new();
+        let text = "let a = 1;\nlet b = 2;\nlet c = 3;";
+        let range = Range::new(Position::new(1, 0), Position::new(1, 10));
+        let hints = provider.get_inlay_hints(text, range);
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].position, Position::new(1, 5));
+    }
+}
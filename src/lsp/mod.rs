@@ -6,21 +6,41 @@
 // - Text document synchronization
 // - Basic diagnostics
 // - Position/range utilities
+// - Inlay type hints for let bindings
+// - Hover with symbol declarations
+// - Document formatting
+// - Semantic tokens for highlighting
+// - Signature help for calls
+// - Scope-aware rename
 
 /// Code completion support
 pub mod completion;
 /// Diagnostic reporting
 pub mod diagnostics;
+/// Document formatting
+pub mod formatting;
+/// Hover information for symbols
+pub mod hover;
+/// Inlay hints for inferred types
+pub mod inlay_hints;
 /// LSP protocol types and messages
 pub mod protocol;
+/// Semantic tokens for syntax highlighting
+pub mod semantic_tokens;
 /// LSP server implementation
 pub mod server;
+/// Signature help for function calls
+pub mod signature_help;
 /// Symbol resolution
 pub mod symbols;
 /// Text document synchronization
 pub mod text_sync;
 
 pub use protocol::{
-    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Location, Position, Range,
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Hover, InlayHint,
+    InlayHintKind, Location, MarkupContent, MarkupKind, ParameterInformation, Position, Range,
+    SemanticTokenType, SemanticTokens, SemanticTokensLegend, SemanticTokensOptions,
+    ServerCapabilities, SignatureHelp, SignatureInformation, TextEdit, WorkspaceEdit,
 };
 pub use server::LspServer;
+pub use symbols::RenameError;
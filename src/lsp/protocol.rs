@@ -2,6 +2,7 @@
 // Based on Language Server Protocol 3.17 specification
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Position in a text document (zero-based)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -132,8 +133,31 @@ impl Location {
     }
 }
 
-/// Completion item kind
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Text edit applied to a document (e.g., by textDocument/formatting)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextEdit {
+    /// Range of the text being replaced
+    pub range: Range,
+    /// Replacement text
+    pub new_text: String,
+}
+
+impl TextEdit {
+    /// Create a new text edit
+    pub fn new(range: Range, new_text: String) -> Self {
+        Self { range, new_text }
+    }
+}
+
+/// Edits to several documents at once (e.g., by textDocument/rename)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceEdit {
+    /// Edits for each document URI, in document order
+    pub changes: BTreeMap<String, Vec<TextEdit>>,
+}
+
+/// Completion item kind (ordered by protocol value)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum CompletionItemKind {
     /// Plain text completion
     Text = 1,
@@ -233,6 +257,85 @@ impl CompletionItem {
     }
 }
 
+/// Inlay hint kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InlayHintKind {
+    /// Type annotation hint
+    Type = 1,
+    /// Parameter name hint
+    Parameter = 2,
+}
+
+/// Inlay hint (inline annotation rendered by the editor)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InlayHint {
+    /// Position the hint is rendered at
+    pub position: Position,
+    /// Hint text (e.g., ": i64")
+    pub label: String,
+    /// The kind of this hint
+    pub kind: Option<InlayHintKind>,
+    /// Render padding before the hint
+    pub padding_left: Option<bool>,
+    /// Render padding after the hint
+    pub padding_right: Option<bool>,
+}
+
+impl InlayHint {
+    /// Create a type hint (`: <type>`) at a position
+    pub fn type_hint(position: Position, type_name: &str) -> Self {
+        Self {
+            position,
+            label: format!(": {}", type_name),
+            kind: Some(InlayHintKind::Type),
+            padding_left: Some(false),
+            padding_right: Some(false),
+        }
+    }
+}
+
+/// Format of markup content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarkupKind {
+    /// Plain text
+    #[serde(rename = "plaintext")]
+    PlainText,
+    /// Markdown
+    #[serde(rename = "markdown")]
+    Markdown,
+}
+
+/// Text with a markup format
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarkupContent {
+    /// Format of `value`
+    pub kind: MarkupKind,
+    /// The content itself
+    pub value: String,
+}
+
+/// Hover result (textDocument/hover)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hover {
+    /// Content shown in the hover popup
+    pub contents: MarkupContent,
+    /// Range of the hovered symbol
+    pub range: Option<Range>,
+}
+
+impl Hover {
+    /// Create a hover showing `code` as a Ruchy code block
+    pub fn code(code: &str, range: Range) -> Self {
+        Self {
+            contents: MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```ruchy\n{}\n```", code),
+            },
+            range: Some(range),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -311,4 +414,149 @@ mod tests {
         assert!(item.documentation.is_some());
         assert_eq!(item.insert_text, Some("println($0)".to_string()));
     }
+
+    #[test]
+    fn test_inlay_type_hint() {
+        let hint = InlayHint::type_hint(Position::new(2, 7), "i64");
+        assert_eq!(hint.label, ": i64");
+        assert_eq!(hint.kind, Some(InlayHintKind::Type));
+        assert_eq!(hint.position, Position::new(2, 7));
+    }
+}
+
+/// Parameter of a signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterInformation {
+    /// Parameter text as it appears in the signature label (e.g., "n: i64")
+    pub label: String,
+}
+
+/// Signature of a callable (function or builtin)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureInformation {
+    /// Full signature (e.g., "fun factorial(n: i64) -> i64")
+    pub label: String,
+    /// Human-readable description
+    pub documentation: Option<String>,
+    /// Parameters in declaration order
+    pub parameters: Vec<ParameterInformation>,
+}
+
+/// Signature help result (textDocument/signatureHelp)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureHelp {
+    /// Candidate signatures (Ruchy has no overloading, so at most one)
+    pub signatures: Vec<SignatureInformation>,
+    /// Index of the active signature
+    pub active_signature: Option<u32>,
+    /// Index of the parameter being typed
+    pub active_parameter: Option<u32>,
+}
+
+/// Semantic token type, in legend order (the encoded type is the index)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SemanticTokenType {
+    /// Language keyword
+    Keyword = 0,
+    /// Function name (declaration or call)
+    Function = 1,
+    /// Method name after `.`
+    Method = 2,
+    /// Function or closure parameter
+    Parameter = 3,
+    /// Local or global variable
+    Variable = 4,
+    /// Struct field after `.`
+    Property = 5,
+    /// Struct or enum name
+    Type = 6,
+    /// Enum variant
+    EnumMember = 7,
+    /// String or character literal
+    String = 8,
+    /// Numeric literal
+    Number = 9,
+    /// Operator
+    Operator = 10,
+}
+
+impl SemanticTokenType {
+    /// Every token type, in legend order
+    pub const ALL: [SemanticTokenType; 11] = [
+        SemanticTokenType::Keyword,
+        SemanticTokenType::Function,
+        SemanticTokenType::Method,
+        SemanticTokenType::Parameter,
+        SemanticTokenType::Variable,
+        SemanticTokenType::Property,
+        SemanticTokenType::Type,
+        SemanticTokenType::EnumMember,
+        SemanticTokenType::String,
+        SemanticTokenType::Number,
+        SemanticTokenType::Operator,
+    ];
+
+    /// Name of this type in the LSP specification
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SemanticTokenType::Keyword => "keyword",
+            SemanticTokenType::Function => "function",
+            SemanticTokenType::Method => "method",
+            SemanticTokenType::Parameter => "parameter",
+            SemanticTokenType::Variable => "variable",
+            SemanticTokenType::Property => "property",
+            SemanticTokenType::Type => "type",
+            SemanticTokenType::EnumMember => "enumMember",
+            SemanticTokenType::String => "string",
+            SemanticTokenType::Number => "number",
+            SemanticTokenType::Operator => "operator",
+        }
+    }
+}
+
+/// Token types and modifiers the server encodes semantic tokens with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SemanticTokensLegend {
+    /// Token type names; a token's type is an index into this list
+    pub token_types: Vec<String>,
+    /// Token modifier names; a token's modifiers are a bit set over this list
+    pub token_modifiers: Vec<String>,
+}
+
+impl Default for SemanticTokensLegend {
+    /// Legend for `SemanticTokenType` (no modifiers)
+    fn default() -> Self {
+        Self {
+            token_types: SemanticTokenType::ALL
+                .iter()
+                .map(|token_type| token_type.as_str().to_string())
+                .collect(),
+            token_modifiers: vec![],
+        }
+    }
+}
+
+/// Semantic tokens support advertised in the server capabilities
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SemanticTokensOptions {
+    /// Legend used to encode tokens
+    pub legend: SemanticTokensLegend,
+    /// Whether whole-document requests (semanticTokens/full) are supported
+    pub full: bool,
+}
+
+/// Semantic tokens result (textDocument/semanticTokens/full)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SemanticTokens {
+    /// Five integers per token: delta line, delta start character, length,
+    /// token type and modifier bits. The start is relative to the previous
+    /// token when both are on the same line.
+    pub data: Vec<u32>,
+}
+
+/// Capabilities reported to the client in the initialize response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerCapabilities {
+    /// Semantic highlighting support
+    pub semantic_tokens_provider: Option<SemanticTokensOptions>,
 }
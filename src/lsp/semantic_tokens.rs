@@ -0,0 +1,385 @@
+// Semantic Tokens Provider
+// Classifies tokens for editor highlighting (textDocument/semanticTokens/full)
+//
+// Tokens come from the parser's lexer (`lex`). Identifiers are classified
+// with names collected by walking the AST:
+// - after `.`, a name is a method when called and a property otherwise
+// - inside a function, its parameters (and those of closures in its body)
+//   are parameters
+// - declared functions, and any other name called with `(`, are functions
+// - struct and enum names (and built-in types like i64) are types, and enum
+//   variants are enum members
+// - everything else is a variable
+//
+// Punctuation is left to the editor's grammar. A multi-line string is split
+// into one token per line, since clients need not support tokens that span
+// lines.
+
+use super::protocol::{SemanticTokenType, SemanticTokens};
+use crate::interpreter::ast::AstNode;
+use crate::interpreter::parser::{lex, LexedToken, Parser, TokenClass};
+use crate::interpreter::visitor::{walk_node, Visitor};
+use std::collections::{HashMap, HashSet};
+
+/// Built-in type names, highlighted as types in annotations
+const PRIMITIVE_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "isize", "usize", "f32", "f64", "bool",
+    "char", "str", "String",
+];
+
+/// Semantic tokens for a whole document
+///
+/// Returns `None` when the lexer rejects the source (e.g. an unterminated
+/// string). A parse error only costs the classification of names declared
+/// in the items that failed to parse.
+pub fn semantic_tokens(source: &str) -> Option<SemanticTokens> {
+    let tokens = lex(source).ok()?;
+    let (ast, _) = Parser::new(source).parse_recovering();
+    let mut names = Names::default();
+    names.visit_ast(&ast);
+
+    let lines: Vec<&str> = source
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+    let text = |index: usize| {
+        tokens
+            .get(index)
+            .map_or("", |token| token_text(&lines, token))
+    };
+
+    let no_params = HashSet::new();
+    // Functions whose parameter list or body we are in, with the brace depth
+    // of the body once it has opened
+    let mut functions: Vec<(&HashSet<String>, Option<usize>)> = vec![];
+    let mut depth = 0;
+    let mut encoder = Encoder::default();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let token_type = match token.class {
+            TokenClass::Keyword => SemanticTokenType::Keyword,
+            TokenClass::Number => SemanticTokenType::Number,
+            TokenClass::String => SemanticTokenType::String,
+            TokenClass::Operator => SemanticTokenType::Operator,
+            TokenClass::Punctuation => {
+                match text(index) {
+                    "{" => {
+                        depth += 1;
+                        if let Some((_, body @ None)) = functions.last_mut() {
+                            *body = Some(depth);
+                        }
+                    }
+                    "}" => {
+                        if functions
+                            .last()
+                            .is_some_and(|(_, body)| *body == Some(depth))
+                        {
+                            functions.pop();
+                        }
+                        depth = depth.saturating_sub(1);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            TokenClass::Identifier => {
+                let name = text(index);
+                let previous = index.checked_sub(1).map_or("", text);
+                let called = text(index + 1) == "(";
+                if previous == "fun" {
+                    let params = names.params.get(name).unwrap_or(&no_params);
+                    functions.push((params, None));
+                }
+                let params = match functions.last() {
+                    Some((params, _)) => *params,
+                    None => names.params.get("").unwrap_or(&no_params),
+                };
+
+                if previous == "." {
+                    if called {
+                        SemanticTokenType::Method
+                    } else {
+                        SemanticTokenType::Property
+                    }
+                } else if previous != "fun" && params.contains(name) {
+                    SemanticTokenType::Parameter
+                } else if names.functions.contains(name) || called {
+                    SemanticTokenType::Function
+                } else if names.types.contains(name) || PRIMITIVE_TYPES.contains(&name) {
+                    SemanticTokenType::Type
+                } else if names.variants.contains(name) {
+                    SemanticTokenType::EnumMember
+                } else {
+                    SemanticTokenType::Variable
+                }
+            }
+        };
+        encoder.push(&lines, token, token_type);
+    }
+
+    Some(SemanticTokens { data: encoder.data })
+}
+
+/// Names declared in a program
+#[derive(Default)]
+struct Names {
+    functions: HashSet<String>,
+    /// Parameters by enclosing function name; closures outside any function
+    /// are filed under ""
+    params: HashMap<String, HashSet<String>>,
+    types: HashSet<String>,
+    variants: HashSet<String>,
+    /// Function whose body is being visited ("" at the top level)
+    current: String,
+}
+
+impl Visitor for Names {
+    fn visit_node(&mut self, node: &AstNode) {
+        match node {
+            AstNode::TestDef { name, .. } => {
+                self.functions.insert(name.clone());
+            }
+            AstNode::StructDef { name, .. }
+            | AstNode::ImplBlock {
+                type_name: name, ..
+            } => {
+                self.types.insert(name.clone());
+            }
+            AstNode::EnumDef { name, variants } => {
+                self.types.insert(name.clone());
+                self.variants
+                    .extend(variants.iter().map(|variant| variant.name.clone()));
+            }
+            _ => {}
+        }
+        walk_node(self, node);
+    }
+
+    fn visit_function_def(&mut self, name: &str, params: &[String], body: &[AstNode]) {
+        self.functions.insert(name.to_string());
+        self.params
+            .entry(name.to_string())
+            .or_default()
+            .extend(params.iter().cloned());
+        let enclosing = std::mem::replace(&mut self.current, name.to_string());
+        self.visit_statements(body);
+        self.current = enclosing;
+    }
+
+    fn visit_closure(&mut self, params: &[String], body: &[AstNode]) {
+        self.params
+            .entry(self.current.clone())
+            .or_default()
+            .extend(params.iter().cloned());
+        self.visit_statements(body);
+    }
+}
+
+/// Source text of a token's first line
+fn token_text<'a>(lines: &[&'a str], token: &LexedToken) -> &'a str {
+    let line = lines.get(token.start.line - 1).copied().unwrap_or("");
+    let end = if token.end.line == token.start.line {
+        token.end.column - 1
+    } else {
+        usize::MAX
+    };
+    let start = char_offset(line, token.start.column - 1);
+    &line[start..char_offset(line, end)]
+}
+
+/// Byte offset of the `chars`-th character of `line` (clamped to its length)
+fn char_offset(line: &str, chars: usize) -> usize {
+    line.char_indices()
+        .nth(chars)
+        .map_or(line.len(), |(offset, _)| offset)
+}
+
+/// UTF-16 length of the first `chars` characters of `line`
+fn utf16_column(line: &str, chars: usize) -> u32 {
+    line.chars().take(chars).map(char::len_utf16).sum::<usize>() as u32
+}
+
+/// Builds the delta-encoded token array
+#[derive(Default)]
+struct Encoder {
+    data: Vec<u32>,
+    line: u32,
+    character: u32,
+}
+
+impl Encoder {
+    /// Append a token, one entry per source line it covers
+    fn push(&mut self, lines: &[&str], token: &LexedToken, token_type: SemanticTokenType) {
+        for line_number in token.start.line..=token.end.line {
+            let line = lines.get(line_number - 1).copied().unwrap_or("");
+            let from = if line_number == token.start.line {
+                token.start.column - 1
+            } else {
+                0
+            };
+            let to = if line_number == token.end.line {
+                token.end.column - 1
+            } else {
+                usize::MAX
+            };
+            let start = utf16_column(line, from);
+            let end = utf16_column(line, to);
+            if end > start {
+                self.emit((line_number - 1) as u32, start, end - start, token_type);
+            }
+        }
+    }
+
+    fn emit(&mut self, line: u32, character: u32, length: u32, token_type: SemanticTokenType) {
+        let delta_line = line - self.line;
+        let delta_start = if delta_line == 0 {
+            character - self.character
+        } else {
+            character
+        };
+        self.data
+            .extend([delta_line, delta_start, length, token_type as u32, 0]);
+        self.line = line;
+        self.character = character;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use SemanticTokenType::*;
+
+    /// Helper: decode the delta array into (line, character, length, type)
+    fn decode(source: &str) -> Vec<(u32, u32, u32, SemanticTokenType)> {
+        let data = semantic_tokens(source).unwrap().data;
+        let mut line = 0;
+        let mut character = 0;
+        data.chunks(5)
+            .map(|entry| {
+                if entry[0] > 0 {
+                    line += entry[0];
+                    character = 0;
+                }
+                character += entry[1];
+                assert_eq!(entry[4], 0);
+                (
+                    line,
+                    character,
+                    entry[2],
+                    SemanticTokenType::ALL[entry[3] as usize],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_function_tokens() {
+        let source = "fun add(a, b) {\n    let sum = a + b;\n    sum\n}\nadd(1, 2)";
+        assert_eq!(
+            decode(source),
+            vec![
+                (0, 0, 3, Keyword),
+                (0, 4, 3, Function),
+                (0, 8, 1, Parameter),
+                (0, 11, 1, Parameter),
+                (1, 4, 3, Keyword),
+                (1, 8, 3, Variable),
+                (1, 12, 1, Operator),
+                (1, 14, 1, Parameter),
+                (1, 16, 1, Operator),
+                (1, 18, 1, Parameter),
+                (2, 4, 3, Variable),
+                (4, 0, 3, Function),
+                (4, 4, 1, Number),
+                (4, 7, 1, Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parameters_are_scoped_to_their_function() {
+        let source = "fun f(n) { n }\nfun g() { let n = 1; n }";
+        let types: Vec<_> = decode(source)
+            .into_iter()
+            .filter(|&(_, _, length, _)| length == 1)
+            .filter(|&(_, _, _, token_type)| token_type != Number && token_type != Operator)
+            .map(|(line, _, _, token_type)| (line, token_type))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                (0, Function),
+                (0, Parameter),
+                (0, Parameter),
+                (1, Function),
+                (1, Variable),
+                (1, Variable),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_types_members_and_calls() {
+        let source = "enum Color { Red }\nlet c = Color::Red;\nlet n = p.x + v.len();\nprintln(n)";
+        let tokens = decode(source);
+        let at = |line, character| {
+            tokens
+                .iter()
+                .find(|token| (token.0, token.1) == (line, character))
+                .map(|token| token.3)
+        };
+        assert_eq!(at(0, 5), Some(Type));
+        assert_eq!(at(0, 13), Some(EnumMember));
+        assert_eq!(at(1, 8), Some(Type));
+        assert_eq!(at(1, 15), Some(EnumMember));
+        assert_eq!(at(2, 8), Some(Variable));
+        assert_eq!(at(2, 10), Some(Property));
+        assert_eq!(at(2, 16), Some(Method));
+        assert_eq!(at(3, 0), Some(Function));
+    }
+
+    #[test]
+    fn test_closure_parameters() {
+        let source = "let double = |x| x * 2;";
+        let tokens = decode(source);
+        assert_eq!(tokens[1], (0, 4, 6, Variable));
+        assert_eq!(tokens[4], (0, 14, 1, Parameter));
+        assert_eq!(tokens[6], (0, 17, 1, Parameter));
+    }
+
+    #[test]
+    fn test_multiline_string_split_per_line() {
+        let source = "let s = \"ab\ncd\";\n// x\nlet t = \"😀\" + s;";
+        let tokens = decode(source);
+        assert_eq!(tokens[3], (0, 8, 3, String));
+        assert_eq!(tokens[4], (1, 0, 3, String));
+        assert_eq!(tokens[8], (3, 8, 4, String));
+        assert_eq!(tokens[10], (3, 15, 1, Variable));
+    }
+
+    #[test]
+    fn test_unclosed_parameter_list() {
+        // `f` fails to parse, so `x` is not known to be its parameter
+        let source = "fun g() { 2 }\nfun f(x: Option<i64> {";
+        assert_eq!(
+            decode(source),
+            vec![
+                (0, 0, 3, Keyword),
+                (0, 4, 1, Function),
+                (0, 10, 1, Number),
+                (1, 0, 3, Keyword),
+                (1, 4, 1, Function),
+                (1, 6, 1, Variable),
+                (1, 9, 6, Variable),
+                (1, 15, 1, Operator),
+                (1, 16, 3, Type),
+                (1, 19, 1, Operator),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        assert!(semantic_tokens("let s = \"open").is_none());
+    }
+}
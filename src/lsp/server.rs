@@ -3,8 +3,17 @@
 
 use super::completion::CompletionProvider;
 use super::diagnostics::DiagnosticsProvider;
-use super::protocol::{CompletionItem, Diagnostic, Location, Position, TextDocumentItem};
-use super::symbols::SymbolTable;
+use super::formatting::format_document;
+use super::hover::HoverProvider;
+use super::inlay_hints::InlayHintProvider;
+use super::protocol::{
+    CompletionItem, Diagnostic, Hover, InlayHint, Location, Position, Range, SemanticTokens,
+    SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities, SignatureHelp,
+    TextDocumentItem, TextEdit, WorkspaceEdit,
+};
+use super::semantic_tokens::semantic_tokens;
+use super::signature_help::SignatureHelpProvider;
+use super::symbols::{find_definition, rename_symbol, sort_locations, RenameError, SymbolTable};
 use super::text_sync::TextDocumentManager;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -14,6 +23,9 @@ pub struct LspServer {
     text_documents: Arc<Mutex<TextDocumentManager>>,
     diagnostics: DiagnosticsProvider,
     completion: CompletionProvider,
+    inlay_hints: InlayHintProvider,
+    hover: HoverProvider,
+    signature_help: SignatureHelpProvider,
     symbol_tables: Arc<Mutex<HashMap<String, SymbolTable>>>,
     initialized: bool,
 }
@@ -25,6 +37,9 @@ impl LspServer {
             text_documents: Arc::new(Mutex::new(TextDocumentManager::new())),
             diagnostics: DiagnosticsProvider::new(),
             completion: CompletionProvider::new(),
+            inlay_hints: InlayHintProvider::new(),
+            hover: HoverProvider::new(),
+            signature_help: SignatureHelpProvider::new(),
             symbol_tables: Arc::new(Mutex::new(HashMap::new())),
             initialized: false,
         }
@@ -36,6 +51,16 @@ impl LspServer {
         true
     }
 
+    /// Capabilities advertised in the initialize response
+    pub fn capabilities(&self) -> ServerCapabilities {
+        ServerCapabilities {
+            semantic_tokens_provider: Some(SemanticTokensOptions {
+                legend: SemanticTokensLegend::default(),
+                full: true,
+            }),
+        }
+    }
+
     /// Check if server is initialized
     pub fn is_initialized(&self) -> bool {
         self.initialized
@@ -116,27 +141,121 @@ impl LspServer {
         self.completion.get_completions(text, position)
     }
 
-    /// Go to definition
-    pub fn goto_definition(&self, uri: &str, position: Position) -> Option<Location> {
+    /// Get inlay hints (textDocument/inlayHint) within a range
+    pub fn get_inlay_hints(&self, uri: &str, range: Range) -> Vec<InlayHint> {
+        if !self.initialized {
+            return vec![];
+        }
+
+        let docs = self.text_documents.lock().unwrap();
+        let text = match docs.get_text(uri) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        self.inlay_hints.get_inlay_hints(text, range)
+    }
+
+    /// Get hover information (textDocument/hover) at a position
+    pub fn get_hover(&self, uri: &str, position: Position) -> Option<Hover> {
         if !self.initialized {
             return None;
         }
 
-        // Get document text
         let docs = self.text_documents.lock().unwrap();
         let text = docs.get_text(uri)?;
 
-        // Get symbol table
         let tables = self.symbol_tables.lock().unwrap();
         let table = tables.get(uri)?;
 
-        // Find symbol at cursor position
-        let symbol_name = table.find_symbol_at_position(text, position)?;
+        self.hover.get_hover(text, table, position)
+    }
+
+    /// Get signature help (textDocument/signatureHelp) for the call at a position
+    pub fn get_signature_help(&self, uri: &str, position: Position) -> Option<SignatureHelp> {
+        if !self.initialized {
+            return None;
+        }
 
-        // Look up symbol definition
-        let symbol = table.get_symbol(&symbol_name)?;
+        let docs = self.text_documents.lock().unwrap();
+        let text = docs.get_text(uri)?;
+
+        let tables = self.symbol_tables.lock().unwrap();
+        let table = tables.get(uri)?;
 
-        Some(symbol.location.clone())
+        self.signature_help
+            .get_signature_help(text, table, position)
+    }
+
+    /// Format a document (textDocument/formatting)
+    ///
+    /// Returns one edit replacing the whole document, or none when it is
+    /// already formatted or cannot be formatted (see `formatting`).
+    pub fn format_document(&self, uri: &str) -> Vec<TextEdit> {
+        if !self.initialized {
+            return vec![];
+        }
+
+        let docs = self.text_documents.lock().unwrap();
+        let Some(text) = docs.get_text(uri) else {
+            return vec![];
+        };
+
+        let formatted = format_document(text);
+        if formatted == text {
+            return vec![];
+        }
+        vec![TextEdit::new(document_range(text), formatted)]
+    }
+
+    /// Semantic tokens for a whole document (textDocument/semanticTokens/full)
+    ///
+    /// Token types index into the legend from `capabilities`.
+    pub fn get_semantic_tokens(&self, uri: &str) -> Option<SemanticTokens> {
+        if !self.initialized {
+            return None;
+        }
+
+        let docs = self.text_documents.lock().unwrap();
+        let text = docs.get_text(uri)?;
+
+        semantic_tokens(text)
+    }
+
+    /// Go to definition (textDocument/definition)
+    ///
+    /// Variables resolve to the nearest enclosing binding, so a shadowed name
+    /// jumps to the `let` that is actually in scope at the cursor.
+    pub fn goto_definition(&self, uri: &str, position: Position) -> Option<Location> {
+        if !self.initialized {
+            return None;
+        }
+
+        let docs = self.text_documents.lock().unwrap();
+        let text = docs.get_text(uri)?;
+
+        find_definition(uri, text, position)
+    }
+
+    /// Rename the symbol at a position (textDocument/rename)
+    ///
+    /// Only uses that resolve to the same binding are edited; see
+    /// `rename_symbol`. An unknown document, or a server that is not yet
+    /// initialized, has no symbol to rename.
+    pub fn rename(
+        &self,
+        uri: &str,
+        position: Position,
+        new_name: &str,
+    ) -> Result<WorkspaceEdit, RenameError> {
+        if !self.initialized {
+            return Err(RenameError::NoSymbol);
+        }
+
+        let docs = self.text_documents.lock().unwrap();
+        let text = docs.get_text(uri).ok_or(RenameError::NoSymbol)?;
+
+        rename_symbol(uri, text, position, new_name)
     }
 
     /// Find all references
@@ -175,6 +294,7 @@ impl LspServer {
             locations.push(symbol.location.clone());
         }
 
+        sort_locations(&mut locations);
         locations
     }
 
@@ -184,6 +304,16 @@ impl LspServer {
     }
 }
 
+/// Range covering all of `text`
+fn document_range(text: &str) -> Range {
+    let lines = text.split('\n').count() - 1;
+    let last = text.rsplit('\n').next().unwrap_or("");
+    Range::new(
+        Position::new(0, 0),
+        Position::new(lines as u32, last.encode_utf16().count() as u32),
+    )
+}
+
 impl Default for LspServer {
     fn default() -> Self {
         Self::new()
@@ -193,6 +323,7 @@ impl Default for LspServer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lsp::protocol::MarkupKind;
 
     #[test]
     fn test_lsp_initialize() {
@@ -358,6 +489,63 @@ mod tests {
         assert_eq!(loc.range.start.line, 1);
     }
 
+    #[test]
+    fn test_goto_definition_from_call_site() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun square(n) {\n    n * n\n}\nlet y = square(4);".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        // Cursor on "square" in the call on line 3
+        let location = server
+            .goto_definition("file:///test.ruchy", Position::new(3, 10))
+            .unwrap();
+        assert_eq!(location.uri, "file:///test.ruchy");
+        assert_eq!(
+            location.range,
+            Range::new(Position::new(0, 4), Position::new(0, 10))
+        );
+    }
+
+    #[test]
+    fn test_goto_definition_respects_shadowing() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "let x = 1;\nfun main() {\n    let x = 2;\n    println(x);\n}\nprintln(x);"
+                .to_string(),
+        };
+        server.text_document_did_open(item);
+
+        // Inner use resolves to the shadowing `let` inside main
+        let location = server
+            .goto_definition("file:///test.ruchy", Position::new(3, 12))
+            .unwrap();
+        assert_eq!(
+            location.range,
+            Range::new(Position::new(2, 8), Position::new(2, 9))
+        );
+
+        // Use after main's block closes resolves to the outer `let`
+        let location = server
+            .goto_definition("file:///test.ruchy", Position::new(5, 8))
+            .unwrap();
+        assert_eq!(
+            location.range,
+            Range::new(Position::new(0, 4), Position::new(0, 5))
+        );
+    }
+
     #[test]
     fn test_goto_definition_before_initialize() {
         let server = LspServer::new();
@@ -409,4 +597,293 @@ mod tests {
         let refs = server.find_references("file:///nonexistent.ruchy", Position::new(0, 0));
         assert_eq!(refs.len(), 0);
     }
+
+    #[test]
+    fn test_get_inlay_hints() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun main() {\n    let x = 5;\n    let y: i64 = 6;\n}".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        let range = Range::new(Position::new(0, 0), Position::new(3, 1));
+        let hints = server.get_inlay_hints("file:///test.ruchy", range);
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].label, ": i64");
+        assert_eq!(hints[0].position, Position::new(1, 9));
+    }
+
+    #[test]
+    fn test_get_inlay_hints_before_initialize() {
+        let server = LspServer::new();
+        let range = Range::new(Position::new(0, 0), Position::new(0, 0));
+        assert!(server
+            .get_inlay_hints("file:///test.ruchy", range)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_hover_on_function_call() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun factorial(n) {\n    if n <= 1 { 1 } else { n * factorial(n - 1) }\n}\nlet x = factorial(5);"
+                .to_string(),
+        };
+        server.text_document_did_open(item);
+
+        // Cursor inside "factorial" in the call on line 3
+        let hover = server
+            .get_hover("file:///test.ruchy", Position::new(3, 10))
+            .unwrap();
+        assert_eq!(hover.contents.kind, MarkupKind::Markdown);
+        assert!(hover.contents.value.contains("fun factorial(n)"));
+        assert_eq!(
+            hover.range,
+            Some(Range::new(Position::new(3, 8), Position::new(3, 17)))
+        );
+
+        let hover = server
+            .get_hover("file:///test.ruchy", Position::new(3, 4))
+            .unwrap();
+        assert!(hover.contents.value.contains("let x"));
+    }
+
+    #[test]
+    fn test_format_document_replaces_whole_document() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun add(a,b){a+b}\nlet x=add(1,2)".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        let edits = server.format_document("file:///test.ruchy");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(
+            edits[0].range,
+            Range::new(Position::new(0, 0), Position::new(1, 14))
+        );
+        assert_eq!(
+            edits[0].new_text,
+            "fun add(a, b) {\n    a + b\n}\n\nlet x = add(1, 2);\n"
+        );
+    }
+
+    #[test]
+    fn test_format_document_already_formatted() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "let x = 1;\n".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        assert!(server.format_document("file:///test.ruchy").is_empty());
+    }
+
+    #[test]
+    fn test_format_document_before_initialize() {
+        let server = LspServer::new();
+        assert!(server.format_document("file:///test.ruchy").is_empty());
+    }
+
+    #[test]
+    fn test_capabilities_semantic_tokens_legend() {
+        let server = LspServer::new();
+        let provider = server.capabilities().semantic_tokens_provider.unwrap();
+        assert!(provider.full);
+        assert_eq!(provider.legend.token_types[0], "keyword");
+        assert_eq!(
+            provider.legend.token_types.len(),
+            crate::lsp::protocol::SemanticTokenType::ALL.len()
+        );
+    }
+
+    #[test]
+    fn test_get_semantic_tokens() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun square(n) {\n    n * n\n}".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        let tokens = server.get_semantic_tokens("file:///test.ruchy").unwrap();
+        let legend = server
+            .capabilities()
+            .semantic_tokens_provider
+            .unwrap()
+            .legend;
+
+        // Decode (delta line, delta start, length, type, modifiers) entries
+        let mut decoded = vec![];
+        let (mut line, mut character) = (0, 0);
+        for entry in tokens.data.chunks(5) {
+            if entry[0] > 0 {
+                character = 0;
+            }
+            line += entry[0];
+            character += entry[1];
+            let token_type = legend.token_types[entry[3] as usize].as_str();
+            decoded.push((line, character, entry[2], token_type));
+        }
+        assert_eq!(
+            decoded,
+            vec![
+                (0, 0, 3, "keyword"),
+                (0, 4, 6, "function"),
+                (0, 11, 1, "parameter"),
+                (1, 4, 1, "parameter"),
+                (1, 6, 1, "operator"),
+                (1, 8, 1, "parameter"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_semantic_tokens_before_initialize() {
+        let server = LspServer::new();
+        assert!(server.get_semantic_tokens("file:///test.ruchy").is_none());
+    }
+
+    #[test]
+    fn test_get_signature_help_after_first_comma() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun power(base, exp) {\n    base\n}\nlet x = power(2, ".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        let help = server
+            .get_signature_help("file:///test.ruchy", Position::new(3, 17))
+            .unwrap();
+        assert_eq!(help.signatures[0].label, "fun power(base, exp)");
+        assert_eq!(help.active_parameter, Some(1));
+
+        let help = server
+            .get_signature_help("file:///test.ruchy", Position::new(3, 14))
+            .unwrap();
+        assert_eq!(help.active_parameter, Some(0));
+    }
+
+    #[test]
+    fn test_get_signature_help_before_initialize() {
+        let server = LspServer::new();
+        let position = Position::new(0, 0);
+        assert!(server
+            .get_signature_help("file:///test.ruchy", position)
+            .is_none());
+    }
+
+    #[test]
+    fn test_rename_function_and_call_sites() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun double(x) {\n    x * 2\n}\nlet y = double(double(1));".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        let edit = server
+            .rename("file:///test.ruchy", Position::new(0, 5), "twice")
+            .unwrap();
+        let edits = &edit.changes["file:///test.ruchy"];
+        let starts: Vec<Position> = edits.iter().map(|edit| edit.range.start).collect();
+        assert_eq!(
+            starts,
+            vec![
+                Position::new(0, 4),
+                Position::new(3, 8),
+                Position::new(3, 15)
+            ]
+        );
+        assert!(edits.iter().all(|edit| edit.new_text == "twice"));
+    }
+
+    #[test]
+    fn test_rename_scoped_local() {
+        let mut server = LspServer::new();
+        server.initialize();
+
+        let item = TextDocumentItem {
+            uri: "file:///test.ruchy".to_string(),
+            language_id: "ruchy".to_string(),
+            version: 1,
+            text: "fun a() {\n    let n = 1;\n    n\n}\nfun b(n) {\n    n\n}".to_string(),
+        };
+        server.text_document_did_open(item);
+
+        let edit = server
+            .rename("file:///test.ruchy", Position::new(2, 4), "count")
+            .unwrap();
+        let ranges: Vec<Range> = edit.changes["file:///test.ruchy"]
+            .iter()
+            .map(|edit| edit.range)
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                Range::new(Position::new(1, 8), Position::new(1, 9)),
+                Range::new(Position::new(2, 4), Position::new(2, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rename_before_initialize() {
+        let server = LspServer::new();
+        let result = server.rename("file:///test.ruchy", Position::new(0, 0), "y");
+        assert_eq!(result, Err(RenameError::NoSymbol));
+    }
+
+    #[test]
+    fn test_get_hover_before_initialize() {
+        let server = LspServer::new();
+        assert_eq!(
+            server.get_hover("file:///test.ruchy", Position::new(0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_hover_nonexistent_document() {
+        let mut server = LspServer::new();
+        server.initialize();
+        assert_eq!(
+            server.get_hover("file:///nonexistent.ruchy", Position::new(0, 0)),
+            None
+        );
+    }
 }
@@ -0,0 +1,368 @@
+// Signature Help Provider
+// Shows the parameters of the call being typed (textDocument/signatureHelp)
+//
+// The text before the cursor is scanned for the innermost unclosed `(` that
+// follows a function name; commas at that call's own nesting level give the
+// active parameter. Strings, character literals and comments are skipped, and
+// a `{` (e.g. a closure body) ends the search. Functions declared in the
+// document come from the symbol table and shadow builtins of the same name.
+
+use super::hover::function_signature;
+use super::protocol::{ParameterInformation, Position, SignatureHelp, SignatureInformation};
+use super::symbols::{SymbolKind, SymbolTable};
+
+/// Builtins with fixed signatures: (name, signature, documentation)
+const BUILTIN_SIGNATURES: &[(&str, &str, &str)] = &[
+    (
+        "println",
+        "fun println(template, args...)",
+        "Prints a message to stdout with newline, filling each {} from args",
+    ),
+    (
+        "print",
+        "fun print(template, args...)",
+        "Prints a message to stdout without newline, filling each {} from args",
+    ),
+    (
+        "format",
+        "fun format(template, args...) -> String",
+        "Returns the template with each {} replaced by the next argument",
+    ),
+    (
+        "read_file",
+        "fun read_file(path: String) -> String",
+        "Reads a whole file into a string",
+    ),
+    (
+        "write_file",
+        "fun write_file(path: String, content: String)",
+        "Writes a string to a file, replacing its contents",
+    ),
+];
+
+/// Signature help provider
+pub struct SignatureHelpProvider;
+
+impl SignatureHelpProvider {
+    /// Create a new signature help provider
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get the signature of the call enclosing a position
+    ///
+    /// The active parameter is the number of commas between the call's `(`
+    /// and the cursor; for a variadic builtin it stays on the last parameter.
+    /// Returns `None` outside a call, or when the callee is not a known
+    /// function.
+    pub fn get_signature_help(
+        &self,
+        document_text: &str,
+        symbols: &SymbolTable,
+        position: Position,
+    ) -> Option<SignatureHelp> {
+        let before = text_before(document_text, position)?;
+        let (name, commas) = enclosing_call(&before)?;
+        let signature = document_signature(document_text, symbols, &name)
+            .or_else(|| builtin_signature(&name))?;
+
+        let variadic = signature
+            .parameters
+            .last()
+            .is_some_and(|parameter| parameter.label.ends_with("..."));
+        let active = if variadic {
+            commas.min(signature.parameters.len() - 1)
+        } else {
+            commas
+        };
+        Some(SignatureHelp {
+            signatures: vec![signature],
+            active_signature: Some(0),
+            active_parameter: Some(active as u32),
+        })
+    }
+}
+
+impl Default for SignatureHelpProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The document text before a position, or `None` if it is past the text
+fn text_before(text: &str, position: Position) -> Option<Vec<char>> {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let line = lines.get(position.line as usize)?;
+    if position.character as usize > line.chars().count() {
+        return None;
+    }
+
+    let mut before: Vec<char> = lines[..position.line as usize]
+        .iter()
+        .flat_map(|line| line.chars().chain(['\n']))
+        .collect();
+    before.extend(line.chars().take(position.character as usize));
+    Some(before)
+}
+
+/// Open delimiter seen while scanning
+struct Open {
+    /// `(`, `[` or `{`
+    delimiter: char,
+    /// Function name before a `(` (not a method or a declaration)
+    callee: Option<String>,
+    /// Commas directly inside this delimiter so far
+    commas: usize,
+}
+
+/// The innermost call still open at the end of `chars`, with the number of
+/// its argument separators seen so far
+fn enclosing_call(chars: &[char]) -> Option<(String, usize)> {
+    let mut open: Vec<Open> = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            quote @ ('"' | '\'') => {
+                i += 1;
+                while i < chars.len() && chars[i] != quote {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 1;
+            }
+            delimiter @ ('(' | '[' | '{') => open.push(Open {
+                delimiter,
+                callee: if delimiter == '(' {
+                    callee_before(chars, i)
+                } else {
+                    None
+                },
+                commas: 0,
+            }),
+            ')' | ']' | '}' => {
+                open.pop();
+            }
+            ',' => {
+                if let Some(innermost) = open.last_mut() {
+                    innermost.commas += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // Tuples and arrays inside the arguments belong to the enclosing call
+    open.into_iter()
+        .rev()
+        .take_while(|frame| frame.delimiter != '{')
+        .find_map(|frame| frame.callee.map(|name| (name, frame.commas)))
+}
+
+/// The function name directly before the `(` at `paren`
+///
+/// `None` for a method call (`v.push(`), a declaration (`fun f(`), or a
+/// parenthesis that does not follow a name.
+fn callee_before(chars: &[char], paren: usize) -> Option<String> {
+    let mut end = paren;
+    while end > 0 && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+    let mut start = end;
+    while start > 0 && is_identifier_char(chars[start - 1]) {
+        start -= 1;
+    }
+    if start == end || chars[start].is_ascii_digit() {
+        return None;
+    }
+
+    let mut before = start;
+    while before > 0 && chars[before - 1].is_whitespace() {
+        before -= 1;
+    }
+    if before > 0 && chars[before - 1] == '.' {
+        return None;
+    }
+    let declaration = chars[..before].ends_with(&['f', 'u', 'n'])
+        && (before == 3 || !is_identifier_char(chars[before - 4]));
+    if declaration {
+        return None;
+    }
+    Some(chars[start..end].iter().collect())
+}
+
+/// Signature of a function declared in the document
+fn document_signature(
+    text: &str,
+    symbols: &SymbolTable,
+    name: &str,
+) -> Option<SignatureInformation> {
+    let symbol = symbols.get_symbol(name)?;
+    if symbol.kind != SymbolKind::Function {
+        return None;
+    }
+    let location = symbol.location.range.start;
+    let line = text.lines().nth(location.line as usize)?;
+    let declaration = line.get(location.character as usize..)?;
+
+    let label = function_signature(declaration);
+    Some(SignatureInformation {
+        parameters: parameters(&label),
+        label,
+        documentation: None,
+    })
+}
+
+/// Fixed signature of a builtin function
+fn builtin_signature(name: &str) -> Option<SignatureInformation> {
+    let (_, label, documentation) = BUILTIN_SIGNATURES
+        .iter()
+        .find(|(builtin, _, _)| *builtin == name)?;
+    Some(SignatureInformation {
+        label: label.to_string(),
+        documentation: Some(documentation.to_string()),
+        parameters: parameters(label),
+    })
+}
+
+/// Parameters of `fun name(a, b: Vec<i64>)`, split at top-level commas
+fn parameters(label: &str) -> Vec<ParameterInformation> {
+    let Some(open) = label.find('(') else {
+        return vec![];
+    };
+    let mut parameters = vec![];
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in label[open + 1..].chars() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            ')' if depth == 0 => break,
+            ')' | ']' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parameters.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parameters.push(current);
+    parameters
+        .into_iter()
+        .map(|parameter| parameter.trim().to_string())
+        .filter(|parameter| !parameter.is_empty())
+        .map(|label| ParameterInformation { label })
+        .collect()
+}
+
+/// Check if character is valid in identifier
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Helper: signature help for `text` at a position
+    fn help(text: &str, line: u32, character: u32) -> Option<SignatureHelp> {
+        let mut table = SymbolTable::new();
+        table.parse_document("file:///test.ruchy", text);
+        SignatureHelpProvider::new().get_signature_help(
+            text,
+            &table,
+            Position::new(line, character),
+        )
+    }
+
+    /// Helper: (label, active parameter) for `text` at a position
+    fn active(text: &str, line: u32, character: u32) -> Option<(String, u32)> {
+        help(text, line, character).map(|help| {
+            (
+                help.signatures[0].label.clone(),
+                help.active_parameter.unwrap(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_document_function_after_first_comma() {
+        let text = "fun add(a: i64, b: i64) -> i64 {\n    a + b\n}\nadd(1, ";
+        let help = help(text, 3, 7).unwrap();
+        assert_eq!(help.active_signature, Some(0));
+        assert_eq!(help.active_parameter, Some(1));
+        assert_eq!(help.signatures[0].label, "fun add(a: i64, b: i64) -> i64");
+        let labels: Vec<&str> = help.signatures[0]
+            .parameters
+            .iter()
+            .map(|parameter| parameter.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["a: i64", "b: i64"]);
+    }
+
+    #[test]
+    fn test_first_parameter_right_after_paren() {
+        let text = "fun factorial(n) {\n    n\n}\nlet x = factorial(";
+        assert_eq!(
+            active(text, 3, 18),
+            Some(("fun factorial(n)".to_string(), 0))
+        );
+    }
+
+    #[test]
+    fn test_builtin_signatures() {
+        assert_eq!(
+            active("write_file(\"out.txt\", ", 0, 22),
+            Some((
+                "fun write_file(path: String, content: String)".to_string(),
+                1
+            ))
+        );
+        assert_eq!(
+            active("read_file(", 0, 10),
+            Some(("fun read_file(path: String) -> String".to_string(), 0))
+        );
+        // Variadic: every extra argument stays on `args...`
+        assert_eq!(
+            active("println(\"{} {}\", a, b", 0, 21),
+            Some(("fun println(template, args...)".to_string(), 1))
+        );
+    }
+
+    #[test]
+    fn test_nested_calls_and_literals() {
+        let text = "fun pair(a, b) { a }\npair(len(\"x, y\"), [1, 2], ";
+        // Inside the nested call's string
+        assert_eq!(active(text, 1, 12), None);
+        // Inside the array: still the outer call's second argument
+        assert_eq!(active(text, 1, 22), Some(("fun pair(a, b)".to_string(), 1)));
+        // After the array
+        assert_eq!(active(text, 1, 26), Some(("fun pair(a, b)".to_string(), 2)));
+    }
+
+    #[test]
+    fn test_no_signature_outside_calls() {
+        let text = "fun add(a, b) { a + b }\nadd(1, 2)\nv.push(";
+        // After the call closed
+        assert_eq!(active(text, 1, 9), None);
+        // In the declaration's own parameter list
+        assert_eq!(active(text, 0, 10), None);
+        // Method call on an unknown receiver
+        assert_eq!(active(text, 2, 7), None);
+        // Past the end of the document
+        assert_eq!(active(text, 9, 0), None);
+    }
+}
@@ -1,11 +1,20 @@
 // Symbol Tracking and Resolution
 // Provides go-to-definition and find-references support
+//
+// Symbols live in hash maps, so everything handed back to a client is sorted
+// first: symbols by kind then name, locations by position. Editors and tests
+// then see the same order on every request.
+//
+// Go-to-definition is scope-aware: `find_definition` scans the document's
+// braces so a use resolves to the nearest enclosing binding, not just the
+// last `let` of that name anywhere in the file. `rename_symbol` uses the same
+// resolution, so it only edits the uses of that one binding.
 
-use super::protocol::{Location, Position, Range};
-use std::collections::HashMap;
+use super::protocol::{Location, Position, Range, TextEdit, WorkspaceEdit};
+use std::collections::{BTreeMap, HashMap};
 
-/// Symbol kind
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Symbol kind (declaration order is the sort order)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SymbolKind {
     /// Function symbol
     Function,
@@ -63,47 +72,23 @@ impl SymbolTable {
         self.symbols.get(name)
     }
 
-    /// Get all references to a symbol
+    /// Get all references to a symbol, sorted by position without duplicates
     pub fn get_references(&self, name: &str) -> Vec<Location> {
-        self.references.get(name).cloned().unwrap_or_default()
+        let mut locations = self.references.get(name).cloned().unwrap_or_default();
+        sort_locations(&mut locations);
+        locations
+    }
+
+    /// All symbol definitions, sorted by kind then name
+    pub fn symbols(&self) -> Vec<&Symbol> {
+        let mut symbols: Vec<&Symbol> = self.symbols.values().collect();
+        symbols.sort_by(|a, b| (&a.kind, &a.name).cmp(&(&b.kind, &b.name)));
+        symbols
     }
 
     /// Find symbol at position (simple word-based lookup)
     pub fn find_symbol_at_position(&self, text: &str, position: Position) -> Option<String> {
-        let lines: Vec<&str> = text.lines().collect();
-        if position.line as usize >= lines.len() {
-            return None;
-        }
-
-        let line = lines[position.line as usize];
-        if position.character as usize >= line.len() {
-            return None;
-        }
-
-        // Find word boundaries around cursor position
-        let chars: Vec<char> = line.chars().collect();
-        let pos = position.character as usize;
-
-        // Find start of word
-        let mut start = pos;
-        while start > 0 && is_identifier_char(chars[start - 1]) {
-            start -= 1;
-        }
-
-        // Find end of word
-        let mut end = pos;
-        while end < chars.len() && is_identifier_char(chars[end]) {
-            end += 1;
-        }
-
-        if start < end {
-            let word: String = chars[start..end].iter().collect();
-            if !word.is_empty() {
-                return Some(word);
-            }
-        }
-
-        None
+        word_at_position(text, position).map(|(word, _)| word)
     }
 
     /// Parse document and build symbol table
@@ -150,6 +135,62 @@ impl Default for SymbolTable {
     }
 }
 
+/// Sort locations by document and position, dropping duplicates
+pub(crate) fn sort_locations(locations: &mut Vec<Location>) {
+    locations.sort_by(|a, b| {
+        let key = |l: &Location| {
+            (
+                l.range.start.line,
+                l.range.start.character,
+                l.range.end.line,
+                l.range.end.character,
+            )
+        };
+        a.uri.cmp(&b.uri).then_with(|| key(a).cmp(&key(b)))
+    });
+    locations.dedup();
+}
+
+/// The identifier under the cursor and its range on the line
+pub(crate) fn word_at_position(text: &str, position: Position) -> Option<(String, Range)> {
+    let lines: Vec<&str> = text.lines().collect();
+    if position.line as usize >= lines.len() {
+        return None;
+    }
+
+    let line = lines[position.line as usize];
+    if position.character as usize >= line.len() {
+        return None;
+    }
+
+    // Find word boundaries around cursor position
+    let chars: Vec<char> = line.chars().collect();
+    let pos = position.character as usize;
+
+    // Find start of word
+    let mut start = pos;
+    while start > 0 && is_identifier_char(chars[start - 1]) {
+        start -= 1;
+    }
+
+    // Find end of word
+    let mut end = pos;
+    while end < chars.len() && is_identifier_char(chars[end]) {
+        end += 1;
+    }
+
+    if start < end {
+        let word: String = chars[start..end].iter().collect();
+        let range = Range::new(
+            Position::new(position.line, start as u32),
+            Position::new(position.line, end as u32),
+        );
+        return Some((word, range));
+    }
+
+    None
+}
+
 /// Check if character is valid in identifier
 fn is_identifier_char(c: char) -> bool {
     c.is_alphanumeric() || c == '_'
@@ -173,6 +214,7 @@ fn extract_function_name(text: &str) -> Option<String> {
 /// Extract variable name from text after "let "
 fn extract_variable_name(text: &str) -> Option<String> {
     let trimmed = text.trim();
+    let trimmed = trimmed.strip_prefix("mut ").unwrap_or(trimmed).trim_start();
     // Find first word before = or space
     let end_pos = trimmed.find(['=', ' ', ':']).unwrap_or(trimmed.len());
 
@@ -183,6 +225,555 @@ fn extract_variable_name(text: &str) -> Option<String> {
     None
 }
 
+/// A lexical token used by the definition scanner
+#[derive(Debug, Clone, PartialEq)]
+enum ScanToken {
+    /// Identifier or keyword, with its range
+    Ident(String, Range),
+    /// Any other non-whitespace character
+    Punct(char),
+}
+
+/// A name introduced by `let`, `fun`, `struct`/`enum`, a parameter or a
+/// `for` loop variable
+#[derive(Clone)]
+struct Binding {
+    name: String,
+    range: Range,
+    /// Token index of the name
+    token: usize,
+    /// Scope ids enclosing the binding, outermost first
+    scope: Vec<usize>,
+    /// Functions and types are visible before their declaration
+    hoisted: bool,
+}
+
+/// Language keywords, which cannot be used as names
+const KEYWORDS: &[&str] = &[
+    "as", "break", "continue", "else", "enum", "false", "for", "fun", "if", "impl", "in", "let",
+    "loop", "match", "move", "mut", "repeat", "return", "struct", "true", "until", "use", "while",
+];
+
+/// Why a rename was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// No binding, or use of one, at the position
+    NoSymbol,
+    /// The new name is not an identifier, or is a keyword
+    InvalidName(String),
+    /// The new name is already bound in the symbol's scope, or renaming
+    /// would change which binding a use refers to
+    Conflict {
+        /// The rejected name
+        name: String,
+        /// The existing binding, or the use whose meaning would change
+        range: Range,
+    },
+}
+
+impl std::fmt::Display for RenameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RenameError::NoSymbol => write!(f, "no symbol to rename at this position"),
+            RenameError::InvalidName(name) => write!(f, "'{}' is not a valid name", name),
+            RenameError::Conflict { name, range } => write!(
+                f,
+                "renaming to '{}' conflicts with line {}, column {}",
+                name,
+                range.start.line + 1,
+                range.start.character + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+/// Location of the definition of the identifier at `position`
+///
+/// Resolves to the nearest enclosing binding: a shadowing `let` in the same
+/// block wins if it precedes the use, and bindings in blocks that have
+/// already closed are ignored. Functions and types are visible throughout
+/// their enclosing block. Positions on a definition resolve to itself.
+pub fn find_definition(uri: &str, text: &str, position: Position) -> Option<Location> {
+    let tokens = scan_tokens(text);
+    let target = token_at(&tokens, position)?;
+    let ScanToken::Ident(name, _) = &tokens[target] else {
+        return None;
+    };
+
+    let (bindings, scopes) = collect_bindings(&tokens);
+    resolve(&bindings, name, &scopes[target], target)
+        .map(|binding| Location::new(uri.to_string(), bindings[binding].range))
+}
+
+/// Rename the symbol at `position` to `new_name` (textDocument/rename)
+///
+/// Edits the binding and every use that resolves to it (see
+/// `find_definition`), so a same-named variable in another function is left
+/// alone. Field names after `.` and in `name: value` pairs are not uses.
+/// Rejects a name already bound in the same scope, and any rename that
+/// would make a use refer to a different binding than before.
+pub fn rename_symbol(
+    uri: &str,
+    text: &str,
+    position: Position,
+    new_name: &str,
+) -> Result<WorkspaceEdit, RenameError> {
+    let valid = new_name.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && new_name.chars().all(is_identifier_char)
+        && !KEYWORDS.contains(&new_name);
+    if !valid {
+        return Err(RenameError::InvalidName(new_name.to_string()));
+    }
+
+    let tokens = scan_tokens(text);
+    let (bindings, scopes) = collect_bindings(&tokens);
+    let resolved: Vec<Option<usize>> = (0..tokens.len())
+        .map(|i| match use_name(&tokens, &bindings, i) {
+            Some(name) => resolve(&bindings, name, &scopes[i], i),
+            None => None,
+        })
+        .collect();
+
+    let symbol = token_at(&tokens, position)
+        .and_then(|target| resolved[target])
+        .ok_or(RenameError::NoSymbol)?;
+    let binding = &bindings[symbol];
+    if binding.name == new_name {
+        return Ok(WorkspaceEdit::default());
+    }
+    if let Some(existing) = bindings
+        .iter()
+        .find(|b| b.name == new_name && b.scope == binding.scope)
+    {
+        return Err(RenameError::Conflict {
+            name: new_name.to_string(),
+            range: existing.range,
+        });
+    }
+
+    // Every use of either name must resolve to the same binding afterwards
+    let mut renamed = bindings.clone();
+    renamed[symbol].name = new_name.to_string();
+    let mut edits = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let ScanToken::Ident(name, range) = token else {
+            continue;
+        };
+        if resolved[i] == Some(symbol) {
+            if resolve(&renamed, new_name, &scopes[i], i) != Some(symbol) {
+                return Err(RenameError::Conflict {
+                    name: new_name.to_string(),
+                    range: *range,
+                });
+            }
+            edits.push(TextEdit::new(*range, new_name.to_string()));
+        } else if name == new_name
+            && resolved[i].is_some()
+            && resolve(&renamed, name, &scopes[i], i) != resolved[i]
+        {
+            return Err(RenameError::Conflict {
+                name: new_name.to_string(),
+                range: *range,
+            });
+        }
+    }
+
+    Ok(WorkspaceEdit {
+        changes: BTreeMap::from([(uri.to_string(), edits)]),
+    })
+}
+
+/// Index of the identifier token under `position`
+fn token_at(tokens: &[ScanToken], position: Position) -> Option<usize> {
+    tokens.iter().position(|token| match token {
+        ScanToken::Ident(_, range) => {
+            range.start.line == position.line
+                && range.start.character <= position.character
+                && position.character < range.end.character
+        }
+        ScanToken::Punct(_) => false,
+    })
+}
+
+/// The binding that the identifier `name` at token `token` refers to
+///
+/// The nearest enclosing binding wins (see `find_definition`); a binding's
+/// own name token resolves to itself.
+fn resolve(bindings: &[Binding], name: &str, scope: &[usize], token: usize) -> Option<usize> {
+    if let Some(own) = bindings.iter().position(|b| b.token == token) {
+        return Some(own);
+    }
+    bindings
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| b.name == name)
+        .filter(|(_, b)| scope.starts_with(&b.scope))
+        .filter(|(_, b)| b.hoisted || b.token < token)
+        .max_by_key(|(_, b)| (b.scope.len(), b.token))
+        .map(|(index, _)| index)
+}
+
+/// Name of token `i` if it can refer to a binding: a binding's own name,
+/// or an identifier that is not a field (`p.x`, `Point { x: 1 }`)
+fn use_name<'a>(tokens: &'a [ScanToken], bindings: &[Binding], i: usize) -> Option<&'a str> {
+    let ScanToken::Ident(name, _) = &tokens[i] else {
+        return None;
+    };
+    if bindings.iter().any(|b| b.token == i) {
+        return Some(name);
+    }
+    let punct = |j: Option<usize>| match j.and_then(|j| tokens.get(j)) {
+        Some(ScanToken::Punct(c)) => Some(*c),
+        _ => None,
+    };
+    let after_dot = punct(i.checked_sub(1)) == Some('.');
+    let field_key = punct(Some(i + 1)) == Some(':')
+        && punct(Some(i + 2)) != Some(':')
+        && punct(i.checked_sub(1)) != Some(':');
+    if after_dot || field_key {
+        return None;
+    }
+    Some(name)
+}
+
+/// Split a document into identifiers and punctuation, skipping comments and
+/// string and char literals
+///
+/// Names used inside strings are kept as identifiers: the code in f-string
+/// holes (`f"{x + 1}"`) and the named placeholders of format templates
+/// (`format("{x}")`, `println("{x} {}", y)`), which the interpreter looks
+/// up in the current scope.
+fn scan_tokens(text: &str) -> Vec<ScanToken> {
+    let mut scanner = Scanner {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 0,
+        character: 0,
+        tokens: Vec::new(),
+    };
+    scanner.code(false);
+    scanner.tokens
+}
+
+/// Functions whose first argument is a format template (INTERP-101)
+///
+/// `format` always expands it; the others only when arguments follow.
+const TEMPLATE_FUNCTIONS: &[&str] = &["format", "print", "println", "panic"];
+
+/// Cursor over a document for `scan_tokens`
+struct Scanner {
+    chars: Vec<char>,
+    pos: usize,
+    line: u32,
+    character: u32,
+    tokens: Vec<ScanToken>,
+}
+
+impl Scanner {
+    fn peek(&self, ahead: usize) -> Option<char> {
+        self.chars.get(self.pos + ahead).copied()
+    }
+
+    fn position(&self) -> Position {
+        Position::new(self.line, self.character)
+    }
+
+    fn advance(&mut self) {
+        if self.peek(0) == Some('\n') {
+            self.line += 1;
+            self.character = 0;
+        } else {
+            self.character += 1;
+        }
+        self.pos += 1;
+    }
+
+    /// Scan code up to the end of the document, or in an f-string hole up
+    /// to (and consuming) the `}` that closes it
+    fn code(&mut self, in_hole: bool) {
+        let mut depth = 0;
+        while let Some(c) = self.peek(0) {
+            match c {
+                '}' if in_hole && depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                '/' if self.peek(1) == Some('/') => {
+                    while self.peek(0).is_some_and(|c| c != '\n') {
+                        self.advance();
+                    }
+                }
+                '/' if self.peek(1) == Some('*') => self.block_comment(),
+                '"' => self.string(),
+                '\'' => self.char_literal(),
+                'f' if self.peek(1) == Some('"') => {
+                    self.advance();
+                    self.advance();
+                    self.fstring();
+                }
+                c if is_identifier_char(c) => {
+                    let start = self.position();
+                    let mut name = String::new();
+                    while let Some(c) = self.peek(0).filter(|c| is_identifier_char(*c)) {
+                        name.push(c);
+                        self.advance();
+                    }
+                    let range = Range::new(start, self.position());
+                    self.tokens.push(ScanToken::Ident(name, range));
+                }
+                c => {
+                    if c == '{' {
+                        depth += 1;
+                    } else if c == '}' {
+                        depth -= 1;
+                    }
+                    if !c.is_whitespace() {
+                        self.tokens.push(ScanToken::Punct(c));
+                    }
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    /// Skip a `/* */` comment, which may nest
+    fn block_comment(&mut self) {
+        self.advance();
+        self.advance();
+        let mut depth = 1;
+        while let Some(c) = self.peek(0) {
+            if c == '/' && self.peek(1) == Some('*') {
+                depth += 1;
+                self.advance();
+            } else if c == '*' && self.peek(1) == Some('/') {
+                depth -= 1;
+                self.advance();
+                if depth == 0 {
+                    self.advance();
+                    return;
+                }
+            }
+            self.advance();
+        }
+    }
+
+    /// Skip a char literal (`'{'`, `'\n'`); a lone `'` is punctuation
+    fn char_literal(&mut self) {
+        let length = match (self.peek(1), self.peek(2), self.peek(3)) {
+            (Some('\\'), Some(_), Some('\'')) => 4,
+            (Some(c), Some('\''), _) if c != '\'' => 3,
+            _ => {
+                self.tokens.push(ScanToken::Punct('\''));
+                self.advance();
+                return;
+            }
+        };
+        for _ in 0..length {
+            self.advance();
+        }
+    }
+
+    /// Scan the rest of an f-string after its opening `f"`
+    fn fstring(&mut self) {
+        while let Some(c) = self.peek(0) {
+            self.advance();
+            match c {
+                '"' => return,
+                '\\' => self.advance(),
+                '{' => self.code(true),
+                _ => {}
+            }
+        }
+    }
+
+    /// Skip a string literal, keeping its named placeholders as identifiers
+    /// when it is the template of a format function
+    fn string(&mut self) {
+        let template_of = match self.tokens.as_slice() {
+            [.., ScanToken::Ident(name, _), ScanToken::Punct('(')]
+                if TEMPLATE_FUNCTIONS.contains(&name.as_str()) =>
+            {
+                Some(name.clone())
+            }
+            _ => None,
+        };
+        let mut placeholders = Vec::new();
+        self.advance();
+        while let Some(c) = self.peek(0) {
+            match c {
+                '"' => break,
+                '\\' => self.advance(),
+                '{' if self.peek(1) == Some('{') => self.advance(),
+                '{' => {
+                    self.advance();
+                    while self.peek(0).is_some_and(char::is_whitespace) {
+                        self.advance();
+                    }
+                    let start = self.position();
+                    let mut name = String::new();
+                    while let Some(c) = self.peek(0).filter(|c| is_identifier_char(*c)) {
+                        name.push(c);
+                        self.advance();
+                    }
+                    let end = self.position();
+                    while self.peek(0).is_some_and(|c| c != '}' && c != '"') {
+                        self.advance();
+                    }
+                    if name.starts_with(|c: char| !c.is_ascii_digit()) {
+                        placeholders.push(ScanToken::Ident(name, Range::new(start, end)));
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            self.advance();
+        }
+        self.advance();
+
+        let mut rest = self.chars[self.pos.min(self.chars.len())..].iter();
+        let has_arguments = rest.find(|c| !c.is_whitespace()) == Some(&',');
+        if template_of.is_some_and(|name| name == "format" || has_arguments) {
+            self.tokens.extend(placeholders);
+        }
+    }
+}
+
+/// All bindings in the token stream, plus the scope at each token
+fn collect_bindings(tokens: &[ScanToken]) -> (Vec<Binding>, Vec<Vec<usize>>) {
+    let mut bindings = Vec::new();
+    let mut scope: Vec<usize> = Vec::new();
+    let mut next_scope = 0;
+    let mut scopes = Vec::with_capacity(tokens.len());
+    // Parameters and loop variables belong to the block opened next
+    let mut pending: Vec<(String, Range, usize)> = Vec::new();
+
+    let ident = |i: usize| match tokens.get(i) {
+        Some(ScanToken::Ident(name, range)) => Some((name.clone(), *range)),
+        _ => None,
+    };
+
+    for i in 0..tokens.len() {
+        scopes.push(scope.clone());
+        match &tokens[i] {
+            ScanToken::Punct('{') => {
+                next_scope += 1;
+                scope.push(next_scope);
+                for (name, range, token) in pending.drain(..) {
+                    bindings.push(Binding {
+                        name,
+                        range,
+                        token,
+                        scope: scope.clone(),
+                        hoisted: false,
+                    });
+                }
+            }
+            ScanToken::Punct('}') => {
+                scope.pop();
+            }
+            ScanToken::Ident(keyword, _) if keyword == "let" => {
+                let mut j = i + 1;
+                if ident(j).is_some_and(|(name, _)| name == "mut") {
+                    j += 1;
+                }
+                for (name, range, token) in pattern_names(tokens, j) {
+                    bindings.push(Binding {
+                        name,
+                        range,
+                        token,
+                        scope: scope.clone(),
+                        hoisted: false,
+                    });
+                }
+            }
+            ScanToken::Ident(keyword, _) if keyword == "fun" => {
+                if let Some((name, range)) = ident(i + 1) {
+                    bindings.push(Binding {
+                        name,
+                        range,
+                        token: i + 1,
+                        scope: scope.clone(),
+                        hoisted: true,
+                    });
+                    pending.extend(parameter_names(tokens, i + 2));
+                }
+            }
+            ScanToken::Ident(keyword, _) if keyword == "struct" || keyword == "enum" => {
+                if let Some((name, range)) = ident(i + 1) {
+                    bindings.push(Binding {
+                        name,
+                        range,
+                        token: i + 1,
+                        scope: scope.clone(),
+                        hoisted: true,
+                    });
+                }
+            }
+            ScanToken::Ident(keyword, _) if keyword == "for" => {
+                pending.extend(pattern_names(tokens, i + 1));
+            }
+            _ => {}
+        }
+    }
+
+    (bindings, scopes)
+}
+
+/// Names bound by a `let`/`for` pattern starting at token `start`: a single
+/// identifier or a (possibly nested) tuple of them
+fn pattern_names(tokens: &[ScanToken], start: usize) -> Vec<(String, Range, usize)> {
+    let mut names = Vec::new();
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            ScanToken::Punct('(') => depth += 1,
+            ScanToken::Punct(')') => depth -= 1,
+            ScanToken::Ident(name, range) if name != "_" && name != "mut" => {
+                names.push((name.clone(), *range, i));
+            }
+            _ if depth == 0 => break,
+            _ => {}
+        }
+        if depth == 0 {
+            break;
+        }
+    }
+    names
+}
+
+/// Parameter names of a function whose `(` is at token `start`
+///
+/// A parameter is the first identifier after `(` or `,`; type annotations
+/// after `:` are skipped.
+fn parameter_names(tokens: &[ScanToken], start: usize) -> Vec<(String, Range, usize)> {
+    let mut names = Vec::new();
+    if tokens.get(start) != Some(&ScanToken::Punct('(')) {
+        return names;
+    }
+    let mut depth = 0;
+    let mut expect_name = true;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            ScanToken::Punct('(') | ScanToken::Punct('<') | ScanToken::Punct('[') => depth += 1,
+            ScanToken::Punct(')') | ScanToken::Punct('>') | ScanToken::Punct(']') => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            ScanToken::Punct(',') if depth == 1 => expect_name = true,
+            ScanToken::Ident(name, range) if depth == 1 && expect_name && name != "mut" => {
+                names.push((name.clone(), *range, i));
+                expect_name = false;
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,6 +809,57 @@ mod tests {
         assert_eq!(refs[0].uri, "file:///test.ruchy");
     }
 
+    #[test]
+    fn test_references_sorted_and_deduplicated() {
+        let mut table = SymbolTable::new();
+        let at = |line, character| {
+            Location::new(
+                "file:///test.ruchy".to_string(),
+                Range::new(
+                    Position::new(line, character),
+                    Position::new(line, character + 4),
+                ),
+            )
+        };
+
+        table.add_reference("main".to_string(), at(7, 2));
+        table.add_reference("main".to_string(), at(3, 9));
+        table.add_reference("main".to_string(), at(7, 2));
+        table.add_reference("main".to_string(), at(3, 1));
+
+        assert_eq!(
+            table.get_references("main"),
+            vec![at(3, 1), at(3, 9), at(7, 2)]
+        );
+    }
+
+    #[test]
+    fn test_symbols_sorted_by_kind_then_name() {
+        let mut table = SymbolTable::new();
+        table.parse_document(
+            "file:///test.ruchy",
+            "let zeta = 1
+fun beta() {}
+let alpha = 2
+fun alpha_fn() {}",
+        );
+
+        let order: Vec<(SymbolKind, &str)> = table
+            .symbols()
+            .iter()
+            .map(|s| (s.kind.clone(), s.name.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (SymbolKind::Function, "alpha_fn"),
+                (SymbolKind::Function, "beta"),
+                (SymbolKind::Variable, "alpha"),
+                (SymbolKind::Variable, "zeta"),
+            ]
+        );
+    }
+
     #[test]
     fn test_find_symbol_at_position() {
         let table = SymbolTable::new();
@@ -279,9 +921,168 @@ mod tests {
             Some("count".to_string())
         );
         assert_eq!(extract_variable_name("x: i32"), Some("x".to_string()));
+        assert_eq!(
+            extract_variable_name("mut total = 0"),
+            Some("total".to_string())
+        );
         assert_eq!(extract_variable_name(""), None);
     }
 
+    #[test]
+    fn test_find_definition_of_parameter() {
+        let text = "fun add(a, b: i32) {\n    a + b\n}";
+        let location = find_definition("file:///test.ruchy", text, Position::new(1, 8)).unwrap();
+        assert_eq!(
+            location.range,
+            Range::new(Position::new(0, 11), Position::new(0, 12))
+        );
+    }
+
+    #[test]
+    fn test_find_definition_shadowing_in_same_block() {
+        let text = "let x = 1;\nlet y = x;\nlet x = x + 1;\nprintln(x);";
+        let uri = "file:///test.ruchy";
+
+        // Before the shadowing `let`, `x` is the first binding
+        let location = find_definition(uri, text, Position::new(1, 8)).unwrap();
+        assert_eq!(location.range.start, Position::new(0, 4));
+
+        // After it, the second binding wins
+        let location = find_definition(uri, text, Position::new(3, 8)).unwrap();
+        assert_eq!(location.range.start, Position::new(2, 4));
+    }
+
+    #[test]
+    fn test_find_definition_hoists_functions() {
+        let text = "let r = later();\nfun later() { 1 }";
+        let location = find_definition("file:///test.ruchy", text, Position::new(0, 9)).unwrap();
+        assert_eq!(location.range.start, Position::new(1, 4));
+    }
+
+    #[test]
+    fn test_find_definition_ignores_closed_blocks_and_strings() {
+        let text = "fun f() {\n    let t = 1;\n}\nprintln(\"t\", t);";
+        let uri = "file:///test.ruchy";
+        assert_eq!(find_definition(uri, text, Position::new(3, 14)), None);
+        assert_eq!(find_definition(uri, text, Position::new(3, 9)), None);
+    }
+
+    #[test]
+    fn test_find_definition_struct_and_for_variable() {
+        let text = "struct Point { x: i32 }\nfor (i, p) in items {\n    let q: Point = p;\n}";
+        let uri = "file:///test.ruchy";
+
+        let location = find_definition(uri, text, Position::new(2, 12)).unwrap();
+        assert_eq!(location.range.start, Position::new(0, 7));
+
+        let location = find_definition(uri, text, Position::new(2, 19)).unwrap();
+        assert_eq!(location.range.start, Position::new(1, 8));
+    }
+
+    /// Helper: (line, character) of each edit for a rename at a position
+    fn renamed_at(
+        text: &str,
+        line: u32,
+        character: u32,
+        new_name: &str,
+    ) -> Result<Vec<(u32, u32)>, RenameError> {
+        let uri = "file:///test.ruchy";
+        let edit = rename_symbol(uri, text, Position::new(line, character), new_name)?;
+        Ok(edit.changes[uri]
+            .iter()
+            .inspect(|edit| assert_eq!(edit.new_text, new_name))
+            .map(|edit| (edit.range.start.line, edit.range.start.character))
+            .collect())
+    }
+
+    #[test]
+    fn test_rename_function_updates_call_sites() {
+        let text = "fun square(n) {\n    n * n\n}\nlet a = square(2);\nlet b = square(a);";
+        assert_eq!(
+            renamed_at(text, 3, 9, "sq").unwrap(),
+            vec![(0, 4), (3, 8), (4, 8)]
+        );
+    }
+
+    #[test]
+    fn test_rename_local_only_in_scope() {
+        let text = "fun f() {\n    let x = 1;\n    x + 1\n}\nfun g() {\n    let x = 2;\n    x\n}";
+        assert_eq!(renamed_at(text, 2, 4, "y").unwrap(), vec![(1, 8), (2, 4)]);
+        assert_eq!(renamed_at(text, 5, 8, "y").unwrap(), vec![(5, 8), (6, 4)]);
+    }
+
+    #[test]
+    fn test_rename_skips_fields() {
+        let text = "let x = 1;\nlet p = Point { x: x };\np.x";
+        assert_eq!(renamed_at(text, 0, 4, "y").unwrap(), vec![(0, 4), (1, 19)]);
+    }
+
+    #[test]
+    fn test_rename_updates_uses_inside_strings() {
+        let text = "let x = 1;\nprintln(f\"{x}\");";
+        assert_eq!(renamed_at(text, 0, 4, "y").unwrap(), vec![(0, 4), (1, 11)]);
+
+        let text = "let x = 1;\nlet s = f\"a{x + f\"{x}\"}\";";
+        assert_eq!(
+            renamed_at(text, 0, 4, "y").unwrap(),
+            vec![(0, 4), (1, 12), (1, 19)]
+        );
+
+        let text = "let x = 1;\nformat(\"{x} {{x}} {x:>3}\");\nprintln(\"{x} {}\", 2);";
+        assert_eq!(
+            renamed_at(text, 0, 4, "y").unwrap(),
+            vec![(0, 4), (1, 9), (1, 19), (2, 10)]
+        );
+
+        // A lone println argument is printed as written
+        let text = "let x = 1;\nprintln(\"{x}\");\nlet s = \"{x}\";";
+        assert_eq!(renamed_at(text, 0, 4, "y").unwrap(), vec![(0, 4)]);
+    }
+
+    #[test]
+    fn test_rename_skips_block_comments_and_chars() {
+        let text = "let x = 1;\n/* x /* x */ { */\nlet c = '{';\nfun f() {\n    x\n}";
+        assert_eq!(renamed_at(text, 0, 4, "y").unwrap(), vec![(0, 4), (4, 4)]);
+    }
+
+    #[test]
+    fn test_rename_rejects_conflicts() {
+        let conflict = |line, character| RenameError::Conflict {
+            name: "a".to_string(),
+            range: Range::new(
+                Position::new(line, character),
+                Position::new(line, character + 1),
+            ),
+        };
+
+        // Already bound in the same scope
+        let text = "let a = 1;\nlet b = 2;\na + b";
+        assert_eq!(renamed_at(text, 1, 4, "a"), Err(conflict(0, 4)));
+
+        // An outer `a` used inside the function would be shadowed
+        let text = "let a = 1;\nfun f() {\n    let b = 2;\n    a + b\n}";
+        assert_eq!(renamed_at(text, 2, 8, "a"), Err(conflict(3, 4)));
+
+        // The renamed outer binding would be captured by an inner `a`
+        let text = "let x = 1;\nfun f() {\n    let a = 2;\n    x + a\n}";
+        assert_eq!(renamed_at(text, 0, 4, "a"), Err(conflict(3, 4)));
+    }
+
+    #[test]
+    fn test_rename_rejects_invalid_names_and_non_symbols() {
+        let text = "let x = 1;\np.field";
+        for name in ["1x", "let", "", "a-b"] {
+            assert_eq!(
+                renamed_at(text, 0, 4, name),
+                Err(RenameError::InvalidName(name.to_string()))
+            );
+        }
+        assert_eq!(renamed_at(text, 0, 0, "y"), Err(RenameError::NoSymbol));
+        assert_eq!(renamed_at(text, 1, 3, "y"), Err(RenameError::NoSymbol));
+        let unchanged = rename_symbol("file:///test.ruchy", text, Position::new(0, 4), "x");
+        assert_eq!(unchanged, Ok(WorkspaceEdit::default()));
+    }
+
     #[test]
     fn test_is_identifier_char() {
         assert!(is_identifier_char('a'));
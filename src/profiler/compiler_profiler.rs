@@ -370,7 +370,7 @@ impl CompilerProfiler {
     /// Returns OSR candidates ranked by hotness
     pub fn osr_candidates_sorted(&self, iteration_threshold: usize) -> Vec<super::LoopProfile> {
         let mut candidates = self.osr_candidates(iteration_threshold);
-        candidates.sort_by(|a, b| b.iteration_count.cmp(&a.iteration_count));
+        candidates.sort_by_key(|b| std::cmp::Reverse(b.iteration_count));
         candidates
     }
 
@@ -434,6 +434,19 @@ impl CompilerProfiler {
                 for stmt in body {
                     self.analyze_node(stmt, opportunities, name);
                 }
+
+                // Self-recursive calls in tail position (INTERP-093)
+                let tail_calls = crate::interpreter::tail_calls::find_tail_calls(node);
+                if tail_calls.iter().any(|call| call.is_recursive()) {
+                    opportunities.push(OptimizationOpportunity {
+                        kind: super::OptKind::TailCallOpt {
+                            function: name.clone(),
+                        },
+                        location: name.clone(),
+                        estimated_speedup: 1.2, // 15-25% for recursive functions
+                        confidence: 0.8,
+                    });
+                }
             }
 
             // Let declarations: analyze value
@@ -484,28 +497,14 @@ impl CompilerProfiler {
 
     /// Convert expression to string for reporting
     fn expr_to_string(&self, node: &crate::interpreter::parser::AstNode) -> String {
-        use crate::interpreter::parser::{AstNode, BinaryOperator};
+        use crate::interpreter::parser::AstNode;
 
         match node {
             AstNode::IntegerLiteral(n) => n.to_string(),
             AstNode::FloatLiteral(f) => f.to_string(),
             AstNode::BooleanLiteral(b) => b.to_string(),
             AstNode::BinaryOp { op, left, right } => {
-                let op_str = match op {
-                    BinaryOperator::Add => "+",
-                    BinaryOperator::Subtract => "-",
-                    BinaryOperator::Multiply => "*",
-                    BinaryOperator::Divide => "/",
-                    BinaryOperator::Modulo => "%",
-                    BinaryOperator::Equal => "==",
-                    BinaryOperator::NotEqual => "!=",
-                    BinaryOperator::LessThan => "<",
-                    BinaryOperator::LessEqual => "<=",
-                    BinaryOperator::GreaterThan => ">",
-                    BinaryOperator::GreaterEqual => ">=",
-                    BinaryOperator::And => "&&",
-                    BinaryOperator::Or => "||",
-                };
+                let op_str = op.symbol();
                 format!(
                     "{} {} {}",
                     self.expr_to_string(left),
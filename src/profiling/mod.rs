@@ -62,9 +62,9 @@
 //! - [x] GREEN Phase: Basic sampling infrastructure (perf_event_open syscall)
 //! - [x] GREEN Phase: Ring buffer allocation and reading
 //! - [x] REFACTOR: Extract actual sample fields (ip, tid, time, stack)
-//! - [ ] REFACTOR: DWARF unwinding (function names)
-//! - [ ] REFACTOR: Flame graph generation
-//! - [ ] REFACTOR: Hotspot analysis
+//! - [x] REFACTOR: Symbol resolution (function names via `SymbolResolver`)
+//! - [x] REFACTOR: Flame graph generation (folded stacks, SVG colored by source file)
+//! - [x] REFACTOR: Hotspot analysis (by IP, by function, by call path)
 //!
 //! # References
 //!
@@ -77,6 +77,12 @@
 use std::error::Error;
 use std::fmt;
 
+mod svg;
+mod symbols;
+
+pub use svg::{file_color, frame_file, NO_FILE_COLOR};
+pub use symbols::SymbolResolver;
+
 #[cfg(feature = "profiling")]
 use perf_event_open::{
     config::{Cpu, Opts, Proc, SampleOn, Size},
@@ -98,6 +104,8 @@ pub enum ProfilerError {
     ReadFailed(String),
     /// Permission denied (need root or CAP_PERFMON)
     PermissionDenied(String),
+    /// Failed to load symbols for address resolution
+    SymbolsUnavailable(String),
 }
 
 impl fmt::Display for ProfilerError {
@@ -116,6 +124,7 @@ impl fmt::Display for ProfilerError {
                     msg
                 )
             }
+            ProfilerError::SymbolsUnavailable(msg) => write!(f, "Failed to load symbols: {}", msg),
         }
     }
 }
@@ -164,21 +173,39 @@ impl FlameGraph {
     ///
     /// FlameGraph with aggregated stack traces
     pub fn from_samples(samples: &[Sample]) -> Self {
+        // Format each IP as hex: 0x7ffff7a1b2c3
+        Self::from_labelled_samples(samples, |ip| format!("0x{:x}", ip))
+    }
+
+    /// Create a flame graph whose frames are resolved function names
+    ///
+    /// Frames are labelled by [`SymbolResolver::frame_label`]
+    /// (`function (file:line)`), so the SVG colors them by source file.
+    /// Addresses the resolver cannot map stay as hex.
+    pub fn from_samples_resolved(samples: &[Sample], resolver: &SymbolResolver) -> Self {
+        Self::from_labelled_samples(samples, |ip| {
+            resolver
+                .frame_label(ip)
+                .unwrap_or_else(|| format!("0x{:x}", ip))
+        })
+    }
+
+    /// Aggregate samples by stack, naming each frame with `label`
+    fn from_labelled_samples(samples: &[Sample], label: impl Fn(u64) -> String) -> Self {
         let mut stacks = std::collections::HashMap::new();
 
         for sample in samples {
             // Build stack trace string from instruction pointers
-            // Format each IP as hex: 0x7ffff7a1b2c3
             let stack_trace = if sample.stack.is_empty() {
                 // Use IP if no stack trace available
-                format!("0x{:x}", sample.ip)
+                label(sample.ip)
             } else {
                 // Reverse stack so deepest frame is last (flame graph convention)
                 sample
                     .stack
                     .iter()
                     .rev()
-                    .map(|ip| format!("0x{:x}", ip))
+                    .map(|&ip| label(ip))
                     .collect::<Vec<_>>()
                     .join(";")
             };
@@ -190,6 +217,25 @@ impl FlameGraph {
         FlameGraph { stacks }
     }
 
+    /// Create a flame graph from brendangregg format data
+    ///
+    /// Accepts the output of [`FlameGraph::to_string`] (or any folded stacks,
+    /// e.g. with symbol-resolved frames). Lines without a trailing count are
+    /// ignored; repeated stacks are summed.
+    pub fn from_folded(data: &str) -> Self {
+        let mut stacks = std::collections::HashMap::new();
+
+        for line in data.lines() {
+            if let Some((stack, count)) = line.trim().rsplit_once(' ') {
+                if let Ok(count) = count.parse::<usize>() {
+                    *stacks.entry(stack.to_string()).or_insert(0) += count;
+                }
+            }
+        }
+
+        FlameGraph { stacks }
+    }
+
     /// Generate brendangregg format flame graph data
     ///
     /// Returns a string where each line is:
@@ -216,8 +262,8 @@ impl FlameGraph {
 /// Represents a single hotspot (instruction pointer) with its sample count and percentage.
 #[derive(Debug, Clone)]
 pub struct HotspotEntry {
-    /// Function identifier (instruction pointer as hex string)
-    /// Note: Use DWARF unwinding for human-readable function names
+    /// Function identifier: instruction pointer as hex string, or the
+    /// function name from [`Hotspot::analyze_resolved`]
     pub function: String,
     /// Number of samples at this instruction pointer
     pub count: usize,
@@ -225,6 +271,32 @@ pub struct HotspotEntry {
     pub percentage: f64,
 }
 
+/// Call-path hotspot entry
+///
+/// One node of the call tree: a function reached through a specific chain of
+/// callers, with the samples spent in it alone (exclusive) and in it plus
+/// everything it called (inclusive).
+#[derive(Debug, Clone)]
+pub struct CallPathEntry {
+    /// Frames from the outermost caller to this function (hex IPs)
+    pub path: Vec<String>,
+    /// Samples whose whole stack is exactly `path` (self time)
+    pub exclusive_count: usize,
+    /// Samples whose stack starts with `path` (total time)
+    pub inclusive_count: usize,
+    /// `exclusive_count` as a percentage of all samples
+    pub self_percentage: f64,
+    /// `inclusive_count` as a percentage of all samples
+    pub total_percentage: f64,
+}
+
+impl CallPathEntry {
+    /// The innermost frame of the path
+    pub fn function(&self) -> &str {
+        self.path.last().map(String::as_str).unwrap_or_default()
+    }
+}
+
 /// Hotspot analyzer for identifying top N functions by CPU time
 ///
 /// Aggregates profiling samples by instruction pointer and identifies
@@ -267,35 +339,120 @@ impl Hotspot {
     /// # }
     /// ```
     pub fn analyze(samples: &[Sample], top_n: usize) -> Vec<HotspotEntry> {
+        Self::analyze_by(samples, top_n, |ip| format!("0x{:x}", ip))
+    }
+
+    /// Analyze samples and return the top N functions by name
+    ///
+    /// Like [`Hotspot::analyze`], but samples are aggregated by the function
+    /// containing their IP (so every address in a hot loop counts toward
+    /// one entry). IPs the resolver cannot map are kept as hex.
+    pub fn analyze_resolved(
+        samples: &[Sample],
+        top_n: usize,
+        resolver: &SymbolResolver,
+    ) -> Vec<HotspotEntry> {
+        Self::analyze_by(samples, top_n, |ip| {
+            resolver
+                .function_name(ip)
+                .unwrap_or_else(|| format!("0x{:x}", ip))
+        })
+    }
+
+    /// Aggregate samples under `key(ip)` and return the top N
+    fn analyze_by(
+        samples: &[Sample],
+        top_n: usize,
+        key: impl Fn(u64) -> String,
+    ) -> Vec<HotspotEntry> {
         if samples.is_empty() {
             return Vec::new();
         }
 
-        // Aggregate samples by instruction pointer
-        let mut ip_counts: std::collections::HashMap<u64, usize> = std::collections::HashMap::new();
+        // Aggregate samples by function key
+        let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
 
         for sample in samples {
-            *ip_counts.entry(sample.ip).or_insert(0) += 1;
+            *counts.entry(key(sample.ip)).or_insert(0) += 1;
         }
 
         let total_samples = samples.len() as f64;
 
         // Convert to HotspotEntry with percentages
-        let mut hotspots: Vec<HotspotEntry> = ip_counts
-            .iter()
-            .map(|(ip, count)| HotspotEntry {
-                function: format!("0x{:x}", ip),
-                count: *count,
-                percentage: (*count as f64 / total_samples) * 100.0,
+        let mut hotspots: Vec<HotspotEntry> = counts
+            .into_iter()
+            .map(|(function, count)| HotspotEntry {
+                function,
+                count,
+                percentage: (count as f64 / total_samples) * 100.0,
             })
             .collect();
 
-        // Sort by count (descending)
-        hotspots.sort_by(|a, b| b.count.cmp(&a.count));
+        // Sort by count (descending), ties by name for stable output
+        hotspots.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.function.cmp(&b.function))
+        });
 
         // Return top N
         hotspots.into_iter().take(top_n).collect()
     }
+
+    /// Analyze samples by call path and return the top N paths
+    ///
+    /// Every prefix of every sampled stack is an entry, so a caller's
+    /// inclusive count rolls up all of its callees, while its exclusive
+    /// count only covers samples that stopped in it. Samples without a
+    /// stack are treated as a one-frame stack at their IP.
+    ///
+    /// Entries are sorted by inclusive count (descending), then exclusive
+    /// count (descending), then path.
+    pub fn analyze_callgraph(samples: &[Sample], top_n: usize) -> Vec<CallPathEntry> {
+        if samples.is_empty() {
+            return Vec::new();
+        }
+
+        // Call path (outermost first) → (exclusive, inclusive)
+        let mut counts: std::collections::HashMap<Vec<u64>, (usize, usize)> =
+            std::collections::HashMap::new();
+
+        for sample in samples {
+            let frames: Vec<u64> = if sample.stack.is_empty() {
+                vec![sample.ip]
+            } else {
+                // Stacks are innermost first; paths read from the root
+                sample.stack.iter().rev().copied().collect()
+            };
+
+            for depth in 1..=frames.len() {
+                counts.entry(frames[..depth].to_vec()).or_default().1 += 1;
+            }
+            counts.entry(frames).or_default().0 += 1;
+        }
+
+        let total_samples = samples.len() as f64;
+
+        let mut entries: Vec<CallPathEntry> = counts
+            .into_iter()
+            .map(|(path, (exclusive, inclusive))| CallPathEntry {
+                path: path.iter().map(|ip| format!("0x{:x}", ip)).collect(),
+                exclusive_count: exclusive,
+                inclusive_count: inclusive,
+                self_percentage: (exclusive as f64 / total_samples) * 100.0,
+                total_percentage: (inclusive as f64 / total_samples) * 100.0,
+            })
+            .collect();
+
+        entries.sort_by(|a, b| {
+            b.inclusive_count
+                .cmp(&a.inclusive_count)
+                .then_with(|| b.exclusive_count.cmp(&a.exclusive_count))
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        entries.into_iter().take(top_n).collect()
+    }
 }
 
 /// Statistical profiler using perf_event_open
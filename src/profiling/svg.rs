@@ -0,0 +1,226 @@
+//! Flame graph SVG rendering (DEBUGGER-058)
+//!
+//! Renders a [`FlameGraph`] as a standalone SVG, root frames at the bottom.
+//! Frames are colored by the source file they belong to, so distinct modules
+//! stand out, and a legend maps each color back to its file.
+//!
+//! Resolved frames carry their location as `function (file:line)`; frames
+//! without that suffix (e.g. raw instruction pointers) are drawn in gray.
+//! Colors come from a fixed hash of the file path, so the same input always
+//! renders byte-identical SVG.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use super::FlameGraph;
+
+/// Total SVG width in pixels
+const SVG_WIDTH: f64 = 1200.0;
+/// Height of one frame (and one legend row) in pixels
+const FRAME_HEIGHT: f64 = 16.0;
+/// Frames narrower than this get no text label
+const MIN_LABEL_WIDTH: f64 = 24.0;
+/// Approximate width of one label character in pixels
+const CHAR_WIDTH: f64 = 7.0;
+/// Fill for frames without file information
+pub const NO_FILE_COLOR: &str = "rgb(200,200,200)";
+
+/// Call-tree node aggregated from the folded stacks
+#[derive(Default)]
+struct FrameNode {
+    count: usize,
+    children: BTreeMap<String, FrameNode>,
+}
+
+/// Source file of a resolved frame label (`function (file:line)`)
+///
+/// Returns `None` for frames without a location suffix.
+pub fn frame_file(frame: &str) -> Option<&str> {
+    let location = frame.strip_suffix(')')?.rsplit_once(" (")?.1;
+    let (file, line) = location.rsplit_once(':')?;
+    if file.is_empty() || line.is_empty() || !line.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(file)
+}
+
+/// Fill color for frames from `file` (stable hash of the path to a hue)
+pub fn file_color(file: Option<&str>) -> String {
+    match file {
+        // FNV-1a: unlike DefaultHasher, stable across Rust releases
+        Some(file) => {
+            let hash = file.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+            });
+            format!("hsl({}, 65%, 60%)", hash % 360)
+        }
+        None => NO_FILE_COLOR.to_string(),
+    }
+}
+
+impl FlameGraph {
+    /// Render the flame graph as an SVG document
+    ///
+    /// Frames are colored by source file (see [`frame_file`]) with a legend
+    /// listing every file present; frames without file info are gray.
+    /// Output is deterministic for a given set of stacks.
+    pub fn to_svg(&self) -> String {
+        let mut root = FrameNode::default();
+        for (stack, &count) in &self.stacks {
+            root.count += count;
+            let mut node = &mut root;
+            for frame in stack.split(';') {
+                node = node.children.entry(frame.to_string()).or_default();
+                node.count += count;
+            }
+        }
+
+        let mut files = BTreeSet::new();
+        let mut has_unresolved = false;
+        let depth = collect_files(&root, &mut files, &mut has_unresolved);
+
+        let legend_rows = files.len() + usize::from(has_unresolved);
+        let graph_top = (legend_rows as f64 + 1.0) * FRAME_HEIGHT;
+        let height = graph_top + depth as f64 * FRAME_HEIGHT;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             font-family=\"monospace\" font-size=\"12\">",
+            SVG_WIDTH, height
+        );
+
+        // Legend: one swatch per source file, then the no-file entry
+        let mut legend: Vec<(String, &str)> = files
+            .iter()
+            .map(|file| (file_color(Some(file)), *file))
+            .collect();
+        if has_unresolved {
+            legend.push((NO_FILE_COLOR.to_string(), "(no file info)"));
+        }
+        let _ = writeln!(svg, "<g class=\"legend\">");
+        for (row, (color, label)) in legend.iter().enumerate() {
+            let y = row as f64 * FRAME_HEIGHT;
+            let _ = writeln!(
+                svg,
+                "<rect x=\"0\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\
+                 <text x=\"{}\" y=\"{}\">{}</text>",
+                y + 2.0,
+                FRAME_HEIGHT - 4.0,
+                FRAME_HEIGHT - 4.0,
+                color,
+                FRAME_HEIGHT,
+                y + FRAME_HEIGHT - 4.0,
+                escape_xml(label)
+            );
+        }
+        let _ = writeln!(svg, "</g>");
+
+        if root.count > 0 {
+            let scale = SVG_WIDTH / root.count as f64;
+            let mut x = 0.0;
+            for (name, child) in &root.children {
+                render_frame(
+                    &mut svg, name, child, x, 0, depth, graph_top, scale, root.count,
+                );
+                x += child.count as f64 * scale;
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Record every frame's file and return the depth of the deepest stack
+fn collect_files<'a>(
+    node: &'a FrameNode,
+    files: &mut BTreeSet<&'a str>,
+    has_unresolved: &mut bool,
+) -> usize {
+    let mut depth = 0;
+    for (name, child) in &node.children {
+        match frame_file(name) {
+            Some(file) => {
+                files.insert(file);
+            }
+            None => *has_unresolved = true,
+        }
+        depth = depth.max(1 + collect_files(child, files, has_unresolved));
+    }
+    depth
+}
+
+/// Draw one frame and (recursively) its callees above it
+#[allow(clippy::too_many_arguments)]
+fn render_frame(
+    svg: &mut String,
+    name: &str,
+    node: &FrameNode,
+    x: f64,
+    level: usize,
+    depth: usize,
+    graph_top: f64,
+    scale: f64,
+    total: usize,
+) {
+    let width = node.count as f64 * scale;
+    let y = graph_top + (depth - level - 1) as f64 * FRAME_HEIGHT;
+    let percentage = node.count as f64 * 100.0 / total as f64;
+
+    let _ = write!(
+        svg,
+        "<g><title>{} ({} samples, {:.2}%)</title>\
+         <rect x=\"{:.2}\" y=\"{}\" width=\"{:.2}\" height=\"{}\" fill=\"{}\"/>",
+        escape_xml(name),
+        node.count,
+        percentage,
+        x,
+        y,
+        width,
+        FRAME_HEIGHT - 1.0,
+        file_color(frame_file(name))
+    );
+    if width >= MIN_LABEL_WIDTH {
+        let max_chars = ((width - 6.0) / CHAR_WIDTH) as usize;
+        let label: String = if name.chars().count() > max_chars {
+            let kept: String = name.chars().take(max_chars.saturating_sub(2)).collect();
+            format!("{}..", kept)
+        } else {
+            name.to_string()
+        };
+        let _ = write!(
+            svg,
+            "<text x=\"{:.2}\" y=\"{}\">{}</text>",
+            x + 3.0,
+            y + FRAME_HEIGHT - 4.0,
+            escape_xml(&label)
+        );
+    }
+    svg.push_str("</g>\n");
+
+    let mut child_x = x;
+    for (child_name, child) in &node.children {
+        render_frame(
+            svg,
+            child_name,
+            child,
+            child_x,
+            level + 1,
+            depth,
+            graph_top,
+            scale,
+            total,
+        );
+        child_x += child.count as f64 * scale;
+    }
+}
+
+/// Escape text for use in SVG content
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
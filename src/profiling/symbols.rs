@@ -0,0 +1,166 @@
+//! Symbol resolution for profile addresses (DEBUGGER-060)
+//!
+//! Maps sampled instruction pointers back to function names using the
+//! symbol table and DWARF line tables of an executable (via `addr2line`).
+//! Runtime addresses are shifted by the executable's load bias first, so
+//! position-independent executables resolve like fixed-address ones.
+//!
+//! Lookups are cached per address: profiles repeat the same IPs many times.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use addr2line::Loader;
+
+use super::ProfilerError;
+
+/// A resolved address: its function, offset, and the function's location
+#[derive(Debug)]
+struct ResolvedSymbol {
+    /// Demangled function name (without the Rust hash suffix)
+    name: String,
+    /// Bytes from the start of the function to the address
+    offset: u64,
+    /// Source file and line of the function's first instruction
+    location: Option<(String, u32)>,
+}
+
+/// Resolves instruction pointers to human-readable function names
+///
+/// # Example
+///
+/// ```
+/// use ruchyruchy::profiling::SymbolResolver;
+///
+/// #[inline(never)]
+/// fn target() {}
+///
+/// let resolver = SymbolResolver::for_current_exe().unwrap();
+/// let symbol = resolver.resolve(target as usize as u64).unwrap();
+/// assert!(symbol.ends_with("target+0x0"), "{}", symbol);
+/// ```
+pub struct SymbolResolver {
+    /// Symbol table and DWARF of the executable
+    loader: Loader,
+    /// Runtime address minus the executable's link-time address
+    load_bias: u64,
+    /// Previous lookups (runtime IP → symbol, `None` if unresolvable)
+    cache: RefCell<HashMap<u64, Option<Rc<ResolvedSymbol>>>>,
+}
+
+impl SymbolResolver {
+    /// Resolver for the running process's own executable
+    ///
+    /// Reads the load address from `/proc/self/maps`, so it is Linux-only.
+    pub fn for_current_exe() -> Result<Self, ProfilerError> {
+        let exe = std::env::current_exe()
+            .map_err(|e| ProfilerError::SymbolsUnavailable(e.to_string()))?;
+        let loader = Loader::new(&exe)
+            .map_err(|e| ProfilerError::SymbolsUnavailable(format!("{}: {}", exe.display(), e)))?;
+        let load_address = current_load_address(&exe)?;
+        let load_bias = load_address.wrapping_sub(loader.relative_address_base());
+        Ok(Self::with_loader(loader, load_bias))
+    }
+
+    /// Resolver for the executable at `path`, loaded `load_bias` bytes above
+    /// its link-time addresses (0 for non-PIE executables)
+    pub fn from_path(path: &Path, load_bias: u64) -> Result<Self, ProfilerError> {
+        let loader = Loader::new(path)
+            .map_err(|e| ProfilerError::SymbolsUnavailable(format!("{}: {}", path.display(), e)))?;
+        Ok(Self::with_loader(loader, load_bias))
+    }
+
+    fn with_loader(loader: Loader, load_bias: u64) -> Self {
+        Self {
+            loader,
+            load_bias,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// `function+0xoffset` for a runtime instruction pointer
+    ///
+    /// Returns `None` if no symbol covers `ip`.
+    pub fn resolve(&self, ip: u64) -> Option<String> {
+        self.lookup(ip)
+            .map(|symbol| format!("{}+0x{:x}", symbol.name, symbol.offset))
+    }
+
+    /// Demangled name of the function containing `ip`
+    pub fn function_name(&self, ip: u64) -> Option<String> {
+        self.lookup(ip).map(|symbol| symbol.name.clone())
+    }
+
+    /// Flame graph frame label: `function (file:line)`, or just `function`
+    /// without line info
+    ///
+    /// The location is the function's start, so every sample in a function
+    /// gets the same label (and source-file color, see [`super::frame_file`]).
+    pub fn frame_label(&self, ip: u64) -> Option<String> {
+        self.lookup(ip).map(|symbol| match &symbol.location {
+            Some((file, line)) => format!("{} ({}:{})", symbol.name, file, line),
+            None => symbol.name.clone(),
+        })
+    }
+
+    fn lookup(&self, ip: u64) -> Option<Rc<ResolvedSymbol>> {
+        if let Some(cached) = self.cache.borrow().get(&ip) {
+            return cached.clone();
+        }
+
+        let probe = ip.wrapping_sub(self.load_bias);
+        // The symbol map answers "nearest symbol below", even far past the
+        // last function, so only trust addresses inside the code section
+        let in_text = self
+            .loader
+            .get_section_range(b".text")
+            .is_some_and(|text| (text.begin..text.end).contains(&probe));
+        let symbol = if in_text {
+            self.loader.find_symbol_info(probe)
+        } else {
+            None
+        };
+        let resolved = symbol.map(|symbol| {
+            let name = addr2line::demangle_auto(Cow::Borrowed(symbol.name()), None).into_owned();
+            let location = self
+                .loader
+                .find_location(symbol.address())
+                .ok()
+                .flatten()
+                .and_then(|loc| Some((loc.file?.to_string(), loc.line?)));
+            Rc::new(ResolvedSymbol {
+                name,
+                offset: probe - symbol.address(),
+                location,
+            })
+        });
+
+        self.cache.borrow_mut().insert(ip, resolved.clone());
+        resolved
+    }
+}
+
+/// Lowest address at which `exe` is mapped into this process
+fn current_load_address(exe: &Path) -> Result<u64, ProfilerError> {
+    let maps = std::fs::read_to_string("/proc/self/maps")
+        .map_err(|e| ProfilerError::SymbolsUnavailable(format!("/proc/self/maps: {}", e)))?;
+    let exe = exe.to_string_lossy();
+
+    // Format: start-end perms offset dev inode path
+    maps.lines()
+        .filter(|line| line.ends_with(exe.as_ref()))
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let range = fields.next()?;
+            let offset = fields.nth(1)?;
+            if u64::from_str_radix(offset, 16).ok()? != 0 {
+                return None;
+            }
+            u64::from_str_radix(range.split('-').next()?, 16).ok()
+        })
+        .min()
+        .ok_or_else(|| ProfilerError::SymbolsUnavailable(format!("{} is not mapped", exe)))
+}
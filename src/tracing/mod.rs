@@ -7,7 +7,7 @@
 //! ## DEBUGGER-014: Compiler Instrumentation (Complete ✅)
 //! - `events` - Trace event structures (function entry/exit, syscalls)
 //! - `buffer` - Per-thread lock-free buffers
-//! - `output` - JSON and strace-style formatters
+//! - `output` - JSON, strace-style and Chrome Trace Event formatters, name filters
 //!
 //! ## DEBUGGER-015: eBPF Syscall Tracing (GREEN Phase ⏳)
 //! - `ebpf_placeholder` - eBPF syscall tracing (requires setup)
@@ -32,4 +32,6 @@ pub mod ebpf_placeholder;
 
 pub use buffer::TraceBuffer;
 pub use events::{FunctionEntry, FunctionExit, SourceLocation, TraceEvent, TypeInfo, TypedValue};
-pub use output::{JsonFormatter, TraceFile, TraceMetadata, TraceStats};
+pub use output::{
+    ChromeTraceFormatter, JsonFormatter, TraceFile, TraceFilter, TraceMetadata, TraceStats,
+};
@@ -4,6 +4,7 @@
 
 use super::events::TraceEvent;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::Write;
 
 /// Trace file metadata
@@ -30,6 +31,21 @@ pub struct TraceFile {
     pub stats: TraceStats,
 }
 
+impl TraceFile {
+    /// Export the events in Chrome Trace Event Format (see [`ChromeTraceFormatter`])
+    pub fn to_chrome_trace(&self) -> String {
+        ChromeTraceFormatter::format(&self.events)
+    }
+
+    /// Keep only the events `filter` selects (see [`TraceFilter::apply`])
+    ///
+    /// `stats.total_events` is updated to the filtered count.
+    pub fn apply_filter(&mut self, filter: &TraceFilter) {
+        self.events = filter.apply(std::mem::take(&mut self.events));
+        self.stats.total_events = self.events.len();
+    }
+}
+
 /// Trace statistics
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TraceStats {
@@ -138,6 +154,190 @@ impl TextFormatter {
     }
 }
 
+/// Chrome Trace Event Format exporter (DEBUGGER-062)
+///
+/// Produces a `{"traceEvents": [...]}` document that `chrome://tracing` and
+/// Perfetto load directly:
+/// - `FunctionEnter` → `ph: "B"` with the source location (and arguments)
+///   in `args`
+/// - `FunctionExit` → `ph: "E"` with the return value in `args`
+/// - `Syscall` → a complete `ph: "X"` event of its duration
+///
+/// Timestamps are converted from nanoseconds to the format's microseconds;
+/// thread IDs become `tid`. Events keep their input order, so B/E pairs
+/// nest as they were recorded.
+pub struct ChromeTraceFormatter;
+
+impl ChromeTraceFormatter {
+    /// Process ID reported for every event (traces cover one process)
+    const PID: u64 = 1;
+
+    /// Format a single event as a Chrome trace event object
+    pub fn format_event(event: &TraceEvent) -> serde_json::Value {
+        let ts = event.timestamp_ns() as f64 / 1_000.0;
+        match event {
+            TraceEvent::FunctionEnter(entry) => serde_json::json!({
+                "name": entry.name,
+                "cat": "function",
+                "ph": "B",
+                "ts": ts,
+                "pid": Self::PID,
+                "tid": entry.thread_id,
+                "args": {
+                    "file": entry.location.file,
+                    "line": entry.location.line,
+                    "column": entry.location.column,
+                    "arguments": entry.args.iter().map(|arg| &arg.value).collect::<Vec<_>>(),
+                },
+            }),
+            TraceEvent::FunctionExit(exit) => {
+                let mut args = serde_json::Map::new();
+                if let Some(ref ret) = exit.return_value {
+                    args.insert("return".to_string(), ret.value.clone());
+                }
+                serde_json::json!({
+                    "name": exit.name,
+                    "cat": "function",
+                    "ph": "E",
+                    "ts": ts,
+                    "pid": Self::PID,
+                    "tid": exit.thread_id,
+                    "args": args,
+                })
+            }
+            TraceEvent::Syscall(syscall) => serde_json::json!({
+                "name": syscall.name,
+                "cat": "syscall",
+                "ph": "X",
+                "ts": ts,
+                "dur": syscall.duration_ns as f64 / 1_000.0,
+                "pid": Self::PID,
+                "tid": syscall.thread_id,
+                "args": {
+                    "number": syscall.number,
+                    "arguments": syscall.args,
+                    "return": syscall.return_value,
+                },
+            }),
+        }
+    }
+
+    /// Format all events as a Chrome trace JSON document
+    pub fn format(events: &[TraceEvent]) -> String {
+        let trace_events: Vec<serde_json::Value> = events.iter().map(Self::format_event).collect();
+        serde_json::json!({
+            "traceEvents": trace_events,
+            "displayTimeUnit": "ns",
+        })
+        .to_string()
+    }
+}
+
+/// Function-name filter for trace events (DEBUGGER-063)
+///
+/// Rules are globs over the function name: `*` matches any run of
+/// characters and `?` a single one, so `parse_*` is a prefix rule. An event
+/// is kept when it matches some include rule (or there are none) and no
+/// exclude rule. Syscall events are always kept.
+///
+/// # Example
+///
+/// ```
+/// use ruchyruchy::tracing::TraceFilter;
+///
+/// let filter = TraceFilter::new().include("parse_*").exclude("parse_ws");
+/// assert!(filter.matches("parse_expr"));
+/// assert!(!filter.matches("parse_ws"));
+/// assert!(!filter.matches("lex_token"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TraceFilter {
+    /// Keep only names matching one of these (empty = keep all)
+    include: Vec<String>,
+    /// Drop names matching any of these
+    exclude: Vec<String>,
+}
+
+impl TraceFilter {
+    /// Create a filter that keeps every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an include rule
+    pub fn include(mut self, pattern: &str) -> Self {
+        self.include.push(pattern.to_string());
+        self
+    }
+
+    /// Add an exclude rule
+    pub fn exclude(mut self, pattern: &str) -> Self {
+        self.exclude.push(pattern.to_string());
+        self
+    }
+
+    /// Whether events for function `name` pass the filter
+    pub fn matches(&self, name: &str) -> bool {
+        let included =
+            self.include.is_empty() || self.include.iter().any(|pattern| glob_match(pattern, name));
+        included && !self.exclude.iter().any(|pattern| glob_match(pattern, name))
+    }
+
+    /// Filter an event stream, keeping entry/exit nesting balanced
+    ///
+    /// A `FunctionExit` is kept exactly when the `FunctionEnter` it closes
+    /// (the innermost open one on the same thread) was kept, so the output
+    /// never has an exit without its entry. An exit with no open entry
+    /// falls back to matching its own name.
+    pub fn apply(&self, events: Vec<TraceEvent>) -> Vec<TraceEvent> {
+        // Per thread: whether each open entry was kept, innermost last
+        let mut open: HashMap<u64, Vec<bool>> = HashMap::new();
+
+        events
+            .into_iter()
+            .filter(|event| match event {
+                TraceEvent::FunctionEnter(entry) => {
+                    let keep = self.matches(&entry.name);
+                    open.entry(entry.thread_id).or_default().push(keep);
+                    keep
+                }
+                TraceEvent::FunctionExit(exit) => open
+                    .get_mut(&exit.thread_id)
+                    .and_then(Vec::pop)
+                    .unwrap_or_else(|| self.matches(&exit.name)),
+                TraceEvent::Syscall(_) => true,
+            })
+            .collect()
+    }
+}
+
+/// Match `text` against a glob with `*` (any run) and `?` (one character)
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it currently covers up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, covered)) = backtrack {
+            // Let the last `*` absorb one more character and retry
+            p = star + 1;
+            t = covered + 1;
+            backtrack = Some((star, covered + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,4 +419,16 @@ mod tests {
         assert!(text.contains("<- compute() = 100"));
         assert!(text.contains("[1.500ms]"));
     }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("parse_*", "parse_expr"));
+        assert!(glob_match("parse_*", "parse_"));
+        assert!(!glob_match("parse_*", "reparse_expr"));
+        assert!(glob_match("*_expr", "parse_binary_expr"));
+        assert!(glob_match("p?rse*e*r", "parse_expr"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("lex", "lexer"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+    }
 }
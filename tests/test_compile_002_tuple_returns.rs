@@ -0,0 +1,142 @@
+// COMPILE-002: Tuple Returns in Rust Codegen
+//
+// Mission: Multi-value returns transpile to idiomatic Rust tuples
+// - A function returning a tuple gets a tuple return type
+// - `let (a, b) = f()` becomes Rust tuple destructuring
+// - Element types are inferred; unknown ones fall back to i64 with a warning
+//
+// Test Coverage:
+// - test_tuple_return_golden: exact Rust for divmod and its call site
+// - test_mixed_element_types_and_early_return: (i64, bool) from two returns
+// - test_uninferable_element_falls_back_with_warning: forward call
+// - test_destructure_arity_mismatch_is_error: 3 names for a 2-tuple
+// - test_nested_destructure_golden: let ((a, _), b) keeps its nesting
+// - test_generated_tuple_code_compiles_and_runs: rustc agrees with interpreter
+
+use ruchyruchy::compiler::{CodeGenerator, CompileError};
+use ruchyruchy::interpreter::{Evaluator, OutputSink, Parser};
+use std::process::Command;
+
+/// Helper: transpile source, returning the Rust code and warnings
+fn transpile(source: &str) -> Result<(String, Vec<String>), CompileError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut codegen = CodeGenerator::new();
+    let code = codegen.generate(&ast)?.to_string();
+    Ok((code, codegen.warnings().to_vec()))
+}
+
+const DIVMOD: &str = "fun divmod(a, b) {
+    (a / b, a % b)
+}
+let (q, r) = divmod(17, 5)
+println(\"{} {}\", q, r)";
+
+/// Test: Golden output for a tuple-returning function and its caller
+#[test]
+fn test_tuple_return_golden() {
+    let (code, warnings) = transpile(DIVMOD).unwrap();
+    assert_eq!(
+        code,
+        "fn divmod(a: i64, b: i64) -> (i64, i64) {
+    (a / b, a % b)
+}
+
+fn main() {
+    let (q, r) = divmod(17, 5);
+    println!(\"{} {}\", q, r);
+}
+"
+    );
+    assert!(warnings.is_empty(), "{:?}", warnings);
+}
+
+/// Test: Element types come from every return site
+#[test]
+fn test_mixed_element_types_and_early_return() {
+    let (code, warnings) =
+        transpile("fun sign(n) { if n < 0 { return (0 - n, false) } (n, true) }").unwrap();
+    assert!(
+        code.starts_with("fn sign(n: i64) -> (i64, bool) {\n"),
+        "{}",
+        code
+    );
+    assert!(
+        code.contains("        return (0 - n, false);\n"),
+        "{}",
+        code
+    );
+    assert!(warnings.is_empty(), "{:?}", warnings);
+}
+
+/// Test: An element whose type cannot be inferred is assumed i64 and reported
+#[test]
+fn test_uninferable_element_falls_back_with_warning() {
+    let (code, warnings) = transpile("fun pair(n) { (n, later(n)) }\nfun later(n) { n }").unwrap();
+    assert!(code.contains("fn pair(n: i64) -> (i64, i64) {"), "{}", code);
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(
+        warnings[0].contains("fun pair (tuple element 1)"),
+        "{}",
+        warnings[0]
+    );
+}
+
+/// Test: Destructuring into the wrong number of names is a codegen error
+#[test]
+fn test_destructure_arity_mismatch_is_error() {
+    let err = transpile("fun two() { (1, 2) }\nlet (a, b, c) = two()").unwrap_err();
+    assert_eq!(
+        err,
+        CompileError::CodeGenError("cannot destructure a 2-tuple into 3 names".to_string())
+    );
+}
+
+/// Test: Nested tuple patterns and wildcards become the same Rust pattern
+#[test]
+fn test_nested_destructure_golden() {
+    let (code, _) =
+        transpile("let ((a, _), b) = ((1, true), 2.5)\nprintln(\"{} {}\", a, b)").unwrap();
+    assert!(
+        code.contains("    let ((a, _), b) = ((1, true), 2.5);\n"),
+        "{}",
+        code
+    );
+    assert!(code.contains("println!(\"{} {}\", a, b);"), "{}", code);
+}
+
+/// Test: The generated Rust compiles and prints what the interpreter prints
+#[test]
+fn test_generated_tuple_code_compiles_and_runs() {
+    let (code, _) = transpile(DIVMOD).unwrap();
+
+    let sink = OutputSink::buffer();
+    let ast = Parser::new(DIVMOD).parse().unwrap();
+    Evaluator::new()
+        .with_output(sink.clone())
+        .eval_program(&ast)
+        .unwrap();
+    let expected = sink.contents().unwrap();
+
+    let dir = std::env::temp_dir().join(format!("compile_002_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let source = dir.join("divmod.rs");
+    let binary = dir.join("divmod");
+    std::fs::write(&source, &code).unwrap();
+
+    let status = Command::new("rustc")
+        .arg("--edition=2021")
+        .arg("-o")
+        .arg(&binary)
+        .arg(&source)
+        .status()
+        .expect("run rustc");
+    assert!(
+        status.success(),
+        "generated code failed to compile:\n{}",
+        code
+    );
+
+    let output = Command::new(&binary).output().expect("run binary");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), expected);
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,160 @@
+// COMPILE-003: For Loops in Rust Codegen
+//
+// Mission: `for` loops over ranges and vectors transpile to idiomatic Rust
+// - `for i in 0..n` stays a Rust range loop; `0..=n` stays inclusive
+// - A vector variable is iterated by reference and stays usable afterwards
+// - The loop variable is typed for the body (i64 for ranges, element type for vectors)
+//
+// Test Coverage:
+// - test_counting_loop_golden: exact Rust for a range loop
+// - test_vector_iteration_golden: exact Rust for a vector loop
+// - test_inclusive_range_and_non_copy_elements: 1..=n and Vec<String>
+// - test_loop_over_non_iterable_is_error: for over an i64 parameter
+// - test_generated_loop_code_compiles_and_runs: rustc agrees with interpreter
+
+use ruchyruchy::compiler::{CodeGenerator, CompileError};
+use ruchyruchy::interpreter::{Evaluator, OutputSink, Parser};
+use std::process::Command;
+
+/// Helper: transpile source to Rust
+fn transpile(source: &str) -> Result<String, CompileError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut codegen = CodeGenerator::new();
+    Ok(codegen.generate(&ast)?.to_string())
+}
+
+/// Helper: program output as printed by the interpreter
+fn interpret(source: &str) -> String {
+    let sink = OutputSink::buffer();
+    let ast = Parser::new(source).parse().unwrap();
+    Evaluator::new()
+        .with_output(sink.clone())
+        .eval_program(&ast)
+        .unwrap();
+    sink.contents().unwrap()
+}
+
+const COUNTING: &str = "fun sum_below(n) {
+    let mut total = 0
+    for i in 0..n {
+        total = total + i
+    }
+    total
+}
+println(sum_below(5))";
+
+const VECTOR: &str = "let v = [1, 2, 3]
+for x in v {
+    println(\"{}\", x * 2)
+}
+println(v)";
+
+const SQUARES: &str = "fun sum_squares(n) {
+    let mut total = 0
+    for i in 1..=n {
+        if i % 2 == 0 {
+            continue
+        }
+        total = total + i * i
+    }
+    total
+}
+let names = [\"ada\", \"grace\"]
+for name in names {
+    println(name)
+}
+println(sum_squares(5))";
+
+/// Test: Golden output for a counting loop over a range
+#[test]
+fn test_counting_loop_golden() {
+    assert_eq!(
+        transpile(COUNTING).unwrap(),
+        "fn sum_below(n: i64) -> i64 {
+    let mut total = 0;
+    for i in 0..n {
+        total = total + i;
+    }
+    total
+}
+
+fn main() {
+    println!(\"{}\", sum_below(5));
+}
+"
+    );
+}
+
+/// Test: Golden output for iterating over a vector
+#[test]
+fn test_vector_iteration_golden() {
+    assert_eq!(
+        transpile(VECTOR).unwrap(),
+        "fn main() {
+    let v = vec![1, 2, 3];
+    for &x in &v {
+        println!(\"{}\", x * 2);
+    }
+    println!(\"{:?}\", v);
+}
+"
+    );
+}
+
+/// Test: Inclusive ranges keep `..=`; non-Copy elements iterate a clone
+#[test]
+fn test_inclusive_range_and_non_copy_elements() {
+    let code = transpile(SQUARES).unwrap();
+    assert!(code.contains("    for i in 1..=n {\n"), "{}", code);
+    assert!(
+        code.contains("    for name in names.clone() {\n"),
+        "{}",
+        code
+    );
+}
+
+/// Test: Looping over something that is neither a range nor a vector fails
+#[test]
+fn test_loop_over_non_iterable_is_error() {
+    let err = transpile("fun f(n) { for i in n { println(i) } }").unwrap_err();
+    assert_eq!(
+        err,
+        CompileError::CodeGenError("for i in a i64: expected a range or vector".to_string())
+    );
+}
+
+/// Test: Every loop example compiles with rustc and prints what the interpreter prints
+#[test]
+fn test_generated_loop_code_compiles_and_runs() {
+    let dir = std::env::temp_dir().join(format!("compile_003_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for (name, program) in [
+        ("counting", COUNTING),
+        ("vector", VECTOR),
+        ("squares", SQUARES),
+    ] {
+        let code = transpile(program).unwrap();
+        let source = dir.join(format!("{}.rs", name));
+        let binary = dir.join(name);
+        std::fs::write(&source, &code).unwrap();
+
+        let status = Command::new("rustc")
+            .arg("--edition=2021")
+            .arg("-o")
+            .arg(&binary)
+            .arg(&source)
+            .status()
+            .expect("run rustc");
+        assert!(status.success(), "{} failed to compile:\n{}", name, code);
+
+        let output = Command::new(&binary).output().expect("run binary");
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            interpret(program),
+            "{}",
+            name
+        );
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,126 @@
+// INTERP-084: Parallel Conformance Runner
+//
+// Mission: Run conformance suites across a worker pool
+// - Each test case gets its own Evaluator with a buffered OutputSink
+// - Captured output never leaks between tests
+// - Outcomes are sorted by test id regardless of scheduling
+// - Summary reports per-test time and total wall time
+//
+// Test Coverage:
+// - test_runner_captures_output_per_test: isolated stdout capture
+// - test_runner_reports_failures: eval errors and output mismatches fail
+// - test_runner_ordering_is_deterministic: worker count does not change order
+// - test_runner_report_includes_timings: per-test ms and total seconds
+// - test_output_sink_captures_println: Evaluator::with_output captures println
+
+use ruchyruchy::conformance::{ConformanceRunner, TestCase};
+use ruchyruchy::interpreter::{Evaluator, OutputSink, Parser};
+
+/// Helper: build a test case with expected output lines
+fn case(chapter: usize, name: &str, source: &str, expected: &[&str]) -> TestCase {
+    TestCase {
+        name: name.to_string(),
+        chapter,
+        chapter_name: "runner".to_string(),
+        description: format!("Runner test {}", name),
+        source_code: source.to_string(),
+        expected_output: expected.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+/// Helper: a mixed suite of passing programs, out of id order
+fn suite() -> Vec<TestCase> {
+    (0..20)
+        .rev()
+        .map(|i| {
+            case(
+                1 + i % 3,
+                &format!("test_{:02}", i),
+                &format!("println({})", i),
+                &[&i.to_string()],
+            )
+        })
+        .collect()
+}
+
+/// Test: Each test sees only its own printed output
+#[test]
+fn test_runner_captures_output_per_test() {
+    let summary = ConformanceRunner::new().with_workers(4).run(&suite());
+
+    assert_eq!(summary.passed(), 20);
+    for outcome in &summary.outcomes {
+        let n = outcome
+            .id
+            .rsplit('_')
+            .next()
+            .unwrap()
+            .parse::<i64>()
+            .unwrap();
+        assert_eq!(outcome.output, format!("{}\n", n));
+    }
+}
+
+/// Test: Eval errors and output mismatches are reported as failures
+#[test]
+fn test_runner_reports_failures() {
+    let cases = vec![
+        case(1, "test_ok", "println(1)", &["1"]),
+        case(1, "test_error", "undefined_fn()", &[]),
+        case(1, "test_mismatch", "println(2)", &["3"]),
+    ];
+    let summary = ConformanceRunner::new().with_workers(3).run(&cases);
+
+    assert_eq!(summary.passed(), 1);
+    assert_eq!(summary.failed(), 2);
+    let error = summary.outcomes[0].error.as_deref().unwrap();
+    assert!(error.starts_with("eval error"), "{}", error);
+    let mismatch = summary.outcomes[1].error.as_deref().unwrap();
+    assert!(mismatch.starts_with("output mismatch"), "{}", mismatch);
+}
+
+/// Test: Outcome order is sorted by id for any worker count
+#[test]
+fn test_runner_ordering_is_deterministic() {
+    let ids = |workers| {
+        ConformanceRunner::new()
+            .with_workers(workers)
+            .run(&suite())
+            .outcomes
+            .into_iter()
+            .map(|o| o.id)
+            .collect::<Vec<_>>()
+    };
+
+    let serial = ids(1);
+    let mut sorted = serial.clone();
+    sorted.sort();
+    assert_eq!(serial, sorted);
+    assert_eq!(ids(8), serial);
+}
+
+/// Test: Report lists per-test timings and total wall time
+#[test]
+fn test_runner_report_includes_timings() {
+    let cases = vec![case(2, "test_timed", "println(7)", &["7"])];
+    let report = ConformanceRunner::new().run(&cases).report();
+
+    assert!(report.contains("PASS ch02/test_timed ("), "{}", report);
+    assert!(report.contains("ms)"), "{}", report);
+    assert!(
+        report.contains("1 passed, 0 failed, 1 total in"),
+        "{}",
+        report
+    );
+}
+
+/// Test: Evaluator output can be redirected into a buffer
+#[test]
+fn test_output_sink_captures_println() {
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    let ast = Parser::new("println(\"a\")\nprintln(42)").parse().unwrap();
+    eval.eval_program(&ast).unwrap();
+
+    assert_eq!(sink.contents(), Some("a\n42\n".to_string()));
+}
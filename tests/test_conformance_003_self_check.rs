@@ -0,0 +1,101 @@
+// INTERP-105: Self-Checking Conformance Exports
+//
+// Mission: Exported .ruchy files validate themselves
+// - The exporter runs each program with the interpreter to capture golden values
+// - Golden values are appended as in-language assert_eq statements
+// - Re-running an exported file passes its own assertions
+// - A different result makes the file fail on its own
+//
+// Test Coverage:
+// - test_assertions_cover_bindings_and_main: lets, tuples, main's return value
+// - test_values_without_literals_are_skipped: nil, maps, failing programs
+// - test_exported_file_passes_its_own_assertions: export, read back, re-run
+// - test_self_check_catches_wrong_values: tampered golden value fails
+
+use std::fs;
+
+use ruchyruchy::conformance::{golden_assertions, ConformanceExporter};
+use ruchyruchy::interpreter::{EvalError, Evaluator, OutputSink, Parser, Value};
+
+/// Helper: run a program with output captured and discarded
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new().with_output(OutputSink::buffer());
+    eval.eval_program(&ast)
+}
+
+/// Test: Top-level bindings and main's return value become assertions
+#[test]
+fn test_assertions_cover_bindings_and_main() {
+    let source = r#"
+        let count = 3;
+        let ratio = 6.0 / 2.0;
+        let (first, second) = ("a", [1, 2]);
+        count = count + 1;
+        fun main() { let n = 4; n * 10 }
+    "#;
+    assert_eq!(
+        golden_assertions(source),
+        vec![
+            "assert_eq(count, 4)",
+            "assert_eq(ratio, 3.0)",
+            "assert_eq(first, \"a\")",
+            "assert_eq(second, [1, 2])",
+            "assert_eq(main(), 40)",
+        ]
+    );
+}
+
+/// Test: Values that have no literal form produce no assertion
+#[test]
+fn test_values_without_literals_are_skipped() {
+    assert!(golden_assertions("let nothing = println(\"hi\");").is_empty());
+    assert!(golden_assertions("let m = {\"k\": 1};").is_empty());
+    assert!(golden_assertions("fun main() { println(\"no value\") }").is_empty());
+    assert!(golden_assertions("let x = undefined_name;").is_empty());
+    assert!(golden_assertions("let = ;").is_empty());
+}
+
+/// Test: An exported chapter file runs its own assertions and passes
+#[test]
+fn test_exported_file_passes_its_own_assertions() {
+    let output_dir =
+        std::env::temp_dir().join(format!("ruchyruchy_self_check_{}", std::process::id()));
+    let exporter = ConformanceExporter {
+        output_dir: output_dir.clone(),
+    };
+    exporter.export_chapter(1, "hello_world", 12).unwrap();
+
+    let chapter_dir = output_dir.join("chapter_01_hello_world");
+    let mut checked = 0;
+    for entry in fs::read_dir(&chapter_dir).unwrap() {
+        let content = fs::read_to_string(entry.unwrap().path()).unwrap();
+        if !content.contains("assert_eq(") {
+            continue;
+        }
+        checked += 1;
+        if let Err(e) = run(&content) {
+            panic!("self-check failed: {}\n{}", e, content);
+        }
+    }
+    fs::remove_dir_all(&output_dir).ok();
+
+    assert!(
+        checked > 0,
+        "no exported chapter 1 file has self-check asserts"
+    );
+}
+
+/// Test: The appended assertions fail when the program computes something else
+#[test]
+fn test_self_check_catches_wrong_values() {
+    let source = "let answer = 6 * 7;";
+    let checks = golden_assertions(source);
+    assert_eq!(checks, vec!["assert_eq(answer, 42)"]);
+    assert!(run(&format!("{}\n{}", source, checks.join("\n"))).is_ok());
+
+    // Another implementation computing 6 * 6 would fail its own file
+    let divergent = format!("let answer = 6 * 6;\n{}", checks.join("\n"));
+    let err = run(&divergent).unwrap_err();
+    assert!(err.is_assertion_failure(), "{:?}", err);
+}
@@ -0,0 +1,107 @@
+// INTERP-106: External Conformance Test Import
+//
+// Mission: Projects contribute their own conformance cases
+// - A directory of .ruchy files with companion .expected files is imported
+// - Each pair becomes a TestCase carrying the source and expected output
+// - A .ruchy file without its .expected file is a descriptive error
+// - Imported cases are exported as chapter 00 and read back like the rest
+//
+// Test Coverage:
+// - test_import_fixture_directory: two-file fixture → one TestCase
+// - test_missing_expected_output_is_an_error: error names both files
+// - test_imported_dir_round_trips_through_export: export, import_exported
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ruchyruchy::conformance::{ConformanceExporter, ExportError};
+
+/// Helper: an empty directory unique to this test
+fn fresh_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("ruchyruchy_import_{}_{}", name, std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Helper: write the two-file greeting fixture into `dir`
+fn write_fixture(dir: &Path) {
+    fs::write(
+        dir.join("greeting.ruchy"),
+        "let name = \"Ruchy\";\nprintln(\"Hello, {}!\", name);\nprintln(\"bye\");\n",
+    )
+    .unwrap();
+    fs::write(dir.join("greeting.expected"), "Hello, Ruchy!\nbye\n").unwrap();
+}
+
+/// Test: A .ruchy/.expected pair becomes one TestCase
+#[test]
+fn test_import_fixture_directory() {
+    let dir = fresh_dir("fixture");
+    write_fixture(&dir);
+
+    let cases = ConformanceExporter::new().import_from_dir(&dir).unwrap();
+    fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(cases.len(), 1);
+    let case = &cases[0];
+    assert_eq!(case.name, "greeting");
+    assert_eq!(case.chapter, 0);
+    assert!(case.chapter_name.starts_with("ruchyruchy_import_fixture"));
+    assert_eq!(
+        case.source_code,
+        "let name = \"Ruchy\";\nprintln(\"Hello, {}!\", name);\nprintln(\"bye\");"
+    );
+    assert_eq!(case.expected_output, vec!["Hello, Ruchy!", "bye"]);
+}
+
+/// Test: A missing .expected file names the source and the file looked for
+#[test]
+fn test_missing_expected_output_is_an_error() {
+    let dir = fresh_dir("missing");
+    write_fixture(&dir);
+    fs::write(dir.join("orphan.ruchy"), "println(1);").unwrap();
+
+    let err = ConformanceExporter::new()
+        .import_from_dir(&dir)
+        .unwrap_err();
+    fs::remove_dir_all(&dir).ok();
+
+    match &err {
+        ExportError::MissingExpectedOutput {
+            source_file,
+            expected_file,
+        } => {
+            assert!(source_file.ends_with("orphan.ruchy"));
+            assert!(expected_file.ends_with("orphan.expected"));
+        }
+        other => panic!("expected MissingExpectedOutput, got {:?}", other),
+    }
+    assert!(err.to_string().contains("orphan.expected"), "{}", err);
+}
+
+/// Test: Imported cases are exported as chapter 00 and read back
+#[test]
+fn test_imported_dir_round_trips_through_export() {
+    let dir = fresh_dir("source");
+    write_fixture(&dir);
+    let output_dir = fresh_dir("output");
+    let exporter = ConformanceExporter {
+        output_dir: output_dir.clone(),
+    };
+
+    let result = exporter.export_imported_dir(&dir).unwrap();
+    assert_eq!(result.test_count, 1);
+    assert_eq!(result.chapters_exported, 1);
+
+    let cases = exporter.import_exported().unwrap();
+    fs::remove_dir_all(&dir).ok();
+    fs::remove_dir_all(&output_dir).ok();
+
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].name, "greeting");
+    assert_eq!(cases[0].chapter, 0);
+    assert_eq!(cases[0].expected_output, vec!["Hello, Ruchy!", "bye"]);
+    assert!(cases[0].source_code.starts_with("let name = \"Ruchy\";"));
+}
@@ -0,0 +1,67 @@
+// INTERP-107: Parallel Conformance Export
+//
+// Mission: Export the suite on all CPUs and report progress
+// - Test files of all chapters are written by parallel workers
+// - The progress callback fires once per file with (done, total)
+// - Counts and file contents match the serial export
+//
+// Test Coverage:
+// - test_parallel_export_matches_serial: counts, callback, identical files
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use ruchyruchy::conformance::ConformanceExporter;
+
+/// Helper: an exporter writing to an empty directory unique to this test
+fn exporter(name: &str) -> ConformanceExporter {
+    let output_dir =
+        std::env::temp_dir().join(format!("ruchyruchy_export_{}_{}", name, std::process::id()));
+    fs::remove_dir_all(&output_dir).ok();
+    ConformanceExporter { output_dir }
+}
+
+/// Helper: every exported file, keyed by its path relative to `root`
+fn exported_files(root: &Path) -> BTreeMap<PathBuf, String> {
+    let mut files = BTreeMap::new();
+    for chapter in fs::read_dir(root).unwrap() {
+        for file in fs::read_dir(chapter.unwrap().path()).unwrap() {
+            let path = file.unwrap().path();
+            let content = fs::read_to_string(&path).unwrap();
+            files.insert(path.strip_prefix(root).unwrap().to_path_buf(), content);
+        }
+    }
+    files
+}
+
+/// Test: Parallel export writes the serial export's files and reports progress
+#[test]
+fn test_parallel_export_matches_serial() {
+    let serial = exporter("serial");
+    let parallel = exporter("parallel");
+
+    let baseline = serial.export_all_chapters().unwrap();
+    let calls = Mutex::new(Vec::new());
+    let result = parallel
+        .export_all_chapters_parallel(|done, total| calls.lock().unwrap().push((done, total)))
+        .unwrap();
+
+    assert_eq!(result, baseline);
+    assert!(result.test_count > 0);
+
+    let calls = calls.into_inner().unwrap();
+    let total = result.test_count;
+    assert_eq!(calls.len(), total);
+    let expected: Vec<(usize, usize)> = (1..=total).map(|done| (done, total)).collect();
+    assert_eq!(calls, expected);
+
+    let serial_files = exported_files(&serial.output_dir);
+    let parallel_files = exported_files(&parallel.output_dir);
+    fs::remove_dir_all(&serial.output_dir).ok();
+    fs::remove_dir_all(&parallel.output_dir).ok();
+
+    assert_eq!(parallel_files.len(), total);
+    assert!(parallel_files == serial_files, "exported files differ");
+}
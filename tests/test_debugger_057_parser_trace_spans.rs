@@ -0,0 +1,119 @@
+// DEBUGGER-057: Line-Aware Parser Traces
+//
+// Mission: Parser traces point at the exact failing token
+// - Parse errors carry the failing token's real line and column
+// - parser_trace lists each token step with its line:column
+// - Every trace shows the failing line with a caret under the token
+// - The errors-only trace shows just the failing region plus context
+//
+// Test Coverage:
+// - test_parse_error_has_token_position: ParseError::span on line 3
+// - test_trace_marks_failing_line_and_column: caret and location header
+// - test_trace_steps_are_annotated: tokens listed with line:column
+// - test_errors_only_shows_limited_context: distant lines are omitted
+// - test_analysis_trace_includes_location: root cause mode points too
+// - test_tokenizer_errors_have_literal_position: bad literals on line 3
+
+use ruchyruchy::debugger::{parser_trace, parser_trace_errors_only, parser_trace_with_analysis};
+use ruchyruchy::interpreter::parser::{Parser, Span};
+
+/// A program whose third line is missing a value
+const BROKEN: &str =
+    "let a = 1;\nlet b = 2;\nlet c = ;\nlet d = 4;\nlet e = 5;\nlet f = 6;\nlet g = 7;";
+
+/// Test: The parse error is located at the failing token
+#[test]
+fn test_parse_error_has_token_position() {
+    let err = Parser::new(BROKEN).parse().unwrap_err();
+    assert_eq!(err.span(), Some(Span { line: 3, column: 9 }));
+    assert!(err.to_string().contains("3:9"), "{}", err);
+}
+
+/// Test: The trace names the location and draws a caret under the token
+#[test]
+fn test_trace_marks_failing_line_and_column() {
+    let trace = parser_trace(BROKEN);
+    assert!(
+        trace.contains("Parse failed at line 3, column 9"),
+        "{}",
+        trace
+    );
+    assert!(
+        trace.contains("3 | let c = ;\n  |         ^\n"),
+        "{}",
+        trace
+    );
+}
+
+/// Test: Token steps carry positions and stop at the failing token
+#[test]
+fn test_trace_steps_are_annotated() {
+    let trace = parser_trace(BROKEN);
+    assert!(trace.contains("1:1  Let"), "{}", trace);
+    assert!(trace.contains("2:5  Identifier(\"b\")"), "{}", trace);
+    assert!(
+        trace.contains("3:9  Semicolon  <-- failing token"),
+        "{}",
+        trace
+    );
+    assert!(!trace.contains("Identifier(\"d\")"), "{}", trace);
+}
+
+/// Test: Errors-only mode shows a few lines around the failure
+#[test]
+fn test_errors_only_shows_limited_context() {
+    let trace = parser_trace_errors_only(BROKEN);
+    assert!(
+        trace.contains("ERROR detected at line 3, column 9"),
+        "{}",
+        trace
+    );
+    for shown in ["1 | let a = 1;", "3 | let c = ;", "5 | let e = 5;"] {
+        assert!(trace.contains(shown), "missing {:?} in\n{}", shown, trace);
+    }
+    assert!(!trace.contains("let f = 6;"), "{}", trace);
+    assert!(!trace.contains("Token"), "{}", trace);
+}
+
+/// Test: Root cause mode also reports where the failure is
+#[test]
+fn test_analysis_trace_includes_location() {
+    let trace = parser_trace_with_analysis("fun f() {\n    let x = 1 +;\n}");
+    assert!(
+        trace.contains("Parse failed at line 2, column 16"),
+        "{}",
+        trace
+    );
+    assert!(
+        trace.contains("2 |     let x = 1 +;\n  |                ^"),
+        "{}",
+        trace
+    );
+    assert!(trace.contains("Root Cause Analysis"), "{}", trace);
+}
+
+/// Test: Malformed literals are located at the start of the literal
+#[test]
+fn test_tokenizer_errors_have_literal_position() {
+    let cases = [
+        (
+            "let a = 1;\nlet b = 2;\nlet s = \"x\\q\";",
+            Span { line: 3, column: 9 },
+        ),
+        (
+            "let a = 1;\n\n  let m = 0b1012;",
+            Span {
+                line: 3,
+                column: 11,
+            },
+        ),
+        (
+            "let a = 1;\nlet b = 2;\nlet c = 'ab';",
+            Span { line: 3, column: 9 },
+        ),
+    ];
+    for (source, span) in cases {
+        let err = Parser::new(source).parse().unwrap_err();
+        assert_eq!(err.span(), Some(span), "{}: {}", source, err);
+    }
+}
@@ -0,0 +1,97 @@
+// DEBUGGER-058: Flame Graph Frame Coloring by Source File
+//
+// Mission: Large flame graphs are readable because modules stand out
+// - FlameGraph::to_svg renders frames colored by their source file
+// - Colors come from a stable hash of the path, so SVGs are reproducible
+// - A legend maps each color to its file
+// - Frames without file info (raw IPs) are a neutral gray
+//
+// Test Coverage:
+// - test_frame_file_parses_resolved_labels: `function (file:line)` labels
+// - test_file_colors_are_stable_and_distinct: fixed hue per file
+// - test_svg_colors_frames_by_file: same file, same fill; legend entries
+// - test_unresolved_frames_are_gray: hex IP frames and the no-file legend row
+// - test_svg_is_deterministic: identical SVG for the same stacks
+
+use ruchyruchy::profiling::{file_color, frame_file, FlameGraph, Sample, NO_FILE_COLOR};
+
+/// Folded stacks with symbol-resolved frames from two source files
+const RESOLVED: &str = "main (src/main.rs:10);parse (src/parser.rs:42) 30\n\
+                        main (src/main.rs:10);parse (src/parser.rs:42);lex (src/parser.rs:7) 20\n\
+                        main (src/main.rs:10);run (src/main.rs:25) 50";
+
+/// Test: Resolved frame labels yield their file; others yield None
+#[test]
+fn test_frame_file_parses_resolved_labels() {
+    assert_eq!(
+        frame_file("parse (src/parser.rs:42)"),
+        Some("src/parser.rs")
+    );
+    assert_eq!(
+        frame_file("Vec<T>::push (/rustc/lib/vec.rs:1820)"),
+        Some("/rustc/lib/vec.rs")
+    );
+    assert_eq!(frame_file("0x7ffff7a1b2c3"), None);
+    assert_eq!(frame_file("call (no location)"), None);
+    assert_eq!(frame_file("parse (src/parser.rs:)"), None);
+}
+
+/// Test: Each file hashes to one fixed color; different files differ
+#[test]
+fn test_file_colors_are_stable_and_distinct() {
+    let parser = file_color(Some("src/parser.rs"));
+    assert_eq!(parser, file_color(Some("src/parser.rs")));
+    assert!(parser.starts_with("hsl("), "{}", parser);
+    assert_ne!(parser, file_color(Some("src/main.rs")));
+    assert_eq!(file_color(None), NO_FILE_COLOR);
+}
+
+/// Test: Frames from the same file share a fill and the legend lists files
+#[test]
+fn test_svg_colors_frames_by_file() {
+    let svg = FlameGraph::from_folded(RESOLVED).to_svg();
+    assert!(svg.starts_with("<svg"), "{}", svg);
+    assert!(svg.trim_end().ends_with("</svg>"));
+
+    let parser_fill = format!("fill=\"{}\"", file_color(Some("src/parser.rs")));
+    let main_fill = format!("fill=\"{}\"", file_color(Some("src/main.rs")));
+    // parse + lex frames, plus one legend swatch
+    assert_eq!(svg.matches(&parser_fill).count(), 3, "{}", svg);
+    // main + run frames, plus one legend swatch
+    assert_eq!(svg.matches(&main_fill).count(), 3, "{}", svg);
+
+    assert!(svg.contains(">src/main.rs</text>"));
+    assert!(svg.contains(">src/parser.rs</text>"));
+    assert!(!svg.contains("(no file info)"));
+    assert!(svg.contains("<title>main (src/main.rs:10) (100 samples, 100.00%)</title>"));
+    assert!(svg.contains("<title>run (src/main.rs:25) (50 samples, 50.00%)</title>"));
+}
+
+/// Test: Frames without file info are drawn gray with a legend entry
+#[test]
+fn test_unresolved_frames_are_gray() {
+    let samples = vec![Sample {
+        ip: 0x1000,
+        tid: 1,
+        time: 0,
+        stack: vec![0x1000, 0x2000],
+    }];
+    let svg = FlameGraph::from_samples(&samples).to_svg();
+
+    let gray = format!("fill=\"{}\"", NO_FILE_COLOR);
+    assert_eq!(svg.matches(&gray).count(), 3, "{}", svg);
+    assert!(svg.contains("(no file info)"));
+    assert!(!svg.contains("hsl("));
+}
+
+/// Test: The same stacks always render the same SVG
+#[test]
+fn test_svg_is_deterministic() {
+    let first = FlameGraph::from_folded(RESOLVED).to_svg();
+    for _ in 0..5 {
+        assert_eq!(FlameGraph::from_folded(RESOLVED).to_svg(), first);
+    }
+
+    let round_trip = FlameGraph::from_folded(&FlameGraph::from_folded(RESOLVED).to_string());
+    assert_eq!(round_trip.to_svg(), first);
+}
@@ -0,0 +1,103 @@
+// DEBUGGER-059: Flame Graph SVG Geometry
+//
+// Mission: FlameGraph::to_svg output can be snapshot-tested
+// - Frame widths are proportional to sample counts (1200px = all samples)
+// - Callees sit one row above their caller, siblings in name order
+// - Frames wide enough for text carry their label; narrow ones do not
+// - Only the aggregated stacks matter: sample times and thread IDs never
+//   reach the SVG
+//
+// Test Coverage:
+// - test_frame_rects_proportional_to_samples: exact rect geometry for 3:1 split
+// - test_frame_labels: labels on wide frames, none on a 1% frame
+// - test_sample_times_do_not_affect_svg: same stacks, different times/tids
+// - test_empty_graph_renders_empty_svg: no samples, no frames
+
+use ruchyruchy::profiling::{FlameGraph, Sample};
+
+/// Helper: `count` samples with user stack `stack` (innermost frame first)
+fn samples(stack: &[u64], count: usize) -> Vec<Sample> {
+    (0..count)
+        .map(|i| Sample {
+            ip: stack[0],
+            tid: 1,
+            time: i as u64 * 1000,
+            stack: stack.to_vec(),
+        })
+        .collect()
+}
+
+/// Test: Rectangles are scaled by sample share and stacked by depth
+#[test]
+fn test_frame_rects_proportional_to_samples() {
+    // 0xa calls 0xb (3 samples) and 0xc (1 sample)
+    let mut all = samples(&[0xb, 0xa], 3);
+    all.extend(samples(&[0xc, 0xa], 1));
+    let svg = FlameGraph::from_samples(&all).to_svg();
+
+    // One legend row plus a spacer row puts the graph at y=32; two levels deep
+    assert!(
+        svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"1200\" height=\"64\"")
+    );
+    let gray = "fill=\"rgb(200,200,200)\"";
+    for (x, y, width) in [
+        ("0.00", 48, "1200.00"),
+        ("0.00", 32, "900.00"),
+        ("900.00", 32, "300.00"),
+    ] {
+        let rect = format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"15\" {}/>",
+            x, y, width, gray
+        );
+        assert!(svg.contains(&rect), "missing {}\n{}", rect, svg);
+    }
+    assert!(svg.contains("<title>0xa (4 samples, 100.00%)</title>"));
+    assert!(svg.contains("<title>0xb (3 samples, 75.00%)</title>"));
+    assert!(svg.contains("<title>0xc (1 samples, 25.00%)</title>"));
+}
+
+/// Test: Wide frames are labelled; a frame narrower than a label is not
+#[test]
+fn test_frame_labels() {
+    let mut all = samples(&[0xb, 0xa], 99);
+    all.extend(samples(&[0xc, 0xa], 1));
+    let svg = FlameGraph::from_samples(&all).to_svg();
+
+    assert!(
+        svg.contains("<text x=\"3.00\" y=\"60\">0xa</text>"),
+        "{}",
+        svg
+    );
+    assert!(
+        svg.contains("<text x=\"3.00\" y=\"44\">0xb</text>"),
+        "{}",
+        svg
+    );
+    // 0xc is 12px wide: drawn and titled, but without a text label
+    assert!(svg.contains("<title>0xc (1 samples, 1.00%)</title>"));
+    assert!(!svg.contains(">0xc</text>"), "{}", svg);
+}
+
+/// Test: Timestamps and thread IDs do not change the rendering
+#[test]
+fn test_sample_times_do_not_affect_svg() {
+    let first = FlameGraph::from_samples(&samples(&[0x2, 0x1], 5)).to_svg();
+    let shifted: Vec<Sample> = samples(&[0x2, 0x1], 5)
+        .into_iter()
+        .map(|sample| Sample {
+            time: sample.time + 987_654_321,
+            tid: 42,
+            ..sample
+        })
+        .collect();
+    assert_eq!(FlameGraph::from_samples(&shifted).to_svg(), first);
+}
+
+/// Test: No samples still render a well-formed, frameless SVG
+#[test]
+fn test_empty_graph_renders_empty_svg() {
+    let svg = FlameGraph::from_samples(&[]).to_svg();
+    assert!(svg.starts_with("<svg"), "{}", svg);
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(!svg.contains("<title>"), "{}", svg);
+}
@@ -0,0 +1,156 @@
+// DEBUGGER-060: Symbol Resolution for Profiles
+//
+// Mission: Hotspots and flame graphs show function names, not raw IPs
+// - SymbolResolver maps a runtime IP to `function+0xoffset` using the
+//   executable's symbol table and DWARF (addr2line), with a per-IP cache
+// - Hotspot::analyze_resolved aggregates samples by function name
+// - FlameGraph::from_samples_resolved labels frames `function (file:line)`
+// - Unresolvable addresses fall back to hex
+//
+// Test Coverage:
+// - test_resolve_known_function: a local fn resolves to its name at +0x0
+// - test_resolve_inside_function_reports_offset: ip + 4 is +0x4
+// - test_unmapped_address_is_none: address 0x10 has no symbol
+// - test_resolution_is_cached_and_stable: repeated lookups agree
+// - test_hotspots_by_function_name: two IPs in one function form one entry
+// - test_resolved_flame_graph_labels: frames carry names and this file
+
+use ruchyruchy::profiling::{frame_file, FlameGraph, Hotspot, Sample, SymbolResolver};
+
+/// A function whose address the tests resolve
+#[inline(never)]
+fn known_leaf_function(x: u64) -> u64 {
+    std::hint::black_box(x).wrapping_mul(31).rotate_left(7)
+}
+
+/// A second function, used as the caller frame in stacks
+#[inline(never)]
+fn known_caller_function(x: u64) -> u64 {
+    known_leaf_function(std::hint::black_box(x)) ^ 0x55
+}
+
+/// Helper: runtime address of a function
+fn address_of(f: fn(u64) -> u64) -> u64 {
+    f as usize as u64
+}
+
+/// Helper: a sample at `ip` with user stack `stack` (innermost first)
+fn sample(ip: u64, stack: Vec<u64>) -> Sample {
+    Sample {
+        ip,
+        tid: 1,
+        time: 0,
+        stack,
+    }
+}
+
+/// Test: The entry address of a local function resolves to its name
+#[test]
+fn test_resolve_known_function() {
+    let resolver = SymbolResolver::for_current_exe().unwrap();
+    let symbol = resolver.resolve(address_of(known_leaf_function)).unwrap();
+    assert!(
+        symbol.ends_with("::known_leaf_function+0x0"),
+        "unexpected symbol {}",
+        symbol
+    );
+    assert!(!symbol.contains("::h"), "hash not stripped: {}", symbol);
+}
+
+/// Test: An address inside the function reports its offset
+#[test]
+fn test_resolve_inside_function_reports_offset() {
+    let resolver = SymbolResolver::for_current_exe().unwrap();
+    let symbol = resolver
+        .resolve(address_of(known_leaf_function) + 4)
+        .unwrap();
+    assert!(symbol.ends_with("::known_leaf_function+0x4"), "{}", symbol);
+}
+
+/// Test: An address outside the executable does not resolve
+#[test]
+fn test_unmapped_address_is_none() {
+    let resolver = SymbolResolver::for_current_exe().unwrap();
+    assert_eq!(resolver.resolve(0x10), None);
+    assert_eq!(resolver.function_name(0x10), None);
+}
+
+/// Test: Repeated lookups (served from the cache) give the same answer
+#[test]
+fn test_resolution_is_cached_and_stable() {
+    let resolver = SymbolResolver::for_current_exe().unwrap();
+    let ip = address_of(known_caller_function);
+    let first = resolver.resolve(ip);
+    assert!(first.is_some());
+    for _ in 0..3 {
+        assert_eq!(resolver.resolve(ip), first);
+    }
+    assert_eq!(resolver.function_name(ip).unwrap() + "+0x0", first.unwrap());
+}
+
+/// Test: Samples at different IPs in one function count as one hotspot
+#[test]
+fn test_hotspots_by_function_name() {
+    assert_eq!(known_caller_function(3), known_leaf_function(3) ^ 0x55);
+    let resolver = SymbolResolver::for_current_exe().unwrap();
+    let leaf = address_of(known_leaf_function);
+    let caller = address_of(known_caller_function);
+    let samples = vec![
+        sample(leaf, vec![]),
+        sample(leaf + 2, vec![]),
+        sample(leaf + 4, vec![]),
+        sample(caller, vec![]),
+        sample(0x10, vec![]),
+    ];
+
+    let hotspots = Hotspot::analyze_resolved(&samples, 10, &resolver);
+    assert_eq!(hotspots.len(), 3, "{:?}", hotspots);
+    assert!(hotspots[0].function.ends_with("::known_leaf_function"));
+    assert_eq!(hotspots[0].count, 3);
+    assert!((hotspots[0].percentage - 60.0).abs() < 1e-9);
+    // One sample each; ties are ordered by name
+    let rest: Vec<&str> = hotspots[1..].iter().map(|h| h.function.as_str()).collect();
+    assert!(rest.contains(&"0x10"), "{:?}", rest);
+    assert!(rest
+        .iter()
+        .any(|name| name.ends_with("::known_caller_function")));
+}
+
+/// Test: Resolved flame graph frames are `function (file:line)`
+#[test]
+fn test_resolved_flame_graph_labels() {
+    let resolver = SymbolResolver::for_current_exe().unwrap();
+    let leaf = address_of(known_leaf_function);
+    let caller = address_of(known_caller_function);
+    let samples = vec![
+        sample(leaf, vec![leaf, caller]),
+        sample(leaf + 4, vec![leaf + 4, caller + 8]),
+    ];
+
+    let folded = FlameGraph::from_samples_resolved(&samples, &resolver).to_string();
+    // Both samples aggregate into a single caller;leaf stack
+    assert_eq!(folded.lines().count(), 1, "{}", folded);
+    let (stack, count) = folded.rsplit_once(' ').unwrap();
+    assert_eq!(count, "2");
+
+    let frames: Vec<&str> = stack.split(';').collect();
+    assert_eq!(frames.len(), 2, "{}", stack);
+    assert!(
+        frames[0].contains("::known_caller_function ("),
+        "{}",
+        frames[0]
+    );
+    assert!(
+        frames[1].contains("::known_leaf_function ("),
+        "{}",
+        frames[1]
+    );
+    for frame in frames {
+        let file = frame_file(frame).unwrap_or_else(|| panic!("no file in {}", frame));
+        assert!(
+            file.ends_with("test_debugger_060_symbol_resolution.rs"),
+            "{}",
+            file
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// DEBUGGER-061: Call-Graph Hotspots
+//
+// Mission: See which call path dominates, not just the hottest leaf
+// - Hotspot::analyze_callgraph keys entries by call path (root first)
+// - Inclusive counts roll up every sample below a path
+// - Exclusive counts cover only samples that stopped at the path's leaf
+// - Entries expose self-time and total-time percentages
+//
+// Test Coverage:
+// - test_inclusive_counts_roll_up: main → {parse → lex, run}
+// - test_exclusive_counts_match_leaf_tallies: exclusive sums equal sample count
+// - test_same_leaf_different_callers: one function reached via two paths
+// - test_percentages_and_ordering: self/total %, sorted by inclusive count
+// - test_top_n_and_empty_input: truncation and no samples
+// - test_sample_without_stack_uses_ip: one-frame path at the IP
+
+use ruchyruchy::profiling::{CallPathEntry, Hotspot, Sample};
+
+const MAIN: u64 = 0x1000;
+const PARSE: u64 = 0x2000;
+const LEX: u64 = 0x3000;
+const RUN: u64 = 0x4000;
+
+/// Helper: `count` samples with the given stack (innermost frame first)
+fn samples(stack: &[u64], count: usize) -> Vec<Sample> {
+    (0..count)
+        .map(|i| Sample {
+            ip: stack[0],
+            tid: 1,
+            time: i as u64,
+            stack: stack.to_vec(),
+        })
+        .collect()
+}
+
+/// Helper: main → parse (2 self), main → parse → lex (3), main → run (5),
+/// main itself (1)
+fn program_samples() -> Vec<Sample> {
+    let mut all = samples(&[PARSE, MAIN], 2);
+    all.extend(samples(&[LEX, PARSE, MAIN], 3));
+    all.extend(samples(&[RUN, MAIN], 5));
+    all.extend(samples(&[MAIN], 1));
+    all
+}
+
+/// Helper: the entry for a root-first path
+fn entry<'a>(entries: &'a [CallPathEntry], path: &[u64]) -> &'a CallPathEntry {
+    let path: Vec<String> = path.iter().map(|ip| format!("0x{:x}", ip)).collect();
+    entries
+        .iter()
+        .find(|e| e.path == path)
+        .unwrap_or_else(|| panic!("no entry for {:?} in {:?}", path, entries))
+}
+
+/// Test: A caller's inclusive count is its own samples plus its callees'
+#[test]
+fn test_inclusive_counts_roll_up() {
+    let entries = Hotspot::analyze_callgraph(&program_samples(), 100);
+    assert_eq!(entries.len(), 4, "{:?}", entries);
+
+    assert_eq!(entry(&entries, &[MAIN]).inclusive_count, 11);
+    assert_eq!(entry(&entries, &[MAIN, PARSE]).inclusive_count, 5);
+    assert_eq!(entry(&entries, &[MAIN, PARSE, LEX]).inclusive_count, 3);
+    assert_eq!(entry(&entries, &[MAIN, RUN]).inclusive_count, 5);
+}
+
+/// Test: Exclusive counts are exactly the per-leaf sample tallies
+#[test]
+fn test_exclusive_counts_match_leaf_tallies() {
+    let entries = Hotspot::analyze_callgraph(&program_samples(), 100);
+
+    assert_eq!(entry(&entries, &[MAIN]).exclusive_count, 1);
+    assert_eq!(entry(&entries, &[MAIN, PARSE]).exclusive_count, 2);
+    assert_eq!(entry(&entries, &[MAIN, PARSE, LEX]).exclusive_count, 3);
+    assert_eq!(entry(&entries, &[MAIN, RUN]).exclusive_count, 5);
+
+    let exclusive_total: usize = entries.iter().map(|e| e.exclusive_count).sum();
+    assert_eq!(exclusive_total, program_samples().len());
+}
+
+/// Test: The same leaf under two callers is two separate paths
+#[test]
+fn test_same_leaf_different_callers() {
+    let mut all = samples(&[LEX, PARSE, MAIN], 4);
+    all.extend(samples(&[LEX, RUN, MAIN], 1));
+    let entries = Hotspot::analyze_callgraph(&all, 100);
+
+    let via_parse = entry(&entries, &[MAIN, PARSE, LEX]);
+    let via_run = entry(&entries, &[MAIN, RUN, LEX]);
+    assert_eq!(via_parse.exclusive_count, 4);
+    assert_eq!(via_run.exclusive_count, 1);
+    assert_eq!(via_parse.function(), "0x3000");
+    assert_eq!(via_run.function(), "0x3000");
+
+    // The leaf-only view merges them into one hotspot
+    let flat = Hotspot::analyze(&all, 10);
+    assert_eq!(flat.len(), 1);
+    assert_eq!(flat[0].count, 5);
+}
+
+/// Test: Percentages use the total sample count; hottest path first
+#[test]
+fn test_percentages_and_ordering() {
+    let entries = Hotspot::analyze_callgraph(&program_samples(), 100);
+
+    let main = &entries[0];
+    assert_eq!(main.path, vec!["0x1000".to_string()]);
+    assert!((main.total_percentage - 100.0).abs() < 1e-9);
+    assert!((main.self_percentage - 100.0 / 11.0).abs() < 1e-9);
+
+    // parse and run tie on inclusive count; run has more self time
+    assert_eq!(entries[1].function(), "0x4000");
+    assert_eq!(entries[2].function(), "0x2000");
+    assert!((entries[1].self_percentage - entries[1].total_percentage).abs() < 1e-9);
+    assert_eq!(entries[3].function(), "0x3000");
+
+    for pair in entries.windows(2) {
+        assert!(pair[0].inclusive_count >= pair[1].inclusive_count);
+    }
+}
+
+/// Test: top_n limits the result; no samples yields no entries
+#[test]
+fn test_top_n_and_empty_input() {
+    let entries = Hotspot::analyze_callgraph(&program_samples(), 2);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].inclusive_count, 11);
+
+    assert!(Hotspot::analyze_callgraph(&[], 10).is_empty());
+}
+
+/// Test: A sample without a stack counts as a one-frame path at its IP
+#[test]
+fn test_sample_without_stack_uses_ip() {
+    let sample = Sample {
+        ip: 0xabc,
+        tid: 1,
+        time: 0,
+        stack: vec![],
+    };
+    let entries = Hotspot::analyze_callgraph(&[sample], 10);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].path, vec!["0xabc".to_string()]);
+    assert_eq!(entries[0].exclusive_count, 1);
+    assert_eq!(entries[0].inclusive_count, 1);
+}
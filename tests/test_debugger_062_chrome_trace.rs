@@ -0,0 +1,178 @@
+// DEBUGGER-062: Chrome Trace Event Export
+//
+// Mission: Load RuchyRuchy traces in Perfetto / chrome://tracing directly
+// - TraceFile::to_chrome_trace emits `{"traceEvents": [...]}`
+// - FunctionEnter/FunctionExit become `B`/`E` events, syscalls `X`
+// - SourceLocation goes to `args`, thread IDs to `tid`
+// - Timestamps are microseconds (from the events' nanoseconds)
+//
+// Test Coverage:
+// - test_events_are_balanced_per_thread: nested calls on two threads
+// - test_location_and_values_in_args: file/line/column, arguments, return
+// - test_timestamps_and_thread_ids: ts in µs, tid from thread_id
+// - test_syscall_is_complete_event: ph X with dur
+// - test_round_trip_through_json_trace: TraceFile JSON → back → Chrome trace
+
+use ruchyruchy::tracing::events::SyscallEvent;
+use ruchyruchy::tracing::{
+    FunctionEntry, FunctionExit, JsonFormatter, SourceLocation, TraceEvent, TraceFile,
+    TraceMetadata, TraceStats, TypeInfo, TypedValue,
+};
+use serde_json::Value;
+
+/// Helper: an i64 traced value
+fn int(value: i64) -> TypedValue {
+    TypedValue {
+        type_info: TypeInfo {
+            name: "i64".to_string(),
+            fields: None,
+        },
+        value: serde_json::json!(value),
+    }
+}
+
+/// Helper: a function entry at `line` of main.ruchy
+fn enter(name: &str, line: u32, ts: u64, tid: u64) -> TraceEvent {
+    TraceEvent::FunctionEnter(FunctionEntry {
+        name: name.to_string(),
+        args: vec![int(line as i64)],
+        location: SourceLocation {
+            file: "main.ruchy".to_string(),
+            line,
+            column: 5,
+        },
+        timestamp_ns: ts,
+        thread_id: tid,
+    })
+}
+
+/// Helper: a function exit returning `ret`
+fn exit(name: &str, ret: i64, ts: u64, tid: u64) -> TraceEvent {
+    TraceEvent::FunctionExit(FunctionExit {
+        name: name.to_string(),
+        return_value: Some(int(ret)),
+        duration_ns: 0,
+        timestamp_ns: ts,
+        thread_id: tid,
+    })
+}
+
+/// Helper: main → fib → fib on thread 1, worker on thread 2, interleaved
+fn trace_file() -> TraceFile {
+    let events = vec![
+        enter("main", 1, 1_000, 1),
+        enter("fib", 2, 2_000, 1),
+        enter("worker", 9, 2_500, 2),
+        enter("fib", 2, 3_000, 1),
+        exit("fib", 1, 4_000, 1),
+        exit("worker", 0, 4_500, 2),
+        exit("fib", 2, 5_000, 1),
+        exit("main", 0, 6_000, 1),
+    ];
+    TraceFile {
+        metadata: TraceMetadata {
+            program: "main.ruchy".to_string(),
+            start_time: "2026-01-01T00:00:00Z".to_string(),
+            ruchy_version: "3.147.7".to_string(),
+            ruchyruchy_version: "1.27.0".to_string(),
+        },
+        stats: TraceStats {
+            total_events: events.len(),
+            dropped_events: 0,
+            duration_ns: 5_000,
+        },
+        events,
+    }
+}
+
+/// Helper: the `traceEvents` array of an exported trace
+fn trace_events(json: &str) -> Vec<Value> {
+    let doc: Value = serde_json::from_str(json).expect("valid JSON");
+    doc["traceEvents"].as_array().expect("traceEvents").clone()
+}
+
+/// Test: Every E closes the most recent open B of the same name on its thread
+#[test]
+fn test_events_are_balanced_per_thread() {
+    let events = trace_events(&trace_file().to_chrome_trace());
+    assert_eq!(events.len(), 8);
+
+    let mut open: std::collections::HashMap<u64, Vec<String>> = Default::default();
+    let mut max_depth = 0;
+    for event in &events {
+        let tid = event["tid"].as_u64().unwrap();
+        let name = event["name"].as_str().unwrap().to_string();
+        let stack = open.entry(tid).or_default();
+        match event["ph"].as_str().unwrap() {
+            "B" => {
+                stack.push(name);
+                max_depth = max_depth.max(stack.len());
+            }
+            "E" => assert_eq!(stack.pop(), Some(name), "unbalanced at {}", event),
+            other => panic!("unexpected phase {}", other),
+        }
+    }
+    assert!(open.values().all(Vec::is_empty), "{:?}", open);
+    assert_eq!(max_depth, 3);
+}
+
+/// Test: Begin events carry the location; end events the return value
+#[test]
+fn test_location_and_values_in_args() {
+    let events = trace_events(&trace_file().to_chrome_trace());
+
+    let main = &events[0];
+    assert_eq!(main["name"], "main");
+    assert_eq!(main["ph"], "B");
+    assert_eq!(main["args"]["file"], "main.ruchy");
+    assert_eq!(main["args"]["line"], 1);
+    assert_eq!(main["args"]["column"], 5);
+    assert_eq!(main["args"]["arguments"], serde_json::json!([1]));
+
+    let inner_exit = &events[4];
+    assert_eq!(inner_exit["ph"], "E");
+    assert_eq!(inner_exit["args"]["return"], 1);
+}
+
+/// Test: ts is microseconds and tid is the event's thread ID
+#[test]
+fn test_timestamps_and_thread_ids() {
+    let events = trace_events(&trace_file().to_chrome_trace());
+
+    assert_eq!(events[0]["ts"].as_f64(), Some(1.0));
+    assert_eq!(events[2]["ts"].as_f64(), Some(2.5));
+    assert_eq!(events[2]["tid"], 2);
+    assert_eq!(events[7]["tid"], 1);
+    assert!(events.iter().all(|e| e["pid"] == events[0]["pid"]));
+}
+
+/// Test: A syscall is a single complete event with its duration
+#[test]
+fn test_syscall_is_complete_event() {
+    let mut trace = trace_file();
+    trace.events.push(TraceEvent::Syscall(SyscallEvent {
+        number: 1,
+        name: "write".to_string(),
+        args: vec![serde_json::json!(1)],
+        return_value: 6,
+        duration_ns: 1_500,
+        timestamp_ns: 7_000,
+        thread_id: 1,
+    }));
+
+    let events = trace_events(&trace.to_chrome_trace());
+    let syscall = events.last().unwrap();
+    assert_eq!(syscall["ph"], "X");
+    assert_eq!(syscall["name"], "write");
+    assert_eq!(syscall["dur"].as_f64(), Some(1.5));
+    assert_eq!(syscall["args"]["return"], 6);
+}
+
+/// Test: A trace saved as JSON and loaded back exports identically
+#[test]
+fn test_round_trip_through_json_trace() {
+    let original = trace_file();
+    let saved = JsonFormatter::new(false).format(&original).unwrap();
+    let loaded: TraceFile = serde_json::from_str(&saved).unwrap();
+    assert_eq!(loaded.to_chrome_trace(), original.to_chrome_trace());
+}
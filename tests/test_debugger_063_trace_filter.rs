@@ -0,0 +1,226 @@
+// DEBUGGER-063: Trace Event Filtering
+//
+// Mission: Whole-compiler traces stay readable
+// - TraceFilter keeps functions matching include globs (`parse_*`) and
+//   drops those matching exclude globs
+// - An exit is dropped exactly when its entry was, so nesting stays balanced
+// - TraceFile::apply_filter filters before serialization
+//
+// Test Coverage:
+// - test_include_prefix_only: only parse_* functions remain
+// - test_exclude_rules: everything except lex_* and debug_*
+// - test_filtered_stream_is_balanced: recursion and interleaved threads
+// - test_exit_follows_entry_not_name: mismatched exit name follows its entry
+// - test_syscalls_are_kept: non-function events pass through
+// - test_trace_file_filter_before_output: Chrome export of a filtered trace
+
+use ruchyruchy::tracing::events::SyscallEvent;
+use ruchyruchy::tracing::{
+    FunctionEntry, FunctionExit, SourceLocation, TraceEvent, TraceFile, TraceFilter, TraceMetadata,
+    TraceStats,
+};
+use std::collections::HashMap;
+
+/// Helper: a function entry on thread `tid`
+fn enter(name: &str, tid: u64) -> TraceEvent {
+    TraceEvent::FunctionEnter(FunctionEntry {
+        name: name.to_string(),
+        args: vec![],
+        location: SourceLocation {
+            file: "compiler.ruchy".to_string(),
+            line: 1,
+            column: 1,
+        },
+        timestamp_ns: 0,
+        thread_id: tid,
+    })
+}
+
+/// Helper: a function exit on thread `tid`
+fn exit(name: &str, tid: u64) -> TraceEvent {
+    TraceEvent::FunctionExit(FunctionExit {
+        name: name.to_string(),
+        return_value: None,
+        duration_ns: 0,
+        timestamp_ns: 0,
+        thread_id: tid,
+    })
+}
+
+/// Helper: compile → parse_program → {lex_token, parse_expr → debug_dump}
+fn compiler_events() -> Vec<TraceEvent> {
+    vec![
+        enter("compile", 1),
+        enter("parse_program", 1),
+        enter("lex_token", 1),
+        exit("lex_token", 1),
+        enter("parse_expr", 1),
+        enter("debug_dump", 1),
+        exit("debug_dump", 1),
+        exit("parse_expr", 1),
+        exit("parse_program", 1),
+        exit("compile", 1),
+    ]
+}
+
+/// Helper: events rendered as `+name` / `-name` (`!name` for syscalls)
+fn shape(events: &[TraceEvent]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| match event {
+            TraceEvent::FunctionEnter(e) => format!("+{}", e.name),
+            TraceEvent::FunctionExit(e) => format!("-{}", e.name),
+            TraceEvent::Syscall(e) => format!("!{}", e.name),
+        })
+        .collect()
+}
+
+/// Helper: assert every exit closes the innermost open entry of its thread
+fn assert_balanced(events: &[TraceEvent]) {
+    let mut open: HashMap<u64, Vec<&str>> = HashMap::new();
+    for event in events {
+        match event {
+            TraceEvent::FunctionEnter(e) => open.entry(e.thread_id).or_default().push(&e.name),
+            TraceEvent::FunctionExit(e) => {
+                let top = open.entry(e.thread_id).or_default().pop();
+                assert_eq!(
+                    top,
+                    Some(e.name.as_str()),
+                    "unbalanced: {:?}",
+                    shape(events)
+                );
+            }
+            TraceEvent::Syscall(_) => {}
+        }
+    }
+    assert!(open.values().all(Vec::is_empty), "unclosed: {:?}", open);
+}
+
+/// Test: An include prefix keeps only the matching functions
+#[test]
+fn test_include_prefix_only() {
+    let filtered = TraceFilter::new()
+        .include("parse_*")
+        .apply(compiler_events());
+    assert_eq!(
+        shape(&filtered),
+        [
+            "+parse_program",
+            "+parse_expr",
+            "-parse_expr",
+            "-parse_program"
+        ]
+    );
+    assert_balanced(&filtered);
+}
+
+/// Test: Exclude rules drop matching functions from an unrestricted trace
+#[test]
+fn test_exclude_rules() {
+    let filtered = TraceFilter::new()
+        .exclude("lex_*")
+        .exclude("debug_*")
+        .apply(compiler_events());
+    assert_eq!(
+        shape(&filtered),
+        [
+            "+compile",
+            "+parse_program",
+            "+parse_expr",
+            "-parse_expr",
+            "-parse_program",
+            "-compile"
+        ]
+    );
+    assert_balanced(&filtered);
+}
+
+/// Test: Recursive calls and interleaved threads stay well-formed
+#[test]
+fn test_filtered_stream_is_balanced() {
+    let events = vec![
+        enter("parse_expr", 1),
+        enter("helper", 2),
+        enter("eval", 1),
+        enter("parse_expr", 1),
+        enter("parse_atom", 2),
+        exit("parse_expr", 1),
+        exit("parse_atom", 2),
+        exit("eval", 1),
+        exit("helper", 2),
+        exit("parse_expr", 1),
+    ];
+    let filtered = TraceFilter::new().include("parse_*").apply(events);
+    assert_balanced(&filtered);
+    assert_eq!(filtered.len(), 6);
+    assert!(shape(&filtered)
+        .iter()
+        .all(|event| event[1..].starts_with("parse_")));
+}
+
+/// Test: An exit is kept or dropped with the entry it closes
+#[test]
+fn test_exit_follows_entry_not_name() {
+    // The exit for dropped `helper` is recorded under a parse_* name
+    let events = vec![
+        enter("parse_block", 1),
+        enter("helper", 1),
+        exit("parse_helper", 1),
+        exit("parse_block", 1),
+    ];
+    let filtered = TraceFilter::new().include("parse_*").apply(events);
+    assert_eq!(shape(&filtered), ["+parse_block", "-parse_block"]);
+}
+
+/// Test: Syscall events are not subject to function-name rules
+#[test]
+fn test_syscalls_are_kept() {
+    let mut events = compiler_events();
+    events.insert(
+        3,
+        TraceEvent::Syscall(SyscallEvent {
+            number: 0,
+            name: "read".to_string(),
+            args: vec![],
+            return_value: 0,
+            duration_ns: 0,
+            timestamp_ns: 0,
+            thread_id: 1,
+        }),
+    );
+    let filtered = TraceFilter::new().include("parse_program").apply(events);
+    assert_eq!(
+        shape(&filtered),
+        ["+parse_program", "!read", "-parse_program"]
+    );
+}
+
+/// Test: Filtering a TraceFile limits what gets exported
+#[test]
+fn test_trace_file_filter_before_output() {
+    let events = compiler_events();
+    let mut trace = TraceFile {
+        metadata: TraceMetadata {
+            program: "compiler.ruchy".to_string(),
+            start_time: "2026-01-01T00:00:00Z".to_string(),
+            ruchy_version: "3.147.7".to_string(),
+            ruchyruchy_version: "1.27.0".to_string(),
+        },
+        stats: TraceStats {
+            total_events: events.len(),
+            dropped_events: 0,
+            duration_ns: 0,
+        },
+        events,
+    };
+
+    trace.apply_filter(&TraceFilter::new().include("parse_expr"));
+    assert_eq!(trace.stats.total_events, 2);
+
+    let chrome = trace.to_chrome_trace();
+    assert!(chrome.contains("parse_expr"));
+    for dropped in ["compile", "lex_token", "debug_dump", "parse_program"] {
+        let name = format!("\"name\":\"{}\"", dropped);
+        assert!(!chrome.contains(&name), "{} in {}", dropped, chrome);
+    }
+}
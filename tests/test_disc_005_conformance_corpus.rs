@@ -0,0 +1,119 @@
+// DISC-005: Conformance Corpus as Differential Input
+//
+// Tests for checking the exported conformance suite for interpreter/JIT divergence.
+//
+// Requirements:
+// - The exported .ruchy files can be read back as TestCases
+// - DifferentialTester runs each test's main through both engines
+// - JIT-incompatible tests are skipped and counted, not reported
+//
+// Expected behavior:
+// - Programs the JIT can compile (including helper functions) are checked
+// - Programs using println or lacking a parameterless main are skipped
+// - checked + skipped always covers the whole corpus
+//
+// Testing Strategy:
+// - Run small conformance-format programs through check_conformance_corpus
+// - Import the committed suite and check it end to end
+// - Round-trip the exported header format through parse_exported_test
+
+use ruchyruchy::bug_discovery::differential::{
+    CompilationTarget, CompilerVersion, DifferentialTester,
+};
+use ruchyruchy::conformance::{parse_exported_test, ConformanceExporter, TestCase};
+
+/// Helper: a tester (the version list is irrelevant for corpus checks)
+fn tester() -> DifferentialTester {
+    DifferentialTester::new(vec![CompilerVersion {
+        version: "current".to_string(),
+        target: CompilationTarget::Debug,
+    }])
+}
+
+/// Helper: a conformance test case with the given source
+fn case(name: &str, source: &str) -> TestCase {
+    TestCase {
+        name: name.to_string(),
+        chapter: 3,
+        chapter_name: "functions".to_string(),
+        description: "Differential corpus test".to_string(),
+        source_code: source.to_string(),
+        expected_output: Vec::new(),
+    }
+}
+
+/// Test: JIT-compatible programs are checked and agree
+#[test]
+fn test_compatible_programs_are_checked() {
+    let cases = [
+        case(
+            "test_helper_call",
+            "fun square(x) {\n    return x * x;\n}\n\nfun main() {\n    return square(7) + 1;\n}",
+        ),
+        case(
+            "test_loop_sum",
+            "fun main() {\n    let mut total = 0;\n    let mut i = 1;\n    while i <= 10 {\n        total = total + i;\n        i = i + 1;\n    }\n    return total;\n}",
+        ),
+    ];
+
+    let report = tester().check_conformance_corpus(&cases);
+    assert_eq!(report.checked, 2, "{:?}", report);
+    assert_eq!(report.skipped, 0);
+    assert!(report.divergences.is_empty(), "{:?}", report.divergences);
+}
+
+/// Test: Programs the JIT cannot run are skipped with a count
+#[test]
+fn test_incompatible_programs_are_skipped() {
+    let cases = [
+        case("test_prints", "fun main() {\n    println(\"Hello\");\n}"),
+        case("test_no_main", "let x = 5;\nprintln(x);"),
+        case("test_ok", "fun main() {\n    return 3;\n}"),
+    ];
+
+    let report = tester().check_conformance_corpus(&cases);
+    assert_eq!(report.checked, 1);
+    assert_eq!(report.skipped, 2);
+    assert!(report.divergences.is_empty());
+}
+
+/// Test: The committed conformance suite is checked end to end
+#[test]
+fn test_exported_suite_has_no_divergence() {
+    let cases = ConformanceExporter::new()
+        .import_exported()
+        .expect("import suite");
+    assert!(!cases.is_empty());
+
+    let report = tester().check_conformance_corpus(&cases);
+    assert_eq!(report.checked + report.skipped, cases.len());
+    assert!(report.divergences.is_empty(), "{:?}", report.divergences);
+}
+
+/// Test: Exported files parse back into the original test case
+#[test]
+fn test_parse_exported_test_round_trip() {
+    let content = "// Test: test_ch02_example_01\n\
+                   // Chapter: 02 - variables\n\
+                   // Description: Chapter 2 Example Tests\n\
+                   //\n\
+                   // Expected Output:\n\
+                   // 42\n\
+                   //\n\
+                   \n\
+                   let x = 42;\n\
+                   println(x);\n\
+                   \n\
+                   // Self-check: golden values captured by the RuchyRuchy interpreter\n\
+                   assert_eq(x, 42);\n";
+
+    let test_case = parse_exported_test(content).expect("parse");
+    assert_eq!(test_case.name, "test_ch02_example_01");
+    assert_eq!(test_case.chapter, 2);
+    assert_eq!(test_case.chapter_name, "variables");
+    assert_eq!(test_case.description, "Chapter 2 Example Tests");
+    assert_eq!(test_case.expected_output, vec!["42", ""]);
+    assert_eq!(test_case.source_code, "let x = 42;\nprintln(x);");
+
+    assert!(parse_exported_test("fun main() {}").is_err());
+}
@@ -72,7 +72,9 @@ fn test_parse_simple_hello_world() {
 
     // Verify AST structure
     match &ast.nodes()[0] {
-        AstNode::FunctionDef { name, params, body } => {
+        AstNode::FunctionDef {
+            name, params, body, ..
+        } => {
             assert_eq!(name, "main");
             assert_eq!(params.len(), 0);
             assert!(!body.is_empty());
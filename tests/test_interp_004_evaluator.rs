@@ -69,7 +69,7 @@
 // - test_eval_combined_logical_expression: Combined logical ops (true && !false) ✅
 // - test_eval_comparison_with_logical: Comparison with logical ((5 < 10) && (10 < 15)) ✅
 // - test_eval_logical_type_error: Logical type error (int && bool) ✅
-// - test_eval_not_type_error: NOT type error (!42) ✅
+// - test_eval_not_type_error: NOT type error (!"yes") ✅
 //
 // Operator Precedence (10 tests):
 // - test_precedence_multiplication_over_addition: 2 + 3 * 4 = 14 (not 20) ✅
@@ -644,11 +644,11 @@ fn test_eval_logical_type_error() {
 
 #[test]
 fn test_eval_not_type_error() {
-    // RED: NOT operator on non-boolean should error
+    // RED: NOT operator on a non-boolean, non-integer should error
     let mut eval = Evaluator::new();
     let node = AstNode::UnaryOp {
         op: UnaryOperator::Not,
-        operand: Box::new(AstNode::IntegerLiteral(42)),
+        operand: Box::new(AstNode::StringLiteral("yes".to_string())),
     };
 
     let result = eval.eval(&node);
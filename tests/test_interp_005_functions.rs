@@ -69,6 +69,8 @@ fn test_simple_function_call_no_args() {
     let func_def = AstNode::FunctionDef {
         name: "get_five".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::IntegerLiteral(5))),
         }],
@@ -96,6 +98,8 @@ fn test_function_call_with_one_arg() {
     let func_def = AstNode::FunctionDef {
         name: "double".to_string(),
         params: vec!["x".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::Multiply,
@@ -124,6 +128,8 @@ fn test_function_call_with_multiple_args() {
     let func_def = AstNode::FunctionDef {
         name: "add".to_string(),
         params: vec!["a".to_string(), "b".to_string()],
+        param_types: vec![None; 2],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::Add,
@@ -152,6 +158,8 @@ fn test_function_call_with_expression_args() {
     let func_def = AstNode::FunctionDef {
         name: "multiply".to_string(),
         params: vec!["x".to_string(), "y".to_string()],
+        param_types: vec![None; 2],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::Multiply,
@@ -190,6 +198,8 @@ fn test_function_with_implicit_return() {
     let func_def = AstNode::FunctionDef {
         name: "get_ten".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::IntegerLiteral(10)],
     };
 
@@ -211,6 +221,8 @@ fn test_function_with_explicit_return() {
     let func_def = AstNode::FunctionDef {
         name: "get_twenty".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::IntegerLiteral(20))),
         }],
@@ -238,6 +250,8 @@ fn test_function_with_early_return() {
     let func_def = AstNode::FunctionDef {
         name: "early".to_string(),
         params: vec!["x".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -282,8 +296,11 @@ fn test_function_with_no_return() {
     let func_def = AstNode::FunctionDef {
         name: "no_return".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::LetDecl {
             name: "x".to_string(),
+            mutable: false,
             value: Box::new(AstNode::IntegerLiteral(5)),
         }],
     };
@@ -315,6 +332,8 @@ fn test_recursive_factorial() {
     let func_def = AstNode::FunctionDef {
         name: "factorial".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -367,6 +386,8 @@ fn test_recursive_fibonacci() {
     let func_def = AstNode::FunctionDef {
         name: "fib".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -426,6 +447,8 @@ fn test_tail_recursion() {
     let func_def = AstNode::FunctionDef {
         name: "sum_to".to_string(),
         params: vec!["n".to_string(), "acc".to_string()],
+        param_types: vec![None; 2],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -489,6 +512,8 @@ fn test_mutual_recursion_is_even_is_odd() {
     let is_even_def = AstNode::FunctionDef {
         name: "is_even".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -517,6 +542,8 @@ fn test_mutual_recursion_is_even_is_odd() {
     let is_odd_def = AstNode::FunctionDef {
         name: "is_odd".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -578,6 +605,8 @@ fn test_stack_overflow_detection() {
     let func_def = AstNode::FunctionDef {
         name: "infinite".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::FunctionCall {
                 name: "infinite".to_string(),
@@ -602,7 +631,7 @@ fn test_stack_overflow_detection() {
 
     match result {
         Err(EvalError::StackOverflow) => {} // Expected (unwrapped)
-        Err(EvalError::WithCallStack { error, call_stack }) => {
+        Err(EvalError::WithCallStack { error, call_stack, .. }) => {
             // Also valid - error wrapped with call stack
             match *error {
                 EvalError::StackOverflow => {
@@ -638,6 +667,8 @@ fn test_deep_recursion_within_limit() {
     let func_def = AstNode::FunctionDef {
         name: "count_down".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::IfExpr {
                 condition: Box::new(AstNode::BinaryOp {
@@ -707,6 +738,8 @@ fn test_argument_count_mismatch() {
     let func_def = AstNode::FunctionDef {
         name: "add".to_string(),
         params: vec!["a".to_string(), "b".to_string()],
+        param_types: vec![None; 2],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::Add,
@@ -747,6 +780,8 @@ fn test_argument_count_mismatch_too_many() {
     let func_def = AstNode::FunctionDef {
         name: "add".to_string(),
         params: vec!["a".to_string(), "b".to_string()],
+        param_types: vec![None; 2],
+        return_type: None,
         body: vec![AstNode::Return {
             value: Some(Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::Add,
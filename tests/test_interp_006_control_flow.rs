@@ -69,6 +69,7 @@ fn test_while_loop_simple() {
     // Initialize sum = 0
     eval.eval(&AstNode::LetDecl {
         name: "sum".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(0)),
     })
     .unwrap();
@@ -76,6 +77,7 @@ fn test_while_loop_simple() {
     // Initialize i = 1
     eval.eval(&AstNode::LetDecl {
         name: "i".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(1)),
     })
     .unwrap();
@@ -120,6 +122,7 @@ fn test_while_loop_zero_iterations() {
 
     eval.eval(&AstNode::LetDecl {
         name: "x".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(0)),
     })
     .unwrap();
@@ -155,12 +158,14 @@ fn test_while_loop_nested() {
 
     eval.eval(&AstNode::LetDecl {
         name: "result".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(0)),
     })
     .unwrap();
 
     eval.eval(&AstNode::LetDecl {
         name: "i".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(1)),
     })
     .unwrap();
@@ -174,6 +179,7 @@ fn test_while_loop_nested() {
         body: vec![
             AstNode::LetDecl {
                 name: "j".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::WhileLoop {
@@ -234,6 +240,7 @@ fn test_for_loop_over_vector() {
 
     eval.eval(&AstNode::LetDecl {
         name: "sum".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(0)),
     })
     .unwrap();
@@ -271,6 +278,7 @@ fn test_for_loop_empty_vector() {
 
     eval.eval(&AstNode::LetDecl {
         name: "x".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(0)),
     })
     .unwrap();
@@ -302,6 +310,7 @@ fn test_for_loop_nested() {
 
     eval.eval(&AstNode::LetDecl {
         name: "result".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(0)),
     })
     .unwrap();
@@ -362,14 +371,17 @@ fn test_match_literal_integer() {
             arms: vec![
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(10)],
                 },
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(20)],
                 },
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::IntegerLiteral(3)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(30)],
                 },
             ],
@@ -395,14 +407,17 @@ fn test_match_with_wildcard() {
             arms: vec![
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(10)],
                 },
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(20)],
                 },
                 MatchArm {
                     pattern: Pattern::Wildcard,
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(999)],
                 },
             ],
@@ -427,10 +442,12 @@ fn test_match_with_identifier_binding() {
             arms: vec![
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(10)],
                 },
                 MatchArm {
                     pattern: Pattern::Identifier("x".to_string()),
+                    guard: None,
                     body: vec![AstNode::BinaryOp {
                         op: BinaryOperator::Multiply,
                         left: Box::new(AstNode::Identifier("x".to_string())),
@@ -458,10 +475,12 @@ fn test_match_no_match_error() {
         arms: vec![
             MatchArm {
                 pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                guard: None,
                 body: vec![AstNode::IntegerLiteral(10)],
             },
             MatchArm {
                 pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                guard: None,
                 body: vec![AstNode::IntegerLiteral(20)],
             },
         ],
@@ -489,10 +508,12 @@ fn test_match_with_boolean() {
             arms: vec![
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::BooleanLiteral(true)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(1)],
                 },
                 MatchArm {
                     pattern: Pattern::Literal(AstNode::BooleanLiteral(false)),
+                    guard: None,
                     body: vec![AstNode::IntegerLiteral(0)],
                 },
             ],
@@ -523,13 +544,17 @@ fn test_while_loop_in_function() {
     eval.eval(&AstNode::FunctionDef {
         name: "sum_to".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![
             AstNode::LetDecl {
                 name: "sum".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::LetDecl {
                 name: "i".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::WhileLoop {
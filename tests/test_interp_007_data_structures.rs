@@ -67,6 +67,7 @@ fn test_vector_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::IntegerLiteral(10),
@@ -79,6 +80,7 @@ fn test_vector_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "x".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IndexAccess {
             expr: Box::new(AstNode::Identifier("vec".to_string())),
             index: Box::new(AstNode::IntegerLiteral(0)),
@@ -88,6 +90,7 @@ fn test_vector_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "y".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IndexAccess {
             expr: Box::new(AstNode::Identifier("vec".to_string())),
             index: Box::new(AstNode::IntegerLiteral(1)),
@@ -97,6 +100,7 @@ fn test_vector_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "z".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IndexAccess {
             expr: Box::new(AstNode::Identifier("vec".to_string())),
             index: Box::new(AstNode::IntegerLiteral(2)),
@@ -121,6 +125,7 @@ fn test_vector_index_out_of_bounds() {
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::IntegerLiteral(1),
@@ -149,6 +154,7 @@ fn test_vector_nested_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "matrix".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::VectorLiteral {
@@ -184,6 +190,7 @@ fn test_vector_index_with_expression() {
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::IntegerLiteral(10),
@@ -197,6 +204,7 @@ fn test_vector_index_with_expression() {
 
     eval.eval(&AstNode::LetDecl {
         name: "i".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(1)),
     })
     .unwrap();
@@ -223,6 +231,7 @@ fn test_vector_empty() {
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral { elements: vec![] }),
     })
     .unwrap();
@@ -242,18 +251,21 @@ fn test_vector_mixed_expressions() {
 
     eval.eval(&AstNode::LetDecl {
         name: "a".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(5)),
     })
     .unwrap();
 
     eval.eval(&AstNode::LetDecl {
         name: "b".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(10)),
     })
     .unwrap();
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::Identifier("a".to_string()),
@@ -292,6 +304,7 @@ fn test_hashmap_literal_creation() {
 
     eval.eval(&AstNode::LetDecl {
         name: "map".to_string(),
+        mutable: false,
         value: Box::new(AstNode::HashMapLiteral {
             pairs: vec![
                 (
@@ -321,6 +334,7 @@ fn test_hashmap_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "map".to_string(),
+        mutable: false,
         value: Box::new(AstNode::HashMapLiteral {
             pairs: vec![
                 (
@@ -338,6 +352,7 @@ fn test_hashmap_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "a".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IndexAccess {
             expr: Box::new(AstNode::Identifier("map".to_string())),
             index: Box::new(AstNode::StringLiteral("x".to_string())),
@@ -347,6 +362,7 @@ fn test_hashmap_index_access() {
 
     eval.eval(&AstNode::LetDecl {
         name: "b".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IndexAccess {
             expr: Box::new(AstNode::Identifier("map".to_string())),
             index: Box::new(AstNode::StringLiteral("y".to_string())),
@@ -369,6 +385,7 @@ fn test_hashmap_key_not_found() {
 
     eval.eval(&AstNode::LetDecl {
         name: "map".to_string(),
+        mutable: false,
         value: Box::new(AstNode::HashMapLiteral {
             pairs: vec![(
                 AstNode::StringLiteral("a".to_string()),
@@ -396,6 +413,7 @@ fn test_hashmap_empty() {
 
     eval.eval(&AstNode::LetDecl {
         name: "map".to_string(),
+        mutable: false,
         value: Box::new(AstNode::HashMapLiteral { pairs: vec![] }),
     })
     .unwrap();
@@ -411,6 +429,7 @@ fn test_hashmap_mixed_value_types() {
 
     eval.eval(&AstNode::LetDecl {
         name: "map".to_string(),
+        mutable: false,
         value: Box::new(AstNode::HashMapLiteral {
             pairs: vec![
                 (
@@ -464,18 +483,21 @@ fn test_hashmap_with_expression_values() {
 
     eval.eval(&AstNode::LetDecl {
         name: "x".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(10)),
     })
     .unwrap();
 
     eval.eval(&AstNode::LetDecl {
         name: "y".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(20)),
     })
     .unwrap();
 
     eval.eval(&AstNode::LetDecl {
         name: "map".to_string(),
+        mutable: false,
         value: Box::new(AstNode::HashMapLiteral {
             pairs: vec![
                 (
@@ -528,6 +550,7 @@ fn test_index_on_non_indexable() {
 
     eval.eval(&AstNode::LetDecl {
         name: "x".to_string(),
+        mutable: false,
         value: Box::new(AstNode::IntegerLiteral(42)),
     })
     .unwrap();
@@ -549,6 +572,7 @@ fn test_index_with_non_integer() {
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::IntegerLiteral(1),
@@ -561,6 +585,7 @@ fn test_index_with_non_integer() {
 
     eval.eval(&AstNode::LetDecl {
         name: "i".to_string(),
+        mutable: false,
         value: Box::new(AstNode::StringLiteral("hello".to_string())),
     })
     .unwrap();
@@ -581,6 +606,7 @@ fn test_index_with_negative() {
 
     eval.eval(&AstNode::LetDecl {
         name: "vec".to_string(),
+        mutable: false,
         value: Box::new(AstNode::VectorLiteral {
             elements: vec![
                 AstNode::IntegerLiteral(1),
@@ -78,6 +78,7 @@ fn test_read_file_success() {
 
     let result = eval.eval(&AstNode::LetDecl {
         name: "content".to_string(),
+        mutable: false,
         value: Box::new(AstNode::FunctionCall {
             name: "read_file".to_string(),
             args: vec![AstNode::StringLiteral(path.to_str().unwrap().to_string())],
@@ -110,6 +111,7 @@ fn test_read_file_multiline() {
 
     eval.eval(&AstNode::LetDecl {
         name: "content".to_string(),
+        mutable: false,
         value: Box::new(AstNode::FunctionCall {
             name: "read_file".to_string(),
             args: vec![AstNode::StringLiteral(path.to_str().unwrap().to_string())],
@@ -220,6 +222,7 @@ fn test_println_with_variable() {
 
     eval.eval(&AstNode::LetDecl {
         name: "msg".to_string(),
+        mutable: false,
         value: Box::new(AstNode::StringLiteral("Test message".to_string())),
     })
     .unwrap();
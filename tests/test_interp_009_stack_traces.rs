@@ -60,6 +60,8 @@ fn test_stack_trace_in_nested_function() {
     eval.eval(&AstNode::FunctionDef {
         name: "outer".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::FunctionCall {
             name: "inner".to_string(),
             args: vec![],
@@ -71,8 +73,11 @@ fn test_stack_trace_in_nested_function() {
     eval.eval(&AstNode::FunctionDef {
         name: "inner".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::LetDecl {
             name: "x".to_string(),
+            mutable: false,
             value: Box::new(AstNode::Identifier("undefined_var".to_string())),
         }],
     })
@@ -115,8 +120,11 @@ fn test_stack_trace_depth() {
     eval.eval(&AstNode::FunctionDef {
         name: "level3".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::LetDecl {
             name: "x".to_string(),
+            mutable: false,
             value: Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::Divide,
                 left: Box::new(AstNode::IntegerLiteral(1)),
@@ -129,6 +137,8 @@ fn test_stack_trace_depth() {
     eval.eval(&AstNode::FunctionDef {
         name: "level2".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::FunctionCall {
             name: "level3".to_string(),
             args: vec![],
@@ -139,6 +149,8 @@ fn test_stack_trace_depth() {
     eval.eval(&AstNode::FunctionDef {
         name: "level1".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::FunctionCall {
             name: "level2".to_string(),
             args: vec![],
@@ -178,6 +190,8 @@ fn test_stack_trace_with_recursion() {
     eval.eval(&AstNode::FunctionDef {
         name: "factorial".to_string(),
         params: vec!["n".to_string()],
+        param_types: vec![None],
+        return_type: None,
         body: vec![AstNode::IfExpr {
             condition: Box::new(AstNode::BinaryOp {
                 op: BinaryOperator::LessThan,
@@ -186,6 +200,7 @@ fn test_stack_trace_with_recursion() {
             }),
             then_branch: vec![AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::Identifier("undefined".to_string())),
             }],
             else_branch: None,
@@ -229,6 +244,8 @@ fn test_stack_trace_includes_function_names() {
     eval.eval(&AstNode::FunctionDef {
         name: "caller".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::FunctionCall {
             name: "callee".to_string(),
             args: vec![],
@@ -239,6 +256,8 @@ fn test_stack_trace_includes_function_names() {
     eval.eval(&AstNode::FunctionDef {
         name: "callee".to_string(),
         params: vec![],
+        param_types: vec![],
+        return_type: None,
         body: vec![AstNode::BinaryOp {
             op: BinaryOperator::Divide,
             left: Box::new(AstNode::IntegerLiteral(10)),
@@ -273,6 +292,7 @@ fn test_top_level_error_minimal_stack() {
 
     let result = eval.eval(&AstNode::LetDecl {
         name: "x".to_string(),
+        mutable: false,
         value: Box::new(AstNode::BinaryOp {
             op: BinaryOperator::Divide,
             left: Box::new(AstNode::IntegerLiteral(1)),
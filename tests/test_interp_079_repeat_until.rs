@@ -0,0 +1,92 @@
+// INTERP-079: Repeat-Until Loops
+//
+// Mission: Support post-tested loops for teaching loop variants
+// - Syntax: repeat { body } until condition
+// - Body executes at least once before the condition is checked
+// - Loop exits as soon as the condition becomes true
+//
+// Test Coverage:
+// - test_repeat_until_runs_once_when_condition_true: body runs exactly once
+// - test_repeat_until_counts_to_limit: loops while condition is false
+// - test_repeat_until_return_propagates: early return exits enclosing function
+// - test_repeat_until_non_boolean_condition: type error for non-bool condition
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program, returning the last value
+fn run(source: &str) -> Result<Value, String> {
+    let mut parser = Parser::new(source);
+    let ast = parser
+        .parse()
+        .map_err(|e| format!("Parse error: {:?}", e))?;
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+        .map_err(|e| format!("Eval error: {:?}", e))
+}
+
+/// Test: Body runs exactly once when the condition is immediately satisfied
+#[test]
+fn test_repeat_until_runs_once_when_condition_true() {
+    let source = r#"
+        let mut count = 0;
+        repeat {
+            count = count + 1;
+        } until true
+        count
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Integer(1));
+}
+
+/// Test: Loop continues while the until-condition is false
+#[test]
+fn test_repeat_until_counts_to_limit() {
+    let source = r#"
+        let mut i = 0;
+        repeat {
+            i += 1;
+        } until i >= 5
+        i
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Integer(5));
+}
+
+/// Test: Early return inside the body propagates out of the function
+#[test]
+fn test_repeat_until_return_propagates() {
+    let source = r#"
+        fun first_over(limit) {
+            let mut n = 1;
+            repeat {
+                if n > limit {
+                    return n;
+                }
+                n = n * 2;
+            } until false
+            0
+        }
+        first_over(10)
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Integer(16));
+}
+
+/// Test: Non-boolean condition is a type error
+#[test]
+fn test_repeat_until_non_boolean_condition() {
+    let source = r#"
+        repeat {
+            let x = 1;
+        } until 42
+    "#;
+
+    let result = run(source);
+    assert!(
+        result.is_err(),
+        "Integer condition should fail: {:?}",
+        result
+    );
+}
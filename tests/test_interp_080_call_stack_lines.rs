@@ -0,0 +1,108 @@
+// INTERP-080: Call-Stack Traces with Source Lines
+//
+// Mission: Point each stack frame at the line it was called from
+// - Tokens record their 1-based line/column
+// - Parser::with_spans() wraps statements in AstNode::Spanned
+// - Evaluator records the call-site line when pushing a frame
+// - Display renders frames as "at inner (line 6)"
+//
+// Test Coverage:
+// - test_trace_includes_call_site_lines: two-level chain shows both lines
+// - test_call_sites_parallel_to_call_stack: structured access to lines
+// - test_trace_without_spans_has_no_lines: default parser keeps old shape
+// - test_with_spans_records_statement_position: line and column of statements
+// - test_spans_do_not_change_results: spanned programs evaluate identically
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::{AstNode, Parser, Span};
+use ruchyruchy::interpreter::value::Value;
+
+/// Two-level call chain: main calls outer (line 9), outer calls inner (line 6)
+const FAILING_PROGRAM: &str = r#"
+fun inner(x) {
+    return x / 0;
+}
+fun outer(x) {
+    let y = inner(x);
+    return y;
+}
+outer(1)
+"#;
+
+/// Helper: parse with spans and evaluate, returning the evaluation error
+fn run_to_error(source: &str) -> EvalError {
+    let ast = Parser::new(source).with_spans().parse().unwrap();
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).unwrap_err()
+}
+
+/// Test: Rendered trace names each frame with its call-site line
+#[test]
+fn test_trace_includes_call_site_lines() {
+    let trace = run_to_error(FAILING_PROGRAM).to_string();
+
+    assert!(trace.contains("1. at inner (line 6)"), "trace: {}", trace);
+    assert!(trace.contains("2. at outer (line 9)"), "trace: {}", trace);
+}
+
+/// Test: call_sites lines up with call_stack (outermost first)
+#[test]
+fn test_call_sites_parallel_to_call_stack() {
+    match run_to_error(FAILING_PROGRAM) {
+        EvalError::WithCallStack {
+            call_stack,
+            call_sites,
+            ..
+        } => {
+            assert_eq!(call_stack, vec!["outer".to_string(), "inner".to_string()]);
+            assert_eq!(call_sites, vec![Some(9), Some(6)]);
+        }
+        other => panic!("Expected WithCallStack, got: {:?}", other),
+    }
+}
+
+/// Test: Without spans the trace still lists frames, just without lines
+#[test]
+fn test_trace_without_spans_has_no_lines() {
+    let ast = Parser::new(FAILING_PROGRAM).parse().unwrap();
+    let mut eval = Evaluator::new();
+    let trace = eval.eval_program(&ast).unwrap_err().to_string();
+
+    assert!(trace.contains("1. at inner\n"), "trace: {}", trace);
+    assert!(!trace.contains("(line"), "trace: {}", trace);
+}
+
+/// Test: with_spans() records where each statement starts
+#[test]
+fn test_with_spans_records_statement_position() {
+    let ast = Parser::new("let a = 1;\n  let b = 2;")
+        .with_spans()
+        .parse()
+        .unwrap();
+
+    match &ast.nodes()[1] {
+        AstNode::Spanned { span, node } => {
+            assert_eq!(*span, Span { line: 2, column: 3 });
+            assert!(matches!(**node, AstNode::LetDecl { .. }));
+        }
+        other => panic!("Expected Spanned statement, got: {:?}", other),
+    }
+}
+
+/// Test: Span annotations are transparent to evaluation
+#[test]
+fn test_spans_do_not_change_results() {
+    let source = r#"
+        fun add(a, b) {
+            if a > b {
+                return a + b;
+            }
+            a * b
+        }
+        add(2, 3) + add(5, 1)
+    "#;
+    let ast = Parser::new(source).with_spans().parse().unwrap();
+    let mut eval = Evaluator::new();
+
+    assert_eq!(eval.eval_program(&ast).unwrap(), Value::Integer(12));
+}
@@ -0,0 +1,84 @@
+// INTERP-081: Timing Builtins (time_now, bench)
+//
+// Mission: Let learners measure their own Ruchy code from inside the language
+// - time_now() returns monotonic nanoseconds
+// - bench(closure, n) runs the closure n times and returns the mean duration
+// - bench with n <= 0 is an error
+// - Closures may have a bare expression body: || 1 + 1
+//
+// Test Coverage:
+// - test_time_now_is_monotonic: later readings are never smaller
+// - test_bench_returns_non_negative_mean: bench(|| 1 + 1, 100) >= 0
+// - test_bench_rejects_non_positive_count: n = 0 and n = -1 error
+// - test_bench_requires_closure: non-closure first argument errors
+// - test_expression_bodied_closure: |x| x * 2 without braces
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program, returning the last value
+fn run(source: &str) -> Result<Value, String> {
+    let mut parser = Parser::new(source);
+    let ast = parser
+        .parse()
+        .map_err(|e| format!("Parse error: {:?}", e))?;
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+        .map_err(|e| format!("Eval error: {:?}", e))
+}
+
+/// Test: time_now() never goes backwards
+#[test]
+fn test_time_now_is_monotonic() {
+    let source = r#"
+        let start = time_now();
+        let end = time_now();
+        (end >= start) && (start >= 0)
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Boolean(true));
+}
+
+/// Test: bench returns a non-negative mean duration
+#[test]
+fn test_bench_returns_non_negative_mean() {
+    let result = run("bench(|| 1 + 1, 100)").unwrap();
+
+    match result {
+        Value::Integer(nanos) => assert!(nanos >= 0, "mean was {}", nanos),
+        other => panic!("Expected integer duration, got: {:?}", other),
+    }
+}
+
+/// Test: bench with a non-positive iteration count errors
+#[test]
+fn test_bench_rejects_non_positive_count() {
+    let zero = run("bench(|| 1, 0)").unwrap_err();
+    assert!(
+        zero.contains("iteration count must be positive"),
+        "{}",
+        zero
+    );
+
+    assert!(run("bench(|| 1, -1)").is_err());
+}
+
+/// Test: bench needs a closure to run
+#[test]
+fn test_bench_requires_closure() {
+    let err = run("bench(42, 10)").unwrap_err();
+
+    assert!(err.contains("Closure"), "{}", err);
+}
+
+/// Test: Closures without braces evaluate their single expression
+#[test]
+fn test_expression_bodied_closure() {
+    let source = r#"
+        let double = |x| x * 2;
+        double(21)
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Integer(42));
+}
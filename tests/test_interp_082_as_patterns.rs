@@ -0,0 +1,116 @@
+// INTERP-082: As-Patterns and Range Patterns in match
+//
+// Mission: Bind the whole matched value while checking a sub-pattern
+// - Range patterns: 1..5 (exclusive), 1..=5 (inclusive)
+// - As-patterns: x @ 1..=5 binds x only when the range matches
+// - JIT compiles range and as-patterns to integer comparisons
+//
+// Test Coverage:
+// - test_as_pattern_binds_matched_value: x @ 1..=5 on 3 returns 3
+// - test_as_pattern_falls_through_on_mismatch: 9 skips the arm
+// - test_range_pattern_inclusive_and_exclusive: boundary behavior
+// - test_range_pattern_ignores_non_integers: strings never match ranges
+// - test_jit_as_pattern: JIT-compiled match agrees with the interpreter
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::interpreter::value::Value;
+use ruchyruchy::jit::JitCompiler;
+
+/// Helper: parse and evaluate a program, returning the last value
+fn run(source: &str) -> Result<Value, String> {
+    let mut parser = Parser::new(source);
+    let ast = parser
+        .parse()
+        .map_err(|e| format!("Parse error: {:?}", e))?;
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+        .map_err(|e| format!("Eval error: {:?}", e))
+}
+
+/// Classifier used by several tests
+const CLASSIFY: &str = r#"
+    fun classify(n) {
+        match n {
+            x @ 1..=5 => x,
+            y @ 10..20 => y * 10,
+            _ => 0
+        }
+    }
+"#;
+
+/// Test: x @ 1..=5 matching 3 binds x to 3 and the arm returns 3
+#[test]
+fn test_as_pattern_binds_matched_value() {
+    let source = r#"
+        let n = 3;
+        match n {
+            x @ 1..=5 => x,
+            _ => 0
+        }
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Integer(3));
+}
+
+/// Test: When the sub-pattern fails, the next arm is tried
+#[test]
+fn test_as_pattern_falls_through_on_mismatch() {
+    let source = format!("{}\nclassify(9)", CLASSIFY);
+
+    assert_eq!(run(&source).unwrap(), Value::Integer(0));
+}
+
+/// Test: ..= includes the end, .. excludes it
+#[test]
+fn test_range_pattern_inclusive_and_exclusive() {
+    let source = format!(
+        "{}\n[classify(1), classify(5), classify(10), classify(19), classify(20)]",
+        CLASSIFY
+    );
+
+    assert_eq!(
+        run(&source).unwrap(),
+        Value::Vector(vec![
+            Value::Integer(1),
+            Value::Integer(5),
+            Value::Integer(100),
+            Value::Integer(190),
+            Value::Integer(0),
+        ])
+    );
+}
+
+/// Test: Range patterns do not match non-integer values
+#[test]
+fn test_range_pattern_ignores_non_integers() {
+    let source = r#"
+        match "three" {
+            1..=5 => "small",
+            _ => "other"
+        }
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::String("other".to_string()));
+}
+
+/// Test: The JIT compiles as-patterns and range patterns
+#[test]
+fn test_jit_as_pattern() {
+    let ast = Parser::new(CLASSIFY).parse().unwrap();
+    let (params, body) = match &ast.nodes()[0] {
+        AstNode::FunctionDef { params, body, .. } => (params.clone(), body.clone()),
+        other => panic!("Expected FunctionDef, got: {:?}", other),
+    };
+    let body = AstNode::Block { statements: body };
+
+    let mut jit = JitCompiler::new().expect("Failed to create JIT compiler");
+    let classify: fn(i64) -> i64 = jit
+        .compile_function_with_params(&params, &body)
+        .expect("Should compile as-patterns");
+
+    assert_eq!(classify(3), 3);
+    assert_eq!(classify(9), 0);
+    assert_eq!(classify(20), 0);
+    assert_eq!(classify(15), 150);
+}
@@ -0,0 +1,71 @@
+// INTERP-083: Builtin Shadowing Warnings
+//
+// Mission: Explain why a user function named like a builtin is never called
+// - Builtins are dispatched before user-defined functions
+// - Defining such a function records EvalWarning::BuiltinShadowed
+// - The warning message says the builtin takes precedence
+//
+// Test Coverage:
+// - test_shadowing_builtin_records_warning: fun println(...) warns
+// - test_builtin_still_takes_precedence: the builtin runs, not the user fn
+// - test_regular_function_has_no_warning: unrelated names stay silent
+// - test_warning_message_explains_precedence: Display wording
+
+use ruchyruchy::interpreter::evaluator::{EvalWarning, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program, returning the evaluator and last value
+fn run(source: &str) -> (Evaluator, Value) {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    let value = eval.eval_program(&ast).expect("eval");
+    (eval, value)
+}
+
+/// Test: Defining a function named like a builtin records a warning
+#[test]
+fn test_shadowing_builtin_records_warning() {
+    let (eval, _) = run("fun println(x) { x }");
+
+    assert_eq!(
+        eval.warnings(),
+        &[EvalWarning::BuiltinShadowed {
+            name: "println".to_string()
+        }]
+    );
+}
+
+/// Test: Calls still go to the builtin (user function is ignored)
+#[test]
+fn test_builtin_still_takes_precedence() {
+    let source = r#"
+        fun time_now() { -1 }
+        time_now()
+    "#;
+    let (eval, value) = run(source);
+
+    assert_eq!(eval.warnings().len(), 1);
+    assert!(matches!(value, Value::Integer(n) if n >= 0));
+}
+
+/// Test: Ordinary function names produce no warnings
+#[test]
+fn test_regular_function_has_no_warning() {
+    let (eval, _) = run("fun double(x) { x * 2 }\ndouble(4)");
+
+    assert!(eval.warnings().is_empty());
+}
+
+/// Test: Warning text tells the learner which definition wins
+#[test]
+fn test_warning_message_explains_precedence() {
+    let warning = EvalWarning::BuiltinShadowed {
+        name: "assert".to_string(),
+    };
+
+    assert_eq!(
+        warning.to_string(),
+        "function 'assert' has the same name as a builtin; calls to 'assert' use the builtin"
+    );
+}
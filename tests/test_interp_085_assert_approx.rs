@@ -0,0 +1,85 @@
+// INTERP-085: assert_approx Builtin
+//
+// Mission: Compare floats with a tolerance instead of exact equality
+// - assert_approx(a, b, epsilon) passes when |a - b| <= epsilon
+// - Failure message shows both values and the actual difference
+// - Integers are accepted and compared as floats
+// - Non-numeric arguments are a type error
+// - A NaN or negative epsilon is an error, not a silent pass
+//
+// Test Coverage:
+// - test_assert_approx_passes_within_epsilon: 0.1 + 0.2 vs 0.3
+// - test_assert_approx_fails_outside_epsilon: message includes difference
+// - test_assert_approx_accepts_integers: mixed Integer/Float arguments
+// - test_assert_approx_rejects_non_numeric: strings are rejected
+// - test_assert_approx_rejects_invalid_epsilon: NaN and negative epsilon
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program, returning the last value
+fn run(source: &str) -> Result<Value, String> {
+    let mut parser = Parser::new(source);
+    let ast = parser
+        .parse()
+        .map_err(|e| format!("Parse error: {:?}", e))?;
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).map_err(|e| format!("{}", e))
+}
+
+/// Test: Values within epsilon pass even when not exactly equal
+#[test]
+fn test_assert_approx_passes_within_epsilon() {
+    let source = r#"
+        let sum = 0.1 + 0.2;
+        assert_approx(sum, 0.3, 0.000001);
+        sum == 0.3
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Boolean(false));
+}
+
+/// Test: Failure reports both values and the actual difference
+#[test]
+fn test_assert_approx_fails_outside_epsilon() {
+    let err = run("assert_approx(1.0, 1.5, 0.1)").unwrap_err();
+
+    assert!(err.contains("assertion failed"), "{}", err);
+    assert!(err.contains("assert_approx(1, 1.5, 0.1)"), "{}", err);
+    assert!(err.contains("difference 0.5"), "{}", err);
+}
+
+/// Test: Integer arguments are compared as floats
+#[test]
+fn test_assert_approx_accepts_integers() {
+    assert!(run("assert_approx(2, 2.0000001, 0.001)").is_ok());
+}
+
+/// Test: Non-numeric arguments are rejected
+#[test]
+fn test_assert_approx_rejects_non_numeric() {
+    let err = run(r#"assert_approx("1.0", 1.0, 0.1)"#).unwrap_err();
+
+    assert!(err.contains("Integer or Float"), "{}", err);
+}
+
+/// Test: A NaN or negative epsilon is rejected instead of passing everything
+#[test]
+fn test_assert_approx_rejects_invalid_epsilon() {
+    let err = run("assert_approx(1.0, 5.0, 0.0 / 0.0)").unwrap_err();
+    assert!(
+        err.contains("epsilon must be a non-negative number"),
+        "{}",
+        err
+    );
+
+    let err = run("assert_approx(1.0, 1.0, 0.0 - 0.1)").unwrap_err();
+    assert!(
+        err.contains("epsilon must be a non-negative number"),
+        "{}",
+        err
+    );
+
+    assert!(run("assert_approx(1.0, 1.0, 0.0)").is_ok());
+}
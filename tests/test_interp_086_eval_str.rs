@@ -0,0 +1,101 @@
+// INTERP-086: eval_str Builtin (Runtime Evaluation)
+//
+// Mission: Parse and evaluate source strings at runtime for metaprogramming demos
+// - eval_str(source) returns the value of the last statement
+// - Runs in a child of the current scope: outer variables are visible
+// - Inner let bindings do not leak into the caller
+// - Nested eval_str calls count toward the call-depth limit
+// - Parse and evaluation errors propagate as EvalError
+//
+// Test Coverage:
+// - test_eval_str_simple_expression: eval_str("1 + 2") == 3
+// - test_eval_str_sees_outer_scope: reads and assigns outer variables
+// - test_eval_str_bindings_stay_local: inner let does not leak
+// - test_eval_str_counts_toward_call_depth: recursion via eval_str overflows sooner
+// - test_eval_str_propagates_errors: parse and runtime errors surface
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: strip call-stack wrapping to get the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Test: A simple expression string evaluates to its value
+#[test]
+fn test_eval_str_simple_expression() {
+    assert_eq!(run(r#"eval_str("1 + 2")"#).unwrap(), Value::Integer(3));
+}
+
+/// Test: Evaluated code reads and updates the caller's variables
+#[test]
+fn test_eval_str_sees_outer_scope() {
+    let source = r#"
+        let mut x = 40;
+        eval_str("x = x + 1");
+        eval_str("x + 1")
+    "#;
+
+    assert_eq!(run(source).unwrap(), Value::Integer(42));
+}
+
+/// Test: let bindings inside eval_str stay inside it
+#[test]
+fn test_eval_str_bindings_stay_local() {
+    let source = r#"
+        eval_str("let hidden = 1");
+        eval_str("let hidden = 2");
+        hidden
+    "#;
+
+    let err = root_error(run(source).unwrap_err());
+    assert!(matches!(err, EvalError::UndefinedVariable { ref name } if name == "hidden"));
+}
+
+/// Test: Each eval_str level consumes call depth
+#[test]
+fn test_eval_str_counts_toward_call_depth() {
+    let program = |n: i64| {
+        format!(
+            r#"
+            fun down(n) {{
+                if n == 0 {{ 0 }} else {{ eval_str("down(n - 1)") }}
+            }}
+            down({})
+            "#,
+            n
+        )
+    };
+
+    // 10 calls + 10 eval_str levels fit under the limit
+    assert_eq!(run(&program(10)).unwrap(), Value::Integer(0));
+    // 20 calls alone would fit, but eval_str doubles the depth
+    let err = root_error(run(&program(20)).unwrap_err());
+    assert!(matches!(err, EvalError::StackOverflow), "{:?}", err);
+}
+
+/// Test: Parse errors and runtime errors propagate to the caller
+#[test]
+fn test_eval_str_propagates_errors() {
+    let parse_err = run(r#"eval_str("let = ")"#).unwrap_err();
+    assert!(
+        parse_err.to_string().contains("parse error"),
+        "{}",
+        parse_err
+    );
+
+    let runtime_err = root_error(run(r#"eval_str("missing + 1")"#).unwrap_err());
+    assert!(matches!(runtime_err, EvalError::UndefinedVariable { .. }));
+}
@@ -0,0 +1,113 @@
+// INTERP-088: Enums with Explicit Discriminants and Integer Casts
+//
+// Mission: C-style enums that teach the enum/integer relationship
+// - enum Status { Ok = 0, Err = 1 } binds Status::Ok and Status::Err
+// - `Status::Err as i64` yields the discriminant via eval_type_cast
+// - Variants match by path pattern and by their integer value
+// - Implicit discriminants auto-increment from the previous variant (like C)
+// - Duplicate or non-integer discriminants error at definition
+//
+// Test Coverage:
+// - test_enum_variant_cast_to_integer: Status::Err as i64 == 1
+// - test_enum_implicit_discriminants_auto_increment: mixed explicit/implicit values
+// - test_match_enum_variant_by_path: match on Status::Err arm
+// - test_match_enum_variant_by_integer: discriminated variant matches its integer
+// - test_enum_invalid_discriminants_error: duplicate and non-integer values rejected
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: Casting a variant to an integer yields its discriminant
+#[test]
+fn test_enum_variant_cast_to_integer() {
+    let source = r#"
+enum Status { Ok = 0, Err = 1 }
+Status::Err as i64
+"#;
+    assert_eq!(run(source).unwrap(), Value::Integer(1));
+}
+
+/// Test: Variants without a value continue from the previous discriminant
+#[test]
+fn test_enum_implicit_discriminants_auto_increment() {
+    let source = r#"
+enum Code { First, Second, Tenth = 10, Eleventh, Negative = -5, AfterNegative }
+let mut codes = vec![];
+codes.push(Code::First as i64);
+codes.push(Code::Second as i64);
+codes.push(Code::Tenth as i64);
+codes.push(Code::Eleventh as i64);
+codes.push(Code::AfterNegative as i64);
+codes
+"#;
+    assert_eq!(
+        run(source).unwrap(),
+        Value::Vector(vec![
+            Value::Integer(0),
+            Value::Integer(1),
+            Value::Integer(10),
+            Value::Integer(11),
+            Value::Integer(-4),
+        ])
+    );
+}
+
+/// Test: Variants match path patterns naming the same variant
+#[test]
+fn test_match_enum_variant_by_path() {
+    let source = r#"
+enum Status { Ok = 0, Err = 1 }
+fun describe(s) {
+    match s {
+        Status::Ok => "ok",
+        Status::Err => "err",
+        _ => "unknown"
+    }
+}
+describe(Status::Err)
+"#;
+    assert_eq!(run(source).unwrap(), Value::String("err".to_string()));
+}
+
+/// Test: A discriminated variant matches its integer value
+#[test]
+fn test_match_enum_variant_by_integer() {
+    let source = r#"
+enum Level { Low = 1, High = 5 }
+let level = Level::High;
+match level {
+    1 => "low",
+    5 => "high",
+    _ => "other"
+}
+"#;
+    assert_eq!(run(source).unwrap(), Value::String("high".to_string()));
+}
+
+/// Test: Duplicate and non-integer discriminants error at definition
+#[test]
+fn test_enum_invalid_discriminants_error() {
+    let duplicate = run("enum Dup { A = 1, B = 0, C }");
+    match duplicate {
+        Err(EvalError::ValueError(ValueError::InvalidOperation { message, .. })) => {
+            assert!(message.contains("already used by Dup::A"), "{}", message);
+        }
+        other => panic!("expected duplicate discriminant error, got {:?}", other),
+    }
+
+    let non_integer = run(r#"enum Bad { A = "one" }"#);
+    match non_integer {
+        Err(EvalError::ValueError(ValueError::InvalidOperation { message, .. })) => {
+            assert!(message.contains("must be an integer"), "{}", message);
+        }
+        other => panic!("expected non-integer discriminant error, got {:?}", other),
+    }
+}
@@ -0,0 +1,126 @@
+// INTERP-089: Struct Display via User-Defined to_string
+//
+// Mission: Teach custom formatting with impl blocks
+// - impl Point { fun to_string(self) { ... } } registers Point::to_string
+// - println(point) prints the result of point.to_string() when defined
+// - Structs without to_string keep the default map rendering
+// - Methods from impl blocks are callable with receiver.method(args)
+// - Structs keep supporting map-style key access and map methods
+//
+// Test Coverage:
+// - test_println_uses_user_to_string: custom to_string changes printed output
+// - test_println_without_to_string_uses_default: default rendering is kept
+// - test_impl_methods_callable_on_receiver: self plus extra arguments
+// - test_println_vector_of_structs_uses_to_string: nested structs dispatch too
+// - test_to_string_must_return_string: non-string result is a type error
+// - test_struct_key_access_and_map_methods: p["x"], p.len() and friends still work
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+use ruchyruchy::interpreter::OutputSink;
+
+/// Helper: parse and evaluate a program, returning its captured output
+fn run_output(source: &str) -> Result<String, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    eval.eval_program(&ast)?;
+    Ok(sink.contents().unwrap_or_default())
+}
+
+const POINT: &str = r#"
+struct Point { x: i64, y: i64 }
+impl Point {
+    fun to_string(self) -> String {
+        f"({self.x}, {self.y})"
+    }
+    fun scaled(self, factor) {
+        Point { x: self.x * factor, y: self.y * factor }
+    }
+}
+"#;
+
+/// Test: Defining to_string changes how println renders a struct
+#[test]
+fn test_println_uses_user_to_string() {
+    let source = format!("{}\nlet p = Point {{ x: 1, y: 2 }};\nprintln(p)", POINT);
+    assert_eq!(run_output(&source).unwrap(), "(1, 2)\n");
+}
+
+/// Test: A struct without to_string prints with the default rendering
+#[test]
+fn test_println_without_to_string_uses_default() {
+    let source = r#"
+struct Meters { value: i64 }
+let m = Meters { value: 5 };
+println(m)
+"#;
+    assert_eq!(run_output(source).unwrap(), "{\"value\": 5}\n");
+}
+
+/// Test: Impl methods receive the struct as self plus any arguments
+#[test]
+fn test_impl_methods_callable_on_receiver() {
+    let source = format!(
+        "{}\nlet p = Point {{ x: 1, y: 2 }};\nlet q = p.scaled(3);\nprintln(q.to_string());\nq.y",
+        POINT
+    );
+    let ast = Parser::new(&source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    assert_eq!(eval.eval_program(&ast).unwrap(), Value::Integer(6));
+    assert_eq!(sink.contents().unwrap(), "(3, 6)\n");
+}
+
+/// Test: Structs nested in vectors are printed via their to_string
+#[test]
+fn test_println_vector_of_structs_uses_to_string() {
+    let source = format!(
+        "{}\nlet points = vec![Point {{ x: 0, y: 0 }}, Point {{ x: 4, y: 5 }}];\nprintln(points)",
+        POINT
+    );
+    assert_eq!(run_output(&source).unwrap(), "[(0, 0), (4, 5)]\n");
+}
+
+/// Test: A to_string that does not return a string is a type error
+#[test]
+fn test_to_string_must_return_string() {
+    let source = r#"
+struct Id { n: i64 }
+impl Id {
+    fun to_string(self) {
+        self.n
+    }
+}
+println(Id { n: 7 })
+"#;
+    assert!(matches!(run_output(source), Err(EvalError::ValueError(_))));
+}
+
+/// Test: Struct values accept the key access and map methods structs had as maps
+#[test]
+fn test_struct_key_access_and_map_methods() {
+    let run = |source: &str| {
+        let ast = Parser::new(source).parse().expect("parse");
+        Evaluator::new().eval_program(&ast)
+    };
+    let prelude = "struct P { x: i64, y: i64 }\nlet p = P { x: 3, y: 4 };\n";
+
+    assert_eq!(
+        run(&format!("{}p[\"x\"]", prelude)).unwrap(),
+        Value::Integer(3)
+    );
+    assert_eq!(
+        run(&format!("{}p.len()", prelude)).unwrap(),
+        Value::Integer(2)
+    );
+    assert_eq!(
+        run(&format!("{}p.contains_key(\"y\")", prelude)).unwrap(),
+        Value::Boolean(true)
+    );
+    assert_eq!(
+        run(&format!("{}p[\"y\"] = 9;\np.y", prelude)).unwrap(),
+        Value::Integer(9)
+    );
+}
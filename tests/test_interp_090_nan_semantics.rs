@@ -0,0 +1,129 @@
+// INTERP-090: NaN and Infinity Semantics
+//
+// Mission: Documented IEEE 754 behavior for float edge cases
+// - 0.0 / 0.0 is NaN, 1.0 / 0.0 is Infinity (integer / 0 still errors)
+// - NaN comparisons are false, except != which is true
+// - NaN never matches a float literal pattern
+// - sort() orders NaN after every number instead of panicking
+//
+// Test Coverage:
+// - test_float_division_by_zero_follows_ieee: Infinity and NaN values
+// - test_nan_equality: NaN == NaN is false, NaN != NaN is true
+// - test_nan_ordering_comparisons_are_false: <, >, <=, >= with NaN
+// - test_sort_vector_with_nan: NaN sorts last, numbers ascending
+// - test_match_float_literal_with_nan: NaN falls through to the wildcard arm
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: Float division by zero yields Infinity or NaN instead of an error
+#[test]
+fn test_float_division_by_zero_follows_ieee() {
+    match run("1.0 / 0.0").unwrap() {
+        Value::Float(f) => assert!(f.is_infinite() && f > 0.0),
+        other => panic!("expected Infinity, got {:?}", other),
+    }
+    match run("0.0 / 0.0").unwrap() {
+        Value::Float(f) => assert!(f.is_nan()),
+        other => panic!("expected NaN, got {:?}", other),
+    }
+    assert!(
+        run("1 / 0").is_err(),
+        "integer division by zero still errors"
+    );
+}
+
+/// Test: NaN is never equal to itself
+#[test]
+fn test_nan_equality() {
+    assert_eq!(
+        run("let nan = 0.0 / 0.0; nan == nan").unwrap(),
+        Value::Boolean(false)
+    );
+    assert_eq!(
+        run("let nan = 0.0 / 0.0; nan != nan").unwrap(),
+        Value::Boolean(true)
+    );
+}
+
+/// Test: Ordering comparisons involving NaN are all false
+#[test]
+fn test_nan_ordering_comparisons_are_false() {
+    for op in ["<", ">", "<=", ">="] {
+        for (left, right) in [("nan", "1.0"), ("1.0", "nan"), ("nan", "nan")] {
+            let source = format!("let nan = 0.0 / 0.0; {} {} {}", left, op, right);
+            assert_eq!(
+                run(&source).unwrap(),
+                Value::Boolean(false),
+                "{} {} {} should be false",
+                left,
+                op,
+                right
+            );
+        }
+    }
+    assert_eq!(
+        run("let inf = 1.0 / 0.0; inf > 1000000.0").unwrap(),
+        Value::Boolean(true)
+    );
+}
+
+/// Test: Sorting a vector containing NaN places NaN last
+#[test]
+fn test_sort_vector_with_nan() {
+    let source = r#"
+let nan = 0.0 / 0.0;
+let inf = 1.0 / 0.0;
+let mut v = vec![3.0, nan, inf, 1.0, nan, 2.5];
+v.sort();
+v
+"#;
+    let Value::Vector(sorted) = run(source).unwrap() else {
+        panic!("expected vector");
+    };
+    let floats: Vec<f64> = sorted
+        .iter()
+        .map(|v| match v {
+            Value::Float(f) => *f,
+            other => panic!("expected float, got {:?}", other),
+        })
+        .collect();
+
+    assert_eq!(&floats[..4], &[1.0, 2.5, 3.0, f64::INFINITY]);
+    assert!(floats[4].is_nan() && floats[5].is_nan());
+}
+
+/// Test: NaN does not match a float literal arm
+#[test]
+fn test_match_float_literal_with_nan() {
+    let source = r#"
+fun classify(x) {
+    match x {
+        0.0 => "zero",
+        1.5 => "one and a half",
+        _ => "other"
+    }
+}
+let mut seen = vec![];
+seen.push(classify(0.0));
+seen.push(classify(1.5));
+seen.push(classify(0.0 / 0.0));
+seen
+"#;
+    assert_eq!(
+        run(source).unwrap(),
+        Value::Vector(vec![
+            Value::String("zero".to_string()),
+            Value::String("one and a half".to_string()),
+            Value::String("other".to_string()),
+        ])
+    );
+}
@@ -0,0 +1,103 @@
+// INTERP-091: len as a Free Function
+//
+// Mission: len(x) works alongside the x.len() method
+// - Strings report their byte length, like .len()
+// - Vectors and tuples report their element count
+// - HashMaps report their entry count
+// - Non-collections fail with a type mismatch naming the type
+//
+// Test Coverage:
+// - test_len_of_vector: len([1, 2, 3]) is 3
+// - test_len_of_string: len("hi") is 2
+// - test_len_of_tuple_and_hashmap: tuple elements and map entries
+// - test_len_of_integer_is_type_error: len(42) names Integer in the error
+// - test_len_argument_count: len() and len(a, b) are rejected
+// - test_user_len_is_shadowed: fun len(...) records a BuiltinShadowed warning
+
+use ruchyruchy::interpreter::evaluator::{EvalError, EvalWarning, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: len of a vector literal counts its elements
+#[test]
+fn test_len_of_vector() {
+    assert_eq!(run("len([1, 2, 3])").unwrap(), Value::integer(3));
+}
+
+/// Test: len of a string counts its bytes
+#[test]
+fn test_len_of_string() {
+    assert_eq!(run("len(\"hi\")").unwrap(), Value::integer(2));
+    assert_eq!(
+        run("let s = \"hello\"; len(s) == s.len()").unwrap(),
+        Value::Boolean(true)
+    );
+}
+
+/// Test: len of tuples and hashmaps
+#[test]
+fn test_len_of_tuple_and_hashmap() {
+    assert_eq!(run("len((1, \"a\", true))").unwrap(), Value::integer(3));
+
+    let source = r#"
+        let mut m = HashMap::new();
+        m.insert("a", 1);
+        m.insert("b", 2);
+        len(m)
+    "#;
+    assert_eq!(run(source).unwrap(), Value::integer(2));
+}
+
+/// Test: len of a non-collection is a type mismatch naming the found type
+#[test]
+fn test_len_of_integer_is_type_error() {
+    match run("len(42)") {
+        Err(EvalError::ValueError(ValueError::TypeMismatch {
+            found, operation, ..
+        })) => {
+            assert_eq!(found, "Integer");
+            assert_eq!(operation, "len");
+        }
+        other => panic!("expected TypeMismatch, got {:?}", other),
+    }
+}
+
+/// Test: len takes exactly one argument
+#[test]
+fn test_len_argument_count() {
+    for source in ["len()", "len([1], [2])"] {
+        assert!(
+            matches!(
+                run(source),
+                Err(EvalError::ArgumentCountMismatch { expected: 1, .. })
+            ),
+            "{} should be rejected",
+            source
+        );
+    }
+}
+
+/// Test: Defining fun len records a shadowing warning (builtin wins)
+#[test]
+fn test_user_len_is_shadowed() {
+    let ast = Parser::new("fun len(x) { 0 } len([1, 2])")
+        .parse()
+        .expect("parse");
+    let mut eval = Evaluator::new();
+    let value = eval.eval_program(&ast).expect("eval");
+
+    assert_eq!(value, Value::integer(2));
+    assert_eq!(
+        eval.warnings(),
+        &[EvalWarning::BuiltinShadowed {
+            name: "len".to_string()
+        }]
+    );
+}
@@ -0,0 +1,97 @@
+// INTERP-092: dbg Builtin
+//
+// Mission: A Rust-like dbg(expr) for teaching and quick inspection
+// - Prints "expr = value" using the argument's source text
+// - Returns the value unchanged, so it can be used inline
+// - Writes to a separate debug sink (stderr by default), never to println output
+//
+// Test Coverage:
+// - test_dbg_returns_value: let y = dbg(x + 1) binds the value
+// - test_dbg_echoes_expression_source: rendered text matches the source
+// - test_dbg_keeps_needed_parentheses: (a + b) * c is not flattened
+// - test_dbg_does_not_touch_program_output: println output stays separate
+// - test_dbg_argument_count: dbg() is rejected
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: evaluate a program, returning its value and the dbg output
+fn run(source: &str) -> (Result<Value, EvalError>, String) {
+    let ast = Parser::new(source).parse().expect("parse");
+    let debug = OutputSink::buffer();
+    let mut eval = Evaluator::new()
+        .with_output(OutputSink::buffer())
+        .with_debug_output(debug.clone());
+    let result = eval.eval_program(&ast);
+    (result, debug.contents().unwrap_or_default())
+}
+
+/// Test: dbg returns its argument's value
+#[test]
+fn test_dbg_returns_value() {
+    let (result, debug) = run("let x = 41; let y = dbg(x + 1); y * 2");
+
+    assert_eq!(result.unwrap(), Value::integer(84));
+    assert_eq!(debug, "x + 1 = 42\n");
+}
+
+/// Test: The rendered expression matches the source for common forms
+#[test]
+fn test_dbg_echoes_expression_source() {
+    let cases = [
+        ("let v = [1, 2, 3]; dbg(v[0])", "v[0] = 1"),
+        ("let s = \"hi\"; dbg(s.len())", "s.len() = 2"),
+        ("dbg(\"hi\")", "\"hi\" = \"hi\""),
+        ("dbg(2.5)", "2.5 = 2.5"),
+        ("let t = (1, true); dbg(t)", "t = (1, true)"),
+        ("let n = 3; dbg(-n + 1 == 2)", "-n + 1 == 2 = false"),
+    ];
+
+    for (source, expected) in cases {
+        let (result, debug) = run(source);
+        assert!(result.is_ok(), "{} failed: {:?}", source, result);
+        assert_eq!(debug.trim_end(), expected, "source: {}", source);
+    }
+}
+
+/// Test: Grouping that changes evaluation order is preserved
+#[test]
+fn test_dbg_keeps_needed_parentheses() {
+    let (result, debug) = run("let a = 1; let b = 2; let c = 3; dbg((a + b) * c)");
+
+    assert_eq!(result.unwrap(), Value::integer(9));
+    assert_eq!(debug, "(a + b) * c = 9\n");
+
+    let (_, debug) = run("dbg(10 - (4 - 1))");
+    assert_eq!(debug, "10 - (4 - 1) = 7\n");
+
+    let (_, debug) = run("dbg(10 - 4 - 1)");
+    assert_eq!(debug, "10 - 4 - 1 = 5\n");
+}
+
+/// Test: dbg output goes to the debug sink, not the program output
+#[test]
+fn test_dbg_does_not_touch_program_output() {
+    let ast = Parser::new("println(dbg(7))").parse().expect("parse");
+    let output = OutputSink::buffer();
+    let debug = OutputSink::buffer();
+    let mut eval = Evaluator::new()
+        .with_output(output.clone())
+        .with_debug_output(debug.clone());
+    eval.eval_program(&ast).expect("eval");
+
+    assert_eq!(output.contents(), Some("7\n".to_string()));
+    assert_eq!(debug.contents(), Some("7 = 7\n".to_string()));
+}
+
+/// Test: dbg takes exactly one argument
+#[test]
+fn test_dbg_argument_count() {
+    let (result, _) = run("dbg()");
+    assert!(matches!(
+        result,
+        Err(EvalError::ArgumentCountMismatch { expected: 1, .. })
+    ));
+}
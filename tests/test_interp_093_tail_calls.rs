@@ -0,0 +1,204 @@
+// INTERP-093: Tail-Position Call Detection
+//
+// Mission: Show learners which recursive calls could be tail-call optimized
+// - find_tail_calls(function) lists calls whose result is returned directly
+// - Tail position flows through if/else, match arms, blocks, and return
+// - Tail position does NOT flow through operators (1 + f(n - 1))
+// - The compiler profiler reports TailCallOpt for tail-recursive functions
+//
+// Test Coverage:
+// - test_accumulator_recursion_is_tail: fact(n - 1, acc * n) in an else branch
+// - test_operator_wrapped_recursion_is_not_tail: n * fact(n - 1)
+// - test_tail_position_through_match_and_block: last expression of arms/blocks
+// - test_return_is_tail_position: return f(x) inside a loop
+// - test_return_inside_expressions_is_tail_position: let x = if c { return f(..) } ..
+// - test_arguments_and_non_last_statements_are_not_tail: f(g(x)), f(x); 0
+// - test_lines_and_diagnostic_message: spans give lines; Display reads as info
+// - test_profiler_reports_tail_call_opportunity: OptKind::TailCallOpt
+
+use ruchyruchy::interpreter::parser::{AstNode, BinaryOperator, Parser};
+use ruchyruchy::interpreter::tail_calls::{find_all_tail_calls, find_tail_calls, TailCall};
+use ruchyruchy::profiler::{CompilerProfiler, OptKind};
+
+/// Helper: tail calls of the first function in the source
+fn tail_calls(source: &str) -> Vec<TailCall> {
+    let ast = Parser::new(source).parse().expect("parse");
+    find_tail_calls(&ast.nodes()[0])
+}
+
+/// Helper: callee names of the tail calls
+fn callees(source: &str) -> Vec<String> {
+    tail_calls(source).into_iter().map(|c| c.callee).collect()
+}
+
+/// Test: Accumulator-style recursion in an else branch is a tail call
+#[test]
+fn test_accumulator_recursion_is_tail() {
+    let calls = tail_calls(
+        r#"
+        fun fact(n, acc) {
+            if n <= 1 {
+                acc
+            } else {
+                fact(n - 1, acc * n)
+            }
+        }
+    "#,
+    );
+
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].caller, "fact");
+    assert_eq!(calls[0].callee, "fact");
+    assert!(calls[0].is_recursive());
+}
+
+/// Test: A call wrapped in a binary operator is not in tail position
+#[test]
+fn test_operator_wrapped_recursion_is_not_tail() {
+    let source = r#"
+        fun fact(n) {
+            if n <= 1 {
+                1
+            } else {
+                n * fact(n - 1)
+            }
+        }
+    "#;
+    assert!(callees(source).is_empty());
+
+    assert!(callees("fun f(n) { 1 + f(n - 1) }").is_empty());
+}
+
+/// Test: Tail position flows through match arms and nested blocks
+#[test]
+fn test_tail_position_through_match_and_block() {
+    let source = r#"
+        fun walk(n) {
+            match n {
+                0 => done(),
+                1 => { let m = n; step(m) },
+                _ => 1 + walk(n - 1),
+            }
+        }
+    "#;
+    assert_eq!(callees(source), vec!["done", "step"]);
+}
+
+/// Test: A returned call is in tail position, even inside a loop
+#[test]
+fn test_return_is_tail_position() {
+    let source = r#"
+        fun search(n) {
+            while n > 0 {
+                if n == 5 {
+                    return found(n);
+                }
+                tick(n);
+            }
+            0
+        }
+    "#;
+    assert_eq!(callees(source), vec!["found"]);
+}
+
+/// Test: A return nested in a let, assignment, or operand is still found
+#[test]
+fn test_return_inside_expressions_is_tail_position() {
+    assert_eq!(
+        callees("fun f(n) { let x = { return f(n - 1) }; x }"),
+        vec!["f"]
+    );
+    assert_eq!(
+        callees("fun f(n) { let mut x = 0; x = { return g(n) }; x }"),
+        vec!["g"]
+    );
+    assert_eq!(callees("fun f(n) { 1 + { return g(n) } }"), vec!["g"]);
+
+    // let x = if c { return f(n - 1) } else { 1 }; x
+    let call = AstNode::FunctionCall {
+        name: "f".to_string(),
+        args: vec![AstNode::BinaryOp {
+            left: Box::new(AstNode::Identifier("n".to_string())),
+            op: BinaryOperator::Subtract,
+            right: Box::new(AstNode::IntegerLiteral(1)),
+        }],
+    };
+    let function = AstNode::FunctionDef {
+        name: "f".to_string(),
+        params: vec!["n".to_string(), "c".to_string()],
+        param_types: vec![None, None],
+        return_type: None,
+        body: vec![
+            AstNode::LetDecl {
+                name: "x".to_string(),
+                mutable: false,
+                value: Box::new(AstNode::IfExpr {
+                    condition: Box::new(AstNode::Identifier("c".to_string())),
+                    then_branch: vec![AstNode::Return {
+                        value: Some(Box::new(call)),
+                    }],
+                    else_branch: Some(vec![AstNode::IntegerLiteral(1)]),
+                }),
+            },
+            AstNode::Identifier("x".to_string()),
+        ],
+    };
+    let calls = find_tail_calls(&function);
+    assert_eq!(calls.len(), 1);
+    assert!(calls[0].is_recursive());
+
+    // A return inside a closure leaves the closure, not f
+    assert_eq!(
+        callees("fun f(n) { let k = |x| { return g(x) }; k(n) }"),
+        vec!["k"]
+    );
+}
+
+/// Test: Arguments and non-final statements are not tail calls
+#[test]
+fn test_arguments_and_non_last_statements_are_not_tail() {
+    assert_eq!(callees("fun f(x) { g(h(x)) }"), vec!["g"]);
+    assert!(callees("fun f(x) { g(x); 0 }").is_empty());
+    assert!(callees("fun f(x) { let y = g(x); y }").is_empty());
+}
+
+/// Test: With spans, calls carry their statement line; Display reads as info
+#[test]
+fn test_lines_and_diagnostic_message() {
+    let source =
+        "fun count(n) {\n    if n == 0 {\n        0\n    } else {\n        count(n - 1)\n    }\n}";
+    let ast = Parser::new(source).with_spans().parse().expect("parse");
+    let calls = find_all_tail_calls(&ast);
+
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].line, Some(5));
+    assert_eq!(
+        calls[0].to_string(),
+        "info: call to 'count' in 'count' at line 5 is in tail position \
+         (tail recursion, could be optimized into a loop)"
+    );
+}
+
+/// Test: The compiler profiler reports tail-recursive functions
+#[test]
+fn test_profiler_reports_tail_call_opportunity() {
+    let source = r#"
+        fun loop_sum(n, acc) {
+            if n == 0 { acc } else { loop_sum(n - 1, acc + n) }
+        }
+        fun fib(n) {
+            if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+        }
+    "#;
+    let ast = Parser::new(source).parse().expect("parse");
+    let opportunities = CompilerProfiler::new().analyze_ast(&ast);
+
+    let tail_opts: Vec<&str> = opportunities
+        .iter()
+        .filter_map(|o| match &o.kind {
+            OptKind::TailCallOpt { function } => Some(function.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tail_opts, vec!["loop_sum"]);
+}
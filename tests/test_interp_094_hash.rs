@@ -0,0 +1,136 @@
+// INTERP-094: Deterministic hash Builtin
+//
+// Mission: A stable hash(value) for teaching hash tables
+// - hash(value) returns an Integer that is the same in every run
+// - Equal values hash equally (HashMap insertion order does not matter)
+// - Different values of different types hash differently
+// - Closures and NaN are unhashable and fail with a clear error
+// - The hash is stable within a version but not cryptographic
+//
+// Test Coverage:
+// - test_equal_values_hash_equally: strings, vectors, tuples, structs
+// - test_hash_is_reproducible: fresh evaluators agree; matches Value::stable_hash
+// - test_hashmap_hash_ignores_insertion_order: same entries, different order
+// - test_distinct_values_hash_differently: 1 vs "1" vs [1] vs 1.0, (1, 2) vs [1, 2]
+// - test_unhashable_values_error: closures and NaN
+// - test_hash_for_bucket_index: hash(k) % n works as a bucket index
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: evaluate hash(<expr>) to an i64
+fn hash_of(expr: &str) -> i64 {
+    run(&format!("hash({})", expr))
+        .unwrap()
+        .as_integer()
+        .unwrap()
+}
+
+/// Test: Equal values produce equal hashes
+#[test]
+fn test_equal_values_hash_equally() {
+    assert_eq!(
+        run("let a = \"key\"; let b = \"k\" + \"ey\"; hash(a) == hash(b)").unwrap(),
+        Value::Boolean(true)
+    );
+    assert_eq!(hash_of("[1, 2, 3]"), hash_of("[1, 2, 3]"));
+    assert_eq!(hash_of("(1, true, \"x\")"), hash_of("(1, true, \"x\")"));
+
+    let source = r#"
+        struct Point { x: i64, y: i64 }
+        let p = Point { x: 1, y: 2 };
+        let q = Point { y: 2, x: 1 };
+        hash(p) == hash(q)
+    "#;
+    assert_eq!(run(source).unwrap(), Value::Boolean(true));
+}
+
+/// Test: The hash is reproducible across evaluators and matches the Value API
+#[test]
+fn test_hash_is_reproducible() {
+    let first = hash_of("\"hello\"");
+    for _ in 0..3 {
+        assert_eq!(hash_of("\"hello\""), first);
+    }
+    assert_eq!(
+        Value::string("hello".to_string()).stable_hash().unwrap(),
+        first
+    );
+}
+
+/// Test: HashMap hashes do not depend on insertion order
+#[test]
+fn test_hashmap_hash_ignores_insertion_order() {
+    let source = r#"
+        let mut a = HashMap::new();
+        a.insert("one", 1);
+        a.insert("two", 2);
+        a.insert("three", 3);
+        let mut b = HashMap::new();
+        b.insert("three", 3);
+        b.insert("one", 1);
+        b.insert("two", 2);
+        hash(a) == hash(b)
+    "#;
+    assert_eq!(run(source).unwrap(), Value::Boolean(true));
+}
+
+/// Test: Values that are not equal (including across types) hash differently
+#[test]
+fn test_distinct_values_hash_differently() {
+    let hashes = [
+        hash_of("1"),
+        hash_of("2"),
+        hash_of("\"1\""),
+        hash_of("[1]"),
+        hash_of("(1, 2)"),
+        hash_of("[1, 2]"),
+        hash_of("true"),
+        hash_of("1.0"),
+    ];
+    for (i, a) in hashes.iter().enumerate() {
+        for b in &hashes[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+/// Test: Closures and NaN cannot be hashed
+#[test]
+fn test_unhashable_values_error() {
+    match run("let f = |x| x + 1; hash(f)") {
+        Err(EvalError::ValueError(ValueError::TypeMismatch {
+            found, operation, ..
+        })) => {
+            assert_eq!(found, "Closure");
+            assert_eq!(operation, "hash");
+        }
+        other => panic!("expected TypeMismatch, got {:?}", other),
+    }
+
+    assert!(matches!(
+        run("hash(0.0 / 0.0)"),
+        Err(EvalError::ValueError(ValueError::InvalidOperation { .. }))
+    ));
+}
+
+/// Test: hash(k) can pick a bucket in a teaching hash table
+#[test]
+fn test_hash_for_bucket_index() {
+    let source = r#"
+        let h = hash("apple");
+        let bucket = h % 8;
+        if bucket < 0 { bucket + 8 } else { bucket }
+    "#;
+    let bucket = run(source).unwrap().as_integer().unwrap();
+    assert!((0..8).contains(&bucket));
+    assert_eq!(bucket, hash_of("\"apple\"").rem_euclid(8));
+}
@@ -0,0 +1,110 @@
+// INTERP-095: Buffered Channel Mock
+//
+// Mission: Make mpsc channel examples demonstrate real data flow
+// - mpsc::channel() returns (tx, rx) sharing a channel id
+// - tx.send(v) enqueues v into an evaluator-held buffer for that channel
+// - rx.recv() dequeues the oldest value (nil when the channel is empty)
+// - Separate channels never see each other's values
+//
+// Test Coverage:
+// - test_sent_value_is_received: a value sent from a spawned thread arrives
+// - test_values_arrive_in_fifo_order: several sends, recv in order
+// - test_recv_on_empty_channel_returns_nil: default when nothing was sent
+// - test_channels_are_independent: two channels keep separate buffers
+// - test_send_on_receiver_is_rejected: only the Sender end can send
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: The value sent is the value received
+#[test]
+fn test_sent_value_is_received() {
+    let source = r#"
+        use std::sync::mpsc;
+        use std::thread;
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            tx.send(42).unwrap();
+        });
+        rx.recv().unwrap()
+    "#;
+    assert_eq!(run(source).unwrap(), Value::integer(42));
+}
+
+/// Test: Values are received in the order they were sent
+#[test]
+fn test_values_arrive_in_fifo_order() {
+    let source = r#"
+        let (tx, rx) = mpsc::channel();
+        for i in 0..3 {
+            tx.send(i * 10);
+        }
+        let a = rx.recv();
+        let b = rx.recv();
+        let c = rx.recv();
+        [a, b, c]
+    "#;
+    assert_eq!(
+        run(source).unwrap(),
+        Value::vector(vec![
+            Value::integer(0),
+            Value::integer(10),
+            Value::integer(20)
+        ])
+    );
+}
+
+/// Test: recv on an empty channel returns nil
+#[test]
+fn test_recv_on_empty_channel_returns_nil() {
+    let source = r#"
+        let (tx, rx) = mpsc::channel();
+        tx.send("only");
+        rx.recv();
+        rx.recv()
+    "#;
+    assert_eq!(run(source).unwrap(), Value::nil());
+}
+
+/// Test: Each channel has its own buffer
+#[test]
+fn test_channels_are_independent() {
+    let source = r#"
+        let (tx1, rx1) = mpsc::channel();
+        let (tx2, rx2) = mpsc::channel();
+        tx1.send("first");
+        tx2.send("second");
+        (rx2.recv(), rx1.recv())
+    "#;
+    assert_eq!(
+        run(source).unwrap(),
+        Value::tuple(vec![
+            Value::string("second".to_string()),
+            Value::string("first".to_string())
+        ])
+    );
+}
+
+/// Test: Sending on the Receiver end is an error
+#[test]
+fn test_send_on_receiver_is_rejected() {
+    let source = r#"
+        let (tx, rx) = mpsc::channel();
+        rx.send(1)
+    "#;
+    match run(source) {
+        Err(EvalError::UnsupportedOperation { operation }) => {
+            assert!(operation.contains("Sender"), "{}", operation);
+        }
+        other => panic!("expected UnsupportedOperation, got {:?}", other),
+    }
+}
@@ -0,0 +1,73 @@
+// INTERP-096: Locale-Independent Number Formatting
+//
+// Mission: Program output must not depend on the machine's locale
+// - Floats always use '.' as the decimal separator
+// - Integers and floats are never digit-grouped
+// - Every display path (println, f-strings, to_string) renders the same text
+//
+// Test Coverage:
+// - test_float_uses_dot_under_comma_locale: de_DE locale in the environment
+// - test_large_numbers_are_not_grouped: 1234567 and 1234567.5
+// - test_display_paths_agree: println, f-string, to_string, Display
+// - test_number_display_edge_cases: Display of extreme and non-finite values
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: run a program and return what it printed
+fn printed(source: &str) -> String {
+    let ast = Parser::new(source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    eval.eval_program(&ast).expect("eval");
+    sink.contents().unwrap_or_default()
+}
+
+/// Test: A comma-decimal locale in the environment does not change output
+#[test]
+fn test_float_uses_dot_under_comma_locale() {
+    // Only this test touches the locale variables, so no other thread reads them
+    for var in ["LC_ALL", "LC_NUMERIC", "LANG"] {
+        std::env::set_var(var, "de_DE.UTF-8");
+    }
+
+    assert_eq!(printed("println(3.25)"), "3.25\n");
+    assert_eq!(printed("println(0.5 * 3.0)"), "1.5\n");
+}
+
+/// Test: No thousands separators for large values
+#[test]
+fn test_large_numbers_are_not_grouped() {
+    assert_eq!(
+        printed("println(1234567)\nprintln(1234567.5)\nprintln(-9876543)"),
+        "1234567\n1234567.5\n-9876543\n"
+    );
+}
+
+/// Test: All display paths render numbers identically
+#[test]
+fn test_display_paths_agree() {
+    let source = r#"
+        let x = 1234.5;
+        println(x);
+        println(f"{x}");
+        println(x.to_string());
+        println([x, 1000]);
+    "#;
+    assert_eq!(printed(source), "1234.5\n1234.5\n1234.5\n[1234.5, 1000]\n");
+    assert_eq!(Value::float(1234.5).to_string(), "1234.5");
+}
+
+/// Test: Display of extreme and non-finite values
+#[test]
+fn test_number_display_edge_cases() {
+    assert_eq!(Value::integer(1_000_000).to_string(), "1000000");
+    assert_eq!(Value::integer(i64::MIN).to_string(), "-9223372036854775808");
+    assert_eq!(Value::float(0.1 + 0.2).to_string(), "0.30000000000000004");
+    assert_eq!(Value::float(3.0).to_string(), "3");
+    assert_eq!(Value::float(1e-7).to_string(), "0.0000001");
+    assert_eq!(Value::float(f64::NAN).to_string(), "NaN");
+    assert_eq!(Value::float(f64::NEG_INFINITY).to_string(), "-inf");
+}
@@ -0,0 +1,64 @@
+// INTERP-097: assert_eq and Assertion Errors
+//
+// Mission: Failed assertions are a distinct error, not a generic one
+// - assert, assert_eq, and assert_approx fail with EvalError::AssertionFailed
+// - assert_eq reports the asserted expressions and both values
+// - is_assertion_failure() sees through call-stack wrappers
+//
+// Test Coverage:
+// - test_assert_eq_passes_on_equal_values: returns nil
+// - test_assert_eq_failure_reports_both_sides: source text and values
+// - test_assert_failure_shows_condition: assert(x > 3) message
+// - test_assertion_inside_function_is_detected: WithCallStack wrapper
+// - test_other_errors_are_not_assertions: undefined variable
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: assert_eq succeeds when both sides are equal
+#[test]
+fn test_assert_eq_passes_on_equal_values() {
+    assert_eq!(run("assert_eq(1 + 1, 2)").unwrap(), Value::nil());
+    assert_eq!(run("assert_eq([1, 2], [1, 2])").unwrap(), Value::nil());
+}
+
+/// Test: A failing assert_eq names both expressions and their values
+#[test]
+fn test_assert_eq_failure_reports_both_sides() {
+    match run("let x = 2; assert_eq(x * 2, 5)") {
+        Err(EvalError::AssertionFailed { message, .. }) => {
+            assert_eq!(message, "x * 2 == 5 (left: 4, right: 5)");
+        }
+        other => panic!("expected AssertionFailed, got {:?}", other),
+    }
+}
+
+/// Test: A failing assert shows the condition that was false
+#[test]
+fn test_assert_failure_shows_condition() {
+    let err = run("let x = 1; assert(x > 3)").unwrap_err();
+    assert!(matches!(err, EvalError::AssertionFailed { .. }));
+    assert_eq!(err.to_string(), "assertion failed: x > 3");
+}
+
+/// Test: Assertions inside a function are still recognized
+#[test]
+fn test_assertion_inside_function_is_detected() {
+    let err = run("fun check(n) { assert_eq(n, 0) } check(7)").unwrap_err();
+    assert!(err.is_assertion_failure(), "{:?}", err);
+}
+
+/// Test: Non-assertion errors are not classified as assertions
+#[test]
+fn test_other_errors_are_not_assertions() {
+    let err = run("missing + 1").unwrap_err();
+    assert!(!err.is_assertion_failure());
+}
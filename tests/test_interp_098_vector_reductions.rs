@@ -0,0 +1,106 @@
+// INTERP-098: Vector Reductions
+//
+// Mission: Common reductions as methods on vectors for introductory examples
+// - sum() and product() fold numbers (integers stay exact, mixing promotes to float)
+// - min() and max() use Value::compare
+// - count() is the element count, average() is always a float
+// - Empty-vector min/max/average are clear errors
+//
+// Test Coverage:
+// - test_sum_and_product: integer, float, and mixed vectors
+// - test_min_and_max: numbers and strings
+// - test_count_and_average: average of integers is a float
+// - test_empty_vector_reductions: identities for sum/product, errors otherwise
+// - test_non_numeric_sum_is_type_error: sum over strings
+// - test_product_overflow_is_error: no silent wraparound
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: sum and product over integer, float, and mixed vectors
+#[test]
+fn test_sum_and_product() {
+    assert_eq!(run("[1, 2, 3, 4].sum()").unwrap(), Value::integer(10));
+    assert_eq!(run("[1, 2, 3, 4].product()").unwrap(), Value::integer(24));
+    assert_eq!(run("[0.5, 1.5].sum()").unwrap(), Value::float(2.0));
+    assert_eq!(run("[2, 1.5].product()").unwrap(), Value::float(3.0));
+}
+
+/// Test: min and max follow Value::compare
+#[test]
+fn test_min_and_max() {
+    assert_eq!(run("[3, -1, 2].min()").unwrap(), Value::integer(-1));
+    assert_eq!(run("[3, 7.5, 2].max()").unwrap(), Value::float(7.5));
+    assert_eq!(
+        run("[\"pear\", \"apple\", \"fig\"].min()").unwrap(),
+        Value::string("apple".to_string())
+    );
+}
+
+/// Test: count is the length and average is a float
+#[test]
+fn test_count_and_average() {
+    assert_eq!(run("[4, 5, 6].count()").unwrap(), Value::integer(3));
+    assert_eq!(run("[1, 2].average()").unwrap(), Value::float(1.5));
+    assert_eq!(
+        run("let v = [2, 4, 6]; v.average()").unwrap(),
+        Value::float(4.0)
+    );
+}
+
+/// Test: Empty vectors have identities for sum/product and errors otherwise
+#[test]
+fn test_empty_vector_reductions() {
+    assert_eq!(run("[].sum()").unwrap(), Value::integer(0));
+    assert_eq!(run("[].product()").unwrap(), Value::integer(1));
+    assert_eq!(run("[].count()").unwrap(), Value::integer(0));
+
+    for method in ["min", "max", "average"] {
+        match run(&format!("[].{}()", method)) {
+            Err(EvalError::ValueError(ValueError::InvalidOperation { operation, message })) => {
+                assert_eq!(operation, format!("Array.{}()", method));
+                assert!(message.starts_with("empty vector"), "{}", message);
+            }
+            other => panic!(
+                "expected empty-vector error for {}, got {:?}",
+                method, other
+            ),
+        }
+    }
+}
+
+/// Test: Summing non-numbers is a type mismatch
+#[test]
+fn test_non_numeric_sum_is_type_error() {
+    match run("[1, \"two\"].sum()") {
+        Err(EvalError::ValueError(ValueError::TypeMismatch {
+            found, operation, ..
+        })) => {
+            assert_eq!(found, "String");
+            assert_eq!(operation, "Array.sum()");
+        }
+        other => panic!("expected TypeMismatch, got {:?}", other),
+    }
+}
+
+/// Test: Integer overflow in a product is reported, not wrapped
+#[test]
+fn test_product_overflow_is_error() {
+    let result = run("[9223372036854775807, 2].product()");
+    assert!(
+        matches!(
+            result,
+            Err(EvalError::ValueError(ValueError::InvalidOperation { .. }))
+        ),
+        "{:?}",
+        result
+    );
+}
@@ -0,0 +1,67 @@
+// INTERP-099: print and Output Flushed on Error
+//
+// Mission: Output printed before an error is never lost
+// - print(x) writes x without a trailing newline
+// - eval_program flushes the output sinks before returning, on success and
+//   on the error path, so a failing assert still shows preceding output
+//
+// Test Coverage:
+// - test_print_has_no_newline: print and println mix on one line
+// - test_output_before_failed_assert_is_kept: print("before") then assert
+// - test_output_before_error_in_function_is_kept: error inside a call
+// - test_print_argument_count: print() with no argument is rejected
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+
+/// Helper: run a program, returning its result and everything it printed
+fn run(source: &str) -> (Result<(), EvalError>, String) {
+    let ast = Parser::new(source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    let result = eval.eval_program(&ast).map(|_| ());
+    (result, sink.contents().unwrap_or_default())
+}
+
+/// Test: print does not append a newline
+#[test]
+fn test_print_has_no_newline() {
+    let (result, output) = run("print(\"a\")\nprint(1)\nprintln(\"b\")");
+    assert!(result.is_ok());
+    assert_eq!(output, "a1b\n");
+}
+
+/// Test: Output printed before a failing assert is still captured
+#[test]
+fn test_output_before_failed_assert_is_kept() {
+    let (result, output) = run("print(\"before\")\nassert(1 == 2)\nprint(\"after\")");
+    assert!(result.unwrap_err().is_assertion_failure());
+    assert_eq!(output, "before");
+}
+
+/// Test: Output survives an error raised inside a function call
+#[test]
+fn test_output_before_error_in_function_is_kept() {
+    let source = r#"
+        fun step(n) {
+            print(n);
+            return missing;
+        }
+        print("start ");
+        step(1)
+    "#;
+    let (result, output) = run(source);
+    assert!(result.is_err());
+    assert_eq!(output, "start 1");
+}
+
+/// Test: print requires exactly one argument
+#[test]
+fn test_print_argument_count() {
+    let (result, _) = run("print()");
+    assert!(matches!(
+        result,
+        Err(EvalError::ArgumentCountMismatch { .. })
+    ));
+}
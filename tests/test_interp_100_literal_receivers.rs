@@ -0,0 +1,90 @@
+// INTERP-100: Method Calls on Any Receiver Expression
+//
+// Mission: Methods work the same on literals and call results as on variables
+// - Any receiver expression is evaluated once and dispatched through call_method
+// - Only variable receivers of push/push_str/pop/sort/insert are mutated in place
+// - Mutating methods on temporaries are accepted (pop returns the last element)
+//
+// Test Coverage:
+// - test_len_on_literal_vector: [1, 2, 3].len()
+// - test_len_on_literals_of_other_types: "x".len() and a map literal
+// - test_len_on_function_call_result: get_items().len()
+// - test_receiver_is_evaluated_once: side effects of the receiver happen once
+// - test_mutating_methods_on_temporaries: pop/sort/push on literals
+// - test_in_place_push_still_mutates_variable: v.push(x) keeps working
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: .len() on a vector literal
+#[test]
+fn test_len_on_literal_vector() {
+    assert_eq!(run("[1, 2, 3].len()").unwrap(), Value::integer(3));
+    assert_eq!(run("[].is_empty()").unwrap(), Value::boolean(true));
+}
+
+/// Test: .len() on string and map literals
+#[test]
+fn test_len_on_literals_of_other_types() {
+    assert_eq!(run("\"x\".len()").unwrap(), Value::integer(1));
+    assert_eq!(
+        run("{\"a\": 1, \"b\": 2}.get(\"b\")").unwrap(),
+        Value::integer(2)
+    );
+}
+
+/// Test: .len() on the result of a function call
+#[test]
+fn test_len_on_function_call_result() {
+    let source = r#"
+        fun get_items() {
+            return [10, 20, 30, 40];
+        }
+        get_items().len()
+    "#;
+    assert_eq!(run(source).unwrap(), Value::integer(4));
+}
+
+/// Test: The receiver expression runs exactly once
+#[test]
+fn test_receiver_is_evaluated_once() {
+    let source = r#"
+        fun noisy() {
+            println("called");
+            return "abc";
+        }
+        noisy().len()
+    "#;
+    let ast = Parser::new(source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    assert_eq!(eval.eval_program(&ast).unwrap(), Value::integer(3));
+    assert_eq!(sink.contents().unwrap(), "called\n");
+}
+
+/// Test: Mutating methods are accepted on temporaries
+#[test]
+fn test_mutating_methods_on_temporaries() {
+    assert_eq!(run("[3, 1, 2].pop()").unwrap(), Value::integer(2));
+    assert_eq!(run("[].pop()").unwrap(), Value::nil());
+    assert_eq!(run("[3, 1, 2].sort()").unwrap(), Value::nil());
+    assert_eq!(run("[1].push(2)").unwrap(), Value::nil());
+}
+
+/// Test: push on a variable still mutates it in place
+#[test]
+fn test_in_place_push_still_mutates_variable() {
+    assert_eq!(
+        run("let v = [1]; v.push(2); v.len()").unwrap(),
+        Value::integer(2)
+    );
+}
@@ -0,0 +1,106 @@
+// INTERP-101: Positional and Named Format Arguments
+//
+// Mission: Placeholder arguments for format and print/println
+// - {} takes the next argument, {N} the N-th (reusable, reorderable)
+// - {name} resolves a variable in the current scope
+// - {{ and }} are literal braces
+// - Out-of-range indices and unknown names are clear errors
+// - f-strings interpolate expressions, so {x} may repeat there as well
+//
+// Test Coverage:
+// - test_positional_reorder_and_reuse: format("{1} {0} {1}", a, b)
+// - test_implicit_placeholders: format("{} + {}", 1, 2) and println
+// - test_named_placeholder_resolves_scope: format("{name}") and inside a function
+// - test_fstring_reuses_variable: f"{x} and {x} again"
+// - test_literal_braces: {{ and }}
+// - test_out_of_range_index_is_error: {2} with two arguments
+// - test_unknown_name_is_error: {missing}
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: evaluate a program expected to produce a string
+fn string(source: &str) -> String {
+    run(source).unwrap().as_string().unwrap().to_string()
+}
+
+/// Test: Positional indices can swap and repeat arguments
+#[test]
+fn test_positional_reorder_and_reuse() {
+    assert_eq!(
+        string("let a = \"A\"; let b = \"B\"; format(\"{1} {0} {1}\", a, b)"),
+        "B A B"
+    );
+}
+
+/// Test: Empty placeholders consume arguments in order, also in println
+#[test]
+fn test_implicit_placeholders() {
+    assert_eq!(string("format(\"{} + {} = {}\", 1, 2, 1 + 2)"), "1 + 2 = 3");
+
+    let ast = Parser::new("let r = 15; println(\"10 + 5 = {}\", r)")
+        .parse()
+        .expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    eval.eval_program(&ast).unwrap();
+    assert_eq!(sink.contents().unwrap(), "10 + 5 = 15\n");
+}
+
+/// Test: Named placeholders read variables from the current scope
+#[test]
+fn test_named_placeholder_resolves_scope() {
+    assert_eq!(
+        string("let name = \"Ada\"; format(\"hi {name}, {0}\", [1, 2])"),
+        "hi Ada, [1, 2]"
+    );
+    let source = r#"
+        fun greet(who) {
+            return format("hello {who}");
+        }
+        greet("world")
+    "#;
+    assert_eq!(string(source), "hello world");
+}
+
+/// Test: f-strings can interpolate the same variable several times
+#[test]
+fn test_fstring_reuses_variable() {
+    assert_eq!(string("let x = 7; f\"{x} and {x} again\""), "7 and 7 again");
+}
+
+/// Test: Doubled braces are literal
+#[test]
+fn test_literal_braces() {
+    assert_eq!(string("format(\"{{{}}}\", 5)"), "{5}");
+}
+
+/// Test: An index past the argument list is an error
+#[test]
+fn test_out_of_range_index_is_error() {
+    match run("format(\"{0} {2}\", 1, 2)") {
+        Err(EvalError::ValueError(ValueError::InvalidOperation { operation, message })) => {
+            assert_eq!(operation, "format");
+            assert!(message.contains("{2} is out of range"), "{}", message);
+        }
+        other => panic!("expected InvalidOperation, got {:?}", other),
+    }
+}
+
+/// Test: A name that is not in scope is an error
+#[test]
+fn test_unknown_name_is_error() {
+    match run("format(\"{missing}\")") {
+        Err(EvalError::UndefinedVariable { name }) => assert_eq!(name, "missing"),
+        other => panic!("expected UndefinedVariable, got {:?}", other),
+    }
+}
@@ -0,0 +1,126 @@
+// INTERP-102: F-String Hole Evaluation
+//
+// Mission: Holes are ordinary expressions evaluated by the interpreter
+// - Nested f-strings and string literals inside a hole are lexed as one token
+// - Holes may call user functions and methods
+// - Each hole is charged to the call-depth budget (StackOverflow, not a crash)
+// - A malformed hole reports its offset within the f-string
+//
+// Test Coverage:
+// - test_nested_fstring: f"outer {f"inner {x}"}"
+// - test_hole_with_braces_in_string_literal: "{" inside a hole's string
+// - test_hole_calls_user_function: f"{double(x)}"
+// - test_hole_with_method_calls: f"{name.len()} {[1, 2].sum()}"
+// - test_malformed_hole_reports_offset: f"ok {1 +} more"
+// - test_unterminated_hole_is_error: f"value {x"
+// - test_recursive_fstring_is_stack_overflow: holes count toward call depth
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Helper: evaluate a program expected to produce a string
+fn string(source: &str) -> String {
+    run(source).unwrap().as_string().unwrap().to_string()
+}
+
+/// Test: An f-string inside a hole is interpolated too
+#[test]
+fn test_nested_fstring() {
+    assert_eq!(
+        string("let x = 3; f\"outer {f\"inner {x}\"} done\""),
+        "outer inner 3 done"
+    );
+}
+
+/// Test: Braces inside a string literal in a hole do not end the hole
+#[test]
+fn test_hole_with_braces_in_string_literal() {
+    let source = "let s = \"{\"; f\"[{s + \"}\"}]\"";
+    assert_eq!(string(source), "[{}]");
+}
+
+/// Test: A hole can call a user-defined function
+#[test]
+fn test_hole_calls_user_function() {
+    let source = r#"
+        fun double(n) {
+            return n * 2;
+        }
+        let x = 21;
+        f"answer: {double(x)}"
+    "#;
+    assert_eq!(string(source), "answer: 42");
+}
+
+/// Test: A hole can contain method calls
+#[test]
+fn test_hole_with_method_calls() {
+    assert_eq!(
+        string("let name = \"ruchy\"; f\"{name.len()} {[1, 2].sum()}\""),
+        "5 3"
+    );
+}
+
+/// Test: Parse errors in a hole name the hole's offset
+#[test]
+fn test_malformed_hole_reports_offset() {
+    match run("f\"ok {1 +} more\"") {
+        Err(EvalError::UnsupportedOperation { operation }) => {
+            assert!(operation.contains("at offset 3"), "{}", operation);
+            assert!(operation.contains("'1 +'"), "{}", operation);
+        }
+        other => panic!("expected UnsupportedOperation, got {:?}", other),
+    }
+}
+
+/// Test: A hole without a closing brace is an error
+#[test]
+fn test_unterminated_hole_is_error() {
+    match run("let x = 1; f\"value {x\"") {
+        Err(EvalError::UnsupportedOperation { operation }) => {
+            assert!(operation.contains("unterminated"), "{}", operation);
+        }
+        other => panic!("expected UnsupportedOperation, got {:?}", other),
+    }
+}
+
+/// Test: Recursion through holes is charged twice per level
+#[test]
+fn test_recursive_fstring_is_stack_overflow() {
+    let program = |n: i64| {
+        format!(
+            r#"
+            fun down(n) {{
+                if n == 0 {{ "done" }} else {{ f"{{down(n - 1)}}" }}
+            }}
+            down({})
+            "#,
+            n
+        )
+    };
+
+    // 10 calls + 10 holes fit under the limit
+    assert_eq!(
+        run(&program(10)).unwrap(),
+        Value::string("done".to_string())
+    );
+    // 20 calls alone would fit, but each hole adds a level
+    let err = root_error(run(&program(20)).unwrap_err());
+    assert!(matches!(err, EvalError::StackOverflow), "{:?}", err);
+}
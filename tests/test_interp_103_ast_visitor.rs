@@ -0,0 +1,195 @@
+// INTERP-103: Reusable AST Visitors
+//
+// Mission: New analyses and transforms reuse one traversal
+// - Visitor walks a tree immutably with a hook per common node kind
+// - VisitorMut walks a tree mutably so transforms can rewrite nodes
+// - Ast::visit reaches every node, including loop and closure bodies
+//
+// Test Coverage:
+// - test_hooks_receive_node_fields: unused-variable style analysis
+// - test_visitor_reaches_nested_nodes: calls in closures, loops, match arms
+// - test_overriding_hook_controls_descent: skipping function bodies
+// - test_visitor_mut_folds_constants: folding preserves program behavior
+// - test_visitor_mut_edits_statement_lists: removing statements
+// - test_ast_visit_sees_loop_bodies: Ast::visit uses the same traversal
+
+use std::collections::HashSet;
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::{Ast, AstNode, BinaryOperator, Parser};
+use ruchyruchy::interpreter::visitor::{walk_node_mut, Visitor, VisitorMut};
+
+/// Helper: parse a program
+fn parse(source: &str) -> Ast {
+    Parser::new(source).parse().expect("parse")
+}
+
+/// Records declared and referenced variable names
+#[derive(Default)]
+struct VariableUse {
+    declared: Vec<String>,
+    used: HashSet<String>,
+}
+
+impl Visitor for VariableUse {
+    fn visit_let_decl(&mut self, name: &str, value: &AstNode) {
+        self.declared.push(name.to_string());
+        self.visit_node(value);
+    }
+
+    fn visit_identifier(&mut self, name: &str) {
+        self.used.insert(name.to_string());
+    }
+}
+
+/// Collects the names of called functions
+#[derive(Default)]
+struct CallCollector {
+    calls: Vec<String>,
+}
+
+impl Visitor for CallCollector {
+    fn visit_function_call(&mut self, name: &str, args: &[AstNode]) {
+        self.calls.push(name.to_string());
+        self.visit_statements(args);
+    }
+}
+
+/// Folds integer arithmetic on two literals
+struct ConstantFolder;
+
+impl VisitorMut for ConstantFolder {
+    fn visit_node(&mut self, node: &mut AstNode) {
+        walk_node_mut(self, node);
+        if let AstNode::BinaryOp { op, left, right } = node {
+            if let (AstNode::IntegerLiteral(a), AstNode::IntegerLiteral(b)) =
+                (left.as_ref(), right.as_ref())
+            {
+                let folded = match op {
+                    BinaryOperator::Add => a.checked_add(*b),
+                    BinaryOperator::Subtract => a.checked_sub(*b),
+                    BinaryOperator::Multiply => a.checked_mul(*b),
+                    _ => None,
+                };
+                if let Some(value) = folded {
+                    *node = AstNode::IntegerLiteral(value);
+                }
+            }
+        }
+    }
+}
+
+/// Test: Hooks see the fields of the node they handle
+#[test]
+fn test_hooks_receive_node_fields() {
+    let ast = parse("let a = 1; let b = a + 2; let unused = 3; println(b)");
+    let mut analysis = VariableUse::default();
+    analysis.visit_ast(&ast);
+
+    assert_eq!(analysis.declared, vec!["a", "b", "unused"]);
+    let unused: Vec<_> = analysis
+        .declared
+        .iter()
+        .filter(|name| !analysis.used.contains(*name))
+        .collect();
+    assert_eq!(unused, vec!["unused"]);
+}
+
+/// Test: The default traversal descends into every kind of body
+#[test]
+fn test_visitor_reaches_nested_nodes() {
+    let source = r#"
+        fun main() {
+            let f = |x| inner(x);
+            for i in 0..3 { looped(i) }
+            while false { waited() }
+            match 1 { 1 => matched(), _ => other() }
+            [listed(), 2].len()
+        }
+    "#;
+    let mut collector = CallCollector::default();
+    collector.visit_ast(&parse(source));
+
+    assert_eq!(
+        collector.calls,
+        vec!["inner", "looped", "waited", "matched", "other", "listed"]
+    );
+}
+
+/// Test: An overridden hook decides whether to visit children
+#[test]
+fn test_overriding_hook_controls_descent() {
+    /// Only sees top-level code
+    #[derive(Default)]
+    struct TopLevelCalls(CallCollector);
+
+    impl Visitor for TopLevelCalls {
+        fn visit_function_def(&mut self, _name: &str, _params: &[String], _body: &[AstNode]) {}
+
+        fn visit_function_call(&mut self, name: &str, args: &[AstNode]) {
+            self.0.visit_function_call(name, args);
+        }
+    }
+
+    let mut visitor = TopLevelCalls::default();
+    visitor.visit_ast(&parse("fun helper() { hidden() } helper()"));
+    assert_eq!(visitor.0.calls, vec!["helper"]);
+}
+
+/// Test: A folding transform rewrites nested expressions without changing results
+#[test]
+fn test_visitor_mut_folds_constants() {
+    let source = "fun area() { let side = 2 + 3; side * (4 * 5) } area()";
+    let mut ast = parse(source);
+    ConstantFolder.visit_ast(&mut ast);
+
+    match &ast.nodes()[0] {
+        AstNode::FunctionDef { body, .. } => {
+            assert_eq!(body[0].emit(), "let side = 5;");
+            assert_eq!(body[1].emit(), "side * 20");
+        }
+        other => panic!("expected FunctionDef, got {:?}", other),
+    }
+
+    let folded = Evaluator::new().eval_program(&ast).unwrap();
+    let original = Evaluator::new().eval_program(&parse(source)).unwrap();
+    assert_eq!(folded, original);
+}
+
+/// Test: Statement-list hooks can remove statements
+#[test]
+fn test_visitor_mut_edits_statement_lists() {
+    /// Drops every println call, at any depth
+    struct StripPrints;
+
+    impl VisitorMut for StripPrints {
+        fn visit_statements(&mut self, statements: &mut Vec<AstNode>) {
+            statements
+                .retain(|s| !matches!(s, AstNode::FunctionCall { name, .. } if name == "println"));
+            for statement in statements.iter_mut() {
+                self.visit_node(statement);
+            }
+        }
+    }
+
+    let mut ast = parse("println(1); let x = 2; if x > 1 { println(x); x } else { 0 }");
+    StripPrints.visit_ast(&mut ast);
+
+    let mut collector = CallCollector::default();
+    collector.visit_ast(&ast);
+    assert!(collector.calls.is_empty(), "{:?}", collector.calls);
+    assert_eq!(ast.nodes().len(), 2);
+}
+
+/// Test: Ast::visit reaches nodes inside loop bodies
+#[test]
+fn test_ast_visit_sees_loop_bodies() {
+    let ast = parse("let n = 0; while n < 3 { n = n + 1; } for i in 0..2 { println(i) }");
+    let mut identifiers = Vec::new();
+    ast.visit(|node| {
+        if let AstNode::Identifier(name) = node {
+            identifiers.push(name.clone());
+        }
+    });
+    assert_eq!(identifiers, vec!["n", "n", "i"]);
+}
@@ -0,0 +1,88 @@
+// INTERP-104: `_` as a Throwaway Binding
+//
+// Mission: `_` evaluates a value for its effects and binds nothing
+// - `let _ = expr` runs expr and discards the result
+// - `_` may appear in parameter lists, any number of times
+// - Tuple destructuring, for loops, and closures accept `_`
+// - `_` is not a readable variable
+//
+// Test Coverage:
+// - test_let_underscore_discards_value: let _ = 5; and side effects
+// - test_underscore_parameters: f(_, x) and repeated `_`
+// - test_underscore_in_destructuring_and_loops: tuples, for, closures
+// - test_underscore_is_not_an_expression: reading `_` is a parse error
+// - test_underscore_parameter_in_jit: both engines agree on f(_, x)
+
+use ruchyruchy::debugger::differential::{run_interpreter, run_jit};
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: let _ evaluates its value and binds nothing
+#[test]
+fn test_let_underscore_discards_value() {
+    assert_eq!(run("let _ = 5;").unwrap(), Value::nil());
+    assert_eq!(run("let _ = 5; let _ = 6; 7").unwrap(), Value::integer(7));
+
+    let ast = Parser::new("fun noisy() { println(\"ran\"); 1 } let _ = noisy();")
+        .parse()
+        .expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    eval.eval_program(&ast).unwrap();
+    assert_eq!(sink.contents().unwrap_or_default(), "ran\n");
+}
+
+/// Test: `_` parameters accept an argument without binding it
+#[test]
+fn test_underscore_parameters() {
+    assert_eq!(run("fun f(_, x) { x } f(1, 2)").unwrap(), Value::integer(2));
+    assert_eq!(
+        run("fun g(_, _, z) { z } g(1, 2, 3)").unwrap(),
+        Value::integer(3)
+    );
+    assert_eq!(
+        run("fun h(_: i32, y: i32) -> i32 { y * 2 } h(9, 4)").unwrap(),
+        Value::integer(8)
+    );
+}
+
+/// Test: Destructuring, loop variables, and closure parameters accept `_`
+#[test]
+fn test_underscore_in_destructuring_and_loops() {
+    assert_eq!(
+        run("let (a, _, _) = (1, 2, 3); a").unwrap(),
+        Value::integer(1)
+    );
+    assert_eq!(
+        run("let n = 0; for _ in 0..4 { n = n + 1; } n").unwrap(),
+        Value::integer(4)
+    );
+    assert_eq!(
+        run("let second = |_, y| y; second(1, 2)").unwrap(),
+        Value::integer(2)
+    );
+}
+
+/// Test: `_` cannot be read back
+#[test]
+fn test_underscore_is_not_an_expression() {
+    assert!(Parser::new("let _ = 1; _ + 1").parse().is_err());
+    assert!(Parser::new("fun f(_) { _ }").parse().is_err());
+}
+
+/// Test: The JIT compiles `_` parameters like the interpreter runs them
+#[test]
+fn test_underscore_parameter_in_jit() {
+    let source = "fun main(_, x) { let _ = x * 10; return x + 1; }";
+    assert_eq!(run_interpreter(source, "main", &[5, 6]), Ok(7));
+    assert_eq!(run_jit(source, "main", &[5, 6]), Ok(7));
+}
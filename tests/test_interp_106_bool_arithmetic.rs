@@ -0,0 +1,108 @@
+// INTERP-106: Configurable Boolean Arithmetic
+//
+// Mission: Make the interpreter's stance on `true + true` explicit
+// - Default (Rust-style): booleans in arithmetic are a TypeMismatch
+// - with_bool_arithmetic (C-style): booleans coerce to 0/1
+// - The JIT stores booleans as 0/1 and matches the C-style mode
+//
+// Test Coverage:
+// - test_default_rejects_bool_arithmetic: every arithmetic operator
+// - test_flag_coerces_bools_to_integers: + - * / %, unary minus, mixed
+// - test_flag_leaves_other_operations_alone: logic and comparisons unchanged
+// - test_jit_matches_c_style_mode: differential run of true + true
+
+use ruchyruchy::debugger::differential::run_jit;
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program with the given evaluator
+fn run_with(mut eval: Evaluator, source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    eval.eval_program(&ast)
+}
+
+/// Test: Without the flag, any boolean arithmetic operand is a type mismatch
+#[test]
+fn test_default_rejects_bool_arithmetic() {
+    for (source, symbol) in [
+        ("true + true", "+"),
+        ("1 - false", "-"),
+        ("true * 3", "*"),
+        ("4 / true", "/"),
+        ("5 % true", "%"),
+        ("-true", "-"),
+    ] {
+        match run_with(Evaluator::new(), source) {
+            Err(EvalError::ValueError(ValueError::TypeMismatch {
+                expected,
+                found,
+                operation,
+            })) => {
+                assert_eq!(expected, "Integer or Float", "{}", source);
+                assert_eq!(found, "Boolean", "{}", source);
+                assert_eq!(operation, format!("arithmetic ({})", symbol), "{}", source);
+            }
+            other => panic!("{}: expected TypeMismatch, got {:?}", source, other),
+        }
+    }
+
+    let err = run_with(Evaluator::new(), "let x = true; x += 1; x").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Type mismatch in arithmetic (+): expected Integer or Float, found Boolean"
+    );
+}
+
+/// Test: With the flag, booleans are 1 and 0
+#[test]
+fn test_flag_coerces_bools_to_integers() {
+    let cases = [
+        ("true + true", 2),
+        ("true - false", 1),
+        ("true * 7", 7),
+        ("6 / true", 6),
+        ("5 % true", 0),
+        ("-true", -1),
+        ("(3 > 2) + (1 > 2) + (2 == 2)", 2),
+        ("let n = 10; n += true; n", 11),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(
+            run_with(Evaluator::new().with_bool_arithmetic(), source).unwrap(),
+            Value::integer(expected),
+            "{}",
+            source
+        );
+    }
+}
+
+/// Test: The flag only affects arithmetic
+#[test]
+fn test_flag_leaves_other_operations_alone() {
+    let eval = || Evaluator::new().with_bool_arithmetic();
+    assert_eq!(
+        run_with(eval(), "true && false").unwrap(),
+        Value::boolean(false)
+    );
+    assert_eq!(
+        run_with(eval(), "true == true").unwrap(),
+        Value::boolean(true)
+    );
+    assert_eq!(run_with(eval(), "!true").unwrap(), Value::boolean(false));
+    assert!(run_with(eval(), "true + \"s\"").is_err());
+}
+
+/// Test: The JIT computes the C-style result
+#[test]
+fn test_jit_matches_c_style_mode() {
+    let source = "fun main(a, b) { return (a < b) + (a < b); }";
+    assert_eq!(run_jit(source, "main", &[1, 2]), Ok(2));
+
+    let call = "fun main(a, b) { return (a < b) + (a < b); } main(1, 2)";
+    assert_eq!(
+        run_with(Evaluator::new().with_bool_arithmetic(), call).unwrap(),
+        Value::integer(2)
+    );
+    assert!(run_with(Evaluator::new(), call).is_err());
+}
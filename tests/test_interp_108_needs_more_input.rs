@@ -0,0 +1,106 @@
+// INTERP-108: REPL Multiline Continuation Detection
+//
+// Mission: A REPL can tell "unfinished" input from "wrong" input
+// - needs_more_input is true for open delimiters and open string literals
+// - ...and for input that ends mid-expression
+// - Complete or already-malformed input is false (the parser reports it)
+//
+// Test Coverage:
+// - test_complete_statement_needs_no_more_input: let, fun, calls
+// - test_open_brace_needs_more_input: fun f() {, nested delimiters
+// - test_open_string_needs_more_input: "..., f"...{
+// - test_trailing_operator_needs_more_input: let x =, 1 +, else
+// - test_delimiters_in_comments_and_literals_are_ignored: // {, "{", '('
+// - test_malformed_input_is_left_to_the_parser: stray closer
+// - test_accumulated_lines_parse_once_complete: REPL loop simulation
+
+use ruchyruchy::interpreter::{needs_more_input, Evaluator, Parser};
+
+/// Test: A complete statement does not ask for continuation
+#[test]
+fn test_complete_statement_needs_no_more_input() {
+    for source in [
+        "",
+        "let x = 1",
+        "let x = 1;",
+        "fun f() { 1 }",
+        "println(\"hi\")",
+        "return",
+    ] {
+        assert!(!needs_more_input(source), "{:?}", source);
+    }
+}
+
+/// Test: An unclosed opening delimiter asks for continuation
+#[test]
+fn test_open_brace_needs_more_input() {
+    for source in [
+        "fun f() {",
+        "fun f() {\n    if x {\n        1\n    }",
+        "let v = [1, 2,",
+        "foo(1, (2",
+    ] {
+        assert!(needs_more_input(source), "{:?}", source);
+    }
+}
+
+/// Test: An unterminated string or f-string asks for continuation
+#[test]
+fn test_open_string_needs_more_input() {
+    assert!(needs_more_input("println(\"hello"));
+    assert!(needs_more_input("let s = \"line one\nline two"));
+    assert!(needs_more_input("let s = f\"total: {x"));
+    assert!(!needs_more_input("let s = f\"total: {x}\""));
+}
+
+/// Test: Input ending in an operator or keyword is unfinished
+#[test]
+fn test_trailing_operator_needs_more_input() {
+    for source in [
+        "let x =",
+        "let y = 1 +",
+        "a &&",
+        "if x { 1 } else",
+        "let f = |x|",
+    ] {
+        assert!(needs_more_input(source), "{:?}", source);
+    }
+}
+
+/// Test: Delimiters inside comments and literals do not count
+#[test]
+fn test_delimiters_in_comments_and_literals_are_ignored() {
+    assert!(!needs_more_input("let x = 1 // {"));
+    assert!(!needs_more_input("let s = \"{ [ (\""));
+    assert!(!needs_more_input("let c = '('"));
+    assert!(needs_more_input("fun f() { // }"));
+}
+
+/// Test: Malformed input is not treated as unfinished
+#[test]
+fn test_malformed_input_is_left_to_the_parser() {
+    assert!(!needs_more_input("}"));
+    assert!(!needs_more_input("fun f() { (1 }"));
+}
+
+/// Test: Lines accumulated until complete parse and run
+#[test]
+fn test_accumulated_lines_parse_once_complete() {
+    let lines = ["fun add(a, b) {", "    a +", "        b", "}"];
+    let mut buffer = String::new();
+    let mut prompts = 0;
+    for line in lines {
+        buffer.push_str(line);
+        buffer.push('\n');
+        if needs_more_input(&buffer) {
+            prompts += 1;
+        }
+    }
+    assert_eq!(prompts, 3);
+    assert!(!needs_more_input(&buffer));
+
+    let source = format!("{}add(2, 3)", buffer);
+    let ast = Parser::new(&source).parse().expect("parse");
+    let result = Evaluator::new().eval_program(&ast).unwrap();
+    assert_eq!(result.to_string(), "5");
+}
@@ -0,0 +1,120 @@
+// INTERP-109: Option/Result Values and std-Style Methods
+//
+// Mission: Idiomatic error handling with real Option/Result values
+// - Some(x), None, Ok(x), Err(e) are prelude enum variants with payloads
+// - map, unwrap_or, unwrap_or_else, expect, is_some/is_none, is_ok/is_err
+// - expect on None/Err panics with the given message
+//
+// Test Coverage:
+// - test_unwrap_or: Some(5).unwrap_or(0) == 5, None.unwrap_or(0) == 0
+// - test_is_predicates: is_some/is_none/is_ok/is_err
+// - test_map_transforms_only_the_present_side: Some/Ok mapped, None/Err kept
+// - test_unwrap_or_else_calls_the_fallback: Option (no args) and Result (error)
+// - test_expect_panics_with_message: None and Err
+// - test_display_and_equality: Some(5), None, Err("e")
+// - test_predicate_of_the_other_enum_is_rejected: Some(1).is_ok()
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    Evaluator::new().eval_program(&ast)
+}
+
+/// Test: unwrap_or returns the payload or the default
+#[test]
+fn test_unwrap_or() {
+    assert_eq!(run("Some(5).unwrap_or(0)").unwrap(), Value::integer(5));
+    assert_eq!(run("None.unwrap_or(0)").unwrap(), Value::integer(0));
+    assert_eq!(run("Ok(1).unwrap_or(2)").unwrap(), Value::integer(1));
+    assert_eq!(run("Err(\"e\").unwrap_or(2)").unwrap(), Value::integer(2));
+}
+
+/// Test: The is_* predicates report which variant is held
+#[test]
+fn test_is_predicates() {
+    assert_eq!(run("Some(1).is_some()").unwrap(), Value::boolean(true));
+    assert_eq!(run("None.is_none()").unwrap(), Value::boolean(true));
+    assert_eq!(
+        run("let r = Ok(1); r.is_err()").unwrap(),
+        Value::boolean(false)
+    );
+    assert_eq!(run("Err(0).is_err()").unwrap(), Value::boolean(true));
+}
+
+/// Test: map applies the closure to Some/Ok and passes None/Err through
+#[test]
+fn test_map_transforms_only_the_present_side() {
+    assert_eq!(
+        run("Some(2).map(|x| x * 10)").unwrap(),
+        Value::some(Value::integer(20))
+    );
+    assert_eq!(run("None.map(|x| x * 10)").unwrap(), Value::none());
+    assert_eq!(
+        run("Ok(2).map(|x| x + 1)").unwrap(),
+        Value::ok(Value::integer(3))
+    );
+    assert_eq!(
+        run("Err(\"bad\").map(|x| x + 1)").unwrap(),
+        Value::err(Value::string("bad".to_string()))
+    );
+}
+
+/// Test: unwrap_or_else runs the fallback closure only when needed
+#[test]
+fn test_unwrap_or_else_calls_the_fallback() {
+    assert_eq!(
+        run("let d = 7; None.unwrap_or_else(|| d * 2)").unwrap(),
+        Value::integer(14)
+    );
+    assert_eq!(
+        run("Some(1).unwrap_or_else(|| missing)").unwrap(),
+        Value::integer(1)
+    );
+    assert_eq!(
+        run("Err(\"disk\").unwrap_or_else(|e| e + \"!\")").unwrap(),
+        Value::string("disk!".to_string())
+    );
+}
+
+/// Test: expect returns the payload or panics with the message
+#[test]
+fn test_expect_panics_with_message() {
+    assert_eq!(
+        run("Some(3).expect(\"need a value\")").unwrap(),
+        Value::integer(3)
+    );
+    match run("None.expect(\"need a value\")") {
+        Err(EvalError::Panic { message }) => assert_eq!(message, "need a value"),
+        other => panic!("expected Panic, got {:?}", other),
+    }
+    let err = run("Err(404).expect(\"fetch failed\")").unwrap_err();
+    assert_eq!(err.to_string(), "panicked: fetch failed: 404");
+}
+
+/// Test: Option/Result values print like Rust and compare by payload
+#[test]
+fn test_display_and_equality() {
+    assert_eq!(run("Some(5)").unwrap().to_string(), "Some(5)");
+    assert_eq!(run("None").unwrap().to_string(), "None");
+    assert_eq!(run("Ok(Some(1))").unwrap().to_string(), "Ok(Some(1))");
+    assert_eq!(run("Some(1) == Some(1)").unwrap(), Value::boolean(true));
+    assert_eq!(run("Some(1) == Some(2)").unwrap(), Value::boolean(false));
+    assert_eq!(run("Some(1) == None").unwrap(), Value::boolean(false));
+}
+
+/// Test: Result predicates are not methods of Option (and vice versa)
+#[test]
+fn test_predicate_of_the_other_enum_is_rejected() {
+    assert!(matches!(
+        run("Some(1).is_ok()"),
+        Err(EvalError::UnsupportedOperation { .. })
+    ));
+    assert!(matches!(
+        run("Ok(1).is_some()"),
+        Err(EvalError::UnsupportedOperation { .. })
+    ));
+}
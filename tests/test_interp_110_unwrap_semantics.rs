@@ -0,0 +1,84 @@
+// INTERP-110: unwrap Respects Option/Result Semantics
+//
+// Mission: unwrap never silently "succeeds" on a missing value
+// - Some(x).unwrap() / Ok(x).unwrap() return the payload
+// - None.unwrap() / Err(e).unwrap() panic, naming the error
+// - The legacy pass-through stays for the concurrency mock wrappers
+//
+// Test Coverage:
+// - test_unwrap_some_and_ok_return_payload: Some(5), Ok("v")
+// - test_unwrap_none_panics: None.unwrap()
+// - test_unwrap_err_panics_with_error: Err("boom").unwrap()
+// - test_unwrap_panic_stops_the_program: later statements do not run
+// - test_mock_wrapper_unwrap_still_passes_through: Mutex lock().unwrap()
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+use ruchyruchy::interpreter::OutputSink;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    Evaluator::new().eval_program(&ast)
+}
+
+/// Test: unwrap on Some/Ok yields the wrapped value
+#[test]
+fn test_unwrap_some_and_ok_return_payload() {
+    assert_eq!(run("Some(5).unwrap()").unwrap(), Value::integer(5));
+    assert_eq!(
+        run("let r = Ok(\"v\"); r.unwrap()").unwrap(),
+        Value::string("v".to_string())
+    );
+    assert_eq!(run("Some(5).unwrap() + 1").unwrap(), Value::integer(6));
+}
+
+/// Test: unwrap on None panics instead of returning the receiver
+#[test]
+fn test_unwrap_none_panics() {
+    match run("None.unwrap()") {
+        Err(EvalError::Panic { message }) => {
+            assert_eq!(message, "called `unwrap()` on a `None` value")
+        }
+        other => panic!("expected Panic, got {:?}", other),
+    }
+}
+
+/// Test: unwrap on Err panics with the contained error
+#[test]
+fn test_unwrap_err_panics_with_error() {
+    let err = run("Err(\"boom\").unwrap()").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "panicked: called `unwrap()` on an `Err` value: \"boom\""
+    );
+}
+
+/// Test: A panicking unwrap stops execution
+#[test]
+fn test_unwrap_panic_stops_the_program() {
+    let sink = OutputSink::buffer();
+    let ast = Parser::new("println(\"before\")\nlet x = None.unwrap()\nprintln(\"after\")")
+        .parse()
+        .unwrap();
+    let result = Evaluator::new()
+        .with_output(sink.clone())
+        .eval_program(&ast);
+    assert!(
+        matches!(result, Err(EvalError::Panic { .. })),
+        "{:?}",
+        result
+    );
+    assert_eq!(sink.contents().unwrap(), "before\n");
+}
+
+/// Test: Mock concurrency wrappers keep the legacy unwrap pass-through
+#[test]
+fn test_mock_wrapper_unwrap_still_passes_through() {
+    let source = "use std::sync::Mutex;
+let m = Mutex::new(41);
+let v = m.lock().unwrap();
+v + 1";
+    assert_eq!(run(source).unwrap(), Value::integer(42));
+}
@@ -0,0 +1,88 @@
+// INTERP-111: Binary-Safe File I/O (read_bytes, write_bytes)
+//
+// Mission: Programs can write and read exact bytes, not just strings
+// - write_bytes(path, vector) writes a vector of 0-255 integers as raw bytes
+// - read_bytes(path) returns the file contents as a vector of integers
+// - Out-of-range values are rejected, naming the value and its index
+//
+// Test Coverage:
+// - test_write_then_read_bytes_round_trip: non-UTF-8 bytes survive a round trip
+// - test_write_bytes_matches_file_contents: the file holds exactly those bytes
+// - test_write_bytes_rejects_out_of_range: 256 and -1 are errors, file untouched
+// - test_read_bytes_missing_file: file not found is an error
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+use std::fs;
+use std::path::PathBuf;
+
+/// Helper: a temp file path unique to this test file
+fn temp_file_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("ruchy_test_interp_111_{}", name))
+}
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    Evaluator::new().eval_program(&ast)
+}
+
+/// Test: Bytes written with write_bytes read back unchanged
+#[test]
+fn test_write_then_read_bytes_round_trip() {
+    let path = temp_file_path("round_trip.bin");
+    let source = format!(
+        "write_bytes(\"{0}\", [0, 1, 127, 128, 200, 255])\nread_bytes(\"{0}\")",
+        path.display()
+    );
+    let result = run(&source);
+    let _ = fs::remove_file(&path);
+
+    let expected = Value::vector(
+        [0, 1, 127, 128, 200, 255]
+            .into_iter()
+            .map(Value::integer)
+            .collect(),
+    );
+    assert_eq!(result.unwrap(), expected);
+}
+
+/// Test: The written file contains exactly the given bytes
+#[test]
+fn test_write_bytes_matches_file_contents() {
+    let path = temp_file_path("contents.bin");
+    let result = run(&format!(
+        "write_bytes(\"{}\", [137, 80, 78, 71])",
+        path.display()
+    ));
+    let contents = fs::read(&path);
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(result.unwrap(), Value::nil());
+    assert_eq!(contents.unwrap(), vec![137, 80, 78, 71]);
+}
+
+/// Test: Values outside 0-255 are rejected with their value and index
+#[test]
+fn test_write_bytes_rejects_out_of_range() {
+    let path = temp_file_path("out_of_range.bin");
+
+    let err = run(&format!("write_bytes(\"{}\", [1, 2, 256])", path.display())).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("byte value 256 at index 2"), "{}", message);
+
+    let err = run(&format!("write_bytes(\"{}\", [-1])", path.display())).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("byte value -1 at index 0"), "{}", message);
+
+    assert!(!path.exists(), "nothing should be written on error");
+}
+
+/// Test: Reading a file that does not exist is an error
+#[test]
+fn test_read_bytes_missing_file() {
+    let path = temp_file_path("does_not_exist.bin");
+    let err = run(&format!("read_bytes(\"{}\")", path.display())).unwrap_err();
+    assert!(err.to_string().contains("read_bytes"), "{}", err);
+}
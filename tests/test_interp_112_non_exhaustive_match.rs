@@ -0,0 +1,88 @@
+// INTERP-112: Runtime Exhaustiveness for Enum Matches
+//
+// Mission: A match that misses an enum variant says which one
+// - Matching an enum value with no arm for its variant (and no wildcard)
+//   fails with EvalError::NonExhaustiveMatch naming the enum and variant
+// - Non-enum values keep the generic NoMatchArm error
+// - Wildcard and identifier arms still catch every variant
+//
+// Test Coverage:
+// - test_missing_variant_arm_is_non_exhaustive: Color::Blue falls through
+// - test_non_exhaustive_message_names_variant: Display text
+// - test_covered_variants_still_match: matching variants are unaffected
+// - test_wildcard_covers_missing_variant: `_` arm prevents the error
+// - test_non_enum_values_keep_no_match_arm: integers are not enums
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Enum with three variants, matched by arms covering only two
+const COLORS: &str = "enum Color { Red, Green, Blue }\n\
+                      fun code(c) { match c { Color::Red => 1, Color::Green => 2 } }\n";
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: A variant without an arm fails with NonExhaustiveMatch
+#[test]
+fn test_missing_variant_arm_is_non_exhaustive() {
+    let err = run("enum Color { Red, Green, Blue }\n\
+                   let c = Color::Blue;\n\
+                   match c { Color::Red => 1, Color::Green => 2 }")
+    .unwrap_err();
+    match err {
+        EvalError::NonExhaustiveMatch {
+            enum_name,
+            unmatched_variant,
+        } => {
+            assert_eq!(enum_name, "Color");
+            assert_eq!(unmatched_variant, "Blue");
+        }
+        other => panic!("expected NonExhaustiveMatch, got {:?}", other),
+    }
+}
+
+/// Test: The error message names the uncovered variant
+#[test]
+fn test_non_exhaustive_message_names_variant() {
+    let err = run(&format!("{}code(Color::Blue)", COLORS)).unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("non-exhaustive match: Color::Blue not covered"),
+        "{}",
+        message
+    );
+}
+
+/// Test: Variants that have arms still match normally
+#[test]
+fn test_covered_variants_still_match() {
+    assert_eq!(
+        run(&format!("{}code(Color::Red)", COLORS)).unwrap(),
+        Value::integer(1)
+    );
+    assert_eq!(
+        run(&format!("{}code(Color::Green)", COLORS)).unwrap(),
+        Value::integer(2)
+    );
+}
+
+/// Test: A wildcard arm covers any remaining variant
+#[test]
+fn test_wildcard_covers_missing_variant() {
+    let source = "enum Color { Red, Green, Blue }\n\
+                  match Color::Blue { Color::Red => 1, _ => 0 }";
+    assert_eq!(run(source).unwrap(), Value::integer(0));
+}
+
+/// Test: Non-enum scrutinees keep the generic NoMatchArm error
+#[test]
+fn test_non_enum_values_keep_no_match_arm() {
+    let err = run("match 99 { 1 => 10, 2 => 20 }").unwrap_err();
+    assert!(matches!(err, EvalError::NoMatchArm), "{:?}", err);
+}
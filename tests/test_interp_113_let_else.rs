@@ -0,0 +1,134 @@
+// INTERP-113: let-else for Refutable Bindings
+//
+// Mission: `let Some(x) = opt else { return };` binds or leaves the block
+// - Parses into AstNode::LetElse { pattern, value, else_block }
+// - Variant patterns Some(x), Ok(v), Err(e) and None match Option/Result values
+// - On a match the pattern's variables are bound in the enclosing scope
+// - Otherwise the else block runs; it must return, break, continue or panic
+// - An else block that can fall through is a parse (static) error
+//
+// Test Coverage:
+// - test_let_else_parses_to_let_else_node: AST shape
+// - test_let_else_binds_on_match: Some(21) binds x
+// - test_let_else_runs_else_block_on_mismatch: None returns early
+// - test_let_else_with_result_and_break: Ok(v) in a loop, break on Err
+// - test_let_else_panic_in_else_block: panic(..) diverges
+// - test_let_else_non_diverging_else_is_error: fall-through rejected
+// - test_variant_patterns_in_match: Some(x) / None arms
+
+use ruchyruchy::interpreter::ast::{AstNode, Pattern};
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Function that doubles the payload of an Option, or returns -1
+const DOUBLE: &str = "fun double(o) {\n\
+                          let Some(x) = o else { return -1; };\n\
+                          x * 2\n\
+                      }\n";
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: let-else parses into a LetElse node with a variant pattern
+#[test]
+fn test_let_else_parses_to_let_else_node() {
+    let ast = Parser::new("let Some(x) = o else { return 0; }")
+        .parse()
+        .expect("parse");
+    match &ast.nodes()[0] {
+        AstNode::LetElse {
+            pattern,
+            value,
+            else_block,
+        } => {
+            assert_eq!(
+                **pattern,
+                Pattern::Variant {
+                    name: "Some".to_string(),
+                    payload: Some(Box::new(Pattern::Identifier("x".to_string()))),
+                }
+            );
+            assert_eq!(**value, AstNode::Identifier("o".to_string()));
+            assert_eq!(else_block.len(), 1);
+        }
+        other => panic!("expected LetElse, got {:?}", other),
+    }
+}
+
+/// Test: A matching value binds the pattern variable after the statement
+#[test]
+fn test_let_else_binds_on_match() {
+    let result = run(&format!("{}double(Some(21))", DOUBLE));
+    assert_eq!(result.unwrap(), Value::integer(42));
+}
+
+/// Test: A non-matching value runs the else block
+#[test]
+fn test_let_else_runs_else_block_on_mismatch() {
+    let result = run(&format!("{}double(None)", DOUBLE));
+    assert_eq!(result.unwrap(), Value::integer(-1));
+}
+
+/// Test: Result patterns work, and break leaves the enclosing loop
+#[test]
+fn test_let_else_with_result_and_break() {
+    let source = "let results = [Ok(1), Ok(2), Err(\"bad\"), Ok(4)];\n\
+                  let mut total = 0;\n\
+                  for r in results {\n\
+                      let Ok(v) = r else { break; };\n\
+                      total = total + v;\n\
+                  }\n\
+                  total";
+    assert_eq!(run(source).unwrap(), Value::integer(3));
+}
+
+/// Test: panic(..) is an accepted way for the else block to diverge
+#[test]
+fn test_let_else_panic_in_else_block() {
+    let err = run("let o = None;\nlet Some(x) = o else { panic(\"no value\") };\nx").unwrap_err();
+    match err {
+        EvalError::Panic { message } => assert_eq!(message, "no value"),
+        other => panic!("expected Panic, got {:?}", other),
+    }
+}
+
+/// Test: An else block that can fall through is rejected when parsing
+#[test]
+fn test_let_else_non_diverging_else_is_error() {
+    for source in [
+        "let Some(x) = o else { println(\"missing\") };",
+        "let Some(x) = o else { };",
+        "let Some(x) = o else { if y { return 1; } };",
+    ] {
+        let err = Parser::new(source).parse().unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("let-else block must diverge"),
+            "{}: {:?}",
+            source,
+            err
+        );
+    }
+
+    // Both branches of an if/else diverging is enough
+    assert!(
+        Parser::new("let Some(x) = o else { if y { return 1; } else { return 2; } };")
+            .parse()
+            .is_ok()
+    );
+}
+
+/// Test: Variant patterns also work as match arms
+#[test]
+fn test_variant_patterns_in_match() {
+    let source = "fun unwrap_or_zero(o) { match o { Some(x) => x, None => 0 } }\n\
+                  unwrap_or_zero(Some(7)) + unwrap_or_zero(None)";
+    assert_eq!(run(source).unwrap(), Value::integer(7));
+
+    let source = "match Err(\"e\") { Ok(v) => v, Err(e) => e }";
+    assert_eq!(run(source).unwrap(), Value::string("e".to_string()));
+}
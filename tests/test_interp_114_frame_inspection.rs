@@ -0,0 +1,123 @@
+// INTERP-114: Frame Scope Inspection for the Debugger Backtrace
+//
+// Mission: A backtrace shows every active frame with its variables
+// - with_frame_inspection keeps each caller's scope alongside call_stack
+// - pause_at_line captures the frames when a source line is reached
+// - The REPL debugger's :breakat / :backtrace render them per frame
+// - Off by default: no frames are kept or captured
+//
+// Test Coverage:
+// - test_debugger_reads_n_in_each_recursive_frame: fact(3) paused at its base case
+// - test_frames_record_call_lines_and_locals: let bindings and call sites
+// - test_inspection_is_off_by_default: nothing captured without opting in
+// - test_frames_unwind_after_error: failed calls leave no stale frames
+
+use ruchyruchy::debugger::{DebugCommand, DebugSession};
+use ruchyruchy::interpreter::{Evaluator, Parser, Value};
+
+/// Recursive factorial; line 3 is the base case
+const FACT: &str = "fun fact(n) {\n\
+                    if n <= 1 {\n\
+                    return 1;\n\
+                    }\n\
+                    n * fact(n - 1)\n\
+                    }\n\
+                    let result = fact(3);";
+
+/// Test: Paused inside the deepest call, each frame shows its own `n`
+#[test]
+fn test_debugger_reads_n_in_each_recursive_frame() {
+    let mut session = DebugSession::new(FACT).expect("session");
+    session.execute_command(DebugCommand::BreakAt(3)).unwrap();
+
+    let hit = session.execute_command(DebugCommand::Continue).unwrap();
+    assert_eq!(hit, "Breakpoint hit at source line 3 in fact");
+
+    let frames = session.paused_frames().expect("paused frames");
+    let ns: Vec<&Value> = frames.iter().map(|f| f.variable("n").unwrap()).collect();
+    assert_eq!(
+        ns,
+        [&Value::integer(3), &Value::integer(2), &Value::integer(1)]
+    );
+
+    let backtrace = session.execute_command(DebugCommand::Backtrace).unwrap();
+    assert_eq!(
+        backtrace,
+        "Call stack at source line 3:\n  #0: fact(n = 3)\n  #1: fact(n = 2)\n  #2: fact(n = 1)\n"
+    );
+}
+
+/// Test: Frames carry their call-site line and their let bindings
+#[test]
+fn test_frames_record_call_lines_and_locals() {
+    let source = "fun inner(x) {\n\
+                  let doubled = x * 2;\n\
+                  doubled + 1\n\
+                  }\n\
+                  fun outer(a) {\n\
+                  let b = a + 1;\n\
+                  inner(b)\n\
+                  }\n\
+                  outer(4)";
+    let ast = Parser::new(source).with_spans().parse().unwrap();
+    let mut eval = Evaluator::new().with_frame_inspection();
+    eval.pause_at_line(3);
+    assert_eq!(eval.eval_program(&ast).unwrap(), Value::integer(11));
+
+    let (line, frames) = eval.take_paused_frames().expect("paused");
+    assert_eq!(line, 3);
+    assert_eq!(frames.len(), 2);
+
+    assert_eq!(frames[0].function, "outer");
+    assert_eq!(frames[0].call_line, Some(9));
+    assert_eq!(
+        frames[0].variables,
+        vec![
+            ("a".to_string(), Value::integer(4)),
+            ("b".to_string(), Value::integer(5)),
+        ]
+    );
+
+    assert_eq!(frames[1].function, "inner");
+    assert_eq!(frames[1].call_line, Some(7));
+    assert_eq!(frames[1].variable("doubled"), Some(&Value::integer(10)));
+}
+
+/// Test: Without opting in, no frames are kept or captured
+#[test]
+fn test_inspection_is_off_by_default() {
+    let ast = Parser::new(FACT).with_spans().parse().unwrap();
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).unwrap();
+    assert!(eval.frames().is_empty());
+    assert!(eval.take_paused_frames().is_none());
+}
+
+/// Test: A call that fails does not leave its scope on the frame stack
+#[test]
+fn test_frames_unwind_after_error() {
+    let source = "fun boom(n) {\n\
+                  missing + n\n\
+                  }\n\
+                  fun ok(m) {\n\
+                  m\n\
+                  }";
+    let ast = Parser::new(source).with_spans().parse().unwrap();
+    let mut eval = Evaluator::new().with_frame_inspection();
+    eval.eval_program(&ast).unwrap();
+
+    let call = Parser::new("boom(1)").with_spans().parse().unwrap();
+    assert!(eval.eval_program(&call).is_err());
+    assert!(eval.frames().is_empty());
+
+    // The next call's frame reads its own scope, not boom's
+    eval.pause_at_line(5);
+    let call = Parser::new("ok(7)").with_spans().parse().unwrap();
+    eval.eval_program(&call).unwrap();
+    let (_, frames) = eval.take_paused_frames().expect("paused");
+    assert_eq!(frames.len(), 1);
+    assert_eq!(
+        frames[0].variables,
+        vec![("m".to_string(), Value::integer(7))]
+    );
+}
@@ -0,0 +1,111 @@
+// INTERP-115: #[test] Functions
+//
+// Mission: Ruchy programs can carry their own test suites
+// - `#[test]` marks a zero-parameter function as a test
+// - run_tests() discovers every test and reports pass/fail
+// - Normal evaluation skips test functions
+//
+// Test Coverage:
+// - test_discovers_passing_and_failing_tests: one pass, one fail
+// - test_tests_see_program_functions: tests call helpers defined alongside
+// - test_runtime_errors_fail_the_test: panic and undefined names
+// - test_test_with_parameters_is_rejected: parse error
+// - test_normal_evaluation_skips_tests: test body never runs
+// - test_unknown_attributes_are_ignored: #[inline] fun still defined
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::run_tests;
+use ruchyruchy::interpreter::value::Value;
+
+/// Test: Two #[test] functions are discovered, one passing and one failing
+#[test]
+fn test_discovers_passing_and_failing_tests() {
+    let source = r#"
+#[test]
+fun addition_works() {
+    assert_eq(1 + 1, 2)
+}
+
+#[test]
+fun subtraction_is_broken() {
+    assert_eq(5 - 3, 3)
+}
+"#;
+    let results = run_tests(source).unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].name, "addition_works");
+    assert!(results[0].passed);
+    assert_eq!(results[0].failure, None);
+    assert_eq!(results[1].name, "subtraction_is_broken");
+    assert!(!results[1].passed);
+    let failure = results[1].failure.as_deref().unwrap();
+    assert!(failure.contains("5 - 3 == 3"), "{}", failure);
+}
+
+/// Test: Test bodies can call functions defined in the program
+#[test]
+fn test_tests_see_program_functions() {
+    let source = r#"
+fun double(n) { n * 2 }
+
+#[test]
+fun double_of_ten() {
+    assert_eq(double(10), 20)
+}
+"#;
+    let results = run_tests(source).unwrap();
+    assert!(results[0].passed, "{:?}", results[0].failure);
+}
+
+/// Test: Errors other than failed assertions also fail the test
+#[test]
+fn test_runtime_errors_fail_the_test() {
+    let source = r#"
+#[test]
+fun panics() { panic("boom") }
+
+#[test]
+fun typo() { undefined_name + 1 }
+"#;
+    let results = run_tests(source).unwrap();
+    assert!(results.iter().all(|r| !r.passed));
+    assert!(results[0].failure.as_deref().unwrap().contains("boom"));
+    assert!(results[1]
+        .failure
+        .as_deref()
+        .unwrap()
+        .contains("undefined_name"));
+}
+
+/// Test: A test function that takes parameters is a parse error
+#[test]
+fn test_test_with_parameters_is_rejected() {
+    let err = run_tests("#[test]\nfun needs_arg(x) { assert(x) }").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("test function 'needs_arg' must not take parameters"),
+        "{}",
+        err
+    );
+}
+
+/// Test: Evaluating the program normally does not run its tests
+#[test]
+fn test_normal_evaluation_skips_tests() {
+    let source = "#[test]\nfun always_fails() { assert(false) }\n42";
+    let ast = Parser::new(source).parse().unwrap();
+    let mut eval = Evaluator::new();
+    assert_eq!(eval.eval_program(&ast).unwrap(), Value::Integer(42));
+}
+
+/// Test: Attributes other than #[test] leave the function as a normal one
+#[test]
+fn test_unknown_attributes_are_ignored() {
+    let source = "#[inline]\nfun seven() { 7 }\nseven()";
+    let ast = Parser::new(source).parse().unwrap();
+    let mut eval = Evaluator::new();
+    assert_eq!(eval.eval_program(&ast).unwrap(), Value::Integer(7));
+    assert!(run_tests(source).unwrap().is_empty());
+}
@@ -0,0 +1,104 @@
+// INTERP-116: Meta-Evaluation Depth Limit
+//
+// Mission: Re-entrant evaluation cannot recurse without bound
+// - F-string holes and eval_str share one meta-evaluation depth counter
+// - Nesting past the limit raises EvalError::MetaRecursionLimit
+// - The counter unwinds on error, so the evaluator stays usable
+//
+// Test Coverage:
+// - test_self_referential_fstring_hits_limit: eval_str of an f-string that evals itself
+// - test_nested_fstring_literals_hit_limit: f-strings nested in source past the limit
+// - test_moderate_nesting_is_allowed: a few levels evaluate normally
+// - test_depth_resets_after_limit_error: later evaluation still works
+// - test_limit_error_message: names the limit and both constructs
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Self-referential source: `src` is `f"{eval_str(src)}"`, which evals itself
+const SELF_REFERENTIAL: &str = r#"
+let q = '"'.to_string();
+let src = "f" + q + "{eval_str(src)}" + q;
+eval_str(src)
+"#;
+
+/// Helper: parse and evaluate a program in the given evaluator
+fn run_in(eval: &mut Evaluator, source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    eval.eval_program(&ast)
+}
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    run_in(&mut Evaluator::new(), source)
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Helper: `depth` f-strings nested inside each other around `1`
+fn nested_fstring(depth: usize) -> String {
+    (0..depth).fold("1".to_string(), |inner, _| format!("f\"{{{}}}\"", inner))
+}
+
+/// Test: A deeply self-referential f-string trips the limit
+#[test]
+fn test_self_referential_fstring_hits_limit() {
+    let err = root_error(run(SELF_REFERENTIAL).unwrap_err());
+    assert!(
+        matches!(err, EvalError::MetaRecursionLimit { .. }),
+        "{:?}",
+        err
+    );
+}
+
+/// Test: F-strings nested directly in the source share the same limit
+#[test]
+fn test_nested_fstring_literals_hit_limit() {
+    let err = root_error(run(&nested_fstring(40)).unwrap_err());
+    assert!(
+        matches!(err, EvalError::MetaRecursionLimit { .. }),
+        "{:?}",
+        err
+    );
+}
+
+/// Test: A few levels of nesting evaluate normally
+#[test]
+fn test_moderate_nesting_is_allowed() {
+    assert_eq!(
+        run(&nested_fstring(5)).unwrap(),
+        Value::string("1".to_string())
+    );
+    assert_eq!(
+        run(r#"let inner = "1 + 1"; eval_str("eval_str(inner)")"#).unwrap(),
+        Value::Integer(2)
+    );
+}
+
+/// Test: Hitting the limit leaves the evaluator usable
+#[test]
+fn test_depth_resets_after_limit_error() {
+    let mut eval = Evaluator::new();
+    assert!(run_in(&mut eval, SELF_REFERENTIAL).is_err());
+    assert_eq!(
+        run_in(&mut eval, &nested_fstring(5)).unwrap(),
+        Value::string("1".to_string())
+    );
+}
+
+/// Test: The error message names the limit and what nested too deeply
+#[test]
+fn test_limit_error_message() {
+    let err = root_error(run(SELF_REFERENTIAL).unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "meta-evaluation depth limit (16) exceeded: f-string holes or eval_str nested too deeply"
+    );
+}
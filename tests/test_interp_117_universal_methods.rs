@@ -0,0 +1,86 @@
+// INTERP-117: Universal to_string and type_name Methods
+//
+// Mission: Reflective methods work the same on every value
+// - `.to_string()` renders any value as println would
+// - `.type_name()` returns the type name used in error messages
+// - Neither errors for any receiver type
+//
+// Test Coverage:
+// - test_integer_to_string: (5).to_string() == "5"
+// - test_vector_type_name: [1].type_name() == "Vector"
+// - test_to_string_on_every_type: scalars, collections, Option, enum
+// - test_type_name_on_every_type: one name per value kind
+// - test_methods_compose: to_string().type_name() is String
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: evaluate a program expected to produce a string
+fn string(source: &str) -> String {
+    match run(source) {
+        Ok(value) => value.as_string().unwrap().to_string(),
+        Err(e) => panic!("{}: {}", source, e),
+    }
+}
+
+/// Test: An integer converts to its decimal text
+#[test]
+fn test_integer_to_string() {
+    assert_eq!(string("(5).to_string()"), "5");
+}
+
+/// Test: A vector reports its type as "Vector"
+#[test]
+fn test_vector_type_name() {
+    assert_eq!(string("[1].type_name()"), "Vector");
+}
+
+/// Test: to_string matches println's rendering for every kind of value
+#[test]
+fn test_to_string_on_every_type() {
+    let cases = [
+        ("3.5.to_string()", "3.5"),
+        ("true.to_string()", "true"),
+        ("\"hi\".to_string()", "hi"),
+        ("[1, 2].to_string()", "[1, 2]"),
+        ("(1, \"a\").to_string()", "(1, a)"),
+        ("Some(1).to_string()", "Some(1)"),
+        ("None.to_string()", "None"),
+        ("enum Color { Red }\nColor::Red.to_string()", "Color::Red"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(string(source), expected, "{}", source);
+    }
+}
+
+/// Test: type_name never errors and names each kind of value
+#[test]
+fn test_type_name_on_every_type() {
+    let cases = [
+        ("(5).type_name()", "Integer"),
+        ("2.0.type_name()", "Float"),
+        ("\"s\".type_name()", "String"),
+        ("false.type_name()", "Boolean"),
+        ("(1, 2).type_name()", "Tuple"),
+        ("{\"k\": 1}.type_name()", "HashMap"),
+        ("Ok(1).type_name()", "Enum"),
+        ("let f = |x| x\nf.type_name()", "Closure"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(string(source), expected, "{}", source);
+    }
+}
+
+/// Test: The methods chain like any other
+#[test]
+fn test_methods_compose() {
+    assert_eq!(string("[1, 2].len().to_string().type_name()"), "String");
+}
@@ -0,0 +1,91 @@
+// INTERP-118: Block Comments
+//
+// Mission: `/* ... */` comments are skipped by the tokenizer
+// - Single-line and multi-line block comments produce no tokens
+// - Block comments nest: /* outer /* inner */ still outer */
+// - An unterminated block comment is a ParseError::InvalidSyntax
+//
+// Test Coverage:
+// - test_single_line_block_comment: inline between tokens
+// - test_multi_line_block_comment: spans lines inside a function
+// - test_nested_block_comment: inner */ does not end the outer comment
+// - test_comment_markers_in_strings_are_text: "/* not a comment */"
+// - test_unterminated_block_comment_is_error: reports where it started
+// - test_unterminated_block_comment_needs_more_input: REPL keeps reading
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::{needs_more_input, ParseError, Parser};
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).expect("eval")
+}
+
+/// Test: A block comment on one line is skipped
+#[test]
+fn test_single_line_block_comment() {
+    assert_eq!(run("let x = /* the answer */ 42; x"), Value::Integer(42));
+    assert_eq!(run("1 + /**/ 2"), Value::Integer(3));
+}
+
+/// Test: A block comment may span several lines
+#[test]
+fn test_multi_line_block_comment() {
+    let source = "fun double(n) {
+    /*
+     * Multiply by two.
+     * Works for negatives * too.
+     */
+    n * 2
+}
+double(21)";
+    assert_eq!(run(source), Value::Integer(42));
+}
+
+/// Test: Nested block comments close only at the matching */
+#[test]
+fn test_nested_block_comment() {
+    assert_eq!(
+        run("/* outer /* inner */ still outer */ 7"),
+        Value::Integer(7)
+    );
+    assert_eq!(run("/* a /* b /* c */ */ */ 8"), Value::Integer(8));
+}
+
+/// Test: Comment markers inside a string literal are ordinary text
+#[test]
+fn test_comment_markers_in_strings_are_text() {
+    assert_eq!(
+        run("\"/* not a comment */\""),
+        Value::string("/* not a comment */".to_string())
+    );
+}
+
+/// Test: An unterminated block comment is an error at its opening
+#[test]
+fn test_unterminated_block_comment_is_error() {
+    let err = Parser::new("let x = 1\n  /* never /* closed */\nx")
+        .parse()
+        .unwrap_err();
+    match err {
+        ParseError::InvalidSyntax {
+            message,
+            line,
+            column,
+        } => {
+            assert_eq!(message, "unterminated block comment");
+            assert_eq!((line, column), (2, 3));
+        }
+        other => panic!("expected InvalidSyntax, got {:?}", other),
+    }
+}
+
+/// Test: The REPL waits for more input inside an open block comment
+#[test]
+fn test_unterminated_block_comment_needs_more_input() {
+    assert!(needs_more_input("let x = 1 /* still"));
+    assert!(!needs_more_input("let x = 1 /* done */"));
+}
@@ -0,0 +1,140 @@
+// INTERP-119: break and continue in Interpreter Loops
+//
+// Mission: Loops can exit early or skip ahead in every loop form
+// - `break` stops the innermost for/while/repeat-until loop; the loop is nil
+// - `continue` skips the rest of the body and starts the next iteration
+// - A break/continue outside any loop is EvalError::LoopJumpOutsideLoop
+//
+// Test Coverage:
+// - test_break_stops_for_loop: remaining elements are not visited
+// - test_break_makes_loop_nil: the loop expression itself is nil
+// - test_continue_skips_rest_of_body: odd numbers summed
+// - test_while_and_repeat_until: both loop forms honor break/continue
+// - test_nested_loops_jump_innermost_only: outer loop keeps running
+// - test_break_inside_if_in_loop: jumps through nested blocks
+// - test_jump_outside_loop_is_error: top level and function body
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Test: break ends a for loop before the remaining elements
+#[test]
+fn test_break_stops_for_loop() {
+    let source = "let mut seen = 0
+for i in 0..10 {
+    if i == 3 { break }
+    seen = seen + 1
+}
+seen";
+    assert_eq!(run(source).unwrap(), Value::Integer(3));
+}
+
+/// Test: A loop exited with break evaluates to nil
+#[test]
+fn test_break_makes_loop_nil() {
+    assert_eq!(run("for i in [1, 2, 3] { break }").unwrap(), Value::nil());
+}
+
+/// Test: continue skips the statements after it
+#[test]
+fn test_continue_skips_rest_of_body() {
+    let source = "let mut total = 0
+for i in 1..=6 {
+    if i % 2 == 0 { continue }
+    total = total + i
+}
+total";
+    assert_eq!(run(source).unwrap(), Value::Integer(9));
+}
+
+/// Test: while and repeat-until support break and continue too
+#[test]
+fn test_while_and_repeat_until() {
+    let while_source = "let mut i = 0
+let mut total = 0
+while true {
+    i = i + 1
+    if i > 5 { break }
+    if i == 2 { continue }
+    total = total + i
+}
+total";
+    assert_eq!(run(while_source).unwrap(), Value::Integer(13));
+
+    let repeat_source = "let mut i = 0
+repeat {
+    i = i + 1
+    if i == 4 { break }
+} until i > 100
+i";
+    assert_eq!(run(repeat_source).unwrap(), Value::Integer(4));
+}
+
+/// Test: A jump in an inner loop leaves the outer loop running
+#[test]
+fn test_nested_loops_jump_innermost_only() {
+    let source = "let mut pairs = 0
+for i in 0..3 {
+    for j in 0..3 {
+        if j > i { break }
+        pairs = pairs + 1
+    }
+}
+pairs";
+    assert_eq!(run(source).unwrap(), Value::Integer(6));
+}
+
+/// Test: break inside nested blocks still reaches the loop
+#[test]
+fn test_break_inside_if_in_loop() {
+    let source = "fun first_over(v, limit) {
+    let mut found = -1
+    for x in v {
+        if x > limit {
+            if found == -1 {
+                found = x
+                break
+            }
+        }
+    }
+    found
+}
+first_over([1, 8, 3, 9], 5)";
+    assert_eq!(run(source).unwrap(), Value::Integer(8));
+}
+
+/// Test: break/continue with no enclosing loop is a clear error
+#[test]
+fn test_jump_outside_loop_is_error() {
+    match run("break") {
+        Err(EvalError::LoopJumpOutsideLoop { keyword }) => assert_eq!(keyword, "break"),
+        other => panic!("expected LoopJumpOutsideLoop, got {:?}", other),
+    }
+
+    // A loop in the caller does not catch a jump from a called function
+    let err = root_error(run("fun skip() { continue }\nfor i in 0..2 { skip() }").unwrap_err());
+    match err {
+        EvalError::LoopJumpOutsideLoop { keyword } => assert_eq!(keyword, "continue"),
+        other => panic!("expected LoopJumpOutsideLoop, got {:?}", other),
+    }
+    assert_eq!(
+        run("break").unwrap_err().to_string(),
+        "'break' outside of a loop"
+    );
+}
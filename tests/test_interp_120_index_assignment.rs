@@ -0,0 +1,113 @@
+// INTERP-120: Index Assignment
+//
+// Mission: Collection elements can be written with `v[i] = x`
+// - Vectors: integer index, bounds-checked (IndexOutOfBounds)
+// - HashMaps: string key, inserted if absent
+// - Nested targets (g[r][c] = x) and compound forms (v[i] += x) write back
+//
+// Test Coverage:
+// - test_vector_element_assignment: v[0] = 99
+// - test_out_of_bounds_write_is_error: v[5] = 1 on a 2-element vector
+// - test_hashmap_key_insertion: new key inserted, existing key replaced
+// - test_nested_index_assignment: grid[1][0] = 9
+// - test_assignment_in_loop_and_function: fills a local vector
+// - test_compound_index_assignment: v[1] += 5
+// - test_assignment_to_temporary_is_error: [1][0] = 3
+// - test_index_assignment_emits_source: AST round-trips to source
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: evaluate a program and render its value
+fn show(source: &str) -> String {
+    run(source).unwrap().to_string()
+}
+
+/// Test: Assigning to a vector element replaces it
+#[test]
+fn test_vector_element_assignment() {
+    assert_eq!(
+        show("let mut v = vec![1, 2, 3]; v[0] = 99; v"),
+        "[99, 2, 3]"
+    );
+}
+
+/// Test: Writing past the end is an IndexOutOfBounds error
+#[test]
+fn test_out_of_bounds_write_is_error() {
+    match run("let mut v = [1, 2]\nv[5] = 1") {
+        Err(EvalError::ValueError(ValueError::IndexOutOfBounds { index, len })) => {
+            assert_eq!((index, len), (5, 2));
+        }
+        other => panic!("expected IndexOutOfBounds, got {:?}", other),
+    }
+    assert!(run("let mut v = [1]\nv[-1] = 0").is_err());
+}
+
+/// Test: Assigning to a missing key inserts it; an existing key is replaced
+#[test]
+fn test_hashmap_key_insertion() {
+    let source = "let mut ages = {\"ada\": 36}
+ages[\"grace\"] = 85
+ages[\"ada\"] = 37
+ages";
+    match run(source).unwrap() {
+        Value::HashMap(map) => {
+            assert_eq!(map.len(), 2);
+            assert_eq!(map.get("ada"), Some(&Value::Integer(37)));
+            assert_eq!(map.get("grace"), Some(&Value::Integer(85)));
+        }
+        other => panic!("expected HashMap, got {:?}", other),
+    }
+}
+
+/// Test: A nested element write updates the inner and outer vectors
+#[test]
+fn test_nested_index_assignment() {
+    assert_eq!(
+        show("let mut grid = [[1, 2], [3, 4]]; grid[1][0] = 9; grid"),
+        "[[1, 2], [9, 4]]"
+    );
+}
+
+/// Test: Index assignment works on locals inside loops and functions
+#[test]
+fn test_assignment_in_loop_and_function() {
+    let source = "fun squares(n) {
+    let mut out = vec![0; n]
+    for i in 0..n {
+        out[i] = i * i
+    }
+    out
+}
+squares(4)";
+    assert_eq!(show(source), "[0, 1, 4, 9]");
+}
+
+/// Test: Compound assignment on an element writes the result back
+#[test]
+fn test_compound_index_assignment() {
+    assert_eq!(show("let mut v = [1, 2, 3]; v[1] += 5; v"), "[1, 7, 3]");
+}
+
+/// Test: Only collections reachable from a variable can be assigned into
+#[test]
+fn test_assignment_to_temporary_is_error() {
+    let err = run("[1][0] = 3").unwrap_err();
+    assert!(err.to_string().contains("temporary"), "{}", err);
+}
+
+/// Test: The AST emits the assignment back as source
+#[test]
+fn test_index_assignment_emits_source() {
+    let ast = Parser::new("m[\"k\"] = 1 + 2").parse().unwrap();
+    assert_eq!(ast.nodes()[0].emit(), "m[\"k\"] = 1 + 2;");
+}
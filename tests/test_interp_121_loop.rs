@@ -0,0 +1,122 @@
+// INTERP-121: loop { } with break-with-value
+//
+// Mission: Infinite loops that produce a value
+// - `loop { body }` repeats the body until a `break`
+// - `break value` makes the loop evaluate to that value; bare `break` is nil
+// - `return` inside a loop still leaves the enclosing function
+// - Only `loop` accepts a break value; while/for/repeat reject it
+//
+// Test Coverage:
+// - test_loop_break_with_value: let x = loop { ... break 5; }
+// - test_bare_break_is_nil: loop without a break value
+// - test_loop_as_statement: loop used for its side effects
+// - test_return_inside_loop: early return propagates out of the function
+// - test_nested_loop_breaks_innermost: inner value feeds the outer loop
+// - test_continue_in_loop: continue restarts the body
+// - test_break_value_outside_loop_is_error: while loop with break 1
+// - test_emit_break: `break 5;` and `break;`
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: The value given to break becomes the loop's value
+#[test]
+fn test_loop_break_with_value() {
+    let source = "let mut n = 0
+let x = loop {
+    n = n + 1
+    if n == 5 { break n * 10; }
+};
+x";
+    assert_eq!(run(source).unwrap(), Value::Integer(50));
+}
+
+/// Test: A bare break makes the loop evaluate to nil
+#[test]
+fn test_bare_break_is_nil() {
+    assert_eq!(run("let x = loop { break; }\nx").unwrap(), Value::nil());
+}
+
+/// Test: A loop in statement position runs for its side effects
+#[test]
+fn test_loop_as_statement() {
+    let source = "let mut total = 0
+let mut i = 0
+loop {
+    i = i + 1
+    if i > 4 { break }
+    total = total + i
+}
+total";
+    assert_eq!(run(source).unwrap(), Value::Integer(10));
+}
+
+/// Test: return inside a loop leaves the enclosing function
+#[test]
+fn test_return_inside_loop() {
+    let source = "fun first_square_over(limit) {
+    let mut i = 1
+    loop {
+        if i * i > limit { return i * i; }
+        i = i + 1
+    }
+    0
+}
+first_square_over(50)";
+    assert_eq!(run(source).unwrap(), Value::Integer(64));
+}
+
+/// Test: break leaves only the innermost loop
+#[test]
+fn test_nested_loop_breaks_innermost() {
+    let source = "let mut rounds = 0
+let result = loop {
+    let inner = loop { break 7; };
+    rounds = rounds + 1
+    if rounds == 3 { break inner * rounds; }
+};
+result";
+    assert_eq!(run(source).unwrap(), Value::Integer(21));
+}
+
+/// Test: continue skips the rest of the body and starts the next iteration
+#[test]
+fn test_continue_in_loop() {
+    let source = "let mut i = 0
+let mut odds = 0
+loop {
+    i = i + 1
+    if i > 6 { break odds; }
+    if i % 2 == 0 { continue }
+    odds = odds + 1
+}";
+    assert_eq!(run(source).unwrap(), Value::Integer(3));
+}
+
+/// Test: A break value in a while loop is an error
+#[test]
+fn test_break_value_outside_loop_is_error() {
+    let err = run("while true { break 1; }").unwrap_err();
+    assert!(
+        matches!(err, EvalError::UnsupportedOperation { ref operation }
+            if operation.contains("break with a value")),
+        "{:?}",
+        err
+    );
+}
+
+/// Test: break with and without a value emits back to source
+#[test]
+fn test_emit_break() {
+    let value = Some(Box::new(AstNode::IntegerLiteral(5)));
+    assert_eq!(AstNode::Break { value }.emit(), "break 5;");
+    assert_eq!(AstNode::Break { value: None }.emit(), "break;");
+}
@@ -0,0 +1,112 @@
+// INTERP-122: Closure Values with Environment Capture
+//
+// Mission: Closures are first-class values
+// - A closure snapshots the variables in scope where it is defined
+// - It can be stored, returned, passed as an argument and called by name
+// - thread::spawn accepts a closure value as well as a closure literal
+// - `move` is accepted; captures are copies either way
+//
+// Test Coverage:
+// - test_counter_maker_returns_independent_closures: make_counter(start) closures
+// - test_closure_captures_local_let: later assignment does not leak in
+// - test_closure_passed_as_argument: higher-order apply(f, x)
+// - test_move_closure_is_accepted: move || with a captured local
+// - test_thread_spawn_with_closure_variable: spawn(f) runs the body
+// - test_closure_arity_mismatch: wrong argument count is an error
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Test: Each closure made by a function keeps its own captured count
+#[test]
+fn test_counter_maker_returns_independent_closures() {
+    let source = "fun make_counter(start) {
+    |step| start + step
+}
+let from_ten = make_counter(10)
+let from_hundred = make_counter(100)
+from_ten(1) + from_hundred(2)";
+    assert_eq!(run(source).unwrap(), Value::Integer(113));
+}
+
+/// Test: A closure sees a local let as it was when the closure was made
+#[test]
+fn test_closure_captures_local_let() {
+    let source = "fun main() {
+    let mut base = 5
+    let add_base = |x| x + base
+    base = 1000
+    add_base(1) + base
+}
+main()";
+    assert_eq!(run(source).unwrap(), Value::Integer(1006));
+}
+
+/// Test: A closure can be passed to a function and called there
+#[test]
+fn test_closure_passed_as_argument() {
+    let source = "fun apply_twice(f, x) {
+    f(f(x))
+}
+let factor = 3
+apply_twice(|n| n * factor, 2)";
+    assert_eq!(run(source).unwrap(), Value::Integer(18));
+}
+
+/// Test: move closures capture like any other closure
+#[test]
+fn test_move_closure_is_accepted() {
+    let source = "let greeting = \"hi\"
+let greet = move || greeting + \"!\"
+greet()";
+    assert_eq!(run(source).unwrap(), Value::string("hi!".to_string()));
+}
+
+/// Test: thread::spawn runs a closure stored in a variable
+#[test]
+fn test_thread_spawn_with_closure_variable() {
+    let source = "let n = 21
+let work = move || println(n * 2)
+let handle = thread::spawn(work)
+handle.join()";
+    let ast = Parser::new(source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    eval.eval_program(&ast).unwrap();
+    assert_eq!(sink.contents().unwrap(), "42\n");
+}
+
+/// Test: Calling a closure with the wrong number of arguments fails
+#[test]
+fn test_closure_arity_mismatch() {
+    let err = root_error(run("let add = |a, b| a + b\nadd(1)").unwrap_err());
+    assert!(
+        matches!(
+            err,
+            EvalError::ArgumentCountMismatch {
+                expected: 2,
+                actual: 1,
+                ..
+            }
+        ),
+        "{:?}",
+        err
+    );
+}
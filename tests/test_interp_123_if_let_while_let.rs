@@ -0,0 +1,129 @@
+// INTERP-123: if let and while let
+//
+// Mission: Pattern-matching conditionals and loops
+// - `if let pattern = expr { .. } else { .. }` runs the then-branch on a match
+// - `while let pattern = expr { .. }` loops until the pattern stops matching
+// - Patterns are the match-arm patterns; their bindings stay in the branch
+//
+// Test Coverage:
+// - test_if_let_binds_identifier: Some(x) binds x in the then-branch
+// - test_if_let_mismatch_runs_else: None against Some(x)
+// - test_if_let_literal_mismatch: Some(3) against Some(4)
+// - test_if_let_wildcard_and_else_if: Some(_) and an else-if chain
+// - test_if_let_bindings_do_not_leak: x undefined after the if
+// - test_while_let_loops_until_mismatch: Ok(v) from a function call
+// - test_while_let_break_and_return: early exits from the body
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: A variant pattern binds its payload inside the then-branch
+#[test]
+fn test_if_let_binds_identifier() {
+    let source = "let opt = Some(20)
+if let Some(x) = opt {
+    x + 1
+} else {
+    0
+}";
+    assert_eq!(run(source).unwrap(), Value::Integer(21));
+}
+
+/// Test: When the pattern does not match, the else-branch runs
+#[test]
+fn test_if_let_mismatch_runs_else() {
+    let source = "let opt = None
+if let Some(x) = opt {
+    x
+} else {
+    -1
+}";
+    assert_eq!(run(source).unwrap(), Value::Integer(-1));
+}
+
+/// Test: A literal in the pattern must equal the payload
+#[test]
+fn test_if_let_literal_mismatch() {
+    let source = "let mut hits = 0
+if let Some(3) = Some(4) { hits = hits + 1 }
+if let Some(4) = Some(4) { hits = hits + 10 }
+hits";
+    assert_eq!(run(source).unwrap(), Value::Integer(10));
+}
+
+/// Test: Wildcards match any payload and else-if chains are accepted
+#[test]
+fn test_if_let_wildcard_and_else_if() {
+    let source = "fun describe(v) {
+    if let Some(_) = v {
+        \"some\"
+    } else if let Ok(n) = v {
+        n.to_string()
+    } else {
+        \"other\"
+    }
+}
+describe(Some(1)) + describe(Ok(7)) + describe(None)";
+    assert_eq!(
+        run(source).unwrap(),
+        Value::string("some7other".to_string())
+    );
+}
+
+/// Test: Pattern bindings are scoped to the then-branch
+#[test]
+fn test_if_let_bindings_do_not_leak() {
+    let err = run("if let Some(x) = Some(1) { x }\nx").unwrap_err();
+    assert!(
+        matches!(err, EvalError::UndefinedVariable { ref name } if name == "x"),
+        "{:?}",
+        err
+    );
+}
+
+/// Test: while let re-evaluates its scrutinee and stops at the first mismatch
+#[test]
+fn test_while_let_loops_until_mismatch() {
+    let source = "fun next_below(i, limit) {
+    if i < limit {
+        return Ok(i);
+    }
+    Err(\"done\")
+}
+let mut i = 0
+let mut total = 0
+while let Ok(v) = next_below(i, 5) {
+    total = total + v
+    i = i + 1
+}
+total";
+    assert_eq!(run(source).unwrap(), Value::Integer(10));
+}
+
+/// Test: break leaves a while let loop and return leaves the function
+#[test]
+fn test_while_let_break_and_return() {
+    let source = "fun first_over(limit) {
+    let mut n = 1
+    while let Some(v) = Some(n * n) {
+        if v > limit { return v; }
+        n = n + 1
+    }
+    0
+}
+let mut count = 0
+while let Some(_) = Some(count) {
+    count = count + 1
+    if count == 3 { break }
+}
+first_over(30) + count";
+    assert_eq!(run(source).unwrap(), Value::Integer(39));
+}
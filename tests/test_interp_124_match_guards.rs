@@ -0,0 +1,92 @@
+// INTERP-124: Match Guards
+//
+// Mission: Match arms can carry a boolean condition
+// - `pattern if cond => body` is taken only when the pattern matches and
+//   the guard is true
+// - The guard sees the pattern's bindings
+// - A false guard falls through to the later arms
+//
+// Test Coverage:
+// - test_guard_on_identifier_pattern: x if x > 0 => "pos"
+// - test_false_guard_falls_through_to_wildcard: later _ arm catches the value
+// - test_guard_sees_variant_binding: Some(n) if n % 2 == 0
+// - test_same_binding_in_consecutive_guarded_arms: n if .. , n if .. , n
+// - test_guard_must_be_boolean: non-boolean guard is a type error
+// - test_jit_rejects_guards: compiling a guarded match is UnsupportedNode
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+use ruchyruchy::jit::{JitCompiler, JitError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: A guard on an identifier pattern selects the arm
+#[test]
+fn test_guard_on_identifier_pattern() {
+    let source = "match 5 { x if x > 0 => \"pos\", _ => \"nonpos\" }";
+    assert_eq!(run(source).unwrap(), Value::string("pos".to_string()));
+}
+
+/// Test: When the guard is false the wildcard arm catches the value
+#[test]
+fn test_false_guard_falls_through_to_wildcard() {
+    let source = "match -3 { x if x > 0 => \"pos\", _ => \"nonpos\" }";
+    assert_eq!(run(source).unwrap(), Value::string("nonpos".to_string()));
+}
+
+/// Test: The guard can use a binding from a variant payload
+#[test]
+fn test_guard_sees_variant_binding() {
+    let source = "fun parity(opt) {
+    match opt {
+        Some(n) if n % 2 == 0 => \"even\",
+        Some(n) => \"odd\",
+        None => \"none\"
+    }
+}
+parity(Some(4)) + parity(Some(7)) + parity(None)";
+    assert_eq!(
+        run(source).unwrap(),
+        Value::string("evenoddnone".to_string())
+    );
+}
+
+/// Test: Bindings of a failed guarded arm do not clash with later arms
+#[test]
+fn test_same_binding_in_consecutive_guarded_arms() {
+    let source = "fun bucket(v) {
+    match v {
+        n if n < 10 => 1,
+        n if n < 100 => 2,
+        n => n
+    }
+}
+bucket(5) + bucket(50) + bucket(500)";
+    assert_eq!(run(source).unwrap(), Value::Integer(503));
+}
+
+/// Test: A guard that is not a boolean is a type error
+#[test]
+fn test_guard_must_be_boolean() {
+    let err = run("match 1 { x if x => 1, _ => 2 }").unwrap_err();
+    assert!(matches!(err, EvalError::ValueError(_)), "{:?}", err);
+}
+
+/// Test: The JIT reports guarded matches as unsupported
+#[test]
+fn test_jit_rejects_guards() {
+    let ast = Parser::new("match 1 { x if x > 0 => 1, _ => 0 }")
+        .parse()
+        .unwrap();
+    let mut jit = JitCompiler::new().unwrap();
+    let err = jit
+        .compile_function_with_params::<fn() -> i64>(&[], &ast.nodes()[0])
+        .unwrap_err();
+    assert!(matches!(err, JitError::UnsupportedNode(_)), "{:?}", err);
+}
@@ -0,0 +1,96 @@
+// INTERP-125: Short-circuit && and ||
+//
+// Mission: Logical operators only evaluate what they need
+// - `false && rhs` is false without evaluating rhs
+// - `true || rhs` is true without evaluating rhs
+// - Otherwise the right side runs and the result is as before
+// - Non-boolean operands are still a ValueError::TypeMismatch
+// - Comparisons bind tighter than &&, which binds tighter than ||
+//
+// Test Coverage:
+// - test_and_skips_rhs_when_left_false: side-effecting rhs never runs
+// - test_or_skips_rhs_when_left_true: side-effecting rhs never runs
+// - test_rhs_runs_when_needed: true && rhs and false || rhs call rhs
+// - test_guard_against_division_by_zero: x != 0 && 10 / x > 1
+// - test_non_boolean_operands_still_error: 1 && true, true && 1
+// - test_logical_precedence: true || false && false, comparisons as operands
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: evaluate a program and return its value and printed output
+fn run_with_output(source: &str) -> (Value, String) {
+    let ast = Parser::new(source).parse().expect("parse");
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    let value = eval.eval_program(&ast).unwrap();
+    (value, sink.contents().unwrap())
+}
+
+/// Program prefix: a function that records each call before returning true
+const NOISY: &str = "fun noisy() {\n    println(\"called\")\n    true\n}\n";
+
+/// Test: The right side of && is skipped when the left side is false
+#[test]
+fn test_and_skips_rhs_when_left_false() {
+    let (value, output) = run_with_output(&format!("{}false && noisy()", NOISY));
+    assert_eq!(value, Value::Boolean(false));
+    assert_eq!(output, "");
+}
+
+/// Test: The right side of || is skipped when the left side is true
+#[test]
+fn test_or_skips_rhs_when_left_true() {
+    let (value, output) = run_with_output(&format!("{}true || noisy()", NOISY));
+    assert_eq!(value, Value::Boolean(true));
+    assert_eq!(output, "");
+}
+
+/// Test: The right side runs when the left side does not decide the result
+#[test]
+fn test_rhs_runs_when_needed() {
+    let (value, output) =
+        run_with_output(&format!("{}(true && noisy()) && (false || noisy())", NOISY));
+    assert_eq!(value, Value::Boolean(true));
+    assert_eq!(output, "called\ncalled\n");
+}
+
+/// Test: A zero check guards the division on its right
+#[test]
+fn test_guard_against_division_by_zero() {
+    let source = "let x = 0\nx != 0 && 10 / x > 1";
+    assert_eq!(run(source).unwrap(), Value::Boolean(false));
+    let source = "let x = 2\nx != 0 && 10 / x > 1";
+    assert_eq!(run(source).unwrap(), Value::Boolean(true));
+}
+
+/// Test: Non-boolean operands produce the same type error as before
+#[test]
+fn test_non_boolean_operands_still_error() {
+    for source in ["1 && true", "true && 1", "0 || false", "false || 0"] {
+        let err = run(source).unwrap_err();
+        assert!(
+            matches!(err, EvalError::ValueError(ValueError::TypeMismatch { .. })),
+            "{}: {:?}",
+            source,
+            err
+        );
+    }
+}
+
+/// Test: && binds tighter than ||, and comparisons tighter than both
+#[test]
+fn test_logical_precedence() {
+    assert_eq!(run("true || false && false").unwrap(), Value::Boolean(true));
+    assert_eq!(run("1 < 2 && 3 > 2").unwrap(), Value::Boolean(true));
+    assert_eq!(run("1 > 2 || 2 == 2").unwrap(), Value::Boolean(true));
+}
@@ -0,0 +1,114 @@
+// INTERP-126: Bitwise Operators
+//
+// Mission: Integer bit manipulation with Rust precedence
+// - `&`, `|`, `^`, `<<`, `>>` on integers
+// - Shifts bind looser than + and -; &, ^, | follow; all bind tighter
+//   than comparisons
+// - Hexadecimal literals: 0xFF
+// - Non-integer operands are a ValueError::TypeMismatch
+// - `&self` and `&str` in signatures still parse
+//
+// Test Coverage:
+// - test_and_or_xor: 6 & 3, 6 | 3, 0xFF ^ 0x0F
+// - test_shifts: 1 << 4, -16 >> 2
+// - test_precedence_with_addition: 1 << 2 + 1, 1 + 2 & 3
+// - test_precedence_with_comparison: 6 & 3 == 2
+// - test_non_integer_operands_error: 1.5 & 1, true | 1
+// - test_shift_out_of_range_errors: 1 << 64, 1 >> -1
+// - test_references_and_closures_still_parse: &self, &str, |x| x
+// - test_emit_keeps_grouping: (1 << 2) + 3 keeps its parentheses
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: Bitwise and, or and xor on integer and hex literals
+#[test]
+fn test_and_or_xor() {
+    assert_eq!(run("6 & 3").unwrap(), Value::Integer(2));
+    assert_eq!(run("6 | 3").unwrap(), Value::Integer(7));
+    assert_eq!(run("0xFF ^ 0x0F").unwrap(), Value::Integer(240));
+}
+
+/// Test: Left shift and arithmetic right shift
+#[test]
+fn test_shifts() {
+    assert_eq!(run("1 << 4").unwrap(), Value::Integer(16));
+    assert_eq!(run("-16 >> 2").unwrap(), Value::Integer(-4));
+}
+
+/// Test: + binds tighter than shifts, and shifts tighter than &
+#[test]
+fn test_precedence_with_addition() {
+    assert_eq!(run("1 << 2 + 1").unwrap(), Value::Integer(8));
+    assert_eq!(run("1 + 2 & 3").unwrap(), Value::Integer(3));
+    assert_eq!(run("1 | 2 ^ 3 & 4").unwrap(), Value::Integer(3));
+}
+
+/// Test: Bitwise operators bind tighter than comparisons
+#[test]
+fn test_precedence_with_comparison() {
+    assert_eq!(run("6 & 3 == 2").unwrap(), Value::Boolean(true));
+    assert_eq!(
+        run("let x = 5\nx & 1 == 1 && x | 2 == 7").unwrap(),
+        Value::Boolean(true)
+    );
+}
+
+/// Test: Non-integer operands are a type mismatch
+#[test]
+fn test_non_integer_operands_error() {
+    for source in ["1.5 & 1", "true | 1", "1 ^ \"a\"", "2.0 << 1"] {
+        let err = run(source).unwrap_err();
+        assert!(
+            matches!(err, EvalError::ValueError(ValueError::TypeMismatch { .. })),
+            "{}: {:?}",
+            source,
+            err
+        );
+    }
+}
+
+/// Test: Shift amounts outside 0..64 are rejected
+#[test]
+fn test_shift_out_of_range_errors() {
+    for source in ["1 << 64", "1 >> -1"] {
+        let err = run(source).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                EvalError::ValueError(ValueError::InvalidOperation { .. })
+            ),
+            "{}: {:?}",
+            source,
+            err
+        );
+    }
+}
+
+/// Test: Reference prefixes in signatures and closure pipes are unaffected
+#[test]
+fn test_references_and_closures_still_parse() {
+    let source = "fun greet(name: &str) -> &str {
+    name
+}
+let double = |x| x * 2
+double(greet(\"a\").len() | 4)";
+    assert_eq!(run(source).unwrap(), Value::Integer(10));
+}
+
+/// Test: Emitting parenthesizes only where precedence requires it
+#[test]
+fn test_emit_keeps_grouping() {
+    let ast = Parser::new("(1 << 2) + 3").parse().unwrap();
+    assert_eq!(ast.nodes()[0].emit(), "(1 << 2) + 3");
+    let ast = Parser::new("(1 + 2) << 3").parse().unwrap();
+    assert_eq!(ast.nodes()[0].emit(), "1 + 2 << 3");
+}
@@ -0,0 +1,92 @@
+// INTERP-127: Hex, Octal and Binary Integer Literals
+//
+// Mission: Integer literals in the bases used for flags, colors and masks
+// - 0x / 0o / 0b prefixes (either case) select base 16, 8 and 2
+// - Underscores separate digits in any base and in floats
+// - An invalid digit for the base is a ParseError, not a silent split
+//
+// Test Coverage:
+// - test_hex_literals: 0xFF, 0Xff, 0x0
+// - test_octal_literals: 0o17, 0o777
+// - test_binary_literals: 0b1010, 0B1
+// - test_underscore_separators: 1_000_000, 0xDE_AD_BE_EF, 0b1111_0000, 1_000.5
+// - test_invalid_digit_is_parse_error: 0b1012, 0o8, 0xFG
+// - test_missing_digits_and_overflow: 0x, 0x1_0000_0000_0000_0000
+// - test_tokens_after_literal: 0b11 + 1, 0xA.to_string(), 0 used alone
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::{ParseError, Parser};
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a single expression
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).expect("eval")
+}
+
+/// Helper: the parse error message for a malformed program
+fn parse_error(source: &str) -> String {
+    match Parser::new(source).parse() {
+        Err(ParseError::InvalidSyntax { message, .. }) => message,
+        other => panic!("{}: expected InvalidSyntax, got {:?}", source, other),
+    }
+}
+
+/// Test: Hexadecimal literals in upper and lower case
+#[test]
+fn test_hex_literals() {
+    assert_eq!(eval("0xFF"), Value::Integer(255));
+    assert_eq!(eval("0Xff"), Value::Integer(255));
+    assert_eq!(eval("0x0"), Value::Integer(0));
+}
+
+/// Test: Octal literals
+#[test]
+fn test_octal_literals() {
+    assert_eq!(eval("0o17"), Value::Integer(15));
+    assert_eq!(eval("0o777"), Value::Integer(511));
+}
+
+/// Test: Binary literals
+#[test]
+fn test_binary_literals() {
+    assert_eq!(eval("0b1010"), Value::Integer(10));
+    assert_eq!(eval("0B1"), Value::Integer(1));
+}
+
+/// Test: Underscores are ignored between digits
+#[test]
+fn test_underscore_separators() {
+    assert_eq!(eval("1_000_000"), Value::Integer(1_000_000));
+    assert_eq!(eval("0xDE_AD_BE_EF"), Value::Integer(0xDEAD_BEEF));
+    assert_eq!(eval("0b1111_0000"), Value::Integer(240));
+    assert_eq!(eval("1_000.5"), Value::Float(1000.5));
+}
+
+/// Test: A digit outside the base is reported
+#[test]
+fn test_invalid_digit_is_parse_error() {
+    assert_eq!(parse_error("0b1012"), "invalid digit '2' in base 2 literal");
+    assert_eq!(parse_error("0o8"), "invalid digit '8' in base 8 literal");
+    assert_eq!(
+        parse_error("let x = 0xFG"),
+        "invalid digit 'G' in base 16 literal"
+    );
+}
+
+/// Test: A bare prefix or a value past i64 is reported
+#[test]
+fn test_missing_digits_and_overflow() {
+    assert_eq!(parse_error("0x"), "missing digits in base 16 literal");
+    assert!(parse_error("0x1_0000_0000_0000_0000").contains("out of range"));
+    assert!(parse_error("99999999999999999999").contains("out of range"));
+}
+
+/// Test: Operators and method calls after a literal still tokenize
+#[test]
+fn test_tokens_after_literal() {
+    assert_eq!(eval("0b11 + 1"), Value::Integer(4));
+    assert_eq!(eval("0xA.to_string()"), Value::string("10".to_string()));
+    assert_eq!(eval("0"), Value::Integer(0));
+}
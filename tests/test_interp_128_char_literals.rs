@@ -0,0 +1,105 @@
+// INTERP-128: Character Literals with Escape Sequences
+//
+// Mission: Write single characters, including control characters and quotes
+// - 'x' evaluates to a one-character string
+// - Escapes: '\n', '\t', '\r', '\0', '\\', '\'' and '\"'
+// - Empty, multi-character, unterminated and unknown-escape literals are
+//   ParseErrors with a descriptive message
+//
+// Test Coverage:
+// - test_plain_char_literal: 'a' and '!'
+// - test_escape_sequences: each escape maps to its character
+// - test_char_as_method_argument: "hello".contains('e')
+// - test_empty_and_multi_char_errors: '' and 'ab'
+// - test_unterminated_char_errors: 'a and a lone quote at end of input
+// - test_unknown_escape_error: '\q'
+// - test_emit_escapes_char: AstNode::CharLiteral round-trips through emit
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::{AstNode, ParseError, Parser};
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).expect("eval")
+}
+
+/// Helper: the parse error message for a malformed program
+fn parse_error(source: &str) -> String {
+    match Parser::new(source).parse() {
+        Err(ParseError::InvalidSyntax { message, .. }) => message,
+        other => panic!("{}: expected InvalidSyntax, got {:?}", source, other),
+    }
+}
+
+/// Test: A plain character literal is a one-character string
+#[test]
+fn test_plain_char_literal() {
+    assert_eq!(eval("'a'"), Value::string("a".to_string()));
+    assert_eq!(eval("'!'"), Value::string("!".to_string()));
+}
+
+/// Test: Every supported escape decodes to its character
+#[test]
+fn test_escape_sequences() {
+    let cases = [
+        (r"'\n'", "\n"),
+        (r"'\t'", "\t"),
+        (r"'\r'", "\r"),
+        (r"'\0'", "\0"),
+        (r"'\\'", "\\"),
+        (r"'\''", "'"),
+        (r#"'\"'"#, "\""),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(
+            eval(source),
+            Value::string(expected.to_string()),
+            "{}",
+            source
+        );
+    }
+}
+
+/// Test: A char works where a one-character string argument is expected
+#[test]
+fn test_char_as_method_argument() {
+    assert_eq!(eval("\"hello\".contains('e')"), Value::Boolean(true));
+    assert_eq!(eval("\"hello\".contains('z')"), Value::Boolean(false));
+}
+
+/// Test: Empty and multi-character literals are rejected
+#[test]
+fn test_empty_and_multi_char_errors() {
+    assert_eq!(parse_error("''"), "empty character literal");
+    assert_eq!(
+        parse_error("let c = 'ab'"),
+        "character literal may only contain one character"
+    );
+}
+
+/// Test: A literal without its closing quote is rejected
+#[test]
+fn test_unterminated_char_errors() {
+    assert_eq!(
+        parse_error("let c = 'a\n1"),
+        "unterminated character literal"
+    );
+    assert_eq!(parse_error("'"), "unterminated character literal");
+}
+
+/// Test: An unknown escape names the offending sequence
+#[test]
+fn test_unknown_escape_error() {
+    assert_eq!(parse_error(r"'\q'"), r"unknown escape sequence '\q'");
+}
+
+/// Test: Emitting a char literal re-escapes it
+#[test]
+fn test_emit_escapes_char() {
+    assert_eq!(AstNode::CharLiteral('\n').emit(), r"'\n'");
+    assert_eq!(AstNode::CharLiteral('\'').emit(), r"'\''");
+    assert_eq!(AstNode::CharLiteral('"').emit(), "'\"'");
+}
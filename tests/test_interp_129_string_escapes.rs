@@ -0,0 +1,104 @@
+// INTERP-129: String Escape Sequences
+//
+// Mission: String literals decode backslash escapes
+// - \n, \t, \r, \0, \\ and \" become the characters they name
+// - An escaped quote does not end the literal
+// - Unknown escapes and unterminated strings are ParseErrors
+//
+// Test Coverage:
+// - test_control_character_escapes: "line1\nline2", \t, \r, \0
+// - test_escaped_quote_and_backslash: "she said \"hi\"", "C:\\dir"
+// - test_println_writes_real_newline: println output contains the newline
+// - test_unknown_escape_error: "\q"
+// - test_unterminated_string_error: "abc and "abc\"
+// - test_emit_reescapes_string: StringLiteral round-trips through emit
+// - test_repl_waits_for_closing_quote: needs_more_input with an escaped quote
+
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::output::OutputSink;
+use ruchyruchy::interpreter::parser::{needs_more_input, AstNode, ParseError, Parser};
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).expect("eval")
+}
+
+/// Helper: the parse error message for a malformed program
+fn parse_error(source: &str) -> String {
+    match Parser::new(source).parse() {
+        Err(ParseError::InvalidSyntax { message, .. }) => message,
+        other => panic!("{}: expected InvalidSyntax, got {:?}", source, other),
+    }
+}
+
+/// Test: Control-character escapes store the actual characters
+#[test]
+fn test_control_character_escapes() {
+    assert_eq!(
+        eval(r#""line1\nline2""#),
+        Value::string("line1\nline2".to_string())
+    );
+    assert_eq!(
+        eval(r#""a\tb\rc\0""#),
+        Value::string("a\tb\rc\0".to_string())
+    );
+}
+
+/// Test: Escaped quotes and backslashes stay inside the literal
+#[test]
+fn test_escaped_quote_and_backslash() {
+    assert_eq!(
+        eval(r#""she said \"hi\"""#),
+        Value::string("she said \"hi\"".to_string())
+    );
+    assert_eq!(eval(r#""C:\\dir""#), Value::string("C:\\dir".to_string()));
+    assert_eq!(eval(r#""\"".len()"#), Value::Integer(1));
+}
+
+/// Test: println writes the decoded newline
+#[test]
+fn test_println_writes_real_newline() {
+    let ast = Parser::new(r#"println("a\nb")"#).parse().unwrap();
+    let sink = OutputSink::buffer();
+    let mut eval = Evaluator::new().with_output(sink.clone());
+    eval.eval_program(&ast).unwrap();
+    assert_eq!(sink.contents().unwrap(), "a\nb\n");
+}
+
+/// Test: An unknown escape names the offending sequence
+#[test]
+fn test_unknown_escape_error() {
+    assert_eq!(
+        parse_error(r#"let s = "\q""#),
+        r"unknown escape sequence '\q'"
+    );
+}
+
+/// Test: A string without its closing quote is rejected
+#[test]
+fn test_unterminated_string_error() {
+    assert_eq!(
+        parse_error(r#"let s = "abc"#),
+        "unterminated string literal"
+    );
+    assert_eq!(parse_error(r#""abc\""#), "unterminated string literal");
+}
+
+/// Test: Emitting a string literal re-escapes special characters
+#[test]
+fn test_emit_reescapes_string() {
+    let node = AstNode::StringLiteral("say \"hi\"\n\\".to_string());
+    assert_eq!(node.emit(), r#""say \"hi\"\n\\""#);
+    let reparsed = Parser::new(&node.emit()).parse().unwrap();
+    assert_eq!(reparsed.nodes()[0], node);
+}
+
+/// Test: The REPL keeps reading while an escaped quote leaves a string open
+#[test]
+fn test_repl_waits_for_closing_quote() {
+    assert!(needs_more_input(r#"let s = "a\""#));
+    assert!(!needs_more_input(r#"let s = "a\"""#));
+}
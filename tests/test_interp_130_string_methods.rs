@@ -0,0 +1,142 @@
+// INTERP-130: Common String Methods
+//
+// Mission: Text processing without leaving Ruchy
+// - trim(), to_uppercase(), to_lowercase() return new strings
+// - starts_with(p) / ends_with(p) return booleans
+// - split(sep) returns a vector of strings
+// - replace(from, to) replaces every occurrence
+// - Wrong argument counts are ArgumentCountMismatch; non-string arguments
+//   are a type error
+//
+// Test Coverage:
+// - test_trim: "  hi  ".trim()
+// - test_case_conversion: to_uppercase / to_lowercase
+// - test_starts_with_ends_with: prefixes, suffixes and char arguments
+// - test_split: "a,b,c".split(","), no separator present, methods on parts
+// - test_replace: "Hello".replace("l", "L")
+// - test_methods_on_variables: receivers bound with let are left unchanged
+// - test_argument_count_mismatch: trim(1), replace("a")
+// - test_non_string_argument_errors: split(1)
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Helper: a string value
+fn string(s: &str) -> Value {
+    Value::string(s.to_string())
+}
+
+/// Test: trim removes leading and trailing whitespace
+#[test]
+fn test_trim() {
+    assert_eq!(run("\"  hi  \".trim()").unwrap(), string("hi"));
+    assert_eq!(run("\"\\t a b \\n\".trim()").unwrap(), string("a b"));
+}
+
+/// Test: Case conversion in both directions
+#[test]
+fn test_case_conversion() {
+    assert_eq!(run("\"Hello\".to_uppercase()").unwrap(), string("HELLO"));
+    assert_eq!(run("\"Hello\".to_lowercase()").unwrap(), string("hello"));
+}
+
+/// Test: Prefix and suffix checks accept strings and chars
+#[test]
+fn test_starts_with_ends_with() {
+    assert_eq!(
+        run("\"ruchy\".starts_with(\"ru\")").unwrap(),
+        Value::Boolean(true)
+    );
+    assert_eq!(
+        run("\"ruchy\".ends_with(\"ru\")").unwrap(),
+        Value::Boolean(false)
+    );
+    assert_eq!(
+        run("\"ruchy\".ends_with('y')").unwrap(),
+        Value::Boolean(true)
+    );
+}
+
+/// Test: split returns the pieces between separators
+#[test]
+fn test_split() {
+    assert_eq!(
+        run("\"a,b,c\".split(\",\")").unwrap(),
+        Value::vector(vec![string("a"), string("b"), string("c")])
+    );
+    assert_eq!(
+        run("\"abc\".split(\",\")").unwrap(),
+        Value::vector(vec![string("abc")])
+    );
+    assert_eq!(
+        run("\"x y z\".split(\" \").len()").unwrap(),
+        Value::Integer(3)
+    );
+}
+
+/// Test: replace substitutes every occurrence
+#[test]
+fn test_replace() {
+    assert_eq!(
+        run("\"Hello\".replace(\"l\", \"L\")").unwrap(),
+        string("HeLLo")
+    );
+    assert_eq!(run("\"aaa\".replace(\"aa\", \"b\")").unwrap(), string("ba"));
+}
+
+/// Test: Methods on a variable return new strings and leave it unchanged
+#[test]
+fn test_methods_on_variables() {
+    let source = "let name = \"  Ada  \"
+let upper = name.trim().to_uppercase()
+upper + \"|\" + name";
+    assert_eq!(run(source).unwrap(), string("ADA|  Ada  "));
+}
+
+/// Test: Wrong argument counts are reported with the method name
+#[test]
+fn test_argument_count_mismatch() {
+    let err = root_error(run("\"a\".trim(1)").unwrap_err());
+    assert!(
+        matches!(err, EvalError::ArgumentCountMismatch { ref function, expected: 0, actual: 1 }
+            if function == "String.trim()"),
+        "{:?}",
+        err
+    );
+    let err = root_error(run("\"a\".replace(\"a\")").unwrap_err());
+    assert!(
+        matches!(
+            err,
+            EvalError::ArgumentCountMismatch {
+                expected: 2,
+                actual: 1,
+                ..
+            }
+        ),
+        "{:?}",
+        err
+    );
+}
+
+/// Test: A non-string separator is a type error
+#[test]
+fn test_non_string_argument_errors() {
+    let err = root_error(run("\"a,b\".split(1)").unwrap_err());
+    assert!(matches!(err, EvalError::ValueError(_)), "{:?}", err);
+}
@@ -0,0 +1,101 @@
+// INTERP-131: Vector map and filter
+//
+// Mission: Functional data processing over vectors
+// - v.map(f) calls f on each element and collects the results
+// - v.filter(f) keeps the elements for which f returns true
+// - Both return new vectors and chain with each other and with sum()
+// - The argument must be a closure; filter's closure must return a boolean
+//
+// Test Coverage:
+// - test_map_doubles_elements: [1, 2, 3].map(|x| x * 2)
+// - test_filter_keeps_matching: [1, 2, 3].filter(|x| x > 1)
+// - test_map_filter_sum_chain: .map(..).filter(..).sum()
+// - test_sum_of_empty_vector_is_zero: [].sum() and a filter that keeps nothing
+// - test_closure_captures_and_receiver_unchanged: captured factor, original vector
+// - test_filter_requires_boolean: |x| x is a type error
+// - test_argument_errors: map() and map(5)
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Helper: an integer vector value
+fn ints(values: &[i64]) -> Value {
+    Value::vector(values.iter().copied().map(Value::Integer).collect())
+}
+
+/// Test: map applies the closure to every element
+#[test]
+fn test_map_doubles_elements() {
+    assert_eq!(run("[1, 2, 3].map(|x| x * 2)").unwrap(), ints(&[2, 4, 6]));
+}
+
+/// Test: filter keeps the elements whose closure result is true
+#[test]
+fn test_filter_keeps_matching() {
+    assert_eq!(run("[1, 2, 3].filter(|x| x > 1)").unwrap(), ints(&[2, 3]));
+}
+
+/// Test: map, filter and sum chain left to right
+#[test]
+fn test_map_filter_sum_chain() {
+    let source = "let v = [1, 2, 3, 4, 5]
+v.map(|x| x * x).filter(|x| x % 2 == 1).sum()";
+    assert_eq!(run(source).unwrap(), Value::Integer(35));
+}
+
+/// Test: Summing nothing gives 0
+#[test]
+fn test_sum_of_empty_vector_is_zero() {
+    assert_eq!(run("[].sum()").unwrap(), Value::Integer(0));
+    assert_eq!(
+        run("[1, 2].filter(|x| x > 10).sum()").unwrap(),
+        Value::Integer(0)
+    );
+}
+
+/// Test: The closure sees captured variables and the receiver is untouched
+#[test]
+fn test_closure_captures_and_receiver_unchanged() {
+    let source = "let factor = 10
+let v = [1, 2]
+let scaled = v.map(|x| x * factor)
+scaled.sum() + v.sum()";
+    assert_eq!(run(source).unwrap(), Value::Integer(33));
+}
+
+/// Test: filter rejects a closure that does not return a boolean
+#[test]
+fn test_filter_requires_boolean() {
+    let err = root_error(run("[1, 2].filter(|x| x)").unwrap_err());
+    assert!(matches!(err, EvalError::ValueError(_)), "{:?}", err);
+}
+
+/// Test: A missing or non-closure argument is reported
+#[test]
+fn test_argument_errors() {
+    let err = root_error(run("[1].map()").unwrap_err());
+    assert!(
+        matches!(err, EvalError::ArgumentCountMismatch { ref function, expected: 1, actual: 0 }
+            if function == "Array.map()"),
+        "{:?}",
+        err
+    );
+    let err = root_error(run("[1].map(5)").unwrap_err());
+    assert!(matches!(err, EvalError::ValueError(_)), "{:?}", err);
+}
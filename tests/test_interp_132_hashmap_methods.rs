@@ -0,0 +1,108 @@
+// INTERP-132: HashMap Mutation and Query Methods
+//
+// Mission: Build and inspect maps programmatically
+// - insert(key, value) adds or overwrites an entry in a map variable
+// - remove(key) deletes an entry and returns its value (nil if missing)
+// - contains_key(key) reports whether an entry exists
+// - keys() / values() return vectors ordered by key
+// - Keys must be strings, as in map literals
+//
+// Test Coverage:
+// - test_insert_into_empty_map: HashMap::new() then insert
+// - test_insert_overwrites_existing_key: second insert replaces the value
+// - test_remove_existing_and_missing_key: returned value and nil
+// - test_contains_key: before and after remove
+// - test_iterate_keys: for k in m.keys() visits every key in order
+// - test_values_follow_key_order: values() lines up with keys()
+// - test_non_string_key_errors: insert(1, ..) is a type error
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: a string value
+fn string(s: &str) -> Value {
+    Value::string(s.to_string())
+}
+
+/// Test: insert adds entries to an empty map
+#[test]
+fn test_insert_into_empty_map() {
+    let source = "let mut m = HashMap::new();
+m.insert(\"a\", 1);
+m.insert(\"b\", 2);
+m.get(\"a\") + m.get(\"b\")";
+    assert_eq!(run(source).unwrap(), Value::Integer(3));
+}
+
+/// Test: Inserting an existing key replaces its value
+#[test]
+fn test_insert_overwrites_existing_key() {
+    let source = "let mut m = {\"a\": 1};
+m.insert(\"a\", 10);
+m.keys().len() * 100 + m[\"a\"]";
+    assert_eq!(run(source).unwrap(), Value::Integer(110));
+}
+
+/// Test: remove returns the removed value, or nil for a missing key
+#[test]
+fn test_remove_existing_and_missing_key() {
+    let source = "let mut m = {\"a\": 1, \"b\": 2};
+let removed = m.remove(\"a\");
+let missing = m.remove(\"zzz\");
+[removed, missing, m.keys().len()]";
+    assert_eq!(
+        run(source).unwrap(),
+        Value::vector(vec![Value::Integer(1), Value::nil(), Value::Integer(1)])
+    );
+}
+
+/// Test: contains_key reflects inserts and removals
+#[test]
+fn test_contains_key() {
+    let source = "let mut m = HashMap::new();
+m.insert(\"k\", true);
+let before = m.contains_key(\"k\");
+m.remove(\"k\");
+[before, m.contains_key(\"k\")]";
+    assert_eq!(
+        run(source).unwrap(),
+        Value::vector(vec![Value::Boolean(true), Value::Boolean(false)])
+    );
+}
+
+/// Test: keys() can drive a for loop and is sorted
+#[test]
+fn test_iterate_keys() {
+    let source = "let m = {\"b\": 2, \"c\": 3, \"a\": 1};
+let mut joined = \"\";
+for k in m.keys() {
+    joined = joined + k;
+}
+joined";
+    assert_eq!(run(source).unwrap(), string("abc"));
+}
+
+/// Test: values() is ordered by key
+#[test]
+fn test_values_follow_key_order() {
+    let source = "{\"y\": 20, \"x\": 10}.values()";
+    assert_eq!(
+        run(source).unwrap(),
+        Value::vector(vec![Value::Integer(10), Value::Integer(20)])
+    );
+}
+
+/// Test: Keys must be strings
+#[test]
+fn test_non_string_key_errors() {
+    let err = run("let mut m = HashMap::new();\nm.insert(1, 2);").unwrap_err();
+    assert!(matches!(err, EvalError::ValueError(_)), "{:?}", err);
+}
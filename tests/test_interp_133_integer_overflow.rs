@@ -0,0 +1,129 @@
+// INTERP-133: Integer Overflow Detection
+//
+// Mission: Integer arithmetic never silently wraps
+// - + - * / % on integers raise ValueError::IntegerOverflow when the result
+//   does not fit in an i64
+// - Unary minus on i64::MIN is an overflow too
+// - Evaluator::with_wrapping_arithmetic() opts into two's-complement wrapping
+//
+// Test Coverage:
+// - test_multiply_near_max_overflows: (i64::MAX / 2 + 1) * 2
+// - test_add_and_subtract_overflow: MAX + 1, MIN - 1
+// - test_negate_min_overflows: -(MIN)
+// - test_divide_and_modulo_min_by_minus_one: MIN / -1, MIN % -1
+// - test_results_in_range_still_work: MAX - 1 + 1, MIN + 0
+// - test_float_arithmetic_unaffected: (1e20)^16 is infinity
+// - test_wrapping_mode_opt_in: the same programs wrap with the flag
+// - test_overflow_message: Display names the operation
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Program prefix binding i64::MAX and i64::MIN
+const BOUNDS: &str = "let max = 9223372036854775807;
+let min = -9223372036854775807 - 1;
+";
+
+/// Helper: evaluate a program after the bounds prefix
+fn run(source: &str) -> Result<Value, EvalError> {
+    run_with(Evaluator::new(), source)
+}
+
+/// Helper: evaluate a program after the bounds prefix with a given evaluator
+fn run_with(mut eval: Evaluator, source: &str) -> Result<Value, EvalError> {
+    let program = format!("{}{}", BOUNDS, source);
+    let ast = Parser::new(&program).parse().expect("parse");
+    eval.eval_program(&ast)
+}
+
+/// Helper: assert that a program overflows in the named operation
+fn assert_overflow(source: &str, expected: &str) {
+    match run(source) {
+        Err(EvalError::ValueError(ValueError::IntegerOverflow { operation })) => {
+            assert_eq!(operation, expected, "{}", source)
+        }
+        other => panic!("{}: expected IntegerOverflow, got {:?}", source, other),
+    }
+}
+
+/// Test: Multiplying past i64::MAX is reported
+#[test]
+fn test_multiply_near_max_overflows() {
+    assert_overflow("(max / 2 + 1) * 2", "multiply");
+    assert_eq!(run("(max / 2) * 2").unwrap(), Value::Integer(i64::MAX - 1));
+}
+
+/// Test: Adding past MAX and subtracting past MIN are reported
+#[test]
+fn test_add_and_subtract_overflow() {
+    assert_overflow("max + 1", "add");
+    assert_overflow("min - 1", "subtract");
+}
+
+/// Test: Negating i64::MIN is reported
+#[test]
+fn test_negate_min_overflows() {
+    assert_overflow("-min", "negate");
+    assert_eq!(run("-max").unwrap(), Value::Integer(-i64::MAX));
+}
+
+/// Test: MIN / -1 and MIN % -1 are reported instead of panicking
+#[test]
+fn test_divide_and_modulo_min_by_minus_one() {
+    assert_overflow("min / -1", "divide");
+    assert_overflow("min % -1", "modulo");
+}
+
+/// Test: Results that fit are unaffected
+#[test]
+fn test_results_in_range_still_work() {
+    assert_eq!(run("max - 1 + 1").unwrap(), Value::Integer(i64::MAX));
+    assert_eq!(run("min + 0").unwrap(), Value::Integer(i64::MIN));
+}
+
+/// Test: Float arithmetic keeps IEEE 754 behavior
+#[test]
+fn test_float_arithmetic_unaffected() {
+    let value = run("let b = 100000000000000000000.0;\nb * b * b * b * b * b * b * b * b * b * b * b * b * b * b * b");
+    assert!(
+        matches!(value, Ok(Value::Float(f)) if f.is_infinite()),
+        "{:?}",
+        value
+    );
+}
+
+/// Test: with_wrapping_arithmetic wraps instead of erroring
+#[test]
+fn test_wrapping_mode_opt_in() {
+    let wrapping = || Evaluator::new().with_wrapping_arithmetic();
+    assert_eq!(
+        run_with(wrapping(), "max + 1").unwrap(),
+        Value::Integer(i64::MIN)
+    );
+    assert_eq!(
+        run_with(wrapping(), "(max / 2 + 1) * 2").unwrap(),
+        Value::Integer(i64::MIN)
+    );
+    assert_eq!(
+        run_with(wrapping(), "-min").unwrap(),
+        Value::Integer(i64::MIN)
+    );
+    assert_eq!(
+        run_with(wrapping(), "min / -1").unwrap(),
+        Value::Integer(i64::MIN)
+    );
+    assert!(matches!(
+        run_with(wrapping(), "1 / 0"),
+        Err(EvalError::ValueError(ValueError::DivisionByZero))
+    ));
+}
+
+/// Test: The error message names the overflowing operation
+#[test]
+fn test_overflow_message() {
+    let err = ValueError::IntegerOverflow {
+        operation: "add".to_string(),
+    };
+    assert_eq!(err.to_string(), "Integer overflow in add");
+}
@@ -0,0 +1,120 @@
+// INTERP-134: Parser Error Recovery
+//
+// Mission: Report every syntax error in a file, not just the first
+// - parse_recovering() returns the items that parsed plus all errors
+// - After an error the parser skips to the next fun/struct, `;` or the
+//   failed item's closing `}`
+// - parse() keeps stopping at the first error
+//
+// Test Coverage:
+// - test_two_broken_functions_one_valid: both errors, valid function kept
+// - test_error_positions_are_distinct: errors point at their own lines
+// - test_top_level_statements_resync_at_semicolon: let a = ; let b = 2;
+// - test_clean_source_has_no_errors: same AST as parse()
+// - test_unclosed_brace_resyncs_at_next_fun: missing } before the next item
+// - test_tokenizer_error_is_returned_alone: unterminated string
+// - test_unclosed_parameter_list_terminates: fun f( { after a valid function
+// - test_unsupported_parameter_syntax_is_an_error: generic, array and mut params
+
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+
+/// Helper: names of the parsed functions, in order
+fn function_names(nodes: &[AstNode]) -> Vec<&str> {
+    nodes
+        .iter()
+        .filter_map(|node| match node {
+            AstNode::FunctionDef { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Two broken functions around a valid one
+const BROKEN: &str = "fun first() {
+    let x = ;
+}
+fun valid(n) {
+    n + 1
+}
+fun second() {
+    let y = (1 + ;
+    y
+}";
+
+/// Test: Both broken functions are reported and the valid one is parsed
+#[test]
+fn test_two_broken_functions_one_valid() {
+    let (ast, errors) = Parser::new(BROKEN).parse_recovering();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert_eq!(function_names(ast.nodes()), vec!["valid"]);
+    assert!(Parser::new(BROKEN).parse().is_err());
+}
+
+/// Test: Each error is located in its own function
+#[test]
+fn test_error_positions_are_distinct() {
+    let (_, errors) = Parser::new(BROKEN).parse_recovering();
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|e| e.span().expect("located error").line)
+        .collect();
+    assert_eq!(lines, vec![2, 8]);
+}
+
+/// Test: Top-level statements resume after the failed statement's `;`
+#[test]
+fn test_top_level_statements_resync_at_semicolon() {
+    let (ast, errors) = Parser::new("let a = ;\nlet b = 2;\nlet c = 3 + ;\nb").parse_recovering();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert_eq!(ast.nodes().len(), 2, "{:?}", ast.nodes());
+}
+
+/// Test: Without errors the result matches parse()
+#[test]
+fn test_clean_source_has_no_errors() {
+    let source = "fun add(a, b) { a + b }\nadd(1, 2)";
+    let (ast, errors) = Parser::new(source).parse_recovering();
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(ast.nodes(), Parser::new(source).parse().unwrap().nodes());
+}
+
+/// Test: A function missing its closing brace does not swallow the next one
+#[test]
+fn test_unclosed_brace_resyncs_at_next_fun() {
+    let source = "fun broken() {
+    let x = 1 +
+fun after() { 2 }";
+    let (ast, errors) = Parser::new(source).parse_recovering();
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(function_names(ast.nodes()), vec!["after"]);
+}
+
+/// Test: A tokenizer error leaves nothing to recover
+#[test]
+fn test_tokenizer_error_is_returned_alone() {
+    let (ast, errors) = Parser::new("fun f() { 1 }\nlet s = \"open").parse_recovering();
+    assert!(ast.nodes().is_empty());
+    assert_eq!(errors.len(), 1);
+}
+
+/// Test: An unclosed parameter list is an error, not an endless loop
+#[test]
+fn test_unclosed_parameter_list_terminates() {
+    let (ast, errors) = Parser::new("fun g() { return 2; } fun f( {").parse_recovering();
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(function_names(ast.nodes()), vec!["g"]);
+}
+
+/// Test: Parameter syntax the parser does not model is reported as an error
+#[test]
+fn test_unsupported_parameter_syntax_is_an_error() {
+    for source in [
+        "fun f(x: Option<i64>) { x }",
+        "fun f(v: Vec<i64>) { v }",
+        "fun f(x: [i64; 3]) { x }",
+        "fun f(mut x) { x }",
+        "fun f(a b) { a }",
+    ] {
+        assert!(Parser::new(source).parse().is_err(), "{}", source);
+    }
+}
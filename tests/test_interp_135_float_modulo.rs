@@ -0,0 +1,75 @@
+// INTERP-135: Float Modulo
+//
+// Mission: `%` works on floats as well as integers
+// - Integer operands keep integer remainder: 7 % 3 is 1
+// - If either operand is a float the result is a float: 5.5 % 2.0 is 1.5
+// - The result takes the sign of the left operand, as in Rust
+// - A zero divisor is a DivisionByZero error for floats too
+//
+// Test Coverage:
+// - test_float_modulo: 5.5 % 2.0, 7.25 % 0.5
+// - test_integer_modulo_unchanged: 7 % 3 stays an Integer
+// - test_mixed_operands_promote_to_float: 7 % 2.5, 7.5 % 2
+// - test_negative_dividend_keeps_sign: (0.0 - 5.5) % 2.0
+// - test_float_zero_divisor_errors: 3.0 % 0.0, 3 % 0.0
+// - test_compound_modulo_assignment: x %= 2.0
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Test: Float remainders
+#[test]
+fn test_float_modulo() {
+    assert_eq!(run("5.5 % 2.0").unwrap(), Value::Float(1.5));
+    assert_eq!(run("7.25 % 0.5").unwrap(), Value::Float(0.25));
+}
+
+/// Test: Integer modulo still produces an integer
+#[test]
+fn test_integer_modulo_unchanged() {
+    assert_eq!(run("7 % 3").unwrap(), Value::Integer(1));
+}
+
+/// Test: One float operand makes the remainder a float
+#[test]
+fn test_mixed_operands_promote_to_float() {
+    assert_eq!(run("7 % 2.5").unwrap(), Value::Float(2.0));
+    assert_eq!(run("7.5 % 2").unwrap(), Value::Float(1.5));
+}
+
+/// Test: The remainder has the sign of the dividend
+#[test]
+fn test_negative_dividend_keeps_sign() {
+    assert_eq!(run("(0.0 - 5.5) % 2.0").unwrap(), Value::Float(-1.5));
+}
+
+/// Test: A zero float divisor is an error rather than NaN
+#[test]
+fn test_float_zero_divisor_errors() {
+    for source in ["3.0 % 0.0", "3 % 0.0"] {
+        let err = run(source).unwrap_err();
+        assert!(
+            matches!(err, EvalError::ValueError(ValueError::DivisionByZero)),
+            "{}: {:?}",
+            source,
+            err
+        );
+    }
+}
+
+/// Test: %= uses the same float rules
+#[test]
+fn test_compound_modulo_assignment() {
+    assert_eq!(
+        run("let mut x = 9.5;\nx %= 2.0;\nx").unwrap(),
+        Value::Float(1.5)
+    );
+}
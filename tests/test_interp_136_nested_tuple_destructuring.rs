@@ -0,0 +1,105 @@
+// INTERP-136: Nested Tuple Destructuring
+//
+// Mission: let patterns destructure tuples at any depth
+// - `let ((a, b), c) = ((1, 2), 3)` binds a=1, b=2, c=3
+// - A nested tuple may appear in any position: `let (tx, (a, b)) = ..`
+// - `_` skips an element at any level
+// - Arity and type mismatches report the element path (e.g. `at .1`)
+//
+// Test Coverage:
+// - test_two_level_nesting: ((a, b), c)
+// - test_nested_tuple_in_last_position: (tx, (a, b)) from a function result
+// - test_three_level_nesting: (((a, b), c), d)
+// - test_wildcard_inside_nested_tuple: ((_, b), _)
+// - test_inner_arity_mismatch_reports_path: (a, (b, c)) against (1, (2, 3, 4))
+// - test_inner_non_tuple_reports_path: (a, (b, c)) against (1, 2)
+// - test_flat_destructuring_errors_unchanged: top-level messages keep their form
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Helper: the message of an UnsupportedOperation error
+fn unsupported_message(source: &str) -> String {
+    match run(source).unwrap_err() {
+        EvalError::UnsupportedOperation { operation } => operation,
+        other => panic!("expected UnsupportedOperation, got {:?}", other),
+    }
+}
+
+/// Test: A tuple nested in the first position binds every name
+#[test]
+fn test_two_level_nesting() {
+    let source = "let ((a, b), c) = ((1, 2), 3);\na * 100 + b * 10 + c";
+    assert_eq!(run(source).unwrap(), Value::Integer(123));
+}
+
+/// Test: A tuple nested in the last position, from a returned value
+#[test]
+fn test_nested_tuple_in_last_position() {
+    let source = "fun pair() {
+    (\"tx\", (4, 5))
+}
+let (tx, (a, b)) = pair();
+tx + (a + b).to_string()";
+    assert_eq!(run(source).unwrap(), Value::string("tx9".to_string()));
+}
+
+/// Test: Three levels of nesting
+#[test]
+fn test_three_level_nesting() {
+    let source = "let (((a, b), c), d) = (((1, 2), 3), 4);\na * 1000 + b * 100 + c * 10 + d";
+    assert_eq!(run(source).unwrap(), Value::Integer(1234));
+}
+
+/// Test: `_` skips elements inside and outside the nested tuple
+#[test]
+fn test_wildcard_inside_nested_tuple() {
+    let source = "let ((_, b), _) = ((1, 2), 3);\nb";
+    assert_eq!(run(source).unwrap(), Value::Integer(2));
+}
+
+/// Test: An inner tuple of the wrong size names its position
+#[test]
+fn test_inner_arity_mismatch_reports_path() {
+    let message = unsupported_message("let (a, (b, c)) = (1, (2, 3, 4));");
+    assert_eq!(
+        message,
+        "tuple destructuring: expected 2 elements at .1, got 3"
+    );
+}
+
+/// Test: A non-tuple where a nested tuple is expected names its position
+#[test]
+fn test_inner_non_tuple_reports_path() {
+    let message = unsupported_message("let (a, (b, c)) = (1, 2);");
+    assert_eq!(
+        message,
+        "tuple destructuring requires tuple at .1, got Integer"
+    );
+    let message = unsupported_message("let ((a, (b, c)), d) = ((1, (2, 3, 4)), 5);");
+    assert_eq!(
+        message,
+        "tuple destructuring: expected 2 elements at .0.1, got 3"
+    );
+}
+
+/// Test: Flat destructuring keeps its original error messages
+#[test]
+fn test_flat_destructuring_errors_unchanged() {
+    assert_eq!(
+        unsupported_message("let (a, b) = (1, 2, 3);"),
+        "tuple destructuring: expected 2 elements, got 3"
+    );
+    assert_eq!(
+        unsupported_message("let ((a, b), c) = 5;"),
+        "tuple destructuring requires tuple, got Integer"
+    );
+}
@@ -0,0 +1,122 @@
+// INTERP-137: Struct Field Assignment
+//
+// Mission: `obj.field = value` updates a struct in place
+// - Assigning an existing field replaces its value
+// - Nested targets (`line.start.x = ..`, `points[0].x = ..`) write back
+//   through every level to the variable
+// - Assigning a field the struct does not have is a KeyNotFound error
+//   (struct fields are fixed); on a HashMap the key is inserted
+//
+// Test Coverage:
+// - test_assign_existing_field: p.x = 10 then p.x
+// - test_other_fields_unchanged: p.y keeps its value
+// - test_nested_field_assignment: line.start.x = 5
+// - test_field_of_vector_element: points[1].y = 7
+// - test_field_assignment_inside_method_body: self.count = self.count + 1
+// - test_unknown_struct_field_is_error: p.z = 1
+// - test_hashmap_field_assignment_inserts: m.key = 1 adds the key
+// - test_field_assignment_on_non_struct: n.x = 1 on an integer
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::{Value, ValueError};
+
+/// Helper: parse and evaluate a program
+fn run(source: &str) -> Result<Value, EvalError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast)
+}
+
+/// Program prefix: a two-field struct
+const POINT: &str = "struct Point { x: i64, y: i64 }\n";
+
+/// Test: An existing field can be overwritten and read back
+#[test]
+fn test_assign_existing_field() {
+    let source = format!(
+        "{}let mut p = Point {{ x: 1, y: 2 }};\np.x = 10;\np.x",
+        POINT
+    );
+    assert_eq!(run(&source).unwrap(), Value::Integer(10));
+}
+
+/// Test: Only the assigned field changes
+#[test]
+fn test_other_fields_unchanged() {
+    let source = format!(
+        "{}let mut p = Point {{ x: 1, y: 2 }};\np.x = 10;\np.y",
+        POINT
+    );
+    assert_eq!(run(&source).unwrap(), Value::Integer(2));
+}
+
+/// Test: A field of a nested struct is written back to the outer struct
+#[test]
+fn test_nested_field_assignment() {
+    let source = format!(
+        "{}struct Line {{ start: Point, end: Point }}
+let mut line = Line {{ start: Point {{ x: 0, y: 0 }}, end: Point {{ x: 3, y: 4 }} }};
+line.start.x = 5;
+line.start.x * 10 + line.end.x",
+        POINT
+    );
+    assert_eq!(run(&source).unwrap(), Value::Integer(53));
+}
+
+/// Test: A field of a struct stored in a vector
+#[test]
+fn test_field_of_vector_element() {
+    let source = format!(
+        "{}let mut points = [Point {{ x: 1, y: 2 }}, Point {{ x: 3, y: 4 }}];
+points[1].y = 7;
+points[1].y + points[0].y",
+        POINT
+    );
+    assert_eq!(run(&source).unwrap(), Value::Integer(9));
+}
+
+/// Test: A method can update a field on its own copy of self
+#[test]
+fn test_field_assignment_inside_method_body() {
+    let source = "struct Counter { count: i64 }
+impl Counter {
+    fun bumped(self) {
+        self.count = self.count + 1;
+        self
+    }
+}
+let c = Counter { count: 41 };
+c.bumped().count";
+    assert_eq!(run(source).unwrap(), Value::Integer(42));
+}
+
+/// Test: Struct fields are fixed, so an unknown field is an error
+#[test]
+fn test_unknown_struct_field_is_error() {
+    let source = format!("{}let mut p = Point {{ x: 1, y: 2 }};\np.z = 3;", POINT);
+    let err = run(&source).unwrap_err();
+    assert!(
+        matches!(err, EvalError::ValueError(ValueError::KeyNotFound { ref key }) if key == "z"),
+        "{:?}",
+        err
+    );
+}
+
+/// Test: On a HashMap a field assignment inserts the key
+#[test]
+fn test_hashmap_field_assignment_inserts() {
+    let source = "let mut m = {\"a\": 1};\nm.b = 2;\nm.a + m.b";
+    assert_eq!(run(source).unwrap(), Value::Integer(3));
+}
+
+/// Test: Assigning a field of a non-struct value is unsupported
+#[test]
+fn test_field_assignment_on_non_struct() {
+    let err = run("let mut n = 5;\nn.x = 1;").unwrap_err();
+    assert!(
+        matches!(err, EvalError::UnsupportedOperation { ref operation } if operation == "field assignment on Integer"),
+        "{:?}",
+        err
+    );
+}
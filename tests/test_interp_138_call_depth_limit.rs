@@ -0,0 +1,79 @@
+// INTERP-138: Configurable Recursion Depth Limit
+//
+// Mission: Let embedders choose how deep recursion may go
+// - The default limit stays at 30 calls, safe on 2MB test threads
+// - `with_max_call_depth(n)` raises (or lowers) the limit per evaluator
+// - Exceeding the limit is still EvalError::StackOverflow
+//
+// Test Coverage:
+// - test_default_limit_catches_overflow: depth(40) fails with the default
+// - test_raised_limit_allows_deep_recursion: depth(1000) with a limit of 2000
+// - test_raised_limit_still_catches_infinite_recursion: forever() stops
+// - test_lowered_limit: depth(10) fails with a limit of 5
+
+use ruchyruchy::interpreter::evaluator::{EvalError, Evaluator};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Program prefix: recursion to a requested depth
+const DEPTH: &str = "fun depth(n) {\n    if n == 0 { 0 } else { 1 + depth(n - 1) }\n}\n";
+
+/// Helper: evaluate a program on a thread with room for deep recursion
+///
+/// `limit` of None keeps the default call-depth limit.
+fn run_deep(source: String, limit: Option<usize>) -> Result<Value, EvalError> {
+    std::thread::Builder::new()
+        .stack_size(512 * 1024 * 1024)
+        .spawn(move || {
+            let ast = Parser::new(&source).parse().expect("parse");
+            let mut eval = match limit {
+                Some(depth) => Evaluator::new().with_max_call_depth(depth),
+                None => Evaluator::new(),
+            };
+            eval.eval_program(&ast)
+        })
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+/// Helper: unwrap call-stack context to the underlying error
+fn root_error(err: EvalError) -> EvalError {
+    match err {
+        EvalError::WithCallStack { error, .. } => root_error(*error),
+        other => other,
+    }
+}
+
+/// Test: Without configuration, recursion past 30 calls is caught
+#[test]
+fn test_default_limit_catches_overflow() {
+    let err = run_deep(format!("{}depth(40)", DEPTH), None).unwrap_err();
+    assert!(matches!(root_error(err), EvalError::StackOverflow));
+}
+
+/// Test: A raised limit lets legitimately deep recursion finish
+#[test]
+fn test_raised_limit_allows_deep_recursion() {
+    let value = run_deep(format!("{}depth(1000)", DEPTH), Some(2000)).unwrap();
+    assert_eq!(value, Value::Integer(1000));
+}
+
+/// Test: Unbounded recursion still stops at the raised limit
+#[test]
+fn test_raised_limit_still_catches_infinite_recursion() {
+    let source = "fun forever(n) {\n    forever(n + 1)\n}\nforever(0)".to_string();
+    let err = run_deep(source, Some(500)).unwrap_err();
+    assert!(matches!(root_error(err), EvalError::StackOverflow));
+}
+
+/// Test: The limit can also be lowered below the default
+#[test]
+fn test_lowered_limit() {
+    let err = run_deep(format!("{}depth(10)", DEPTH), Some(5)).unwrap_err();
+    assert!(matches!(root_error(err), EvalError::StackOverflow));
+    assert_eq!(
+        run_deep(format!("{}depth(3)", DEPTH), Some(5)).unwrap(),
+        Value::Integer(3)
+    );
+}
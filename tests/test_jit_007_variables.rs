@@ -40,6 +40,7 @@ fn test_compile_let_declaration() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(42)),
             },
             AstNode::Identifier("x".to_string()),
@@ -67,6 +68,7 @@ fn test_compile_variable_assignment() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Assignment {
@@ -98,6 +100,7 @@ fn test_compile_variable_increment() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Assignment {
@@ -134,6 +137,7 @@ fn test_compile_while_loop_with_counter() {
         statements: vec![
             AstNode::LetDecl {
                 name: "i".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::WhileLoop {
@@ -177,10 +181,12 @@ fn test_compile_while_loop_sum() {
         statements: vec![
             AstNode::LetDecl {
                 name: "sum".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::LetDecl {
                 name: "i".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::WhileLoop {
@@ -238,10 +244,12 @@ fn test_compile_multiple_variables() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(2)),
             },
             AstNode::BinaryOp {
@@ -71,6 +71,7 @@ fn test_compile_return_from_loop() {
         statements: vec![
             AstNode::LetDecl {
                 name: "i".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::WhileLoop {
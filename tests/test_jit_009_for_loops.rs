@@ -39,6 +39,7 @@ fn test_compile_for_loop_sum() {
         statements: vec![
             AstNode::LetDecl {
                 name: "sum".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::ForLoop {
@@ -46,6 +47,7 @@ fn test_compile_for_loop_sum() {
                 iterable: Box::new(AstNode::Range {
                     start: Box::new(AstNode::IntegerLiteral(0)),
                     end: Box::new(AstNode::IntegerLiteral(5)),
+                    inclusive: false,
                 }),
                 body: vec![AstNode::Assignment {
                     name: "sum".to_string(),
@@ -81,6 +83,7 @@ fn test_compile_for_loop_variable_range() {
         statements: vec![
             AstNode::LetDecl {
                 name: "sum".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::ForLoop {
@@ -88,6 +91,7 @@ fn test_compile_for_loop_variable_range() {
                 iterable: Box::new(AstNode::Range {
                     start: Box::new(AstNode::IntegerLiteral(0)),
                     end: Box::new(AstNode::Identifier("n".to_string())),
+                    inclusive: false,
                 }),
                 body: vec![AstNode::Assignment {
                     name: "sum".to_string(),
@@ -126,6 +130,7 @@ fn test_compile_for_loop_nonzero_start() {
         statements: vec![
             AstNode::LetDecl {
                 name: "sum".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::ForLoop {
@@ -133,6 +138,7 @@ fn test_compile_for_loop_nonzero_start() {
                 iterable: Box::new(AstNode::Range {
                     start: Box::new(AstNode::IntegerLiteral(1)),
                     end: Box::new(AstNode::Identifier("n".to_string())),
+                    inclusive: false,
                 }),
                 body: vec![AstNode::Assignment {
                     name: "sum".to_string(),
@@ -174,6 +180,7 @@ fn test_compile_for_loop_early_return() {
                 iterable: Box::new(AstNode::Range {
                     start: Box::new(AstNode::IntegerLiteral(0)),
                     end: Box::new(AstNode::Identifier("n".to_string())),
+                    inclusive: false,
                 }),
                 body: vec![AstNode::IfExpr {
                     condition: Box::new(AstNode::BinaryOp {
@@ -218,6 +225,7 @@ fn test_compile_for_loop_countdown() {
         statements: vec![
             AstNode::LetDecl {
                 name: "count".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::ForLoop {
@@ -229,6 +237,7 @@ fn test_compile_for_loop_countdown() {
                         right: Box::new(AstNode::Identifier("n".to_string())),
                     }),
                     end: Box::new(AstNode::IntegerLiteral(10)),
+                    inclusive: false,
                 }),
                 body: vec![AstNode::Assignment {
                     name: "count".to_string(),
@@ -138,9 +138,8 @@ fn test_compile_function_call_multiple_params() {
 ///
 /// Validates: fun factorial(n) { if (n <= 1) { return 1; } return n * factorial(n - 1); }
 ///
-/// Note: Recursive functions require forward declaration support (planned for future release)
+/// JIT-031: compile_named_function resolves the self-call before finalizing
 #[test]
-#[ignore = "Recursive functions require forward declaration support"]
 fn test_compile_recursive_function_call() {
     let mut jit = JitCompiler::new().expect("Failed to create JIT compiler");
 
@@ -176,12 +175,9 @@ fn test_compile_recursive_function_call() {
     };
 
     let factorial: fn(i64) -> i64 = jit
-        .compile_function_with_params(&["n".to_string()], &factorial_body)
+        .compile_named_function("factorial", &["n".to_string()], &factorial_body)
         .expect("Should compile factorial function");
 
-    // Register for recursive calls
-    jit.register_function("factorial".to_string(), factorial as *const u8);
-
     // Test factorial
     assert_eq!(factorial(5), 120, "factorial(5) should be 120");
     assert_eq!(factorial(10), 3628800, "factorial(10) should be 3628800");
@@ -37,6 +37,7 @@ fn test_compile_array_literal_and_index() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(1),
@@ -73,6 +74,7 @@ fn test_compile_array_multiple_access() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(10),
@@ -116,6 +118,7 @@ fn test_compile_array_computed_index() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(5),
@@ -127,6 +130,7 @@ fn test_compile_array_computed_index() {
             },
             AstNode::LetDecl {
                 name: "i".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(2)),
             },
             AstNode::Return {
@@ -162,6 +166,7 @@ fn test_compile_array_in_loop() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(1),
@@ -173,6 +178,7 @@ fn test_compile_array_in_loop() {
             },
             AstNode::LetDecl {
                 name: "sum".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::ForLoop {
@@ -180,6 +186,7 @@ fn test_compile_array_in_loop() {
                 iterable: Box::new(AstNode::Range {
                     start: Box::new(AstNode::IntegerLiteral(0)),
                     end: Box::new(AstNode::IntegerLiteral(4)),
+                    inclusive: false,
                 }),
                 body: vec![AstNode::Assignment {
                     name: "sum".to_string(),
@@ -219,6 +226,7 @@ fn test_compile_empty_array() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral { elements: vec![] }),
             },
             AstNode::Return {
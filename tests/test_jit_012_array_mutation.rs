@@ -41,6 +41,7 @@ fn test_compile_array_element_compound_assignment() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(1),
@@ -91,6 +92,7 @@ fn test_compile_array_compound_assignment_computed_index() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(10),
@@ -101,6 +103,7 @@ fn test_compile_array_compound_assignment_computed_index() {
             },
             AstNode::LetDecl {
                 name: "i".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(2)),
             },
             // arr[i] += 70
@@ -144,6 +147,7 @@ fn test_compile_array_multiply_assignment() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(5),
@@ -195,6 +199,7 @@ fn test_compile_array_mutation_in_loop() {
         statements: vec![
             AstNode::LetDecl {
                 name: "arr".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VectorLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(1),
@@ -208,6 +213,7 @@ fn test_compile_array_mutation_in_loop() {
                 iterable: Box::new(AstNode::Range {
                     start: Box::new(AstNode::IntegerLiteral(0)),
                     end: Box::new(AstNode::IntegerLiteral(3)),
+                    inclusive: false,
                 }),
                 body: vec![
                     // arr[i] *= 2
@@ -60,6 +60,7 @@ fn test_compile_variable_negation() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::Return {
@@ -112,6 +113,7 @@ fn test_compile_logical_not_integer() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Return {
@@ -142,6 +144,7 @@ fn test_compile_logical_not_zero() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::Return {
@@ -197,6 +200,7 @@ fn test_compile_negation_in_expression() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::Return {
@@ -256,6 +260,7 @@ fn test_compile_logical_not_in_conditional() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(0)),
             },
             AstNode::IfExpr {
@@ -62,6 +62,7 @@ fn test_compile_string_in_variable() {
         statements: vec![
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StringLiteral("world".to_string())),
             },
             AstNode::Return {
@@ -180,10 +181,12 @@ fn test_compile_string_concatenation_variables() {
         statements: vec![
             AstNode::LetDecl {
                 name: "a".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StringLiteral("hello".to_string())),
             },
             AstNode::LetDecl {
                 name: "b".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StringLiteral(" world".to_string())),
             },
             AstNode::Return {
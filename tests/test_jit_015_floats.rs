@@ -74,6 +74,7 @@ fn test_compile_float_in_variable() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FloatLiteral(2.75)),
             },
             AstNode::Return {
@@ -264,10 +265,12 @@ fn test_compile_float_expression() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FloatLiteral(2.0)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FloatLiteral(3.0)),
             },
             AstNode::Return {
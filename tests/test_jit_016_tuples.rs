@@ -40,6 +40,7 @@ fn test_compile_tuple_literal() {
         statements: vec![
             AstNode::LetDecl {
                 name: "t".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(1),
@@ -75,6 +76,7 @@ fn test_compile_tuple_field_access() {
         statements: vec![
             AstNode::LetDecl {
                 name: "t".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(10),
@@ -111,6 +113,7 @@ fn test_compile_tuple_multiple_access() {
         statements: vec![
             AstNode::LetDecl {
                 name: "t".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(5),
@@ -154,6 +157,7 @@ fn test_compile_tuple_pair() {
         statements: vec![
             AstNode::LetDecl {
                 name: "pair".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(42), AstNode::IntegerLiteral(100)],
                 }),
@@ -189,6 +193,7 @@ fn test_compile_heterogeneous_tuple() {
         statements: vec![
             AstNode::LetDecl {
                 name: "mixed".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(42), AstNode::FloatLiteral(3.5)],
                 }),
@@ -222,6 +227,7 @@ fn test_compile_nested_tuple() {
         statements: vec![
             AstNode::LetDecl {
                 name: "t".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::TupleLiteral {
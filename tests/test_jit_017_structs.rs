@@ -60,6 +60,7 @@ fn test_compile_struct_literal() {
             struct_def,
             AstNode::LetDecl {
                 name: "p".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Point".to_string(),
                     fields: vec![
@@ -115,6 +116,7 @@ fn test_compile_struct_field_access() {
             struct_def,
             AstNode::LetDecl {
                 name: "p".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Point".to_string(),
                     fields: vec![
@@ -171,6 +173,7 @@ fn test_compile_struct_multiple_access() {
             struct_def,
             AstNode::LetDecl {
                 name: "p".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Point".to_string(),
                     fields: vec![
@@ -228,6 +231,7 @@ fn test_compile_struct_single_field() {
             struct_def,
             AstNode::LetDecl {
                 name: "w".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Wrapper".to_string(),
                     fields: vec![("value".to_string(), AstNode::IntegerLiteral(42))],
@@ -285,6 +289,7 @@ fn test_compile_struct_three_fields() {
             struct_def,
             AstNode::LetDecl {
                 name: "t".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Triple".to_string(),
                     fields: vec![
@@ -343,6 +348,7 @@ fn test_compile_multiple_struct_instances() {
             struct_def,
             AstNode::LetDecl {
                 name: "p1".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Point".to_string(),
                     fields: vec![
@@ -353,6 +359,7 @@ fn test_compile_multiple_struct_instances() {
             },
             AstNode::LetDecl {
                 name: "p2".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Point".to_string(),
                     fields: vec![
@@ -417,6 +424,7 @@ fn test_compile_heterogeneous_struct() {
             struct_def,
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::StructLiteral {
                     name: "Mixed".to_string(),
                     fields: vec![
@@ -40,6 +40,7 @@ fn test_compile_empty_hashmap() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral { pairs: vec![] }),
             },
             AstNode::Return {
@@ -69,6 +70,7 @@ fn test_compile_hashmap_literal() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral {
                     pairs: vec![
                         (AstNode::IntegerLiteral(1), AstNode::IntegerLiteral(10)),
@@ -102,6 +104,7 @@ fn test_compile_hashmap_lookup() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral {
                     pairs: vec![
                         (AstNode::IntegerLiteral(1), AstNode::IntegerLiteral(100)),
@@ -137,6 +140,7 @@ fn test_compile_hashmap_multiple_lookup() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral {
                     pairs: vec![
                         (AstNode::IntegerLiteral(10), AstNode::IntegerLiteral(1)),
@@ -173,6 +177,7 @@ fn test_compile_hashmap_computed_values() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral {
                     pairs: vec![
                         (
@@ -227,6 +232,7 @@ fn test_compile_hashmap_insertion() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral { pairs: vec![] }),
             },
             AstNode::CompoundAssignment {
@@ -266,6 +272,7 @@ fn test_compile_hashmap_string_keys() {
         statements: vec![
             AstNode::LetDecl {
                 name: "m".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::HashMapLiteral {
                     pairs: vec![(
                         AstNode::StringLiteral("key1".to_string()),
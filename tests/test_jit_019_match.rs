@@ -49,6 +49,7 @@ fn test_compile_match_integer_literals() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(2)),
             },
             AstNode::Return {
@@ -57,14 +58,17 @@ fn test_compile_match_integer_literals() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(10)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(20)],
                         },
                         MatchArm {
                             pattern: Pattern::Wildcard,
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(0)],
                         },
                     ],
@@ -97,6 +101,7 @@ fn test_compile_match_wildcard_only() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(100)),
             },
             AstNode::Return {
@@ -104,6 +109,7 @@ fn test_compile_match_wildcard_only() {
                     expr: Box::new(AstNode::Identifier("x".to_string())),
                     arms: vec![MatchArm {
                         pattern: Pattern::Wildcard,
+                        guard: None,
                         body: vec![AstNode::IntegerLiteral(42)],
                     }],
                 })),
@@ -135,6 +141,7 @@ fn test_compile_match_variable_binding() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Return {
@@ -142,6 +149,7 @@ fn test_compile_match_variable_binding() {
                     expr: Box::new(AstNode::Identifier("x".to_string())),
                     arms: vec![MatchArm {
                         pattern: Pattern::Identifier("n".to_string()),
+                        guard: None,
                         body: vec![AstNode::BinaryOp {
                             left: Box::new(AstNode::Identifier("n".to_string())),
                             op: ruchyruchy::interpreter::parser::BinaryOperator::Add,
@@ -180,6 +188,7 @@ fn test_compile_match_first_arm() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::Return {
@@ -188,18 +197,22 @@ fn test_compile_match_first_arm() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(100)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(200)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(3)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(300)],
                         },
                         MatchArm {
                             pattern: Pattern::Wildcard,
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(0)],
                         },
                     ],
@@ -235,6 +248,7 @@ fn test_compile_match_last_literal_arm() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(3)),
             },
             AstNode::Return {
@@ -243,18 +257,22 @@ fn test_compile_match_last_literal_arm() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(100)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(200)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(3)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(300)],
                         },
                         MatchArm {
                             pattern: Pattern::Wildcard,
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(0)],
                         },
                     ],
@@ -289,6 +307,7 @@ fn test_compile_match_wildcard_fallthrough() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(99)),
             },
             AstNode::Return {
@@ -297,14 +316,17 @@ fn test_compile_match_wildcard_fallthrough() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(100)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(200)],
                         },
                         MatchArm {
                             pattern: Pattern::Wildcard,
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(999)],
                         },
                     ],
@@ -338,6 +360,7 @@ fn test_compile_match_boolean_literals() {
         statements: vec![
             AstNode::LetDecl {
                 name: "flag".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::BooleanLiteral(true)),
             },
             AstNode::Return {
@@ -346,10 +369,12 @@ fn test_compile_match_boolean_literals() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::BooleanLiteral(true)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(1)],
                         },
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::BooleanLiteral(false)),
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(0)],
                         },
                     ],
@@ -386,6 +411,7 @@ fn test_compile_match_complex_body() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::Return {
@@ -394,9 +420,11 @@ fn test_compile_match_complex_body() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                            guard: None,
                             body: vec![
                                 AstNode::LetDecl {
                                     name: "y".to_string(),
+                                    mutable: false,
                                     value: Box::new(AstNode::IntegerLiteral(10)),
                                 },
                                 AstNode::BinaryOp {
@@ -408,6 +436,7 @@ fn test_compile_match_complex_body() {
                         },
                         MatchArm {
                             pattern: Pattern::Wildcard,
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(0)],
                         },
                     ],
@@ -446,10 +475,12 @@ fn test_compile_nested_match() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(1)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(2)),
             },
             AstNode::Return {
@@ -458,15 +489,18 @@ fn test_compile_nested_match() {
                     arms: vec![
                         MatchArm {
                             pattern: Pattern::Literal(AstNode::IntegerLiteral(1)),
+                            guard: None,
                             body: vec![AstNode::MatchExpr {
                                 expr: Box::new(AstNode::Identifier("y".to_string())),
                                 arms: vec![
                                     MatchArm {
                                         pattern: Pattern::Literal(AstNode::IntegerLiteral(2)),
+                                        guard: None,
                                         body: vec![AstNode::IntegerLiteral(12)],
                                     },
                                     MatchArm {
                                         pattern: Pattern::Wildcard,
+                                        guard: None,
                                         body: vec![AstNode::IntegerLiteral(10)],
                                     },
                                 ],
@@ -474,6 +508,7 @@ fn test_compile_nested_match() {
                         },
                         MatchArm {
                             pattern: Pattern::Wildcard,
+                            guard: None,
                             body: vec![AstNode::IntegerLiteral(0)],
                         },
                     ],
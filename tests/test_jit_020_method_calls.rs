@@ -61,6 +61,7 @@ fn test_compile_method_call_no_args() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Return {
@@ -114,10 +115,12 @@ fn test_compile_method_call_one_arg() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(20)),
             },
             AstNode::Return {
@@ -179,14 +182,17 @@ fn test_compile_method_call_multiple_args() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(20)),
             },
             AstNode::LetDecl {
                 name: "z".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(30)),
             },
             AstNode::Return {
@@ -288,10 +294,12 @@ fn test_compile_method_call_on_expression() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Return {
@@ -367,10 +375,12 @@ fn test_compile_chained_method_calls() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Return {
@@ -428,10 +438,12 @@ fn test_compile_method_call_in_expression() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::Return {
@@ -10,6 +10,7 @@
 // 3. Destructuring from tuple variables: let t = (1, 2); let (a, b) = t;
 // 4. Using destructured values in expressions
 // 5. Two-element, three-element, and larger tuples
+// 6. Nested patterns and wildcards: let ((a, _), b) = ((1, 2), 3); (INTERP-136)
 //
 // Why this is critical:
 // - Pattern matching is fundamental in modern languages
@@ -25,9 +26,17 @@
 //
 // Method: Incremental TDD starting with simplest cases
 
-use ruchyruchy::interpreter::parser::{AstNode, BinaryOperator};
+use ruchyruchy::interpreter::parser::{AstNode, BinaryOperator, Pattern};
 use ruchyruchy::jit::JitCompiler;
 
+/// Helper: one identifier pattern per name
+fn names(names: &[&str]) -> Vec<Pattern> {
+    names
+        .iter()
+        .map(|name| Pattern::Identifier(name.to_string()))
+        .collect()
+}
+
 /// Test: Simple two-element tuple destructuring from literal
 ///
 /// Validates: let (a, b) = (10, 20); return a + b;
@@ -42,7 +51,7 @@ fn test_compile_tuple_destruct_two_elements() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec!["a".to_string(), "b".to_string()],
+                patterns: names(&["a", "b"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(10), AstNode::IntegerLiteral(20)],
                 }),
@@ -78,7 +87,7 @@ fn test_compile_tuple_destruct_three_elements() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec!["x".to_string(), "y".to_string(), "z".to_string()],
+                patterns: names(&["x", "y", "z"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(5),
@@ -124,12 +133,13 @@ fn test_compile_tuple_destruct_from_variable() {
         statements: vec![
             AstNode::LetDecl {
                 name: "t".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(100), AstNode::IntegerLiteral(200)],
                 }),
             },
             AstNode::TupleDestruct {
-                names: vec!["a".to_string(), "b".to_string()],
+                patterns: names(&["a", "b"]),
                 value: Box::new(AstNode::Identifier("t".to_string())),
             },
             AstNode::Return {
@@ -163,7 +173,7 @@ fn test_compile_tuple_destruct_usage() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec!["x".to_string(), "y".to_string()],
+                patterns: names(&["x", "y"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(7), AstNode::IntegerLiteral(3)],
                 }),
@@ -200,7 +210,7 @@ fn test_compile_tuple_destruct_with_reassignment() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec!["a".to_string(), "b".to_string()],
+                patterns: names(&["a", "b"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(10), AstNode::IntegerLiteral(20)],
                 }),
@@ -245,13 +255,13 @@ fn test_compile_multiple_tuple_destructs() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec!["a".to_string(), "b".to_string()],
+                patterns: names(&["a", "b"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(1), AstNode::IntegerLiteral(2)],
                 }),
             },
             AstNode::TupleDestruct {
-                names: vec!["c".to_string(), "d".to_string()],
+                patterns: names(&["c", "d"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(3), AstNode::IntegerLiteral(4)],
                 }),
@@ -297,13 +307,13 @@ fn test_compile_tuple_destruct_swap() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec!["a".to_string(), "b".to_string()],
+                patterns: names(&["a", "b"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![AstNode::IntegerLiteral(10), AstNode::IntegerLiteral(20)],
                 }),
             },
             AstNode::TupleDestruct {
-                names: vec!["x".to_string(), "y".to_string()],
+                patterns: names(&["x", "y"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::Identifier("b".to_string()),
@@ -342,12 +352,7 @@ fn test_compile_tuple_destruct_four_elements() {
     let body = AstNode::Block {
         statements: vec![
             AstNode::TupleDestruct {
-                names: vec![
-                    "a".to_string(),
-                    "b".to_string(),
-                    "c".to_string(),
-                    "d".to_string(),
-                ],
+                patterns: names(&["a", "b", "c", "d"]),
                 value: Box::new(AstNode::TupleLiteral {
                     elements: vec![
                         AstNode::IntegerLiteral(2),
@@ -381,3 +386,46 @@ fn test_compile_tuple_destruct_four_elements() {
 
     assert_eq!(main(), 120, "a * b * c * d should be 2 * 3 * 4 * 5 = 120");
 }
+
+/// Test: Nested tuple destructuring with a wildcard
+///
+/// Validates: let ((a, _), b) = ((4, 99), 6); return a * b;
+#[test]
+fn test_compile_nested_tuple_destruct() {
+    let mut jit = JitCompiler::new().expect("Failed to create JIT compiler");
+
+    let body = AstNode::Block {
+        statements: vec![
+            AstNode::TupleDestruct {
+                patterns: vec![
+                    Pattern::Tuple(vec![
+                        Pattern::Identifier("a".to_string()),
+                        Pattern::Wildcard,
+                    ]),
+                    Pattern::Identifier("b".to_string()),
+                ],
+                value: Box::new(AstNode::TupleLiteral {
+                    elements: vec![
+                        AstNode::TupleLiteral {
+                            elements: vec![AstNode::IntegerLiteral(4), AstNode::IntegerLiteral(99)],
+                        },
+                        AstNode::IntegerLiteral(6),
+                    ],
+                }),
+            },
+            AstNode::Return {
+                value: Some(Box::new(AstNode::BinaryOp {
+                    left: Box::new(AstNode::Identifier("a".to_string())),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(AstNode::Identifier("b".to_string())),
+                })),
+            },
+        ],
+    };
+
+    let main: fn() -> i64 = jit
+        .compile_function_with_params(&[], &body)
+        .expect("Should compile nested tuple destructuring");
+
+    assert_eq!(main(), 24, "a * b should be 4 * 6 = 24");
+}
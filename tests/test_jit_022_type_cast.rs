@@ -43,6 +43,7 @@ fn test_compile_int_to_float_literal() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TypeCast {
                     expr: Box::new(AstNode::IntegerLiteral(42)),
                     target_type: "f64".to_string(),
@@ -80,10 +81,12 @@ fn test_compile_int_to_float_variable() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TypeCast {
                     expr: Box::new(AstNode::Identifier("x".to_string())),
                     target_type: "f64".to_string(),
@@ -119,6 +122,7 @@ fn test_compile_float_to_int_literal() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TypeCast {
                     expr: Box::new(AstNode::FloatLiteral(42.7)),
                     target_type: "i64".to_string(),
@@ -153,10 +157,12 @@ fn test_compile_float_to_int_variable() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FloatLiteral(99.9)),
             },
             AstNode::LetDecl {
                 name: "y".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::TypeCast {
                     expr: Box::new(AstNode::Identifier("x".to_string())),
                     target_type: "i64".to_string(),
@@ -190,6 +196,7 @@ fn test_compile_cast_in_float_arithmetic() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::Return {
@@ -261,6 +268,7 @@ fn test_compile_roundtrip_cast() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(42)),
             },
             AstNode::Return {
@@ -297,6 +305,7 @@ fn test_compile_negative_int_to_float() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::UnaryOp {
                     op: ruchyruchy::interpreter::parser::UnaryOperator::Negate,
                     operand: Box::new(AstNode::IntegerLiteral(10)),
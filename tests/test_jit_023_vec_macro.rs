@@ -43,6 +43,7 @@ fn test_compile_vec_macro_empty() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![],
                     repeat_count: None,
@@ -77,6 +78,7 @@ fn test_compile_vec_macro_list_form() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![
                         AstNode::IntegerLiteral(10),
@@ -117,6 +119,7 @@ fn test_compile_vec_macro_repeat_form() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![AstNode::IntegerLiteral(42)],
                     repeat_count: Some(Box::new(AstNode::IntegerLiteral(5))),
@@ -154,10 +157,12 @@ fn test_compile_vec_macro_repeat_variable_count() {
         statements: vec![
             AstNode::LetDecl {
                 name: "n".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(3)),
             },
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![AstNode::IntegerLiteral(100)],
                     repeat_count: Some(Box::new(AstNode::Identifier("n".to_string()))),
@@ -194,6 +199,7 @@ fn test_compile_vec_macro_computed_elements() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![
                         AstNode::BinaryOp {
@@ -248,6 +254,7 @@ fn test_compile_vec_macro_single_element() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![AstNode::IntegerLiteral(42)],
                     repeat_count: None,
@@ -284,6 +291,7 @@ fn test_compile_vec_macro_repeat_expression() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![AstNode::BinaryOp {
                         left: Box::new(AstNode::IntegerLiteral(10)),
@@ -338,6 +346,7 @@ fn test_compile_vec_macro_repeat_zero() {
         statements: vec![
             AstNode::LetDecl {
                 name: "v".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::VecMacro {
                     elements: vec![AstNode::IntegerLiteral(42)],
                     repeat_count: Some(Box::new(AstNode::IntegerLiteral(0))),
@@ -48,10 +48,12 @@ fn test_compile_fstring_single_interpolation() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(42)),
             },
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "value: {x}".to_string(),
                 }),
@@ -84,6 +86,7 @@ fn test_compile_fstring_expression_interpolation() {
         statements: vec![
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "result: {10 + 5}".to_string(),
                 }),
@@ -118,14 +121,17 @@ fn test_compile_fstring_multiple_interpolations() {
         statements: vec![
             AstNode::LetDecl {
                 name: "a".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "b".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(20)),
             },
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "{a} + {b} = {a + b}".to_string(),
                 }),
@@ -162,6 +168,7 @@ fn test_compile_fstring_plain_text() {
         statements: vec![
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "plain text".to_string(),
                 }),
@@ -194,6 +201,7 @@ fn test_compile_fstring_empty() {
         statements: vec![
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "".to_string(),
                 }),
@@ -227,10 +235,12 @@ fn test_compile_fstring_nested_expression() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(5)),
             },
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "result: {x * 2 + 1}".to_string(),
                 }),
@@ -265,14 +275,17 @@ fn test_compile_fstring_only_interpolations() {
         statements: vec![
             AstNode::LetDecl {
                 name: "a".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(10)),
             },
             AstNode::LetDecl {
                 name: "b".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::IntegerLiteral(20)),
             },
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "{a}{b}".to_string(),
                 }),
@@ -309,6 +322,7 @@ fn test_compile_fstring_escaped_braces() {
         statements: vec![
             AstNode::LetDecl {
                 name: "s".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::FString {
                     content: "{{escaped}}".to_string(),
                 }),
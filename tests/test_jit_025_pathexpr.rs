@@ -44,6 +44,7 @@ fn test_compile_pathexpr_simple() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["std".to_string(), "sync".to_string()],
                 }),
@@ -76,6 +77,7 @@ fn test_compile_pathexpr_multi_segment() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["std".to_string(), "sync".to_string(), "Arc".to_string()],
                 }),
@@ -135,6 +137,7 @@ fn test_compile_pathexpr_single_segment() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["Module".to_string()],
                 }),
@@ -168,12 +171,14 @@ fn test_compile_pathexpr_common_types() {
         statements: vec![
             AstNode::LetDecl {
                 name: "a".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["Option".to_string(), "Some".to_string()],
                 }),
             },
             AstNode::LetDecl {
                 name: "b".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["Result".to_string(), "Ok".to_string()],
                 }),
@@ -207,6 +212,7 @@ fn test_compile_pathexpr_in_arithmetic() {
         statements: vec![
             AstNode::LetDecl {
                 name: "x".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["std".to_string(), "sync".to_string()],
                 }),
@@ -245,12 +251,14 @@ fn test_compile_multiple_pathexprs() {
         statements: vec![
             AstNode::LetDecl {
                 name: "a".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["std".to_string(), "sync".to_string()],
                 }),
             },
             AstNode::LetDecl {
                 name: "b".to_string(),
+                mutable: false,
                 value: Box::new(AstNode::PathExpr {
                     segments: vec!["std".to_string(), "thread".to_string()],
                 }),
@@ -0,0 +1,117 @@
+// JIT-026: Logical NOT Semantics Agree Between Interpreter and JIT
+//
+// Mission: `!` means the same thing in both execution engines
+//
+// Chosen semantics (integer-logical, documented on Value::logical_not):
+// - !true is false, !false is true
+// - !0 is true, !n for any other integer is false (so !!5 is true)
+// - Results are booleans (0/1 in the JIT)
+// - Floats and strings are a type error (interpreter) / compile error (JIT)
+//
+// Why this is critical:
+// - The JIT stores booleans as 0/1 integers and already treated `!` this way
+// - The interpreter rejected `!5`, so `!!5` diverged between the engines
+// - Jidoka: any interpreter/JIT mismatch is a line-stopping failure
+//
+// Test Coverage:
+// - test_not_of_comparison_differential: !(a < b) over a grid of inputs
+// - test_not_of_integers_differential: !0, !5, !!5, !-1
+// - test_not_in_condition_differential: if !(a == b) { .. } else { .. }
+// - test_not_rejects_float_in_both: neither engine accepts !1.5
+// - test_not_rejects_string_in_both: neither engine accepts !"s"
+// - test_not_rejects_string_local_in_both: nor !s for a local bound to a string
+
+use ruchyruchy::debugger::differential::{compare_results, run_interpreter, run_jit};
+
+/// Helper: run both engines and require the same outcome
+fn assert_agree(source: &str, args: &[i64]) -> Option<i64> {
+    let interp = run_interpreter(source, "main", args);
+    let jit = run_jit(source, "main", args);
+    compare_results(source, "main", args, interp.clone().ok(), jit.clone().ok()).unwrap_or_else(
+        |e| {
+            panic!(
+                "{}\nsource: {}\nargs: {:?}\ninterp: {:?}\njit: {:?}",
+                e, source, args, interp, jit
+            )
+        },
+    );
+    interp.ok()
+}
+
+/// Test: !(a < b) matches for equal, smaller, and larger operands
+#[test]
+fn test_not_of_comparison_differential() {
+    let source = "fun main(a, b) { return !(a < b); }";
+    for a in -2..=2 {
+        for b in -2..=2 {
+            let expected = if a < b { 0 } else { 1 };
+            assert_eq!(
+                assert_agree(source, &[a, b]),
+                Some(expected),
+                "a={} b={}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+/// Test: ! on integers is integer-logical in both engines
+#[test]
+fn test_not_of_integers_differential() {
+    let cases = [
+        ("fun main(x) { return !0; }", 1),
+        ("fun main(x) { return !5; }", 0),
+        ("fun main(x) { return !!5; }", 1),
+        ("fun main(x) { return !-1; }", 0),
+        ("fun main(x) { return !!x; }", 1),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(assert_agree(source, &[7]), Some(expected), "{}", source);
+    }
+}
+
+/// Test: ! used as a branch condition
+#[test]
+fn test_not_in_condition_differential() {
+    let source = "fun main(a, b) { if !(a == b) { return 10; } else { return 20; } }";
+    assert_eq!(assert_agree(source, &[3, 3]), Some(20));
+    assert_eq!(assert_agree(source, &[3, 4]), Some(10));
+}
+
+/// Test: Floats are not valid `!` operands in either engine
+#[test]
+fn test_not_rejects_float_in_both() {
+    let source = "fun main() { return !1.5; }";
+    assert!(run_interpreter(source, "main", &[]).is_err());
+    let jit = run_jit(source, "main", &[]).unwrap_err();
+    assert!(jit.contains("logical_not"), "{}", jit);
+}
+
+/// Test: Strings are not valid `!` operands in either engine
+#[test]
+fn test_not_rejects_string_in_both() {
+    let source = "fun main() { return !\"yes\"; }";
+    assert!(run_interpreter(source, "main", &[]).is_err());
+    let jit = run_jit(source, "main", &[]).unwrap_err();
+    assert!(jit.contains("found String"), "{}", jit);
+}
+
+/// Test: A local bound to a string is rejected like a string literal
+#[test]
+fn test_not_rejects_string_local_in_both() {
+    let sources = [
+        "fun main() { let s = \"abc\"; return !s; }",
+        "fun main() { let s = \"abc\"; let t = s; return !t; }",
+        "fun main() { let mut s = 1; s = f\"{s}\"; return !s; }",
+    ];
+    for source in sources {
+        assert!(run_interpreter(source, "main", &[]).is_err(), "{}", source);
+        let jit = run_jit(source, "main", &[]).unwrap_err();
+        assert!(jit.contains("found String"), "{}: {}", source, jit);
+    }
+
+    // Reassigning an integer makes the local valid again
+    let source = "fun main() { let mut s = \"abc\"; s = 0; return !s; }";
+    assert_eq!(assert_agree(source, &[]), Some(1));
+}
@@ -0,0 +1,74 @@
+// JIT-027: Loop Values Agree Between Interpreter and JIT
+//
+// Mission: Formalize what a loop evaluates to
+// - while and for loops are statements and always evaluate to nil
+// - A loop in expression position (`let r = while ..`) binds nil
+// - The JIT represents nil as 0, so both engines report 0 at the i64 boundary
+//
+// Test Coverage:
+// - test_while_in_let_binds_nil: interpreter binds nil, not the last body value
+// - test_for_in_let_binds_nil: same for for-in loops
+// - test_loop_value_differential: returning a loop's value agrees (0)
+// - test_loop_side_effects_differential: the loop still runs before yielding nil
+
+use ruchyruchy::debugger::differential::{compare_results, run_interpreter, run_jit};
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: parse and evaluate a program in the interpreter
+fn run(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).expect("eval")
+}
+
+/// Helper: run both engines and require the same outcome
+fn assert_agree(source: &str, args: &[i64]) -> Option<i64> {
+    let interp = run_interpreter(source, "main", args);
+    let jit = run_jit(source, "main", args);
+    compare_results(source, "main", args, interp.clone().ok(), jit.clone().ok()).unwrap_or_else(
+        |e| {
+            panic!(
+                "{}\nsource: {}\nargs: {:?}\ninterp: {:?}\njit: {:?}",
+                e, source, args, interp, jit
+            )
+        },
+    );
+    interp.ok()
+}
+
+/// Test: A while loop used as a let initializer binds nil
+#[test]
+fn test_while_in_let_binds_nil() {
+    let source = "let i = 0; let r = while i < 3 { i = i + 1; i * 10 }; r";
+    assert_eq!(run(source), Value::nil());
+}
+
+/// Test: A for loop used as a let initializer binds nil
+#[test]
+fn test_for_in_let_binds_nil() {
+    let source = "let r = for x in [1, 2, 3] { x }; r";
+    assert_eq!(run(source), Value::nil());
+}
+
+/// Test: Returning a loop's value is 0 in both engines
+#[test]
+fn test_loop_value_differential() {
+    let cases = [
+        "fun main(n) { let i = 0; let r = while i < n { i = i + 1; }; return r; }",
+        "fun main(n) { let t = 0; let r = for x in 0..n { t = t + x; }; return r; }",
+    ];
+    for source in cases {
+        for n in [0, 1, 5] {
+            assert_eq!(assert_agree(source, &[n]), Some(0), "{} n={}", source, n);
+        }
+    }
+}
+
+/// Test: The loop body runs even though the loop itself yields nil
+#[test]
+fn test_loop_side_effects_differential() {
+    let source = "fun main(n) { let t = 0; let r = for x in 0..n { t = t + x; }; return t; }";
+    assert_eq!(assert_agree(source, &[5]), Some(10));
+}
@@ -0,0 +1,77 @@
+// JIT-028: break/continue Lowering in the JIT
+//
+// Mission: Early loop exits behave the same in both execution engines
+// - `break` jumps to the innermost loop's exit block
+// - `continue` jumps to the loop header (while) or increment block (for)
+// - Jumps outside a loop are rejected by both engines
+//
+// Test Coverage:
+// - test_while_early_break_differential: while loop stopping at n
+// - test_for_continue_differential: sum of odd numbers below n
+// - test_for_break_differential: first multiple of 7 at or above n
+// - test_nested_break_exits_inner_loop_only: break in an inner for loop
+// - test_jump_outside_loop_rejected_in_both: top-level break in a function
+
+use ruchyruchy::debugger::differential::{compare_results, run_interpreter, run_jit};
+
+/// Helper: run both engines and require the same outcome
+fn assert_agree(source: &str, args: &[i64]) -> Option<i64> {
+    let interp = run_interpreter(source, "main", args);
+    let jit = run_jit(source, "main", args);
+    compare_results(source, "main", args, interp.clone().ok(), jit.clone().ok()).unwrap_or_else(
+        |e| {
+            panic!(
+                "{}\nsource: {}\nargs: {:?}\ninterp: {:?}\njit: {:?}",
+                e, source, args, interp, jit
+            )
+        },
+    );
+    interp.ok()
+}
+
+/// Test: A while loop left early with break
+#[test]
+fn test_while_early_break_differential() {
+    let source =
+        "fun main(n) { let i = 0; while i < 100 { if i == n { break; } i = i + 1; } return i; }";
+    for n in [0, 5, 42, 150] {
+        assert_eq!(assert_agree(source, &[n]), Some(n.min(100)), "n={}", n);
+    }
+}
+
+/// Test: continue in a for loop still advances the loop variable
+#[test]
+fn test_for_continue_differential() {
+    let source = "fun main(n) { let total = 0; for i in 0..n { if i % 2 == 0 { continue; } total = total + i; } return total; }";
+    assert_eq!(assert_agree(source, &[10]), Some(25));
+    assert_eq!(assert_agree(source, &[0]), Some(0));
+    assert_eq!(assert_agree(source, &[7]), Some(9));
+}
+
+/// Test: break in a for loop keeps the value seen at the break
+#[test]
+fn test_for_break_differential() {
+    let source = "fun main(n) { let found = 0; for i in n..1000 { if i % 7 == 0 { found = i; break; } } return found; }";
+    assert_eq!(assert_agree(source, &[1]), Some(7));
+    assert_eq!(assert_agree(source, &[50]), Some(56));
+    assert_eq!(assert_agree(source, &[70]), Some(70));
+}
+
+/// Test: break leaves only the innermost loop
+#[test]
+fn test_nested_break_exits_inner_loop_only() {
+    let source = "fun main(n) { let count = 0; for i in 0..n { for j in 0..n { if j > i { break; } count = count + 1; } } return count; }";
+    // Pairs with j <= i: n * (n + 1) / 2
+    assert_eq!(assert_agree(source, &[4]), Some(10));
+    assert_eq!(assert_agree(source, &[6]), Some(21));
+}
+
+/// Test: break outside a loop is an error in both engines
+#[test]
+fn test_jump_outside_loop_rejected_in_both() {
+    let source = "fun main() { break; return 1; }";
+    let interp = run_interpreter(source, "main", &[]).unwrap_err();
+    assert!(interp.contains("LoopJumpOutsideLoop"), "{}", interp);
+    let jit = run_jit(source, "main", &[]).unwrap_err();
+    assert!(jit.contains("outside of a loop"), "{}", jit);
+}
@@ -0,0 +1,103 @@
+// JIT-029: Exclusive and Inclusive Range Bounds
+//
+// Mission: `a..b` and `a..=b` mean the same thing in both engines
+// - a..b stops before b; a..=b includes b
+// - Empty ranges (5..5, 6..=5) produce no elements and run no iterations
+// - 5..=5 is a single element
+// - The JIT's for-loop compares with < for .. and <= for ..=
+//
+// Test Coverage:
+// - test_interpreter_range_values: vectors built from each kind of range
+// - test_empty_and_single_element_ranges: 5..5, 5..=5, 6..=5
+// - test_for_loop_sums_differential: exclusive and inclusive bounds over a grid
+// - test_for_loop_empty_ranges_differential: zero iterations in both engines
+
+use ruchyruchy::debugger::differential::{compare_results, run_interpreter, run_jit};
+use ruchyruchy::interpreter::evaluator::Evaluator;
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::interpreter::value::Value;
+
+/// Helper: evaluate a program in the interpreter
+fn eval(source: &str) -> Value {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut eval = Evaluator::new();
+    eval.eval_program(&ast).expect("eval")
+}
+
+/// Helper: an integer vector value
+fn ints(values: &[i64]) -> Value {
+    Value::vector(values.iter().copied().map(Value::Integer).collect())
+}
+
+/// Helper: run both engines and require the same outcome
+fn assert_agree(source: &str, args: &[i64]) -> Option<i64> {
+    let interp = run_interpreter(source, "main", args);
+    let jit = run_jit(source, "main", args);
+    compare_results(source, "main", args, interp.clone().ok(), jit.clone().ok()).unwrap_or_else(
+        |e| {
+            panic!(
+                "{}\nsource: {}\nargs: {:?}\ninterp: {:?}\njit: {:?}",
+                e, source, args, interp, jit
+            )
+        },
+    );
+    interp.ok()
+}
+
+/// Program: sum of start..end
+const SUM_EXCLUSIVE: &str = "fun main(a, b) {
+    let mut total = 0;
+    for i in a..b { total = total + i; }
+    return total;
+}";
+
+/// Program: sum of start..=end
+const SUM_INCLUSIVE: &str = "fun main(a, b) {
+    let mut total = 0;
+    for i in a..=b { total = total + i; }
+    return total;
+}";
+
+/// Program: count the iterations of start..=end
+const COUNT_INCLUSIVE: &str = "fun main(a, b) {
+    let mut count = 0;
+    for i in a..=b { count = count + 1; }
+    return count;
+}";
+
+/// Test: Range expressions evaluate to integer vectors
+#[test]
+fn test_interpreter_range_values() {
+    assert_eq!(eval("1..5"), ints(&[1, 2, 3, 4]));
+    assert_eq!(eval("1..=5"), ints(&[1, 2, 3, 4, 5]));
+    assert_eq!(eval("let n = 3;\n-1..=n"), ints(&[-1, 0, 1, 2, 3]));
+}
+
+/// Test: Equal and reversed bounds give empty or single-element ranges
+#[test]
+fn test_empty_and_single_element_ranges() {
+    assert_eq!(eval("5..5"), ints(&[]));
+    assert_eq!(eval("5..=5"), ints(&[5]));
+    assert_eq!(eval("6..=5"), ints(&[]));
+}
+
+/// Test: for-loop sums agree for both bound kinds
+#[test]
+fn test_for_loop_sums_differential() {
+    assert_eq!(assert_agree(SUM_EXCLUSIVE, &[1, 5]), Some(10));
+    assert_eq!(assert_agree(SUM_INCLUSIVE, &[1, 5]), Some(15));
+    for a in -2..=2 {
+        for b in -2..=2 {
+            assert_agree(SUM_EXCLUSIVE, &[a, b]);
+            assert_agree(SUM_INCLUSIVE, &[a, b]);
+        }
+    }
+}
+
+/// Test: Empty ranges run no iterations and 5..=5 runs one
+#[test]
+fn test_for_loop_empty_ranges_differential() {
+    assert_eq!(assert_agree(SUM_EXCLUSIVE, &[5, 5]), Some(0));
+    assert_eq!(assert_agree(COUNT_INCLUSIVE, &[5, 5]), Some(1));
+    assert_eq!(assert_agree(COUNT_INCLUSIVE, &[6, 5]), Some(0));
+}
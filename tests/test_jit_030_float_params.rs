@@ -0,0 +1,130 @@
+// JIT-030: Float-Typed Parameters and Return Values
+//
+// Mission: JIT functions can take and return f64 with the native ABI
+// - compile_function_with_types declares each parameter as Int or Float
+// - A Float return type makes the function return a real f64
+// - Float parameters stay f64 inside the body; no bit-casting needed
+// - compile_function_with_params is unchanged: every slot is i64
+//
+// Test Coverage:
+// - test_two_float_params_float_return: avg(a, b) as fn(f64, f64) -> f64
+// - test_explicit_return_of_float: return a * b
+// - test_mixed_int_and_float_params: scale(n, factor) as fn(i64, f64) -> f64
+// - test_integer_result_converted_to_float_return: body yielding an i64
+// - test_float_params_int_return: comparison result as fn(f64, f64) -> i64
+// - test_param_type_count_mismatch: types list must match the names
+
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::jit::{JitCompiler, JitError, JitType};
+
+/// Helper: the parameter names and body of the only function in `source`
+fn function(source: &str) -> (Vec<String>, AstNode) {
+    let ast = Parser::new(source).parse().expect("parse");
+    match &ast.nodes()[0] {
+        AstNode::FunctionDef { params, body, .. } => (
+            params.clone(),
+            AstNode::Block {
+                statements: body.clone(),
+            },
+        ),
+        other => panic!("expected a function, got {:?}", other),
+    }
+}
+
+/// Test: Two float parameters and a float result from the tail expression
+#[test]
+fn test_two_float_params_float_return() {
+    let (params, body) = function("fun avg(a, b) -> f64 { (a + b) / 2.0 }");
+    let mut jit = JitCompiler::new().unwrap();
+    let avg: fn(f64, f64) -> f64 = jit
+        .compile_function_with_types(
+            &params,
+            &[JitType::Float, JitType::Float],
+            JitType::Float,
+            &body,
+        )
+        .unwrap();
+    assert_eq!(avg(1.5, 2.5), 2.0);
+    assert_eq!(avg(-3.0, 4.0), 0.5);
+}
+
+/// Test: An explicit return of a float value
+#[test]
+fn test_explicit_return_of_float() {
+    let (params, body) = function("fun mul(a, b) -> f64 { return a * b; }");
+    let mut jit = JitCompiler::new().unwrap();
+    let mul: fn(f64, f64) -> f64 = jit
+        .compile_function_with_types(
+            &params,
+            &[JitType::Float, JitType::Float],
+            JitType::Float,
+            &body,
+        )
+        .unwrap();
+    assert_eq!(mul(1.25, 4.0), 5.0);
+}
+
+/// Test: An integer and a float parameter in one signature
+#[test]
+fn test_mixed_int_and_float_params() {
+    let (params, body) = function("fun scale(n, factor) -> f64 { (n as f64) * factor }");
+    let mut jit = JitCompiler::new().unwrap();
+    let scale: fn(i64, f64) -> f64 = jit
+        .compile_function_with_types(
+            &params,
+            &[JitType::Int, JitType::Float],
+            JitType::Float,
+            &body,
+        )
+        .unwrap();
+    assert_eq!(scale(3, 0.5), 1.5);
+}
+
+/// Test: An integer body value is converted when the return type is Float
+#[test]
+fn test_integer_result_converted_to_float_return() {
+    let (params, body) = function("fun sum(a, b) -> f64 { a + b }");
+    let mut jit = JitCompiler::new().unwrap();
+    let sum: fn(i64, i64) -> f64 = jit
+        .compile_function_with_types(
+            &params,
+            &[JitType::Int, JitType::Int],
+            JitType::Float,
+            &body,
+        )
+        .unwrap();
+    assert_eq!(sum(2, 40), 42.0);
+}
+
+/// Test: Float parameters with an integer (boolean) result
+#[test]
+fn test_float_params_int_return() {
+    let (params, body) = function("fun less(a, b) { a < b }");
+    let mut jit = JitCompiler::new().unwrap();
+    let less: fn(f64, f64) -> i64 = jit
+        .compile_function_with_types(
+            &params,
+            &[JitType::Float, JitType::Float],
+            JitType::Int,
+            &body,
+        )
+        .unwrap();
+    assert_eq!(less(0.5, 0.75), 1);
+    assert_eq!(less(0.75, 0.5), 0);
+}
+
+/// Test: One type is required per parameter name
+#[test]
+fn test_param_type_count_mismatch() {
+    let (params, body) = function("fun avg(a, b) -> f64 { (a + b) / 2.0 }");
+    let mut jit = JitCompiler::new().unwrap();
+    let err = jit
+        .compile_function_with_types::<fn(f64) -> f64>(
+            &params,
+            &[JitType::Float],
+            JitType::Float,
+            &body,
+        )
+        .unwrap_err();
+    assert!(matches!(err, JitError::CompilationFailed(_)), "{:?}", err);
+}
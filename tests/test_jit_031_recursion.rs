@@ -0,0 +1,126 @@
+// JIT-031: Self-Recursive Functions
+//
+// Mission: A JIT function can call itself by name
+// - compile_named_function declares the function before compiling its body,
+//   so a call to its own name becomes a direct call
+// - The finished function is registered under its name for later callers
+// - Calls to other unregistered functions still fail to compile
+//
+// Test Coverage:
+// - test_factorial: fun factorial(n) with early return
+// - test_fibonacci: two recursive calls per level
+// - test_registered_for_later_callers: main() calls the compiled factorial
+// - test_self_call_arity_mismatch: factorial() with no arguments
+// - test_unknown_function_still_rejected: a call to an undefined name
+// - test_recursive_differential: interpreter and JIT agree on gcd
+
+use ruchyruchy::debugger::differential::{run_interpreter, run_jit};
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::jit::{JitCompiler, JitError};
+
+/// Helper: the named function's parameters and body from `source`
+fn function(source: &str, wanted: &str) -> (Vec<String>, AstNode) {
+    let ast = Parser::new(source).parse().expect("parse");
+    ast.nodes()
+        .iter()
+        .find_map(|node| match node {
+            AstNode::FunctionDef {
+                name, params, body, ..
+            } if name == wanted => Some((
+                params.clone(),
+                AstNode::Block {
+                    statements: body.clone(),
+                },
+            )),
+            _ => None,
+        })
+        .expect("function not found")
+}
+
+/// Program: factorial with an early return
+const FACTORIAL: &str = "fun factorial(n) {
+    if (n <= 1) { return 1 }
+    return n * factorial(n - 1)
+}";
+
+/// Program: naive doubly-recursive fibonacci
+const FIBONACCI: &str = "fun fibonacci(n) {
+    if (n < 2) { return n }
+    return fibonacci(n - 1) + fibonacci(n - 2)
+}";
+
+/// Test: factorial compiles and computes through the self-call
+#[test]
+fn test_factorial() {
+    let (params, body) = function(FACTORIAL, "factorial");
+    let mut jit = JitCompiler::new().unwrap();
+    let factorial: fn(i64) -> i64 = jit
+        .compile_named_function("factorial", &params, &body)
+        .unwrap();
+    assert_eq!(factorial(1), 1);
+    assert_eq!(factorial(5), 120);
+    assert_eq!(factorial(20), 2_432_902_008_176_640_000);
+}
+
+/// Test: fibonacci makes two self-calls per level
+#[test]
+fn test_fibonacci() {
+    let (params, body) = function(FIBONACCI, "fibonacci");
+    let mut jit = JitCompiler::new().unwrap();
+    let fibonacci: fn(i64) -> i64 = jit
+        .compile_named_function("fibonacci", &params, &body)
+        .unwrap();
+    assert_eq!(fibonacci(0), 0);
+    assert_eq!(fibonacci(1), 1);
+    assert_eq!(fibonacci(10), 55);
+    assert_eq!(fibonacci(25), 75025);
+}
+
+/// Test: A later function can call the registered recursive function
+#[test]
+fn test_registered_for_later_callers() {
+    let source = format!("{}\nfun main() {{ factorial(6) + 1 }}", FACTORIAL);
+    let mut jit = JitCompiler::new().unwrap();
+    let (params, body) = function(&source, "factorial");
+    jit.compile_named_function::<fn(i64) -> i64>("factorial", &params, &body)
+        .unwrap();
+    let (params, body) = function(&source, "main");
+    let main: fn() -> i64 = jit.compile_function_with_params(&params, &body).unwrap();
+    assert_eq!(main(), 721);
+}
+
+/// Test: A self-call with the wrong number of arguments is rejected
+#[test]
+fn test_self_call_arity_mismatch() {
+    let (params, body) = function("fun factorial(n) { factorial() }", "factorial");
+    let mut jit = JitCompiler::new().unwrap();
+    let err = jit
+        .compile_named_function::<fn(i64) -> i64>("factorial", &params, &body)
+        .unwrap_err();
+    assert!(matches!(err, JitError::CompilationFailed(_)), "{:?}", err);
+}
+
+/// Test: Only the function being compiled is resolved early
+#[test]
+fn test_unknown_function_still_rejected() {
+    let (params, body) = function("fun f(n) { g(n) }", "f");
+    let mut jit = JitCompiler::new().unwrap();
+    let err = jit
+        .compile_named_function::<fn(i64) -> i64>("f", &params, &body)
+        .unwrap_err();
+    assert!(matches!(err, JitError::UnsupportedNode(_)), "{:?}", err);
+}
+
+/// Test: Differential run of a recursive function
+#[test]
+fn test_recursive_differential() {
+    let source = "fun gcd(a, b) {
+    if (b == 0) { return a }
+    return gcd(b, a % b)
+}";
+    for (a, b) in [(48, 18), (17, 5), (0, 9), (100, 75)] {
+        let interp = run_interpreter(source, "gcd", &[a, b]).unwrap();
+        let jit = run_jit(source, "gcd", &[a, b]).unwrap();
+        assert_eq!(interp, jit, "gcd({}, {})", a, b);
+    }
+}
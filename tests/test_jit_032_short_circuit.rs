@@ -0,0 +1,101 @@
+// JIT-032: Short-Circuit && and || in the JIT
+//
+// Mission: The JIT evaluates logical operators like the interpreter does
+// - `false && rhs` and `true || rhs` never run the right operand
+// - Otherwise the right operand decides the 0/1 result
+// - A right side that would divide by zero is safe behind a guard
+// - Float operands are still rejected
+//
+// Test Coverage:
+// - test_and_guards_division_differential: b != 0 && a / b > 1
+// - test_or_guards_division_differential: b == 0 || a / b > 1
+// - test_guarded_function_call_differential: the right side calls a helper
+// - test_truth_table_differential: all four operand combinations, both ops
+// - test_chained_logical_operators_differential: a && b || c with guards
+// - test_float_operands_rejected: 1.0 && 1 is UnsupportedNode
+
+use ruchyruchy::debugger::differential::{compare_results, run_interpreter, run_jit};
+use ruchyruchy::interpreter::parser::Parser;
+use ruchyruchy::jit::{JitCompiler, JitError};
+
+/// Helper: run both engines and require the same outcome
+fn assert_agree(source: &str, args: &[i64]) -> i64 {
+    let interp = run_interpreter(source, "main", args);
+    let jit = run_jit(source, "main", args);
+    compare_results(source, "main", args, interp.clone().ok(), jit.clone().ok()).unwrap_or_else(
+        |e| {
+            panic!(
+                "{}\nsource: {}\nargs: {:?}\ninterp: {:?}\njit: {:?}",
+                e, source, args, interp, jit
+            )
+        },
+    );
+    interp.unwrap()
+}
+
+/// Test: && skips a division by zero on its right
+#[test]
+fn test_and_guards_division_differential() {
+    let source = "fun main(a, b) { b != 0 && a / b > 1 }";
+    assert_eq!(assert_agree(source, &[10, 0]), 0);
+    assert_eq!(assert_agree(source, &[10, 2]), 1);
+    assert_eq!(assert_agree(source, &[1, 2]), 0);
+}
+
+/// Test: || skips a division by zero on its right
+#[test]
+fn test_or_guards_division_differential() {
+    let source = "fun main(a, b) { b == 0 || a / b > 1 }";
+    assert_eq!(assert_agree(source, &[10, 0]), 1);
+    assert_eq!(assert_agree(source, &[10, 2]), 1);
+    assert_eq!(assert_agree(source, &[1, 2]), 0);
+}
+
+/// Test: A right side that calls a dividing helper only runs when needed
+#[test]
+fn test_guarded_function_call_differential() {
+    let source = "fun ratio(a, b) { a / b }
+fun main(a, b) { b != 0 && ratio(a, b) == 5 }";
+    assert_eq!(assert_agree(source, &[25, 0]), 0);
+    assert_eq!(assert_agree(source, &[25, 5]), 1);
+}
+
+/// Test: Both operators agree with the interpreter on every combination
+#[test]
+fn test_truth_table_differential() {
+    for op in ["&&", "||"] {
+        let source = format!("fun main(a, b) {{ (a == 1) {} (b == 1) }}", op);
+        for a in [0, 1] {
+            for b in [0, 1] {
+                assert_agree(&source, &[a, b]);
+            }
+        }
+    }
+}
+
+/// Test: Mixed chains keep precedence and short-circuit at each step
+#[test]
+fn test_chained_logical_operators_differential() {
+    let source = "fun main(a, b) { a != 0 && 100 / a > 10 || b != 0 && 100 / b > 10 }";
+    for (a, b) in [(0, 0), (0, 5), (5, 0), (50, 50), (50, 5)] {
+        assert_agree(source, &[a, b]);
+    }
+}
+
+/// Test: Logical operators on floats are still unsupported
+#[test]
+fn test_float_operands_rejected() {
+    for source in ["1.0 && 1", "1 || 2.5"] {
+        let ast = Parser::new(source).parse().unwrap();
+        let mut jit = JitCompiler::new().unwrap();
+        let err = jit
+            .compile_function_with_params::<fn() -> i64>(&[], &ast.nodes()[0])
+            .unwrap_err();
+        assert!(
+            matches!(err, JitError::UnsupportedNode(_)),
+            "{}: {:?}",
+            source,
+            err
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// JIT-033: Object File Output
+//
+// Mission: Compile Ruchy functions to a native object file
+// - ObjectCompiler reuses the JIT's code generator with an ObjectModule
+// - compile_to_object writes a relocatable ELF / Mach-O file
+// - Each compiled function is an exported symbol a C program can call
+// - Host-only constructs (strings, calls to other functions) are rejected
+//
+// Test Coverage:
+// - test_object_file_has_native_header: ELF or Mach-O, parsed by goblin
+// - test_functions_are_exported_symbols: add and factorial in the symbol table
+// - test_string_literals_rejected: "hi" is UnsupportedNode
+// - test_calls_to_other_functions_rejected: only self-calls are allowed
+// - test_object_links_with_c_main: cc links a C main and runs it (if cc exists)
+//
+// Requires `--features object-output`.
+#![cfg(feature = "object-output")]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::jit::{JitError, ObjectCompiler};
+
+/// Helper: a scratch path for one test's object file
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("jit_033_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+/// Helper: compile every function in `source` into one object file
+fn compile_all(source: &str, path: &Path) -> Result<(), JitError> {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut compiler = ObjectCompiler::new("ruchy_test")?;
+    for node in ast.nodes() {
+        if let AstNode::FunctionDef {
+            name, params, body, ..
+        } = node
+        {
+            let body = AstNode::Block {
+                statements: body.clone(),
+            };
+            compiler.compile_function(name, params, &body)?;
+        }
+    }
+    compiler.compile_to_object(path)
+}
+
+/// Program: two exported functions, one recursive
+const PROGRAM: &str = "fun add(a, b) { a + b }
+fun factorial(n) {
+    if (n <= 1) { return 1 }
+    return n * factorial(n - 1)
+}";
+
+/// Test: The output starts with the host's object-file header
+#[test]
+fn test_object_file_has_native_header() {
+    let path = scratch("header.o");
+    compile_all(PROGRAM, &path).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    match goblin::Object::parse(&bytes).unwrap() {
+        goblin::Object::Elf(elf) => {
+            assert_eq!(&bytes[..4], b"\x7fELF");
+            assert_eq!(elf.header.e_type, goblin::elf::header::ET_REL);
+        }
+        goblin::Object::Mach(_) => {}
+        other => panic!("unexpected object format: {:?}", other),
+    }
+}
+
+/// Test: Each compiled function is a named symbol
+#[test]
+fn test_functions_are_exported_symbols() {
+    let path = scratch("symbols.o");
+    compile_all(PROGRAM, &path).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    let names: Vec<String> = match goblin::Object::parse(&bytes).unwrap() {
+        goblin::Object::Elf(elf) => elf
+            .syms
+            .iter()
+            .filter_map(|sym| elf.strtab.get_at(sym.st_name).map(str::to_string))
+            .collect(),
+        goblin::Object::Mach(goblin::mach::Mach::Binary(macho)) => macho
+            .symbols()
+            .filter_map(|sym| {
+                sym.ok()
+                    .map(|(name, _)| name.trim_start_matches('_').to_string())
+            })
+            .collect(),
+        other => panic!("unexpected object format: {:?}", other),
+    };
+    for expected in ["add", "factorial"] {
+        assert!(
+            names.iter().any(|n| n == expected),
+            "{} missing from {:?}",
+            expected,
+            names
+        );
+    }
+}
+
+/// Test: String literals need host memory, so they are rejected
+#[test]
+fn test_string_literals_rejected() {
+    let err = compile_all("fun greet() { \"hi\" }", &scratch("string.o")).unwrap_err();
+    assert!(matches!(err, JitError::UnsupportedNode(_)), "{:?}", err);
+}
+
+/// Test: A call to a different function cannot be resolved
+#[test]
+fn test_calls_to_other_functions_rejected() {
+    let source = "fun double(x) { x * 2 }\nfun quad(x) { double(double(x)) }";
+    let err = compile_all(source, &scratch("calls.o")).unwrap_err();
+    assert!(matches!(err, JitError::UnsupportedNode(_)), "{:?}", err);
+}
+
+/// Test: A C program links against the object and gets the right results
+#[test]
+fn test_object_links_with_c_main() {
+    if Command::new("cc").arg("--version").output().is_err() {
+        eprintln!("skipping: no C compiler");
+        return;
+    }
+    let object = scratch("link.o");
+    compile_all(PROGRAM, &object).unwrap();
+    let main_c = scratch("main.c");
+    std::fs::write(
+        &main_c,
+        "long add(long, long);\nlong factorial(long);\n\
+         int main(void) { return (int)(add(2, 3) + factorial(5)); }\n",
+    )
+    .unwrap();
+    let binary = scratch("demo");
+    let status = Command::new("cc")
+        .arg(&main_c)
+        .arg(&object)
+        .arg("-o")
+        .arg(&binary)
+        .status()
+        .unwrap();
+    assert!(status.success(), "cc failed to link");
+    let status = Command::new(&binary).status().unwrap();
+    assert_eq!(status.code(), Some(125));
+}
@@ -0,0 +1,97 @@
+// JIT-034: Heap Allocation for vec![value; count]
+//
+// Mission: The repeat form of vec! is sized at run time
+// - The JIT calls an allocator for count * 8 bytes instead of using a
+//   fixed 1024-element stack slot
+// - Counts above 1024 no longer overwrite neighbouring memory
+// - The MVP leaks each allocation (documented on jit_alloc_words)
+//
+// Test Coverage:
+// - test_large_repeat_reads_back: vec![7; 5000][4000] is 7
+// - test_last_element_of_large_repeat: v[4999] with a runtime count
+// - test_neighbouring_locals_not_clobbered: locals around a big vec! survive
+// - test_repeat_in_loop: a fresh array per iteration
+// - test_repeat_matches_interpreter: differential check for small counts
+
+use ruchyruchy::debugger::differential::{run_interpreter, run_jit};
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::jit::JitCompiler;
+
+/// Helper: JIT-compile `main(params..)` from `source`
+fn compile(source: &str) -> (JitCompiler, *const u8) {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut jit = JitCompiler::new().unwrap();
+    let ptr = match &ast.nodes()[0] {
+        AstNode::FunctionDef { params, body, .. } => jit
+            .compile_function_with_params::<*const u8>(
+                params,
+                &AstNode::Block {
+                    statements: body.clone(),
+                },
+            )
+            .unwrap(),
+        other => panic!("expected a function, got {:?}", other),
+    };
+    (jit, ptr)
+}
+
+/// Test: An element far past 1024 holds the repeated value
+#[test]
+fn test_large_repeat_reads_back() {
+    let (_jit, ptr) = compile("fun main() { let v = vec![7; 5000]; v[4000] }");
+    let main: fn() -> i64 = unsafe { std::mem::transmute(ptr) };
+    assert_eq!(main(), 7);
+}
+
+/// Test: The count can come from a parameter
+#[test]
+fn test_last_element_of_large_repeat() {
+    let (_jit, ptr) = compile("fun main(n) { let v = vec![3; n]; v[n - 1] }");
+    let main: fn(i64) -> i64 = unsafe { std::mem::transmute(ptr) };
+    assert_eq!(main(5000), 3);
+    assert_eq!(main(100_000), 3);
+}
+
+/// Test: Locals next to a large array keep their values
+#[test]
+fn test_neighbouring_locals_not_clobbered() {
+    let source = "fun main() {
+    let before = [1, 2, 3];
+    let v = vec![9; 5000];
+    let after = [4, 5, 6];
+    before[0] + before[2] + after[0] + after[2] + v[4999]
+}";
+    let (_jit, ptr) = compile(source);
+    let main: fn() -> i64 = unsafe { std::mem::transmute(ptr) };
+    assert_eq!(main(), 1 + 3 + 4 + 6 + 9);
+}
+
+/// Test: Each loop iteration gets its own array
+#[test]
+fn test_repeat_in_loop() {
+    let source = "fun main() {
+    let mut total = 0;
+    for i in 0..10 {
+        let v = vec![i; 2000];
+        total = total + v[1999];
+    }
+    total
+}";
+    let (_jit, ptr) = compile(source);
+    let main: fn() -> i64 = unsafe { std::mem::transmute(ptr) };
+    assert_eq!(main(), 45);
+}
+
+/// Test: Small repeats agree with the interpreter
+#[test]
+fn test_repeat_matches_interpreter() {
+    let source = "fun main(n) { let v = vec![n * 2; n + 1]; v[n] + v[0] }";
+    for n in [0, 1, 5, 40] {
+        assert_eq!(
+            run_interpreter(source, "main", &[n]),
+            run_jit(source, "main", &[n]),
+            "n = {}",
+            n
+        );
+    }
+}
@@ -0,0 +1,173 @@
+// JIT-035: Array Bounds Checks
+//
+// Mission: Optionally trap on out-of-range array indices
+// - Arrays carry a length word just below element 0 (vector literals,
+//   vec![a, b, ..] and vec![value; count])
+// - `JitCompiler::set_bounds_checks(true)` makes `v[i]` and `v[i] op= x`
+//   compare i against that length and execute a Cranelift trap when
+//   i < 0 or i >= len
+// - Checks are off by default; HashMap indexing is unaffected
+//
+// A trap kills the process, so the trapping tests re-run this test binary
+// with JIT_035_CHILD set and assert that the child died from a signal.
+//
+// Test Coverage:
+// - test_valid_indices_with_checks: first and last elements of each array form
+// - test_runtime_index_with_checks: an index passed as a parameter
+// - test_hashmap_index_with_checks: HashMap lookups skip the array check
+// - test_checks_off_by_default: unchecked results agree with the interpreter
+// - test_index_past_end_traps: v[3] on a 3-element array
+// - test_negative_index_traps: v[-1]
+// - test_repeat_index_past_end_traps: v[n] on vec![0; n]
+// - test_compound_assign_past_end_traps: v[2] += 1 on a 2-element array
+
+use ruchyruchy::debugger::differential::{run_interpreter, run_jit};
+use ruchyruchy::interpreter::parser::{AstNode, Parser};
+use ruchyruchy::jit::JitCompiler;
+
+/// Env var naming the trapping case a child process should run
+const CHILD_ENV: &str = "JIT_035_CHILD";
+
+/// Helper: JIT-compile `main(params..)` from `source` with bounds checks on
+fn compile_checked(source: &str) -> (JitCompiler, *const u8) {
+    let ast = Parser::new(source).parse().expect("parse");
+    let mut jit = JitCompiler::new().unwrap();
+    jit.set_bounds_checks(true);
+    let ptr = match &ast.nodes()[0] {
+        AstNode::FunctionDef { params, body, .. } => jit
+            .compile_function_with_params::<*const u8>(
+                params,
+                &AstNode::Block {
+                    statements: body.clone(),
+                },
+            )
+            .unwrap(),
+        other => panic!("expected a function, got {:?}", other),
+    };
+    (jit, ptr)
+}
+
+/// Helper: run `main(arg)` from `source` with checks on (child side)
+fn run_checked(source: &str, arg: i64) -> i64 {
+    let (_jit, ptr) = compile_checked(source);
+    let main: fn(i64) -> i64 = unsafe { std::mem::transmute(ptr) };
+    main(arg)
+}
+
+/// Helper: in the child, run `source`; in the parent, spawn the child for
+/// `test_name` and assert it was killed by a trap
+fn assert_traps(test_name: &str, source: &str, arg: i64) {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        let result = run_checked(source, arg);
+        // Only reached if no trap fired; the parent sees a clean exit
+        println!("no trap, result {}", result);
+        return;
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([test_name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .output()
+        .expect("spawn child test");
+    assert!(
+        !output.status.success(),
+        "expected a trap, child exited cleanly: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        assert!(
+            output.status.signal().is_some(),
+            "expected a signal, got {:?}",
+            output.status
+        );
+    }
+}
+
+/// Test: In-range indices read the right elements with checks on
+#[test]
+fn test_valid_indices_with_checks() {
+    let cases = [
+        ("fun main(n) { let v = [10, 20, 30]; v[0] + v[2] }", 40),
+        ("fun main(n) { let v = vec![4, 5]; v[1] }", 5),
+        ("fun main(n) { let v = vec![7; 5000]; v[4999] }", 7),
+        (
+            "fun main(n) { let mut v = [1, 2, 3]; v[1] += 40; v[1] }",
+            42,
+        ),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(run_checked(source, 0), expected, "{}", source);
+    }
+}
+
+/// Test: A runtime index inside the array passes the check
+#[test]
+fn test_runtime_index_with_checks() {
+    let source = "fun main(i) { let v = [10, 20, 30, 40]; v[i] }";
+    for i in 0..4 {
+        assert_eq!(run_checked(source, i), (i + 1) * 10);
+    }
+}
+
+/// Test: HashMap indexing is not treated as an array access
+#[test]
+fn test_hashmap_index_with_checks() {
+    let source = "fun main(n) { let m = {1: 100, 2: 200}; m[2] }";
+    assert_eq!(run_checked(source, 0), 200);
+}
+
+/// Test: Without the flag, array code behaves as before
+#[test]
+fn test_checks_off_by_default() {
+    let source = "fun main(n) { let v = [n, n * 2, n * 3]; v[0] + v[2] + vec![n; 4][3] }";
+    for n in [0, 1, 9] {
+        assert_eq!(
+            run_interpreter(source, "main", &[n]),
+            run_jit(source, "main", &[n]),
+            "n = {}",
+            n
+        );
+    }
+}
+
+/// Test: Reading one past the end traps
+#[test]
+fn test_index_past_end_traps() {
+    assert_traps(
+        "test_index_past_end_traps",
+        "fun main(i) { let v = [10, 20, 30]; v[i] }",
+        3,
+    );
+}
+
+/// Test: A negative index traps
+#[test]
+fn test_negative_index_traps() {
+    assert_traps(
+        "test_negative_index_traps",
+        "fun main(i) { let v = [10, 20, 30]; v[i] }",
+        -1,
+    );
+}
+
+/// Test: The heap-allocated repeat form records its runtime length
+#[test]
+fn test_repeat_index_past_end_traps() {
+    assert_traps(
+        "test_repeat_index_past_end_traps",
+        "fun main(n) { let v = vec![0; n]; v[n] }",
+        100,
+    );
+}
+
+/// Test: Compound assignment through an out-of-range index traps
+#[test]
+fn test_compound_assign_past_end_traps() {
+    assert_traps(
+        "test_compound_assign_past_end_traps",
+        "fun main(i) { let mut v = [1, 2]; v[i] += 1; v[0] }",
+        2,
+    );
+}
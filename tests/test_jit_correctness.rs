@@ -49,7 +49,10 @@ fn verify_jit(source: &str) -> Result<i64, String> {
     // Find and compile all functions
     let mut main_fn: Option<fn() -> i64> = None;
     for node in ast.nodes() {
-        if let ruchyruchy::interpreter::parser::AstNode::FunctionDef { name, params, body } = node {
+        if let ruchyruchy::interpreter::parser::AstNode::FunctionDef {
+            name, params, body, ..
+        } = node
+        {
             if name == "main" {
                 // Compile main function
                 main_fn = Some(
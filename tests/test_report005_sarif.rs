@@ -0,0 +1,291 @@
+// REPORT-005: SARIF Output (INTEGRATION TESTS)
+//
+// Tests for exporting bug reports as SARIF 2.1.0 documents for CI
+// code-scanning integration (e.g. GitHub's Security tab).
+//
+// Requirements:
+// - Valid SARIF 2.1.0 document (`$schema`, `version`, `runs`)
+// - One `result` per bug report
+// - Rule id from `BugCategory`, level from `Severity`
+// - Locations from related files, or the embedded reproduction code
+// - Reports without spans still produce valid (region-less) results
+//
+// The validator below encodes the constraints of the official SARIF 2.1.0
+// JSON schema (sarif-schema-2.1.0.json) for every property `to_sarif` emits,
+// so the tests run offline.
+
+use ruchyruchy::bug_discovery::confidence::ConfidenceScore;
+use ruchyruchy::bug_reporting::{
+    to_sarif, BugCategory, BugReport, Severity, SARIF_SCHEMA, SARIF_VERSION,
+};
+use serde_json::Value;
+
+fn report(title: &str, severity: Severity, category: BugCategory) -> BugReport {
+    BugReport::new(
+        title.to_string(),
+        format!("{} description", title),
+        severity,
+        category,
+        "fun main() { crash() }".to_string(),
+        "no crash".to_string(),
+        "segfault".to_string(),
+        ConfidenceScore {
+            overall: 0.9,
+            discovery_method_weight: 0.9,
+            reproducibility_score: 1.0,
+            quantitative_evidence: 0.8,
+            root_cause_clarity: 0.7,
+        },
+    )
+}
+
+fn parse(sarif: &str) -> Value {
+    serde_json::from_str(sarif).expect("SARIF output must be valid JSON")
+}
+
+fn require<'a>(value: &'a Value, key: &str, context: &str) -> Result<&'a Value, String> {
+    value
+        .get(key)
+        .ok_or_else(|| format!("{}: missing required property '{}'", context, key))
+}
+
+fn require_str<'a>(value: &'a Value, key: &str, context: &str) -> Result<&'a str, String> {
+    require(value, key, context)?
+        .as_str()
+        .ok_or_else(|| format!("{}.{}: expected string", context, key))
+}
+
+fn validate_message(message: &Value, context: &str) -> Result<(), String> {
+    // message: requires "text" or "id"
+    if message.get("text").and_then(Value::as_str).is_none() && message.get("id").is_none() {
+        return Err(format!("{}: message needs 'text' or 'id'", context));
+    }
+    Ok(())
+}
+
+fn validate_artifact_location(
+    location: &Value,
+    artifact_count: usize,
+    context: &str,
+) -> Result<(), String> {
+    if let Some(uri) = location.get("uri") {
+        let uri = uri
+            .as_str()
+            .ok_or(format!("{}.uri: expected string", context))?;
+        if uri.is_empty() {
+            return Err(format!("{}.uri: must not be empty", context));
+        }
+    }
+    if let Some(index) = location.get("index") {
+        // index: integer >= -1, and must refer to run.artifacts
+        let index = index
+            .as_i64()
+            .ok_or(format!("{}.index: expected integer", context))?;
+        if index < -1 || index >= artifact_count as i64 {
+            return Err(format!("{}.index: {} out of range", context, index));
+        }
+    }
+    Ok(())
+}
+
+/// Validate a document against the SARIF 2.1.0 schema constraints
+fn validate_sarif(doc: &Value) -> Result<(), String> {
+    if require_str(doc, "version", "sarifLog")? != "2.1.0" {
+        return Err("sarifLog.version: must be \"2.1.0\"".to_string());
+    }
+    if let Some(schema) = doc.get("$schema") {
+        schema.as_str().ok_or("sarifLog.$schema: expected string")?;
+    }
+    let runs = require(doc, "runs", "sarifLog")?
+        .as_array()
+        .ok_or("sarifLog.runs: expected array")?;
+
+    for (r, run) in runs.iter().enumerate() {
+        let ctx = format!("runs[{}]", r);
+        let tool = require(run, "tool", &ctx)?;
+        let driver = require(tool, "driver", &format!("{}.tool", ctx))?;
+        require_str(driver, "name", &format!("{}.tool.driver", ctx))?;
+
+        let rules = match driver.get("rules") {
+            Some(rules) => rules
+                .as_array()
+                .ok_or("driver.rules: expected array")?
+                .clone(),
+            None => Vec::new(),
+        };
+        for (i, rule) in rules.iter().enumerate() {
+            let rctx = format!("{}.tool.driver.rules[{}]", ctx, i);
+            require_str(rule, "id", &rctx)?;
+            if let Some(desc) = rule.get("shortDescription") {
+                // multiformatMessageString: requires "text"
+                require_str(desc, "text", &format!("{}.shortDescription", rctx))?;
+            }
+        }
+
+        let artifacts = match run.get("artifacts") {
+            Some(a) => a.as_array().ok_or("run.artifacts: expected array")?.clone(),
+            None => Vec::new(),
+        };
+        if run.get("artifacts").is_some() && artifacts.is_empty() {
+            return Err(format!("{}.artifacts: minItems 1 when present", ctx));
+        }
+        for (i, artifact) in artifacts.iter().enumerate() {
+            let actx = format!("{}.artifacts[{}]", ctx, i);
+            if let Some(location) = artifact.get("location") {
+                validate_artifact_location(location, artifacts.len(), &actx)?;
+            }
+        }
+
+        let Some(results) = run.get("results") else {
+            continue;
+        };
+        let results = results.as_array().ok_or("run.results: expected array")?;
+        for (i, result) in results.iter().enumerate() {
+            let rctx = format!("{}.results[{}]", ctx, i);
+            validate_message(require(result, "message", &rctx)?, &rctx)?;
+
+            if let Some(level) = result.get("level") {
+                let level = level.as_str().ok_or("result.level: expected string")?;
+                if !["none", "note", "warning", "error"].contains(&level) {
+                    return Err(format!("{}.level: '{}' not in enum", rctx, level));
+                }
+            }
+            if let Some(index) = result.get("ruleIndex") {
+                let index = index.as_i64().ok_or("result.ruleIndex: expected integer")?;
+                if index < -1 || index >= rules.len() as i64 {
+                    return Err(format!("{}.ruleIndex: {} out of range", rctx, index));
+                }
+                let rule_id = result.get("ruleId").and_then(Value::as_str);
+                if index >= 0 && rule_id != rules[index as usize]["id"].as_str() {
+                    return Err(format!("{}.ruleId: does not match ruleIndex", rctx));
+                }
+            }
+            if let Some(locations) = result.get("locations") {
+                let locations = locations
+                    .as_array()
+                    .ok_or("result.locations: expected array")?;
+                for (l, location) in locations.iter().enumerate() {
+                    let lctx = format!("{}.locations[{}]", rctx, l);
+                    if let Some(physical) = location.get("physicalLocation") {
+                        // physicalLocation: requires artifactLocation or address
+                        let artifact_location = physical.get("artifactLocation");
+                        if artifact_location.is_none() && physical.get("address").is_none() {
+                            return Err(format!("{}: physicalLocation needs a target", lctx));
+                        }
+                        if let Some(artifact_location) = artifact_location {
+                            validate_artifact_location(artifact_location, artifacts.len(), &lctx)?;
+                        }
+                        if let Some(region) = physical.get("region") {
+                            let start = region.get("startLine").and_then(Value::as_i64);
+                            if start.is_some_and(|line| line < 1) {
+                                return Err(format!("{}.region.startLine: minimum 1", lctx));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Test: Empty report list produces a valid document with no results
+#[test]
+fn test_sarif_empty_report_list_is_valid() {
+    let doc = parse(&to_sarif(&[]));
+
+    validate_sarif(&doc).unwrap();
+    assert_eq!(doc["$schema"], SARIF_SCHEMA);
+    assert_eq!(doc["version"], SARIF_VERSION);
+    assert_eq!(doc["runs"][0]["tool"]["driver"]["name"], "ruchyruchy");
+    assert_eq!(doc["runs"][0]["results"].as_array().unwrap().len(), 0);
+}
+
+/// Test: Each bug becomes a result with rule id and level
+#[test]
+fn test_sarif_result_per_bug_with_rule_and_level() {
+    let reports = vec![
+        report(
+            "Crash on empty match",
+            Severity::Critical,
+            BugCategory::Crash,
+        ),
+        report("Wrong sum", Severity::Medium, BugCategory::WrongOutput),
+        report("Typo in error", Severity::Low, BugCategory::Crash),
+    ];
+    let doc = parse(&to_sarif(&reports));
+    validate_sarif(&doc).unwrap();
+
+    let results = doc["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["ruleId"], "RUCHY-CRASH");
+    assert_eq!(results[0]["level"], "error");
+    assert_eq!(results[1]["ruleId"], "RUCHY-WRONG-OUTPUT");
+    assert_eq!(results[1]["level"], "warning");
+    assert_eq!(results[2]["level"], "note");
+
+    // Rules are deduplicated per category
+    let rules = doc["runs"][0]["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(results[2]["ruleIndex"], results[0]["ruleIndex"]);
+}
+
+/// Test: Related files become result locations
+#[test]
+fn test_sarif_related_files_become_locations() {
+    let mut bug = report("Parser panic", Severity::High, BugCategory::ParserError);
+    bug.add_related_file("src/interpreter/parser.rs".to_string());
+    bug.add_related_file("src/interpreter/ast.rs".to_string());
+
+    let doc = parse(&to_sarif(&[bug]));
+    validate_sarif(&doc).unwrap();
+
+    let locations = doc["runs"][0]["results"][0]["locations"]
+        .as_array()
+        .unwrap();
+    assert_eq!(locations.len(), 2);
+    assert_eq!(
+        locations[0]["physicalLocation"]["artifactLocation"]["uri"],
+        "src/interpreter/parser.rs"
+    );
+    assert!(doc["runs"][0].get("artifacts").is_none());
+}
+
+/// Test: Reports without spans point at the embedded reproduction
+#[test]
+fn test_sarif_reproduction_without_span_is_valid() {
+    let bug = report("Hang in loop", Severity::High, BugCategory::Hang);
+    let doc = parse(&to_sarif(&[bug]));
+    validate_sarif(&doc).unwrap();
+
+    let location = &doc["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+    assert!(location.get("region").is_none());
+    assert_eq!(location["artifactLocation"]["index"], 0);
+
+    let artifact = &doc["runs"][0]["artifacts"][0];
+    assert_eq!(
+        artifact["location"]["uri"],
+        location["artifactLocation"]["uri"]
+    );
+    assert_eq!(artifact["contents"]["text"], "fun main() { crash() }");
+}
+
+/// Test: The validator rejects documents that violate the schema
+#[test]
+fn test_sarif_validator_rejects_invalid_documents() {
+    let mut doc = parse(&to_sarif(&[report(
+        "Leak",
+        Severity::Medium,
+        BugCategory::MemoryLeak,
+    )]));
+    validate_sarif(&doc).unwrap();
+
+    doc["runs"][0]["results"][0]["level"] = Value::from("fatal");
+    assert!(validate_sarif(&doc).is_err());
+
+    doc["runs"][0]["results"][0]["level"] = Value::from("warning");
+    doc["runs"][0]["results"][0]["ruleIndex"] = Value::from(7);
+    assert!(validate_sarif(&doc).is_err());
+}